@@ -0,0 +1,321 @@
+//! Writes the frontend's generated TypeScript:
+//!
+//! - `src/types/bindings.ts`, every model in commander-core (see
+//!   [`commander_core::bindings`]);
+//! - `src/types/commands.ts`, the arguments and result of every command
+//!   registered in the app's `generate_handler!`, read from the signatures of
+//!   the `#[tauri::command]` functions.
+//!
+//! Run it with `npm run bindings` after changing a model or a command.  With
+//! `--check` it writes nothing and fails if either file is out of date, which
+//! is how CI keeps them honest.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::ExitCode;
+use syn::punctuated::Punctuated;
+use syn::{FnArg, GenericArgument, Item, ItemFn, Pat, PathArguments, ReturnType, Type};
+
+const COMMANDS_HEADER: &str =
+    "// Generated from the app's Tauri commands by `npm run bindings`. Do not edit.\n";
+
+/// Parameters Tauri fills in itself rather than reading from the invoke args.
+const INJECTED: &[&str] = &["State", "AppHandle", "Window", "WebviewWindow", "Webview"];
+
+fn main() -> ExitCode {
+    let check = std::env::args().any(|arg| arg == "--check");
+    let app_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let types_dir = app_dir.join("../src/types");
+
+    let commands = match commands_typescript(&app_dir) {
+        Ok(commands) => commands,
+        Err(e) => {
+            eprintln!("commander-bindgen: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let files = [
+        (
+            types_dir.join("bindings.ts"),
+            commander_core::bindings::typescript(),
+        ),
+        (types_dir.join("commands.ts"), commands),
+    ];
+
+    let mut stale = false;
+    for (path, content) in files {
+        let current = std::fs::read_to_string(&path).ok();
+        if current.as_deref() == Some(content.as_str()) {
+            continue;
+        }
+        if check {
+            eprintln!("{} is out of date; run `npm run bindings`", path.display());
+            stale = true;
+        } else if let Err(e) = std::fs::write(&path, content) {
+            eprintln!("Failed to write {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    if stale {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// A registered command's TypeScript signature.
+struct Command {
+    name: String,
+    /// `(name, type, optional)` per argument, in camelCase as Tauri expects.
+    args: Vec<(String, String, bool)>,
+    result: String,
+}
+
+/// The whole `commands.ts` file.
+fn commands_typescript(app_dir: &Path) -> Result<String, String> {
+    let models: BTreeSet<String> = commander_core::bindings::type_names().into_iter().collect();
+    let mut used = BTreeSet::new();
+    let mut commands = Vec::new();
+    for (module, name) in registered_commands(&app_dir.join("src/main.rs"))? {
+        let path = app_dir.join("src/commands").join(format!("{module}.rs"));
+        let item = find_command(&path, &name)?;
+        let mut resolve = |ty: &Type| {
+            to_ts(ty, &models, &mut used).map_err(|e| format!("{}: `{name}`: {e}", path.display()))
+        };
+
+        let mut args = Vec::new();
+        for input in &item.sig.inputs {
+            let FnArg::Typed(arg) = input else { continue };
+            if is_injected(&arg.ty) {
+                continue;
+            }
+            let Pat::Ident(ident) = &*arg.pat else {
+                return Err(format!(
+                    "{}: `{name}` has a destructured argument",
+                    path.display()
+                ));
+            };
+            let optional = generic_of(&arg.ty, "Option").is_some();
+            args.push((
+                camel_case(&ident.ident.to_string()),
+                resolve(&arg.ty)?,
+                optional,
+            ));
+        }
+        let result = match &item.sig.output {
+            ReturnType::Default => "void".to_string(),
+            ReturnType::Type(_, ty) => {
+                let ty = generic_of(ty, "CmdResult")
+                    .or_else(|| generic_of(ty, "Result"))
+                    .unwrap_or(ty);
+                resolve(ty)?
+            }
+        };
+        commands.push(Command { name, args, result });
+    }
+
+    let mut out = String::from(COMMANDS_HEADER);
+    if !used.is_empty() {
+        out.push_str("\nimport type {\n");
+        for name in &used {
+            out.push_str(&format!("  {name},\n"));
+        }
+        out.push_str("} from \"./bindings\";\n");
+    }
+    out.push_str("\n/** The arguments and result of every command, by name. */\n");
+    out.push_str("export interface Commands {\n");
+    for command in commands {
+        out.push_str(&format!("  {}: {{\n", command.name));
+        if command.args.is_empty() {
+            out.push_str("    args: Record<string, never>;\n");
+        } else {
+            out.push_str("    args: {\n");
+            for (name, ty, optional) in command.args {
+                let mark = if optional { "?" } else { "" };
+                out.push_str(&format!("      {name}{mark}: {ty};\n"));
+            }
+            out.push_str("    };\n");
+        }
+        out.push_str(&format!("    result: {};\n", command.result));
+        out.push_str("  };\n");
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// `(module, function)` for each `commands::<module>::<function>` passed to
+/// `generate_handler!` in `main.rs`.
+fn registered_commands(main_rs: &Path) -> Result<Vec<(String, String)>, String> {
+    let source = read(main_rs)?;
+    let start = source
+        .find("generate_handler![")
+        .ok_or_else(|| format!("{}: no generate_handler! call", main_rs.display()))?
+        + "generate_handler![".len();
+    let len = source[start..]
+        .find(']')
+        .ok_or_else(|| format!("{}: unterminated generate_handler!", main_rs.display()))?;
+    let paths = syn::parse::Parser::parse_str(
+        Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+        &source[start..start + len],
+    )
+    .map_err(|e| format!("{}: {e}", main_rs.display()))?;
+
+    paths
+        .iter()
+        .map(|path| {
+            let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+            match segments.as_slice() {
+                [root, module, name] if root == "commands" => Ok((module.clone(), name.clone())),
+                _ => Err(format!(
+                    "{}: unexpected command path `{}`",
+                    main_rs.display(),
+                    segments.join("::")
+                )),
+            }
+        })
+        .collect()
+}
+
+/// The `#[tauri::command]` function `name` in the module at `path`.
+fn find_command(path: &Path, name: &str) -> Result<ItemFn, String> {
+    let file = syn::parse_file(&read(path)?).map_err(|e| format!("{}: {e}", path.display()))?;
+    file.items
+        .into_iter()
+        .find_map(|item| match item {
+            Item::Fn(item) if item.sig.ident == name => Some(item),
+            _ => None,
+        })
+        .filter(|item| {
+            item.attrs.iter().any(|attr| {
+                let segments: Vec<String> = attr
+                    .path()
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect();
+                segments == ["tauri", "command"]
+            })
+        })
+        .ok_or_else(|| format!("{}: no #[tauri::command] fn {name}", path.display()))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(path) => path.path.segments.last(),
+        Type::Reference(reference) => last_segment(&reference.elem),
+        _ => None,
+    }
+}
+
+fn is_injected(ty: &Type) -> bool {
+    last_segment(ty).is_some_and(|s| INJECTED.iter().any(|name| s.ident == name))
+}
+
+/// The type arguments of `ty` if it is `wrapper<..>`.
+fn generics<'a>(ty: &'a Type, wrapper: &str) -> Option<Vec<&'a Type>> {
+    let segment = last_segment(ty).filter(|s| s.ident == wrapper)?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// The first type argument of `ty` if it is `wrapper<..>`.
+fn generic_of<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    generics(ty, wrapper)?.first().copied()
+}
+
+/// The TypeScript for a Rust type as serde serialises it.  Model names are
+/// added to `used`; any other named type is an error, since the frontend
+/// would have nothing to check it against.
+fn to_ts(
+    ty: &Type,
+    models: &BTreeSet<String>,
+    used: &mut BTreeSet<String>,
+) -> Result<String, String> {
+    let ty = match ty {
+        Type::Reference(reference) => &*reference.elem,
+        Type::Paren(paren) => &*paren.elem,
+        Type::Tuple(tuple) if tuple.elems.is_empty() => return Ok("void".to_string()),
+        Type::Tuple(tuple) => {
+            let elems = tuple
+                .elems
+                .iter()
+                .map(|elem| to_ts(elem, models, used))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(format!("[{}]", elems.join(", ")));
+        }
+        Type::Slice(slice) => return Ok(array_of(to_ts(&slice.elem, models, used)?)),
+        ty => ty,
+    };
+    if !matches!(ty, Type::Path(_)) {
+        return Err("unsupported type".to_string());
+    }
+    let Some(segment) = last_segment(ty) else {
+        return Err("unsupported type".to_string());
+    };
+
+    let name = segment.ident.to_string();
+    let args = generics(ty, &name).unwrap_or_default();
+    let arg = |i: usize, used: &mut BTreeSet<String>| match args.get(i) {
+        Some(arg) => to_ts(arg, models, used),
+        None => Err(format!("`{name}` without type arguments")),
+    };
+    Ok(match name.as_str() {
+        "String" | "str" | "char" | "PathBuf" | "Path" => "string".to_string(),
+        "bool" => "boolean".to_string(),
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" | "f32"
+        | "f64" => "number".to_string(),
+        "Value" => "unknown".to_string(),
+        "Box" => arg(0, used)?,
+        "Option" => format!("{} | null", arg(0, used)?),
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => array_of(arg(0, used)?),
+        "HashMap" | "BTreeMap" => format!("Record<{}, {}>", arg(0, used)?, arg(1, used)?),
+        _ if models.contains(&name) => {
+            used.insert(name.clone());
+            name
+        }
+        _ => {
+            return Err(format!(
+                "`{name}` is not a commander-core model, so it has no TypeScript binding"
+            ))
+        }
+    })
+}
+
+fn array_of(item: String) -> String {
+    if item.contains(" | ") {
+        format!("({item})[]")
+    } else {
+        format!("{item}[]")
+    }
+}
+
+/// `snake_case` to the `camelCase` Tauri expects argument keys in.
+fn camel_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+    for c in name.trim_start_matches('_').chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
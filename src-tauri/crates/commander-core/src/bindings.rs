@@ -0,0 +1,312 @@
+//! TypeScript declarations for every type in [`models`](crate::models),
+//! derived from their JSON schemas so the frontend's types follow the Rust
+//! ones instead of being kept in step by hand.  `commander-bindgen` writes
+//! them to `src/types/bindings.ts`.
+
+use crate::models::*;
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
+use schemars::Map;
+
+const HEADER: &str =
+    "// Generated from commander-core's models by `npm run bindings`. Do not edit.\n";
+
+/// Models the frontend builds and sends rather than receives: the fields
+/// serde can do without are optional in their TypeScript.
+const INPUTS: &[&str] = &[
+    "CreateProjectInput",
+    "CreatePlanningItemInput",
+    "UpdatePlanningItemInput",
+    "IssueImportFilters",
+    "RedactionRules",
+    "UpsertTaskGithubLinkInput",
+    "UpsertPlanningItemGithubLinkInput",
+];
+
+/// Register every exported model with `gen`.
+macro_rules! register {
+    ($gen:expr, $($ty:ty),* $(,)?) => {
+        $( $gen.subschema_for::<$ty>(); )*
+    };
+}
+
+/// The schema of every exported model, by name.
+fn definitions() -> Map<String, Schema> {
+    let mut gen = SchemaGenerator::new(SchemaSettings::draft07());
+    register!(
+        gen,
+        Project,
+        CreateProjectInput,
+        ProjectTemplate,
+        GitSummary,
+        ProjectOverview,
+        ProjectGroup,
+        ProjectDependencyEdge,
+        ProjectDiskUsage,
+        PlanningItem,
+        PlanningStatus,
+        CreatePlanningItemInput,
+        UpdatePlanningItemInput,
+        SavedFilter,
+        SessionStack,
+        SessionBookmark,
+        PlanVersion,
+        ClaudeSettingsFile,
+        SlashCommand,
+        SlashCommandFile,
+        ClaudeActivity,
+        ActivityDay,
+        ActivityProject,
+        SessionStorageReport,
+        SessionStorageProject,
+        SessionCleanupResult,
+        IssueImportFilters,
+        IssueImportResult,
+        ClaudeTask,
+        ClaudeTaskFile,
+        FlatClaudeTask,
+        ClaudeProcess,
+        ClaudeTodoList,
+        ClaudeTodo,
+        ClaudeHistoryEntry,
+        ClaudeHistoryPage,
+        ClaudeTeam,
+        TeamMember,
+        TeamOwner,
+        TaskGraph,
+        TaskGraphNode,
+        TaskGraphEdge,
+        ClaudePlan,
+        SessionMessage,
+        ClaudeSession,
+        SessionToolCall,
+        SessionTurn,
+        SessionAttachment,
+        SessionDetail,
+        SessionFollowStarted,
+        SessionFollowOutput,
+        SessionSummaryPreview,
+        SessionSummaryResult,
+        RedactionRules,
+        Redaction,
+        RedactedSession,
+        RunArtifact,
+        GitStatus,
+        GitFile,
+        GitCommit,
+        GitBranch,
+        GitProgress,
+        GitPullResult,
+        SessionDiffFile,
+        SessionDiff,
+        WorkspaceSnapshot,
+        ProjectBadge,
+        EnvFile,
+        EnvVar,
+        DeployConfig,
+        SearchProjectResult,
+        SearchPlanningItemResult,
+        SearchPlanResult,
+        SearchTaskResult,
+        SearchResults,
+        SessionSearchHit,
+        MatchOffset,
+        SessionTurnMatch,
+        SessionIndexStats,
+        CostBreakdown,
+        CostSummary,
+        BudgetThreshold,
+        UsageBucket,
+        UsageStats,
+        AutoSuggestion,
+        DashboardPlanningItem,
+        ActiveAgent,
+        CiFailure,
+        DashboardData,
+        TaskGithubLink,
+        UpsertTaskGithubLinkInput,
+        PlanningItemGithubLink,
+        UpsertPlanningItemGithubLinkInput,
+        IssueStateError,
+        TaskIssueStateRefresh,
+        PlanningItemIssueStateRefresh,
+        GithubAuthStatus,
+        CreateGithubIssueOutput,
+        IssueTemplate,
+        ClosingCommitSuggestion,
+        GithubIssueSummary,
+        GithubIssuePage,
+        GithubRemote,
+        GithubProject,
+        GithubProjectColumn,
+        GithubPullRequest,
+        PrCheck,
+        CreateGithubPrOutput,
+        CachedIssueComment,
+        CachedIssue,
+        ProjectWithStats,
+        SyncResult,
+        SyncHistoryProject,
+        SyncHistoryEntry,
+        EventLogEntry,
+        MaintenanceReport,
+        DuplicateProjectGroup,
+        ClaudeDirConfig,
+        DashboardWidget,
+        AppSettings,
+        DemoSeedResult,
+        SnapshotInfo,
+        DestructiveOpResult,
+        PresentationServerInfo,
+        AppNotification,
+        SyncRules,
+        StatusSyncAction,
+        PtyInfo,
+        PtySessionUsage,
+        PtyResourceUsage,
+        PtyLimits,
+        TerminalInfo,
+        EditorInfo,
+        UpdateInfo,
+    );
+    gen.take_definitions()
+}
+
+/// The names of the types `bindings.ts` exports.
+pub fn type_names() -> Vec<String> {
+    definitions().into_keys().collect()
+}
+
+/// The whole `bindings.ts` file.
+pub fn typescript() -> String {
+    let mut out = String::from(HEADER);
+    for (name, schema) in &definitions() {
+        out.push('\n');
+        let Schema::Object(obj) = schema else {
+            out.push_str(&format!("export type {name} = unknown;\n"));
+            continue;
+        };
+        push_doc(&mut out, obj, "");
+        match &obj.object {
+            Some(object) if !object.properties.is_empty() => {
+                out.push_str(&format!("export interface {name} {{\n"));
+                let input = INPUTS.contains(&name.as_str());
+                for (field, schema) in &object.properties {
+                    if let Schema::Object(field_obj) = schema {
+                        push_doc(&mut out, field_obj, "  ");
+                    }
+                    let optional = input && !object.required.contains(field);
+                    let mark = if optional { "?" } else { "" };
+                    out.push_str(&format!("  {field}{mark}: {};\n", ts_type(schema)));
+                }
+                out.push_str("}\n");
+            }
+            _ => out.push_str(&format!("export type {name} = {};\n", object_type(obj))),
+        }
+    }
+    out
+}
+
+fn push_doc(out: &mut String, obj: &SchemaObject, indent: &str) {
+    let Some(description) = obj.metadata.as_ref().and_then(|m| m.description.as_ref()) else {
+        return;
+    };
+    let lines: Vec<&str> = description.lines().collect();
+    if let [line] = lines.as_slice() {
+        out.push_str(&format!("{indent}/** {line} */\n"));
+        return;
+    }
+    out.push_str(&format!("{indent}/**\n"));
+    for line in lines {
+        out.push_str(&format!("{indent} * {line}\n").replace(" * \n", " *\n"));
+    }
+    out.push_str(&format!("{indent} */\n"));
+}
+
+fn ts_type(schema: &Schema) -> String {
+    match schema {
+        Schema::Bool(_) => "unknown".to_string(),
+        Schema::Object(obj) => object_type(obj),
+    }
+}
+
+fn object_type(obj: &SchemaObject) -> String {
+    if let Some(reference) = &obj.reference {
+        return reference.trim_start_matches("#/definitions/").to_string();
+    }
+    if let Some(values) = &obj.enum_values {
+        // JSON literals are valid TypeScript literal types.
+        return values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    if let Some(sub) = &obj.subschemas {
+        // A documented `$ref` field is wrapped in a one-element `allOf`.
+        if let Some([only]) = sub.all_of.as_deref() {
+            return ts_type(only);
+        }
+        if let Some(variants) = sub.any_of.as_ref().or(sub.one_of.as_ref()) {
+            return variants.iter().map(ts_type).collect::<Vec<_>>().join(" | ");
+        }
+    }
+
+    let types: Vec<&InstanceType> = match &obj.instance_type {
+        Some(SingleOrVec::Single(t)) => vec![t.as_ref()],
+        Some(SingleOrVec::Vec(ts)) => ts.iter().collect(),
+        None => return "unknown".to_string(),
+    };
+    types
+        .into_iter()
+        .map(|t| match t {
+            InstanceType::Null => "null".to_string(),
+            InstanceType::Boolean => "boolean".to_string(),
+            InstanceType::Integer | InstanceType::Number => "number".to_string(),
+            InstanceType::String => "string".to_string(),
+            InstanceType::Array => array_type(obj),
+            InstanceType::Object => object_literal(obj),
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn array_type(obj: &SchemaObject) -> String {
+    match obj.array.as_ref().and_then(|a| a.items.as_ref()) {
+        Some(SingleOrVec::Single(item)) => {
+            let item = ts_type(item);
+            if item.contains(" | ") {
+                format!("({item})[]")
+            } else {
+                format!("{item}[]")
+            }
+        }
+        Some(SingleOrVec::Vec(items)) => {
+            format!(
+                "[{}]",
+                items.iter().map(ts_type).collect::<Vec<_>>().join(", ")
+            )
+        }
+        None => "unknown[]".to_string(),
+    }
+}
+
+fn object_literal(obj: &SchemaObject) -> String {
+    let Some(object) = &obj.object else {
+        return "Record<string, unknown>".to_string();
+    };
+    if object.properties.is_empty() {
+        let value = object
+            .additional_properties
+            .as_deref()
+            .map(ts_type)
+            .unwrap_or_else(|| "unknown".to_string());
+        return format!("Record<string, {value}>");
+    }
+    let fields: Vec<String> = object
+        .properties
+        .iter()
+        .map(|(field, schema)| format!("{field}: {}", ts_type(schema)))
+        .collect();
+    format!("{{ {} }}", fields.join("; "))
+}
@@ -0,0 +1,363 @@
+use crate::error::CommanderError;
+use rusqlite::Connection;
+use std::path::Path;
+
+pub fn init_db(path: &Path) -> Result<Connection, CommanderError> {
+    let conn = Connection::open(path).map_err(CommanderError::from)?;
+
+    // Wait up to 5 s when another writer holds the lock (WAL mode allows one writer at a time)
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(CommanderError::from)?;
+
+    // Enable WAL mode for better concurrent performance
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")
+        .map_err(CommanderError::from)?;
+
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS projects (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            path TEXT NOT NULL UNIQUE,
+            tags TEXT NOT NULL DEFAULT '[]',
+            color TEXT,
+            sort_order INTEGER DEFAULT 0,
+            is_archived INTEGER DEFAULT 0,
+            created_at TEXT DEFAULT (datetime('now')),
+            identity_key TEXT
+        );
+
+        CREATE TABLE IF NOT EXISTS project_groups (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL UNIQUE,
+            sort_order INTEGER DEFAULT 0,
+            created_at TEXT DEFAULT (datetime('now'))
+        );
+
+        CREATE TABLE IF NOT EXISTS planning_items (
+            id TEXT PRIMARY KEY,
+            project_id TEXT REFERENCES projects(id) ON DELETE CASCADE,
+            subject TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL DEFAULT 'backlog'
+                CHECK (status IN ('backlog','todo','in_progress','done')),
+            priority INTEGER DEFAULT 0,
+            sort_order INTEGER DEFAULT 0,
+            created_at TEXT DEFAULT (datetime('now')),
+            updated_at TEXT DEFAULT (datetime('now'))
+        );
+
+        -- TODO: reserved for future encrypted env-var caching feature
+        CREATE TABLE IF NOT EXISTS env_var_cache (
+            id TEXT PRIMARY KEY,
+            project_id TEXT REFERENCES projects(id) ON DELETE CASCADE,
+            env_file TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value_encrypted TEXT NOT NULL,
+            iv TEXT NOT NULL,
+            UNIQUE(project_id, env_file, key)
+        );
+
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+
+        -- Session → project attribution, written by read_claude_sessions
+        CREATE TABLE IF NOT EXISTS session_project_links (
+            session_id TEXT NOT NULL,
+            project_id TEXT REFERENCES projects(id) ON DELETE CASCADE,
+            PRIMARY KEY (session_id, project_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS task_github_links (
+            task_id TEXT NOT NULL,
+            team_id TEXT NOT NULL,
+            github_issue_url TEXT NOT NULL,
+            github_issue_number INTEGER,
+            github_repo TEXT,
+            created_at TEXT DEFAULT (datetime('now')),
+            PRIMARY KEY (task_id, team_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS planning_item_github_links (
+            item_id TEXT PRIMARY KEY REFERENCES planning_items(id) ON DELETE CASCADE,
+            github_issue_url TEXT NOT NULL,
+            github_issue_number INTEGER,
+            github_repo TEXT,
+            created_at TEXT DEFAULT (datetime('now')),
+            github_issue_state TEXT,
+            state_updated_at TEXT
+        );
+
+        CREATE TABLE IF NOT EXISTS run_artifacts (
+            id TEXT PRIMARY KEY,
+            session_id TEXT NOT NULL,
+            project_id TEXT REFERENCES projects(id) ON DELETE SET NULL,
+            path TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            source TEXT NOT NULL,
+            size_bytes INTEGER,
+            detected_at TEXT DEFAULT (datetime('now')),
+            UNIQUE(session_id, path)
+        );
+
+        CREATE TABLE IF NOT EXISTS saved_filters (
+            id TEXT PRIMARY KEY,
+            board TEXT NOT NULL,
+            name TEXT NOT NULL,
+            query TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            UNIQUE(board, name)
+        );
+
+        CREATE TABLE IF NOT EXISTS session_stacks (
+            session_id TEXT PRIMARY KEY,
+            primary_language TEXT,
+            languages TEXT NOT NULL DEFAULT '[]',
+            tags TEXT NOT NULL DEFAULT '[]',
+            files_touched INTEGER NOT NULL DEFAULT 0,
+            project_id TEXT,
+            last_modified TEXT,
+            file_size INTEGER NOT NULL,
+            analyzed_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS sync_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            synced_at TEXT NOT NULL,
+            added TEXT NOT NULL DEFAULT '[]',
+            updated TEXT NOT NULL DEFAULT '[]',
+            archived TEXT NOT NULL DEFAULT '[]',
+            unchanged_count INTEGER NOT NULL DEFAULT 0,
+            scanned_roots TEXT NOT NULL DEFAULT '[]',
+            scan_duration_ms INTEGER NOT NULL DEFAULT 0,
+            total_duration_ms INTEGER NOT NULL DEFAULT 0
+        );
+
+        CREATE TABLE IF NOT EXISTS disk_usage (
+            project_path TEXT PRIMARY KEY,
+            total_bytes INTEGER NOT NULL,
+            node_modules_bytes INTEGER NOT NULL,
+            target_bytes INTEGER NOT NULL,
+            git_bytes INTEGER NOT NULL,
+            build_bytes INTEGER NOT NULL,
+            computed_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS workspace_snapshots (
+            id TEXT PRIMARY KEY,
+            project_path TEXT NOT NULL,
+            branch TEXT,
+            head_commit TEXT NOT NULL,
+            snapshot_commit TEXT NOT NULL,
+            has_changes INTEGER NOT NULL DEFAULT 0,
+            label TEXT,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS github_issue_cache (
+            repo TEXT NOT NULL COLLATE NOCASE,
+            number INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            body TEXT,
+            state TEXT NOT NULL,
+            url TEXT,
+            comments TEXT,
+            updated_at TEXT,
+            fetched_at TEXT NOT NULL,
+            PRIMARY KEY (repo, number)
+        );
+
+        CREATE TABLE IF NOT EXISTS task_order_overrides (
+            team_id TEXT NOT NULL,
+            status TEXT NOT NULL,
+            task_id TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            PRIMARY KEY (team_id, status, task_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS session_index_files (
+            path TEXT PRIMARY KEY,
+            session_id TEXT NOT NULL,
+            project_key TEXT NOT NULL,
+            cwd TEXT,
+            mtime INTEGER NOT NULL,
+            size INTEGER NOT NULL,
+            turns INTEGER NOT NULL,
+            indexed_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS cost_ledger (
+            session_id TEXT NOT NULL,
+            day TEXT NOT NULL,
+            model TEXT NOT NULL,
+            project_key TEXT NOT NULL,
+            cwd TEXT,
+            input_tokens INTEGER NOT NULL DEFAULT 0,
+            output_tokens INTEGER NOT NULL DEFAULT 0,
+            cache_creation_tokens INTEGER NOT NULL DEFAULT 0,
+            cache_read_tokens INTEGER NOT NULL DEFAULT 0,
+            cost_usd REAL NOT NULL DEFAULT 0,
+            PRIMARY KEY (session_id, day, model)
+        );
+
+        CREATE TABLE IF NOT EXISTS cost_ledger_files (
+            path TEXT PRIMARY KEY,
+            mtime INTEGER NOT NULL,
+            size INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS session_meta_cache (
+            path TEXT PRIMARY KEY,
+            mtime INTEGER NOT NULL,
+            size INTEGER NOT NULL,
+            cwd TEXT,
+            title TEXT,
+            message_count INTEGER NOT NULL DEFAULT 0
+        );
+
+        CREATE TABLE IF NOT EXISTS activity_days (
+            session_id TEXT NOT NULL,
+            day TEXT NOT NULL,
+            project_key TEXT NOT NULL,
+            cwd TEXT,
+            messages INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (session_id, day)
+        );
+
+        CREATE TABLE IF NOT EXISTS activity_files (
+            path TEXT PRIMARY KEY,
+            mtime INTEGER NOT NULL,
+            size INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS auto_suggestions (
+            id TEXT PRIMARY KEY,
+            kind TEXT NOT NULL,
+            target_id TEXT NOT NULL,
+            team_id TEXT,
+            source TEXT,
+            project_id TEXT,
+            title TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            commit_id TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'pending'
+                CHECK (status IN ('pending','accepted','dismissed')),
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS event_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            kind TEXT NOT NULL,
+            message TEXT NOT NULL,
+            details TEXT,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS session_bookmarks (
+            session_id TEXT PRIMARY KEY,
+            project_key TEXT NOT NULL,
+            source TEXT NOT NULL DEFAULT 'default',
+            note TEXT,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS plan_versions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            source TEXT NOT NULL,
+            filename TEXT NOT NULL,
+            content TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_plan_versions_plan ON plan_versions(source, filename);
+
+        CREATE VIRTUAL TABLE IF NOT EXISTS session_turns_fts USING fts5(
+            path UNINDEXED,
+            session_id UNINDEXED,
+            project_key UNINDEXED,
+            role UNINDEXED,
+            timestamp UNINDEXED,
+            text,
+            tokenize = 'porter unicode61'
+        );
+        ",
+    )
+    .map_err(CommanderError::from)?;
+
+    // Migration: add identity_key to existing DBs that pre-date this column.
+    // ALTER TABLE fails with "duplicate column name" if it already exists — that is fine.
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN identity_key TEXT", []);
+    conn.execute_batch(
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_projects_identity_key \
+         ON projects(identity_key) WHERE identity_key IS NOT NULL;",
+    )
+    .map_err(CommanderError::from)?;
+
+    // Migration: add cached GitHub issue state columns to task_github_links.
+    let _ = conn.execute(
+        "ALTER TABLE task_github_links ADD COLUMN github_issue_state TEXT",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE task_github_links ADD COLUMN state_updated_at TEXT",
+        [],
+    );
+
+    // Migration: per-project icon (emoji or detected framework key).
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN icon TEXT", []);
+
+    // Migration: detected stack columns, refreshed on every sync.
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN language TEXT", []);
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN framework TEXT", []);
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN package_manager TEXT", []);
+
+    // Migration: GitHub issue source for planning items imported from a tracker.
+    let _ = conn.execute("ALTER TABLE planning_items ADD COLUMN github_repo TEXT", []);
+    let _ = conn.execute(
+        "ALTER TABLE planning_items ADD COLUMN github_issue_number INTEGER",
+        [],
+    );
+    let _ = conn.execute(
+        "ALTER TABLE planning_items ADD COLUMN github_issue_url TEXT",
+        [],
+    );
+    // Migration: plan file created from a planning item.
+    let _ = conn.execute(
+        "ALTER TABLE planning_items ADD COLUMN plan_filename TEXT",
+        [],
+    );
+    conn.execute_batch(
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_planning_items_github_issue \
+         ON planning_items(project_id, github_repo, github_issue_number) \
+         WHERE github_issue_number IS NOT NULL;",
+    )
+    .map_err(CommanderError::from)?;
+    // Migration: items imported before planning_item_github_links existed
+    // keep their source issue as a link.
+    conn.execute_batch(
+        "INSERT OR IGNORE INTO planning_item_github_links \
+         (item_id, github_issue_url, github_issue_number, github_repo) \
+         SELECT id, github_issue_url, github_issue_number, github_repo \
+         FROM planning_items WHERE github_issue_url IS NOT NULL;",
+    )
+    .map_err(CommanderError::from)?;
+
+    // Migration: recency tracking for the "recently worked on" list.
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN last_opened_at TEXT", []);
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN real_path TEXT", []);
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN worktree_of TEXT", []);
+
+    // Migration: optional project group membership.
+    let _ = conn.execute(
+        "ALTER TABLE projects ADD COLUMN group_id TEXT \
+         REFERENCES project_groups(id) ON DELETE SET NULL",
+        [],
+    );
+
+    // Migration: why sync archived a project, shown in the archived view.
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN archived_reason TEXT", []);
+    let _ = conn.execute("ALTER TABLE projects ADD COLUMN archived_at TEXT", []);
+
+    Ok(conn)
+}
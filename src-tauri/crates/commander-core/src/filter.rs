@@ -0,0 +1,407 @@
+//! Board filter expressions, shared by the planning board and the task
+//! board: parsing the query a user types and matching records against it.
+
+use crate::error::CommanderError;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+
+/// A parsed board filter such as
+/// `status:in_progress,todo tag:acme updated:<7d -owner:bot "login bug"`.
+///
+/// Whitespace-separated terms are AND-ed.  A term is either free text
+/// (matched against subject and description) or `key:value`:
+///
+/// - `status:`, `tag:` — exact match; comma-separated values are OR-ed
+/// - `project:`, `owner:`, `team:` — substring match
+/// - `priority:` — `3`, `>2`, `<=1`, ...
+/// - `created:`, `updated:` — `<7d` (within the last 7 days), `>2w` (longer
+///   ago), or a date: `2024-05-01`, `<2024-05-01`, `>=2024-05-01`
+///
+/// A leading `-` negates a term; double quotes keep spaces in a value.
+#[derive(Debug, Clone)]
+pub struct BoardFilter {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone)]
+struct Term {
+    negated: bool,
+    kind: TermKind,
+}
+
+#[derive(Debug, Clone)]
+enum TermKind {
+    Text(String),
+    Status(Vec<String>),
+    Tag(Vec<String>),
+    Project(String),
+    Owner(String),
+    Team(String),
+    /// Inclusive bounds.
+    Priority(Option<i64>, Option<i64>),
+    /// Half-open `[from, to)` range.
+    Created(Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+    Updated(Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+}
+
+/// The fields of a planning item or task a filter can look at.  Fields that
+/// don't apply to a record stay at their default and never match.
+#[derive(Debug, Default)]
+pub struct FilterFields<'a> {
+    pub subject: &'a str,
+    pub description: Option<&'a str>,
+    pub status: &'a str,
+    pub tags: &'a [String],
+    pub project: Option<&'a str>,
+    pub owner: Option<&'a str>,
+    pub team: Option<&'a str>,
+    pub priority: Option<i64>,
+    pub created_at: Option<&'a str>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl BoardFilter {
+    pub fn parse(input: &str) -> Result<Self, CommanderError> {
+        Self::parse_at(input, Utc::now())
+    }
+
+    /// `parse` with relative times (`<7d`) counted back from `now`.
+    fn parse_at(input: &str, now: DateTime<Utc>) -> Result<Self, CommanderError> {
+        let terms = tokenize(input)?
+            .into_iter()
+            .map(|token| parse_term(&token, now))
+            .collect::<Result<_, _>>()?;
+        Ok(BoardFilter { terms })
+    }
+
+    /// `None` and blank strings mean "no filter".
+    pub fn parse_optional(input: Option<&str>) -> Result<Option<Self>, CommanderError> {
+        match input.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(s) => Self::parse(s).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn matches(&self, fields: &FilterFields) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.kind.matches(fields) != term.negated)
+    }
+}
+
+impl TermKind {
+    fn matches(&self, f: &FilterFields) -> bool {
+        let contains = |field: Option<&str>, needle: &str| {
+            field.is_some_and(|v| v.to_lowercase().contains(needle))
+        };
+        match self {
+            TermKind::Text(needle) => {
+                contains(Some(f.subject), needle) || contains(f.description, needle)
+            }
+            TermKind::Status(values) => values.iter().any(|v| f.status.eq_ignore_ascii_case(v)),
+            TermKind::Tag(values) => values
+                .iter()
+                .any(|v| f.tags.iter().any(|t| t.eq_ignore_ascii_case(v))),
+            TermKind::Project(needle) => contains(f.project, needle),
+            TermKind::Owner(needle) => contains(f.owner, needle),
+            TermKind::Team(needle) => contains(f.team, needle),
+            TermKind::Priority(min, max) => f
+                .priority
+                .is_some_and(|p| min.is_none_or(|min| p >= min) && max.is_none_or(|max| p <= max)),
+            TermKind::Created(from, to) => in_range(f.created_at, *from, *to),
+            TermKind::Updated(from, to) => in_range(f.updated_at, *from, *to),
+        }
+    }
+}
+
+fn in_range(value: Option<&str>, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> bool {
+    value
+        .and_then(parse_timestamp)
+        .is_some_and(|t| from.is_none_or(|from| t >= from) && to.is_none_or(|to| t < to))
+}
+
+/// Split on whitespace outside double quotes, dropping the quotes.
+fn tokenize(input: &str) -> Result<Vec<String>, CommanderError> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(CommanderError::parse("Unterminated quote in filter"));
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+fn parse_term(token: &str, now: DateTime<Utc>) -> Result<Term, CommanderError> {
+    let (negated, body) = match token.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, token),
+    };
+
+    let Some((key, value)) = body.split_once(':') else {
+        return Ok(Term {
+            negated,
+            kind: TermKind::Text(body.to_lowercase()),
+        });
+    };
+    if value.is_empty() {
+        return Err(CommanderError::parse(format!("Missing value for `{key}:`")));
+    }
+
+    let list = || {
+        value
+            .split(',')
+            .filter(|v| !v.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+    };
+    let kind = match key.to_lowercase().as_str() {
+        "status" => TermKind::Status(list()),
+        "tag" => TermKind::Tag(list()),
+        "project" => TermKind::Project(value.to_lowercase()),
+        "owner" => TermKind::Owner(value.to_lowercase()),
+        "team" => TermKind::Team(value.to_lowercase()),
+        "priority" => {
+            let (min, max) = parse_priority(value)?;
+            TermKind::Priority(min, max)
+        }
+        "created" => {
+            let (from, to) = parse_time_range(value, now)?;
+            TermKind::Created(from, to)
+        }
+        "updated" => {
+            let (from, to) = parse_time_range(value, now)?;
+            TermKind::Updated(from, to)
+        }
+        other => {
+            return Err(CommanderError::parse(format!(
+                "Unknown filter key `{other}` (expected status, tag, project, owner, \
+                 team, priority, created or updated)"
+            )))
+        }
+    };
+    Ok(Term { negated, kind })
+}
+
+/// Split a leading comparison operator off a value.
+fn split_operator(value: &str) -> (&str, &str) {
+    for op in ["<=", ">=", "<", ">", "="] {
+        if let Some(rest) = value.strip_prefix(op) {
+            return (op, rest);
+        }
+    }
+    ("", value)
+}
+
+fn parse_priority(value: &str) -> Result<(Option<i64>, Option<i64>), CommanderError> {
+    let (op, number) = split_operator(value);
+    let n: i64 = number
+        .parse()
+        .map_err(|_| CommanderError::parse(format!("Invalid priority `{value}`")))?;
+    Ok(match op {
+        "<" => (None, Some(n - 1)),
+        "<=" => (None, Some(n)),
+        ">" => (Some(n + 1), None),
+        ">=" => (Some(n), None),
+        _ => (Some(n), Some(n)),
+    })
+}
+
+type TimeRange = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// `<7d` / `>7d` are relative to now ("newer than" / "older than"); dates
+/// compare against whole UTC days.
+fn parse_time_range(value: &str, now: DateTime<Utc>) -> Result<TimeRange, CommanderError> {
+    let (op, operand) = split_operator(value);
+
+    if let Some(age) = parse_age(operand) {
+        let cutoff = now - age;
+        return Ok(match op {
+            ">" | ">=" => (None, Some(cutoff)),
+            _ => (Some(cutoff), None),
+        });
+    }
+
+    let day = NaiveDate::parse_from_str(operand, "%Y-%m-%d").map_err(|_| {
+        CommanderError::parse(format!(
+            "Invalid time `{value}` (use e.g. <7d, >2w or 2024-05-01)"
+        ))
+    })?;
+    let start = day.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    let end = start + Duration::days(1);
+    Ok(match op {
+        "<" => (None, Some(start)),
+        "<=" => (None, Some(end)),
+        ">" => (Some(end), None),
+        ">=" => (Some(start), None),
+        _ => (Some(start), Some(end)),
+    })
+}
+
+/// `30m`, `12h`, `7d`, `2w`.
+fn parse_age(s: &str) -> Option<Duration> {
+    let unit = s.chars().last()?;
+    let n: i64 = s[..s.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'm' => Some(Duration::minutes(n)),
+        'h' => Some(Duration::hours(n)),
+        'd' => Some(Duration::days(n)),
+        'w' => Some(Duration::weeks(n)),
+        _ => None,
+    }
+}
+
+/// RFC 3339, SQLite `datetime('now')` output, or epoch milliseconds.
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+        return Some(t.with_timezone(&Utc));
+    }
+    if let Ok(t) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+        return Some(t.and_utc());
+    }
+    s.parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        parse_timestamp("2026-05-10T12:00:00Z").unwrap()
+    }
+
+    fn filter(input: &str) -> BoardFilter {
+        BoardFilter::parse_at(input, now()).unwrap()
+    }
+
+    fn item<'a>(subject: &'a str, status: &'a str) -> FilterFields<'a> {
+        FilterFields {
+            subject,
+            status,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tokenize_keeps_quoted_spaces() {
+        assert_eq!(
+            tokenize(r#"status:todo "login bug"  -tag:"needs review""#).unwrap(),
+            ["status:todo", "login bug", "-tag:needs review"]
+        );
+        assert!(tokenize(r#""open"#).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_missing_values() {
+        assert!(BoardFilter::parse("colour:red").is_err());
+        assert!(BoardFilter::parse("status:").is_err());
+        assert!(BoardFilter::parse("priority:high").is_err());
+        assert!(BoardFilter::parse("updated:<soon").is_err());
+    }
+
+    #[test]
+    fn blank_input_is_no_filter() {
+        assert!(BoardFilter::parse_optional(None).unwrap().is_none());
+        assert!(BoardFilter::parse_optional(Some("   ")).unwrap().is_none());
+        assert!(BoardFilter::parse_optional(Some("bug")).unwrap().is_some());
+    }
+
+    #[test]
+    fn terms_are_anded_and_list_values_ored() {
+        let f = filter("status:todo,in_progress login");
+        assert!(f.matches(&item("Fix login", "todo")));
+        assert!(f.matches(&item("LOGIN page", "IN_PROGRESS")));
+        assert!(!f.matches(&item("Fix login", "done")));
+        assert!(!f.matches(&item("Fix signup", "todo")));
+    }
+
+    #[test]
+    fn text_matches_description_too() {
+        let fields = FilterFields {
+            description: Some("Users see a blank page"),
+            ..item("Fix login", "todo")
+        };
+        assert!(filter("blank").matches(&fields));
+        assert!(!filter("crash").matches(&fields));
+    }
+
+    #[test]
+    fn negation_inverts_a_term() {
+        let tags = ["acme".to_string()];
+        let fields = FilterFields {
+            tags: &tags,
+            owner: Some("review-bot"),
+            ..item("Fix login", "todo")
+        };
+        assert!(filter("tag:acme").matches(&fields));
+        assert!(!filter("-tag:acme").matches(&fields));
+        assert!(!filter("-owner:bot").matches(&fields));
+        assert!(filter("-owner:alice").matches(&fields));
+    }
+
+    #[test]
+    fn priority_comparisons() {
+        let with = |priority| FilterFields {
+            priority: Some(priority),
+            ..item("x", "todo")
+        };
+        assert!(filter("priority:>2").matches(&with(3)));
+        assert!(!filter("priority:>2").matches(&with(2)));
+        assert!(filter("priority:<=1").matches(&with(1)));
+        assert!(!filter("priority:<=1").matches(&with(2)));
+        assert!(filter("priority:2").matches(&with(2)));
+        assert!(!filter("priority:2").matches(&item("x", "todo")));
+    }
+
+    #[test]
+    fn relative_times_count_back_from_now() {
+        let updated = |at| FilterFields {
+            updated_at: Some(at),
+            ..item("x", "todo")
+        };
+        assert!(filter("updated:<7d").matches(&updated("2026-05-05T00:00:00Z")));
+        assert!(!filter("updated:<7d").matches(&updated("2026-05-01T00:00:00Z")));
+        assert!(filter("updated:>1w").matches(&updated("2026-05-01T00:00:00Z")));
+        assert!(filter("updated:<12h").matches(&updated("2026-05-10 08:00:00")));
+        assert!(!filter("updated:<7d").matches(&item("x", "todo")));
+    }
+
+    #[test]
+    fn dates_compare_whole_days() {
+        let created = |at| FilterFields {
+            created_at: Some(at),
+            ..item("x", "todo")
+        };
+        let f = filter("created:2026-05-01");
+        assert!(f.matches(&created("2026-05-01T00:00:00Z")));
+        assert!(f.matches(&created("2026-05-01T23:59:59Z")));
+        assert!(!f.matches(&created("2026-05-02T00:00:00Z")));
+        assert!(filter("created:<2026-05-01").matches(&created("2026-04-30T23:00:00Z")));
+        assert!(!filter("created:<2026-05-01").matches(&created("2026-05-01T00:00:00Z")));
+        assert!(filter("created:>=2026-05-01").matches(&created("2026-05-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn parses_all_timestamp_forms() {
+        let expected = parse_timestamp("2026-05-10T12:00:00+00:00");
+        assert!(expected.is_some());
+        assert_eq!(parse_timestamp("2026-05-10 12:00:00"), expected);
+        assert_eq!(parse_timestamp("1778414400000"), expected);
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+}
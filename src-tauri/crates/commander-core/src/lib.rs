@@ -0,0 +1,28 @@
+//! Core logic of Claude Commander with no Tauri dependency, shared by the
+//! desktop app, the CLI, the MCP server and tests.
+//!
+//! - [`bindings`] renders the models as TypeScript for the frontend.
+//! - [`db`] opens the SQLite database and applies migrations.
+//! - [`filter`] parses board filter expressions and matches records.
+//! - [`models`] holds every record exchanged with the frontend.
+//! - [`process`] picks Claude Code CLI processes out of `ps` output.
+//! - [`redaction`] strips secrets from transcripts before they are shared.
+//! - [`scan`] walks scan roots and derives project identity keys; the app
+//!   reconciles its results against the `projects` table.
+//! - [`stack`] detects a directory's language, framework and package manager.
+//! - [`session`] locates and parses Claude Code session transcripts and
+//!   attributes them to projects.
+//!
+//! Fallible functions return [`error::CommanderError`]; frontends convert it
+//! to whatever their transport needs (the Tauri commands send its JSON form).
+
+pub mod bindings;
+pub mod db;
+pub mod error;
+pub mod filter;
+pub mod models;
+pub mod process;
+pub mod redaction;
+pub mod scan;
+pub mod session;
+pub mod stack;
@@ -0,0 +1,2140 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Declares a schema-only type for fields that serde sees as a plain string
+/// or number but that only ever hold the listed values.  Such a field opts
+/// in with `#[schemars(with = "...")]`, and the TypeScript bindings then
+/// type it as a union of the literals.
+macro_rules! literal_union {
+    ($(#[doc = $doc:literal])* $name:ident = $($value:literal)|+) => {
+        $(#[doc = $doc])*
+        pub struct $name;
+
+        impl JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                let doc: Vec<&str> = vec![$($doc.trim()),*];
+                schemars::schema::SchemaObject {
+                    metadata: (!doc.is_empty()).then(|| {
+                        Box::new(schemars::schema::Metadata {
+                            description: Some(doc.join("\n")),
+                            ..Default::default()
+                        })
+                    }),
+                    enum_values: Some(vec![$(serde_json::Value::from($value)),+]),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+// ─── Projects ──────────────────────────────────────────────────────────────
+
+literal_union!(
+    /// Why a project was archived: by sync (`missing_path`, `out_of_scan_root`,
+    /// `inactive`) or by hand (`manual`).
+    ArchiveReason = "missing_path" | "out_of_scan_root" | "inactive" | "manual"
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub tags: Vec<String>,
+    pub color: Option<String>,
+    pub sort_order: i64,
+    pub is_archived: bool,
+    pub created_at: String,
+    pub identity_key: Option<String>,
+    /// Emoji chosen by the user, or a detected framework key (`"rust"`, `"nextjs"`, ...).
+    pub icon: Option<String>,
+    pub group_id: Option<String>,
+    /// Detected on sync: primary language (`"typescript"`, `"rust"`, ...).
+    pub language: Option<String>,
+    /// Detected on sync: `"Next.js"`, `"Tauri"`, `"Axum"`, `"Django"`, ...
+    pub framework: Option<String>,
+    /// Detected on sync: `"pnpm"`, `"cargo"`, `"poetry"`, ...
+    pub package_manager: Option<String>,
+    /// Last time Claude or a terminal was opened for the project.
+    pub last_opened_at: Option<String>,
+    /// Canonical location when `path` runs through a symlink; `None` when
+    /// `path` is already the real path.
+    #[serde(default)]
+    pub real_path: Option<String>,
+    /// For a linked git worktree, the id of the project holding the main checkout.
+    #[serde(default)]
+    pub worktree_of: Option<String>,
+    /// Why the project was archived: `"missing_path"`, `"out_of_scan_root"` or
+    /// `"inactive"` when sync did it, `"manual"` for `archive_project`.
+    /// `None` while active.
+    #[serde(default)]
+    #[schemars(with = "Option<ArchiveReason>")]
+    pub archived_reason: Option<String>,
+    /// When the project was last archived; `None` while active or for
+    /// records archived before this was tracked.
+    #[serde(default)]
+    pub archived_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreateProjectInput {
+    pub name: String,
+    pub path: String,
+    pub tags: Option<Vec<String>>,
+    pub color: Option<String>,
+    pub identity_key: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// A starter layout for `create_project_from_template`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectTemplate {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+}
+
+// ─── Project Overview ──────────────────────────────────────────────────────
+
+/// Counts-only view of `git_status` for dashboard cards.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitSummary {
+    pub branch: String,
+    pub ahead: usize,
+    pub behind: usize,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+}
+
+/// Return value of `get_project_overview`: everything a project card shows,
+/// gathered in one call.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectOverview {
+    pub project_id: String,
+    /// `None` when the project is not a git repository.
+    pub git: Option<GitSummary>,
+    /// Planning items not yet in `done`.
+    pub open_planning_items: usize,
+    pub sessions_last_7_days: usize,
+    /// Distinct GitHub issues linked via planning items or Claude tasks.
+    pub linked_issues: usize,
+    /// Linked issues not known to be closed.
+    pub open_linked_issues: usize,
+}
+
+// ─── Project Groups ────────────────────────────────────────────────────────
+
+/// A named folder of projects ("Client A", "Side projects") for the sidebar.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectGroup {
+    pub id: String,
+    pub name: String,
+    pub sort_order: i64,
+    pub created_at: String,
+    /// Number of non-archived projects assigned to the group.
+    pub project_count: usize,
+}
+
+// ─── Project Dependencies ──────────────────────────────────────────────────
+
+literal_union!(
+    /// Manifest format a project dependency was read from.
+    DependencyEcosystem = "npm" | "cargo"
+);
+
+literal_union!(
+    /// How a dependency was matched to a tracked project.
+    DependencyMatch = "name" | "path"
+);
+
+/// A directed edge in the cross-project dependency graph: the project
+/// `from_project_id` declares a dependency that resolves to `to_project_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectDependencyEdge {
+    pub from_project_id: String,
+    pub to_project_id: String,
+    /// Dependency name as written in the manifest.
+    pub dependency_name: String,
+    #[schemars(with = "DependencyEcosystem")]
+    pub ecosystem: String, // "npm" | "cargo"
+    /// Manifest section the dependency was declared in, e.g. `"devDependencies"`.
+    pub section: String,
+    #[schemars(with = "DependencyMatch")]
+    pub matched_by: String, // "name" | "path"
+}
+
+// ─── Disk Usage ────────────────────────────────────────────────────────────
+
+/// Space a project takes on disk, split into the usual reclaimable parts.
+/// Sizes are allocated bytes; each file counts towards one bucket only.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectDiskUsage {
+    pub project_path: String,
+    pub total_bytes: u64,
+    pub node_modules_bytes: u64,
+    /// Cargo `target/` directories.
+    pub target_bytes: u64,
+    pub git_bytes: u64,
+    /// `dist/`, `build/`, `.next/` and other generated output.
+    pub build_bytes: u64,
+    /// Everything not in one of the buckets above.
+    pub other_bytes: u64,
+    pub computed_at: String,
+    /// Served from the SQLite cache rather than a fresh walk.
+    pub cached: bool,
+}
+
+// ─── Planning Items ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanningItem {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub subject: String,
+    pub description: Option<String>,
+    pub status: PlanningStatus,
+    pub priority: i64,
+    pub sort_order: i64,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Source issue when the item was imported from GitHub.
+    pub github_repo: Option<String>,
+    pub github_issue_number: Option<i64>,
+    pub github_issue_url: Option<String>,
+    /// Plan in the primary Claude root created by `create_plan_from_item`.
+    pub plan_filename: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanningStatus {
+    Backlog,
+    Todo,
+    InProgress,
+    Done,
+}
+
+impl std::fmt::Display for PlanningStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanningStatus::Backlog => write!(f, "backlog"),
+            PlanningStatus::Todo => write!(f, "todo"),
+            PlanningStatus::InProgress => write!(f, "in_progress"),
+            PlanningStatus::Done => write!(f, "done"),
+        }
+    }
+}
+
+// ─── Planning Item Inputs ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CreatePlanningItemInput {
+    pub project_id: String,
+    pub subject: String,
+    pub description: Option<String>,
+    #[schemars(with = "PlanningStatus")]
+    pub status: String, // validated by DB CHECK constraint
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdatePlanningItemInput {
+    pub id: String,
+    pub subject: String,
+    pub description: Option<String>,
+}
+
+literal_union!(
+    /// Board a saved filter applies to.
+    FilterBoard = "planning" | "tasks"
+);
+
+/// A named board filter expression (see `commands::board_filter`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SavedFilter {
+    pub id: String,
+    pub name: String,
+    /// `"planning"` | `"tasks"`
+    #[schemars(with = "FilterBoard")]
+    pub board: String,
+    pub query: String,
+    pub created_at: String,
+}
+
+/// Tech stack inferred from a session's tool calls (see `commands::session_stack`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionStack {
+    pub session_id: String,
+    pub primary_language: Option<String>,
+    /// Languages by how often they were touched, most first.
+    pub languages: Vec<String>,
+    /// Topics such as `"sql-migrations"`, `"docker"`, `"ci"`, `"tests"`.
+    pub tags: Vec<String>,
+    pub files_touched: usize,
+    /// Project owning most of the touched files, else the one matching the session cwd.
+    pub project_id: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// A session pinned with `bookmark_session`, joined with what its transcript
+/// still says about it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionBookmark {
+    pub session_id: String,
+    pub project_key: String,
+    /// Claude config root the session lives in (see `ClaudeSession::source`).
+    pub source: String,
+    pub note: Option<String>,
+    pub created_at: String,
+    pub cwd: Option<String>,
+    /// Transcript mtime; `None` when `missing`.
+    pub last_message_at: Option<String>,
+    /// The transcript has been deleted since it was bookmarked.
+    pub missing: bool,
+}
+
+/// Claude Code's own `settings.json` (see `commands::claude_settings`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeSettingsFile {
+    pub path: String,
+    /// `false` when the file doesn't exist yet; `settings` is then `{}`.
+    pub exists: bool,
+    /// Pass back to `update_claude_settings` to detect concurrent edits.
+    pub modified_at: Option<String>,
+    #[schemars(with = "serde_json::Map<String, serde_json::Value>")]
+    pub settings: serde_json::Value,
+}
+
+literal_union!(SlashCommandScope = "user" | "project");
+
+/// A custom slash command file (see `commands::slash_commands`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SlashCommand {
+    /// Path below the commands directory without `.md`, e.g.
+    /// `"frontend/component"` for `/component` in the `frontend` namespace.
+    pub name: String,
+    /// `"user"` (`~/.claude/commands`) or `"project"` (`<project>/.claude/commands`).
+    #[schemars(with = "SlashCommandScope")]
+    pub scope: String,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub path: String,
+    pub description: Option<String>,
+    pub argument_hint: Option<String>,
+    pub allowed_tools: Option<String>,
+    pub model: Option<String>,
+    /// Every frontmatter field, including the ones above.
+    pub frontmatter: std::collections::BTreeMap<String, String>,
+    pub modified_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SlashCommandFile {
+    pub command: SlashCommand,
+    /// The whole file, frontmatter included.
+    pub content: String,
+    /// The prompt below the frontmatter.
+    pub body: String,
+}
+
+/// A stored copy of a plan's text (see `commands::plan_history`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanVersion {
+    pub id: i64,
+    /// Claude root of the plan (see `ClaudePlan::source`).
+    pub source: String,
+    pub filename: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// Filters for `import_issues_as_items`, mapped onto `gh issue list` flags.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct IssueImportFilters {
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub assignee: Option<String>,
+    pub milestone: Option<String>,
+    /// GitHub search syntax, e.g. `"sort:updated-desc no:assignee"`.
+    pub search: Option<String>,
+    /// Maximum issues to fetch (default 100).
+    pub limit: Option<usize>,
+}
+
+/// Return value of `import_issues_as_items`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueImportResult {
+    pub project_id: String,
+    pub imported: Vec<PlanningItem>,
+    /// Issues that already had a planning item in the project.
+    pub skipped_count: usize,
+}
+
+// ─── Claude Tasks ──────────────────────────────────────────────────────────
+
+literal_union!(ClaudeTaskStatus = "pending" | "in_progress" | "completed" | "deleted");
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeTask {
+    pub id: String,
+    pub team_name: Option<String>,
+    pub subject: String,
+    pub description: Option<String>,
+    #[schemars(with = "ClaudeTaskStatus")]
+    pub status: String,
+    pub owner: Option<String>,
+    pub active_form: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    /// Label of the Claude config root it was read from (`"default"` for
+    /// the primary one; see `AppSettings::extra_claude_dirs`), or
+    /// `project:<project id>` for a project's in-repo `.claude` directory.
+    pub source: String,
+    /// Manual position within its team and status column, if the user has
+    /// reordered it on the board (see `set_task_order`).
+    #[serde(default)]
+    pub sort_order: Option<i64>,
+    /// Ids of tasks in the same team that this one waits for
+    /// (`blockedBy`) and that wait for it (`blocks`).
+    #[serde(default)]
+    pub blocked_by: Vec<String>,
+    #[serde(default)]
+    pub blocks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeTaskFile {
+    pub team_id: String,
+    pub tasks: Vec<ClaudeTask>,
+}
+
+/// A task with the team it belongs to, as returned by `get_all_tasks_flat`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FlatClaudeTask {
+    pub team_id: String,
+    #[serde(flatten)]
+    pub task: ClaudeTask,
+}
+
+/// One `todos/<session>-agent-<agent>.json` file: the TodoWrite list of a
+/// session's main agent or one of its subagents.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeTodoList {
+    pub session_id: String,
+    /// Equal to `session_id` for the main agent.
+    pub agent_id: Option<String>,
+    pub filename: String,
+    /// Claude config root it was read from (see `ClaudeTask::source`).
+    pub source: String,
+    pub modified_at: Option<String>,
+    pub todos: Vec<ClaudeTodo>,
+}
+
+literal_union!(ClaudeTodoStatus = "pending" | "in_progress" | "completed");
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeTodo {
+    pub id: Option<String>,
+    pub content: String,
+    /// "pending" | "in_progress" | "completed"
+    #[schemars(with = "ClaudeTodoStatus")]
+    pub status: String,
+    pub active_form: Option<String>,
+    pub priority: Option<String>,
+}
+
+/// A prompt typed into Claude Code, from `history.jsonl`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeHistoryEntry {
+    pub display: String,
+    /// Working directory the prompt was typed in.
+    pub project: Option<String>,
+    pub timestamp: Option<String>,
+    pub source: String,
+    /// Pastes collapsed into `[Pasted text #n]` placeholders in `display`.
+    pub pasted_count: usize,
+}
+
+/// One page of `read_claude_history`, newest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeHistoryPage {
+    pub entries: Vec<ClaudeHistoryEntry>,
+    /// Entries matching the query across all pages.
+    pub total: usize,
+    pub offset: usize,
+}
+
+/// A running Claude Code CLI process (`get_active_claude_processes`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeProcess {
+    pub pid: u32,
+    /// Working directory, when the OS lets us read it.
+    pub cwd: Option<String>,
+    pub project_id: Option<String>,
+    pub started_at: Option<String>,
+    /// Full command line.
+    pub command: String,
+}
+
+/// An agent team: its `teams/<id>/config.json`, when Claude Code wrote
+/// one, and a summary of the tasks under `tasks/<id>/`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeTeam {
+    /// Directory name under `tasks/` and `teams/`.
+    pub team_id: String,
+    /// Claude config root it was read from (see `ClaudeTask::source`).
+    pub source: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub created_at: Option<String>,
+    /// Name of the lead agent.
+    pub lead: Option<String>,
+    pub members: Vec<TeamMember>,
+    /// Whether `config.json` was found; teams without one are known only
+    /// from their task folder.
+    pub has_config: bool,
+    pub pending: usize,
+    pub in_progress: usize,
+    pub completed: usize,
+    /// Most open tasks first; unowned tasks are not listed.
+    pub owners: Vec<TeamOwner>,
+    /// Latest `updatedAt` of any task.
+    pub last_updated: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TeamMember {
+    pub name: String,
+    pub agent_type: Option<String>,
+    pub model: Option<String>,
+    pub color: Option<String>,
+}
+
+/// Return value of `get_task_graph`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaskGraph {
+    pub team_id: String,
+    /// Ordered by depth, then numeric id.
+    pub nodes: Vec<TaskGraphNode>,
+    pub edges: Vec<TaskGraphEdge>,
+    /// The dependencies form a cycle; depths are then only approximate.
+    pub has_cycle: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaskGraphNode {
+    pub id: String,
+    pub subject: String,
+    #[schemars(with = "ClaudeTaskStatus")]
+    pub status: String,
+    pub owner: Option<String>,
+    /// Longest chain of blockers in front of it; 0 for tasks that wait on
+    /// nothing.  Meant as the layout column.
+    pub depth: usize,
+    /// Some blocker is not completed yet.
+    pub blocked: bool,
+}
+
+/// `from` has to finish before `to` can start.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct TaskGraphEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// Task counts for one owner within a team.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TeamOwner {
+    pub owner: String,
+    pub pending: usize,
+    pub in_progress: usize,
+    pub completed: usize,
+}
+
+// ─── Claude Plans ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudePlan {
+    pub id: String,
+    pub filename: String,
+    pub title: String,
+    pub preview: String,
+    pub content: String,
+    pub modified_at: Option<String>,
+    /// Claude config root it was read from (see `ClaudeTask::source`).
+    pub source: String,
+}
+
+// ─── Claude Sessions ───────────────────────────────────────────────────────
+
+literal_union!(
+    /// Author of a session message or turn.
+    MessageRole = "user" | "assistant"
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionMessage {
+    pub uuid: String,
+    #[schemars(with = "MessageRole")]
+    pub role: String,    // "user" | "assistant"
+    pub content: String, // extracted text
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeSession {
+    pub id: String,
+    pub project_key: String,
+    pub cwd: Option<String>,
+    /// Session summary or first user message, on one line (see
+    /// `read_session_title`).
+    #[serde(default)]
+    pub title: Option<String>,
+    pub message_count: usize,
+    pub last_message_at: Option<String>,
+    pub project_id: Option<String>,
+    /// Claude config root it was read from (see `ClaudeTask::source`).
+    pub source: String,
+}
+
+/// A single tool call embedded inside an assistant turn.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionToolCall {
+    pub id: String,
+    pub name: String,
+    /// Input JSON serialised to a compact string for display.
+    pub input: String,
+    /// Text of the matching `tool_result` from the following user turn,
+    /// if one was found.
+    pub output: Option<String>,
+    /// The tool reported a failure (`is_error` on its `tool_result`).
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+/// A parsed conversation turn from a JSONL session file.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionTurn {
+    pub uuid: String,
+    /// "user" | "assistant"
+    #[schemars(with = "MessageRole")]
+    pub role: String,
+    /// Plain text content (may be empty for tool-only turns).
+    pub content: String,
+    pub timestamp: String,
+    pub tool_calls: Vec<SessionToolCall>,
+    /// Images and documents in the message, plus those returned by its
+    /// tool calls.
+    #[serde(default)]
+    pub attachments: Vec<SessionAttachment>,
+}
+
+literal_union!(AttachmentKind = "image" | "document");
+
+/// An image or document block from a transcript.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionAttachment {
+    /// "image" | "document"
+    #[schemars(with = "AttachmentKind")]
+    pub kind: String,
+    pub media_type: Option<String>,
+    /// Temp file a base64 payload was extracted to; only set by
+    /// `load_session_detail`.
+    pub path: Option<String>,
+    /// Location of a `url` source.
+    pub url: Option<String>,
+    /// The tool call whose result carried it, e.g. `Read` on a screenshot.
+    pub tool_use_id: Option<String>,
+}
+
+/// Return value of `read_claude_session`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionDetail {
+    pub turns: Vec<SessionTurn>,
+    /// Total number of lines in the file (before the 500-turn cap).
+    pub total_count: usize,
+}
+
+// ─── Session Follow ────────────────────────────────────────────────────────
+
+/// Payload of `auto-follow-started`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionFollowStarted {
+    pub session_id: String,
+    pub path: String,
+    pub cwd: Option<String>,
+    pub project_id: Option<String>,
+    /// `true` when started by the watcher rather than `follow_session`.
+    pub auto: bool,
+}
+
+/// Payload of `session-follow-output`: rendered terminal text for new turns.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionFollowOutput {
+    pub session_id: String,
+    pub data: String,
+}
+
+// ─── Session Summaries ─────────────────────────────────────────────────────
+
+literal_union!(
+    /// How a session summary was produced: by `claude -p` or extracted from the transcript.
+    SummaryMethod = "claude" | "extractive"
+);
+
+/// Return value of `preview_session_summary`: the comment to review before
+/// `post_session_summary` posts it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionSummaryPreview {
+    /// The markdown comment, already redacted.
+    pub summary: String,
+    /// "claude" (headless `claude -p`) | "extractive" (fallback)
+    #[schemars(with = "SummaryMethod")]
+    pub method: String,
+    /// What redaction removed from the summary.
+    pub redactions: Vec<Redaction>,
+}
+
+/// Return value of `post_session_summary`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionSummaryResult {
+    /// The markdown that was posted, after redaction.
+    pub summary: String,
+    /// URL of the created comment, as printed by `gh issue comment`.
+    pub comment_url: Option<String>,
+}
+
+// ─── Session Redaction ─────────────────────────────────────────────────────
+
+/// What `redact_session` strips from a transcript. Every rule defaults to on.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedactionRules {
+    /// Values from the session project's `.env*` files and inline
+    /// `SECRET_KEY=value` assignments.
+    #[serde(default = "default_true")]
+    pub env_values: bool,
+    /// Well-known API key and token formats (Anthropic, OpenAI, GitHub, AWS, ...).
+    #[serde(default = "default_true")]
+    pub api_keys: bool,
+    /// Absolute paths under the user's home directory, rewritten to `~`.
+    #[serde(default = "default_true")]
+    pub home_paths: bool,
+    /// Extra user-supplied regexes; every match becomes `[REDACTED]`.
+    #[serde(default)]
+    pub custom_patterns: Vec<String>,
+}
+
+impl Default for RedactionRules {
+    fn default() -> Self {
+        Self {
+            env_values: true,
+            api_keys: true,
+            home_paths: true,
+            custom_patterns: vec![],
+        }
+    }
+}
+
+literal_union!(
+    /// Rule that produced a redaction (see `RedactionRules`).
+    RedactionKind = "env_value" | "api_key" | "home_path" | "custom"
+);
+
+/// One replacement made by `redact_session`, listed so the user can review
+/// what will be removed before sharing.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Redaction {
+    /// Turn the match was in; empty for text outside the transcript, such as
+    /// a session summary.
+    pub turn_uuid: String,
+    /// "env_value" | "api_key" | "home_path" | "custom"
+    #[schemars(with = "RedactionKind")]
+    pub kind: String,
+    /// The matched text with everything past its first few characters masked.
+    pub preview: String,
+    pub replacement: String,
+}
+
+/// Return value of `redact_session`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedactedSession {
+    pub session_id: String,
+    pub turns: Vec<SessionTurn>,
+    pub redactions: Vec<Redaction>,
+    pub total_count: usize,
+}
+
+// ─── Run Artifacts ─────────────────────────────────────────────────────────
+
+literal_union!(RunArtifactKind = "screenshot" | "report" | "file");
+
+literal_union!(
+    /// Where a run artifact was found: a `Write` tool call or a path in tool output.
+    RunArtifactSource = "write" | "tool_output"
+);
+
+/// A file produced during a Claude session (report, screenshot, ...),
+/// registered by `get_run_artifacts`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RunArtifact {
+    pub id: String,
+    pub session_id: String,
+    pub project_id: Option<String>,
+    pub path: String,
+    /// "screenshot" | "report" | "file"
+    #[schemars(with = "RunArtifactKind")]
+    pub kind: String,
+    /// "write" (created by a Write tool call) | "tool_output" (path mentioned in a tool result)
+    #[schemars(with = "RunArtifactSource")]
+    pub source: String,
+    pub size_bytes: Option<u64>,
+    /// Whether the file is still on disk; computed on read, not stored.
+    pub exists: bool,
+    pub detected_at: Option<String>,
+}
+
+// ─── Git ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitStatus {
+    pub branch: String,
+    pub ahead: usize,
+    pub behind: usize,
+    pub staged: Vec<GitFile>,
+    pub unstaged: Vec<GitFile>,
+    pub untracked: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitFile {
+    pub path: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitCommit {
+    pub hash: String,
+    pub short_hash: String,
+    pub message: String,
+    pub author: String,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitBranch {
+    pub name: String,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+}
+
+literal_union!(GitProgressPhase = "receiving" | "resolving" | "checkout" | "done");
+
+/// Payload of the `git-progress` event emitted during clone / fetch / pull.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitProgress {
+    pub operation_id: String,
+    /// "receiving" | "resolving" | "checkout" | "done"
+    #[schemars(with = "GitProgressPhase")]
+    pub phase: String,
+    pub received_objects: usize,
+    pub total_objects: usize,
+    pub indexed_deltas: usize,
+    pub total_deltas: usize,
+    pub received_bytes: usize,
+    pub checkout_completed: usize,
+    pub checkout_total: usize,
+}
+
+literal_union!(GitPullStatus = "up_to_date" | "fast_forward");
+
+/// Return value of `git_pull`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitPullResult {
+    /// "up_to_date" | "fast_forward"
+    #[schemars(with = "GitPullStatus")]
+    pub status: String,
+    /// Short hash of HEAD after the pull.
+    pub head: String,
+}
+
+literal_union!(DiffFileStatus = "added" | "deleted" | "modified" | "renamed");
+
+/// One file in a `SessionDiff`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionDiffFile {
+    pub path: String,
+    /// "added" | "deleted" | "modified" | "renamed"
+    #[schemars(with = "DiffFileStatus")]
+    pub status: String,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Return value of `diff_since_session`: what changed in a repository since
+/// a Claude session started.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionDiff {
+    pub session_id: String,
+    pub session_started_at: String,
+    /// Last commit made before the session started; `None` when the whole
+    /// history is newer (the diff then starts from an empty tree).
+    pub base_commit: Option<GitCommit>,
+    /// Commits on HEAD made after the session started.
+    pub commits_since: usize,
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub files: Vec<SessionDiffFile>,
+}
+
+/// A pre-agent-run checkpoint of a repository (see `commands::workspace_snapshots`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspaceSnapshot {
+    pub id: String,
+    pub project_path: String,
+    /// Checked-out branch, or `None` for a detached HEAD.
+    pub branch: Option<String>,
+    pub head_commit: String,
+    /// Commit holding the working tree, kept alive by `refs/commander/snapshots/<id>`.
+    pub snapshot_commit: String,
+    /// Whether there was uncommitted work to capture.
+    pub has_changes: bool,
+    pub label: Option<String>,
+    pub created_at: String,
+}
+
+// ─── Project Badges ────────────────────────────────────────────────────────
+
+literal_union!(BadgeKind = "ci" | "coverage" | "version" | "license" | "downloads" | "other");
+
+literal_union!(
+    /// Where a badge was found: the README or a CI config.
+    BadgeSource = "readme" | "ci_config"
+);
+
+/// A status badge found in a project's README or implied by its CI config.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectBadge {
+    /// `"ci"` | `"coverage"` | `"version"` | `"license"` | `"downloads"` | `"other"`
+    #[schemars(with = "BadgeKind")]
+    pub kind: String,
+    pub label: String,
+    pub image_url: String,
+    /// Where the badge links to, e.g. the workflow runs or coverage report.
+    pub link_url: Option<String>,
+    /// `"readme"` | `"ci_config"`
+    #[schemars(with = "BadgeSource")]
+    pub source: String,
+}
+
+// ─── Env Vars ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnvFile {
+    pub filename: String,
+    pub path: String,
+    pub var_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnvVar {
+    pub key: String,
+    pub value: String,
+    pub masked: bool,
+}
+
+// ─── Deploy Config ─────────────────────────────────────────────────────────
+
+literal_union!(DeployKind = "fly" | "vercel");
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeployConfig {
+    #[schemars(with = "DeployKind")]
+    pub kind: String, // "fly" | "vercel"
+    pub app_name: Option<String>,
+    pub region: Option<String>,
+    #[schemars(with = "serde_json::Map<String, serde_json::Value>")]
+    pub raw: serde_json::Value,
+}
+
+// ─── Search Results ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchProjectResult {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub tags: Vec<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchPlanningItemResult {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub project_name: String,
+    pub subject: String,
+    pub description: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchPlanResult {
+    pub id: String,
+    pub filename: String,
+    pub title: String,
+    pub preview: String,
+    pub modified_at: Option<String>,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchTaskResult {
+    pub id: String,
+    pub team_id: String,
+    pub team_name: Option<String>,
+    pub subject: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResults {
+    pub projects: Vec<SearchProjectResult>,
+    pub planning_items: Vec<SearchPlanningItemResult>,
+    pub plans: Vec<SearchPlanResult>,
+    pub tasks: Vec<SearchTaskResult>,
+}
+
+/// One matching transcript turn from `search_sessions`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionSearchHit {
+    pub session_id: String,
+    /// Directory name under `~/.claude/projects`.
+    pub project_key: String,
+    /// Working directory from the transcript's first line, if recorded.
+    pub cwd: Option<String>,
+    /// "user" | "assistant"
+    #[schemars(with = "MessageRole")]
+    pub role: String,
+    pub timestamp: String,
+    /// Matched text with each hit wrapped in `\u{2}` … `\u{3}`.
+    pub snippet: String,
+}
+
+/// A matched range within a turn's `content`, in UTF-16 code units so it
+/// can be used with JavaScript string indices directly.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MatchOffset {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// One turn matching `search_in_session`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionTurnMatch {
+    pub uuid: String,
+    /// Position among the transcript's turns, the same order
+    /// `read_claude_session` returns them in.
+    pub turn_index: usize,
+    /// "user" | "assistant"
+    #[schemars(with = "MessageRole")]
+    pub role: String,
+    pub timestamp: String,
+    /// Context around the first match, hits wrapped in `\u{2}` … `\u{3}`.
+    pub snippet: String,
+    /// Every match in `content`; empty when only tool calls matched.
+    pub offsets: Vec<MatchOffset>,
+    /// Tool calls whose name or input matched.
+    pub tool_call_ids: Vec<String>,
+}
+
+literal_union!(
+    /// Statuses of the planning items the dashboard lists.
+    DashboardPlanningStatus = "todo" | "in_progress"
+);
+
+/// Open planning item shown by the `due_items` dashboard widget.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DashboardPlanningItem {
+    pub id: String,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub subject: String,
+    /// "todo" | "in_progress"
+    #[schemars(with = "DashboardPlanningStatus")]
+    pub status: String,
+    pub priority: i64,
+    pub updated_at: String,
+}
+
+/// Claude session whose transcript was written to recently.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ActiveAgent {
+    pub session_id: String,
+    pub project_key: String,
+    pub cwd: Option<String>,
+    pub project_id: Option<String>,
+    /// RFC 3339 time of the last transcript write.
+    pub last_activity: String,
+}
+
+/// Latest GitHub Actions run of a workflow on a branch, which failed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CiFailure {
+    pub project_id: String,
+    pub project_name: String,
+    /// "owner/repo"
+    pub repo: String,
+    pub workflow: String,
+    pub title: String,
+    pub branch: String,
+    pub url: String,
+    pub created_at: String,
+}
+
+/// Return value of `get_dashboard_data`; widgets that were not requested
+/// are `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DashboardData {
+    pub recent_projects: Option<Vec<Project>>,
+    pub due_items: Option<Vec<DashboardPlanningItem>>,
+    pub active_agents: Option<Vec<ActiveAgent>>,
+    pub cost: Option<CostSummary>,
+    pub ci_failures: Option<Vec<CiFailure>>,
+}
+
+/// Estimated spend for one key (project or model) in a `CostSummary`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CostBreakdown {
+    pub key: String,
+    pub cost_usd: f64,
+    pub sessions: usize,
+}
+
+literal_union!(BudgetPeriod = "monthly" | "weekly");
+
+literal_union!(
+    /// Share of a budget, in percent, that raises a warning.
+    BudgetPercent = 50 | 80 | 100
+);
+
+/// Payload of the `budget-threshold` event, raised once per period for
+/// each threshold crossed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetThreshold {
+    /// "monthly" | "weekly"
+    #[schemars(with = "BudgetPeriod")]
+    pub period: String,
+    /// `YYYY-MM` for months, the Monday's date for weeks.
+    pub period_start: String,
+    /// 50, 80 or 100.
+    #[schemars(with = "BudgetPercent")]
+    pub threshold: u32,
+    pub spent_usd: f64,
+    pub budget_usd: f64,
+}
+
+/// Return value of `get_cost_summary`.  Costs are estimates from list
+/// prices and the token counts in session transcripts.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CostSummary {
+    /// `"YYYY-MM"`
+    pub month: String,
+    pub total_usd: f64,
+    /// `AppSettings::monthly_budget_usd`; `0` = no budget.
+    pub budget_usd: f64,
+    pub budget_used_pct: Option<f64>,
+    pub sessions: usize,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_creation_tokens: i64,
+    pub cache_read_tokens: i64,
+    /// Keyed by session working directory (or project key when unknown).
+    pub by_project: Vec<CostBreakdown>,
+    pub by_model: Vec<CostBreakdown>,
+}
+
+/// Token and cost totals for one day, project or model in `UsageStats`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UsageBucket {
+    /// `"YYYY-MM-DD"`, project id (or the session cwd when no project
+    /// matches), or model name.
+    pub key: String,
+    /// Project name for project buckets.
+    pub label: Option<String>,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_creation_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cost_usd: f64,
+    pub sessions: usize,
+}
+
+/// Return value of `get_usage_stats`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UsageStats {
+    /// The requested range, e.g. `"30d"` or `"all"`.
+    pub range: String,
+    /// First day included, `None` for `"all"`.
+    pub since: Option<String>,
+    pub totals: UsageBucket,
+    /// Oldest day first; days without usage are omitted.
+    pub by_day: Vec<UsageBucket>,
+    /// Most expensive first.
+    pub by_project: Vec<UsageBucket>,
+    pub by_model: Vec<UsageBucket>,
+}
+
+/// Return value of `analyze_session_storage`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SessionStorageReport {
+    pub total_bytes: u64,
+    pub total_sessions: usize,
+    /// Largest first.
+    pub projects: Vec<SessionStorageProject>,
+}
+
+/// Transcripts of one Claude project directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SessionStorageProject {
+    /// Claude root label (see `ClaudeSession::source`).
+    pub source: String,
+    pub project_key: String,
+    /// Working directory of the most recent session.
+    pub cwd: Option<String>,
+    /// Transcripts plus their companion directories.
+    pub bytes: u64,
+    pub sessions: usize,
+    /// Oldest and newest transcript mtimes.
+    pub oldest: Option<String>,
+    pub newest: Option<String>,
+}
+
+/// Return value of `cleanup_sessions`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SessionCleanupResult {
+    pub dry_run: bool,
+    /// Sessions removed, or that would be on a dry run.
+    pub sessions: usize,
+    pub bytes: u64,
+    pub paths: Vec<String>,
+    /// Where the files were moved; `None` when deleted or on a dry run.
+    pub trash_path: Option<String>,
+    /// Old sessions kept because they are bookmarked.
+    pub skipped_bookmarked: usize,
+    pub errors: Vec<String>,
+}
+
+/// Return value of `get_claude_activity`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeActivity {
+    /// The requested range, e.g. `"90d"` or `"all"`.
+    pub range: String,
+    /// First day included, `None` for `"all"`.
+    pub since: Option<String>,
+    pub total_sessions: usize,
+    pub total_messages: i64,
+    /// Oldest day first; days without messages are omitted.
+    pub days: Vec<ActivityDay>,
+    /// Most messages first.
+    pub top_projects: Vec<ActivityProject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ActivityDay {
+    /// Local `"YYYY-MM-DD"`.
+    pub day: String,
+    pub sessions: usize,
+    pub messages: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ActivityProject {
+    /// Project id, or the session cwd when no project matches.
+    pub key: String,
+    /// Project name when `key` is a project id.
+    pub label: Option<String>,
+    pub sessions: usize,
+    pub messages: i64,
+}
+
+literal_union!(SuggestionKind = "planning_item" | "claude_task");
+
+/// A proposed status change inferred from a recent commit, e.g. marking an
+/// in-progress planning item done because a commit references its issue.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AutoSuggestion {
+    /// `"{kind}:{target}:{commit}"`, stable across detection passes.
+    pub id: String,
+    /// `"planning_item"` or `"claude_task"`.
+    #[schemars(with = "SuggestionKind")]
+    pub kind: String,
+    /// Planning item id or Claude task id.
+    pub target_id: String,
+    /// Team of a Claude task; `None` for planning items.
+    pub team_id: Option<String>,
+    /// Claude config root of a task; `None` for planning items.
+    pub source: Option<String>,
+    pub project_id: Option<String>,
+    /// Subject of the item or task.
+    pub title: String,
+    /// Human-readable evidence, e.g. "Commit 1a2b3c4 references #42".
+    pub reason: String,
+    pub commit_id: String,
+    pub created_at: String,
+}
+
+/// Outcome of one session indexing pass.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SessionIndexStats {
+    /// Transcripts (re)indexed because they were new or changed.
+    pub indexed: usize,
+    pub turns: usize,
+    /// Transcripts dropped because their file is gone.
+    pub removed: usize,
+    pub total_sessions: usize,
+}
+
+// ─── GitHub Issue Links ────────────────────────────────────────────────────
+
+literal_union!(
+    /// State of a GitHub issue.
+    IssueState = "open" | "closed"
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaskGithubLink {
+    pub task_id: String,
+    pub team_id: String,
+    pub github_issue_url: String,
+    pub github_issue_number: Option<i64>,
+    pub github_repo: Option<String>,
+    pub created_at: String,
+    /// Cached issue state: `"open"` or `"closed"`.  `None` = not yet fetched.
+    #[schemars(with = "Option<IssueState>")]
+    pub github_issue_state: Option<String>,
+    /// When `github_issue_state` was last refreshed.
+    pub state_updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpsertTaskGithubLinkInput {
+    pub task_id: String,
+    pub team_id: String,
+    pub github_issue_url: String,
+    pub github_issue_number: Option<i64>,
+    pub github_repo: Option<String>,
+}
+
+/// Kanban card → GitHub issue link; the planning-item counterpart of
+/// `TaskGithubLink`.  Imported items are linked to their source issue.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanningItemGithubLink {
+    pub item_id: String,
+    pub github_issue_url: String,
+    pub github_issue_number: Option<i64>,
+    pub github_repo: Option<String>,
+    pub created_at: String,
+    /// Cached issue state: `"open"` or `"closed"`.  `None` = not yet fetched.
+    #[schemars(with = "Option<IssueState>")]
+    pub github_issue_state: Option<String>,
+    pub state_updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpsertPlanningItemGithubLinkInput {
+    pub item_id: String,
+    pub github_issue_url: String,
+    pub github_issue_number: Option<i64>,
+    pub github_repo: Option<String>,
+}
+
+/// A repository whose issue states could not be refreshed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueStateError {
+    pub repo: String,
+    pub message: String,
+}
+
+/// Result of `fetch_issue_states`: every task link, refreshed where
+/// possible, plus one error per repository that failed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaskIssueStateRefresh {
+    pub links: Vec<TaskGithubLink>,
+    pub errors: Vec<IssueStateError>,
+}
+
+/// Result of `fetch_planning_item_issue_states`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanningItemIssueStateRefresh {
+    pub links: Vec<PlanningItemGithubLink>,
+    pub errors: Vec<IssueStateError>,
+}
+
+literal_union!(
+    /// Why `gh` cannot be used; the same codes GitHub commands fail with.
+    GithubAuthProblem =
+        "GH_NOT_INSTALLED"
+        | "GH_NOT_AUTHENTICATED"
+        | "GH_MISSING_SCOPE"
+        | "GH_RATE_LIMITED"
+        | "GH_NETWORK_ERROR"
+);
+
+/// Result of `check_github_auth`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubAuthStatus {
+    /// `gh` is on PATH and runnable.
+    pub installed: bool,
+    /// Version reported by `gh --version`, e.g. `"2.40.1"`.
+    pub gh_version: Option<String>,
+    /// A personal access token is stored in the keychain; when set, the
+    /// remaining fields describe that token rather than `gh`'s login.
+    pub token_stored: bool,
+    pub authenticated: bool,
+    pub login: Option<String>,
+    /// OAuth scopes granted to the token.  Empty for fine-grained tokens,
+    /// which do not report scopes.
+    pub scopes: Vec<String>,
+    /// Scopes needed for issue creation that the token lacks.
+    pub missing_scopes: Vec<String>,
+    pub rate_limit_remaining: Option<i64>,
+    /// Unix timestamp at which the rate-limit window resets.
+    pub rate_limit_reset: Option<i64>,
+    /// `GH_*` problem code when something blocks GitHub actions, else `None`.
+    #[schemars(with = "Option<GithubAuthProblem>")]
+    pub problem: Option<String>,
+    /// Human-readable fix for `problem`.
+    pub remediation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateGithubIssueOutput {
+    pub number: i64,
+    pub url: String,
+}
+
+/// An issue template from `get_issue_templates`, ready to pre-fill a new
+/// issue.  Issue forms are flattened into one markdown heading per field.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueTemplate {
+    /// File name in the template directory, e.g. `"bug_report.md"`.
+    pub file: String,
+    pub name: String,
+    pub about: Option<String>,
+    /// Title prefix such as `"[Bug] "`.
+    pub title: Option<String>,
+    pub labels: Vec<String>,
+    pub body: String,
+}
+
+/// From `suggest_closing_commit`: the trailer that closes a task's linked
+/// issue, and the commit message it was appended to, when one was given.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClosingCommitSuggestion {
+    /// E.g. `"Fixes owner/repo#12"`.
+    pub trailer: String,
+    pub message: Option<String>,
+}
+
+/// An issue row in the `list_github_issues` picker.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubIssueSummary {
+    pub number: i64,
+    pub title: String,
+    /// `"open"` or `"closed"`.
+    #[schemars(with = "IssueState")]
+    pub state: String,
+    pub url: String,
+    pub labels: Vec<String>,
+    pub author: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubIssuePage {
+    pub issues: Vec<GithubIssueSummary>,
+    /// 1-based.
+    pub page: usize,
+    pub has_more: bool,
+}
+
+/// A GitHub Projects (v2) board, from `list_github_projects`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubProject {
+    /// GraphQL node id, passed back to `add_issue_to_github_project`.
+    pub id: String,
+    pub number: i64,
+    pub title: String,
+    pub url: String,
+    pub closed: bool,
+    /// Options of the board's `Status` field; empty when it has none.
+    pub columns: Vec<GithubProjectColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubProjectColumn {
+    pub id: String,
+    pub name: String,
+}
+
+/// A git remote that points at GitHub, from `list_github_remotes`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubRemote {
+    /// Remote name, e.g. `"origin"` or `"upstream"`.
+    pub remote: String,
+    /// `"owner/repo"`.
+    pub repo: String,
+}
+
+literal_union!(PullRequestState = "open" | "closed" | "merged");
+
+literal_union!(ReviewDecision = "APPROVED" | "CHANGES_REQUESTED" | "REVIEW_REQUIRED");
+
+/// A pull request as listed by `list_github_prs`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GithubPullRequest {
+    pub number: i64,
+    pub title: String,
+    /// `"open"`, `"closed"` or `"merged"`.
+    #[schemars(with = "PullRequestState")]
+    pub state: String,
+    pub url: String,
+    pub head_ref: String,
+    pub base_ref: String,
+    pub is_draft: bool,
+    pub author: Option<String>,
+    /// `"APPROVED"`, `"CHANGES_REQUESTED"` or `"REVIEW_REQUIRED"`; `None`
+    /// when the repository requires no review.
+    #[schemars(with = "Option<ReviewDecision>")]
+    pub review_decision: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+literal_union!(
+    /// Outcome group `gh pr checks` sorts a check into.
+    CheckBucket = "pass" | "fail" | "pending" | "skipping" | "cancel"
+);
+
+/// One CI check or status on a pull request (`gh pr checks`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PrCheck {
+    pub name: String,
+    pub workflow: Option<String>,
+    /// `"pass"`, `"fail"`, `"pending"`, `"skipping"` or `"cancel"`.
+    #[schemars(with = "CheckBucket")]
+    pub bucket: String,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateGithubPrOutput {
+    pub number: i64,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CachedIssueComment {
+    pub author: Option<String>,
+    pub body: String,
+    pub created_at: Option<String>,
+}
+
+/// A GitHub issue as it looked when last fetched, served from the local
+/// cache so it stays browsable without network access.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CachedIssue {
+    pub repo: String,
+    pub number: i64,
+    pub title: String,
+    pub body: Option<String>,
+    /// `"open"` or `"closed"`.
+    #[schemars(with = "IssueState")]
+    pub state: String,
+    pub url: Option<String>,
+    /// Empty until fetched by `refresh_issue_cache`; other fetches only
+    /// carry titles and states.
+    pub comments: Vec<CachedIssueComment>,
+    /// GitHub's last-updated time for the issue, when known.
+    pub updated_at: Option<String>,
+    /// When this copy was fetched.
+    pub fetched_at: String,
+    /// `fetched_at` is older than the staleness threshold.
+    pub stale: bool,
+}
+
+/// A project with its planning item counts, as returned by
+/// `get_projects_with_stats`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectWithStats {
+    #[serde(flatten)]
+    pub project: Project,
+    pub backlog_count: usize,
+    pub todo_count: usize,
+    pub in_progress_count: usize,
+    pub done_count: usize,
+    /// Planning items linked to a GitHub issue.
+    pub linked_issue_count: usize,
+    /// Linked planning items that are not done yet.
+    pub open_linked_issue_count: usize,
+}
+
+// ─── Sync Result ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncResult {
+    /// Projects whose on-disk path differed from the DB record (renamed/moved).
+    pub updated: Vec<Project>,
+    /// Brand-new projects discovered by the scan.
+    pub added: Vec<Project>,
+    /// Number of projects that matched exactly and needed no change.
+    pub unchanged_count: usize,
+    /// Number of DB records archived because their path no longer exists on
+    /// disk, or by the inactivity policy.
+    pub archived_count: usize,
+    /// Every root directory that was walked for this sync.
+    pub scanned_roots: Vec<String>,
+    /// Time spent walking the roots and probing candidate directories.
+    pub scan_duration_ms: u64,
+    /// Wall-clock time for the whole sync, including DB reconciliation.
+    pub total_duration_ms: u64,
+}
+
+/// A project touched by a sync, as recorded in `sync_history`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncHistoryProject {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    /// For archived projects: `"missing_path"` | `"out_of_scan_root"` | `"inactive"`.
+    #[serde(default)]
+    #[schemars(with = "Option<ArchiveReason>")]
+    pub reason: Option<String>,
+}
+
+/// One persisted `sync_projects` run, as returned by `get_sync_history`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncHistoryEntry {
+    pub id: i64,
+    /// RFC 3339 time the sync finished.
+    pub synced_at: String,
+    pub added: Vec<SyncHistoryProject>,
+    pub updated: Vec<SyncHistoryProject>,
+    pub archived: Vec<SyncHistoryProject>,
+    pub unchanged_count: usize,
+    pub scanned_roots: Vec<String>,
+    pub scan_duration_ms: u64,
+    pub total_duration_ms: u64,
+}
+
+/// One row of the event log, as returned by `get_event_log`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EventLogEntry {
+    pub id: i64,
+    /// What produced the entry, e.g. `"maintenance"`.
+    pub kind: String,
+    pub message: String,
+    /// Structured payload, e.g. a serialised `MaintenanceReport`.
+    pub details: Option<serde_json::Value>,
+    pub created_at: String,
+}
+
+/// Outcome of one database maintenance run.  Steps that fail are listed in
+/// `errors`; the others still run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct MaintenanceReport {
+    pub ran_at: String,
+    pub duration_ms: u64,
+    /// WAL frames copied back into the database file.
+    pub wal_frames_checkpointed: i64,
+    /// Compacted copy written with `VACUUM INTO`.
+    pub backup_path: Option<String>,
+    /// History and log rows older than `history_retention_days`.
+    pub pruned_rows: usize,
+    pub sessions_reindexed: usize,
+    pub ledger_sessions_updated: usize,
+    pub errors: Vec<String>,
+}
+
+literal_union!(
+    /// What a group of duplicate projects shares.
+    DuplicateReason = "identity_key" | "path"
+);
+
+/// Project records that point at the same repository.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DuplicateProjectGroup {
+    /// `"identity_key"` or `"path"`.
+    #[schemars(with = "DuplicateReason")]
+    pub reason: String,
+    /// The shared identity key or canonical path.
+    pub key: String,
+    /// Live records first, then oldest first.
+    pub projects: Vec<Project>,
+    /// The record `merge_projects` should keep: the first of `projects`.
+    pub suggested_keep_id: String,
+}
+
+// ─── Settings ──────────────────────────────────────────────────────────────
+
+/// Marker files used to detect a project root when `project_markers` is unset.
+pub const DEFAULT_PROJECT_MARKERS: &[&str] = &["package.json", "Cargo.toml", ".git"];
+
+/// Directories skipped by the project scanner when `scan_exclude_patterns` is unset.
+pub const DEFAULT_SCAN_EXCLUDE_PATTERNS: &[&str] = &["**/node_modules", "**/dist", "**/target"];
+
+/// An additional Claude config root, e.g. a work account installed with
+/// its own `CLAUDE_CONFIG_DIR`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClaudeDirConfig {
+    /// Shown as the `source` of everything read from this root.
+    pub label: String,
+    /// Absolute path of the config directory (the one holding `projects/`).
+    pub path: String,
+}
+
+/// Home screen widgets, in default display order.
+pub const DASHBOARD_WIDGET_IDS: &[&str] = &[
+    "recent_projects",
+    "due_items",
+    "active_agents",
+    "cost",
+    "ci_failures",
+];
+
+literal_union!(
+    DashboardWidgetId = "recent_projects" | "due_items" | "active_agents" | "cost" | "ci_failures"
+);
+
+/// One home screen widget slot; list order is display order.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DashboardWidget {
+    /// One of `DASHBOARD_WIDGET_IDS`.
+    #[schemars(with = "DashboardWidgetId")]
+    pub id: String,
+    pub enabled: bool,
+}
+
+literal_union!(
+    /// Key of a supported editor (see `commands::terminal::EDITORS`).
+    EditorKey =
+        "vscode"
+        | "cursor"
+        | "windsurf"
+        | "zed"
+        | "intellij"
+        | "webstorm"
+        | "pycharm"
+        | "goland"
+        | "rustrover"
+);
+
+literal_union!(UpdateCheckFrequency = "startup" | "daily" | "weekly" | "manual");
+
+literal_union!(
+    /// Priority a notification kind is raised with; `off` silences it.
+    NotificationPriority = "high" | "normal" | "low" | "off"
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AppSettings {
+    /// The first of `scan_paths`, for callers that want one directory such
+    /// as the new-project dialog.  Only used on save when `scan_paths` is
+    /// empty.
+    pub scan_path: Option<String>,
+    /// All project scan roots.  Falls back to `[scan_path]` when unset.
+    #[serde(default)]
+    pub scan_paths: Vec<String>,
+    /// File or directory names that mark a directory as a project root,
+    /// e.g. `pyproject.toml`, `go.mod`, `Gemfile`.
+    #[serde(default = "default_project_markers")]
+    pub project_markers: Vec<String>,
+    /// Glob patterns for directories the scanner skips (e.g. `vendor/**`).
+    #[serde(default = "default_scan_exclude_patterns")]
+    pub scan_exclude_patterns: Vec<String>,
+    /// Absolute directories outside the home directory (external volumes,
+    /// `/opt`, ...) that projects, scans and env files may live under.
+    #[serde(default)]
+    pub allowed_roots: Vec<String>,
+    pub theme: String,
+    pub terminal: String,
+    /// Editor used by `open_in_editor` and `open_file_at`: `"vscode"` |
+    /// `"cursor"` | `"windsurf"` | `"zed"` | `"intellij"` | `"webstorm"` |
+    /// `"pycharm"` | `"goland"` | `"rustrover"`.
+    #[serde(default = "default_editor")]
+    #[schemars(with = "EditorKey")]
+    pub editor: String,
+    pub onboarding_completed: bool,
+    /// When `true`, completing a task that has a linked GitHub issue prompts
+    /// the user to close the issue automatically.
+    pub github_close_prompt: bool,
+    /// How often the background service checks for app updates:
+    /// `"startup"` | `"daily"` | `"weekly"` | `"manual"`.
+    #[serde(default = "default_update_check_frequency")]
+    #[schemars(with = "UpdateCheckFrequency")]
+    pub update_check_frequency: String,
+    /// When `true`, background services skip all network calls.
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// RFC 3339 timestamp of the last completed update check (read-only).
+    #[serde(default)]
+    pub last_update_check: Option<String>,
+    /// Maximum number of concurrent in-app terminals.
+    #[serde(default = "default_pty_max_sessions")]
+    pub pty_max_sessions: usize,
+    /// Per-terminal output rate cap in KiB/s; `0` = unlimited.
+    #[serde(default = "default_pty_max_output_kb_per_sec")]
+    pub pty_max_output_kb_per_sec: u64,
+    /// Total output a terminal may produce before it is killed, in MiB; `0` = unlimited.
+    #[serde(default = "default_pty_max_output_mb")]
+    pub pty_max_output_mb: u64,
+    /// Which status changes the sync engine propagates between linked
+    /// planning items, GitHub issues, and plan checkboxes.
+    #[serde(default)]
+    pub sync_rules: SyncRules,
+    /// When `true`, a newly started Claude session is tailed into a
+    /// read-only terminal viewer automatically.
+    #[serde(default)]
+    pub auto_follow_sessions: bool,
+    /// How often low-priority notifications are coalesced into one digest.
+    #[serde(default = "default_notification_digest_minutes")]
+    pub notification_digest_minutes: u64,
+    /// Per-trigger priority: `"high"` | `"normal"` | `"low"` (digest only) | `"off"`.
+    #[serde(default = "default_notification_priorities")]
+    #[schemars(with = "HashMap<String, NotificationPriority>")]
+    pub notification_priorities: HashMap<String, String>,
+    /// Sync archives projects with no commits, sessions, planning changes or
+    /// opens in this many days; `0` = never.
+    #[serde(default)]
+    pub auto_archive_inactive_days: u32,
+    /// Claude config roots read alongside the primary one; tasks, plans and
+    /// sessions from all roots are merged and tagged with their `source`.
+    #[serde(default)]
+    pub extra_claude_dirs: Vec<ClaudeDirConfig>,
+    /// Monthly estimated agent spend in USD that triggers `budget`
+    /// notifications at 50%, 80% and 100%; `0` = no budget.
+    #[serde(default)]
+    pub monthly_budget_usd: f64,
+    /// The same for the current week, Monday to Sunday.
+    #[serde(default)]
+    pub weekly_budget_usd: f64,
+    /// Home screen layout; `get_dashboard_data` computes the enabled ones
+    /// when called without explicit ids.
+    #[serde(default = "default_dashboard_widgets")]
+    pub dashboard_widgets: Vec<DashboardWidget>,
+    /// Weekly maintenance deletes sync history, event log entries and
+    /// resolved suggestions older than this many days; `0` = keep forever.
+    #[serde(default = "default_history_retention_days")]
+    pub history_retention_days: u32,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_pty_max_sessions() -> usize {
+    8
+}
+
+fn default_pty_max_output_kb_per_sec() -> u64 {
+    2048
+}
+
+fn default_pty_max_output_mb() -> u64 {
+    512
+}
+
+fn default_project_markers() -> Vec<String> {
+    DEFAULT_PROJECT_MARKERS.iter().map(|m| m.to_string()).collect()
+}
+
+fn default_scan_exclude_patterns() -> Vec<String> {
+    DEFAULT_SCAN_EXCLUDE_PATTERNS.iter().map(|p| p.to_string()).collect()
+}
+
+pub const DEFAULT_NOTIFICATION_DIGEST_MINUTES: u64 = 15;
+
+fn default_notification_digest_minutes() -> u64 {
+    DEFAULT_NOTIFICATION_DIGEST_MINUTES
+}
+
+/// Priorities for the built-in notification triggers.
+pub fn default_notification_priorities() -> HashMap<String, String> {
+    [
+        ("update_available", "high"),
+        ("session_started", "normal"),
+        ("session_ended", "low"),
+        ("status_sync", "low"),
+        ("budget", "high"),
+    ]
+    .into_iter()
+    .map(|(trigger, priority)| (trigger.to_string(), priority.to_string()))
+    .collect()
+}
+
+/// Every known widget, enabled, in `DASHBOARD_WIDGET_IDS` order.
+pub fn default_dashboard_widgets() -> Vec<DashboardWidget> {
+    DASHBOARD_WIDGET_IDS
+        .iter()
+        .map(|id| DashboardWidget {
+            id: id.to_string(),
+            enabled: true,
+        })
+        .collect()
+}
+
+fn default_history_retention_days() -> u32 {
+    90
+}
+
+fn default_editor() -> String {
+    "vscode".to_string()
+}
+
+fn default_update_check_frequency() -> String {
+    "daily".to_string()
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            scan_path: dirs::home_dir()
+                .map(|h| h.join("cv").to_string_lossy().to_string()),
+            scan_paths: vec![],
+            project_markers: default_project_markers(),
+            scan_exclude_patterns: default_scan_exclude_patterns(),
+            allowed_roots: Vec::new(),
+            theme: "system".to_string(),
+            terminal: "auto".to_string(),
+            editor: default_editor(),
+            onboarding_completed: false,
+            github_close_prompt: true,
+            update_check_frequency: default_update_check_frequency(),
+            offline_mode: false,
+            last_update_check: None,
+            pty_max_sessions: default_pty_max_sessions(),
+            pty_max_output_kb_per_sec: default_pty_max_output_kb_per_sec(),
+            pty_max_output_mb: default_pty_max_output_mb(),
+            sync_rules: SyncRules::default(),
+            auto_follow_sessions: false,
+            notification_digest_minutes: default_notification_digest_minutes(),
+            notification_priorities: default_notification_priorities(),
+            auto_archive_inactive_days: 0,
+            extra_claude_dirs: Vec::new(),
+            monthly_budget_usd: 0.0,
+            weekly_budget_usd: 0.0,
+            dashboard_widgets: default_dashboard_widgets(),
+            history_retention_days: default_history_retention_days(),
+        }
+    }
+}
+
+/// Return value of `seed_demo_data`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DemoSeedResult {
+    /// Scratch directory holding the demo projects and Claude dir.
+    pub root: String,
+    /// Directory now used in place of `~/.claude`.
+    pub claude_dir: String,
+    pub projects: usize,
+    pub planning_items: usize,
+    pub sessions: usize,
+    pub tasks: usize,
+}
+
+// ─── Backups ───────────────────────────────────────────────────────────────
+
+/// A pre-operation snapshot in `~/.claude-commander/backups`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub created_at: String,
+    /// The operation that triggered it, e.g. `"reset_all_projects"`.
+    pub reason: String,
+    pub row_counts: HashMap<String, usize>,
+}
+
+/// Return value of destructive commands that snapshot first.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DestructiveOpResult {
+    /// Rows deleted.
+    pub count: usize,
+    /// Pass to `restore_snapshot` to undo.
+    pub snapshot_id: String,
+}
+
+// ─── Presentation ──────────────────────────────────────────────────────────
+
+/// A running read-only board view served on the LAN.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PresentationServerInfo {
+    pub project_id: String,
+    pub port: u16,
+    /// Required as `?token=` on every request.
+    pub token: String,
+    /// Ready-to-open URL, token included, using the machine's LAN address.
+    pub url: String,
+    pub started_at: String,
+}
+
+// ─── Notifications ─────────────────────────────────────────────────────────
+
+literal_union!(
+    /// Priority of a raised notification (a `NotificationPriority` other than `off`).
+    NotificationLevel = "high" | "normal" | "low"
+);
+
+/// Payload of the `notification` event.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AppNotification {
+    /// What raised it, e.g. `"status_sync"`; `"digest"` for a coalesced batch.
+    pub trigger: String,
+    /// `"high"` | `"normal"` | `"low"`
+    #[schemars(with = "NotificationLevel")]
+    pub priority: String,
+    pub title: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+// ─── Status Sync ───────────────────────────────────────────────────────────
+
+/// Propagation rules for the status sync engine.  The individual rules apply
+/// to both manual and background runs; `enabled` only controls the
+/// background service.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncRules {
+    /// Run the engine in the background every `interval_minutes`.
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_sync_interval_minutes")]
+    pub interval_minutes: u64,
+    /// Planning item moved to done → close its GitHub issue.
+    #[serde(default = "default_true")]
+    pub item_done_closes_issue: bool,
+    /// GitHub issue closed externally → move its planning item to done.
+    #[serde(default = "default_true")]
+    pub issue_closed_marks_item_done: bool,
+    /// Claude task completed → check off the matching `- [ ]` line in plans.
+    #[serde(default = "default_true")]
+    pub task_completed_checks_plan: bool,
+}
+
+fn default_sync_interval_minutes() -> u64 {
+    15
+}
+
+impl Default for SyncRules {
+    fn default() -> Self {
+        SyncRules {
+            enabled: false,
+            interval_minutes: default_sync_interval_minutes(),
+            item_done_closes_issue: true,
+            issue_closed_marks_item_done: true,
+            task_completed_checks_plan: true,
+        }
+    }
+}
+
+literal_union!(
+    /// Rule of the status sync engine (see `SyncRules`).
+    SyncRule =
+        "item_done_closes_issue"
+        | "issue_closed_marks_item_done"
+        | "task_completed_checks_plan"
+);
+
+/// One change the sync engine would make (dry run) or made.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StatusSyncAction {
+    /// `"item_done_closes_issue"` | `"issue_closed_marks_item_done"` |
+    /// `"task_completed_checks_plan"`
+    #[schemars(with = "SyncRule")]
+    pub rule: String,
+    pub description: String,
+    pub planning_item_id: Option<String>,
+    pub github_repo: Option<String>,
+    pub github_issue_number: Option<i64>,
+    pub plan_filename: Option<String>,
+    /// Claude root the plan lives in (see `ClaudePlan::source`).
+    #[serde(default)]
+    pub plan_source: Option<String>,
+    /// Checkbox text matched against a completed task's subject
+    pub task_subject: Option<String>,
+    pub applied: bool,
+    pub error: Option<String>,
+}
+
+// ─── Terminals ─────────────────────────────────────────────────────────────
+
+/// One live terminal, as listed by `pty_list` and sent with `pty-created`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PtyInfo {
+    pub pty_id: String,
+    pub project_path: String,
+    pub program: String,
+    pub created_at: String,
+    pub last_activity: String,
+    pub env_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PtySessionUsage {
+    pub pty_id: String,
+    pub created_at: String,
+    pub bytes_emitted: u64,
+    pub throttle_events: u64,
+    /// Env file injected into the child environment, if any.
+    pub env_file: Option<String>,
+    /// Names (never values) of the variables injected from `env_file`.
+    pub env_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PtyResourceUsage {
+    pub sessions: Vec<PtySessionUsage>,
+    pub limits: PtyLimits,
+}
+
+/// Resource limits applied to in-app terminals.  `0` disables a byte limit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+pub struct PtyLimits {
+    pub max_sessions: usize,
+    pub max_output_kb_per_sec: u64,
+    pub max_output_mb: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TerminalInfo {
+    pub detected: String,
+    pub available: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EditorInfo {
+    /// The `editor` setting if installed, else the first installed editor.
+    #[schemars(with = "Option<EditorKey>")]
+    pub detected: Option<String>,
+    /// Keys of installed editors, in `EDITORS` order.
+    #[schemars(with = "Vec<EditorKey>")]
+    pub available: Vec<String>,
+}
+
+// ─── Updates ───────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateInfo {
+    pub available: bool,
+    pub version: Option<String>,
+    pub body: Option<String>,
+}
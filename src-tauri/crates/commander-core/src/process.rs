@@ -0,0 +1,78 @@
+//! Recognising running Claude Code CLI processes in `ps` output.
+
+use crate::models::ClaudeProcess;
+
+/// `<pid> <lstart: 5 fields, e.g. "Fri Oct 16 09:41:07 2026"> <command>`.
+pub fn parse_ps_line(line: &str) -> Option<ClaudeProcess> {
+    let mut fields = line.split_whitespace();
+    let pid = fields.next()?.parse().ok()?;
+    let lstart = fields.by_ref().take(5).collect::<Vec<_>>().join(" ");
+    let command = fields.collect::<Vec<_>>().join(" ");
+    let started_at = chrono::NaiveDateTime::parse_from_str(&lstart, "%a %b %d %H:%M:%S %Y")
+        .ok()
+        .and_then(|t| t.and_local_timezone(chrono::Local).single())
+        .map(|t| t.with_timezone(&chrono::Utc).to_rfc3339());
+    Some(ClaudeProcess {
+        pid,
+        cwd: None,
+        project_id: None,
+        started_at,
+        command,
+    })
+}
+
+/// The CLI runs as a `claude` binary, or as node on the npm package's
+/// `cli.js`.
+pub fn is_claude_cli(command: &str) -> bool {
+    let mut args = command.split_whitespace();
+    let program = args.next().unwrap_or_default();
+    let name = program.rsplit('/').next().unwrap_or(program);
+    name == "claude"
+        || (name == "node"
+            && args
+                .next()
+                .is_some_and(|script| script.contains("@anthropic-ai/claude-code")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pid_start_time_and_command() {
+        let line = "  4242 Fri Oct 16 09:41:07 2026     /usr/local/bin/claude --resume  abc";
+        let process = parse_ps_line(line).unwrap();
+        assert_eq!(process.pid, 4242);
+        assert_eq!(process.command, "/usr/local/bin/claude --resume abc");
+        let started = process.started_at.unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&started).is_ok());
+        assert!(process.cwd.is_none() && process.project_id.is_none());
+    }
+
+    #[test]
+    fn keeps_the_process_when_the_start_time_is_unreadable() {
+        let process = parse_ps_line("7 someday claude").unwrap();
+        assert_eq!(process.pid, 7);
+        assert!(process.started_at.is_none());
+    }
+
+    #[test]
+    fn rejects_lines_without_a_pid() {
+        assert!(parse_ps_line("").is_none());
+        assert!(parse_ps_line("PID STARTED COMMAND").is_none());
+    }
+
+    #[test]
+    fn recognises_the_cli() {
+        assert!(is_claude_cli("claude"));
+        assert!(is_claude_cli("/opt/homebrew/bin/claude --continue"));
+        assert!(is_claude_cli(
+            "node /usr/lib/node_modules/@anthropic-ai/claude-code/cli.js"
+        ));
+        assert!(!is_claude_cli("node server.js"));
+        assert!(!is_claude_cli(
+            "/Applications/Claude.app/Contents/MacOS/Claude"
+        ));
+        assert!(!is_claude_cli("vim claude.md"));
+    }
+}
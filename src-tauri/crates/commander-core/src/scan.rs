@@ -0,0 +1,397 @@
+//! Filesystem side of project sync: walking the scan roots, probing each
+//! candidate directory and deriving the identity key that lets a project be
+//! recognised after it is renamed or moved.
+
+use crate::models::Project;
+use crate::stack::detect_stack;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+// ─── Identity key helpers ───────────────────────────────────────────────────
+
+/// Derive a stable identity key for a project directory that survives renames
+/// and relocations.
+///
+/// Strategy 1 – git remote origin URL (normalised, prefixed with `git:`).
+/// Strategy 2 – UUID stamp written to `.claude-commander-id` in the project
+///              root (created on first scan if no git remote is found).
+pub fn compute_identity_key(path: &Path) -> String {
+    if let Some(key) = git_remote_identity(path) {
+        return key;
+    }
+    uuid_stamp_identity(path)
+}
+
+/// Read the `origin` remote URL from the git repository at `path`, normalise
+/// it, and return `"git:<url>"`.  Returns `None` when the directory is not a
+/// git repo or has no `origin` remote.
+///
+/// Linked worktrees share their main checkout's remote, so their key gets a
+/// `#worktree:<name>` suffix to keep the two apart.
+pub fn git_remote_identity(path: &Path) -> Option<String> {
+    let repo = git2::Repository::open(path).ok()?;
+    let remote = repo.find_remote("origin").ok()?;
+    let url = remote.url()?.trim().to_string();
+    // Normalise: strip trailing slash and optional `.git` suffix so that
+    // `https://github.com/foo/bar` and `https://github.com/foo/bar.git` map
+    // to the same key.
+    let normalised = url
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .to_string();
+    if repo.is_worktree() {
+        // `repo.path()` is `<main>/.git/worktrees/<name>/`.
+        let name = repo.path().file_name()?.to_string_lossy().to_string();
+        return Some(format!("git:{}#worktree:{}", normalised, name));
+    }
+    Some(format!("git:{}", normalised))
+}
+
+/// Canonical path of the main working tree when `path` is a linked git
+/// worktree; `None` for ordinary checkouts and non-repos.
+pub fn worktree_main_path(path: &Path) -> Option<PathBuf> {
+    // Only linked worktrees have a `.git` *file*; skip opening anything else.
+    if !path.join(".git").is_file() {
+        return None;
+    }
+    let repo = git2::Repository::open(path).ok()?;
+    if !repo.is_worktree() {
+        return None;
+    }
+    // `repo.path()` is `<main>/.git/worktrees/<name>/`.
+    let main = repo.path().ancestors().nth(3)?;
+    std::fs::canonicalize(main).ok()
+}
+
+/// Read a UUID from the `.claude-commander-id` stamp file inside `path`,
+/// creating and writing one if the file does not yet exist.  Returns
+/// `"stamp:<uuid>"`.
+fn uuid_stamp_identity(path: &Path) -> String {
+    let stamp_file = path.join(".claude-commander-id");
+    if let Ok(content) = std::fs::read_to_string(&stamp_file) {
+        let trimmed = content.trim().to_string();
+        if !trimmed.is_empty() {
+            return format!("stamp:{}", trimmed);
+        }
+    }
+    let id = Uuid::new_v4().to_string();
+    // Best-effort write – if the directory is read-only we silently skip.
+    let _ = std::fs::write(&stamp_file, &id);
+    format!("stamp:{}", id)
+}
+
+// ─── Icon detection ─────────────────────────────────────────────────────────
+
+/// Best-effort framework detection from well-known config files, returning an
+/// icon key the frontend maps to a logo.  Most specific framework wins.
+fn detect_project_icon(path: &Path) -> Option<String> {
+    let has = |name: &str| path.join(name).exists();
+    let has_prefix = |prefix: &str| {
+        std::fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .any(|e| e.file_name().to_string_lossy().starts_with(prefix))
+            })
+            .unwrap_or(false)
+    };
+
+    let key = if has("src-tauri/tauri.conf.json") {
+        "tauri"
+    } else if has_prefix("next.config.") {
+        "nextjs"
+    } else if has_prefix("astro.config.") {
+        "astro"
+    } else if has_prefix("svelte.config.") {
+        "svelte"
+    } else if has_prefix("nuxt.config.") {
+        "nuxt"
+    } else if has_prefix("vite.config.") {
+        "vite"
+    } else if has("Cargo.toml") {
+        "rust"
+    } else if has("go.mod") {
+        "go"
+    } else if has("pyproject.toml") || has("requirements.txt") {
+        "python"
+    } else if has("package.json") {
+        "node"
+    } else {
+        return None;
+    };
+    Some(key.to_string())
+}
+
+// ─── Scanning ───────────────────────────────────────────────────────────────
+
+/// A directory is excluded when a pattern matches its path relative to the
+/// scan root (`vendor/**`, `**/fixtures`) or its bare name (`dist`).
+fn is_excluded(path: &Path, base: &Path, excludes: &[glob::Pattern]) -> bool {
+    let rel = path.strip_prefix(base).unwrap_or(path);
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    excludes
+        .iter()
+        .any(|p| p.matches_path(rel) || p.matches(name))
+}
+
+/// Everything the filesystem walk needs, resolved up front so the DB lock is
+/// not held while scanning.
+pub struct ScanOptions {
+    pub roots: Vec<PathBuf>,
+    pub markers: Vec<String>,
+    pub excludes: Vec<glob::Pattern>,
+}
+
+/// Upper bound on threads probing candidate directories during a scan.
+const MAX_SCAN_WORKERS: usize = 8;
+
+/// Walk every root (depth ≤ 2) and build a `Project` for each candidate
+/// directory.  The walk itself is cheap and stays serial; the per-candidate
+/// probing (git remote lookup, stack and icon detection) is spread across a
+/// small pool of scoped threads.
+pub fn scan_roots(opts: &ScanOptions) -> Vec<Project> {
+    let candidates = collect_candidates(opts);
+    if candidates.is_empty() {
+        return vec![];
+    }
+
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .clamp(1, MAX_SCAN_WORKERS)
+        .min(candidates.len());
+    let next = AtomicUsize::new(0);
+
+    let mut projects: Vec<Project> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut probed = Vec::new();
+                    // Pull candidates off a shared index so one slow repo
+                    // doesn't hold up a whole pre-assigned chunk.
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(candidate) = candidates.get(i) else {
+                            break;
+                        };
+                        probed.push(probe_project(candidate));
+                    }
+                    probed
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|h| {
+                h.join().unwrap_or_else(|_| {
+                    log::warn!("project scan worker panicked; its results were dropped");
+                    vec![]
+                })
+            })
+            .collect()
+    });
+
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+    projects
+}
+
+/// A directory containing a project marker, as reached by the walk and as
+/// it resolves on disk.
+struct Candidate {
+    path: PathBuf,
+    canonical: PathBuf,
+}
+
+impl Candidate {
+    fn is_symlinked(&self) -> bool {
+        self.path != self.canonical
+    }
+}
+
+/// Directories under the scan roots that contain a project marker.
+///
+/// Symlinked directories are followed, and candidates are de-duplicated on
+/// their canonical path so a repo reachable both directly and through a link
+/// (or from overlapping roots) is reported once — preferring the direct path.
+fn collect_candidates(opts: &ScanOptions) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut by_canonical: HashMap<PathBuf, usize> = HashMap::new();
+
+    for base in opts.roots.iter().filter(|b| b.exists()) {
+        for entry in WalkDir::new(base)
+            .min_depth(1)
+            .max_depth(2)
+            .follow_links(true)
+            .into_iter()
+            // Prune excluded directories so their children are never visited.
+            .filter_entry(|e| e.depth() == 0 || !is_excluded(e.path(), base, &opts.excludes))
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_dir())
+        {
+            let path = entry.path();
+
+            if !opts.markers.iter().any(|m| path.join(m).exists()) {
+                continue;
+            }
+
+            let path_str = path.to_string_lossy();
+            if path_str.contains("/.git") || path_str.contains("/.cargo") {
+                continue;
+            }
+
+            let Ok(canonical) = std::fs::canonicalize(path) else {
+                continue;
+            };
+            let candidate = Candidate {
+                path: path.to_path_buf(),
+                canonical,
+            };
+
+            match by_canonical.get(&candidate.canonical) {
+                Some(&i) => {
+                    if candidates[i].is_symlinked() && !candidate.is_symlinked() {
+                        candidates[i] = candidate;
+                    }
+                }
+                None => {
+                    by_canonical.insert(candidate.canonical.clone(), candidates.len());
+                    candidates.push(candidate);
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Build the scanned `Project` for one candidate directory.
+fn probe_project(candidate: &Candidate) -> Project {
+    let path = candidate.path.as_path();
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let identity_key = Some(compute_identity_key(path));
+    let stack = detect_stack(path);
+
+    Project {
+        id: Uuid::new_v4().to_string(), // placeholder; real ID assigned on upsert
+        name,
+        path: path.to_string_lossy().to_string(),
+        tags: vec![],
+        color: None,
+        sort_order: 0,
+        is_archived: false,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        identity_key,
+        icon: detect_project_icon(path),
+        group_id: None,
+        language: stack.language,
+        framework: stack.framework,
+        package_manager: stack.package_manager,
+        last_opened_at: None,
+        real_path: candidate
+            .is_symlinked()
+            .then(|| candidate.canonical.to_string_lossy().to_string()),
+        worktree_of: None,
+        archived_reason: None,
+        archived_at: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEFAULT_PROJECT_MARKERS;
+
+    fn patterns(globs: &[&str]) -> Vec<glob::Pattern> {
+        globs
+            .iter()
+            .map(|g| glob::Pattern::new(g).unwrap())
+            .collect()
+    }
+
+    fn touch(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+
+    fn found(opts: &ScanOptions, base: &Path) -> Vec<String> {
+        let mut names: Vec<String> = collect_candidates(opts)
+            .into_iter()
+            .map(|c| {
+                c.path
+                    .strip_prefix(base)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn excludes_match_relative_paths_and_bare_names() {
+        let base = Path::new("/code");
+        let excludes = patterns(&["vendor/**", "**/fixtures", "dist"]);
+        assert!(is_excluded(Path::new("/code/vendor/lib"), base, &excludes));
+        assert!(is_excluded(
+            Path::new("/code/app/fixtures"),
+            base,
+            &excludes
+        ));
+        assert!(is_excluded(Path::new("/code/app/dist"), base, &excludes));
+        assert!(!is_excluded(Path::new("/code/app"), base, &excludes));
+        assert!(!is_excluded(Path::new("/code/distro"), base, &excludes));
+    }
+
+    #[test]
+    fn finds_directories_with_a_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().to_path_buf();
+        touch(&base.join("web/package.json"));
+        touch(&base.join("tool/Cargo.toml"));
+        touch(&base.join("group/api/go.mod"));
+        touch(&base.join("notes/readme.md"));
+
+        let opts = ScanOptions {
+            roots: vec![base.clone()],
+            markers: DEFAULT_PROJECT_MARKERS
+                .iter()
+                .map(|m| m.to_string())
+                .collect(),
+            excludes: vec![],
+        };
+        assert_eq!(found(&opts, &base), ["tool", "web"]);
+
+        let opts = ScanOptions {
+            markers: vec!["go.mod".to_string()],
+            ..opts
+        };
+        assert_eq!(found(&opts, &base), ["group/api"]);
+    }
+
+    #[test]
+    fn excluded_directories_are_not_descended_into() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().to_path_buf();
+        touch(&base.join("app/package.json"));
+        touch(&base.join("node_modules/package.json"));
+        touch(&base.join("archive/old/package.json"));
+        touch(&base.join("archive/keep.txt"));
+
+        let opts = ScanOptions {
+            roots: vec![base.clone()],
+            markers: vec!["package.json".to_string()],
+            excludes: patterns(&["**/node_modules", "archive"]),
+        };
+        assert_eq!(found(&opts, &base), ["app"]);
+    }
+}
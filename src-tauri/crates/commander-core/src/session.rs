@@ -0,0 +1,609 @@
+//! Reading Claude Code's on-disk state: where it lives, session transcripts
+//! (`projects/<key>/<session>.jsonl`) and which project a session belongs to.
+
+use crate::error::CommanderError;
+use crate::models::{
+    ClaudeDirConfig, Project, SessionAttachment, SessionDetail, SessionToolCall, SessionTurn,
+};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Replaces `~/.claude` while demo data is seeded.
+static CLAUDE_DIR_OVERRIDE: parking_lot::RwLock<Option<PathBuf>> = parking_lot::RwLock::new(None);
+
+/// The Claude config directory: the seeded demo dir if any, else
+/// `$CLAUDE_CONFIG_DIR` (honoured by Claude Code itself), else `~/.claude`.
+pub fn claude_dir() -> PathBuf {
+    if let Some(dir) = CLAUDE_DIR_OVERRIDE.read().clone() {
+        return dir;
+    }
+    if let Some(dir) = std::env::var_os("CLAUDE_CONFIG_DIR").filter(|d| !d.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join(".claude")
+}
+
+/// Read Claude state from `dir` instead of `~/.claude` for the rest of the
+/// process.
+pub fn set_claude_dir_override(dir: PathBuf) {
+    *CLAUDE_DIR_OVERRIDE.write() = Some(dir);
+}
+
+/// Go back to reading the real Claude config directory.
+pub fn clear_claude_dir_override() {
+    *CLAUDE_DIR_OVERRIDE.write() = None;
+}
+
+/// Source label of the primary root returned by `claude_dir()`.
+pub const DEFAULT_CLAUDE_SOURCE: &str = "default";
+
+/// Additional config roots from the `extra_claude_dirs` setting.
+static EXTRA_CLAUDE_DIRS: parking_lot::RwLock<Vec<ClaudeDirConfig>> =
+    parking_lot::RwLock::new(Vec::new());
+
+/// Replace the additional config roots (e.g. a second account's
+/// `CLAUDE_CONFIG_DIR`) that listings read alongside `claude_dir()`.
+pub fn set_extra_claude_dirs(dirs: Vec<ClaudeDirConfig>) {
+    *EXTRA_CLAUDE_DIRS.write() = dirs;
+}
+
+/// Every configured config root as `(source, dir)`, primary first.  Extra
+/// roots are ignored while demo data is seeded, and a root equal to one
+/// already listed is skipped so nothing is read twice.
+pub fn claude_roots() -> Vec<(String, PathBuf)> {
+    let mut roots = vec![(DEFAULT_CLAUDE_SOURCE.to_string(), claude_dir())];
+    if CLAUDE_DIR_OVERRIDE.read().is_some() {
+        return roots;
+    }
+    for extra in EXTRA_CLAUDE_DIRS.read().iter() {
+        let dir = PathBuf::from(&extra.path);
+        if roots.iter().all(|(_, known)| *known != dir) {
+            roots.push((extra.label.clone(), dir));
+        }
+    }
+    roots
+}
+
+/// Source labels of project-local roots are this prefix plus the project id.
+pub const PROJECT_SOURCE_PREFIX: &str = "project:";
+
+/// `<project>/.claude` directories that keep tasks or plans in the repo, as
+/// `(source, dir)`.
+static PROJECT_CLAUDE_DIRS: parking_lot::RwLock<Vec<(String, PathBuf)>> =
+    parking_lot::RwLock::new(Vec::new());
+
+/// Re-scan `projects` for an in-repo `.claude/tasks` or `.claude/plans`.
+/// Returns whether the set of project-local roots changed.
+pub fn set_project_claude_dirs(projects: &[Project]) -> bool {
+    let dirs: Vec<(String, PathBuf)> = projects
+        .iter()
+        .filter(|p| !p.is_archived)
+        .filter_map(|p| {
+            let dir = Path::new(&p.path).join(".claude");
+            (dir.join("tasks").is_dir() || dir.join("plans").is_dir())
+                .then(|| (format!("{PROJECT_SOURCE_PREFIX}{}", p.id), dir))
+        })
+        .collect();
+    let mut current = PROJECT_CLAUDE_DIRS.write();
+    if *current == dirs {
+        return false;
+    }
+    *current = dirs;
+    true
+}
+
+/// Roots tasks and plans are read from: `claude_roots()` followed by the
+/// project-local `.claude` directories.  Sessions, todos and history only
+/// live in the former.
+pub fn agent_state_roots() -> Vec<(String, PathBuf)> {
+    let mut roots = claude_roots();
+    if CLAUDE_DIR_OVERRIDE.read().is_some() {
+        return roots;
+    }
+    for (source, dir) in PROJECT_CLAUDE_DIRS.read().iter() {
+        if roots.iter().all(|(_, known)| known != dir) {
+            roots.push((source.clone(), dir.clone()));
+        }
+    }
+    roots
+}
+
+/// The root labelled `source`, project-local ones included; `None` or an
+/// unknown label means the primary root.
+pub fn claude_root(source: Option<&str>) -> PathBuf {
+    source
+        .and_then(|source| {
+            agent_state_roots()
+                .into_iter()
+                .find(|(label, _)| label == source)
+                .map(|(_, dir)| dir)
+        })
+        .unwrap_or_else(claude_dir)
+}
+
+/// `projects/` directories of every root that has one.
+fn session_dirs() -> Vec<PathBuf> {
+    claude_roots()
+        .into_iter()
+        .map(|(_, dir)| dir.join("projects"))
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
+
+// ─── Sessions ──────────────────────────────────────────────────────────────
+
+/// Parse up to `max_turns` lines of a session file into typed turns.
+pub fn load_session_detail(path: &Path, max_turns: usize) -> Result<SessionDetail, CommanderError> {
+    use std::io::BufRead;
+    let file = std::fs::File::open(path)?;
+
+    let lines: Vec<String> = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+
+    let total_count = lines.len();
+
+    let lines = &lines[..total_count.min(max_turns)];
+    let mut turns: Vec<SessionTurn> = Vec::new();
+    let mut tool_attachments: HashMap<String, Vec<SessionAttachment>> = HashMap::new();
+    for line in lines {
+        let mut turn = parse_session_turn(line);
+        // Cheap pre-check: every attachment block has a `source`.
+        if line.contains("\"source\"") {
+            let mut own = Vec::new();
+            for attachment in extract_attachments(line) {
+                match attachment.tool_use_id.clone() {
+                    Some(id) => tool_attachments.entry(id).or_default().push(attachment),
+                    None => own.push(attachment),
+                }
+            }
+            if let Some(turn) = turn.as_mut().filter(|_| !own.is_empty()) {
+                turn.attachments = own;
+            }
+        }
+        turns.extend(turn);
+    }
+    for turn in &mut turns {
+        for call in &turn.tool_calls {
+            if let Some(found) = tool_attachments.remove(&call.id) {
+                turn.attachments.extend(found);
+            }
+        }
+    }
+    let results: HashMap<String, (String, bool)> = lines
+        .iter()
+        .flat_map(|line| parse_tool_results(line))
+        .map(|(id, output, is_error)| (id, (output, is_error)))
+        .collect();
+    for call in turns.iter_mut().flat_map(|t| t.tool_calls.iter_mut()) {
+        if let Some((output, is_error)) = results.get(&call.id) {
+            call.output = Some(output.clone());
+            call.is_error = *is_error;
+        }
+    }
+
+    Ok(SessionDetail { turns, total_count })
+}
+
+/// Longest tool output kept per call; file reads and test logs can be
+/// megabytes.
+const MAX_TOOL_OUTPUT_CHARS: usize = 20_000;
+
+/// `(tool_use_id, output, is_error)` for each `tool_result` block on a user
+/// line.  Claude writes these on the line after the assistant's `tool_use`.
+pub fn parse_tool_results(line: &str) -> Vec<(String, String, bool)> {
+    let Ok(v) = serde_json::from_str::<serde_json::Value>(line) else {
+        return vec![];
+    };
+    if v["type"].as_str() != Some("user") {
+        return vec![];
+    }
+    let Some(blocks) = v["message"]["content"].as_array() else {
+        return vec![];
+    };
+    blocks
+        .iter()
+        .filter(|b| b["type"].as_str() == Some("tool_result"))
+        .filter_map(|b| {
+            let id = b["tool_use_id"].as_str()?.to_string();
+            // Content is a plain string or a list of text/image blocks.
+            let text = match &b["content"] {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Array(parts) => parts
+                    .iter()
+                    .filter_map(|p| p["text"].as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                _ => String::new(),
+            };
+            let mut output: String = text.chars().take(MAX_TOOL_OUTPUT_CHARS).collect();
+            if output.len() < text.len() {
+                output.push_str("\n… (truncated)");
+            }
+            Some((id, output, b["is_error"].as_bool().unwrap_or(false)))
+        })
+        .collect()
+}
+
+/// Where base64 attachments are written when a transcript is opened.  Files
+/// are named by a hash of their content, so reopening reuses them.
+pub fn attachments_dir() -> PathBuf {
+    std::env::temp_dir()
+        .join("claude-commander")
+        .join("attachments")
+}
+
+/// Image and document blocks of a message, with the id of the tool call
+/// when one is nested in a `tool_result`.
+fn attachment_blocks(content: &Value) -> Vec<(Option<&str>, &Value)> {
+    let is_attachment = |b: &Value| matches!(b["type"].as_str(), Some("image" | "document"));
+    let Some(blocks) = content.as_array() else {
+        return vec![];
+    };
+    let mut found = Vec::new();
+    for block in blocks {
+        if is_attachment(block) {
+            found.push((None, block));
+        } else if block["type"].as_str() == Some("tool_result") {
+            let id = block["tool_use_id"].as_str();
+            if let Some(parts) = block["content"].as_array() {
+                found.extend(parts.iter().filter(|p| is_attachment(p)).map(|p| (id, p)));
+            }
+        }
+    }
+    found
+}
+
+fn to_attachment(block: &Value, tool_use_id: Option<&str>) -> SessionAttachment {
+    let source = &block["source"];
+    SessionAttachment {
+        kind: block["type"].as_str().unwrap_or("image").to_string(),
+        media_type: source["media_type"].as_str().map(String::from),
+        path: None,
+        url: source["url"].as_str().map(String::from),
+        tool_use_id: tool_use_id.map(String::from),
+    }
+}
+
+/// Attachments on one transcript line, with base64 payloads written to
+/// `attachments_dir`.  A payload that fails to decode or write is still
+/// listed, without a path.
+fn extract_attachments(line: &str) -> Vec<SessionAttachment> {
+    let Ok(v) = serde_json::from_str::<Value>(line) else {
+        return vec![];
+    };
+    attachment_blocks(&v["message"]["content"])
+        .into_iter()
+        .map(|(tool_use_id, block)| {
+            let mut attachment = to_attachment(block, tool_use_id);
+            if block["source"]["type"].as_str() == Some("base64") {
+                match write_attachment(&block["source"]) {
+                    Ok(path) => attachment.path = Some(path.to_string_lossy().to_string()),
+                    Err(e) => log::warn!("failed to extract session attachment: {}", e),
+                }
+            }
+            attachment
+        })
+        .collect()
+}
+
+fn write_attachment(source: &Value) -> Result<PathBuf, CommanderError> {
+    use base64::Engine;
+    use std::hash::{Hash, Hasher};
+    let data = source["data"].as_str().unwrap_or_default();
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    data.hash(&mut hasher);
+    let extension = match source["media_type"].as_str().unwrap_or_default() {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "application/pdf" => "pdf",
+        _ => "bin",
+    };
+    let dir = attachments_dir();
+    let path = dir.join(format!("{:016x}.{extension}", hasher.finish()));
+    if !path.exists() {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| CommanderError::parse(e.to_string()))?;
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(&path, bytes)?;
+    }
+    Ok(path)
+}
+
+/// An extracted attachment as a `data:` URL the webview can display.  Only
+/// files inside `attachments_dir` are served.
+pub fn attachment_data_url(path: &Path) -> Result<String, CommanderError> {
+    use base64::Engine;
+    let canonical = path.canonicalize()?;
+    let dir = attachments_dir().canonicalize()?;
+    if !canonical.starts_with(&dir) {
+        return Err(CommanderError::internal(
+            "Not an extracted session attachment",
+        ));
+    }
+    let media_type = match canonical.extension().and_then(|e| e.to_str()) {
+        Some("png") => "image/png",
+        Some("jpg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("pdf") => "application/pdf",
+        _ => "application/octet-stream",
+    };
+    let bytes = std::fs::read(&canonical)?;
+    Ok(format!(
+        "data:{media_type};base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    ))
+}
+
+/// Locate `<session_id>.jsonl` under any project directory of any configured
+/// Claude root.
+pub fn find_session_file(session_id: &str) -> Result<PathBuf, CommanderError> {
+    // Session ids are UUIDs; anything else could escape the projects directory.
+    if session_id.is_empty()
+        || !session_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(CommanderError::internal(format!(
+            "Invalid session id: {}",
+            session_id
+        )));
+    }
+
+    let file_name = format!("{}.jsonl", session_id);
+    session_dirs()
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.filter_map(|e| e.ok()))
+        .map(|e| e.path().join(&file_name))
+        .find(|p| p.is_file())
+        .ok_or_else(|| CommanderError::FileNotFound {
+            path: file_name.clone(),
+        })
+}
+
+pub fn parse_session_turn(line: &str) -> Option<SessionTurn> {
+    let v: serde_json::Value = serde_json::from_str(line).ok()?;
+
+    let msg_type = v["type"].as_str()?;
+    // Only process "user" and "assistant" message types
+    if msg_type != "user" && msg_type != "assistant" {
+        return None;
+    }
+
+    let uuid = v["uuid"].as_str().unwrap_or("").to_string();
+    let timestamp = v["timestamp"].as_str().unwrap_or("").to_string();
+    let message = &v["message"];
+    let role = msg_type.to_string();
+
+    match msg_type {
+        "user" => {
+            // User content can be a plain string or an array of content blocks
+            let content = match &message["content"] {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Array(blocks) => {
+                    // Collect text blocks; skip tool_result blocks for the text field
+                    blocks
+                        .iter()
+                        .filter(|b| b["type"].as_str() == Some("text"))
+                        .filter_map(|b| b["text"].as_str())
+                        .collect::<Vec<_>>()
+                        .join("")
+                }
+                _ => return None,
+            };
+
+            // Images returned by tools belong to the assistant turn that
+            // made the call (see `load_session_detail`).
+            let attachments: Vec<SessionAttachment> = attachment_blocks(&message["content"])
+                .into_iter()
+                .filter(|(tool_use_id, _)| tool_use_id.is_none())
+                .map(|(_, block)| to_attachment(block, None))
+                .collect();
+
+            if content.is_empty() && attachments.is_empty() {
+                // May still be a tool_result only turn — skip silently
+                return None;
+            }
+
+            Some(SessionTurn {
+                uuid,
+                role,
+                content,
+                timestamp,
+                tool_calls: vec![],
+                attachments,
+            })
+        }
+        "assistant" => {
+            let blocks = message["content"].as_array()?;
+
+            // Extract plain text from text blocks
+            let content: String = blocks
+                .iter()
+                .filter(|b| b["type"].as_str() == Some("text"))
+                .filter_map(|b| b["text"].as_str())
+                .collect::<Vec<_>>()
+                .join("");
+
+            // Extract tool_use blocks
+            let tool_calls: Vec<SessionToolCall> = blocks
+                .iter()
+                .filter(|b| b["type"].as_str() == Some("tool_use"))
+                .map(|b| {
+                    let id = b["id"].as_str().unwrap_or("").to_string();
+                    let name = b["name"].as_str().unwrap_or("unknown").to_string();
+                    let input =
+                        serde_json::to_string(&b["input"]).unwrap_or_else(|_| "{}".to_string());
+                    SessionToolCall {
+                        id,
+                        name,
+                        input,
+                        output: None,
+                        is_error: false,
+                    }
+                })
+                .collect();
+
+            // Skip turns that have neither text nor tool calls
+            if content.is_empty() && tool_calls.is_empty() {
+                return None;
+            }
+
+            Some(SessionTurn {
+                uuid,
+                role,
+                content,
+                timestamp,
+                tool_calls,
+                attachments: vec![],
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Count sessions attributed to `project_id` whose file was modified since
+/// `since`.  Files are filtered by mtime before their first line is read.
+pub fn count_recent_sessions(
+    project_id: &str,
+    projects: &[Project],
+    since: std::time::SystemTime,
+) -> usize {
+    session_files()
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("jsonl"))
+        .filter(|p| {
+            p.metadata()
+                .and_then(|m| m.modified())
+                .is_ok_and(|t| t >= since)
+        })
+        .filter(|p| {
+            read_first_line_cwd(p)
+                .and_then(|cwd| correlate_session_project(&cwd, projects))
+                .as_deref()
+                == Some(project_id)
+        })
+        .count()
+}
+
+/// Ids of projects with at least one session file modified since `since`.
+pub fn projects_with_sessions_since(
+    projects: &[Project],
+    since: std::time::SystemTime,
+) -> HashSet<String> {
+    session_files()
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("jsonl"))
+        .filter(|p| {
+            p.metadata()
+                .and_then(|m| m.modified())
+                .is_ok_and(|t| t >= since)
+        })
+        .filter_map(|p| {
+            read_first_line_cwd(&p).and_then(|cwd| correlate_session_project(&cwd, projects))
+        })
+        .collect()
+}
+
+/// Every `<project>/<session>.jsonl` path across all roots.
+fn session_files() -> impl Iterator<Item = PathBuf> {
+    session_dirs()
+        .into_iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|dirs| dirs.filter_map(|e| e.ok()))
+        .filter_map(|dir| std::fs::read_dir(dir.path()).ok())
+        .flat_map(|files| files.filter_map(|e| e.ok()))
+        .map(|e| e.path())
+}
+
+/// Return the id of the project whose path is the deepest ancestor of `cwd`,
+/// so a session started inside a monorepo sub-package is attributed to that
+/// package's project rather than to the repository root.
+///
+/// A symlinked project also matches through its `real_path`, since tools
+/// usually report the resolved directory as their cwd.
+pub fn correlate_session_project(cwd: &str, projects: &[Project]) -> Option<String> {
+    let cwd = Path::new(cwd);
+    projects
+        .iter()
+        .filter_map(|p| {
+            std::iter::once(&p.path)
+                .chain(p.real_path.as_ref())
+                .map(Path::new)
+                .filter(|root| cwd.starts_with(root))
+                .map(|root| root.components().count())
+                .max()
+                .map(|depth| (p, depth))
+        })
+        .max_by_key(|(_, depth)| *depth)
+        .map(|(p, _)| p.id.clone())
+}
+
+/// Bytes of a transcript read when looking for its title.  Resumed sessions
+/// put their `summary` lines first, and the opening prompt is normally
+/// within the first few records.
+const TITLE_SCAN_BYTES: u64 = 64 * 1024;
+
+const TITLE_MAX_CHARS: usize = 120;
+
+/// A one-line title for a session: the `summary` Claude Code wrote for it
+/// if one appears in the head of the file, otherwise its first real user
+/// message.  Slash-command wrappers, meta records and tool results are
+/// skipped.  Only the first `TITLE_SCAN_BYTES` are read.
+pub fn read_session_title(path: &Path) -> Option<String> {
+    use std::io::{BufRead, Read};
+    let file = std::fs::File::open(path).ok()?;
+    let reader = std::io::BufReader::new(file.take(TITLE_SCAN_BYTES));
+
+    let mut first_prompt = None;
+    for line in reader.lines() {
+        // The last line is cut off at the cap and fails to parse.
+        let Ok(line) = line else { break };
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        match v["type"].as_str() {
+            Some("summary") => {
+                if let Some(summary) = v["summary"].as_str().filter(|s| !s.trim().is_empty()) {
+                    return Some(one_line(summary));
+                }
+            }
+            Some("user") if first_prompt.is_none() && v["isMeta"].as_bool() != Some(true) => {
+                first_prompt = parse_session_turn(&line)
+                    .map(|turn| turn.content)
+                    .filter(|c| !c.trim().is_empty() && !c.trim_start().starts_with('<'));
+            }
+            _ => {}
+        }
+    }
+    first_prompt.map(|p| one_line(&p))
+}
+
+/// Collapse whitespace and cut to `TITLE_MAX_CHARS`.
+fn one_line(text: &str) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= TITLE_MAX_CHARS {
+        return collapsed;
+    }
+    let cut: String = collapsed.chars().take(TITLE_MAX_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+pub fn read_first_line_cwd(path: &Path) -> Option<String> {
+    use std::io::BufRead;
+    let file = std::fs::File::open(path).ok()?;
+    let reader = std::io::BufReader::new(file);
+    let first_line = reader.lines().next()?.ok()?;
+    let json: serde_json::Value = serde_json::from_str(&first_line).ok()?;
+    json.get("cwd")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
@@ -0,0 +1,262 @@
+use std::path::Path;
+
+/// Primary language, framework and package manager of a project directory.
+/// Every field is `None` when nothing conclusive was found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectStack {
+    pub language: Option<String>,
+    pub framework: Option<String>,
+    pub package_manager: Option<String>,
+}
+
+/// `(dependency, framework)` pairs, most specific first: a Next.js app also
+/// depends on `react`, so `next` must be checked before `react`.
+const NPM_FRAMEWORKS: &[(&str, &str)] = &[
+    ("@tauri-apps/api", "Tauri"),
+    ("electron", "Electron"),
+    ("next", "Next.js"),
+    ("nuxt", "Nuxt"),
+    ("@remix-run/react", "Remix"),
+    ("@sveltejs/kit", "SvelteKit"),
+    ("astro", "Astro"),
+    ("@angular/core", "Angular"),
+    ("@nestjs/core", "NestJS"),
+    ("svelte", "Svelte"),
+    ("vue", "Vue"),
+    ("react", "React"),
+    ("express", "Express"),
+    ("fastify", "Fastify"),
+    ("vite", "Vite"),
+];
+
+const CARGO_FRAMEWORKS: &[(&str, &str)] = &[
+    ("tauri", "Tauri"),
+    ("leptos", "Leptos"),
+    ("dioxus", "Dioxus"),
+    ("bevy", "Bevy"),
+    ("axum", "Axum"),
+    ("actix-web", "Actix Web"),
+    ("rocket", "Rocket"),
+    ("warp", "Warp"),
+];
+
+const PYTHON_FRAMEWORKS: &[(&str, &str)] = &[
+    ("django", "Django"),
+    ("fastapi", "FastAPI"),
+    ("flask", "Flask"),
+    ("streamlit", "Streamlit"),
+];
+
+const GO_FRAMEWORKS: &[(&str, &str)] = &[
+    ("github.com/gin-gonic/gin", "Gin"),
+    ("github.com/labstack/echo", "Echo"),
+    ("github.com/gofiber/fiber", "Fiber"),
+];
+
+/// Lockfile → package manager, checked in order.
+const LOCKFILES: &[(&str, &str)] = &[
+    ("pnpm-lock.yaml", "pnpm"),
+    ("bun.lockb", "bun"),
+    ("bun.lock", "bun"),
+    ("yarn.lock", "yarn"),
+    ("package-lock.json", "npm"),
+    ("Cargo.lock", "cargo"),
+    ("uv.lock", "uv"),
+    ("poetry.lock", "poetry"),
+    ("Pipfile.lock", "pipenv"),
+    ("go.sum", "go"),
+    ("Gemfile.lock", "bundler"),
+    ("composer.lock", "composer"),
+];
+
+const NODE_PACKAGE_MANAGERS: &[&str] = &["pnpm", "bun", "yarn", "npm"];
+
+/// Detect the stack of the project at `path` from its manifests and
+/// lockfiles.  The first manifest family present decides the primary
+/// language: JS/TS, then Rust, Python, Go, Ruby, PHP.
+pub fn detect_stack(path: &Path) -> ProjectStack {
+    let mut stack = detect_node(path)
+        .or_else(|| detect_cargo(path))
+        .or_else(|| detect_python(path))
+        .or_else(|| detect_go(path))
+        .or_else(|| detect_simple(path, "Gemfile", "ruby", &[("rails", "Rails")]))
+        .or_else(|| {
+            detect_simple(
+                path,
+                "composer.json",
+                "php",
+                &[("laravel/framework", "Laravel")],
+            )
+        })
+        .unwrap_or_default();
+
+    if stack.package_manager.is_none() {
+        stack.package_manager = LOCKFILES
+            .iter()
+            .find(|(file, _)| path.join(file).exists())
+            .map(|(_, pm)| pm.to_string());
+    }
+    stack
+}
+
+fn detect_node(path: &Path) -> Option<ProjectStack> {
+    let content = std::fs::read_to_string(path.join("package.json")).ok()?;
+    let pkg: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    let has_dep = |name: &str| {
+        ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .any(|section| pkg[section].get(name).is_some())
+    };
+
+    let language = if path.join("tsconfig.json").exists() || has_dep("typescript") {
+        "typescript"
+    } else {
+        "javascript"
+    };
+
+    let mut framework = NPM_FRAMEWORKS
+        .iter()
+        .find(|(dep, _)| has_dep(dep))
+        .map(|(_, fw)| fw.to_string());
+    // A Tauri app may only list the CLI; the src-tauri crate is authoritative.
+    if framework.as_deref() != Some("Tauri") && path.join("src-tauri/tauri.conf.json").exists() {
+        framework = Some("Tauri".to_string());
+    }
+
+    // Corepack's `packageManager` field ("pnpm@9.1.0") beats lockfile guessing.
+    let package_manager = pkg["packageManager"]
+        .as_str()
+        .and_then(|s| s.split('@').next())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .or_else(|| {
+            LOCKFILES
+                .iter()
+                .filter(|(_, pm)| NODE_PACKAGE_MANAGERS.contains(pm))
+                .find(|(file, _)| path.join(file).exists())
+                .map(|(_, pm)| pm.to_string())
+        })
+        .or_else(|| Some("npm".to_string()));
+
+    Some(ProjectStack {
+        language: Some(language.to_string()),
+        framework,
+        package_manager,
+    })
+}
+
+fn detect_cargo(path: &Path) -> Option<ProjectStack> {
+    let content = std::fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = content.parse().ok()?;
+
+    let has_dep = |name: &str| {
+        let in_table = |t: Option<&toml::Value>| t.and_then(|t| t.get(name)).is_some();
+        in_table(manifest.get("dependencies"))
+            || in_table(manifest.get("dev-dependencies"))
+            || in_table(
+                manifest
+                    .get("workspace")
+                    .and_then(|w| w.get("dependencies")),
+            )
+    };
+
+    Some(ProjectStack {
+        language: Some("rust".to_string()),
+        framework: CARGO_FRAMEWORKS
+            .iter()
+            .find(|(dep, _)| has_dep(dep))
+            .map(|(_, fw)| fw.to_string()),
+        package_manager: Some("cargo".to_string()),
+    })
+}
+
+fn detect_python(path: &Path) -> Option<ProjectStack> {
+    let pyproject = std::fs::read_to_string(path.join("pyproject.toml")).ok();
+    let requirements = std::fs::read_to_string(path.join("requirements.txt")).ok();
+    if pyproject.is_none() && requirements.is_none() && !path.join("setup.py").exists() {
+        return None;
+    }
+
+    // Dependency names from PEP 621 `project.dependencies`, Poetry's table,
+    // and requirements.txt lines, lower-cased without version specifiers.
+    let mut deps: Vec<String> = Vec::new();
+    if let Some(doc) = pyproject
+        .as_deref()
+        .and_then(|c| c.parse::<toml::Value>().ok())
+    {
+        if let Some(list) = doc
+            .get("project")
+            .and_then(|p| p.get("dependencies"))
+            .and_then(|d| d.as_array())
+        {
+            deps.extend(list.iter().filter_map(|d| d.as_str()).map(requirement_name));
+        }
+        if let Some(table) = doc
+            .get("tool")
+            .and_then(|t| t.get("poetry"))
+            .and_then(|p| p.get("dependencies"))
+            .and_then(|d| d.as_table())
+        {
+            deps.extend(table.keys().map(|k| k.to_lowercase()));
+        }
+    }
+    if let Some(req) = &requirements {
+        deps.extend(
+            req.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with('-'))
+                .map(requirement_name),
+        );
+    }
+
+    Some(ProjectStack {
+        language: Some("python".to_string()),
+        framework: PYTHON_FRAMEWORKS
+            .iter()
+            .find(|(dep, _)| deps.iter().any(|d| d == dep))
+            .map(|(_, fw)| fw.to_string()),
+        package_manager: None,
+    })
+}
+
+/// `"Django>=4.2"` / `"fastapi[all] ~= 0.110"` → `"django"` / `"fastapi"`.
+fn requirement_name(spec: &str) -> String {
+    spec.split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .next()
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+fn detect_go(path: &Path) -> Option<ProjectStack> {
+    let content = std::fs::read_to_string(path.join("go.mod")).ok()?;
+    Some(ProjectStack {
+        language: Some("go".to_string()),
+        framework: GO_FRAMEWORKS
+            .iter()
+            .find(|(module, _)| content.contains(module))
+            .map(|(_, fw)| fw.to_string()),
+        package_manager: Some("go".to_string()),
+    })
+}
+
+/// Manifests where a substring search for the dependency name is precise
+/// enough (Gemfile `gem "rails"`, composer.json `"laravel/framework"`).
+fn detect_simple(
+    path: &Path,
+    manifest: &str,
+    language: &str,
+    frameworks: &[(&str, &str)],
+) -> Option<ProjectStack> {
+    let content = std::fs::read_to_string(path.join(manifest)).ok()?;
+    Some(ProjectStack {
+        language: Some(language.to_string()),
+        framework: frameworks
+            .iter()
+            .find(|(dep, _)| {
+                content.contains(&format!("\"{}\"", dep)) || content.contains(&format!("'{}'", dep))
+            })
+            .map(|(_, fw)| fw.to_string()),
+        package_manager: None,
+    })
+}
@@ -0,0 +1,199 @@
+use crate::commands::claude::{correlate_session_project, read_first_line_cwd};
+use crate::commands::cost_ledger::range_start;
+use crate::commands::projects::load_db_projects;
+use crate::commands::session_search::list_session_files;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{ActivityDay, ActivityProject, ClaudeActivity};
+use crate::state::AppState;
+use crate::utils::parse_timestamp;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufRead;
+use std::path::Path;
+use tauri::State;
+
+/// Projects returned in `ClaudeActivity::top_projects`.
+const TOP_PROJECTS_LIMIT: usize = 10;
+
+/// Sessions and messages per day over `range` (`"<N>d"` or `"all"`), plus
+/// the most active projects, for the activity heatmap.  Transcripts that
+/// changed since the last call are re-counted first; the rest come from the
+/// `activity_days` cache.
+#[tauri::command]
+pub fn get_claude_activity(state: State<AppState>, range: String) -> CmdResult<ClaudeActivity> {
+    let since = range_start(&range)?;
+    update_activity_cache(&state).map_err(to_cmd_err)?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let projects = load_db_projects(conn).map_err(to_cmd_err)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT session_id, day, cwd, project_key, messages FROM activity_days
+             WHERE ?1 IS NULL OR day >= ?1",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let rows: Vec<(String, String, Option<String>, String, i64)> = stmt
+        .query_map([&since], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut sessions = HashSet::new();
+    let mut total_messages = 0;
+    let mut by_day: BTreeMap<String, (HashSet<String>, i64)> = BTreeMap::new();
+    let mut by_project: HashMap<String, (HashSet<String>, i64)> = HashMap::new();
+    for (session_id, day, cwd, project_key, messages) in rows {
+        let project = cwd
+            .as_deref()
+            .and_then(|cwd| correlate_session_project(cwd, &projects))
+            .unwrap_or_else(|| cwd.unwrap_or(project_key));
+        for (set, count) in [
+            by_day.entry(day).or_default(),
+            by_project.entry(project).or_default(),
+        ] {
+            set.insert(session_id.clone());
+            *count += messages;
+        }
+        sessions.insert(session_id);
+        total_messages += messages;
+    }
+
+    let names: HashMap<&str, &str> = projects
+        .iter()
+        .map(|p| (p.id.as_str(), p.name.as_str()))
+        .collect();
+    let mut top_projects: Vec<ActivityProject> = by_project
+        .into_iter()
+        .map(|(key, (sessions, messages))| ActivityProject {
+            label: names.get(key.as_str()).map(|n| n.to_string()),
+            key,
+            sessions: sessions.len(),
+            messages,
+        })
+        .collect();
+    top_projects.sort_by(|a, b| b.messages.cmp(&a.messages).then(a.key.cmp(&b.key)));
+    top_projects.truncate(TOP_PROJECTS_LIMIT);
+
+    Ok(ClaudeActivity {
+        range,
+        since,
+        total_sessions: sessions.len(),
+        total_messages,
+        days: by_day
+            .into_iter()
+            .map(|(day, (sessions, messages))| ActivityDay {
+                day,
+                sessions: sessions.len(),
+                messages,
+            })
+            .collect(),
+        top_projects,
+    })
+}
+
+/// Re-count transcripts whose mtime or size changed since the last pass.
+/// Like the cost ledger, counts outlive deleted transcripts.
+pub(crate) fn update_activity_cache(state: &AppState) -> Result<usize, CommanderError> {
+    let files = list_session_files();
+    let known: HashMap<String, (i64, i64)> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let mut stmt = conn.prepare("SELECT path, mtime, size FROM activity_files")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    let mut updated = 0;
+    for file in &files {
+        let key = file.path.to_string_lossy().to_string();
+        if known.get(&key) == Some(&(file.mtime, file.size)) {
+            continue;
+        }
+        let days = match count_messages_by_day(&file.path) {
+            Ok(days) => days,
+            Err(e) => {
+                log::warn!("skipped activity for {}: {}", file.path.display(), e);
+                continue;
+            }
+        };
+        let cwd = read_first_line_cwd(&file.path);
+
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM activity_days WHERE session_id = ?1",
+            [&file.session_id],
+        )?;
+        for (day, messages) in &days {
+            tx.execute(
+                "INSERT INTO activity_days (session_id, day, project_key, cwd, messages)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![file.session_id, day, file.project_key, cwd, messages],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO activity_files (path, mtime, size) VALUES (?1, ?2, ?3)",
+            rusqlite::params![key, file.mtime, file.size],
+        )?;
+        tx.commit()?;
+        updated += 1;
+    }
+    Ok(updated)
+}
+
+/// Messages per local day: user prompts, plus assistant replies counted
+/// once per message id (Claude Code writes one line per content block).
+/// User lines that only carry tool results are not messages.
+fn count_messages_by_day(path: &Path) -> Result<BTreeMap<String, i64>, CommanderError> {
+    let file = std::fs::File::open(path)?;
+    let mut seen_assistant = HashSet::new();
+    let mut days: BTreeMap<String, i64> = BTreeMap::new();
+    for line in std::io::BufReader::new(file).lines().map_while(Result::ok) {
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        let counted = match v["type"].as_str() {
+            Some("user") => match &v["message"]["content"] {
+                serde_json::Value::String(_) => true,
+                serde_json::Value::Array(blocks) => {
+                    blocks.iter().any(|b| b["type"].as_str() == Some("text"))
+                }
+                _ => false,
+            },
+            Some("assistant") => v["message"]["id"]
+                .as_str()
+                .is_none_or(|id| seen_assistant.insert(id.to_string())),
+            _ => false,
+        };
+        if !counted {
+            continue;
+        }
+        let Some(day) = v["timestamp"].as_str().and_then(parse_timestamp) else {
+            continue;
+        };
+        let day = day
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d")
+            .to_string();
+        *days.entry(day).or_default() += 1;
+    }
+    Ok(days)
+}
@@ -0,0 +1,212 @@
+use crate::commands::claude::{correlate_session_project, find_session_file, read_first_line_cwd};
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::RunArtifact;
+use crate::state::AppState;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use uuid::Uuid;
+
+/// Directory names that conventionally hold generated deliverables.
+const OUTPUT_DIRS: &[&str] = &[
+    "output",
+    "outputs",
+    "out",
+    "reports",
+    "report",
+    "screenshots",
+    "artifacts",
+    "coverage",
+];
+
+const SCREENSHOT_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp"];
+
+const REPORT_EXTENSIONS: &[&str] = &["pdf", "html", "md", "csv", "txt", "json", "xlsx", "svg"];
+
+/// Absolute paths (or `~/...`) ending in a known artifact extension, as they
+/// appear in tool output such as "Screenshot saved to /tmp/shot.png".
+const OUTPUT_PATH_PATTERN: &str =
+    r"(?:~|/)[^\s'`<>|:]*?\.(?i:png|jpe?g|gif|webp|pdf|html|md|csv|txt|json|xlsx|svg)\b";
+
+/// Scan a session transcript for files Claude produced, register any new ones
+/// in `run_artifacts`, and return everything recorded for the session.
+#[tauri::command]
+pub fn get_run_artifacts(
+    state: State<AppState>,
+    session_id: String,
+) -> CmdResult<Vec<RunArtifact>> {
+    // Transcript parsing and filesystem checks happen before the DB lock is taken.
+    let session_path = find_session_file(&session_id)?;
+    let cwd = read_first_line_cwd(&session_path);
+    let found = collect_artifacts(&session_path, cwd.as_deref())?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let project_id = match &cwd {
+        Some(cwd) => correlate_session_project(cwd, &load_db_projects(conn).map_err(to_cmd_err)?),
+        None => None,
+    };
+
+    for (path, (kind, source)) in &found {
+        let size = std::fs::metadata(path).ok().map(|m| m.len() as i64);
+        conn.execute(
+            "INSERT INTO run_artifacts (id, session_id, project_id, path, kind, source, size_bytes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(session_id, path) DO UPDATE SET size_bytes = excluded.size_bytes",
+            rusqlite::params![
+                Uuid::new_v4().to_string(),
+                session_id,
+                project_id,
+                path.to_string_lossy().to_string(),
+                kind,
+                source,
+                size,
+            ],
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    }
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, session_id, project_id, path, kind, source, size_bytes, detected_at
+             FROM run_artifacts WHERE session_id = ?1 ORDER BY detected_at, path",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let artifacts = stmt
+        .query_map([&session_id], |row| {
+            let path: String = row.get(3)?;
+            Ok(RunArtifact {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                project_id: row.get(2)?,
+                exists: Path::new(&path).exists(),
+                path,
+                kind: row.get(4)?,
+                source: row.get(5)?,
+                size_bytes: row.get::<_, Option<i64>>(6)?.map(|s| s as u64),
+                detected_at: row.get(7)?,
+            })
+        })
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(artifacts)
+}
+
+/// Walk the transcript and return `path -> (kind, source)` for every existing
+/// file that qualifies as an artifact. Write-tool paths win over mentions.
+fn collect_artifacts(
+    session_path: &Path,
+    cwd: Option<&str>,
+) -> CmdResult<HashMap<PathBuf, (String, String)>> {
+    use std::io::BufRead;
+    let file = std::fs::File::open(session_path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let mention_re =
+        Regex::new(OUTPUT_PATH_PATTERN).map_err(|e| to_cmd_err(CommanderError::internal(e)))?;
+
+    let mut found: HashMap<PathBuf, (String, String)> = HashMap::new();
+
+    for line in std::io::BufReader::new(file).lines().map_while(Result::ok) {
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        let Some(blocks) = v["message"]["content"].as_array() else {
+            continue;
+        };
+
+        for block in blocks {
+            match block["type"].as_str() {
+                Some("tool_use")
+                    if matches!(block["name"].as_str(), Some("Write" | "NotebookEdit")) =>
+                {
+                    let input = &block["input"];
+                    let raw = input["file_path"]
+                        .as_str()
+                        .or_else(|| input["notebook_path"].as_str());
+                    if let Some(path) = raw.and_then(|p| resolve_path(p, cwd)) {
+                        if let Some(kind) = classify(&path) {
+                            found.insert(path, (kind.to_string(), "write".to_string()));
+                        }
+                    }
+                }
+                Some("tool_result") => {
+                    for text in tool_result_texts(&block["content"]) {
+                        for m in mention_re.find_iter(text) {
+                            let Some(path) = resolve_path(m.as_str(), cwd) else {
+                                continue;
+                            };
+                            if let Some(kind) = classify(&path) {
+                                found
+                                    .entry(path)
+                                    .or_insert((kind.to_string(), "tool_output".to_string()));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    found.retain(|path, _| path.is_file());
+    Ok(found)
+}
+
+/// Tool results carry either a plain string or an array of text blocks.
+fn tool_result_texts(content: &serde_json::Value) -> Vec<&str> {
+    match content {
+        serde_json::Value::String(s) => vec![s.as_str()],
+        serde_json::Value::Array(blocks) => {
+            blocks.iter().filter_map(|b| b["text"].as_str()).collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn resolve_path(raw: &str, cwd: Option<&str>) -> Option<PathBuf> {
+    let raw = raw.trim_end_matches(['.', ',', ')']);
+    if let Some(rest) = raw.strip_prefix("~/") {
+        return dirs::home_dir().map(|h| h.join(rest));
+    }
+    let path = Path::new(raw);
+    if path.is_absolute() {
+        Some(path.to_path_buf())
+    } else {
+        cwd.map(|c| Path::new(c).join(path))
+    }
+}
+
+/// Screenshots qualify anywhere; other files only inside a well-known output
+/// directory or a temp dir, so ordinary source edits are not collected.
+fn classify(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    if SCREENSHOT_EXTENSIONS.contains(&ext.as_str()) {
+        return Some("screenshot");
+    }
+
+    let in_output_dir = path.starts_with(std::env::temp_dir())
+        || path.starts_with("/tmp")
+        || path.parent().is_some_and(|parent| {
+            parent.components().any(|c| {
+                c.as_os_str()
+                    .to_str()
+                    .is_some_and(|s| OUTPUT_DIRS.contains(&s.to_lowercase().as_str()))
+            })
+        });
+    if !in_output_dir {
+        return None;
+    }
+
+    if REPORT_EXTENSIONS.contains(&ext.as_str()) {
+        Some("report")
+    } else {
+        Some("file")
+    }
+}
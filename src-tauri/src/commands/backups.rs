@@ -0,0 +1,233 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::SnapshotInfo;
+use crate::state::AppState;
+use rusqlite::types::{Value, ValueRef};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tauri::State;
+
+/// Tables captured before project-level destructive operations, parents
+/// first so a restore can insert them in order.  These are `projects` and
+/// every table that cascades from it.
+pub(crate) const PROJECT_TABLES: &[&str] = &[
+    "projects",
+    "planning_items",
+    "planning_item_github_links",
+    "env_var_cache",
+    "session_project_links",
+];
+
+/// Older snapshots beyond this many are deleted when a new one is taken.
+const MAX_SNAPSHOTS: usize = 20;
+
+/// On-disk snapshot: rows keyed by column name, per table.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    id: String,
+    created_at: String,
+    reason: String,
+    /// Table name → rows, in `PROJECT_TABLES` order on restore.
+    tables: Vec<(String, Vec<BTreeMap<String, serde_json::Value>>)>,
+}
+
+pub(crate) fn backups_dir() -> Result<PathBuf, CommanderError> {
+    dirs::home_dir()
+        .map(|h| h.join(".claude-commander").join("backups"))
+        .ok_or_else(|| CommanderError::internal("Cannot determine home dir"))
+}
+
+/// Copy `tables` into a JSON snapshot under `~/.claude-commander/backups`
+/// and return its id.  Callers take this before a destructive statement and
+/// abort if it fails.
+pub(crate) fn create_snapshot(
+    conn: &rusqlite::Connection,
+    reason: &str,
+    tables: &[&str],
+) -> Result<String, CommanderError> {
+    let created_at = chrono::Utc::now();
+    let id = format!(
+        "{}-{}",
+        created_at.format("%Y%m%d-%H%M%S"),
+        &uuid::Uuid::new_v4().simple().to_string()[..6]
+    );
+
+    let mut dumped = Vec::new();
+    for table in tables {
+        dumped.push((table.to_string(), dump_table(conn, table)?));
+    }
+    let snapshot = Snapshot {
+        id: id.clone(),
+        created_at: created_at.to_rfc3339(),
+        reason: reason.to_string(),
+        tables: dumped,
+    };
+
+    let dir = backups_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join(format!("{id}.json")),
+        serde_json::to_vec(&snapshot)?,
+    )?;
+    prune_snapshots(&dir);
+    Ok(id)
+}
+
+fn dump_table(
+    conn: &rusqlite::Connection,
+    table: &str,
+) -> Result<Vec<BTreeMap<String, serde_json::Value>>, CommanderError> {
+    let mut stmt = conn.prepare(&format!("SELECT * FROM {table}"))?;
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+    let rows = stmt
+        .query_map([], |row| {
+            let mut map = BTreeMap::new();
+            for (i, column) in columns.iter().enumerate() {
+                let value = match row.get_ref(i)? {
+                    ValueRef::Null => serde_json::Value::Null,
+                    ValueRef::Integer(n) => n.into(),
+                    ValueRef::Real(f) => f.into(),
+                    ValueRef::Text(t) => String::from_utf8_lossy(t).into(),
+                    ValueRef::Blob(b) => b.to_vec().into(),
+                };
+                map.insert(column.clone(), value);
+            }
+            Ok(map)
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Keep the newest `MAX_SNAPSHOTS`; ids sort chronologically.
+fn prune_snapshots(dir: &std::path::Path) {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    let excess = files.len().saturating_sub(MAX_SNAPSHOTS);
+    for path in files.into_iter().take(excess) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            log::warn!("failed to prune snapshot {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn read_snapshot(id: &str) -> Result<Snapshot, CommanderError> {
+    // Ids are generated here; anything else could walk out of the directory.
+    if !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(CommanderError::internal(format!(
+            "Invalid snapshot id: {id}"
+        )));
+    }
+    let path = backups_dir()?.join(format!("{id}.json"));
+    let content = std::fs::read(&path).map_err(|_| CommanderError::FileNotFound {
+        path: path.to_string_lossy().to_string(),
+    })?;
+    Ok(serde_json::from_slice(&content)?)
+}
+
+/// Snapshots on disk, newest first.
+#[tauri::command]
+pub fn list_snapshots() -> CmdResult<Vec<SnapshotInfo>> {
+    let dir = backups_dir().map_err(to_cmd_err)?;
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(vec![]);
+    };
+
+    let mut snapshots: Vec<SnapshotInfo> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let id = e.path().file_stem()?.to_str()?.to_string();
+            let snapshot = read_snapshot(&id).ok()?;
+            Some(SnapshotInfo {
+                id,
+                created_at: snapshot.created_at,
+                reason: snapshot.reason,
+                row_counts: snapshot
+                    .tables
+                    .iter()
+                    .map(|(table, rows)| (table.clone(), rows.len()))
+                    .collect(),
+            })
+        })
+        .collect();
+    snapshots.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(snapshots)
+}
+
+/// Put back every row from the snapshot that is missing now.  Rows that
+/// still exist (same id or path) are left as they are, so nothing created
+/// since the snapshot is lost.  Returns the number of rows restored.
+#[tauri::command]
+pub fn restore_snapshot(state: State<AppState>, id: String) -> CmdResult<usize> {
+    let snapshot = read_snapshot(&id).map_err(to_cmd_err)?;
+
+    let mut db = state.db.lock();
+    let conn = db
+        .as_mut()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let tx = conn
+        .transaction()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let mut restored = 0;
+    for (table, rows) in &snapshot.tables {
+        if !PROJECT_TABLES.contains(&table.as_str()) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Snapshot contains unexpected table: {table}"
+            ))));
+        }
+        for row in rows {
+            let columns: Vec<&str> = row.keys().map(String::as_str).collect();
+            if let Some(bad) = columns
+                .iter()
+                .find(|c| !c.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_'))
+            {
+                return Err(to_cmd_err(CommanderError::internal(format!(
+                    "Snapshot contains invalid column name: {bad}"
+                ))));
+            }
+            let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("?{i}")).collect();
+            let sql = format!(
+                "INSERT OR IGNORE INTO {table} ({}) VALUES ({})",
+                columns.join(", "),
+                placeholders.join(", ")
+            );
+            restored += tx
+                .execute(
+                    &sql,
+                    rusqlite::params_from_iter(row.values().map(json_to_sql)),
+                )
+                .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        }
+    }
+
+    tx.commit()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(restored)
+}
+
+fn json_to_sql(value: &serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Integer(*b as i64),
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(Value::Integer)
+            .unwrap_or_else(|| Value::Real(n.as_f64().unwrap_or_default())),
+        serde_json::Value::String(s) => Value::Text(s.clone()),
+        serde_json::Value::Array(bytes) => Value::Blob(
+            bytes
+                .iter()
+                .filter_map(|b| b.as_u64().map(|b| b as u8))
+                .collect(),
+        ),
+        serde_json::Value::Object(_) => Value::Text(value.to_string()),
+    }
+}
@@ -0,0 +1,238 @@
+use crate::commands::github::detect_github_repo;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::ProjectBadge;
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
+
+const README_NAMES: &[&str] = &["README.md", "readme.md", "Readme.md", "README.markdown"];
+
+/// Image hosts and path fragments that identify a status badge rather than
+/// an ordinary screenshot or logo.
+const BADGE_URL_HINTS: &[&str] = &[
+    "img.shields.io",
+    "badgen.net",
+    "badge.fury.io",
+    "codecov.io",
+    "coveralls.io",
+    "travis-ci.",
+    "circleci.com",
+    "sonarcloud.io/api/project_badges",
+    "api.netlify.com",
+    "readthedocs.org/projects",
+    "/badge.svg",
+    "/badges/",
+];
+
+/// Markdown image, optionally wrapped in a link: `[![alt](img)](href)`.
+const MARKDOWN_BADGE_PATTERN: &str =
+    r"(\[)?!\[([^\]]*)\]\(\s*(\S+?)(?:\s+[^)]*)?\)(?:\]\(\s*(\S+?)(?:\s+[^)]*)?\))?";
+
+/// HTML `<img>`, optionally wrapped in `<a href>`.
+const HTML_BADGE_PATTERN: &str =
+    r#"(?is)(?:<a\s[^>]*?href\s*=\s*"([^"]+)"[^>]*>\s*)?<img\s([^>]*)>"#;
+const HTML_SRC_PATTERN: &str = r#"(?i)\bsrc\s*=\s*"([^"]*)""#;
+const HTML_ALT_PATTERN: &str = r#"(?i)\balt\s*=\s*"([^"]*)""#;
+
+/// The README patterns, compiled once per call.
+struct BadgePatterns {
+    markdown: Regex,
+    html: Regex,
+    src: Regex,
+    alt: Regex,
+}
+
+impl BadgePatterns {
+    fn new() -> CmdResult<Self> {
+        let compile = |p: &str| Regex::new(p).map_err(|e| to_cmd_err(CommanderError::internal(e)));
+        Ok(BadgePatterns {
+            markdown: compile(MARKDOWN_BADGE_PATTERN)?,
+            html: compile(HTML_BADGE_PATTERN)?,
+            src: compile(HTML_SRC_PATTERN)?,
+            alt: compile(HTML_ALT_PATTERN)?,
+        })
+    }
+}
+
+/// Collect CI, coverage, version and license badges for a project: every
+/// badge image in its README, plus badges implied by CI config that the
+/// README doesn't already show (GitHub Actions workflows, Codecov).
+#[tauri::command]
+pub fn get_project_badges(project_path: String) -> CmdResult<Vec<ProjectBadge>> {
+    let dir = Path::new(&project_path);
+    let mut badges = readme_badges(dir, &BadgePatterns::new()?);
+
+    if let Some(repo) = detect_github_repo(project_path.clone()) {
+        for badge in ci_config_badges(dir, &repo) {
+            if !badges.iter().any(|b| b.image_url == badge.image_url) {
+                badges.push(badge);
+            }
+        }
+    }
+
+    Ok(badges)
+}
+
+fn readme_badges(dir: &Path, patterns: &BadgePatterns) -> Vec<ProjectBadge> {
+    let Some(content) = README_NAMES
+        .iter()
+        .find_map(|name| std::fs::read_to_string(dir.join(name)).ok())
+    else {
+        return vec![];
+    };
+
+    let mut badges = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut push = |alt: String, image_url: String, link_url: Option<String>| {
+        if !is_badge_url(&image_url) || !seen.insert(image_url.clone()) {
+            return;
+        }
+        badges.push(ProjectBadge {
+            kind: classify_badge(&alt, &image_url).to_string(),
+            label: if alt.trim().is_empty() {
+                label_from_url(&image_url)
+            } else {
+                alt.trim().to_string()
+            },
+            image_url,
+            link_url: link_url.filter(|l| is_absolute_url(l)),
+            source: "readme".to_string(),
+        });
+    };
+
+    for caps in patterns.markdown.captures_iter(&content) {
+        // A leading `[` without the closing `](href)` isn't a link wrapper.
+        let link = caps.get(1).and(caps.get(4)).map(|m| m.as_str().to_string());
+        push(caps[2].to_string(), caps[3].to_string(), link);
+    }
+
+    for caps in patterns.html.captures_iter(&content) {
+        let attrs = &caps[2];
+        let Some(src) = patterns.src.captures(attrs).map(|c| c[1].to_string()) else {
+            continue;
+        };
+        let alt = patterns
+            .alt
+            .captures(attrs)
+            .map(|c| c[1].to_string())
+            .unwrap_or_default();
+        push(alt, src, caps.get(1).map(|m| m.as_str().to_string()));
+    }
+
+    badges
+}
+
+/// Badges implied by CI/coverage config files in the project.
+fn ci_config_badges(dir: &Path, repo: &str) -> Vec<ProjectBadge> {
+    let mut badges = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(dir.join(".github").join("workflows")) {
+        let mut workflows: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                matches!(
+                    p.extension().and_then(|e| e.to_str()),
+                    Some("yml") | Some("yaml")
+                )
+            })
+            .collect();
+        workflows.sort();
+
+        for path in workflows {
+            let Some(file) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let label = workflow_name(&path).unwrap_or_else(|| {
+                file.trim_end_matches(".yml")
+                    .trim_end_matches(".yaml")
+                    .to_string()
+            });
+            badges.push(ProjectBadge {
+                kind: "ci".to_string(),
+                label,
+                image_url: format!("https://github.com/{repo}/actions/workflows/{file}/badge.svg"),
+                link_url: Some(format!(
+                    "https://github.com/{repo}/actions/workflows/{file}"
+                )),
+                source: "ci_config".to_string(),
+            });
+        }
+    }
+
+    if ["codecov.yml", ".codecov.yml"]
+        .iter()
+        .any(|f| dir.join(f).exists())
+    {
+        badges.push(ProjectBadge {
+            kind: "coverage".to_string(),
+            label: "codecov".to_string(),
+            image_url: format!("https://codecov.io/gh/{repo}/graph/badge.svg"),
+            link_url: Some(format!("https://codecov.io/gh/{repo}")),
+            source: "ci_config".to_string(),
+        });
+    }
+
+    badges
+}
+
+/// Top-level `name:` of a GitHub Actions workflow file.
+fn workflow_name(path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    content.lines().find_map(|line| {
+        let value = line.strip_prefix("name:")?.trim();
+        let value = value.trim_matches(|c| c == '"' || c == '\'');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn is_absolute_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+fn is_badge_url(url: &str) -> bool {
+    is_absolute_url(url) && BADGE_URL_HINTS.iter().any(|hint| url.contains(hint))
+}
+
+/// `"ci"` | `"coverage"` | `"version"` | `"license"` | `"downloads"` | `"other"`
+fn classify_badge(alt: &str, url: &str) -> &'static str {
+    // Leading space so `" ci"` also matches an alt text that starts with "CI".
+    let haystack = format!(" {} {}", alt, url).to_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|n| haystack.contains(n));
+
+    if has(&["coverage", "codecov", "coveralls"]) {
+        "coverage"
+    } else if has(&[
+        "/workflows/",
+        "actions/workflow",
+        "travis-ci.",
+        "circleci.com",
+        "pipeline",
+        "build",
+        " ci",
+    ]) {
+        "ci"
+    } else if has(&["license"]) {
+        "license"
+    } else if has(&["downloads", "/dm/", "/dt/", "/dw/"]) {
+        "downloads"
+    } else if has(&["badge.fury.io", "/v/", "version", "release"]) {
+        "version"
+    } else {
+        "other"
+    }
+}
+
+/// Fallback label when the badge has no alt text: the last meaningful path
+/// segment, e.g. `ci.yml` or `tokio`.
+fn label_from_url(url: &str) -> String {
+    url.split(['?', '#'])
+        .next()
+        .unwrap_or(url)
+        .split('/')
+        .rev()
+        .find(|seg| !seg.is_empty() && *seg != "badge.svg")
+        .unwrap_or("badge")
+        .trim_end_matches(".svg")
+        .to_string()
+}
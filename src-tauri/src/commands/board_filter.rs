@@ -0,0 +1,109 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::SavedFilter;
+use crate::state::AppState;
+use chrono::Utc;
+pub(crate) use commander_core::filter::{BoardFilter, FilterFields};
+use tauri::State;
+use uuid::Uuid;
+
+/// Boards a saved filter can belong to.
+const BOARDS: &[&str] = &["planning", "tasks"];
+
+fn row_to_saved_filter(row: &rusqlite::Row) -> rusqlite::Result<SavedFilter> {
+    Ok(SavedFilter {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        board: row.get(2)?,
+        query: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+#[tauri::command]
+pub fn get_saved_filters(state: State<AppState>) -> CmdResult<Vec<SavedFilter>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, name, board, query, created_at FROM saved_filters ORDER BY board, name",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let filters = stmt
+        .query_map([], row_to_saved_filter)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(filters)
+}
+
+/// Save `query` under `name` for a board, replacing any filter of the same
+/// name there.  The query must parse.
+#[tauri::command]
+pub fn save_filter(
+    state: State<AppState>,
+    name: String,
+    board: String,
+    query: String,
+) -> CmdResult<SavedFilter> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Filter name is required",
+        )));
+    }
+    if !BOARDS.contains(&board.as_str()) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Unknown board: {board}"
+        ))));
+    }
+    BoardFilter::parse(&query).map_err(to_cmd_err)?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let filter = SavedFilter {
+        id: Uuid::new_v4().to_string(),
+        name,
+        board,
+        query,
+        created_at: Utc::now().to_rfc3339(),
+    };
+    conn.execute(
+        "INSERT INTO saved_filters (id, name, board, query, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(board, name) DO UPDATE SET query = excluded.query",
+        rusqlite::params![
+            filter.id,
+            filter.name,
+            filter.board,
+            filter.query,
+            filter.created_at
+        ],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    // On conflict the existing row keeps its id and creation time.
+    conn.query_row(
+        "SELECT id, name, board, query, created_at FROM saved_filters
+         WHERE board = ?1 AND name = ?2",
+        [&filter.board, &filter.name],
+        row_to_saved_filter,
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+#[tauri::command]
+pub fn delete_saved_filter(state: State<AppState>, id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute("DELETE FROM saved_filters WHERE id = ?1", [&id])
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
@@ -1,28 +1,182 @@
+use crate::commands::board_filter::{BoardFilter, FilterFields};
+use crate::commands::plan_history;
+use crate::commands::projects::load_db_projects;
+use crate::commands::session_search::list_session_files;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
 use crate::models::{
-    ClaudePlan, ClaudeSession, ClaudeTask, ClaudeTaskFile, SessionDetail, SessionMessage,
-    SessionToolCall, SessionTurn,
+    ClaudePlan, ClaudeSession, ClaudeTask, ClaudeTaskFile, FlatClaudeTask, Project, SessionDetail,
+    SessionMessage,
+};
+use crate::services::file_watcher::ClaudeWatcher;
+use crate::state::AppState;
+use crate::utils::write_file_atomic;
+use commander_core::scan::git_remote_identity;
+use commander_core::session;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, State};
+
+pub(crate) use commander_core::session::{
+    agent_state_roots, claude_root, claude_roots, clear_claude_dir_override,
+    correlate_session_project, count_recent_sessions, parse_session_turn,
+    projects_with_sessions_since, read_first_line_cwd, read_session_title, set_claude_dir_override,
+    set_extra_claude_dirs, set_project_claude_dirs,
 };
-use std::path::PathBuf;
 
-fn claude_dir() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("/tmp"))
-        .join(".claude")
+/// (Re)start the file watcher over every Claude root that exists, project
+/// `.claude` directories included.  Called at startup and whenever the set
+/// of roots changes.
+pub(crate) fn restart_claude_watcher(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let roots: Vec<PathBuf> = agent_state_roots()
+        .into_iter()
+        .map(|(_, dir)| dir)
+        .filter(|dir| dir.exists())
+        .collect();
+    if roots.is_empty() {
+        *state.claude_watcher.lock() = None;
+        return;
+    }
+    match ClaudeWatcher::new(app.clone(), roots.clone()) {
+        Ok(watcher) => {
+            *state.claude_watcher.lock() = Some(watcher);
+            log::info!("Watching {:?} for changes", roots);
+            let app = app.clone();
+            std::thread::spawn(move || plan_history::snapshot_plans(&app.state::<AppState>()));
+        }
+        Err(e) => log::warn!("Failed to start file watcher: {}", e),
+    }
+}
+
+/// Look for project-local `.claude/tasks` and `.claude/plans` again and
+/// restart the watcher if any appeared or went away.  Runs at startup and
+/// each time the task or plan list is loaded, so a project that starts
+/// keeping agent state in-repo shows up without a restart.
+pub(crate) fn refresh_project_claude_dirs(app: &AppHandle) -> Result<(), CommanderError> {
+    let projects = {
+        let state = app.state::<AppState>();
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        load_db_projects(conn)?
+    };
+    if set_project_claude_dirs(&projects) {
+        restart_claude_watcher(app);
+    }
+    Ok(())
 }
 
 // ─── Tasks ─────────────────────────────────────────────────────────────────
 
+/// Task teams from every configured Claude root and project `.claude`
+/// directory.  Within a team, tasks the user has reordered come first in
+/// their saved order (`sort_order` set), the rest follow by numeric id.
+#[tauri::command]
+pub fn read_claude_tasks(
+    app: AppHandle,
+    state: State<AppState>,
+) -> CmdResult<Vec<ClaudeTaskFile>> {
+    refresh_project_claude_dirs(&app).map_err(to_cmd_err)?;
+    let mut task_files = read_all_task_files()?;
+    let overrides = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_task_order(conn).map_err(to_cmd_err)?
+    };
+
+    for file in &mut task_files {
+        for task in &mut file.tasks {
+            task.sort_order = overrides
+                .get(&(file.team_id.clone(), task.status.clone(), task.id.clone()))
+                .copied();
+        }
+        file.tasks.sort_by_key(|t| {
+            (
+                t.sort_order.is_none(),
+                t.sort_order,
+                t.id.parse::<u64>().unwrap_or(u64::MAX),
+                t.id.clone(),
+            )
+        });
+    }
+    Ok(task_files)
+}
+
+/// Task teams from every root, without manual ordering.
+pub(crate) fn read_all_task_files() -> CmdResult<Vec<ClaudeTaskFile>> {
+    let mut task_files = Vec::new();
+    for (source, root) in agent_state_roots() {
+        task_files.extend(read_task_files(&root.join("tasks"), &source)?);
+    }
+    Ok(task_files)
+}
+
+type TaskOrderKey = (String, String, String);
+
+fn load_task_order(
+    conn: &rusqlite::Connection,
+) -> Result<HashMap<TaskOrderKey, i64>, CommanderError> {
+    let mut stmt =
+        conn.prepare("SELECT team_id, status, task_id, position FROM task_order_overrides")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(((row.get(0)?, row.get(1)?, row.get(2)?), row.get(3)?))
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(rows)
+}
+
+/// Save the board order of one team's `status` column.  `ordered_ids`
+/// replaces any previous order for that column; task files are not touched,
+/// so the order survives Claude Code rewriting them.  Ids of tasks that
+/// later move to another column are simply ignored there.
 #[tauri::command]
-pub fn read_claude_tasks() -> CmdResult<Vec<ClaudeTaskFile>> {
-    let tasks_dir = claude_dir().join("tasks");
+pub fn set_task_order(
+    state: State<AppState>,
+    team_id: String,
+    status: String,
+    ordered_ids: Vec<String>,
+) -> CmdResult<()> {
+    if !TASK_STATUSES.contains(&status.as_str()) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid task status: {status}"
+        ))));
+    }
+    let mut db = state.db.lock();
+    let conn = db
+        .as_mut()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let tx = conn
+        .transaction()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    tx.execute(
+        "DELETE FROM task_order_overrides WHERE team_id = ?1 AND status = ?2",
+        rusqlite::params![team_id, status],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    for (position, task_id) in ordered_ids.iter().enumerate() {
+        tx.execute(
+            "INSERT OR REPLACE INTO task_order_overrides (team_id, status, task_id, position)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![team_id, status, task_id, position as i64],
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    }
+    tx.commit().map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+pub(crate) fn read_task_files(tasks_dir: &Path, source: &str) -> CmdResult<Vec<ClaudeTaskFile>> {
     if !tasks_dir.exists() {
         return Ok(vec![]);
     }
 
     let mut task_files = Vec::new();
 
-    let entries = std::fs::read_dir(&tasks_dir)
+    let entries = std::fs::read_dir(tasks_dir)
         .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
 
     for entry in entries.filter_map(|e| e.ok()) {
@@ -67,43 +221,7 @@ pub fn read_claude_tasks() -> CmdResult<Vec<ClaudeTaskFile>> {
                 }
             };
 
-            let task = ClaudeTask {
-                id: task_path
-                    .file_stem()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_string(),
-                team_name: json.get("teamName").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                subject: json
-                    .get("subject")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Untitled Task")
-                    .to_string(),
-                description: json
-                    .get("description")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string()),
-                status: json
-                    .get("status")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("pending")
-                    .to_string(),
-                owner: json.get("owner").and_then(|v| v.as_str()).map(|s| s.to_string()),
-                active_form: json
-                    .get("activeForm")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string()),
-                created_at: json
-                    .get("createdAt")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string()),
-                updated_at: json
-                    .get("updatedAt")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string()),
-            };
-
-            tasks.push(task);
+            tasks.push(task_from_json(&task_path, &json, source));
         }
 
         task_files.push(ClaudeTaskFile { team_id, tasks });
@@ -112,18 +230,282 @@ pub fn read_claude_tasks() -> CmdResult<Vec<ClaudeTaskFile>> {
     Ok(task_files)
 }
 
+/// Map a Claude Code task file to a `ClaudeTask`; the id is the file stem.
+fn task_from_json(task_path: &Path, json: &serde_json::Value, source: &str) -> ClaudeTask {
+    ClaudeTask {
+        id: task_path
+            .file_stem()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .to_string(),
+        team_name: json.get("teamName").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        subject: json
+            .get("subject")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Untitled Task")
+            .to_string(),
+        description: json
+            .get("description")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        status: json
+            .get("status")
+            .and_then(|v| v.as_str())
+            .unwrap_or("pending")
+            .to_string(),
+        owner: json.get("owner").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        active_form: json
+            .get("activeForm")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        created_at: json
+            .get("createdAt")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        updated_at: json
+            .get("updatedAt")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        source: source.to_string(),
+        sort_order: None,
+        blocked_by: task_ids(json.get("blockedBy")),
+        blocks: task_ids(json.get("blocks")),
+    }
+}
+
+/// A list of task ids, which Claude Code writes as strings; numbers are
+/// accepted too.
+fn task_ids(value: Option<&serde_json::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|ids| {
+            ids.iter()
+                .filter_map(|id| match id {
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    serde_json::Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// All tasks from every team in one list, optionally narrowed by a board
+/// filter expression (see `BoardFilter`).  Most recently updated first.
+#[tauri::command]
+pub fn get_all_tasks_flat(filter: Option<String>) -> CmdResult<Vec<FlatClaudeTask>> {
+    let filter = BoardFilter::parse_optional(filter.as_deref()).map_err(to_cmd_err)?;
+
+    let mut tasks: Vec<FlatClaudeTask> = read_all_task_files()?
+        .into_iter()
+        .flat_map(|file| {
+            let team_id = file.team_id;
+            file.tasks.into_iter().map(move |task| FlatClaudeTask {
+                team_id: team_id.clone(),
+                task,
+            })
+        })
+        .filter(|flat| {
+            let task = &flat.task;
+            filter.as_ref().is_none_or(|f| {
+                f.matches(&FilterFields {
+                    subject: &task.subject,
+                    description: task.description.as_deref(),
+                    status: &task.status,
+                    owner: task.owner.as_deref(),
+                    team: Some(task.team_name.as_deref().unwrap_or(&flat.team_id)),
+                    created_at: task.created_at.as_deref(),
+                    updated_at: task.updated_at.as_deref(),
+                    ..Default::default()
+                })
+            })
+        })
+        .collect();
+
+    tasks.sort_by(|a, b| b.task.updated_at.cmp(&a.task.updated_at));
+    Ok(tasks)
+}
+
+/// Statuses Claude Code understands in a task file.
+const TASK_STATUSES: &[&str] = &["pending", "in_progress", "completed"];
+
+/// Whether `s` is a single, plain path component (no separators, `..` or root).
+fn is_path_component(s: &str) -> bool {
+    let mut parts = Path::new(s).components();
+    matches!(
+        (parts.next(), parts.next()),
+        (Some(std::path::Component::Normal(_)), None)
+    )
+}
+
+/// `<root>/tasks/<team_id>` for the given Claude root.  `team_id` must be a
+/// single path component so a request cannot escape the tasks directory.
+fn task_team_dir(source: Option<&str>, team_id: &str) -> CmdResult<PathBuf> {
+    if !is_path_component(team_id) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid team id: {team_id}"
+        ))));
+    }
+    Ok(claude_root(source).join("tasks").join(team_id))
+}
+
+/// `<root>/tasks/<team_id>/<task_id>.json`, with both ids validated.
+fn task_file_path(source: Option<&str>, team_id: &str, task_id: &str) -> CmdResult<PathBuf> {
+    if !is_path_component(task_id) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid task id: {task_id}"
+        ))));
+    }
+    Ok(task_team_dir(source, team_id)?.join(format!("{task_id}.json")))
+}
+
+/// Change a task's status and/or owner by rewriting its JSON file in place,
+/// so Claude Code picks the change up.  Fields the app does not know about
+/// are kept; `updatedAt` is set to now.  An empty `owner` clears it.
+#[tauri::command]
+pub fn update_claude_task(
+    team_id: String,
+    task_id: String,
+    status: Option<String>,
+    owner: Option<String>,
+    source: Option<String>,
+) -> CmdResult<ClaudeTask> {
+    if let Some(status) = &status {
+        if !TASK_STATUSES.contains(&status.as_str()) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Unknown task status: {status}"
+            ))));
+        }
+    }
+
+    let path = task_file_path(source.as_deref(), &team_id, &task_id)?;
+    let content = std::fs::read_to_string(&path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let mut json: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    let fields = json.as_object_mut().ok_or_else(|| {
+        to_cmd_err(CommanderError::internal(format!(
+            "Task file is not a JSON object: {}",
+            path.display()
+        )))
+    })?;
+
+    if let Some(status) = status {
+        fields.insert("status".to_string(), status.into());
+    }
+    if let Some(owner) = owner {
+        let owner = owner.trim();
+        if owner.is_empty() {
+            fields.remove("owner");
+        } else {
+            fields.insert("owner".to_string(), owner.into());
+        }
+    }
+    fields.insert(
+        "updatedAt".to_string(),
+        chrono::Utc::now().to_rfc3339().into(),
+    );
+
+    let updated =
+        serde_json::to_string_pretty(&json).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    write_file_atomic(&path, updated)?;
+
+    let source = source.unwrap_or_else(|| session::DEFAULT_CLAUDE_SOURCE.to_string());
+    Ok(task_from_json(&path, &json, &source))
+}
+
+/// Queue a new pending task for an agent team by writing
+/// `<root>/tasks/<team_id>/<id>.json`.  The id is one past the highest
+/// numeric id in the team, matching how Claude Code numbers tasks; the team
+/// directory is created when it does not exist yet.
+#[tauri::command]
+pub fn create_claude_task(
+    team_id: String,
+    subject: String,
+    description: Option<String>,
+    source: Option<String>,
+) -> CmdResult<ClaudeTask> {
+    let subject = subject.trim();
+    if subject.is_empty() {
+        return Err(to_cmd_err(CommanderError::internal("Task subject is required")));
+    }
+
+    let team_dir = task_team_dir(source.as_deref(), &team_id)?;
+    std::fs::create_dir_all(&team_dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+
+    let mut next_id = 1u64;
+    let mut team_name = None;
+    for entry in std::fs::read_dir(&team_dir)
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(id) = path.file_stem().and_then(|s| s.to_str()?.parse::<u64>().ok()) {
+            next_id = next_id.max(id + 1);
+        }
+        if team_name.is_none() {
+            team_name = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+                .and_then(|v| v.get("teamName")?.as_str().map(str::to_string));
+        }
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut json = serde_json::json!({
+        "subject": subject,
+        "status": "pending",
+        "createdAt": now,
+        "updatedAt": now,
+    });
+    if let Some(description) = description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        json["description"] = description.into();
+    }
+    if let Some(team_name) = team_name {
+        json["teamName"] = team_name.into();
+    }
+
+    let path = team_dir.join(format!("{next_id}.json"));
+    let content =
+        serde_json::to_string_pretty(&json).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    write_file_atomic(&path, content)?;
+
+    let source = source.unwrap_or_else(|| session::DEFAULT_CLAUDE_SOURCE.to_string());
+    Ok(task_from_json(&path, &json, &source))
+}
+
 // ─── Plans ─────────────────────────────────────────────────────────────────
 
+/// Plans from every configured Claude root and project `.claude`
+/// directory, most recently modified first.
 #[tauri::command]
-pub fn list_claude_plans() -> CmdResult<Vec<ClaudePlan>> {
-    let plans_dir = claude_dir().join("plans");
+pub fn list_claude_plans(app: AppHandle) -> CmdResult<Vec<ClaudePlan>> {
+    refresh_project_claude_dirs(&app).map_err(to_cmd_err)?;
+    read_all_plans()
+}
+
+/// `list_claude_plans` without re-scanning projects.
+pub(crate) fn read_all_plans() -> CmdResult<Vec<ClaudePlan>> {
+    let mut plans = Vec::new();
+    for (source, root) in agent_state_roots() {
+        plans.extend(read_plans(&root.join("plans"), &source)?);
+    }
+
+    // Sort by modified_at descending
+    plans.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    Ok(plans)
+}
+
+fn read_plans(plans_dir: &Path, source: &str) -> CmdResult<Vec<ClaudePlan>> {
     if !plans_dir.exists() {
         return Ok(vec![]);
     }
 
     let mut plans = Vec::new();
 
-    let entries = std::fs::read_dir(&plans_dir)
+    let entries = std::fs::read_dir(plans_dir)
         .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
 
     for entry in entries.filter_map(|e| e.ok()) {
@@ -161,14 +543,7 @@ pub fn list_claude_plans() -> CmdResult<Vec<ClaudePlan>> {
             .take(200)
             .collect();
 
-        let modified_at = path
-            .metadata()
-            .ok()
-            .and_then(|m| m.modified().ok())
-            .map(|t| {
-                let dt: chrono::DateTime<chrono::Utc> = t.into();
-                dt.to_rfc3339()
-            });
+        let modified_at = modified_at(&path);
 
         plans.push(ClaudePlan {
             id: filename.trim_end_matches(".md").to_string(),
@@ -177,103 +552,289 @@ pub fn list_claude_plans() -> CmdResult<Vec<ClaudePlan>> {
             preview,
             content,
             modified_at,
+            source: source.to_string(),
         });
     }
 
-    // Sort by modified_at descending
-    plans.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
     Ok(plans)
 }
 
+/// Read a plan from the root labelled `source` (the primary root if unset).
 #[tauri::command]
-pub fn read_claude_plan(filename: String) -> CmdResult<String> {
-    let path = claude_dir().join("plans").join(&filename);
+pub fn read_claude_plan(filename: String, source: Option<String>) -> CmdResult<String> {
+    let path = claude_root(source.as_deref()).join("plans").join(&filename);
     std::fs::read_to_string(&path)
         .map_err(|e| to_cmd_err(CommanderError::io(e)))
 }
 
-// ─── Sessions ──────────────────────────────────────────────────────────────
-
+/// Overwrite (or create) a plan atomically and return its new
+/// `modified_at`.  Pass the `modified_at` the content was loaded with as
+/// `expected_modified_at`: if the file has changed since, nothing is written
+/// and a `CONFLICT` error is returned.  `None` skips the check, e.g. to
+/// overwrite after a conflict.
 #[tauri::command]
-pub fn read_claude_sessions() -> CmdResult<Vec<ClaudeSession>> {
-    let projects_dir = claude_dir().join("projects");
-    if !projects_dir.exists() {
-        return Ok(vec![]);
+pub fn save_claude_plan(
+    filename: String,
+    content: String,
+    expected_modified_at: Option<String>,
+    source: Option<String>,
+) -> CmdResult<String> {
+    if !filename.ends_with(".md")
+        || filename.starts_with('.')
+        || filename.contains(['/', '\\'])
+    {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid plan filename: {filename}"
+        ))));
+    }
+    let dir = claude_root(source.as_deref()).join("plans");
+    let path = dir.join(&filename);
+
+    if let Some(expected) = expected_modified_at {
+        if modified_at(&path).is_some_and(|current| current != expected) {
+            return Err(to_cmd_err(CommanderError::Conflict {
+                path: path.to_string_lossy().to_string(),
+            }));
+        }
     }
 
-    let mut sessions = Vec::new();
+    std::fs::create_dir_all(&dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    write_file_atomic(&path, content)?;
+    modified_at(&path).ok_or_else(|| {
+        to_cmd_err(CommanderError::FileNotFound {
+            path: path.to_string_lossy().to_string(),
+        })
+    })
+}
 
-    let entries = std::fs::read_dir(&projects_dir)
-        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+/// File mtime as RFC 3339, the form `ClaudePlan::modified_at` uses.
+pub(crate) fn modified_at(path: &Path) -> Option<String> {
+    let modified = path.metadata().ok()?.modified().ok()?;
+    Some(chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339())
+}
 
-    for entry in entries.filter_map(|e| e.ok()) {
-        let project_dir = entry.path();
-        if !project_dir.is_dir() {
-            continue;
+// ─── Sessions ──────────────────────────────────────────────────────────────
+
+/// Sessions from every configured Claude root, most recent first, each with
+/// the project it belongs to (see `link_session_projects`).  Per-file
+/// metadata is cached in `session_meta_cache` and only recomputed for
+/// transcripts whose mtime or size changed.
+#[tauri::command]
+pub fn read_claude_sessions(state: State<AppState>) -> CmdResult<Vec<ClaudeSession>> {
+    let (known_projects, links, cache) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        (
+            load_db_projects(conn).map_err(to_cmd_err)?,
+            load_session_links(conn).map_err(to_cmd_err)?,
+            load_session_meta(conn).map_err(to_cmd_err)?,
+        )
+    }; // DB lock released before walking the session files
+
+    let (mut sessions, fresh, seen) = read_sessions(&cache);
+    let stale: Vec<String> = cache.into_keys().filter(|p| !seen.contains(p)).collect();
+
+    let new_links = link_session_projects(&mut sessions, &known_projects, &links);
+    if !new_links.is_empty() || !fresh.is_empty() || !stale.is_empty() {
+        let mut db = state.db.lock();
+        if let Some(conn) = db.as_mut() {
+            if let Err(e) = save_session_meta(conn, &fresh, &stale) {
+                log::warn!("failed to update session metadata cache: {}", e);
+            }
+            for (session_id, project_id) in &new_links {
+                let _ = conn.execute(
+                    "INSERT OR IGNORE INTO session_project_links (session_id, project_id)
+                     VALUES (?1, ?2)",
+                    rusqlite::params![session_id, project_id],
+                );
+            }
         }
+    }
 
-        let project_key = project_dir
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("")
-            .to_string();
+    // Sort by last activity
+    sessions.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
+    Ok(sessions)
+}
 
-        // Each .jsonl file is a session
-        let session_entries = match std::fs::read_dir(&project_dir) {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
+/// Persisted session → project links.  A session linked to several
+/// projects (e.g. after a merge left a stale row) keeps the last one read.
+fn load_session_links(
+    conn: &rusqlite::Connection,
+) -> Result<HashMap<String, String>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT session_id, project_id FROM session_project_links
+         WHERE project_id IS NOT NULL",
+    )?;
+    let links = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(links)
+}
 
-        for session_entry in session_entries.filter_map(|e| e.ok()) {
-            let session_path = session_entry.path();
-            if session_path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
-                continue;
-            }
+/// Fill `project_id` on each session, in order of preference:
+///
+/// 1. a persisted link to a project that still exists, so sessions stay
+///    attached after the project directory is renamed or moved;
+/// 2. the project whose path contains the session `cwd`;
+/// 3. the project whose `identity_key` matches the git remote of the `cwd`,
+///    which catches a repo that was re-cloned or moved elsewhere.
+///
+/// Returns the `(session_id, project_id)` pairs found by 2 and 3, to be
+/// persisted.
+fn link_session_projects(
+    sessions: &mut [ClaudeSession],
+    projects: &[Project],
+    links: &HashMap<String, String>,
+) -> Vec<(String, String)> {
+    let known: HashSet<&str> = projects.iter().map(|p| p.id.as_str()).collect();
+    let by_identity: HashMap<&str, &str> = projects
+        .iter()
+        .filter_map(|p| Some((p.identity_key.as_deref()?, p.id.as_str())))
+        .collect();
+    let mut identity_cache: HashMap<String, Option<String>> = HashMap::new();
+    let mut new_links = Vec::new();
 
-            let session_id = session_path
-                .file_stem()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
+    for session in sessions.iter_mut() {
+        if let Some(id) = links.get(&session.id).filter(|id| known.contains(id.as_str())) {
+            session.project_id = Some(id.clone());
+            continue;
+        }
+        let Some(cwd) = session.cwd.as_deref() else {
+            continue;
+        };
+        let project_id = correlate_session_project(cwd, projects).or_else(|| {
+            identity_cache
+                .entry(cwd.to_string())
+                .or_insert_with(|| {
+                    let key = git_remote_identity(Path::new(cwd))?;
+                    by_identity.get(key.as_str()).map(|id| id.to_string())
+                })
+                .clone()
+        });
+        if let Some(id) = &project_id {
+            new_links.push((session.id.clone(), id.clone()));
+        }
+        session.project_id = project_id;
+    }
+    new_links
+}
 
-            // Read first line to get cwd
-            let cwd = read_first_line_cwd(&session_path);
+/// Cached per-transcript metadata, keyed by path.
+struct SessionMeta {
+    mtime: i64,
+    size: i64,
+    cwd: Option<String>,
+    title: Option<String>,
+    message_count: usize,
+}
 
-            // Count messages
-            let message_count = count_jsonl_lines(&session_path);
+fn load_session_meta(
+    conn: &rusqlite::Connection,
+) -> Result<HashMap<String, SessionMeta>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT path, mtime, size, cwd, title, message_count FROM session_meta_cache",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                SessionMeta {
+                    mtime: row.get(1)?,
+                    size: row.get(2)?,
+                    cwd: row.get(3)?,
+                    title: row.get(4)?,
+                    message_count: row.get::<_, i64>(5)? as usize,
+                },
+            ))
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(rows)
+}
 
-            // Last modified
-            let last_message_at = session_path
-                .metadata()
-                .ok()
-                .and_then(|m| m.modified().ok())
-                .map(|t| {
-                    let dt: chrono::DateTime<chrono::Utc> = t.into();
-                    dt.to_rfc3339()
-                });
-
-            sessions.push(ClaudeSession {
-                id: session_id,
-                project_key: project_key.clone(),
-                cwd,
-                message_count,
-                last_message_at,
-                project_id: None, // correlated on the frontend
-            });
-        }
+/// One `ClaudeSession` per transcript.  Files whose mtime and size match
+/// `cache` are not opened; the rest are read for cwd, title and line
+/// count and returned as new cache entries, along with every path seen.
+fn read_sessions(
+    cache: &HashMap<String, SessionMeta>,
+) -> (Vec<ClaudeSession>, Vec<(String, SessionMeta)>, HashSet<String>) {
+    let mut sessions = Vec::new();
+    let mut fresh = Vec::new();
+    let mut seen = HashSet::new();
+    for file in list_session_files() {
+        let key = file.path.to_string_lossy().to_string();
+        seen.insert(key.clone());
+        let meta = match cache.get(&key) {
+            Some(meta) if meta.mtime == file.mtime && meta.size == file.size => meta,
+            _ => {
+                fresh.push((
+                    key,
+                    SessionMeta {
+                        mtime: file.mtime,
+                        size: file.size,
+                        cwd: read_first_line_cwd(&file.path),
+                        title: read_session_title(&file.path),
+                        message_count: count_jsonl_lines(&file.path),
+                    },
+                ));
+                &fresh.last().expect("just pushed").1
+            }
+        };
+        sessions.push(ClaudeSession {
+            id: file.session_id,
+            project_key: file.project_key,
+            cwd: meta.cwd.clone(),
+            title: meta.title.clone(),
+            message_count: meta.message_count,
+            last_message_at: chrono::DateTime::from_timestamp(file.mtime, 0)
+                .map(|t| t.to_rfc3339()),
+            project_id: None,
+            source: file.source,
+        });
     }
+    (sessions, fresh, seen)
+}
 
-    // Sort by last activity
-    sessions.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
-    Ok(sessions)
+/// Store the entries `read_sessions` recomputed and drop the `stale` ones
+/// whose transcript no longer exists.
+fn save_session_meta(
+    conn: &mut rusqlite::Connection,
+    fresh: &[(String, SessionMeta)],
+    stale: &[String],
+) -> Result<(), CommanderError> {
+    let tx = conn.transaction()?;
+    for (path, meta) in fresh {
+        tx.execute(
+            "INSERT OR REPLACE INTO session_meta_cache
+             (path, mtime, size, cwd, title, message_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![
+                path,
+                meta.mtime,
+                meta.size,
+                meta.cwd,
+                meta.title,
+                meta.message_count as i64
+            ],
+        )?;
+    }
+    for path in stale {
+        tx.execute("DELETE FROM session_meta_cache WHERE path = ?1", [path])?;
+    }
+    tx.commit()?;
+    Ok(())
 }
 
 #[tauri::command]
 pub fn read_session_messages(
     project_key: String,
     session_id: String,
+    source: Option<String>,
 ) -> CmdResult<Vec<SessionMessage>> {
-    let path = claude_dir()
+    let path = claude_root(source.as_deref())
         .join("projects")
         .join(&project_key)
         .join(format!("{}.jsonl", session_id));
@@ -327,134 +888,33 @@ pub fn read_session_messages(
 pub fn read_claude_session(
     project_key: String,
     session_id: String,
+    source: Option<String>,
 ) -> CmdResult<SessionDetail> {
-    let path = claude_dir()
+    let path = claude_root(source.as_deref())
         .join("projects")
         .join(&project_key)
         .join(format!("{}.jsonl", session_id));
 
-    use std::io::BufRead;
-    let file = std::fs::File::open(&path)
-        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
-
     const MAX_TURNS: usize = 500;
 
-    let lines: Vec<String> = std::io::BufReader::new(file)
-        .lines()
-        .filter_map(|l| l.ok())
-        .filter(|l| !l.trim().is_empty())
-        .collect();
-
-    let total_count = lines.len();
-
-    let turns: Vec<SessionTurn> = lines
-        .into_iter()
-        .take(MAX_TURNS)
-        .filter_map(|line| parse_session_turn(&line))
-        .collect();
-
-    Ok(SessionDetail { turns, total_count })
+    load_session_detail(&path, MAX_TURNS)
 }
 
-fn parse_session_turn(line: &str) -> Option<SessionTurn> {
-    let v: serde_json::Value = serde_json::from_str(line).ok()?;
-
-    let msg_type = v["type"].as_str()?;
-    // Only process "user" and "assistant" message types
-    if msg_type != "user" && msg_type != "assistant" {
-        return None;
-    }
-
-    let uuid = v["uuid"].as_str().unwrap_or("").to_string();
-    let timestamp = v["timestamp"].as_str().unwrap_or("").to_string();
-    let message = &v["message"];
-    let role = msg_type.to_string();
-
-    match msg_type {
-        "user" => {
-            // User content can be a plain string or an array of content blocks
-            let content = match &message["content"] {
-                serde_json::Value::String(s) => s.clone(),
-                serde_json::Value::Array(blocks) => {
-                    // Collect text blocks; skip tool_result blocks for the text field
-                    blocks
-                        .iter()
-                        .filter(|b| b["type"].as_str() == Some("text"))
-                        .filter_map(|b| b["text"].as_str())
-                        .collect::<Vec<_>>()
-                        .join("")
-                }
-                _ => return None,
-            };
-
-            if content.is_empty() {
-                // May still be a tool_result only turn — skip silently
-                return None;
-            }
-
-            Some(SessionTurn {
-                uuid,
-                role,
-                content,
-                timestamp,
-                tool_calls: vec![],
-            })
-        }
-        "assistant" => {
-            let blocks = message["content"].as_array()?;
-
-            // Extract plain text from text blocks
-            let content: String = blocks
-                .iter()
-                .filter(|b| b["type"].as_str() == Some("text"))
-                .filter_map(|b| b["text"].as_str())
-                .collect::<Vec<_>>()
-                .join("");
-
-            // Extract tool_use blocks
-            let tool_calls: Vec<SessionToolCall> = blocks
-                .iter()
-                .filter(|b| b["type"].as_str() == Some("tool_use"))
-                .map(|b| {
-                    let id = b["id"].as_str().unwrap_or("").to_string();
-                    let name = b["name"].as_str().unwrap_or("unknown").to_string();
-                    let input = serde_json::to_string(&b["input"])
-                        .unwrap_or_else(|_| "{}".to_string());
-                    SessionToolCall {
-                        id,
-                        name,
-                        input,
-                        output: None,
-                    }
-                })
-                .collect();
-
-            // Skip turns that have neither text nor tool calls
-            if content.is_empty() && tool_calls.is_empty() {
-                return None;
-            }
+/// An image or document extracted by `read_claude_session`, as a `data:`
+/// URL.  Paths outside the extraction directory are refused.
+#[tauri::command]
+pub fn read_session_attachment(path: String) -> CmdResult<String> {
+    session::attachment_data_url(Path::new(&path)).map_err(to_cmd_err)
+}
 
-            Some(SessionTurn {
-                uuid,
-                role,
-                content,
-                timestamp,
-                tool_calls,
-            })
-        }
-        _ => None,
-    }
+/// Parse up to `max_turns` lines of a session file into typed turns.
+pub(crate) fn load_session_detail(path: &Path, max_turns: usize) -> CmdResult<SessionDetail> {
+    session::load_session_detail(path, max_turns).map_err(to_cmd_err)
 }
 
-fn read_first_line_cwd(path: &std::path::Path) -> Option<String> {
-    use std::io::BufRead;
-    let file = std::fs::File::open(path).ok()?;
-    let reader = std::io::BufReader::new(file);
-    let first_line = reader.lines().next()?.ok()?;
-    let json: serde_json::Value = serde_json::from_str(&first_line).ok()?;
-    json.get("cwd")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string())
+/// Locate `<session_id>.jsonl` under any project directory of any Claude root.
+pub(crate) fn find_session_file(session_id: &str) -> CmdResult<PathBuf> {
+    session::find_session_file(session_id).map_err(to_cmd_err)
 }
 
 fn count_jsonl_lines(path: &std::path::Path) -> usize {
@@ -0,0 +1,88 @@
+use crate::commands::claude::claude_roots;
+use crate::error::CmdResult;
+use crate::models::{ClaudeHistoryEntry, ClaudeHistoryPage};
+use serde_json::Value;
+use std::io::BufRead;
+use std::path::Path;
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Prompts from every root's `history.jsonl`, newest first.  `query` keeps
+/// entries whose prompt or project contains every whitespace-separated term
+/// (case-insensitive); `offset` and `limit` (default 50) page through the
+/// matches.
+#[tauri::command]
+pub fn read_claude_history(
+    limit: Option<usize>,
+    offset: Option<usize>,
+    query: Option<String>,
+) -> CmdResult<ClaudeHistoryPage> {
+    let terms: Vec<String> = query
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut entries: Vec<(i64, ClaudeHistoryEntry)> = claude_roots()
+        .into_iter()
+        .flat_map(|(source, root)| read_history_file(&root.join("history.jsonl"), &source))
+        .filter(|(_, entry)| matches_terms(entry, &terms))
+        .collect();
+    entries.sort_by_key(|(millis, _)| std::cmp::Reverse(*millis));
+
+    let offset = offset.unwrap_or(0);
+    Ok(ClaudeHistoryPage {
+        total: entries.len(),
+        offset,
+        entries: entries
+            .into_iter()
+            .skip(offset)
+            .take(limit.unwrap_or(DEFAULT_PAGE_SIZE))
+            .map(|(_, entry)| entry)
+            .collect(),
+    })
+}
+
+/// Entries of one history file with their millisecond timestamps, in file
+/// order.  Unreadable lines are skipped.
+fn read_history_file(path: &Path, source: &str) -> Vec<(i64, ClaudeHistoryEntry)> {
+    let Ok(file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+    std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| {
+            let v: Value = serde_json::from_str(&line).ok()?;
+            let display = v.get("display")?.as_str()?.to_string();
+            let millis = v.get("timestamp").and_then(|t| t.as_i64()).unwrap_or(0);
+            Some((
+                millis,
+                ClaudeHistoryEntry {
+                    display,
+                    project: v.get("project").and_then(|p| p.as_str()).map(String::from),
+                    timestamp: chrono::DateTime::from_timestamp_millis(millis)
+                        .filter(|_| millis > 0)
+                        .map(|t| t.to_rfc3339()),
+                    source: source.to_string(),
+                    pasted_count: v
+                        .get("pastedContents")
+                        .and_then(|p| p.as_object())
+                        .map_or(0, |p| p.len()),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn matches_terms(entry: &ClaudeHistoryEntry, terms: &[String]) -> bool {
+    if terms.is_empty() {
+        return true;
+    }
+    let display = entry.display.to_lowercase();
+    let project = entry.project.as_deref().unwrap_or_default().to_lowercase();
+    terms
+        .iter()
+        .all(|term| display.contains(term) || project.contains(term))
+}
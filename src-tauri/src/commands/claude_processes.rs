@@ -0,0 +1,92 @@
+use crate::commands::claude::correlate_session_project;
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::ClaudeProcess;
+use crate::state::AppState;
+use commander_core::process::{is_claude_cli, parse_ps_line};
+use std::collections::HashMap;
+use std::process::Command;
+use tauri::State;
+
+/// Running Claude Code CLI processes with their working directory and the
+/// project it falls in.  Uses `ps`, plus `/proc` or `lsof` for the cwd, so
+/// on Windows the list is always empty.
+#[tauri::command]
+pub fn get_active_claude_processes(state: State<AppState>) -> CmdResult<Vec<ClaudeProcess>> {
+    let projects = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_db_projects(conn).map_err(to_cmd_err)?
+    };
+    let mut processes = list_claude_processes();
+    for process in &mut processes {
+        process.project_id = process
+            .cwd
+            .as_deref()
+            .and_then(|cwd| correlate_session_project(cwd, &projects));
+    }
+    Ok(processes)
+}
+
+/// Claude processes without `project_id`, oldest first.
+pub(crate) fn list_claude_processes() -> Vec<ClaudeProcess> {
+    let output = match Command::new("ps")
+        .args(["-axww", "-o", "pid=,lstart=,command="])
+        .output()
+    {
+        Ok(o) if o.status.success() => o,
+        Ok(_) | Err(_) => return Vec::new(),
+    };
+    let mut processes: Vec<ClaudeProcess> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_ps_line)
+        .filter(|p| is_claude_cli(&p.command))
+        .collect();
+
+    let mut cwds = process_cwds(&processes.iter().map(|p| p.pid).collect::<Vec<_>>());
+    for process in &mut processes {
+        process.cwd = cwds.remove(&process.pid);
+    }
+    processes.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    processes
+}
+
+fn process_cwds(pids: &[u32]) -> HashMap<u32, String> {
+    if pids.is_empty() {
+        return HashMap::new();
+    }
+    if cfg!(target_os = "linux") {
+        return pids
+            .iter()
+            .filter_map(|pid| {
+                let cwd = std::fs::read_link(format!("/proc/{pid}/cwd")).ok()?;
+                Some((*pid, cwd.to_string_lossy().to_string()))
+            })
+            .collect();
+    }
+
+    // `-F pn` prints a `p<pid>` line followed by an `n<path>` line per process.
+    let pid_list = pids
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let Ok(output) = Command::new("lsof")
+        .args(["-a", "-d", "cwd", "-F", "pn", "-p", &pid_list])
+        .output()
+    else {
+        return HashMap::new();
+    };
+    let mut cwds = HashMap::new();
+    let mut current = None;
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(pid) = line.strip_prefix('p') {
+            current = pid.parse().ok();
+        } else if let (Some(path), Some(pid)) = (line.strip_prefix('n'), current) {
+            cwds.insert(pid, path.to_string());
+        }
+    }
+    cwds
+}
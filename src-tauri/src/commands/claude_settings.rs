@@ -0,0 +1,156 @@
+use crate::commands::claude::{claude_root, modified_at};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::ClaudeSettingsFile;
+use crate::utils::write_file_atomic;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+
+const PERMISSION_LISTS: &[&str] = &["allow", "deny", "ask", "additionalDirectories"];
+
+/// Claude Code's `settings.json` in the root labelled `source` (the primary
+/// root if unset).  A missing file reads as `{}`.
+#[tauri::command]
+pub fn read_claude_settings(source: Option<String>) -> CmdResult<ClaudeSettingsFile> {
+    let path = settings_path(source.as_deref());
+    let settings = load_settings(&path).map_err(to_cmd_err)?;
+    Ok(ClaudeSettingsFile {
+        path: path.to_string_lossy().to_string(),
+        exists: path.is_file(),
+        modified_at: modified_at(&path),
+        settings: Value::Object(settings),
+    })
+}
+
+/// Apply `patch` to `settings.json` and return the file as written.  Each
+/// top-level key in `patch` replaces that key (`null` removes it); other
+/// keys, including ones Commander doesn't know, are left alone.
+/// `permissions`, `hooks` and `model` are checked against the shape Claude
+/// Code expects, and nothing is written if any check fails or if the file
+/// changed since `expected_modified_at` (a `CONFLICT` error).
+#[tauri::command]
+pub fn update_claude_settings(
+    patch: Value,
+    expected_modified_at: Option<String>,
+    source: Option<String>,
+) -> CmdResult<ClaudeSettingsFile> {
+    let Value::Object(patch) = patch else {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Settings patch must be a JSON object",
+        )));
+    };
+    let path = settings_path(source.as_deref());
+
+    if let Some(expected) = expected_modified_at {
+        if modified_at(&path).is_some_and(|current| current != expected) {
+            return Err(to_cmd_err(CommanderError::Conflict {
+                path: path.to_string_lossy().to_string(),
+            }));
+        }
+    }
+
+    let mut settings = load_settings(&path).map_err(to_cmd_err)?;
+    for (key, value) in patch {
+        if value.is_null() {
+            settings.remove(&key);
+        } else {
+            settings.insert(key, value);
+        }
+    }
+    validate_settings(&settings).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+
+    let mut content =
+        serde_json::to_string_pretty(&settings).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    content.push('\n');
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+    write_file_atomic(&path, content)?;
+
+    Ok(ClaudeSettingsFile {
+        path: path.to_string_lossy().to_string(),
+        exists: true,
+        modified_at: modified_at(&path),
+        settings: Value::Object(settings),
+    })
+}
+
+fn settings_path(source: Option<&str>) -> PathBuf {
+    claude_root(source).join("settings.json")
+}
+
+/// The current settings object.  A file that exists but is not a JSON
+/// object is an error rather than `{}`, so a save never clobbers it.
+fn load_settings(path: &std::path::Path) -> Result<Map<String, Value>, CommanderError> {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Map::new()),
+        Err(e) => return Err(e.into()),
+    };
+    match serde_json::from_str(&raw) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(CommanderError::parse(format!(
+            "{} is not a JSON object",
+            path.display()
+        ))),
+        Err(e) => Err(CommanderError::parse(format!("{}: {e}", path.display()))),
+    }
+}
+
+fn validate_settings(settings: &Map<String, Value>) -> Result<(), String> {
+    if let Some(model) = settings.get("model") {
+        if model.as_str().is_none_or(|m| m.trim().is_empty()) {
+            return Err("model must be a non-empty string".to_string());
+        }
+    }
+
+    if let Some(permissions) = settings.get("permissions") {
+        let permissions = permissions
+            .as_object()
+            .ok_or("permissions must be an object")?;
+        for list in PERMISSION_LISTS {
+            if let Some(rules) = permissions.get(*list) {
+                let valid = rules
+                    .as_array()
+                    .is_some_and(|rules| rules.iter().all(|r| r.is_string()));
+                if !valid {
+                    return Err(format!("permissions.{list} must be a list of strings"));
+                }
+            }
+        }
+        if let Some(mode) = permissions.get("defaultMode") {
+            if !mode.is_string() {
+                return Err("permissions.defaultMode must be a string".to_string());
+            }
+        }
+    }
+
+    // { "<Event>": [ { "matcher"?: string, "hooks": [ { "type", "command"? } ] } ] }
+    if let Some(hooks) = settings.get("hooks") {
+        let hooks = hooks.as_object().ok_or("hooks must be an object")?;
+        for (event, matchers) in hooks {
+            let matchers = matchers
+                .as_array()
+                .ok_or_else(|| format!("hooks.{event} must be a list"))?;
+            for matcher in matchers {
+                if matcher.get("matcher").is_some_and(|m| !m.is_string()) {
+                    return Err(format!("hooks.{event}: matcher must be a string"));
+                }
+                let entries = matcher
+                    .get("hooks")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| format!("hooks.{event}: each entry needs a hooks list"))?;
+                for entry in entries {
+                    if !entry.get("type").is_some_and(Value::is_string) {
+                        return Err(format!("hooks.{event}: every hook needs a type"));
+                    }
+                    if entry.get("type").and_then(Value::as_str) == Some("command")
+                        && !entry.get("command").is_some_and(Value::is_string)
+                    {
+                        return Err(format!("hooks.{event}: command hooks need a command"));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,552 @@
+use crate::commands::claude::{correlate_session_project, read_first_line_cwd};
+use crate::commands::projects::load_db_projects;
+use crate::commands::session_search::list_session_files;
+use crate::commands::settings::{get_setting, set_setting};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{BudgetThreshold, CostBreakdown, CostSummary, UsageBucket, UsageStats};
+use crate::services::notifications;
+use crate::state::AppState;
+use crate::utils::parse_timestamp;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufRead;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Emitted with a `BudgetThreshold` when spend crosses a threshold.
+pub const EVENT_BUDGET_THRESHOLD: &str = "budget-threshold";
+
+/// `(period, budget setting, setting holding the "<period start>:<percent>"
+/// threshold last warned about)`, so each threshold fires once per period.
+const BUDGET_PERIODS: &[(&str, &str, &str)] = &[
+    ("monthly", "monthly_budget_usd", "budget_warned"),
+    ("weekly", "weekly_budget_usd", "budget_warned_weekly"),
+];
+
+/// Percentages of a budget that raise a `budget` notification.
+const BUDGET_THRESHOLDS: &[u32] = &[50, 80, 100];
+
+/// USD per million tokens.
+#[derive(Clone, Copy)]
+pub(crate) struct ModelPricing {
+    pub input: f64,
+    pub output: f64,
+    pub cache_write: f64,
+    pub cache_read: f64,
+}
+
+/// List prices by model-name fragment, most specific first.  Unknown models
+/// are priced like Sonnet so they still show up as a reasonable estimate.
+const PRICING: &[(&str, ModelPricing)] = &[
+    ("opus-4-5", price(5.0, 25.0)),
+    ("opus-4-6", price(5.0, 25.0)),
+    ("opus", price(15.0, 75.0)),
+    ("haiku-4", price(1.0, 5.0)),
+    ("haiku", price(0.8, 4.0)),
+    ("sonnet", price(3.0, 15.0)),
+];
+
+const fn price(input: f64, output: f64) -> ModelPricing {
+    ModelPricing {
+        input,
+        output,
+        cache_write: input * 1.25,
+        cache_read: input * 0.1,
+    }
+}
+
+pub(crate) fn model_pricing(model: &str) -> ModelPricing {
+    PRICING
+        .iter()
+        .find(|(fragment, _)| model.contains(fragment))
+        .map_or(price(3.0, 15.0), |(_, p)| *p)
+}
+
+/// Token counts for one session, day and model.
+#[derive(Default)]
+pub(crate) struct UsageTotals {
+    pub input: i64,
+    pub output: i64,
+    pub cache_creation: i64,
+    pub cache_read: i64,
+}
+
+impl UsageTotals {
+    pub(crate) fn cost_usd(&self, model: &str) -> f64 {
+        let p = model_pricing(model);
+        (self.input as f64 * p.input
+            + self.output as f64 * p.output
+            + self.cache_creation as f64 * p.cache_write
+            + self.cache_read as f64 * p.cache_read)
+            / 1_000_000.0
+    }
+}
+
+/// Sum the `message.usage` blocks of a transcript by local day and model.
+/// Claude Code writes one line per content block with the same message id
+/// and usage, so each message is counted once.
+pub(crate) fn read_usage(
+    path: &Path,
+) -> Result<BTreeMap<(String, String), UsageTotals>, CommanderError> {
+    let file = std::fs::File::open(path).map_err(CommanderError::io)?;
+    let mut by_message: HashMap<String, (String, String, UsageTotals)> = HashMap::new();
+    for (i, line) in std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .enumerate()
+    {
+        if !line.contains("\"usage\"") {
+            continue;
+        }
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        let message = &v["message"];
+        let usage = &message["usage"];
+        let model = message["model"].as_str().unwrap_or("unknown");
+        // Local error messages are recorded under a pseudo-model, not billed.
+        if !usage.is_object() || model == "<synthetic>" {
+            continue;
+        }
+        let Some(day) = v["timestamp"].as_str().and_then(parse_timestamp).map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d")
+                .to_string()
+        }) else {
+            continue;
+        };
+        let tokens = |key: &str| usage[key].as_i64().unwrap_or(0);
+        let id = message["id"]
+            .as_str()
+            .map_or_else(|| format!("line-{i}"), str::to_string);
+        by_message.insert(
+            id,
+            (
+                day,
+                model.to_string(),
+                UsageTotals {
+                    input: tokens("input_tokens"),
+                    output: tokens("output_tokens"),
+                    cache_creation: tokens("cache_creation_input_tokens"),
+                    cache_read: tokens("cache_read_input_tokens"),
+                },
+            ),
+        );
+    }
+
+    let mut totals: BTreeMap<(String, String), UsageTotals> = BTreeMap::new();
+    for (day, model, usage) in by_message.into_values() {
+        let entry = totals.entry((day, model)).or_default();
+        entry.input += usage.input;
+        entry.output += usage.output;
+        entry.cache_creation += usage.cache_creation;
+        entry.cache_read += usage.cache_read;
+    }
+    Ok(totals)
+}
+
+/// Re-cost every transcript that changed since the last pass.  Ledger rows
+/// outlive their transcripts: deleting a session does not refund it.
+pub(crate) fn update_cost_ledger(state: &AppState) -> Result<usize, CommanderError> {
+    let files = list_session_files();
+    let known: HashMap<String, (i64, i64)> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let mut stmt = conn.prepare("SELECT path, mtime, size FROM cost_ledger_files")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    let mut updated = 0;
+    for file in &files {
+        let key = file.path.to_string_lossy().to_string();
+        if known.get(&key) == Some(&(file.mtime, file.size)) {
+            continue;
+        }
+        let usage = match read_usage(&file.path) {
+            Ok(usage) => usage,
+            Err(e) => {
+                log::warn!("skipped costing {}: {}", file.path.display(), e);
+                continue;
+            }
+        };
+        let cwd = read_first_line_cwd(&file.path);
+
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM cost_ledger WHERE session_id = ?1",
+            [&file.session_id],
+        )?;
+        for ((day, model), totals) in &usage {
+            tx.execute(
+                "INSERT INTO cost_ledger
+                     (session_id, day, model, project_key, cwd, input_tokens, output_tokens,
+                      cache_creation_tokens, cache_read_tokens, cost_usd)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                rusqlite::params![
+                    file.session_id,
+                    day,
+                    model,
+                    file.project_key,
+                    cwd,
+                    totals.input,
+                    totals.output,
+                    totals.cache_creation,
+                    totals.cache_read,
+                    totals.cost_usd(model),
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO cost_ledger_files (path, mtime, size) VALUES (?1, ?2, ?3)",
+            rusqlite::params![key, file.mtime, file.size],
+        )?;
+        tx.commit()?;
+        updated += 1;
+    }
+    Ok(updated)
+}
+
+/// Estimated spend for `month` (`"YYYY-MM"`, default: the current month)
+/// from the cost ledger, broken down by project and model, with progress
+/// against the `monthly_budget_usd` setting.
+#[tauri::command]
+pub fn get_cost_summary(state: State<AppState>, month: Option<String>) -> CmdResult<CostSummary> {
+    let month = match month {
+        Some(m) => {
+            chrono::NaiveDate::parse_from_str(&format!("{m}-01"), "%Y-%m-%d").map_err(|_| {
+                to_cmd_err(CommanderError::internal(format!(
+                    "Invalid month (expected YYYY-MM): {m}"
+                )))
+            })?;
+            m
+        }
+        None => current_month(),
+    };
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    load_cost_summary(conn, &month).map_err(to_cmd_err)
+}
+
+pub(crate) fn current_month() -> String {
+    chrono::Local::now().format("%Y-%m").to_string()
+}
+
+pub(crate) fn load_cost_summary(
+    conn: &rusqlite::Connection,
+    month: &str,
+) -> Result<CostSummary, CommanderError> {
+    let pattern = format!("{month}-%");
+    let (total_usd, sessions, input, output, cache_creation, cache_read) = conn.query_row(
+        "SELECT COALESCE(SUM(cost_usd), 0), COUNT(DISTINCT session_id),
+                COALESCE(SUM(input_tokens), 0), COALESCE(SUM(output_tokens), 0),
+                COALESCE(SUM(cache_creation_tokens), 0), COALESCE(SUM(cache_read_tokens), 0)
+         FROM cost_ledger WHERE day LIKE ?1",
+        [&pattern],
+        |row| {
+            Ok((
+                row.get::<_, f64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        },
+    )?;
+    let breakdown = |column: &str| -> Result<Vec<CostBreakdown>, CommanderError> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {column}, SUM(cost_usd), COUNT(DISTINCT session_id)
+             FROM cost_ledger WHERE day LIKE ?1
+             GROUP BY {column} ORDER BY SUM(cost_usd) DESC"
+        ))?;
+        let rows = stmt
+            .query_map([&pattern], |row| {
+                Ok(CostBreakdown {
+                    key: row.get::<_, Option<String>>(0)?.unwrap_or_default(),
+                    cost_usd: row.get(1)?,
+                    sessions: row.get::<_, i64>(2)? as usize,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(rows)
+    };
+
+    let budget_usd = monthly_budget(conn);
+    Ok(CostSummary {
+        month: month.to_string(),
+        total_usd,
+        budget_usd,
+        budget_used_pct: (budget_usd > 0.0).then(|| total_usd / budget_usd * 100.0),
+        sessions: sessions as usize,
+        input_tokens: input,
+        output_tokens: output,
+        cache_creation_tokens: cache_creation,
+        cache_read_tokens: cache_read,
+        by_project: breakdown("COALESCE(cwd, project_key)")?,
+        by_model: breakdown("model")?,
+    })
+}
+
+fn monthly_budget(conn: &rusqlite::Connection) -> f64 {
+    get_setting(conn, "monthly_budget_usd")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.0)
+}
+
+/// Notify once per period for each threshold of the monthly and weekly
+/// budgets the current spend has crossed, and emit `budget-threshold`.
+pub(crate) fn check_budget(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let mut crossed = Vec::new();
+    {
+        let db = state.db.lock();
+        let Some(conn) = db.as_ref() else {
+            return;
+        };
+        for (period, budget_setting, warned_setting) in BUDGET_PERIODS {
+            let budget_usd: f64 = get_setting(conn, budget_setting)
+                .flatten()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0.0);
+            if budget_usd <= 0.0 {
+                continue;
+            }
+            let (period_start, first_day) = period_start(period);
+            let Ok(spent_usd) = conn.query_row(
+                "SELECT COALESCE(SUM(cost_usd), 0) FROM cost_ledger WHERE day >= ?1",
+                [&first_day],
+                |row| row.get::<_, f64>(0),
+            ) else {
+                continue;
+            };
+            let pct = spent_usd / budget_usd * 100.0;
+            let Some(&threshold) = BUDGET_THRESHOLDS.iter().rev().find(|&&t| pct >= t as f64)
+            else {
+                continue;
+            };
+            let already_warned = get_setting(conn, warned_setting)
+                .flatten()
+                .and_then(|v| {
+                    let (start, t) = v.split_once(':')?;
+                    (start == period_start)
+                        .then(|| t.parse::<u32>().ok())
+                        .flatten()
+                })
+                .is_some_and(|t| t >= threshold);
+            if already_warned {
+                continue;
+            }
+            let _ = set_setting(conn, warned_setting, &format!("{period_start}:{threshold}"));
+            crossed.push(BudgetThreshold {
+                period: period.to_string(),
+                period_start,
+                threshold,
+                spent_usd,
+                budget_usd,
+            });
+        }
+    }
+
+    for alert in crossed {
+        let period = if alert.period == "weekly" {
+            "Weekly"
+        } else {
+            "Monthly"
+        };
+        let title = match alert.threshold {
+            100.. => format!("{period} agent budget exceeded"),
+            80.. => format!("{period} agent budget almost used"),
+            _ => format!("Half of the {} agent budget used", alert.period),
+        };
+        notifications::notify(
+            app,
+            "budget",
+            title,
+            format!(
+                "Estimated ${:.2} of ${:.2} spent this {} ({}%+)",
+                alert.spent_usd,
+                alert.budget_usd,
+                if alert.period == "weekly" {
+                    "week"
+                } else {
+                    "month"
+                },
+                alert.threshold
+            ),
+        );
+        let _ = app.emit(EVENT_BUDGET_THRESHOLD, &alert);
+    }
+}
+
+/// `(key, first ledger day)` of the current month or week: the key is
+/// `YYYY-MM` or the Monday's `YYYY-MM-DD`.
+fn period_start(period: &str) -> (String, String) {
+    use chrono::Datelike;
+    let today = chrono::Local::now().date_naive();
+    if period == "weekly" {
+        let monday = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+        let day = monday.format("%Y-%m-%d").to_string();
+        (day.clone(), day)
+    } else {
+        (current_month(), format!("{}-01", current_month()))
+    }
+}
+
+/// Token usage and estimated cost over `range` (`"<N>d"`, e.g. `"30d"`, or
+/// `"all"`), optionally limited to one project, aggregated by day, project
+/// and model.  Sessions are attributed to the project whose path contains
+/// their working directory.
+#[tauri::command]
+pub fn get_usage_stats(
+    state: State<AppState>,
+    range: String,
+    project_id: Option<String>,
+) -> CmdResult<UsageStats> {
+    let since = range_start(&range)?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let projects = load_db_projects(conn).map_err(to_cmd_err)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT session_id, day, model, cwd, project_key, input_tokens, output_tokens,
+                    cache_creation_tokens, cache_read_tokens, cost_usd
+             FROM cost_ledger WHERE ?1 IS NULL OR day >= ?1",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let rows: Vec<(String, String, String, Option<String>, String, UsageBucket)> = stmt
+        .query_map([&since], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                UsageBucket {
+                    input_tokens: row.get(5)?,
+                    output_tokens: row.get(6)?,
+                    cache_creation_tokens: row.get(7)?,
+                    cache_read_tokens: row.get(8)?,
+                    cost_usd: row.get(9)?,
+                    ..Default::default()
+                },
+            ))
+        })
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut totals = Aggregate::default();
+    let mut by_day: BTreeMap<String, Aggregate> = BTreeMap::new();
+    let mut by_project: HashMap<String, Aggregate> = HashMap::new();
+    let mut by_model: HashMap<String, Aggregate> = HashMap::new();
+    for (session_id, day, model, cwd, project_key, usage) in &rows {
+        let project = cwd
+            .as_deref()
+            .and_then(|cwd| correlate_session_project(cwd, &projects));
+        if project_id.is_some() && project != project_id {
+            continue;
+        }
+        let project = project.unwrap_or_else(|| cwd.clone().unwrap_or_else(|| project_key.clone()));
+        for agg in [
+            &mut totals,
+            by_day.entry(day.clone()).or_default(),
+            by_project.entry(project).or_default(),
+            by_model.entry(model.clone()).or_default(),
+        ] {
+            agg.add(session_id, usage);
+        }
+    }
+
+    let names: HashMap<&str, &str> = projects
+        .iter()
+        .map(|p| (p.id.as_str(), p.name.as_str()))
+        .collect();
+    let by_cost = |map: HashMap<String, Aggregate>| {
+        let mut buckets: Vec<UsageBucket> = map
+            .into_iter()
+            .map(|(key, agg)| agg.into_bucket(key))
+            .collect();
+        buckets.sort_by(|a, b| b.cost_usd.total_cmp(&a.cost_usd));
+        buckets
+    };
+    let mut by_project = by_cost(by_project);
+    for bucket in &mut by_project {
+        bucket.label = names.get(bucket.key.as_str()).map(|n| n.to_string());
+    }
+
+    Ok(UsageStats {
+        range,
+        since,
+        totals: totals.into_bucket(String::new()),
+        by_day: by_day
+            .into_iter()
+            .map(|(day, agg)| agg.into_bucket(day))
+            .collect(),
+        by_project,
+        by_model: by_cost(by_model),
+    })
+}
+
+/// First local day (`"YYYY-MM-DD"`) covered by `range` (`"<N>d"`, counting
+/// today, or `"all"` for `None`).
+pub(crate) fn range_start(range: &str) -> CmdResult<Option<String>> {
+    if range == "all" {
+        return Ok(None);
+    }
+    let days: i64 = range
+        .strip_suffix('d')
+        .and_then(|n| n.parse().ok())
+        .filter(|n| *n > 0)
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Invalid range (expected e.g. \"30d\" or \"all\"): {range}"
+            )))
+        })?;
+    Ok(Some(
+        (chrono::Local::now() - chrono::Duration::days(days - 1))
+            .format("%Y-%m-%d")
+            .to_string(),
+    ))
+}
+
+/// Running sum for one `UsageBucket`; sessions are counted once each.
+#[derive(Default)]
+struct Aggregate {
+    usage: UsageBucket,
+    sessions: HashSet<String>,
+}
+
+impl Aggregate {
+    fn add(&mut self, session_id: &str, usage: &UsageBucket) {
+        self.usage.input_tokens += usage.input_tokens;
+        self.usage.output_tokens += usage.output_tokens;
+        self.usage.cache_creation_tokens += usage.cache_creation_tokens;
+        self.usage.cache_read_tokens += usage.cache_read_tokens;
+        self.usage.cost_usd += usage.cost_usd;
+        self.sessions.insert(session_id.to_string());
+    }
+
+    fn into_bucket(self, key: String) -> UsageBucket {
+        UsageBucket {
+            key,
+            sessions: self.sessions.len(),
+            ..self.usage
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::commands::claude::{correlate_session_project, read_first_line_cwd};
+use crate::commands::cost_ledger::{current_month, load_cost_summary};
+use crate::commands::github::detect_github_repo;
+use crate::commands::projects::{load_db_projects, load_recent_projects};
+use crate::commands::session_search::list_session_files;
+use crate::commands::settings::{get_json_setting, get_setting};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{
+    ActiveAgent, CiFailure, DashboardData, DashboardPlanningItem, DashboardWidget, Project,
+    DASHBOARD_WIDGET_IDS,
+};
+use crate::services::github_queue::GithubQueue;
+use crate::state::AppState;
+use crate::utils::run_external_tool;
+use std::collections::HashSet;
+use std::time::Duration;
+use tauri::State;
+
+const RECENT_PROJECTS_LIMIT: usize = 6;
+const DUE_ITEMS_LIMIT: i64 = 10;
+
+/// A session counts as an active agent if its transcript was written to
+/// within this many seconds.
+const ACTIVE_AGENT_WINDOW_SECS: i64 = 10 * 60;
+
+/// Workflow runs fetched per repository when looking for CI failures.
+const CI_RUNS_LIMIT: &str = "20";
+
+/// How long a repository's workflow runs are reused between dashboard
+/// refreshes.
+const CI_RUNS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Compute the requested home screen widgets in one call.  With no ids,
+/// the widgets enabled in the `dashboard_widgets` setting are computed.
+/// Widgets not requested come back as `None`.
+#[tauri::command]
+pub fn get_dashboard_data(
+    state: State<AppState>,
+    widget_ids: Option<Vec<String>>,
+) -> CmdResult<DashboardData> {
+    // Everything that needs the DB is read up front; session scanning and
+    // `gh` calls run unlocked.
+    let (widgets, offline, projects, recent, due_items, cost) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+        let widgets: HashSet<String> = match widget_ids {
+            Some(ids) => ids.into_iter().collect(),
+            None => get_json_setting::<Vec<DashboardWidget>>(conn, "dashboard_widgets")
+                .map(|ws| ws.into_iter().filter(|w| w.enabled).map(|w| w.id).collect())
+                .unwrap_or_else(|| {
+                    DASHBOARD_WIDGET_IDS
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect()
+                }),
+        };
+        if let Some(unknown) = widgets
+            .iter()
+            .find(|id| !DASHBOARD_WIDGET_IDS.contains(&id.as_str()))
+        {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Unknown dashboard widget: {unknown}"
+            ))));
+        }
+
+        let offline = get_setting(conn, "offline_mode").flatten().as_deref() == Some("true");
+        let projects = load_db_projects(conn).map_err(to_cmd_err)?;
+        // CI failures are looked up for the recent projects too.
+        let recent = if widgets.contains("recent_projects") || widgets.contains("ci_failures") {
+            Some(load_recent_projects(conn, RECENT_PROJECTS_LIMIT).map_err(to_cmd_err)?)
+        } else {
+            None
+        };
+        let due_items = if widgets.contains("due_items") {
+            Some(load_due_items(conn).map_err(to_cmd_err)?)
+        } else {
+            None
+        };
+        let cost = if widgets.contains("cost") {
+            Some(load_cost_summary(conn, &current_month()).map_err(to_cmd_err)?)
+        } else {
+            None
+        };
+        (widgets, offline, projects, recent, due_items, cost)
+    };
+
+    let active_agents = widgets
+        .contains("active_agents")
+        .then(|| load_active_agents(&projects));
+    let ci_failures = widgets.contains("ci_failures").then(|| {
+        if offline {
+            Vec::new()
+        } else {
+            load_ci_failures(&state.github, recent.as_deref().unwrap_or_default())
+        }
+    });
+
+    Ok(DashboardData {
+        recent_projects: recent.filter(|_| widgets.contains("recent_projects")),
+        due_items,
+        active_agents,
+        cost,
+        ci_failures,
+    })
+}
+
+/// Open planning items across projects, in-progress first, then by
+/// priority.  Planning items carry no due date, so this is "what's next"
+/// rather than a deadline list.
+fn load_due_items(
+    conn: &rusqlite::Connection,
+) -> Result<Vec<DashboardPlanningItem>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT pi.id, pi.project_id, p.name, pi.subject, pi.status, pi.priority, pi.updated_at
+         FROM planning_items pi
+         LEFT JOIN projects p ON p.id = pi.project_id
+         WHERE pi.status IN ('todo', 'in_progress')
+           AND (p.id IS NULL OR p.is_archived = 0)
+         ORDER BY pi.status = 'in_progress' DESC, pi.priority DESC, pi.updated_at DESC
+         LIMIT ?1",
+    )?;
+    let items = stmt
+        .query_map([DUE_ITEMS_LIMIT], |row| {
+            Ok(DashboardPlanningItem {
+                id: row.get(0)?,
+                project_id: row.get(1)?,
+                project_name: row.get(2)?,
+                subject: row.get(3)?,
+                status: row.get(4)?,
+                priority: row.get(5)?,
+                updated_at: row.get(6)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(items)
+}
+
+/// Sessions written to within `ACTIVE_AGENT_WINDOW_SECS`, most recent first.
+fn load_active_agents(projects: &[Project]) -> Vec<ActiveAgent> {
+    let since = chrono::Utc::now().timestamp() - ACTIVE_AGENT_WINDOW_SECS;
+    let mut files: Vec<_> = list_session_files()
+        .into_iter()
+        .filter(|f| f.mtime >= since)
+        .collect();
+    files.sort_by_key(|f| std::cmp::Reverse(f.mtime));
+    files
+        .into_iter()
+        .map(|f| {
+            let cwd = read_first_line_cwd(&f.path);
+            ActiveAgent {
+                project_id: cwd
+                    .as_deref()
+                    .and_then(|cwd| correlate_session_project(cwd, projects)),
+                last_activity: chrono::DateTime::from_timestamp(f.mtime, 0)
+                    .unwrap_or_default()
+                    .to_rfc3339(),
+                session_id: f.session_id,
+                project_key: f.project_key,
+                cwd,
+            }
+        })
+        .collect()
+}
+
+/// For each project with a GitHub origin, the workflows whose latest run
+/// on its branch failed.  Repositories are queried in parallel through the
+/// GitHub queue; one that `gh` cannot read is skipped.
+fn load_ci_failures(queue: &GithubQueue, projects: &[Project]) -> Vec<CiFailure> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = projects
+            .iter()
+            .filter_map(|p| Some((p, detect_github_repo(p.path.clone())?)))
+            .map(|(project, repo)| scope.spawn(move || repo_ci_failures(queue, project, &repo)))
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|h| h.join().ok())
+            .flatten()
+            .collect()
+    })
+}
+
+fn repo_ci_failures(queue: &GithubQueue, project: &Project, repo: &str) -> Vec<CiFailure> {
+    let fetched = queue.call(&format!("runs/{repo}"), CI_RUNS_CACHE_TTL, || {
+        let output = run_external_tool(std::process::Command::new("gh").args([
+            "run",
+            "list",
+            "--repo",
+            repo,
+            "--limit",
+            CI_RUNS_LIMIT,
+            "--json",
+            "workflowName,displayTitle,headBranch,conclusion,url,createdAt",
+        ]))?;
+        Ok(serde_json::from_slice(&output.stdout).unwrap_or_default())
+    });
+    let runs: Vec<serde_json::Value> = match fetched {
+        Ok(runs) => runs,
+        Err(e) => {
+            log::debug!("gh run list failed for {}: {}", repo, e);
+            return Vec::new();
+        }
+    };
+
+    // Runs are newest first; only the first seen per workflow and branch
+    // says whether it is currently failing.
+    let mut seen = HashSet::new();
+    runs.iter()
+        .filter(|run| {
+            let key = (
+                run["workflowName"].as_str().unwrap_or_default(),
+                run["headBranch"].as_str().unwrap_or_default(),
+            );
+            // Runs still in progress have no conclusion yet.
+            !run["conclusion"].as_str().unwrap_or_default().is_empty() && seen.insert(key)
+        })
+        .filter(|run| run["conclusion"].as_str() == Some("failure"))
+        .map(|run| CiFailure {
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            repo: repo.to_string(),
+            workflow: run["workflowName"].as_str().unwrap_or_default().to_string(),
+            title: run["displayTitle"].as_str().unwrap_or_default().to_string(),
+            branch: run["headBranch"].as_str().unwrap_or_default().to_string(),
+            url: run["url"].as_str().unwrap_or_default().to_string(),
+            created_at: run["createdAt"].as_str().unwrap_or_default().to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,403 @@
+use crate::commands::claude::{
+    clear_claude_dir_override, restart_claude_watcher, set_claude_dir_override,
+};
+use crate::commands::settings::get_setting;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::DemoSeedResult;
+use crate::state::AppState;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// The demo data set.  Edit the fixture, not this module, to change what
+/// gets seeded.
+const DEMO_SEED: &str = include_str!("../../fixtures/demo_seed.json");
+
+/// Hidden setting that allows seeding in release builds (e.g. for E2E runs
+/// against a packaged app).
+const DEMO_SEED_SETTING: &str = "enable_demo_seed";
+
+#[derive(Deserialize)]
+struct Seed {
+    groups: Vec<SeedGroup>,
+    projects: Vec<SeedProject>,
+    sessions: Vec<SeedSession>,
+    tasks: Vec<SeedTeam>,
+    plans: Vec<SeedPlan>,
+}
+
+#[derive(Deserialize)]
+struct SeedGroup {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SeedProject {
+    id: String,
+    name: String,
+    tags: Vec<String>,
+    color: Option<String>,
+    group_id: Option<String>,
+    language: Option<String>,
+    framework: Option<String>,
+    package_manager: Option<String>,
+    /// Relative path → contents, written into the project directory.
+    files: BTreeMap<String, String>,
+    planning_items: Vec<SeedPlanningItem>,
+}
+
+#[derive(Deserialize)]
+struct SeedPlanningItem {
+    subject: String,
+    status: String,
+    priority: i64,
+}
+
+#[derive(Deserialize)]
+struct SeedSession {
+    id: String,
+    project_id: String,
+    /// How long before the seeding time the session started.
+    minutes_ago: i64,
+    turns: Vec<SeedTurn>,
+}
+
+#[derive(Deserialize)]
+struct SeedTurn {
+    role: String,
+    text: String,
+    #[serde(default)]
+    tools: Vec<SeedTool>,
+}
+
+#[derive(Deserialize)]
+struct SeedTool {
+    name: String,
+    input: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct SeedTeam {
+    team_id: String,
+    team_name: String,
+    tasks: Vec<SeedTask>,
+}
+
+#[derive(Deserialize)]
+struct SeedTask {
+    id: String,
+    subject: String,
+    status: String,
+    owner: Option<String>,
+    active_form: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SeedPlan {
+    filename: String,
+    content: String,
+}
+
+/// Populate the DB and a scratch Claude directory from
+/// `fixtures/demo_seed.json`: project directories, groups, planning items,
+/// session transcripts, tasks and plans.  Afterwards every Claude command
+/// reads the scratch directory instead of `~/.claude` until restart or
+/// `clear_demo_data`.
+///
+/// Only available in debug builds or with the hidden `enable_demo_seed`
+/// setting.  Rows are only ever added: re-seeding keeps the demo rows
+/// already present, and a project that owns one of the demo paths is left
+/// alone rather than replaced.
+#[tauri::command]
+pub fn seed_demo_data(app: AppHandle) -> CmdResult<DemoSeedResult> {
+    let state = app.state::<AppState>();
+    ensure_enabled(&state)?;
+    let seed = load_seed()?;
+
+    let root = demo_root();
+    if root.exists() {
+        std::fs::remove_dir_all(&root).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+    let demo_claude = root.join(".claude");
+    let projects_root = root.join("projects");
+
+    let project_paths: BTreeMap<&str, PathBuf> = seed
+        .projects
+        .iter()
+        .map(|p| (p.id.as_str(), projects_root.join(&p.name)))
+        .collect();
+
+    write_files(&seed, &project_paths, &demo_claude)
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+
+    {
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        insert_rows(conn, &seed, &project_paths).map_err(to_cmd_err)?;
+    }
+
+    set_claude_dir_override(demo_claude.clone());
+    restart_claude_watcher(&app);
+
+    Ok(DemoSeedResult {
+        root: root.to_string_lossy().to_string(),
+        claude_dir: demo_claude.to_string_lossy().to_string(),
+        projects: seed.projects.len(),
+        planning_items: seed.projects.iter().map(|p| p.planning_items.len()).sum(),
+        sessions: seed.sessions.len(),
+        tasks: seed.tasks.iter().map(|t| t.tasks.len()).sum(),
+    })
+}
+
+/// Undo `seed_demo_data`: delete the demo projects (their planning items
+/// and session links go with them), the demo groups nothing else uses and
+/// the scratch directory, and read the real Claude directory again.
+#[tauri::command]
+pub fn clear_demo_data(app: AppHandle) -> CmdResult<()> {
+    let state = app.state::<AppState>();
+    ensure_enabled(&state)?;
+    let seed = load_seed()?;
+
+    {
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        delete_rows(conn, &seed).map_err(to_cmd_err)?;
+    }
+
+    let root = demo_root();
+    if root.exists() {
+        std::fs::remove_dir_all(&root).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+
+    clear_claude_dir_override();
+    restart_claude_watcher(&app);
+    Ok(())
+}
+
+fn ensure_enabled(state: &AppState) -> CmdResult<()> {
+    let enabled = cfg!(debug_assertions)
+        || state
+            .db
+            .lock()
+            .as_ref()
+            .and_then(|conn| get_setting(conn, DEMO_SEED_SETTING).flatten())
+            .is_some_and(|v| v == "true");
+    if enabled {
+        Ok(())
+    } else {
+        Err(to_cmd_err(CommanderError::internal(
+            "Demo seeding is only available in debug builds",
+        )))
+    }
+}
+
+fn load_seed() -> CmdResult<Seed> {
+    serde_json::from_str(DEMO_SEED).map_err(|e| to_cmd_err(CommanderError::parse(e)))
+}
+
+fn demo_root() -> PathBuf {
+    std::env::temp_dir().join("claude-commander-demo")
+}
+
+fn write_files(
+    seed: &Seed,
+    project_paths: &BTreeMap<&str, PathBuf>,
+    demo_claude: &Path,
+) -> std::io::Result<()> {
+    for project in &seed.projects {
+        let dir = &project_paths[project.id.as_str()];
+        for (relative, content) in &project.files {
+            write_file(&dir.join(relative), content)?;
+        }
+    }
+
+    let now = chrono::Utc::now();
+    for session in &seed.sessions {
+        let Some(cwd) = project_paths.get(session.project_id.as_str()) else {
+            continue;
+        };
+        let cwd = cwd.to_string_lossy().to_string();
+        // Claude Code keys project directories by the cwd with separators dashed.
+        let key = cwd.replace(['/', '.'], "-");
+        let started = now - chrono::Duration::minutes(session.minutes_ago);
+
+        let lines: Vec<String> = session
+            .turns
+            .iter()
+            .enumerate()
+            .map(|(i, turn)| {
+                let mut content = vec![serde_json::json!({ "type": "text", "text": turn.text })];
+                for (j, tool) in turn.tools.iter().enumerate() {
+                    content.push(serde_json::json!({
+                        "type": "tool_use",
+                        "id": format!("toolu_demo_{i}_{j}"),
+                        "name": tool.name,
+                        "input": tool.input,
+                    }));
+                }
+                serde_json::json!({
+                    "type": turn.role,
+                    "uuid": format!("{}-{i}", session.id),
+                    "sessionId": session.id,
+                    "cwd": cwd,
+                    "timestamp": (started + chrono::Duration::seconds(i as i64 * 30)).to_rfc3339(),
+                    "message": { "role": turn.role, "content": content },
+                })
+                .to_string()
+            })
+            .collect();
+        let path = demo_claude
+            .join("projects")
+            .join(key)
+            .join(format!("{}.jsonl", session.id));
+        write_file(&path, &(lines.join("\n") + "\n"))?;
+        // Session lists sort by mtime, so age the file like the transcript.
+        let last_turn = started + chrono::Duration::seconds(lines.len() as i64 * 30);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)?
+            .set_modified(last_turn.into())?;
+    }
+
+    for team in &seed.tasks {
+        for task in &team.tasks {
+            let json = serde_json::json!({
+                "teamName": team.team_name,
+                "subject": task.subject,
+                "status": task.status,
+                "owner": task.owner,
+                "activeForm": task.active_form,
+                "createdAt": now.to_rfc3339(),
+                "updatedAt": now.to_rfc3339(),
+            });
+            write_file(
+                &demo_claude
+                    .join("tasks")
+                    .join(&team.team_id)
+                    .join(format!("{}.json", task.id)),
+                &json.to_string(),
+            )?;
+        }
+    }
+
+    for plan in &seed.plans {
+        write_file(
+            &demo_claude.join("plans").join(&plan.filename),
+            &plan.content,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, content: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)
+}
+
+fn insert_rows(
+    conn: &mut rusqlite::Connection,
+    seed: &Seed,
+    project_paths: &BTreeMap<&str, PathBuf>,
+) -> Result<(), CommanderError> {
+    let tx = conn.transaction()?;
+
+    for (i, group) in seed.groups.iter().enumerate() {
+        tx.execute(
+            "INSERT OR IGNORE INTO project_groups (id, name, sort_order) VALUES (?1, ?2, ?3)",
+            rusqlite::params![group.id, group.name, i as i64],
+        )?;
+    }
+
+    // Demo projects actually in the DB, whether seeded now or by an earlier
+    // run; one whose path another project already owns is skipped.
+    let mut seeded = HashSet::new();
+    for (i, project) in seed.projects.iter().enumerate() {
+        let path = project_paths[project.id.as_str()]
+            .to_string_lossy()
+            .to_string();
+        tx.execute(
+            "INSERT OR IGNORE INTO projects
+                 (id, name, path, tags, color, sort_order, group_id, language, framework,
+                  package_manager, identity_key)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            rusqlite::params![
+                project.id,
+                project.name,
+                path,
+                serde_json::to_string(&project.tags)?,
+                project.color,
+                i as i64,
+                project.group_id,
+                project.language,
+                project.framework,
+                project.package_manager,
+                format!("demo:{}", project.id),
+            ],
+        )?;
+        let present: bool = tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1 AND path = ?2)",
+            rusqlite::params![project.id, path],
+            |row| row.get(0),
+        )?;
+        if !present {
+            continue;
+        }
+        seeded.insert(project.id.as_str());
+
+        for (j, item) in project.planning_items.iter().enumerate() {
+            tx.execute(
+                "INSERT OR IGNORE INTO planning_items
+                     (id, project_id, subject, status, priority, sort_order)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![
+                    format!("{}-item-{j}", project.id),
+                    project.id,
+                    item.subject,
+                    item.status,
+                    item.priority,
+                    (j as i64 + 1) * 1000,
+                ],
+            )?;
+        }
+    }
+
+    for session in &seed.sessions {
+        if !seeded.contains(session.project_id.as_str()) {
+            continue;
+        }
+        tx.execute(
+            "INSERT OR IGNORE INTO session_project_links (session_id, project_id) VALUES (?1, ?2)",
+            rusqlite::params![session.id, session.project_id],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+fn delete_rows(conn: &mut rusqlite::Connection, seed: &Seed) -> Result<(), CommanderError> {
+    let tx = conn.transaction()?;
+    for project in &seed.projects {
+        tx.execute(
+            "DELETE FROM projects WHERE id = ?1",
+            rusqlite::params![project.id],
+        )?;
+    }
+    for group in &seed.groups {
+        tx.execute(
+            "DELETE FROM project_groups
+             WHERE id = ?1 AND NOT EXISTS (SELECT 1 FROM projects WHERE group_id = ?1)",
+            rusqlite::params![group.id],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{Project, ProjectDependencyEdge};
+use crate::state::AppState;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// A single dependency declaration read from a project manifest.
+struct DeclaredDependency {
+    name: String,
+    ecosystem: &'static str,
+    section: String,
+    /// Local path the dependency points at (`file:`/`link:` specs in
+    /// package.json, `path = "…"` in Cargo.toml), already joined onto the
+    /// declaring project's directory.
+    path_ref: Option<PathBuf>,
+}
+
+const NPM_SECTIONS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+const CARGO_SECTIONS: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+// ─── Manifest parsing ──────────────────────────────────────────────────────
+
+fn read_package_json(dir: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn read_cargo_toml(dir: &Path) -> Option<toml::Value> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    content.parse::<toml::Value>().ok()
+}
+
+/// Package names a project publishes under (npm `name`, Cargo `[package].name`).
+fn manifest_names(dir: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(name) = read_package_json(dir)
+        .as_ref()
+        .and_then(|v| v.get("name"))
+        .and_then(|v| v.as_str())
+    {
+        names.push(name.to_string());
+    }
+    if let Some(name) = read_cargo_toml(dir)
+        .as_ref()
+        .and_then(|v| v.get("package"))
+        .and_then(|p| p.get("name"))
+        .and_then(|v| v.as_str())
+    {
+        names.push(name.to_string());
+    }
+    names
+}
+
+fn npm_dependencies(dir: &Path) -> Vec<DeclaredDependency> {
+    let Some(json) = read_package_json(dir) else {
+        return vec![];
+    };
+
+    let mut deps = Vec::new();
+    for section in NPM_SECTIONS {
+        let Some(map) = json.get(*section).and_then(|v| v.as_object()) else {
+            continue;
+        };
+        for (name, spec) in map {
+            let spec = spec.as_str().unwrap_or("");
+            let path_ref = ["file:", "link:", "portal:"]
+                .iter()
+                .find_map(|prefix| spec.strip_prefix(prefix))
+                .map(|rel| dir.join(rel));
+            deps.push(DeclaredDependency {
+                name: name.clone(),
+                ecosystem: "npm",
+                section: section.to_string(),
+                path_ref,
+            });
+        }
+    }
+    deps
+}
+
+fn cargo_dependencies(dir: &Path) -> Vec<DeclaredDependency> {
+    let Some(manifest) = read_cargo_toml(dir) else {
+        return vec![];
+    };
+
+    // Top-level sections plus the workspace-wide `[workspace.dependencies]` table.
+    let mut tables: Vec<(String, &toml::value::Table)> = CARGO_SECTIONS
+        .iter()
+        .filter_map(|s| {
+            manifest
+                .get(*s)
+                .and_then(|v| v.as_table())
+                .map(|t| (s.to_string(), t))
+        })
+        .collect();
+    if let Some(t) = manifest
+        .get("workspace")
+        .and_then(|w| w.get("dependencies"))
+        .and_then(|v| v.as_table())
+    {
+        tables.push(("workspace.dependencies".to_string(), t));
+    }
+
+    let mut deps = Vec::new();
+    for (section, table) in tables {
+        for (key, spec) in table {
+            // `foo = { package = "real-name", … }` renames the dependency.
+            let name = spec
+                .get("package")
+                .and_then(|v| v.as_str())
+                .unwrap_or(key)
+                .to_string();
+            let path_ref = spec
+                .get("path")
+                .and_then(|v| v.as_str())
+                .map(|rel| dir.join(rel));
+            deps.push(DeclaredDependency {
+                name,
+                ecosystem: "cargo",
+                section: section.clone(),
+                path_ref,
+            });
+        }
+    }
+    deps
+}
+
+// ─── Matching ──────────────────────────────────────────────────────────────
+
+/// Find the project whose directory contains `target` (deepest match wins),
+/// so path references into a sub-crate of a monorepo resolve to that repo.
+fn project_for_path<'a>(target: &Path, roots: &'a [(PathBuf, String)]) -> Option<&'a str> {
+    let target = target.canonicalize().ok()?;
+    roots
+        .iter()
+        .filter(|(root, _)| target.starts_with(root))
+        .max_by_key(|(root, _)| root.components().count())
+        .map(|(_, id)| id.as_str())
+}
+
+// ─── Commands ──────────────────────────────────────────────────────────────
+
+/// Build an edge list of which known projects depend on which, by parsing
+/// every project's package.json / Cargo.toml and matching declared
+/// dependencies against the other projects' package names or local paths.
+#[tauri::command]
+pub fn get_cross_project_dependencies(
+    state: State<AppState>,
+) -> CmdResult<Vec<ProjectDependencyEdge>> {
+    let projects: Vec<Project> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_db_projects(conn).map_err(to_cmd_err)?
+    }; // DB lock released before touching the filesystem
+
+    let mut by_name: HashMap<String, String> = HashMap::new();
+    let mut roots: Vec<(PathBuf, String)> = Vec::new();
+    for proj in &projects {
+        let dir = Path::new(&proj.path);
+        for name in manifest_names(dir) {
+            by_name.entry(name).or_insert_with(|| proj.id.clone());
+        }
+        if let Ok(canonical) = dir.canonicalize() {
+            roots.push((canonical, proj.id.clone()));
+        }
+    }
+
+    let mut edges = Vec::new();
+    let mut seen: HashSet<(String, String, String)> = HashSet::new();
+
+    for proj in &projects {
+        let dir = Path::new(&proj.path);
+        let declared = npm_dependencies(dir)
+            .into_iter()
+            .chain(cargo_dependencies(dir));
+
+        for dep in declared {
+            let (target, matched_by) = match dep.path_ref.as_deref() {
+                Some(p) => (project_for_path(p, &roots), "path"),
+                None => (by_name.get(&dep.name).map(|s| s.as_str()), "name"),
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if target == proj.id {
+                continue;
+            }
+            if !seen.insert((proj.id.clone(), target.to_string(), dep.name.clone())) {
+                continue;
+            }
+            edges.push(ProjectDependencyEdge {
+                from_project_id: proj.id.clone(),
+                to_project_id: target.to_string(),
+                dependency_name: dep.name,
+                ecosystem: dep.ecosystem.to_string(),
+                section: dep.section,
+                matched_by: matched_by.to_string(),
+            });
+        }
+    }
+
+    Ok(edges)
+}
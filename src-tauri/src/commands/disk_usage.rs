@@ -0,0 +1,291 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::ProjectDiskUsage;
+use crate::state::AppState;
+use crate::utils::validate_allowed_path;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use walkdir::WalkDir;
+
+/// Cached results younger than this are returned without walking again.
+const CACHE_TTL_MINUTES: i64 = 60;
+
+/// Generated output that is safe to delete and rebuild.
+const BUILD_DIRS: &[&str] = &[
+    "dist",
+    "build",
+    "out",
+    ".next",
+    ".nuxt",
+    ".output",
+    ".svelte-kit",
+    ".turbo",
+    ".parcel-cache",
+    "__pycache__",
+    "coverage",
+];
+
+const USAGE_COLUMNS: &str = "project_path, total_bytes, node_modules_bytes, target_bytes, \
+     git_bytes, build_bytes, computed_at";
+
+#[derive(Clone, Copy)]
+enum Bucket {
+    NodeModules,
+    Target,
+    Git,
+    Build,
+}
+
+/// Holds a walk's cancel flag in `AppState::disk_usage_scans` while it runs.
+struct ScanGuard {
+    app: AppHandle,
+    project_path: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ScanGuard {
+    fn register(app: &AppHandle, project_path: &str) -> CmdResult<Self> {
+        let state = app.state::<AppState>();
+        let mut scans = state.disk_usage_scans.lock();
+        if scans.contains_key(project_path) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Disk usage scan already running for {project_path}"
+            ))));
+        }
+        let cancelled = Arc::new(AtomicBool::new(false));
+        scans.insert(project_path.to_string(), cancelled.clone());
+        Ok(Self {
+            app: app.clone(),
+            project_path: project_path.to_string(),
+            cancelled,
+        })
+    }
+}
+
+impl Drop for ScanGuard {
+    fn drop(&mut self) {
+        self.app
+            .state::<AppState>()
+            .disk_usage_scans
+            .lock()
+            .remove(&self.project_path);
+    }
+}
+
+/// Total size of a project plus how much of it is `node_modules`, Cargo
+/// `target`, `.git` and build output.  Results are cached in SQLite for an
+/// hour; pass `refresh` to walk again.  The walk runs off the main thread and
+/// stops early when `cancel_project_disk_usage` is called for the same path.
+#[tauri::command]
+pub async fn get_project_disk_usage(
+    app: AppHandle,
+    project_path: String,
+    refresh: Option<bool>,
+) -> CmdResult<ProjectDiskUsage> {
+    let state = app.state::<AppState>();
+    let root = validate_allowed_path(&state, &project_path)?;
+
+    if !refresh.unwrap_or(false) {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let cutoff = chrono::Utc::now() - chrono::Duration::minutes(CACHE_TTL_MINUTES);
+        let fresh = load_cached(conn, &project_path).filter(|u| {
+            chrono::DateTime::parse_from_rfc3339(&u.computed_at).is_ok_and(|t| t >= cutoff)
+        });
+        if let Some(usage) = fresh {
+            return Ok(usage);
+        }
+    }
+
+    let guard = ScanGuard::register(&app, &project_path)?;
+    let cancelled = guard.cancelled.clone();
+    let usage =
+        tauri::async_runtime::spawn_blocking(move || measure(&project_path, &root, &cancelled))
+            .await
+            .map_err(|e| to_cmd_err(CommanderError::internal(e)))?
+            .map_err(to_cmd_err)?;
+    drop(guard);
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        &format!(
+            "INSERT OR REPLACE INTO disk_usage ({USAGE_COLUMNS})
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+        ),
+        rusqlite::params![
+            usage.project_path,
+            usage.total_bytes as i64,
+            usage.node_modules_bytes as i64,
+            usage.target_bytes as i64,
+            usage.git_bytes as i64,
+            usage.build_bytes as i64,
+            usage.computed_at,
+        ],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(usage)
+}
+
+/// Stop a running `get_project_disk_usage` walk.  No-op when none is running.
+#[tauri::command]
+pub fn cancel_project_disk_usage(state: State<AppState>, project_path: String) {
+    if let Some(flag) = state.disk_usage_scans.lock().get(&project_path) {
+        flag.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Cached usage of every active project, most reclaimable space first.
+#[tauri::command]
+pub fn list_project_disk_usage(state: State<AppState>) -> CmdResult<Vec<ProjectDiskUsage>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {USAGE_COLUMNS} FROM disk_usage
+             WHERE project_path IN (SELECT path FROM projects WHERE is_archived = 0)
+             ORDER BY node_modules_bytes + target_bytes + build_bytes DESC"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let usage = stmt
+        .query_map([], row_to_usage)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(usage)
+}
+
+// ─── Internal helpers ───────────────────────────────────────────────────────
+
+fn load_cached(conn: &rusqlite::Connection, project_path: &str) -> Option<ProjectDiskUsage> {
+    conn.query_row(
+        &format!("SELECT {USAGE_COLUMNS} FROM disk_usage WHERE project_path = ?1"),
+        [project_path],
+        row_to_usage,
+    )
+    .ok()
+}
+
+fn row_to_usage(row: &rusqlite::Row) -> rusqlite::Result<ProjectDiskUsage> {
+    let total: i64 = row.get(1)?;
+    let node_modules: i64 = row.get(2)?;
+    let target: i64 = row.get(3)?;
+    let git: i64 = row.get(4)?;
+    let build: i64 = row.get(5)?;
+    Ok(ProjectDiskUsage {
+        project_path: row.get(0)?,
+        total_bytes: total as u64,
+        node_modules_bytes: node_modules as u64,
+        target_bytes: target as u64,
+        git_bytes: git as u64,
+        build_bytes: build as u64,
+        other_bytes: (total - node_modules - target - git - build).max(0) as u64,
+        computed_at: row.get(6)?,
+        cached: true,
+    })
+}
+
+/// Walk `root` once.  A bucket directory is sized as a whole and not
+/// descended into further, so nested `node_modules` or a `dist/` inside
+/// `node_modules` count once, towards the outermost bucket.
+fn measure(
+    project_path: &str,
+    root: &Path,
+    cancelled: &AtomicBool,
+) -> Result<ProjectDiskUsage, CommanderError> {
+    let mut usage = ProjectDiskUsage {
+        project_path: project_path.to_string(),
+        total_bytes: 0,
+        node_modules_bytes: 0,
+        target_bytes: 0,
+        git_bytes: 0,
+        build_bytes: 0,
+        other_bytes: 0,
+        computed_at: String::new(),
+        cached: false,
+    };
+
+    let mut walker = WalkDir::new(root).into_iter();
+    while let Some(entry) = walker.next() {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(CommanderError::internal("Disk usage scan cancelled"));
+        }
+        let Ok(entry) = entry else {
+            continue;
+        };
+
+        if entry.depth() > 0 && entry.file_type().is_dir() {
+            if let Some(bucket) = bucket_for(entry.path()) {
+                walker.skip_current_dir();
+                let size = dir_size(entry.path(), cancelled)?;
+                usage.total_bytes += size;
+                match bucket {
+                    Bucket::NodeModules => usage.node_modules_bytes += size,
+                    Bucket::Target => usage.target_bytes += size,
+                    Bucket::Git => usage.git_bytes += size,
+                    Bucket::Build => usage.build_bytes += size,
+                }
+                continue;
+            }
+        }
+
+        if entry.file_type().is_file() {
+            usage.total_bytes += entry.metadata().map(|m| allocated_bytes(&m)).unwrap_or(0);
+        }
+    }
+
+    usage.other_bytes = usage.total_bytes
+        - usage.node_modules_bytes
+        - usage.target_bytes
+        - usage.git_bytes
+        - usage.build_bytes;
+    usage.computed_at = chrono::Utc::now().to_rfc3339();
+    Ok(usage)
+}
+
+fn dir_size(dir: &Path, cancelled: &AtomicBool) -> Result<u64, CommanderError> {
+    let mut total = 0;
+    for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(CommanderError::internal("Disk usage scan cancelled"));
+        }
+        if entry.file_type().is_file() {
+            total += entry.metadata().map(|m| allocated_bytes(&m)).unwrap_or(0);
+        }
+    }
+    Ok(total)
+}
+
+fn bucket_for(dir: &Path) -> Option<Bucket> {
+    let name = dir.file_name()?.to_str()?;
+    match name {
+        "node_modules" => Some(Bucket::NodeModules),
+        ".git" => Some(Bucket::Git),
+        // Only a Cargo target dir; `target` is an ordinary name elsewhere.
+        "target" if dir.with_file_name("Cargo.toml").is_file() => Some(Bucket::Target),
+        _ if BUILD_DIRS.contains(&name) => Some(Bucket::Build),
+        _ => None,
+    }
+}
+
+/// Bytes actually allocated on disk (sparse and small files differ from
+/// their length), which is what deleting the file gives back.
+#[cfg(unix)]
+fn allocated_bytes(meta: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated_bytes(meta: &std::fs::Metadata) -> u64 {
+    meta.len()
+}
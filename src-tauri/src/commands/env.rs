@@ -1,13 +1,20 @@
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
 use crate::models::{DeployConfig, EnvFile, EnvVar};
-use crate::utils::validate_home_path;
-use std::io::Write;
+use crate::state::AppState;
+use crate::utils::{validate_allowed_path, write_file_atomic};
+use commander_core::redaction::is_secret_key;
 use std::path::Path;
+use tauri::State;
 
 #[tauri::command]
-pub fn list_env_files(project_path: String) -> CmdResult<Vec<EnvFile>> {
-    // Validate project path is within home directory
-    let dir = validate_home_path(&project_path)?;
+pub fn list_env_files(state: State<AppState>, project_path: String) -> CmdResult<Vec<EnvFile>> {
+    // Validate project path is within home directory or an allowed root
+    let dir = validate_allowed_path(&state, &project_path)?;
+    Ok(find_env_files(&dir))
+}
+
+/// `.env` and `.env.*` files directly inside `dir`, sorted by name.
+pub(crate) fn find_env_files(dir: &Path) -> Vec<EnvFile> {
     let mut env_files = Vec::new();
 
     let patterns = [".env", ".env.local", ".env.development", ".env.production", ".env.test"];
@@ -25,7 +32,7 @@ pub fn list_env_files(project_path: String) -> CmdResult<Vec<EnvFile>> {
     }
 
     // Also check for any other .env.* files
-    if let Ok(entries) = std::fs::read_dir(&dir) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
         for entry in entries.filter_map(|e| e.ok()) {
             let fname = entry.file_name();
             let fname_str = fname.to_string_lossy();
@@ -44,15 +51,17 @@ pub fn list_env_files(project_path: String) -> CmdResult<Vec<EnvFile>> {
     }
 
     env_files.sort_by(|a, b| a.filename.cmp(&b.filename));
-    Ok(env_files)
+    env_files
 }
 
 #[tauri::command]
-pub fn get_env_vars(env_file_path: String) -> CmdResult<Vec<EnvVar>> {
-    // Validate env file path is within home directory
-    validate_home_path(&env_file_path)?;
+pub fn get_env_vars(state: State<AppState>, env_file_path: String) -> CmdResult<Vec<EnvVar>> {
+    // Validate env file path is within home directory or an allowed root
+    validate_allowed_path(&state, &env_file_path)?;
+    read_env_vars(Path::new(&env_file_path))
+}
 
-    let path = Path::new(&env_file_path);
+pub(crate) fn read_env_vars(path: &Path) -> CmdResult<Vec<EnvVar>> {
     if !path.exists() {
         return Ok(vec![]);
     }
@@ -65,9 +74,14 @@ pub fn get_env_vars(env_file_path: String) -> CmdResult<Vec<EnvVar>> {
 }
 
 #[tauri::command]
-pub fn set_env_var(env_file_path: String, key: String, value: String) -> CmdResult<()> {
-    // Validate env file path is within home directory
-    validate_home_path(&env_file_path)?;
+pub fn set_env_var(
+    state: State<AppState>,
+    env_file_path: String,
+    key: String,
+    value: String,
+) -> CmdResult<()> {
+    // Validate env file path is within home directory or an allowed root
+    validate_allowed_path(&state, &env_file_path)?;
 
     let path = Path::new(&env_file_path);
 
@@ -106,9 +120,9 @@ pub fn set_env_var(env_file_path: String, key: String, value: String) -> CmdResu
 }
 
 #[tauri::command]
-pub fn delete_env_var(env_file_path: String, key: String) -> CmdResult<()> {
-    // Validate env file path is within home directory
-    validate_home_path(&env_file_path)?;
+pub fn delete_env_var(state: State<AppState>, env_file_path: String, key: String) -> CmdResult<()> {
+    // Validate env file path is within home directory or an allowed root
+    validate_allowed_path(&state, &env_file_path)?;
 
     let path = Path::new(&env_file_path);
 
@@ -187,32 +201,6 @@ pub fn get_deploy_configs(project_path: String) -> CmdResult<Vec<DeployConfig>>
     Ok(configs)
 }
 
-/// Write `content` to `path` atomically using a sibling temp file + rename.
-/// On POSIX (macOS/Linux) `std::fs::rename` is atomic within the same filesystem,
-/// so readers always see either the old or the new content, never a partial write.
-fn write_file_atomic(path: &Path, content: String) -> CmdResult<()> {
-    let filename = path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .ok_or_else(|| to_cmd_err(CommanderError::internal("env file path has no filename")))?;
-
-    let tmp_path = path.with_file_name(format!("{}.tmp", filename));
-
-    {
-        let mut file = std::fs::File::create(&tmp_path)
-            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
-        file.write_all(content.as_bytes())
-            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
-        file.sync_all()
-            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
-    }
-
-    std::fs::rename(&tmp_path, path)
-        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
-
-    Ok(())
-}
-
 fn parse_env_content(content: &str) -> Vec<EnvVar> {
     content
         .lines()
@@ -245,18 +233,6 @@ fn parse_env_file_count(path: &Path) -> usize {
         .unwrap_or(0)
 }
 
-fn is_secret_key(key: &str) -> bool {
-    let lower = key.to_lowercase();
-    lower.contains("secret")
-        || lower.contains("password")
-        || lower.contains("token")
-        || lower.contains("key")
-        || lower.contains("api")
-        || lower.contains("auth")
-        || lower.contains("private")
-        || lower.contains("credential")
-}
-
 fn toml_to_json(val: toml::Value) -> serde_json::Value {
     match val {
         toml::Value::String(s) => serde_json::Value::String(s),
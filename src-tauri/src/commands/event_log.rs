@@ -0,0 +1,57 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::EventLogEntry;
+use crate::state::AppState;
+use tauri::State;
+
+/// The most recent `limit` entries (default 50), newest first, optionally
+/// only those of one `kind`.
+#[tauri::command]
+pub fn get_event_log(
+    state: State<AppState>,
+    limit: Option<usize>,
+    kind: Option<String>,
+) -> CmdResult<Vec<EventLogEntry>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, kind, message, details, created_at FROM event_log
+             WHERE ?1 IS NULL OR kind = ?1
+             ORDER BY id DESC LIMIT ?2",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let entries = stmt
+        .query_map(rusqlite::params![kind, limit.unwrap_or(50) as i64], |row| {
+            let details: Option<String> = row.get(3)?;
+            Ok(EventLogEntry {
+                id: row.get(0)?,
+                kind: row.get(1)?,
+                message: row.get(2)?,
+                details: details.and_then(|d| serde_json::from_str(&d).ok()),
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(entries)
+}
+
+/// Append an entry to the event log.
+pub(crate) fn record_event<T: serde::Serialize>(
+    conn: &rusqlite::Connection,
+    kind: &str,
+    message: &str,
+    details: Option<&T>,
+) -> Result<(), CommanderError> {
+    let details = details.map(serde_json::to_string).transpose()?;
+    conn.execute(
+        "INSERT INTO event_log (kind, message, details, created_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![kind, message, details, chrono::Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
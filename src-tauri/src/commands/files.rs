@@ -0,0 +1,91 @@
+use crate::commands::claude::claude_roots;
+use crate::commands::projects::load_db_projects;
+use crate::commands::settings::allowed_roots;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::state::AppState;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Larger files are refused rather than streamed into the webview.
+const MAX_READ_BYTES: u64 = 5 * 1024 * 1024;
+
+/// The parts of a Claude config root the frontend may read.  The root itself
+/// also holds `.credentials.json` and settings with API keys.
+const CLAUDE_READABLE_DIRS: &[&str] = &["projects", "plans", "tasks", "todos"];
+
+/// Whether `path` names a file that typically holds secrets: Claude's
+/// `.credentials.json`, `.env` files, PEM certificates and keys, and SSH keys
+/// (`id_rsa`, `id_ed25519.pub`, ...).
+fn is_secret_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    let name = name.to_lowercase();
+    name == ".credentials.json"
+        || name.starts_with(".env")
+        || name.ends_with(".pem")
+        || name.starts_with("id_")
+}
+
+/// Read a UTF-8 text file for the frontend.  Unlike the home-directory
+/// check used by other commands, the file must sit inside a registered
+/// project, an `allowed_roots` entry, or one of `CLAUDE_READABLE_DIRS` of a
+/// Claude config root, so the webview cannot read e.g. `~/.ssh`.  Files that
+/// look like secrets (see `is_secret_file`) are refused wherever they are.
+/// This replaces direct `fs` plugin access.
+#[tauri::command]
+pub fn read_allowed_file(state: State<AppState>, path: String) -> CmdResult<String> {
+    let canonical = Path::new(&path)
+        .canonicalize()
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    // Checked on both names so a symlink cannot rename a secret.
+    if is_secret_file(Path::new(&path)) || is_secret_file(&canonical) {
+        return Err(to_cmd_err(CommanderError::internal(
+            "File may contain secrets and cannot be read",
+        )));
+    }
+
+    let roots = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let mut roots: Vec<PathBuf> = load_db_projects(conn)
+            .map_err(to_cmd_err)?
+            .into_iter()
+            .map(|p| PathBuf::from(p.path))
+            .collect();
+        roots.extend(allowed_roots(conn));
+        roots
+    };
+    let permitted = roots
+        .into_iter()
+        .chain(
+            claude_roots()
+                .into_iter()
+                .flat_map(|(_, dir)| CLAUDE_READABLE_DIRS.iter().map(move |sub| dir.join(sub))),
+        )
+        .filter_map(|root| root.canonicalize().ok())
+        .any(|root| canonical.starts_with(root));
+    if !permitted {
+        return Err(to_cmd_err(CommanderError::internal(
+            "File is outside every project, allowed root and readable Claude directory",
+        )));
+    }
+
+    let meta = std::fs::metadata(&canonical).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    if !meta.is_file() {
+        return Err(to_cmd_err(CommanderError::FileNotFound {
+            path: canonical.to_string_lossy().to_string(),
+        }));
+    }
+    if meta.len() > MAX_READ_BYTES {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "File is larger than {} MiB",
+            MAX_READ_BYTES / (1024 * 1024)
+        ))));
+    }
+    let bytes = std::fs::read(&canonical).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    String::from_utf8(bytes)
+        .map_err(|_| to_cmd_err(CommanderError::internal("File is not UTF-8 text")))
+}
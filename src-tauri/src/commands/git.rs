@@ -1,6 +1,11 @@
+use crate::commands::claude::{find_session_file, load_session_detail};
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::models::{GitBranch, GitCommit, GitFile, GitStatus};
+use crate::models::{GitBranch, GitCommit, GitFile, GitStatus, SessionDiff, SessionDiffFile};
+use crate::utils::parse_timestamp;
 use git2::{Repository, StatusOptions};
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
 
 #[tauri::command]
 pub fn git_status(project_path: String) -> CmdResult<GitStatus> {
@@ -90,24 +95,127 @@ pub fn git_log(project_path: String, limit: Option<usize>) -> CmdResult<Vec<GitC
             .find_commit(oid)
             .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
 
-        let hash = oid.to_string();
-        let short_hash = hash[..7].to_string();
-        let message = commit.summary().unwrap_or("").to_string();
-        let author = commit.author().name().unwrap_or("Unknown").to_string();
-        let timestamp = {
-            let t = commit.time();
-            let dt = chrono::DateTime::from_timestamp(t.seconds(), 0)
-                .unwrap_or_default()
-                .with_timezone(&chrono::Utc);
-            dt.to_rfc3339()
-        };
-
-        commits.push(GitCommit { hash, short_hash, message, author, timestamp });
+        commits.push(to_git_commit(&commit));
     }
 
     Ok(commits)
 }
 
+/// Summarise what changed in `project_path` since Claude session
+/// `session_id` started: the tree of the last commit before the session is
+/// diffed against the working tree (HEAD plus uncommitted and untracked
+/// changes), so work the session never committed still shows up.
+#[tauri::command]
+pub fn diff_since_session(project_path: String, session_id: String) -> CmdResult<SessionDiff> {
+    let path = find_session_file(&session_id)?;
+    // The first lines can be summaries or metadata without a timestamp.
+    let started_at = load_session_detail(&path, 50)?
+        .turns
+        .iter()
+        .find_map(|t| parse_timestamp(&t.timestamp))
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Session {session_id} has no timestamped messages"
+            )))
+        })?;
+
+    let repo = Repository::discover(&project_path)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let mut walk = repo
+        .revwalk()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    walk.push_head()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    walk.set_sorting(git2::Sort::TIME | git2::Sort::TOPOLOGICAL)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let mut commits_since = 0;
+    let mut base = None;
+    for oid in walk {
+        let oid = oid.map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let commit = repo
+            .find_commit(oid)
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        if commit.time().seconds() <= started_at.timestamp() {
+            base = Some(commit);
+            break;
+        }
+        commits_since += 1;
+    }
+
+    let base_tree = base
+        .as_ref()
+        .map(|c| c.tree())
+        .transpose()
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let mut opts = git2::DiffOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true);
+    let diff = repo
+        .diff_tree_to_workdir_with_index(base_tree.as_ref(), Some(&mut opts))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let mut files = Vec::new();
+    for idx in 0..diff.deltas().len() {
+        let Some(delta) = diff.get_delta(idx) else {
+            continue;
+        };
+        let status = match delta.status() {
+            git2::Delta::Added | git2::Delta::Untracked => "added",
+            git2::Delta::Deleted => "deleted",
+            git2::Delta::Renamed => "renamed",
+            _ => "modified",
+        };
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // Binary files have no patch; count them as changed with no lines.
+        let (insertions, deletions) = git2::Patch::from_diff(&diff, idx)
+            .ok()
+            .flatten()
+            .and_then(|p| p.line_stats().ok())
+            .map(|(_, added, removed)| (added, removed))
+            .unwrap_or((0, 0));
+        files.push(SessionDiffFile {
+            path,
+            status: status.to_string(),
+            insertions,
+            deletions,
+        });
+    }
+
+    Ok(SessionDiff {
+        session_id,
+        session_started_at: started_at.to_rfc3339(),
+        base_commit: base.as_ref().map(to_git_commit),
+        commits_since,
+        files_changed: files.len(),
+        insertions: files.iter().map(|f| f.insertions).sum(),
+        deletions: files.iter().map(|f| f.deletions).sum(),
+        files,
+    })
+}
+
+fn to_git_commit(commit: &git2::Commit) -> GitCommit {
+    let hash = commit.id().to_string();
+    let short_hash = hash[..7].to_string();
+    let message = commit.summary().unwrap_or("").to_string();
+    let author = commit.author().name().unwrap_or("Unknown").to_string();
+    let timestamp = {
+        let t = commit.time();
+        let dt = chrono::DateTime::from_timestamp(t.seconds(), 0)
+            .unwrap_or_default()
+            .with_timezone(&chrono::Utc);
+        dt.to_rfc3339()
+    };
+    GitCommit { hash, short_hash, message, author, timestamp }
+}
+
 #[tauri::command]
 pub fn git_branches(project_path: String) -> CmdResult<Vec<GitBranch>> {
     let repo = Repository::discover(&project_path)
@@ -138,6 +246,213 @@ pub fn git_branches(project_path: String) -> CmdResult<Vec<GitBranch>> {
     Ok(result)
 }
 
+/// Stage `paths` (relative to the repository root, as `git_status` reports
+/// them), like `git add`: changed and untracked files are added, files
+/// deleted from the working tree are staged as deletions.
+#[tauri::command]
+pub fn git_stage(project_path: String, paths: Vec<String>) -> CmdResult<()> {
+    let repo = Repository::discover(&project_path)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("Cannot stage in a bare repository")))?
+        .to_path_buf();
+    check_relative_paths(&paths)?;
+
+    let mut index = repo.index().map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    for path in &paths {
+        let result = if workdir.join(path).symlink_metadata().is_ok() {
+            index.add_path(Path::new(path))
+        } else {
+            index.remove_path(Path::new(path))
+        };
+        result.map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    }
+    index.write().map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+/// Unstage `paths`, like `git reset -- <paths>`: their index entries go
+/// back to HEAD's, and files added since HEAD (or in a repository without
+/// commits yet) leave the index again.  The working tree is not touched.
+#[tauri::command]
+pub fn git_unstage(project_path: String, paths: Vec<String>) -> CmdResult<()> {
+    let repo = Repository::discover(&project_path)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    check_relative_paths(&paths)?;
+
+    let head = repo
+        .head()
+        .ok()
+        .and_then(|h| h.peel(git2::ObjectType::Commit).ok());
+    repo.reset_default(head.as_ref(), paths.iter().map(String::as_str))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+/// Commit the staged changes with `message`, or with `amend` replace HEAD by
+/// a commit of the index with the new message, keeping HEAD's author.  The
+/// author and committer come from git config (`user.name`, `user.email`),
+/// and the `pre-commit`, `prepare-commit-msg`, `commit-msg` and
+/// `post-commit` hooks run as they would for `git commit -m`; a failing
+/// hook aborts the commit with its output.  Returns the new commit.
+#[tauri::command]
+pub fn git_commit(project_path: String, message: String, amend: bool) -> CmdResult<GitCommit> {
+    let repo = Repository::discover(&project_path)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    if repo.state() != git2::RepositoryState::Clean {
+        return Err(to_cmd_err(CommanderError::internal(
+            "A merge, rebase or cherry-pick is in progress; finish it in a terminal",
+        )));
+    }
+    if message.trim().is_empty() {
+        return Err(to_cmd_err(CommanderError::internal("The commit message is empty")));
+    }
+    let signature = repo.signature().map_err(|_| {
+        to_cmd_err(CommanderError::internal(
+            "Set user.name and user.email in your git config to commit",
+        ))
+    })?;
+    let head = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    if amend && head.is_none() {
+        return Err(to_cmd_err(CommanderError::internal("There is no commit to amend")));
+    }
+
+    run_hook(&repo, "pre-commit", &[])?;
+
+    // Like `git commit`, hand the message to the hooks in COMMIT_EDITMSG and
+    // take back whatever they leave there.
+    let message_file = repo.path().join("COMMIT_EDITMSG");
+    std::fs::write(&message_file, &message).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    run_hook(
+        &repo,
+        "prepare-commit-msg",
+        &[message_file.as_os_str(), OsStr::new("message")],
+    )?;
+    run_hook(&repo, "commit-msg", &[message_file.as_os_str()])?;
+    let message = std::fs::read_to_string(&message_file)
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let message = git2::message_prettify(&message, None)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    if message.trim().is_empty() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "A commit-msg hook left the commit message empty",
+        )));
+    }
+
+    // Re-read the index: pre-commit hooks often format and re-stage files.
+    let mut index = repo.index().map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    index.read(true).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let tree_id = index.write_tree().map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let tree = repo
+        .find_tree(tree_id)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let oid = match &head {
+        Some(head) if amend => head.amend(
+            Some("HEAD"),
+            None,
+            Some(&signature),
+            None,
+            Some(&message),
+            Some(&tree),
+        ),
+        _ => {
+            let unchanged = match &head {
+                Some(head) => head.tree_id() == tree_id,
+                None => index.is_empty(),
+            };
+            if unchanged {
+                return Err(to_cmd_err(CommanderError::internal("Nothing staged to commit")));
+            }
+            let parents: Vec<&git2::Commit> = head.iter().collect();
+            repo.commit(Some("HEAD"), &signature, &signature, &message, &tree, &parents)
+        }
+    }
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    // git ignores how post-commit exits; the commit is made either way.
+    if let Err(e) = run_hook(&repo, "post-commit", &[]) {
+        log::warn!("post-commit hook failed: {}", e);
+    }
+
+    let commit = repo
+        .find_commit(oid)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(to_git_commit(&commit))
+}
+
+/// Run hook `name` with `args` in the working tree when the repository has
+/// it (`core.hooksPath` or `.git/hooks`).  A non-zero exit fails with the
+/// hook's output, which is where hooks explain themselves.
+fn run_hook(repo: &Repository, name: &str, args: &[&OsStr]) -> CmdResult<()> {
+    let Some(hook) = hook_path(repo, name) else {
+        return Ok(());
+    };
+    let output = Command::new(&hook)
+        .args(args)
+        .current_dir(repo.workdir().unwrap_or(repo.path()))
+        .env("GIT_INDEX_FILE", repo.path().join("index"))
+        .env("GIT_EDITOR", ":")
+        .output()
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Err(to_cmd_err(CommanderError::ExternalTool {
+        tool: format!("{name} hook"),
+        kind: "failed".to_string(),
+        exit_code: output.status.code(),
+        stderr: text.trim().to_string(),
+    }))
+}
+
+fn hook_path(repo: &Repository, name: &str) -> Option<PathBuf> {
+    let configured = repo
+        .config()
+        .ok()
+        .and_then(|c| c.get_path("core.hooksPath").ok());
+    let dir = match configured {
+        // A relative hooksPath is resolved against the working tree.
+        Some(dir) if dir.is_relative() => repo.workdir()?.join(dir),
+        Some(dir) => dir,
+        // Linked worktrees share the main repository's hooks; their git dir
+        // names it in a `commondir` file.
+        None => std::fs::read_to_string(repo.path().join("commondir"))
+            .map(|common| repo.path().join(common.trim()))
+            .unwrap_or_else(|_| repo.path().to_path_buf())
+            .join("hooks"),
+    };
+    let hook = dir.join(name);
+    is_executable(&hook).then_some(hook)
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Reject paths that would reach outside the repository.
+fn check_relative_paths(paths: &[String]) -> CmdResult<()> {
+    match paths.iter().find(|p| {
+        Path::new(p)
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    }) {
+        Some(path) => Err(to_cmd_err(CommanderError::internal(format!(
+            "Not a path inside the repository: {path}"
+        )))),
+        None => Ok(()),
+    }
+}
+
 fn compute_ahead_behind(repo: &Repository, head: &git2::Reference) -> (usize, usize) {
     let local_oid = match head.target() {
         Some(o) => o,
@@ -0,0 +1,330 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{GitProgress, GitPullResult};
+use crate::state::AppState;
+use crate::utils::validate_allowed_path;
+use git2::build::{CheckoutBuilder, RepoBuilder};
+use git2::{Cred, CredentialType, FetchOptions, RemoteCallbacks, Repository};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Emitted with a `GitProgress` payload while a clone / fetch / pull runs.
+pub const EVENT_GIT_PROGRESS: &str = "git-progress";
+
+/// Minimum gap between progress events, so large transfers don't flood the
+/// frontend with thousands of events per second.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// libgit2 re-invokes the credential callback after each rejection; give up
+/// after this many attempts instead of looping forever.
+const MAX_CREDENTIAL_ATTEMPTS: usize = 3;
+
+// ─── Operation registry ─────────────────────────────────────────────────────
+
+/// Holds an operation's cancel flag in `AppState::git_operations` for as long
+/// as the operation runs.
+struct OperationGuard {
+    app: AppHandle,
+    id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl OperationGuard {
+    fn register(app: &AppHandle, id: &str) -> CmdResult<Self> {
+        let state = app.state::<AppState>();
+        let mut ops = state.git_operations.lock();
+        if ops.contains_key(id) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Git operation already running: {}",
+                id
+            ))));
+        }
+        let cancelled = Arc::new(AtomicBool::new(false));
+        ops.insert(id.to_string(), cancelled.clone());
+        Ok(Self {
+            app: app.clone(),
+            id: id.to_string(),
+            cancelled,
+        })
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        self.app
+            .state::<AppState>()
+            .git_operations
+            .lock()
+            .remove(&self.id);
+    }
+}
+
+// ─── Callbacks ──────────────────────────────────────────────────────────────
+
+fn emit_progress(app: &AppHandle, progress: GitProgress) {
+    let _ = app.emit(EVENT_GIT_PROGRESS, progress);
+}
+
+fn empty_progress(operation_id: &str, phase: &str) -> GitProgress {
+    GitProgress {
+        operation_id: operation_id.to_string(),
+        phase: phase.to_string(),
+        received_objects: 0,
+        total_objects: 0,
+        indexed_deltas: 0,
+        total_deltas: 0,
+        received_bytes: 0,
+        checkout_completed: 0,
+        checkout_total: 0,
+    }
+}
+
+/// Fetch options that report transfer progress, abort when the operation is
+/// cancelled, and authenticate via ssh-agent or the git credential helper.
+fn fetch_options(guard: &OperationGuard) -> FetchOptions<'static> {
+    let mut callbacks = RemoteCallbacks::new();
+
+    let app = guard.app.clone();
+    let id = guard.id.clone();
+    let cancelled = guard.cancelled.clone();
+    let mut last_emit: Option<Instant> = None;
+    callbacks.transfer_progress(move |stats| {
+        if cancelled.load(Ordering::Relaxed) {
+            // Returning false makes libgit2 abort with GIT_EUSER.
+            return false;
+        }
+        let receiving = stats.received_objects() < stats.total_objects();
+        if last_emit.is_none_or(|t| t.elapsed() >= PROGRESS_INTERVAL) {
+            last_emit = Some(Instant::now());
+            emit_progress(
+                &app,
+                GitProgress {
+                    received_objects: stats.received_objects(),
+                    total_objects: stats.total_objects(),
+                    indexed_deltas: stats.indexed_deltas(),
+                    total_deltas: stats.total_deltas(),
+                    received_bytes: stats.received_bytes(),
+                    ..empty_progress(&id, if receiving { "receiving" } else { "resolving" })
+                },
+            );
+        }
+        true
+    });
+
+    let mut attempts = 0;
+    callbacks.credentials(move |url, username, allowed| {
+        attempts += 1;
+        if attempts > MAX_CREDENTIAL_ATTEMPTS {
+            return Err(git2::Error::from_str("Authentication failed"));
+        }
+        if allowed.contains(CredentialType::SSH_KEY) {
+            Cred::ssh_key_from_agent(username.unwrap_or("git"))
+        } else if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            let config = git2::Config::open_default()?;
+            Cred::credential_helper(&config, url, username)
+        } else {
+            Cred::default()
+        }
+    });
+
+    let mut options = FetchOptions::new();
+    options.remote_callbacks(callbacks);
+    options
+}
+
+/// Map a git2 error, turning the abort caused by a cancel into a clear message.
+fn map_git_err(guard: &OperationGuard, e: git2::Error) -> String {
+    if guard.cancelled.load(Ordering::Relaxed) || e.code() == git2::ErrorCode::User {
+        to_cmd_err(CommanderError::git("Operation cancelled"))
+    } else {
+        to_cmd_err(CommanderError::from(e))
+    }
+}
+
+/// Run blocking git work off the async runtime's worker threads.
+async fn run_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> CmdResult<T> + Send + 'static,
+) -> CmdResult<T> {
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| to_cmd_err(CommanderError::internal(e)))?
+}
+
+// ─── Commands ───────────────────────────────────────────────────────────────
+
+/// Clone `url` into `dest_path` (which must not exist yet), reporting
+/// progress as `git-progress` events tagged with `operation_id`.
+#[tauri::command]
+pub async fn clone_repository(
+    app: AppHandle,
+    url: String,
+    dest_path: String,
+    operation_id: String,
+) -> CmdResult<String> {
+    let dest = validate_allowed_path(&app.state::<AppState>(), &dest_path)?;
+    if dest.exists() {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Destination already exists: {}",
+            dest.display()
+        ))));
+    }
+
+    run_blocking(move || {
+        let guard = OperationGuard::register(&app, &operation_id)?;
+
+        let mut checkout = CheckoutBuilder::new();
+        let progress_app = app.clone();
+        let progress_id = operation_id.clone();
+        let mut last_emit: Option<Instant> = None;
+        checkout.progress(move |_path, completed, total| {
+            if completed == total || last_emit.is_none_or(|t| t.elapsed() >= PROGRESS_INTERVAL) {
+                last_emit = Some(Instant::now());
+                emit_progress(
+                    &progress_app,
+                    GitProgress {
+                        checkout_completed: completed,
+                        checkout_total: total,
+                        ..empty_progress(&progress_id, "checkout")
+                    },
+                );
+            }
+        });
+
+        let result = RepoBuilder::new()
+            .fetch_options(fetch_options(&guard))
+            .with_checkout(checkout)
+            .clone(&url, &dest);
+
+        if let Err(e) = result {
+            // Don't leave a half-written checkout behind.
+            let _ = std::fs::remove_dir_all(&dest);
+            return Err(map_git_err(&guard, e));
+        }
+
+        emit_progress(&app, empty_progress(&operation_id, "done"));
+        Ok(dest.to_string_lossy().to_string())
+    })
+    .await
+}
+
+/// Fetch `remote` (default `origin`) for the repository at `project_path`.
+#[tauri::command]
+pub async fn git_fetch(
+    app: AppHandle,
+    project_path: String,
+    remote: Option<String>,
+    operation_id: String,
+) -> CmdResult<()> {
+    run_blocking(move || {
+        let guard = OperationGuard::register(&app, &operation_id)?;
+        let repo =
+            Repository::discover(&project_path).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+        fetch_remote(&repo, remote.as_deref().unwrap_or("origin"), &guard)?;
+
+        emit_progress(&app, empty_progress(&operation_id, "done"));
+        Ok(())
+    })
+    .await
+}
+
+/// Fetch the current branch's upstream and fast-forward to it.  Diverged
+/// branches are rejected rather than merged, and local changes that would be
+/// overwritten abort the checkout before HEAD moves.
+#[tauri::command]
+pub async fn git_pull(
+    app: AppHandle,
+    project_path: String,
+    remote: Option<String>,
+    operation_id: String,
+) -> CmdResult<GitPullResult> {
+    run_blocking(move || {
+        let guard = OperationGuard::register(&app, &operation_id)?;
+        let repo =
+            Repository::discover(&project_path).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+        let head = repo
+            .head()
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let head_ref = head
+            .name()
+            .ok_or_else(|| to_cmd_err(CommanderError::git("HEAD is not a named reference")))?
+            .to_string();
+        let upstream = repo
+            .branch_upstream_name(&head_ref)
+            .map_err(|_| to_cmd_err(CommanderError::git("Current branch has no upstream")))?;
+        let upstream = upstream.as_str().unwrap_or_default().to_string();
+        let remote_name = match remote {
+            Some(r) => r,
+            None => repo
+                .branch_upstream_remote(&head_ref)
+                .ok()
+                .and_then(|r| r.as_str().map(|s| s.to_string()))
+                .unwrap_or_else(|| "origin".to_string()),
+        };
+
+        fetch_remote(&repo, &remote_name, &guard)?;
+
+        let git_err = |e: git2::Error| to_cmd_err(CommanderError::from(e));
+        let target = repo
+            .find_reference(&upstream)
+            .and_then(|r| repo.reference_to_annotated_commit(&r))
+            .map_err(git_err)?;
+        let (analysis, _) = repo.merge_analysis(&[&target]).map_err(git_err)?;
+
+        let status = if analysis.is_up_to_date() {
+            "up_to_date"
+        } else if analysis.is_fast_forward() {
+            let commit = repo.find_object(target.id(), None).map_err(git_err)?;
+            // Safe checkout fails on conflicting local edits, before the ref moves.
+            repo.checkout_tree(&commit, Some(CheckoutBuilder::new().safe()))
+                .map_err(git_err)?;
+            repo.find_reference(&head_ref)
+                .and_then(|mut r| r.set_target(target.id(), "pull: fast-forward"))
+                .map_err(git_err)?;
+            "fast_forward"
+        } else {
+            return Err(to_cmd_err(CommanderError::git(
+                "Branch has diverged from its upstream; merge or rebase manually",
+            )));
+        };
+
+        let head_id = repo
+            .head()
+            .ok()
+            .and_then(|h| h.target())
+            .map(|id| id.to_string()[..7].to_string())
+            .unwrap_or_default();
+
+        emit_progress(&app, empty_progress(&operation_id, "done"));
+        Ok(GitPullResult {
+            status: status.to_string(),
+            head: head_id,
+        })
+    })
+    .await
+}
+
+/// Request cancellation of a running clone / fetch / pull.  Returns `false`
+/// when no operation with that id is running.
+#[tauri::command]
+pub fn cancel_git_operation(state: State<AppState>, operation_id: String) -> bool {
+    match state.git_operations.lock().get(&operation_id) {
+        Some(flag) => {
+            flag.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
+fn fetch_remote(repo: &Repository, remote_name: &str, guard: &OperationGuard) -> CmdResult<()> {
+    let mut remote = repo
+        .find_remote(remote_name)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    // Empty refspec list = use the remote's configured fetch refspecs.
+    remote
+        .fetch(&[] as &[&str], Some(&mut fetch_options(guard)), None)
+        .map_err(|e| map_git_err(guard, e))
+}
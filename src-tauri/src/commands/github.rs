@@ -1,22 +1,34 @@
+use crate::commands::issue_cache::{cache_issue_comments, cache_issue_state, cache_issues};
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::models::{CreateGithubIssueOutput, TaskGithubLink, UpsertTaskGithubLinkInput};
+use crate::models::{
+    CachedIssueComment, ClosingCommitSuggestion, CreateGithubIssueOutput, CreateGithubPrOutput,
+    GithubAuthStatus, GithubIssuePage, GithubIssueSummary, GithubPullRequest, GithubRemote,
+    IssueStateError, PlanningItemGithubLink, PlanningItemIssueStateRefresh, PrCheck,
+    TaskGithubLink, TaskIssueStateRefresh, UpsertPlanningItemGithubLinkInput,
+    UpsertTaskGithubLinkInput,
+};
+use crate::services::forge::{
+    forge_for_repo, issue_reference, parse_gitlab_issue_url, parse_gitlab_remote,
+};
+use crate::services::github_api::{self, GithubApi};
+use crate::services::github_queue;
 use crate::state::AppState;
+use crate::utils::run_external_tool;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
 use tauri::State;
 
 // ─── Helpers ────────────────────────────────────────────────────────────────
 
 /// Parse a GitHub remote URL into `"owner/repo"` format.
-/// Handles both HTTPS (`https://github.com/owner/repo.git`) and SSH
-/// (`git@github.com:owner/repo.git`) forms.
+/// Handles HTTPS (`https://github.com/owner/repo.git`) and both SSH
+/// (`git@github.com:owner/repo.git`, `ssh://git@github.com/owner/repo`) forms.
 fn parse_github_repo(url: &str) -> Option<String> {
     let url = url.trim();
-    let path = if let Some(p) = url.strip_prefix("https://github.com/") {
-        p
-    } else if let Some(p) = url.strip_prefix("git@github.com:") {
-        p
-    } else {
-        return None;
-    };
+    let path = ["https://github.com/", "git@github.com:", "ssh://git@github.com/"]
+        .iter()
+        .find_map(|prefix| url.strip_prefix(prefix))?;
     let repo = path.trim_end_matches('/').trim_end_matches(".git");
     // Must look like "owner/repo" (exactly one slash, non-empty parts)
     let parts: Vec<&str> = repo.splitn(2, '/').collect();
@@ -29,14 +41,17 @@ fn parse_github_repo(url: &str) -> Option<String> {
 
 /// Parse the `#number` from a GitHub issue URL such as
 /// `https://github.com/owner/repo/issues/123`.
-fn parse_issue_number(url: &str) -> Option<i64> {
+pub(crate) fn parse_issue_number(url: &str) -> Option<i64> {
     url.rsplit('/').next()?.parse::<i64>().ok()
 }
 
-/// Parse `"owner/repo"` from a GitHub issue URL.
-fn parse_repo_from_url(url: &str) -> Option<String> {
+/// Parse `"owner/repo"` from a GitHub issue URL, or the host-qualified
+/// repository from a GitLab one (see `services::forge`).
+pub(crate) fn parse_repo_from_url(url: &str) -> Option<String> {
     // https://github.com/owner/repo/issues/123
-    let path = url.strip_prefix("https://github.com/")?;
+    let Some(path) = url.strip_prefix("https://github.com/") else {
+        return parse_gitlab_issue_url(url);
+    };
     let parts: Vec<&str> = path.splitn(3, '/').collect();
     if parts.len() >= 2 {
         Some(format!("{}/{}", parts[0], parts[1]))
@@ -49,49 +64,322 @@ fn open_in_browser(url: &str) {
     let _ = std::process::Command::new("open").arg(url).spawn();
 }
 
+// ─── Auth checks ────────────────────────────────────────────────────────────
+
+pub const GH_NOT_INSTALLED: &str = "GH_NOT_INSTALLED";
+pub const GH_NOT_AUTHENTICATED: &str = "GH_NOT_AUTHENTICATED";
+pub const GH_MISSING_SCOPE: &str = "GH_MISSING_SCOPE";
+pub const GH_RATE_LIMITED: &str = "GH_RATE_LIMITED";
+pub const GH_NETWORK_ERROR: &str = "GH_NETWORK_ERROR";
+
+/// Classic-token scopes needed to create and close issues.  `repo` implies
+/// `public_repo`, so either satisfies the requirement.
+const ISSUE_SCOPE_ALTERNATIVES: &[&str] = &["repo", "public_repo"];
+
+fn remediation_for(problem: &str, token_stored: bool) -> &'static str {
+    match problem {
+        GH_NOT_AUTHENTICATED if token_stored => {
+            "The stored GitHub token was rejected; save a new one in Settings."
+        }
+        GH_MISSING_SCOPE if token_stored => {
+            "Save a token with the repo scope (or Issues: read and write) in Settings."
+        }
+        GH_NOT_INSTALLED => "Install the GitHub CLI (brew install gh) and restart the app.",
+        GH_NOT_AUTHENTICATED => "Run `gh auth login` in a terminal.",
+        GH_MISSING_SCOPE => "Run `gh auth refresh -s repo` to grant the repo scope.",
+        GH_RATE_LIMITED => "GitHub API rate limit reached; wait for the reset time and retry.",
+        _ => "Check your network connection and try again.",
+    }
+}
+
+/// Version from the first line of `gh --version`, e.g.
+/// `gh version 2.40.1 (2023-12-13)`, or `None` when gh cannot be run.
+fn gh_version() -> Option<String> {
+    let output = std::process::Command::new("gh").arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout.lines().next()?.split_whitespace().nth(2)?;
+    Some(version.to_string())
+}
+
+/// Split the output of `gh api -i` into lower-cased headers and the body.
+fn split_http_response(raw: &str) -> (std::collections::HashMap<String, String>, &str) {
+    let raw = raw.trim_start();
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .or_else(|| raw.split_once("\n\n"))
+        .unwrap_or((raw, ""));
+    let headers = head
+        .lines()
+        .skip(1) // status line
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+    (headers, body)
+}
+
+/// Summarise the gh install and whichever credentials GitHub actions will
+/// use: the stored API token if there is one, otherwise `gh`'s own login.
+/// Never fails: problems are reported through `problem` / `remediation`.
+fn github_auth_status() -> GithubAuthStatus {
+    let mut status = GithubAuthStatus {
+        installed: false,
+        gh_version: gh_version(),
+        token_stored: false,
+        authenticated: false,
+        login: None,
+        scopes: vec![],
+        missing_scopes: vec![],
+        rate_limit_remaining: None,
+        rate_limit_reset: None,
+        problem: None,
+        remediation: None,
+    };
+    status.installed = status.gh_version.is_some();
+    let fail = |mut status: GithubAuthStatus, problem: &str| {
+        status.problem = Some(problem.to_string());
+        status.remediation = Some(remediation_for(problem, status.token_stored).to_string());
+        status
+    };
+
+    let scopes_header = if let Some(api) = GithubApi::from_keychain() {
+        status.token_stored = true;
+        match api.token_status() {
+            Ok(token) => {
+                status.login = Some(token.login);
+                status.rate_limit_remaining = token.rate_limit_remaining;
+                status.rate_limit_reset = token.rate_limit_reset;
+                token.scopes
+            }
+            Err(CommanderError::GithubAuthError { kind, .. }) => return fail(status, &kind),
+            Err(_) => return fail(status, GH_NETWORK_ERROR),
+        }
+    } else if !status.installed {
+        return fail(status, GH_NOT_INSTALLED);
+    } else {
+        match gh_user_status(&mut status) {
+            Ok(scopes) => scopes,
+            Err(problem) => return fail(status, problem),
+        }
+    };
+    status.authenticated = true;
+
+    // Fine-grained tokens omit the header entirely; only judge classic tokens.
+    if let Some(raw_scopes) = scopes_header {
+        status.scopes = raw_scopes
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if !ISSUE_SCOPE_ALTERNATIVES
+            .iter()
+            .any(|s| status.scopes.iter().any(|g| g == s))
+        {
+            status.missing_scopes = vec!["repo".to_string()];
+            return fail(status, GH_MISSING_SCOPE);
+        }
+    }
+
+    if status.rate_limit_remaining == Some(0) {
+        return fail(status, GH_RATE_LIMITED);
+    }
+
+    status
+}
+
+/// Query `gh api -i user`, filling in login and rate limit.  Returns the raw
+/// `X-OAuth-Scopes` header, or the `GH_*` problem when the call fails.
+fn gh_user_status(status: &mut GithubAuthStatus) -> Result<Option<String>, &'static str> {
+    let output = std::process::Command::new("gh")
+        .args(["api", "-i", "user"])
+        .output()
+        .map_err(|_| GH_NOT_INSTALLED)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr).to_lowercase();
+    let (headers, body) = split_http_response(&stdout);
+
+    status.rate_limit_remaining = headers
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.parse().ok());
+    status.rate_limit_reset = headers
+        .get("x-ratelimit-reset")
+        .and_then(|v| v.parse().ok());
+    github_queue::record_rate_limit(status.rate_limit_remaining, status.rate_limit_reset);
+
+    if !output.status.success() {
+        return Err(
+            if stderr.contains("gh auth login")
+                || stderr.contains("not logged")
+                || stderr.contains("http 401")
+            {
+                GH_NOT_AUTHENTICATED
+            } else if status.rate_limit_remaining == Some(0) || stderr.contains("rate limit") {
+                GH_RATE_LIMITED
+            } else {
+                GH_NETWORK_ERROR
+            },
+        );
+    }
+
+    status.login = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v["login"].as_str().map(|s| s.to_string()));
+    Ok(headers.get("x-oauth-scopes").cloned())
+}
+
+/// Error out with a `GITHUB_AUTH_ERROR` before running a gh command that
+/// would otherwise fail with raw stderr.
+pub(crate) fn require_github_auth() -> CmdResult<()> {
+    let status = github_auth_status();
+    match (status.problem, status.remediation) {
+        (Some(kind), Some(reason)) => {
+            Err(to_cmd_err(CommanderError::GithubAuthError { kind, reason }))
+        }
+        _ => Ok(()),
+    }
+}
+
 // ─── Commands ───────────────────────────────────────────────────────────────
 
+/// Report whether the GitHub CLI is installed (and which version), whether
+/// an API token is stored, which account is signed in with which scopes, and
+/// how much API rate limit remains.
+#[tauri::command]
+pub fn check_github_auth() -> GithubAuthStatus {
+    github_auth_status()
+}
+
+/// Store a personal access token in the OS keychain for the REST client, or
+/// remove it with `null`.  The token is checked against `GET /user` first and
+/// the login it belongs to is returned.
+#[tauri::command]
+pub fn set_github_token(token: Option<String>) -> CmdResult<Option<String>> {
+    let Some(token) = token.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()) else {
+        github_api::set_stored_token(None).map_err(to_cmd_err)?;
+        return Ok(None);
+    };
+    let status = GithubApi::with_token(token.clone())
+        .and_then(|api| api.token_status())
+        .map_err(to_cmd_err)?;
+    github_api::set_stored_token(Some(&token)).map_err(to_cmd_err)?;
+    Ok(Some(status.login))
+}
+
 /// Return the GitHub `"owner/repo"` string for the git repository at
-/// `project_path`, or `null` if the directory has no GitHub origin remote.
+/// `project_path`: `origin` when it points at GitHub, otherwise the first of
+/// `list_github_remotes`.  `null` if no remote points at GitHub.
 #[tauri::command]
 pub fn detect_github_repo(project_path: String) -> Option<String> {
-    let repo = git2::Repository::open(&project_path).ok()?;
-    let remote = repo.find_remote("origin").ok()?;
-    let url = remote.url()?.to_string();
-    parse_github_repo(&url)
+    let remotes = github_remotes(&project_path);
+    remotes
+        .iter()
+        .find(|r| r.remote == "origin")
+        .or_else(|| remotes.first())
+        .map(|r| r.repo.clone())
+}
+
+/// Every GitHub repository the project's remotes point at, for choosing
+/// where an issue goes.  In a fork setup `upstream` comes first, then
+/// `origin`, then the other remotes by name; a repo reachable through
+/// several remotes is listed once.
+#[tauri::command]
+pub fn list_github_remotes(project_path: String) -> Vec<GithubRemote> {
+    github_remotes(&project_path)
+}
+
+/// Like `list_github_remotes`, but also lists remotes on GitLab instances
+/// (host-qualified, e.g. `"gitlab.com/group/app"`), for the issue link and
+/// create workflow that works on either forge.
+#[tauri::command]
+pub fn list_issue_remotes(project_path: String) -> Vec<GithubRemote> {
+    matching_remotes(&project_path, |url| {
+        parse_github_repo(url).or_else(|| parse_gitlab_remote(url))
+    })
+}
+
+fn github_remotes(project_path: &str) -> Vec<GithubRemote> {
+    matching_remotes(project_path, parse_github_repo)
+}
+
+/// Remotes whose URL `parse` maps to a repository, ranked and de-duplicated
+/// as described on `list_github_remotes`.
+fn matching_remotes(project_path: &str, parse: fn(&str) -> Option<String>) -> Vec<GithubRemote> {
+    let Ok(repo) = git2::Repository::open(project_path) else {
+        return vec![];
+    };
+    let Ok(names) = repo.remotes() else {
+        return vec![];
+    };
+    let mut remotes: Vec<GithubRemote> = names
+        .iter()
+        .flatten()
+        .filter_map(|name| {
+            let remote = repo.find_remote(name).ok()?;
+            Some(GithubRemote {
+                remote: name.to_string(),
+                repo: parse(remote.url()?)?,
+            })
+        })
+        .collect();
+    let rank = |name: &str| match name {
+        "upstream" => 0,
+        "origin" => 1,
+        _ => 2,
+    };
+    remotes.sort_by(|a, b| (rank(&a.remote), &a.remote).cmp(&(rank(&b.remote), &b.remote)));
+    let mut seen = std::collections::HashSet::new();
+    remotes.retain(|r| seen.insert(r.repo.to_lowercase()));
+    remotes
 }
 
-/// Call `gh issue create` and open the resulting URL in the default browser.
-/// Returns `{ number, url }` on success.
+/// Open an issue on the forge hosting `repo` and open the resulting URL in
+/// the default browser.  `labels` usually come from a template picked via
+/// `get_issue_templates`.  Returns `{ number, url }` on success.
 #[tauri::command]
 pub fn create_github_issue(
+    state: State<AppState>,
     repo: String,
     title: String,
     body: String,
+    labels: Option<Vec<String>>,
 ) -> CmdResult<CreateGithubIssueOutput> {
-    let output = std::process::Command::new("gh")
-        .args([
-            "issue", "create",
-            "--repo", &repo,
-            "--title", &title,
-            "--body", &body,
-            "--json", "number,url",
-        ])
-        .output()
-        .map_err(|e| {
-            to_cmd_err(CommanderError::internal(format!(
-                "Failed to run gh CLI: {}. Is gh installed?",
-                e
-            )))
-        })?;
+    let labels = labels.unwrap_or_default();
+    let created = forge_for_repo(&repo).create_issue(&repo, &title, &body, &labels)?;
+    state.github.invalidate(&issues_key(&repo));
+    open_in_browser(&created.url);
+    Ok(created)
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(to_cmd_err(CommanderError::internal(format!(
-            "gh issue create failed: {}",
-            stderr.trim()
-        ))));
+/// Open a GitHub issue through the REST API when a token is stored,
+/// otherwise with `gh issue create`.
+pub(crate) fn create_remote_issue(
+    repo: &str,
+    title: &str,
+    body: &str,
+    labels: &[String],
+) -> CmdResult<CreateGithubIssueOutput> {
+    if let Some(api) = GithubApi::from_keychain() {
+        let (number, url) = api.create_issue(repo, title, body, labels).map_err(to_cmd_err)?;
+        return Ok(CreateGithubIssueOutput { number, url });
+    }
+
+    require_github_auth()?;
+
+    let mut command = std::process::Command::new("gh");
+    command.args([
+        "issue", "create",
+        "--repo", repo,
+        "--title", title,
+        "--body", body,
+        "--json", "number,url",
+    ]);
+    for label in labels {
+        command.args(["--label", label]);
     }
+    let output = run_external_tool(&mut command)?;
 
     let json: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|e| {
         to_cmd_err(CommanderError::internal(format!(
@@ -109,11 +397,236 @@ pub fn create_github_issue(
         .ok_or_else(|| to_cmd_err(CommanderError::internal("Missing 'url' in gh output")))?
         .to_string();
 
-    open_in_browser(&url);
-
     Ok(CreateGithubIssueOutput { number, url })
 }
 
+const ISSUE_PAGE_SIZE: usize = 30;
+
+const ISSUE_STATES: &[&str] = &["open", "closed", "all"];
+
+/// How long issue and pull request listings are served from the queue's
+/// cache; CI checks change faster and are kept for less.
+const LIST_CACHE_TTL: Duration = Duration::from_secs(30);
+const CHECKS_CACHE_TTL: Duration = Duration::from_secs(15);
+
+/// Queue key prefix of `repo`'s issue listings, dropped when an issue is
+/// created or closed.
+pub(crate) fn issues_key(repo: &str) -> String {
+    format!("issues/{repo}/")
+}
+
+/// One page (1-based, 30 per page) of `repo`'s issues for picking one to
+/// link, newest first.  `state` is `"open"` (default), `"closed"` or
+/// `"all"`; with several `labels` an issue must carry all of them.  `gh`
+/// cannot skip results, so page `n` fetches the first `n` pages and keeps
+/// the last.  Fetched issues also refresh the offline issue cache.
+#[tauri::command]
+pub fn list_github_issues(
+    state: State<AppState>,
+    repo: String,
+    issue_state: Option<String>,
+    labels: Option<Vec<String>>,
+    page: Option<usize>,
+) -> CmdResult<GithubIssuePage> {
+    let issue_state = issue_state.unwrap_or_else(|| "open".to_string());
+    if !ISSUE_STATES.contains(&issue_state.as_str()) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid issue state: {issue_state}"
+        ))));
+    }
+    let page = page.unwrap_or(1).max(1);
+    let labels = labels.unwrap_or_default();
+
+    // One extra issue tells whether another page exists.
+    let limit = (page * ISSUE_PAGE_SIZE + 1).to_string();
+    let key = format!("{}{issue_state}/{limit}/{}", issues_key(&repo), labels.join(","));
+    let issues: Vec<serde_json::Value> = state.github.call(&key, LIST_CACHE_TTL, || {
+        require_github_auth()?;
+        let mut cmd = std::process::Command::new("gh");
+        cmd.args([
+            "issue", "list",
+            "--repo", &repo,
+            "--state", &issue_state,
+            "--limit", &limit,
+            "--json", "number,title,state,url,labels,author,updatedAt",
+        ]);
+        for label in &labels {
+            cmd.args(["--label", label]);
+        }
+        let output = run_external_tool(&mut cmd)?;
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))
+    })?;
+
+    {
+        let db = state.db.lock();
+        if let Some(conn) = db.as_ref() {
+            // Keep the fetched titles and states browsable offline.
+            if let Err(e) = cache_issues(conn, &repo, &issues) {
+                log::warn!("failed to cache issues of {}: {}", repo, e);
+            }
+        }
+    }
+
+    let start = (page - 1) * ISSUE_PAGE_SIZE;
+    Ok(GithubIssuePage {
+        has_more: issues.len() > start + ISSUE_PAGE_SIZE,
+        issues: issues
+            .iter()
+            .skip(start)
+            .take(ISSUE_PAGE_SIZE)
+            .filter_map(|issue| {
+                Some(GithubIssueSummary {
+                    number: issue["number"].as_i64()?,
+                    title: issue["title"].as_str()?.to_string(),
+                    state: issue["state"].as_str().unwrap_or("OPEN").to_lowercase(),
+                    url: issue["url"].as_str()?.to_string(),
+                    labels: issue["labels"]
+                        .as_array()
+                        .map(|labels| {
+                            labels
+                                .iter()
+                                .filter_map(|l| l["name"].as_str().map(str::to_string))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    author: issue["author"]["login"].as_str().map(str::to_string),
+                    updated_at: issue["updatedAt"].as_str().map(str::to_string),
+                })
+            })
+            .collect(),
+        page,
+    })
+}
+
+const PR_LIST_LIMIT: &str = "50";
+
+/// Open pull requests of `repo`, most recently updated first.
+#[tauri::command]
+pub fn list_github_prs(state: State<AppState>, repo: String) -> CmdResult<Vec<GithubPullRequest>> {
+    let prs: Vec<serde_json::Value> = state.github.call(&format!("prs/{repo}"), LIST_CACHE_TTL, || {
+        require_github_auth()?;
+        let output = run_external_tool(std::process::Command::new("gh").args([
+            "pr", "list",
+            "--repo", &repo,
+            "--limit", PR_LIST_LIMIT,
+            "--json", "number,title,state,url,headRefName,baseRefName,isDraft,author,reviewDecision,updatedAt",
+        ]))?;
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))
+    })?;
+    let text = |v: &serde_json::Value| v.as_str().map(str::to_string);
+    Ok(prs
+        .iter()
+        .filter_map(|pr| {
+            Some(GithubPullRequest {
+                number: pr["number"].as_i64()?,
+                title: text(&pr["title"])?,
+                state: pr["state"].as_str().unwrap_or("OPEN").to_lowercase(),
+                url: text(&pr["url"])?,
+                head_ref: text(&pr["headRefName"]).unwrap_or_default(),
+                base_ref: text(&pr["baseRefName"]).unwrap_or_default(),
+                is_draft: pr["isDraft"].as_bool().unwrap_or(false),
+                author: text(&pr["author"]["login"]),
+                // gh reports "" when no review is required.
+                review_decision: text(&pr["reviewDecision"]).filter(|d| !d.is_empty()),
+                updated_at: text(&pr["updatedAt"]),
+            })
+        })
+        .collect())
+}
+
+/// CI checks of pull request `number`.  `gh pr checks` exits non-zero while
+/// checks fail or are pending, so its JSON is read whatever the exit code;
+/// a PR without any checks yields an empty list.
+#[tauri::command]
+pub fn get_pr_checks(
+    state: State<AppState>,
+    repo: String,
+    number: i64,
+) -> CmdResult<Vec<PrCheck>> {
+    let key = format!("checks/{repo}/{number}");
+    let checks: Vec<serde_json::Value> = state.github.call(&key, CHECKS_CACHE_TTL, || {
+        require_github_auth()?;
+        let output = std::process::Command::new("gh")
+            .args([
+                "pr", "checks",
+                &number.to_string(),
+                "--repo", &repo,
+                "--json", "name,workflow,bucket,link",
+            ])
+            .output()
+            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+        serde_json::from_slice(&output.stdout).or_else(|_| {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if stderr.contains("no checks reported") {
+                return Ok(Vec::new());
+            }
+            Err(to_cmd_err(CommanderError::ExternalTool {
+                tool: "gh".to_string(),
+                kind: "failed".to_string(),
+                exit_code: output.status.code(),
+                stderr,
+            }))
+        })
+    })?;
+    Ok(checks
+        .iter()
+        .filter_map(|check| {
+            Some(PrCheck {
+                name: check["name"].as_str()?.to_string(),
+                workflow: check["workflow"]
+                    .as_str()
+                    .filter(|w| !w.is_empty())
+                    .map(str::to_string),
+                bucket: check["bucket"].as_str().unwrap_or("pending").to_string(),
+                link: check["link"]
+                    .as_str()
+                    .filter(|l| !l.is_empty())
+                    .map(str::to_string),
+            })
+        })
+        .collect())
+}
+
+/// Open a pull request from `head` into `base` with `gh pr create`.  `head`
+/// must already be pushed.  Returns `{ number, url }`.
+#[tauri::command]
+pub fn create_github_pr(
+    repo: String,
+    base: String,
+    head: String,
+    title: String,
+    body: String,
+) -> CmdResult<CreateGithubPrOutput> {
+    if title.trim().is_empty() {
+        return Err(to_cmd_err(CommanderError::internal("A pull request needs a title")));
+    }
+    require_github_auth()?;
+
+    // `gh pr create` has no `--json`; it prints the new PR's URL.
+    let output = run_external_tool(std::process::Command::new("gh").args([
+        "pr", "create",
+        "--repo", &repo,
+        "--base", &base,
+        "--head", &head,
+        "--title", &title,
+        "--body", &body,
+    ]))?;
+    let url = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with("https://"))
+        .map(str::to_string)
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("Missing PR URL in gh output")))?;
+    let number = url
+        .rsplit_once("/pull/")
+        .and_then(|(_, n)| n.parse().ok())
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!("Unexpected PR URL: {url}")))
+        })?;
+
+    Ok(CreateGithubPrOutput { number, url })
+}
+
 /// Persist (insert or replace) a task → GitHub issue link.
 #[tauri::command]
 pub fn upsert_task_github_link(
@@ -178,7 +691,7 @@ pub fn get_task_github_links(state: State<AppState>) -> CmdResult<Vec<TaskGithub
     load_all_links(conn).map_err(to_cmd_err)
 }
 
-fn load_all_links(conn: &rusqlite::Connection) -> Result<Vec<TaskGithubLink>, CommanderError> {
+pub(crate) fn load_all_links(conn: &rusqlite::Connection) -> Result<Vec<TaskGithubLink>, CommanderError> {
     let mut stmt = conn
         .prepare(
             "SELECT task_id, team_id, github_issue_url, github_issue_number,
@@ -207,7 +720,7 @@ fn load_all_links(conn: &rusqlite::Connection) -> Result<Vec<TaskGithubLink>, Co
     Ok(links)
 }
 
-/// Close a linked GitHub issue via `gh issue close` and cache the new state.
+/// Close a linked GitHub issue and cache the new state.
 #[tauri::command]
 pub fn close_github_issue(
     state: State<AppState>,
@@ -216,23 +729,8 @@ pub fn close_github_issue(
     repo: String,
     number: i64,
 ) -> CmdResult<TaskGithubLink> {
-    let output = std::process::Command::new("gh")
-        .args(["issue", "close", &number.to_string(), "--repo", &repo])
-        .output()
-        .map_err(|e| {
-            to_cmd_err(CommanderError::internal(format!(
-                "Failed to run gh CLI: {}",
-                e
-            )))
-        })?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(to_cmd_err(CommanderError::internal(format!(
-            "gh issue close failed: {}",
-            stderr.trim()
-        ))));
-    }
+    forge_for_repo(&repo).close_issue(&repo, number)?;
+    state.github.invalidate(&issues_key(&repo));
 
     let now = chrono::Utc::now().to_rfc3339();
 
@@ -248,6 +746,7 @@ pub fn close_github_issue(
         rusqlite::params![now, task_id, team_id],
     )
     .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let _ = cache_issue_state(conn, &repo, number, "closed");
 
     // Return the full updated link.
     let link = conn
@@ -274,62 +773,387 @@ pub fn close_github_issue(
     Ok(link)
 }
 
-/// Fetch the current state of every linked GitHub issue via `gh issue view`
-/// and update the cache.  Skips links where repo or number are missing.
-/// Failures for individual issues are silently skipped so a single bad link
-/// does not abort the whole refresh.
+/// Words GitHub and GitLab accept in front of an issue reference to close
+/// it when the commit lands on the default branch.
+const CLOSING_KEYWORDS: &[&str] = &[
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// A `Fixes owner/repo#N` trailer for the issue linked to a task, so the
+/// commit that finishes the task closes the issue once it is pushed.  The
+/// full reference works from any repository, not just the issue's own.
+/// With `message`, the draft commit message is returned with the trailer
+/// appended after git's trailer conventions, ready for `git_commit`; a
+/// message that already carries it comes back unchanged.
+#[tauri::command]
+pub fn suggest_closing_commit(
+    state: State<AppState>,
+    task_id: String,
+    team_id: String,
+    message: Option<String>,
+) -> CmdResult<ClosingCommitSuggestion> {
+    let (url, repo, number) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        conn.query_row(
+            "SELECT github_issue_url, github_repo, github_issue_number
+             FROM task_github_links WHERE task_id = ?1 AND team_id = ?2",
+            rusqlite::params![task_id, team_id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                ))
+            },
+        )
+        .ok()
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Task {team_id}/{task_id} has no linked issue"
+            )))
+        })?
+    };
+    let repo = repo.or_else(|| parse_repo_from_url(&url));
+    let (Some(repo), Some(number)) = (repo, number) else {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Cannot tell which issue {url} is"
+        ))));
+    };
+
+    let trailer = format!("Fixes {}", issue_reference(&repo, number));
+    Ok(ClosingCommitSuggestion {
+        message: message.map(|m| append_trailer(&m, &trailer)),
+        trailer,
+    })
+}
+
+/// `message` with `trailer` added to its trailer block, which is started
+/// after a blank line unless the last paragraph already is one.
+fn append_trailer(message: &str, trailer: &str) -> String {
+    let message = message.trim_end();
+    if message.lines().any(|l| l.trim().eq_ignore_ascii_case(trailer)) {
+        return message.to_string();
+    }
+    if message.is_empty() {
+        return trailer.to_string();
+    }
+    // The subject line alone is never a trailer block.
+    let in_trailer_block = message
+        .rsplit_once("\n\n")
+        .is_some_and(|(_, last)| last.lines().all(is_trailer_line));
+    let separator = if in_trailer_block { "\n" } else { "\n\n" };
+    format!("{message}{separator}{trailer}")
+}
+
+/// `Token: value` in git's trailer syntax, or a closing reference such as
+/// `Closes #4`.
+fn is_trailer_line(line: &str) -> bool {
+    let line = line.trim();
+    let token_value = line
+        .split_once(": ")
+        .is_some_and(|(token, _)| !token.is_empty() && !token.contains(char::is_whitespace));
+    let closing = line
+        .split_once(' ')
+        .is_some_and(|(word, _)| CLOSING_KEYWORDS.contains(&word.to_lowercase().as_str()));
+    token_value || closing
+}
+
+/// Refresh the cached state of every linked GitHub issue with one GraphQL
+/// query per repository (see `fetch_issue_states_batched`).  Links without
+/// a repo or number are skipped, and a repository that fails is reported in
+/// `errors` without aborting the rest of the refresh.
 #[tauri::command]
-pub fn fetch_issue_states(state: State<AppState>) -> CmdResult<Vec<TaskGithubLink>> {
+pub fn fetch_issue_states(state: State<AppState>) -> CmdResult<TaskIssueStateRefresh> {
+    let links = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_all_links(conn).map_err(to_cmd_err)?
+    };
+
+    // Fetch without holding the lock; each batch is a network round trip.
+    let (states, errors) = fetch_issue_states_batched(
+        links
+            .iter()
+            .filter_map(|l| Some((l.github_repo.as_deref()?, l.github_issue_number?))),
+    );
+
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
-
-    let links = load_all_links(conn).map_err(to_cmd_err)?;
     let now = chrono::Utc::now().to_rfc3339();
-
     for link in &links {
         let (Some(repo), Some(number)) = (&link.github_repo, link.github_issue_number) else {
             continue;
         };
-
-        let Ok(output) = std::process::Command::new("gh")
-            .args([
-                "issue", "view",
-                &number.to_string(),
-                "--repo", repo,
-                "--json", "state",
-            ])
-            .output()
-        else {
+        let Some(state_str) = states.get(&(repo.clone(), number)) else {
             continue;
         };
 
-        if !output.status.success() {
-            continue;
+        let _ = conn.execute(
+            "UPDATE task_github_links
+             SET github_issue_state = ?1, state_updated_at = ?2
+             WHERE task_id = ?3 AND team_id = ?4",
+            rusqlite::params![state_str, now, link.task_id, link.team_id],
+        );
+        let _ = cache_issue_state(conn, repo, number, state_str);
+    }
+
+    Ok(TaskIssueStateRefresh {
+        links: load_all_links(conn).map_err(to_cmd_err)?,
+        errors,
+    })
+}
+
+/// Comments on an issue, oldest first, so a linked task's discussion can be
+/// read in the app.  Fetched comments replace the cached copy.
+#[tauri::command]
+pub fn get_issue_comments(
+    state: State<AppState>,
+    repo: String,
+    number: i64,
+) -> CmdResult<Vec<CachedIssueComment>> {
+    let comments = forge_for_repo(&repo).issue_comments(&repo, number)?;
+
+    let db = state.db.lock();
+    if let Some(conn) = db.as_ref() {
+        let _ = cache_issue_comments(conn, &repo, number, &comments);
+    }
+    Ok(comments)
+}
+
+/// A GitHub issue's comments through the REST API when a token is stored,
+/// otherwise with `gh issue view`.
+pub(crate) fn fetch_remote_issue_comments(
+    repo: &str,
+    number: i64,
+) -> CmdResult<Vec<CachedIssueComment>> {
+    if let Some(api) = GithubApi::from_keychain() {
+        return api.issue_comments(repo, number).map_err(to_cmd_err);
+    }
+    require_github_auth()?;
+    let output = run_external_tool(std::process::Command::new("gh").args([
+        "issue", "view",
+        &number.to_string(),
+        "--repo", repo,
+        "--json", "comments",
+    ]))?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(json["comments"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|c| CachedIssueComment {
+            author: c["author"]["login"].as_str().map(str::to_string),
+            body: c["body"].as_str().unwrap_or_default().to_string(),
+            created_at: c["createdAt"].as_str().map(str::to_string),
+        })
+        .collect())
+}
+
+/// Post `body` as a comment on an issue and return the comment's URL when
+/// the forge reports one.
+#[tauri::command]
+pub fn add_issue_comment(repo: String, number: i64, body: String) -> CmdResult<Option<String>> {
+    if body.trim().is_empty() {
+        return Err(to_cmd_err(CommanderError::internal("A comment needs a body")));
+    }
+    forge_for_repo(&repo).add_issue_comment(&repo, number, &body)
+}
+
+/// Comment on a GitHub issue through the REST API when a token is stored,
+/// otherwise with `gh issue comment`, which prints the new comment's URL.
+pub(crate) fn post_issue_comment(repo: &str, number: i64, body: &str) -> CmdResult<Option<String>> {
+    if let Some(api) = GithubApi::from_keychain() {
+        return api.add_issue_comment(repo, number, body).map_err(to_cmd_err);
+    }
+    require_github_auth()?;
+    let output = run_external_tool(std::process::Command::new("gh").args([
+        "issue", "comment",
+        &number.to_string(),
+        "--repo", repo,
+        "--body", body,
+    ]))?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with("https://"))
+        .map(str::to_string))
+}
+
+/// Close a GitHub issue through the REST API when a token is stored,
+/// otherwise with `gh issue close`.
+pub(crate) fn close_remote_issue(repo: &str, number: i64) -> CmdResult<()> {
+    if let Some(api) = GithubApi::from_keychain() {
+        return api.close_issue(repo, number).map_err(to_cmd_err);
+    }
+    run_external_tool(
+        std::process::Command::new("gh").args(["issue", "close", &number.to_string(), "--repo", repo]),
+    )?;
+    Ok(())
+}
+
+/// Issues looked up per request, e.g. as aliased GraphQL `issue` fields.
+const ISSUE_STATE_BATCH_SIZE: usize = 50;
+/// Requests in flight at once while refreshing issue states.
+const ISSUE_STATE_CONCURRENCY: usize = 4;
+
+/// Current state (`"open"` or `"closed"`) of many issues at once, keyed by
+/// `(repo, number)`.  Issues are grouped by repository and fetched from its
+/// forge with one request per `ISSUE_STATE_BATCH_SIZE` of them, at most
+/// `ISSUE_STATE_CONCURRENCY` at a time.  Issues that could not be fetched
+/// are missing from the map; each repository with a failure gets one entry
+/// in the returned errors.
+pub(crate) fn fetch_issue_states_batched<'a>(
+    issues: impl IntoIterator<Item = (&'a str, i64)>,
+) -> (HashMap<(String, i64), String>, Vec<IssueStateError>) {
+    let mut by_repo: BTreeMap<&str, BTreeSet<i64>> = BTreeMap::new();
+    for (repo, number) in issues {
+        by_repo.entry(repo).or_default().insert(number);
+    }
+    let batches: Vec<(&str, Vec<i64>)> = by_repo
+        .into_iter()
+        .flat_map(|(repo, numbers)| {
+            let numbers: Vec<i64> = numbers.into_iter().collect();
+            numbers
+                .chunks(ISSUE_STATE_BATCH_SIZE)
+                .map(|chunk| (repo, chunk.to_vec()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let workers = batches.len().min(ISSUE_STATE_CONCURRENCY);
+    let queue = Mutex::new(batches.into_iter());
+    let results = Mutex::new(Vec::new());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let Some((repo, numbers)) = queue.lock().next() else {
+                    break;
+                };
+                let result = forge_for_repo(repo).issue_states(repo, &numbers);
+                results.lock().push((repo, result));
+            });
         }
+    });
 
-        let Ok(json) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
-            continue;
-        };
+    let mut states = HashMap::new();
+    let mut errors: Vec<IssueStateError> = Vec::new();
+    for (repo, (fetched, error)) in results.into_inner() {
+        for (number, issue_state) in fetched {
+            states.insert((repo.to_string(), number), issue_state);
+        }
+        if let Some(message) = error {
+            // A repository split over several batches is reported once.
+            if !errors.iter().any(|e| e.repo == repo) {
+                errors.push(IssueStateError {
+                    repo: repo.to_string(),
+                    message,
+                });
+            }
+        }
+    }
+    (states, errors)
+}
 
-        // GitHub returns "OPEN" / "CLOSED" (uppercase).
-        let state_str = json["state"]
-            .as_str()
-            .map(|s| s.to_lowercase())
-            .unwrap_or_default();
-
-        if state_str == "open" || state_str == "closed" {
-            let _ = conn.execute(
-                "UPDATE task_github_links
-                 SET github_issue_state = ?1, state_updated_at = ?2
-                 WHERE task_id = ?3 AND team_id = ?4",
-                rusqlite::params![state_str, now, link.task_id, link.team_id],
-            );
+/// One GraphQL round trip for up to `ISSUE_STATE_BATCH_SIZE` issues of a
+/// GitHub `repo`, through the REST client's token when one is stored and
+/// `gh api graphql` otherwise.  Returns the states that came back and, when
+/// some or all did not, a message explaining why.
+pub(crate) fn fetch_repo_issue_states(
+    api: Option<&GithubApi>,
+    repo: &str,
+    numbers: &[i64],
+) -> (Vec<(i64, String)>, Option<String>) {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return (Vec::new(), Some(format!("Invalid repo: {repo}")));
+    };
+    let fields: String = numbers
+        .iter()
+        .map(|n| format!(" i{n}: issue(number: {n}) {{ state }}"))
+        .collect();
+    let query = format!(
+        "query($owner: String!, $name: String!) {{ repository(owner: $owner, name: $name) {{{fields} }} }}"
+    );
+
+    let mut response = None;
+    if let Some(api) = api {
+        let variables = serde_json::json!({ "owner": owner, "name": name });
+        match api.graphql(&query, variables) {
+            Ok(r) => response = Some(r),
+            Err(e) => log::debug!("GitHub API GraphQL failed, trying gh: {}", e),
         }
     }
+    let response = match response {
+        Some(r) => r,
+        None => match gh_graphql_response(&query, &[("owner", owner), ("name", name)]) {
+            Ok(r) => r,
+            Err(message) => return (Vec::new(), Some(message)),
+        },
+    };
 
-    load_all_links(conn).map_err(to_cmd_err)
+    // GitHub returns "OPEN" / "CLOSED" (uppercase).
+    let repository = &response["data"]["repository"];
+    let states: Vec<(i64, String)> = numbers
+        .iter()
+        .filter_map(|n| {
+            let state_str = repository[format!("i{n}")]["state"]
+                .as_str()?
+                .to_lowercase();
+            Some((*n, state_str))
+        })
+        .collect();
+
+    let error = (states.len() < numbers.len()).then(|| {
+        let messages: Vec<&str> = response["errors"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        if messages.is_empty() {
+            format!(
+                "{} issue(s) missing from the response",
+                numbers.len() - states.len()
+            )
+        } else {
+            messages.join("; ")
+        }
+    });
+    (states, error)
+}
+
+/// `gh api graphql` exits non-zero when the response carries `errors`, even
+/// if it also has partial `data`, so the body is parsed whatever the exit
+/// status and stderr is only used when there is no JSON to read.
+fn gh_graphql_response(
+    query: &str,
+    variables: &[(&str, &str)],
+) -> Result<serde_json::Value, String> {
+    let mut command = std::process::Command::new("gh");
+    command
+        .args(["api", "graphql", "-f"])
+        .arg(format!("query={query}"));
+    for (name, value) in variables {
+        command.arg("-f").arg(format!("{name}={value}"));
+    }
+    let output = command
+        .output()
+        .map_err(|e| format!("Could not run gh: {e}"))?;
+    serde_json::from_slice(&output.stdout).map_err(|_| {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        if stderr.is_empty() {
+            "gh api graphql failed".to_string()
+        } else {
+            stderr
+        }
+    })
 }
 
 /// Remove the GitHub issue link for a task.
@@ -352,3 +1176,158 @@ pub fn delete_task_github_link(
 
     Ok(())
 }
+
+// ─── Planning item links ────────────────────────────────────────────────────
+
+/// Persist (insert or replace) a planning item → GitHub issue link.
+#[tauri::command]
+pub fn upsert_planning_item_github_link(
+    state: State<AppState>,
+    link: UpsertPlanningItemGithubLinkInput,
+) -> CmdResult<PlanningItemGithubLink> {
+    let number = link
+        .github_issue_number
+        .or_else(|| parse_issue_number(&link.github_issue_url));
+    let repo = link
+        .github_repo
+        .clone()
+        .or_else(|| parse_repo_from_url(&link.github_issue_url));
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+
+    conn.execute(
+        "INSERT INTO planning_item_github_links
+             (item_id, github_issue_url, github_issue_number, github_repo, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(item_id) DO UPDATE SET
+             github_issue_url    = excluded.github_issue_url,
+             github_issue_number = excluded.github_issue_number,
+             github_repo         = excluded.github_repo,
+             github_issue_state  = NULL,
+             state_updated_at    = NULL",
+        rusqlite::params![link.item_id, link.github_issue_url, number, repo, now],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(PlanningItemGithubLink {
+        item_id: link.item_id,
+        github_issue_url: link.github_issue_url,
+        github_issue_number: number,
+        github_repo: repo,
+        created_at: now,
+        github_issue_state: None,
+        state_updated_at: None,
+    })
+}
+
+/// Return all planning item → GitHub issue links.
+#[tauri::command]
+pub fn get_planning_item_github_links(
+    state: State<AppState>,
+) -> CmdResult<Vec<PlanningItemGithubLink>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    load_all_item_links(conn).map_err(to_cmd_err)
+}
+
+/// Refresh the cached state of every linked issue on the board, like
+/// `fetch_issue_states` does for tasks, and return the updated links.
+#[tauri::command]
+pub fn fetch_planning_item_issue_states(
+    state: State<AppState>,
+) -> CmdResult<PlanningItemIssueStateRefresh> {
+    let links = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_all_item_links(conn).map_err(to_cmd_err)?
+    };
+
+    let (states, errors) = fetch_issue_states_batched(
+        links
+            .iter()
+            .filter_map(|l| Some((l.github_repo.as_deref()?, l.github_issue_number?))),
+    );
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let now = chrono::Utc::now().to_rfc3339();
+    for link in &links {
+        let (Some(repo), Some(number)) = (&link.github_repo, link.github_issue_number) else {
+            continue;
+        };
+        let Some(issue_state) = states.get(&(repo.clone(), number)) else {
+            continue;
+        };
+        let _ = conn.execute(
+            "UPDATE planning_item_github_links
+             SET github_issue_state = ?1, state_updated_at = ?2
+             WHERE item_id = ?3",
+            rusqlite::params![issue_state, now, link.item_id],
+        );
+        let _ = cache_issue_state(conn, repo, number, issue_state);
+    }
+
+    Ok(PlanningItemIssueStateRefresh {
+        links: load_all_item_links(conn).map_err(to_cmd_err)?,
+        errors,
+    })
+}
+
+/// Remove the GitHub issue link for a planning item.  Imported items keep
+/// their source columns, so re-imports still skip them; an item that was
+/// only linked by hand no longer stands for the issue, which the next import
+/// adds as a card of its own.
+#[tauri::command]
+pub fn delete_planning_item_github_link(state: State<AppState>, item_id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    conn.execute(
+        "DELETE FROM planning_item_github_links WHERE item_id = ?1",
+        [&item_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(())
+}
+
+pub(crate) fn load_all_item_links(
+    conn: &rusqlite::Connection,
+) -> Result<Vec<PlanningItemGithubLink>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT item_id, github_issue_url, github_issue_number, github_repo,
+                created_at, github_issue_state, state_updated_at
+         FROM planning_item_github_links ORDER BY created_at DESC",
+    )?;
+
+    let links = stmt
+        .query_map([], |row| {
+            Ok(PlanningItemGithubLink {
+                item_id: row.get(0)?,
+                github_issue_url: row.get(1)?,
+                github_issue_number: row.get(2)?,
+                github_repo: row.get(3)?,
+                created_at: row.get(4)?,
+                github_issue_state: row.get(5)?,
+                state_updated_at: row.get(6)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(links)
+}
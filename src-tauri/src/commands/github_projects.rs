@@ -0,0 +1,229 @@
+use crate::commands::github::require_github_auth;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{GithubProject, GithubProjectColumn};
+use crate::state::AppState;
+use crate::utils::run_external_tool;
+use serde_json::Value;
+use std::time::Duration;
+use tauri::State;
+
+/// How long an owner's boards are reused before being listed again.
+const PROJECTS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Projects (v2) boards of an owner, with the options of their `Status`
+/// field, which is what the board view groups into columns.
+const OWNER_PROJECTS_QUERY: &str = "
+query($owner: String!) {
+  repositoryOwner(login: $owner) {
+    ... on ProjectV2Owner {
+      projectsV2(first: 50, orderBy: {field: UPDATED_AT, direction: DESC}) {
+        nodes { ...board }
+      }
+    }
+  }
+}";
+
+const REPO_PROJECTS_QUERY: &str = "
+query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) {
+    projectsV2(first: 50, orderBy: {field: UPDATED_AT, direction: DESC}) {
+      nodes { ...board }
+    }
+  }
+}";
+
+const BOARD_FRAGMENT: &str = "
+fragment board on ProjectV2 {
+  id number title url closed
+  field(name: \"Status\") {
+    ... on ProjectV2SingleSelectField { id options { id name } }
+  }
+}";
+
+const ISSUE_ID_QUERY: &str = "
+query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) { issue(number: $number) { id } }
+}";
+
+const ADD_ITEM_MUTATION: &str = "
+mutation($project: ID!, $content: ID!) {
+  addProjectV2ItemById(input: {projectId: $project, contentId: $content}) { item { id } }
+}";
+
+const SET_STATUS_MUTATION: &str = "
+mutation($project: ID!, $item: ID!, $field: ID!, $option: String!) {
+  updateProjectV2ItemFieldValue(input: {
+    projectId: $project, itemId: $item, fieldId: $field,
+    value: {singleSelectOptionId: $option}
+  }) { projectV2Item { id } }
+}";
+
+/// List the Projects (v2) boards of `owner`: a user or organisation login,
+/// or `"owner/repo"` for the boards linked to one repository.  Closed
+/// boards are included and flagged.  Needs the `read:project` scope.
+#[tauri::command]
+pub fn list_github_projects(
+    state: State<AppState>,
+    owner: String,
+) -> CmdResult<Vec<GithubProject>> {
+    let key = format!("projects/{owner}");
+    state
+        .github
+        .call(&key, PROJECTS_CACHE_TTL, || fetch_github_projects(&owner))
+}
+
+fn fetch_github_projects(owner: &str) -> CmdResult<Vec<GithubProject>> {
+    require_github_auth()?;
+    let nodes = match owner.split_once('/') {
+        Some((login, name)) => {
+            let data = gh_graphql(
+                &format!("{REPO_PROJECTS_QUERY}{BOARD_FRAGMENT}"),
+                &[("owner", login), ("name", name)],
+                &[],
+            )?;
+            data["repository"]["projectsV2"]["nodes"].clone()
+        }
+        None => {
+            let data = gh_graphql(
+                &format!("{OWNER_PROJECTS_QUERY}{BOARD_FRAGMENT}"),
+                &[("owner", owner)],
+                &[],
+            )?;
+            data["repositoryOwner"]["projectsV2"]["nodes"].clone()
+        }
+    };
+
+    Ok(nodes
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(parse_project)
+        .collect())
+}
+
+/// Add issue `repo#number` to a Projects (v2) board and, when `column_id`
+/// (a `Status` option id from `list_github_projects`) is given, move it
+/// into that column.  Adding an issue that is already on the board is a
+/// no-op that returns the existing item.  Returns the project item id.
+/// Needs the `project` scope.
+#[tauri::command]
+pub fn add_issue_to_github_project(
+    project_id: String,
+    repo: String,
+    number: i64,
+    column_id: Option<String>,
+) -> CmdResult<String> {
+    require_github_auth()?;
+    let (owner, name) = repo
+        .split_once('/')
+        .ok_or_else(|| to_cmd_err(CommanderError::internal(format!("Invalid repo: {repo}"))))?;
+
+    let data = gh_graphql(
+        ISSUE_ID_QUERY,
+        &[("owner", owner), ("name", name)],
+        &[("number", number)],
+    )?;
+    let issue_id = data["repository"]["issue"]["id"]
+        .as_str()
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Issue {repo}#{number} not found"
+            )))
+        })?
+        .to_string();
+
+    let data = gh_graphql(
+        ADD_ITEM_MUTATION,
+        &[("project", &project_id), ("content", &issue_id)],
+        &[],
+    )?;
+    let item_id = data["addProjectV2ItemById"]["item"]["id"]
+        .as_str()
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::parse(
+                "Missing item id in addProjectV2ItemById response",
+            ))
+        })?
+        .to_string();
+
+    if let Some(option_id) = column_id {
+        let field_id = status_field_id(&project_id)?;
+        gh_graphql(
+            SET_STATUS_MUTATION,
+            &[
+                ("project", &project_id),
+                ("item", &item_id),
+                ("field", &field_id),
+                ("option", &option_id),
+            ],
+            &[],
+        )?;
+    }
+
+    Ok(item_id)
+}
+
+fn status_field_id(project_id: &str) -> CmdResult<String> {
+    let query = format!("query($id: ID!) {{ node(id: $id) {{ ...board }} }}{BOARD_FRAGMENT}");
+    let data = gh_graphql(&query, &[("id", project_id)], &[])?;
+    data["node"]["field"]["id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(
+                "The project has no single-select Status field",
+            ))
+        })
+}
+
+fn parse_project(node: &Value) -> Option<GithubProject> {
+    let columns = node["field"]["options"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|o| {
+            Some(GithubProjectColumn {
+                id: o["id"].as_str()?.to_string(),
+                name: o["name"].as_str()?.to_string(),
+            })
+        })
+        .collect();
+    Some(GithubProject {
+        id: node["id"].as_str()?.to_string(),
+        number: node["number"].as_i64()?,
+        title: node["title"].as_str().unwrap_or_default().to_string(),
+        url: node["url"].as_str().unwrap_or_default().to_string(),
+        closed: node["closed"].as_bool().unwrap_or(false),
+        columns,
+    })
+}
+
+/// Run a GraphQL document through `gh api graphql` and return its `data`.
+/// String variables go through `-f` so ids and logins are never coerced;
+/// `-F` is only used for the integer ones.
+fn gh_graphql(query: &str, strings: &[(&str, &str)], ints: &[(&str, i64)]) -> CmdResult<Value> {
+    let mut command = std::process::Command::new("gh");
+    command
+        .args(["api", "graphql", "-f"])
+        .arg(format!("query={query}"));
+    for (name, value) in strings {
+        command.arg("-f").arg(format!("{name}={value}"));
+    }
+    for (name, value) in ints {
+        command.arg("-F").arg(format!("{name}={value}"));
+    }
+    let output = run_external_tool(&mut command)?;
+    let mut response: Value =
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    if let Some(errors) = response["errors"].as_array().filter(|e| !e.is_empty()) {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "GitHub GraphQL error: {}",
+            messages.join("; ")
+        ))));
+    }
+    Ok(response["data"].take())
+}
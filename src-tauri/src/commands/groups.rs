@@ -0,0 +1,139 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::ProjectGroup;
+use crate::state::AppState;
+use tauri::State;
+use uuid::Uuid;
+
+const GROUP_SELECT: &str = "SELECT g.id, g.name, g.sort_order, g.created_at, \
+     (SELECT COUNT(*) FROM projects p WHERE p.group_id = g.id AND p.is_archived = 0) \
+     FROM project_groups g";
+
+fn row_to_group(row: &rusqlite::Row) -> rusqlite::Result<ProjectGroup> {
+    Ok(ProjectGroup {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        sort_order: row.get(2)?,
+        created_at: row.get(3)?,
+        project_count: row.get::<_, i64>(4)? as usize,
+    })
+}
+
+fn validate_group_name(name: &str) -> CmdResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Group name must not be empty",
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[tauri::command]
+pub fn get_groups(state: State<AppState>) -> CmdResult<Vec<ProjectGroup>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!("{GROUP_SELECT} ORDER BY g.sort_order, g.name"))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let groups = stmt
+        .query_map([], row_to_group)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(groups)
+}
+
+#[tauri::command]
+pub fn create_group(state: State<AppState>, name: String) -> CmdResult<ProjectGroup> {
+    let name = validate_group_name(&name)?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let max_sort: i64 = conn
+        .query_row(
+            "SELECT COALESCE(MAX(sort_order), 0) FROM project_groups",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(0);
+
+    let id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO project_groups (id, name, sort_order) VALUES (?1, ?2, ?3)",
+        rusqlite::params![id, name, max_sort + 1000],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let group = conn
+        .query_row(
+            &format!("{GROUP_SELECT} WHERE g.id = ?1"),
+            [&id],
+            row_to_group,
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(group)
+}
+
+#[tauri::command]
+pub fn rename_group(state: State<AppState>, group_id: String, name: String) -> CmdResult<()> {
+    let name = validate_group_name(&name)?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    conn.execute(
+        "UPDATE project_groups SET name = ?1 WHERE id = ?2",
+        rusqlite::params![name, group_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(())
+}
+
+/// Delete a group.  Its projects are kept and become ungrouped
+/// (`ON DELETE SET NULL`).
+#[tauri::command]
+pub fn delete_group(state: State<AppState>, group_id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    conn.execute("DELETE FROM project_groups WHERE id = ?1", [&group_id])
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(())
+}
+
+/// Move a project into `group_id`, or out of any group when `None`.
+#[tauri::command]
+pub fn assign_project_group(
+    state: State<AppState>,
+    project_id: String,
+    group_id: Option<String>,
+) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    // The foreign key rejects unknown group ids.
+    conn.execute(
+        "UPDATE projects SET group_id = ?1 WHERE id = ?2",
+        rusqlite::params![group_id, project_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use crate::commands::github::require_github_auth;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{CachedIssue, CachedIssueComment};
+use crate::state::AppState;
+use crate::utils::{parse_timestamp, run_external_tool};
+use tauri::State;
+
+/// Cached issues older than this are flagged `stale`.
+const STALE_AFTER_MINUTES: i64 = 60;
+
+const ISSUE_COLUMNS: &str =
+    "repo, number, title, body, state, url, comments, updated_at, fetched_at";
+
+/// Every cached issue for `repo` ("owner/repo"), newest number first.  Reads
+/// only the local cache, so it works offline; check `stale` / `fetched_at`
+/// to tell how current each entry is.
+#[tauri::command]
+pub fn get_cached_issues(state: State<AppState>, repo: String) -> CmdResult<Vec<CachedIssue>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    load_cached_issues(conn, &repo).map_err(to_cmd_err)
+}
+
+/// Fetch up to `limit` issues (default 100, open and closed) with their
+/// comments via `gh issue list`, store them in the cache and return the
+/// cached set.  On a network failure the cache is left untouched.
+#[tauri::command]
+pub fn refresh_issue_cache(
+    state: State<AppState>,
+    repo: String,
+    limit: Option<usize>,
+) -> CmdResult<Vec<CachedIssue>> {
+    require_github_auth()?;
+    let limit = limit.unwrap_or(100).to_string();
+    let output = run_external_tool(std::process::Command::new("gh").args([
+        "issue",
+        "list",
+        "--repo",
+        &repo,
+        "--state",
+        "all",
+        "--limit",
+        &limit,
+        "--json",
+        "number,title,body,url,state,comments,updatedAt",
+    ]))?;
+    let issues: Vec<serde_json::Value> =
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    cache_issues(conn, &repo, &issues).map_err(to_cmd_err)?;
+    load_cached_issues(conn, &repo).map_err(to_cmd_err)
+}
+
+/// Upsert issues as returned by `gh issue list/view --json`.  Fields missing
+/// from a response (e.g. `comments` when they were not requested) keep
+/// their cached value.
+pub(crate) fn cache_issues(
+    conn: &rusqlite::Connection,
+    repo: &str,
+    issues: &[serde_json::Value],
+) -> Result<(), CommanderError> {
+    let now = chrono::Utc::now().to_rfc3339();
+    for issue in issues {
+        let (Some(number), Some(title)) = (issue["number"].as_i64(), issue["title"].as_str())
+        else {
+            continue;
+        };
+        let comments = issue["comments"]
+            .as_array()
+            .map(|comments| {
+                let comments: Vec<CachedIssueComment> = comments
+                    .iter()
+                    .map(|c| CachedIssueComment {
+                        author: c["author"]["login"].as_str().map(str::to_string),
+                        body: c["body"].as_str().unwrap_or_default().to_string(),
+                        created_at: c["createdAt"].as_str().map(str::to_string),
+                    })
+                    .collect();
+                serde_json::to_string(&comments)
+            })
+            .transpose()?;
+        conn.execute(
+            "INSERT INTO github_issue_cache
+                 (repo, number, title, body, state, url, comments, updated_at, fetched_at)
+             VALUES (?1, ?2, ?3, ?4, COALESCE(?5, 'open'), ?6, ?7, ?8, ?9)
+             ON CONFLICT(repo, number) DO UPDATE SET
+                 title      = excluded.title,
+                 body       = COALESCE(excluded.body, github_issue_cache.body),
+                 state      = COALESCE(?5, github_issue_cache.state),
+                 url        = COALESCE(excluded.url, github_issue_cache.url),
+                 comments   = COALESCE(excluded.comments, github_issue_cache.comments),
+                 updated_at = COALESCE(excluded.updated_at, github_issue_cache.updated_at),
+                 fetched_at = excluded.fetched_at",
+            rusqlite::params![
+                repo,
+                number,
+                title,
+                issue["body"].as_str(),
+                issue["state"].as_str().map(str::to_lowercase),
+                issue["url"].as_str(),
+                comments,
+                issue["updatedAt"].as_str(),
+                now,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Record a state observed or set elsewhere (`gh issue view` / `close`) on
+/// an already-cached issue.
+pub(crate) fn cache_issue_state(
+    conn: &rusqlite::Connection,
+    repo: &str,
+    number: i64,
+    state: &str,
+) -> Result<(), CommanderError> {
+    conn.execute(
+        "UPDATE github_issue_cache SET state = ?1, fetched_at = ?2
+         WHERE repo = ?3 AND number = ?4",
+        rusqlite::params![state, chrono::Utc::now().to_rfc3339(), repo, number],
+    )?;
+    Ok(())
+}
+
+/// Replace the cached comments of an already-cached issue.
+pub(crate) fn cache_issue_comments(
+    conn: &rusqlite::Connection,
+    repo: &str,
+    number: i64,
+    comments: &[CachedIssueComment],
+) -> Result<(), CommanderError> {
+    conn.execute(
+        "UPDATE github_issue_cache SET comments = ?1 WHERE repo = ?2 AND number = ?3",
+        rusqlite::params![serde_json::to_string(comments)?, repo, number],
+    )?;
+    Ok(())
+}
+
+fn load_cached_issues(
+    conn: &rusqlite::Connection,
+    repo: &str,
+) -> Result<Vec<CachedIssue>, CommanderError> {
+    let stale_before = chrono::Utc::now() - chrono::Duration::minutes(STALE_AFTER_MINUTES);
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {ISSUE_COLUMNS} FROM github_issue_cache WHERE repo = ?1 ORDER BY number DESC"
+    ))?;
+    let issues = stmt
+        .query_map([repo], |row| {
+            let comments: Option<String> = row.get(6)?;
+            let fetched_at: String = row.get(8)?;
+            Ok(CachedIssue {
+                repo: row.get(0)?,
+                number: row.get(1)?,
+                title: row.get(2)?,
+                body: row.get(3)?,
+                state: row.get(4)?,
+                url: row.get(5)?,
+                comments: comments
+                    .and_then(|c| serde_json::from_str(&c).ok())
+                    .unwrap_or_default(),
+                updated_at: row.get(7)?,
+                stale: parse_timestamp(&fetched_at).is_none_or(|t| t < stale_before),
+                fetched_at,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(issues)
+}
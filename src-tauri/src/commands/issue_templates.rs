@@ -0,0 +1,181 @@
+use crate::commands::github::require_github_auth;
+use crate::commands::slash_commands::{split_frontmatter, unquote};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::IssueTemplate;
+use crate::services::forge::forge_for_repo;
+use crate::services::github_api::GithubApi;
+use crate::state::AppState;
+use crate::utils::run_external_tool;
+use std::collections::BTreeMap;
+use std::process::Command;
+use std::time::Duration;
+use tauri::State;
+
+/// Where GitHub looks for issue templates and issue forms.
+const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// Templates rarely change, and reading them takes a request per file.
+const TEMPLATES_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The issue templates of `repo`, sorted by name, so a new issue can start
+/// from one: its title prefix, body and labels go to `create_github_issue`.
+/// A repository without templates yields an empty list.
+#[tauri::command]
+pub fn get_issue_templates(state: State<AppState>, repo: String) -> CmdResult<Vec<IssueTemplate>> {
+    let key = format!("templates/{repo}");
+    state.github.call(&key, TEMPLATES_CACHE_TTL, || {
+        let mut templates = forge_for_repo(&repo).issue_templates(&repo)?;
+        templates.sort_by_key(|t| t.name.to_lowercase());
+        Ok(templates)
+    })
+}
+
+/// Markdown templates and issue forms under `.github/ISSUE_TEMPLATE`, read
+/// through the REST client when a token is stored and `gh api` otherwise.
+/// The chooser's `config.yml` is not a template and is skipped.
+pub(crate) fn fetch_github_issue_templates(repo: &str) -> CmdResult<Vec<IssueTemplate>> {
+    let api = GithubApi::from_keychain();
+    if api.is_none() {
+        require_github_auth()?;
+    }
+    let fetch = |path: &str, raw: bool| match &api {
+        Some(api) => api.contents(repo, path, raw).map_err(to_cmd_err),
+        None => gh_contents(repo, path, raw),
+    };
+
+    let Some(listing) = fetch(TEMPLATE_DIR, false)? else {
+        return Ok(Vec::new());
+    };
+    let entries: serde_json::Value =
+        serde_json::from_str(&listing).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let mut templates = Vec::new();
+    for entry in entries.as_array().into_iter().flatten() {
+        let (Some(name), Some(path)) = (entry["name"].as_str(), entry["path"].as_str()) else {
+            continue;
+        };
+        let is_form = name.ends_with(".yml") || name.ends_with(".yaml");
+        if entry["type"] != "file" || name.starts_with("config.") {
+            continue;
+        }
+        if !is_form && !name.ends_with(".md") {
+            continue;
+        }
+        let Some(content) = fetch(path, true)? else {
+            continue;
+        };
+        templates.push(if is_form {
+            parse_issue_form(name, &content)
+        } else {
+            parse_markdown_template(name, &content)
+        });
+    }
+    Ok(templates)
+}
+
+/// `gh api` counterpart of `GithubApi::contents`.
+fn gh_contents(repo: &str, path: &str, raw: bool) -> CmdResult<Option<String>> {
+    let mut command = Command::new("gh");
+    command
+        .arg("api")
+        .arg(format!("repos/{repo}/contents/{path}"));
+    if raw {
+        command.args(["-H", "Accept: application/vnd.github.raw"]);
+    }
+    match run_external_tool(&mut command) {
+        Ok(output) => Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned())),
+        // gh reports a missing path as "Not Found (HTTP 404)".
+        Err(e) if e.contains("HTTP 404") => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// A markdown template: frontmatter `name`, `about`, `title` and `labels`
+/// above the body.  A header that does not parse leaves the whole file as
+/// the body.
+fn parse_markdown_template(file: &str, content: &str) -> IssueTemplate {
+    let (fields, body) = split_frontmatter(content).unwrap_or_else(|_| (BTreeMap::new(), content));
+    IssueTemplate {
+        file: file.to_string(),
+        name: fields
+            .get("name")
+            .cloned()
+            .unwrap_or_else(|| template_name_from_file(file)),
+        about: fields.get("about").cloned().filter(|a| !a.is_empty()),
+        title: fields.get("title").cloned().filter(|t| !t.is_empty()),
+        labels: fields
+            .get("labels")
+            .map(|l| split_labels(l))
+            .unwrap_or_default(),
+        body: body.to_string(),
+    }
+}
+
+/// An issue form, read line by line rather than with a YAML parser: the
+/// top-level `name`, `description`, `title` and `labels` keys, plus one
+/// `### label` heading per form field so the issue keeps the form's shape.
+fn parse_issue_form(file: &str, content: &str) -> IssueTemplate {
+    let mut fields = BTreeMap::new();
+    let mut labels = Vec::new();
+    let mut in_labels = false;
+    let mut body = String::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        // A top-level key; `- item` lines may also sit at column 0.
+        if !line.starts_with([' ', '\t', '-']) {
+            in_labels = false;
+            if let Some((key, value)) = trimmed.split_once(':') {
+                let value = unquote(value.trim());
+                if key.trim() == "labels" {
+                    labels.extend(split_labels(value));
+                    in_labels = true;
+                } else if !value.starts_with(['|', '>']) {
+                    fields.insert(key.trim().to_string(), value.to_string());
+                }
+            }
+            continue;
+        }
+        if in_labels {
+            if let Some(item) = trimmed.strip_prefix("- ") {
+                labels.push(unquote(item.trim()).to_string());
+            }
+            continue;
+        }
+        if let Some(label) = trimmed.strip_prefix("label:") {
+            body.push_str(&format!("### {}\n\n\n", unquote(label.trim())));
+        }
+    }
+
+    IssueTemplate {
+        file: file.to_string(),
+        name: fields
+            .remove("name")
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| template_name_from_file(file)),
+        about: fields.remove("description").filter(|d| !d.is_empty()),
+        title: fields.remove("title").filter(|t| !t.is_empty()),
+        labels,
+        body: body.trim_end().to_string(),
+    }
+}
+
+/// `labels: bug, docs` or `labels: [bug, "help wanted"]`.
+fn split_labels(value: &str) -> Vec<String> {
+    value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|l| unquote(l.trim()).to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// `"bug_report.md"` → `"bug report"`, for templates without a `name`.
+fn template_name_from_file(file: &str) -> String {
+    file.rsplit_once('.')
+        .map_or(file, |(stem, _)| stem)
+        .replace(['_', '-'], " ")
+}
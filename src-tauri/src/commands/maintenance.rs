@@ -0,0 +1,150 @@
+use crate::commands::backups::backups_dir;
+use crate::commands::cost_ledger::update_cost_ledger;
+use crate::commands::event_log::record_event;
+use crate::commands::session_search::index_sessions;
+use crate::commands::settings::{get_setting, set_setting};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::MaintenanceReport;
+use crate::state::AppState;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+pub(crate) const EVENT_KIND_MAINTENANCE: &str = "maintenance";
+
+/// Compacted database copies kept in the backups directory.
+const MAX_DB_BACKUPS: usize = 4;
+
+const DB_BACKUP_PREFIX: &str = "commander-";
+
+/// `(table, timestamp column, extra condition)` pruned by retention.
+/// Pending suggestions are kept however old they are.
+const PRUNED_TABLES: &[(&str, &str, &str)] = &[
+    ("sync_history", "synced_at", ""),
+    ("event_log", "created_at", ""),
+    ("auto_suggestions", "created_at", "AND status != 'pending'"),
+];
+
+/// Run database maintenance now instead of waiting for the weekly job.
+#[tauri::command]
+pub fn run_maintenance_now(state: State<AppState>) -> CmdResult<MaintenanceReport> {
+    run_maintenance(&state).map_err(to_cmd_err)
+}
+
+/// Checkpoint the WAL, write a compacted backup with `VACUUM INTO`, prune
+/// history past `history_retention_days` and bring the session index and
+/// cost ledger up to date.  The report goes to the event log, and
+/// `last_maintenance_at` is updated so the scheduler waits another week.
+pub(crate) fn run_maintenance(state: &AppState) -> Result<MaintenanceReport, CommanderError> {
+    let started = std::time::Instant::now();
+    let mut report = MaintenanceReport {
+        ran_at: chrono::Utc::now().to_rfc3339(),
+        ..Default::default()
+    };
+
+    {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+
+        match conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            row.get::<_, i64>(2)
+        }) {
+            Ok(frames) => report.wal_frames_checkpointed = frames,
+            Err(e) => report.errors.push(format!("WAL checkpoint: {e}")),
+        }
+
+        match backup_database(conn) {
+            Ok(path) => report.backup_path = Some(path.to_string_lossy().to_string()),
+            Err(e) => report.errors.push(format!("Backup: {e}")),
+        }
+
+        let retention_days: u32 = get_setting(conn, "history_retention_days")
+            .flatten()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(90);
+        if retention_days > 0 {
+            let cutoff =
+                (chrono::Utc::now() - chrono::Duration::days(retention_days as i64)).to_rfc3339();
+            for (table, column, condition) in PRUNED_TABLES {
+                match conn.execute(
+                    &format!("DELETE FROM {table} WHERE {column} < ?1 {condition}"),
+                    [&cutoff],
+                ) {
+                    Ok(n) => report.pruned_rows += n,
+                    Err(e) => report.errors.push(format!("Prune {table}: {e}")),
+                }
+            }
+        }
+
+        if let Err(e) = conn.execute_batch("PRAGMA optimize;") {
+            report.errors.push(format!("Optimize: {e}"));
+        }
+    }
+
+    // Both take the DB lock themselves.
+    match index_sessions(state) {
+        Ok(stats) => report.sessions_reindexed = stats.indexed,
+        Err(e) => report.errors.push(format!("Session index: {e}")),
+    }
+    match update_cost_ledger(state) {
+        Ok(updated) => report.ledger_sessions_updated = updated,
+        Err(e) => report.errors.push(format!("Cost ledger: {e}")),
+    }
+
+    report.duration_ms = started.elapsed().as_millis() as u64;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+    let message = if report.errors.is_empty() {
+        format!(
+            "Maintenance finished in {} ms; pruned {} rows",
+            report.duration_ms, report.pruned_rows
+        )
+    } else {
+        format!("Maintenance finished with {} error(s)", report.errors.len())
+    };
+    record_event(conn, EVENT_KIND_MAINTENANCE, &message, Some(&report))?;
+    set_setting(conn, "last_maintenance_at", &report.ran_at).map_err(CommanderError::internal)?;
+    Ok(report)
+}
+
+/// `VACUUM INTO` a timestamped file next to the JSON snapshots and drop the
+/// oldest copies beyond `MAX_DB_BACKUPS`.
+fn backup_database(conn: &rusqlite::Connection) -> Result<PathBuf, CommanderError> {
+    let dir = backups_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{DB_BACKUP_PREFIX}{}.db",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    conn.execute("VACUUM INTO ?1", [path.to_string_lossy()])?;
+    prune_db_backups(&dir);
+    Ok(path)
+}
+
+fn prune_db_backups(dir: &Path) {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| {
+                    p.extension().and_then(|e| e.to_str()) == Some("db")
+                        && p.file_name()
+                            .and_then(|n| n.to_str())
+                            .is_some_and(|n| n.starts_with(DB_BACKUP_PREFIX))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    let excess = files.len().saturating_sub(MAX_DB_BACKUPS);
+    for path in files.into_iter().take(excess) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            log::warn!("failed to prune database backup {}: {}", path.display(), e);
+        }
+    }
+}
@@ -1,11 +1,53 @@
+pub mod activity;
+pub mod artifacts;
+pub mod backups;
+pub mod badges;
+pub mod board_filter;
 pub mod claude;
+pub mod claude_history;
+pub mod claude_processes;
+pub mod claude_settings;
+pub mod cost_ledger;
+pub mod dashboard;
+pub mod demo_seed;
+pub mod dependencies;
+pub mod disk_usage;
 pub mod github;
+pub mod github_projects;
 pub mod env;
+pub mod event_log;
+pub mod files;
 pub mod git;
+pub mod git_remote;
+pub mod groups;
+pub mod issue_cache;
+pub mod issue_templates;
+pub mod maintenance;
+pub mod notifications;
+pub mod overview;
+pub mod plan_history;
 pub mod planning;
+pub mod presentation;
 pub mod projects;
 pub mod pty;
+pub mod redaction;
+pub mod scaffold;
 pub mod search;
+pub mod session_bookmarks;
+pub mod session_export;
+pub mod session_follow;
+pub mod session_search;
+pub mod session_stack;
+pub mod session_storage;
+pub mod session_summary;
 pub mod settings;
+pub mod slash_commands;
+pub mod status_sync;
+pub mod suggestions;
+pub mod sync_history;
+pub mod task_export;
+pub mod teams;
 pub mod terminal;
+pub mod todos;
 pub mod updater;
+pub mod workspace_snapshots;
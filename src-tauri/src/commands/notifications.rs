@@ -0,0 +1,17 @@
+use crate::models::AppNotification;
+use crate::services::notifications::flush_digest;
+use crate::state::AppState;
+use tauri::{AppHandle, State};
+
+/// Low-priority notifications queued for the next digest.
+#[tauri::command]
+pub fn get_pending_notifications(state: State<AppState>) -> Vec<AppNotification> {
+    state.notifications.pending()
+}
+
+/// Deliver the pending digest now instead of waiting for the interval.
+/// Returns how many notifications it contained.
+#[tauri::command]
+pub fn flush_notification_digest(app: AppHandle) -> usize {
+    flush_digest(&app)
+}
@@ -0,0 +1,125 @@
+use crate::commands::claude::count_recent_sessions;
+use crate::commands::git::git_status;
+use crate::commands::github::detect_github_repo;
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{GitSummary, ProjectOverview};
+use crate::state::AppState;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use tauri::State;
+
+const RECENT_SESSION_WINDOW: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Aggregate git status, open planning items, recent Claude sessions and
+/// linked GitHub issues for one project, replacing several per-card invokes.
+#[tauri::command]
+pub fn get_project_overview(
+    state: State<AppState>,
+    project_id: String,
+) -> CmdResult<ProjectOverview> {
+    // All DB reads happen up front; git and session scanning run unlocked.
+    let (projects, open_planning_items, item_issues, task_links) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+        let projects = load_db_projects(conn).map_err(to_cmd_err)?;
+
+        let open_planning_items: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM planning_items WHERE project_id = ?1 AND status != 'done'",
+                [&project_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+        // (repo, number, is_open) for planning items imported from issues.
+        let mut stmt = conn
+            .prepare(
+                "SELECT github_repo, github_issue_number, status != 'done' FROM planning_items
+                 WHERE project_id = ?1 AND github_issue_number IS NOT NULL",
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let item_issues: Vec<(Option<String>, i64, bool)> = stmt
+            .query_map([&project_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        // (repo, number, cached state) for every Claude task link.
+        let mut stmt = conn
+            .prepare(
+                "SELECT github_repo, github_issue_number, github_issue_state FROM task_github_links
+                 WHERE github_repo IS NOT NULL AND github_issue_number IS NOT NULL",
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let task_links: Vec<(String, i64, Option<String>)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        (
+            projects,
+            open_planning_items as usize,
+            item_issues,
+            task_links,
+        )
+    };
+
+    let project = projects
+        .iter()
+        .find(|p| p.id == project_id)
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Project not found: {}",
+                project_id
+            )))
+        })?;
+
+    let git = git_status(project.path.clone()).ok().map(|s| GitSummary {
+        branch: s.branch,
+        ahead: s.ahead,
+        behind: s.behind,
+        staged: s.staged.len(),
+        unstaged: s.unstaged.len(),
+        untracked: s.untracked.len(),
+    });
+
+    let since = SystemTime::now() - RECENT_SESSION_WINDOW;
+    let sessions_last_7_days = count_recent_sessions(&project_id, &projects, since);
+
+    // Merge both link sources, keyed by issue; an issue is open unless some
+    // source says it is closed.
+    let repo = detect_github_repo(project.path.clone());
+    let mut issues: HashMap<(String, i64), bool> = HashMap::new();
+    for (item_repo, number, is_open) in item_issues {
+        let key = (item_repo.unwrap_or_default().to_lowercase(), number);
+        let open = issues.entry(key).or_insert(true);
+        *open &= is_open;
+    }
+    if let Some(repo) = &repo {
+        for (link_repo, number, state) in task_links {
+            if !link_repo.eq_ignore_ascii_case(repo) {
+                continue;
+            }
+            let open = issues
+                .entry((link_repo.to_lowercase(), number))
+                .or_insert(true);
+            *open &= state.as_deref() != Some("closed");
+        }
+    }
+
+    Ok(ProjectOverview {
+        project_id,
+        git,
+        open_planning_items,
+        sessions_last_7_days,
+        linked_issues: issues.len(),
+        open_linked_issues: issues.values().filter(|open| **open).count(),
+    })
+}
@@ -0,0 +1,162 @@
+use crate::commands::claude::{agent_state_roots, claude_root, modified_at};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::PlanVersion;
+use crate::state::AppState;
+use crate::utils::write_file_atomic;
+use std::path::Path;
+use tauri::State;
+
+/// Versions kept per plan; older ones are dropped as new ones arrive.
+const MAX_PLAN_VERSIONS: i64 = 50;
+
+/// Stored versions of a plan, newest first.  The newest normally matches
+/// the file on disk; the ones after it are what it was overwritten from.
+#[tauri::command]
+pub fn get_plan_history(
+    state: State<AppState>,
+    filename: String,
+    source: Option<String>,
+) -> CmdResult<Vec<PlanVersion>> {
+    let source = plan_source(source);
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, source, filename, content, created_at FROM plan_versions
+             WHERE source = ?1 AND filename = ?2 ORDER BY id DESC",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let versions = stmt
+        .query_map([&source, &filename], |row| {
+            Ok(PlanVersion {
+                id: row.get(0)?,
+                source: row.get(1)?,
+                filename: row.get(2)?,
+                content: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(versions)
+}
+
+/// Write a stored version back over its plan and return the plan's new
+/// `modified_at`.  The content being replaced is recorded first, so a
+/// restore can itself be undone.
+#[tauri::command]
+pub fn restore_plan_version(state: State<AppState>, id: i64) -> CmdResult<String> {
+    let (source, filename, content) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        conn.query_row(
+            "SELECT source, filename, content FROM plan_versions WHERE id = ?1",
+            [id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+    };
+
+    let path = claude_root(Some(&source)).join("plans").join(&filename);
+    if path.is_file() {
+        record_plan_version(&state, &path).map_err(to_cmd_err)?;
+    } else if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+    write_file_atomic(&path, content)?;
+    // Record the restored text now rather than waiting on the watcher.
+    record_plan_version(&state, &path).map_err(to_cmd_err)?;
+    modified_at(&path).ok_or_else(|| {
+        to_cmd_err(CommanderError::FileNotFound {
+            path: path.to_string_lossy().to_string(),
+        })
+    })
+}
+
+/// Store the current text of the plan at `path` if it differs from the
+/// newest stored version.  Paths that are not a `plans/*.md` file of a
+/// Claude root or project `.claude` directory are ignored.  Called by the file watcher.
+pub(crate) fn record_plan_version(state: &AppState, path: &Path) -> Result<(), CommanderError> {
+    let Some((source, filename)) = plan_identity(path) else {
+        return Ok(());
+    };
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        // Deleted or renamed since the event; nothing to keep.
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+    let latest: Option<String> = conn
+        .query_row(
+            "SELECT content FROM plan_versions WHERE source = ?1 AND filename = ?2
+             ORDER BY id DESC LIMIT 1",
+            [&source, &filename],
+            |row| row.get(0),
+        )
+        .ok();
+    if latest.as_deref() == Some(content.as_str()) {
+        return Ok(());
+    }
+
+    conn.execute(
+        "INSERT INTO plan_versions (source, filename, content, created_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![source, filename, content, chrono::Utc::now().to_rfc3339()],
+    )?;
+    conn.execute(
+        "DELETE FROM plan_versions WHERE source = ?1 AND filename = ?2 AND id NOT IN (
+             SELECT id FROM plan_versions WHERE source = ?1 AND filename = ?2
+             ORDER BY id DESC LIMIT ?3)",
+        rusqlite::params![source, filename, MAX_PLAN_VERSIONS],
+    )?;
+    Ok(())
+}
+
+/// Record every plan not yet stored as-is, so the first change the watcher
+/// sees has a previous version to fall back to.
+pub(crate) fn snapshot_plans(state: &AppState) {
+    for (_, root) in agent_state_roots() {
+        let Ok(entries) = std::fs::read_dir(root.join("plans")) else {
+            continue;
+        };
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if let Err(e) = record_plan_version(state, &path) {
+                log::warn!("failed to snapshot plan {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+/// `(source, filename)` of a `<root>/plans/<name>.md` path.
+fn plan_identity(path: &Path) -> Option<(String, String)> {
+    if path.extension().and_then(|e| e.to_str()) != Some("md") {
+        return None;
+    }
+    let filename = path.file_name()?.to_str()?.to_string();
+    let parent = path.parent()?;
+    agent_state_roots()
+        .into_iter()
+        .find(|(_, root)| root.join("plans") == parent)
+        .map(|(source, _)| (source, filename))
+}
+
+fn plan_source(source: Option<String>) -> String {
+    source.unwrap_or_else(|| commander_core::session::DEFAULT_CLAUDE_SOURCE.to_string())
+}
@@ -1,8 +1,18 @@
+use crate::commands::board_filter::{BoardFilter, FilterFields};
+use crate::commands::claude::claude_root;
+use crate::commands::github::{detect_github_repo, issues_key, require_github_auth};
+use crate::commands::issue_cache::cache_issues;
+use crate::commands::projects::load_db_projects;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
 use crate::models::{
-    CreatePlanningItemInput, PlanningItem, PlanningStatus, UpdatePlanningItemInput,
+    CreatePlanningItemInput, IssueImportFilters, IssueImportResult, PlanningItem, PlanningStatus,
+    Project, UpdatePlanningItemInput,
 };
+use crate::services::forge::forge_for_repo;
 use crate::state::AppState;
+use crate::utils::run_external_tool;
+use std::collections::HashMap;
+use std::io::Write;
 use tauri::State;
 use uuid::Uuid;
 
@@ -27,6 +37,10 @@ fn row_to_item(row: &rusqlite::Row) -> rusqlite::Result<PlanningItem> {
         sort_order: row.get(6)?,
         created_at: row.get(7)?,
         updated_at: row.get(8)?,
+        github_repo: row.get(9)?,
+        github_issue_number: row.get(10)?,
+        github_issue_url: row.get(11)?,
+        plan_filename: row.get(12)?,
     })
 }
 
@@ -35,6 +49,23 @@ pub fn get_planning_items(
     state: State<AppState>,
     project_id: String,
 ) -> CmdResult<Vec<PlanningItem>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    load_planning_items(conn, &project_id).map_err(to_cmd_err)
+}
+
+/// Planning items across all projects, optionally narrowed by a board filter
+/// expression (see `BoardFilter`).
+#[tauri::command]
+pub fn get_all_planning_items(
+    state: State<AppState>,
+    filter: Option<String>,
+) -> CmdResult<Vec<PlanningItem>> {
+    let filter = BoardFilter::parse_optional(filter.as_deref()).map_err(to_cmd_err)?;
+
     let db = state.db.lock();
     let conn = db
         .as_ref()
@@ -43,14 +74,59 @@ pub fn get_planning_items(
     let mut stmt = conn
         .prepare(
             "SELECT id, project_id, subject, description, status, priority, sort_order, \
-             created_at, updated_at \
-             FROM planning_items WHERE project_id = ?1 ORDER BY sort_order",
+             created_at, updated_at, github_repo, github_issue_number, github_issue_url, \
+             plan_filename \
+             FROM planning_items ORDER BY updated_at DESC",
         )
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let items: Vec<PlanningItem> = stmt
+        .query_map([], row_to_item)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let Some(filter) = filter else {
+        return Ok(items);
+    };
+    let projects: HashMap<String, Project> = load_db_projects(conn)
+        .map_err(to_cmd_err)?
+        .into_iter()
+        .map(|p| (p.id.clone(), p))
+        .collect();
+
+    Ok(items
+        .into_iter()
+        .filter(|item| {
+            let project = item.project_id.as_ref().and_then(|id| projects.get(id));
+            let status = item.status.to_string();
+            filter.matches(&FilterFields {
+                subject: &item.subject,
+                description: item.description.as_deref(),
+                status: &status,
+                tags: project.map(|p| p.tags.as_slice()).unwrap_or_default(),
+                project: project.map(|p| p.name.as_str()),
+                priority: Some(item.priority),
+                created_at: Some(&item.created_at),
+                updated_at: Some(&item.updated_at),
+                ..Default::default()
+            })
+        })
+        .collect())
+}
+
+pub(crate) fn load_planning_items(
+    conn: &rusqlite::Connection,
+    project_id: &str,
+) -> Result<Vec<PlanningItem>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, subject, description, status, priority, sort_order, \
+         created_at, updated_at, github_repo, github_issue_number, github_issue_url, \
+         plan_filename \
+         FROM planning_items WHERE project_id = ?1 ORDER BY sort_order",
+    )?;
 
     let items = stmt
-        .query_map([&project_id], row_to_item)
-        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .query_map([project_id], row_to_item)?
         .filter_map(|r| r.ok())
         .collect();
 
@@ -96,7 +172,9 @@ pub fn create_planning_item(
     let result = conn
         .query_row(
             "SELECT id, project_id, subject, description, status, priority, sort_order, \
-             created_at, updated_at FROM planning_items WHERE id = ?1",
+             created_at, updated_at, github_repo, github_issue_number, github_issue_url, \
+             plan_filename \
+             FROM planning_items WHERE id = ?1",
             [&id],
             row_to_item,
         )
@@ -125,7 +203,9 @@ pub fn update_planning_item(
     let result = conn
         .query_row(
             "SELECT id, project_id, subject, description, status, priority, sort_order, \
-             created_at, updated_at FROM planning_items WHERE id = ?1",
+             created_at, updated_at, github_repo, github_issue_number, github_issue_url, \
+             plan_filename \
+             FROM planning_items WHERE id = ?1",
             [&item.id],
             row_to_item,
         )
@@ -176,3 +256,480 @@ pub fn delete_planning_item(state: State<AppState>, id: String) -> CmdResult<()>
 
     Ok(())
 }
+
+/// Skeleton for `create_plan_from_item`; `{{subject}}`, `{{origin}}` and
+/// `{{description}}` are filled in from the item.
+const PLAN_TEMPLATE: &str = "# {{subject}}
+
+{{origin}}
+
+## Context
+
+{{description}}
+
+## Steps
+
+- [ ] 
+
+## Verification
+
+- [ ] 
+";
+
+/// Render a planning item into a new markdown plan under the primary
+/// Claude root's `plans/` and remember its filename on the item.  The file
+/// is named after the subject and never overwrites an existing plan; an
+/// item whose plan still exists gets it back unchanged.
+#[tauri::command]
+pub fn create_plan_from_item(state: State<AppState>, item_id: String) -> CmdResult<PlanningItem> {
+    let (item, project_name) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_item_with_project_name(conn, &item_id)
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+    };
+
+    let plans_dir = claude_root(None).join("plans");
+    if item
+        .plan_filename
+        .as_ref()
+        .is_some_and(|f| plans_dir.join(f).is_file())
+    {
+        return Ok(item);
+    }
+
+    let mut origin = String::from("> Created from a planning item");
+    if let Some(name) = &project_name {
+        origin.push_str(&format!(" in **{name}**"));
+    }
+    if let (Some(repo), Some(number), Some(url)) = (
+        &item.github_repo,
+        item.github_issue_number,
+        &item.github_issue_url,
+    ) {
+        origin.push_str(&format!(" · [{repo}#{number}]({url})"));
+    }
+    let content = PLAN_TEMPLATE
+        .replace("{{subject}}", item.subject.trim())
+        .replace("{{origin}}", &origin)
+        .replace(
+            "{{description}}",
+            item.description
+                .as_deref()
+                .map(str::trim)
+                .filter(|d| !d.is_empty())
+                .unwrap_or("_No description._"),
+        );
+
+    std::fs::create_dir_all(&plans_dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let slug = plan_slug(&item.subject);
+    let mut suffix = 1;
+    let filename = loop {
+        let filename = if suffix == 1 {
+            format!("{slug}.md")
+        } else {
+            format!("{slug}-{suffix}.md")
+        };
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(plans_dir.join(&filename))
+        {
+            Ok(mut file) => {
+                file.write_all(content.as_bytes())
+                    .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+                break filename;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => suffix += 1,
+            Err(e) => return Err(to_cmd_err(CommanderError::io(e))),
+        }
+    };
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "UPDATE planning_items SET plan_filename = ?1 WHERE id = ?2",
+        rusqlite::params![filename, item_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(PlanningItem {
+        plan_filename: Some(filename),
+        ..item
+    })
+}
+
+/// A planning item and the name of its project, if it has one.
+fn load_item_with_project_name(
+    conn: &rusqlite::Connection,
+    item_id: &str,
+) -> rusqlite::Result<(PlanningItem, Option<String>)> {
+    conn.query_row(
+        "SELECT pi.id, pi.project_id, pi.subject, pi.description, pi.status, pi.priority, \
+         pi.sort_order, pi.created_at, pi.updated_at, pi.github_repo, \
+         pi.github_issue_number, pi.github_issue_url, pi.plan_filename, p.name \
+         FROM planning_items pi LEFT JOIN projects p ON p.id = pi.project_id \
+         WHERE pi.id = ?1",
+        [item_id],
+        |row| Ok((row_to_item(row)?, row.get::<_, Option<String>>(13)?)),
+    )
+}
+
+/// Lowercase, dash-separated and at most 60 characters, e.g.
+/// `"Fix login (SSO)"` → `"fix-login-sso"`.
+fn plan_slug(subject: &str) -> String {
+    let mut slug = String::new();
+    for c in subject.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.chars().count() >= 60 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "plan".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Open an issue in `repo` ("owner/repo") for a planning item and link the
+/// two.  The issue gets the item's subject as title; its body carries the
+/// description, the checklist of the item's plan when it has one, and a
+/// footer naming the item.  The item records the issue as its link and,
+/// unless it was imported from another issue, as its source, so importing
+/// the new issue later is a no-op.  Items that already have a link are
+/// refused rather than silently re-pointed.
+#[tauri::command]
+pub fn create_issue_from_planning_item(
+    state: State<AppState>,
+    item_id: String,
+    repo: String,
+) -> CmdResult<PlanningItem> {
+    let (item, project_name) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let linked_url: Option<String> = conn
+            .query_row(
+                "SELECT github_issue_url FROM planning_item_github_links WHERE item_id = ?1",
+                [&item_id],
+                |row| row.get(0),
+            )
+            .ok();
+        if let Some(url) = linked_url {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "This item is already linked to {url}"
+            ))));
+        }
+        load_item_with_project_name(conn, &item_id)
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+    };
+
+    let body = issue_body_for_item(&item, project_name.as_deref());
+    // Created without holding the lock; this is a network round trip.
+    let created = forge_for_repo(&repo).create_issue(&repo, item.subject.trim(), &body, &[])?;
+    state.github.invalidate(&issues_key(&repo));
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "INSERT OR REPLACE INTO planning_item_github_links \
+         (item_id, github_issue_url, github_issue_number, github_repo, \
+          github_issue_state, state_updated_at) \
+         VALUES (?1, ?2, ?3, ?4, 'open', ?5)",
+        rusqlite::params![
+            item_id,
+            created.url,
+            created.number,
+            repo,
+            chrono::Utc::now().to_rfc3339()
+        ],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    conn.execute(
+        "UPDATE planning_items SET github_repo = ?1, github_issue_number = ?2, \
+         github_issue_url = ?3, updated_at = datetime('now') \
+         WHERE id = ?4 AND github_issue_url IS NULL",
+        rusqlite::params![repo, created.number, created.url, item_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    load_item_with_project_name(conn, &item_id)
+        .map(|(item, _)| item)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+/// Markdown body for `create_issue_from_planning_item`.  The trailing HTML
+/// comment is invisible on GitHub but lets the issue be traced back to the
+/// item it came from.
+fn issue_body_for_item(item: &PlanningItem, project_name: Option<&str>) -> String {
+    let mut body = item
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .unwrap_or("_No description._")
+        .to_string();
+
+    let checklist: Vec<String> = item
+        .plan_filename
+        .as_ref()
+        .and_then(|f| std::fs::read_to_string(claude_root(None).join("plans").join(f)).ok())
+        .map(|plan| plan.lines().filter_map(checklist_line).collect())
+        .unwrap_or_default();
+    if !checklist.is_empty() {
+        body.push_str("\n\n## Checklist\n\n");
+        body.push_str(&checklist.join("\n"));
+    }
+
+    body.push_str("\n\n---\n_Created from a Claude Commander planning item");
+    if let Some(name) = project_name {
+        body.push_str(&format!(" in **{name}**"));
+    }
+    body.push_str(&format!(
+        "._\n<!-- claude-commander:planning-item:{} -->\n",
+        item.id
+    ));
+    body
+}
+
+/// A plan's `- [ ] …` / `- [x] …` line as a GitHub task list entry, or
+/// `None` for other lines and for the template's empty checkboxes.
+fn checklist_line(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    let rest = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))?;
+    let (checked, text) = if let Some(text) = rest.strip_prefix("[ ] ") {
+        (false, text)
+    } else if let Some(text) = rest
+        .strip_prefix("[x] ")
+        .or_else(|| rest.strip_prefix("[X] "))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(format!("- [{}] {text}", if checked { "x" } else { " " }))
+}
+
+/// Pull open issues from `repo` ("owner/repo") via `gh issue list` and create
+/// a backlog item for each one in the project whose origin remote is `repo`.
+/// Issues that already have an item in that project, imported or linked by
+/// hand, are skipped.
+#[tauri::command]
+pub fn import_issues_as_items(
+    state: State<AppState>,
+    repo: String,
+    filters: Option<IssueImportFilters>,
+) -> CmdResult<IssueImportResult> {
+    // Resolve the target project without holding the DB lock during git I/O.
+    let projects = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_db_projects(conn).map_err(to_cmd_err)?
+    };
+    // Canonical "owner/repo" casing from the remote, so re-imports de-duplicate
+    // regardless of how the caller typed it.
+    let (project_id, repo) = projects
+        .iter()
+        .find_map(|p| {
+            detect_github_repo(p.path.clone())
+                .filter(|r| r.eq_ignore_ascii_case(&repo))
+                .map(|r| (p.id.clone(), r))
+        })
+        .ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "No project has {} as its origin remote",
+                repo
+            )))
+        })?;
+    import_issues(&state, project_id, repo, filters.unwrap_or_default())
+}
+
+/// Like `import_issues_as_items`, but into the given project, so issues can
+/// come from a repository other than its origin (e.g. a shared tracker).
+/// Each issue's link is stored on its item; re-importing skips issues the
+/// project already has an item for, whatever column it has moved to.
+#[tauri::command]
+pub fn import_github_issues(
+    state: State<AppState>,
+    project_id: String,
+    repo: String,
+    filters: Option<IssueImportFilters>,
+) -> CmdResult<IssueImportResult> {
+    let project_path: String = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        conn.query_row(
+            "SELECT path FROM projects WHERE id = ?1",
+            [&project_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Project not found: {project_id}"
+            )))
+        })?
+    };
+    let repo = repo.trim();
+    if !repo.contains('/') || repo.split('/').any(str::is_empty) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid repository: {repo}"
+        ))));
+    }
+    // Prefer the origin's casing when it is the same repository, so items
+    // imported either way de-duplicate against each other.
+    let repo = detect_github_repo(project_path)
+        .filter(|origin| origin.eq_ignore_ascii_case(repo))
+        .unwrap_or_else(|| repo.to_string());
+    import_issues(&state, project_id, repo, filters.unwrap_or_default())
+}
+
+fn import_issues(
+    state: &AppState,
+    project_id: String,
+    repo: String,
+    filters: IssueImportFilters,
+) -> CmdResult<IssueImportResult> {
+    require_github_auth()?;
+    let limit = filters.limit.unwrap_or(100).to_string();
+    let mut cmd = std::process::Command::new("gh");
+    cmd.args([
+        "issue",
+        "list",
+        "--repo",
+        &repo,
+        "--state",
+        "open",
+        "--limit",
+        &limit,
+        "--json",
+        "number,title,body,url,state,updatedAt",
+    ]);
+    for label in &filters.labels {
+        cmd.args(["--label", label]);
+    }
+    if let Some(assignee) = &filters.assignee {
+        cmd.args(["--assignee", assignee]);
+    }
+    if let Some(milestone) = &filters.milestone {
+        cmd.args(["--milestone", milestone]);
+    }
+    if let Some(search) = &filters.search {
+        cmd.args(["--search", search]);
+    }
+    let output = run_external_tool(&mut cmd)?;
+    let issues: Vec<serde_json::Value> =
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    // Keep the fetched issues browsable offline; failure here is not fatal.
+    let _ = cache_issues(conn, &repo, &issues);
+
+    let mut sort_order: i64 = conn
+        .query_row(
+            "SELECT COALESCE(MAX(sort_order), 0) FROM planning_items \
+             WHERE project_id = ?1 AND status = 'backlog'",
+            [&project_id],
+            |row| row.get(0),
+        )
+        .unwrap_or(0);
+
+    let mut imported = Vec::new();
+    let mut skipped_count = 0;
+    // Oldest issues first, so the board order matches the tracker's.
+    for issue in issues.iter().rev() {
+        let Some(number) = issue["number"].as_i64() else {
+            continue;
+        };
+        let title = issue["title"].as_str().unwrap_or_default();
+        let body = issue["body"].as_str().filter(|b| !b.trim().is_empty());
+        let url = issue["url"].as_str();
+
+        // An item linked to the issue by hand already stands for it.
+        let linked: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM planning_item_github_links l \
+                 JOIN planning_items pi ON pi.id = l.item_id \
+                 WHERE pi.project_id = ?1 AND lower(l.github_repo) = lower(?2) \
+                 AND l.github_issue_number = ?3)",
+                rusqlite::params![project_id, repo, number],
+                |row| row.get(0),
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        if linked {
+            skipped_count += 1;
+            continue;
+        }
+
+        sort_order += 1000;
+        let id = Uuid::new_v4().to_string();
+        // The partial unique index on (project_id, github_repo, github_issue_number)
+        // turns re-imports into no-ops.
+        let inserted = conn
+            .execute(
+                "INSERT OR IGNORE INTO planning_items \
+                 (id, project_id, subject, description, status, sort_order, \
+                  github_repo, github_issue_number, github_issue_url) \
+                 VALUES (?1, ?2, ?3, ?4, 'backlog', ?5, ?6, ?7, ?8)",
+                rusqlite::params![id, project_id, title, body, sort_order, repo, number, url],
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+        if inserted == 0 {
+            skipped_count += 1;
+            continue;
+        }
+        if let Some(url) = url {
+            conn.execute(
+                "INSERT OR IGNORE INTO planning_item_github_links \
+                 (item_id, github_issue_url, github_issue_number, github_repo) \
+                 VALUES (?1, ?2, ?3, ?4)",
+                rusqlite::params![id, url, number, repo],
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        }
+
+        let item = conn
+            .query_row(
+                "SELECT id, project_id, subject, description, status, priority, sort_order, \
+                 created_at, updated_at, github_repo, github_issue_number, github_issue_url, \
+                 plan_filename \
+                 FROM planning_items WHERE id = ?1",
+                [&id],
+                row_to_item,
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        imported.push(item);
+    }
+
+    Ok(IssueImportResult {
+        project_id,
+        imported,
+        skipped_count,
+    })
+}
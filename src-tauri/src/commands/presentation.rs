@@ -0,0 +1,59 @@
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::PresentationServerInfo;
+use crate::services::presentation::PresentationServer;
+use crate::state::AppState;
+use tauri::{AppHandle, State};
+
+/// Serve a read-only, auto-refreshing view of the project's board and recent
+/// Claude activity on the LAN.  Returns the running server if one is already
+/// up for this project.
+#[tauri::command]
+pub fn start_presentation_server(
+    app: AppHandle,
+    state: State<AppState>,
+    project_id: String,
+    port: Option<u16>,
+) -> CmdResult<PresentationServerInfo> {
+    if let Some(server) = state.presentation_servers.lock().get(&project_id) {
+        return Ok(server.info.clone());
+    }
+
+    {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let exists = load_db_projects(conn)
+            .map_err(to_cmd_err)?
+            .iter()
+            .any(|p| p.id == project_id);
+        if !exists {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Project not found: {project_id}"
+            ))));
+        }
+    }
+
+    let server = PresentationServer::start(app, project_id.clone(), port.unwrap_or(0))
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let info = server.info.clone();
+    state.presentation_servers.lock().insert(project_id, server);
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn stop_presentation_server(state: State<AppState>, project_id: String) {
+    // Dropping the server stops it.
+    state.presentation_servers.lock().remove(&project_id);
+}
+
+#[tauri::command]
+pub fn get_presentation_servers(state: State<AppState>) -> Vec<PresentationServerInfo> {
+    state
+        .presentation_servers
+        .lock()
+        .values()
+        .map(|s| s.info.clone())
+        .collect()
+}
@@ -1,92 +1,86 @@
+use crate::commands::backups::{create_snapshot, PROJECT_TABLES};
+use crate::commands::claude::{correlate_session_project, projects_with_sessions_since};
+use crate::commands::settings::{allowed_roots, get_json_setting, get_setting};
+use crate::commands::sync_history::record_sync;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::models::{CreateProjectInput, Project, SyncResult};
+use crate::models::{
+    CreateProjectInput, DestructiveOpResult, DuplicateProjectGroup, Project, ProjectWithStats,
+    SyncHistoryProject, SyncResult, DEFAULT_PROJECT_MARKERS, DEFAULT_SCAN_EXCLUDE_PATTERNS,
+};
 use crate::state::AppState;
-use crate::utils::validate_home_path;
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use crate::utils::{parse_timestamp, validate_allowed_path, validate_path_within};
+use commander_core::scan::{git_remote_identity, scan_roots, worktree_main_path, ScanOptions};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tauri::State;
 use uuid::Uuid;
-use walkdir::WalkDir;
 
-// ─── Identity key helpers ───────────────────────────────────────────────────
+// ─── Color & icon helpers ───────────────────────────────────────────────────
 
-/// Derive a stable identity key for a project directory that survives renames
-/// and relocations.
-///
-/// Strategy 1 – git remote origin URL (normalised, prefixed with `git:`).
-/// Strategy 2 – UUID stamp written to `.claude-commander-id` in the project
-///              root (created on first scan if no git remote is found).
-fn compute_identity_key(path: &Path) -> String {
-    if let Some(key) = git_remote_identity(path) {
-        return key;
-    }
-    uuid_stamp_identity(path)
-}
+/// Same palette the frontend falls back to in `getProjectColor`, so persisting
+/// an auto-assigned color does not change what the user already sees.
+const PROJECT_PALETTE: &[&str] = &[
+    "#6366f1", "#8b5cf6", "#ec4899", "#ef4444", "#f97316",
+    "#eab308", "#22c55e", "#14b8a6", "#06b6d4", "#3b82f6",
+];
 
-/// Read the `origin` remote URL from the git repository at `path`, normalise
-/// it, and return `"git:<url>"`.  Returns `None` when the directory is not a
-/// git repo or has no `origin` remote.
-fn git_remote_identity(path: &Path) -> Option<String> {
-    let repo = git2::Repository::open(path).ok()?;
-    let remote = repo.find_remote("origin").ok()?;
-    let url = remote.url()?.trim().to_string();
-    // Normalise: strip trailing slash and optional `.git` suffix so that
-    // `https://github.com/foo/bar` and `https://github.com/foo/bar.git` map
-    // to the same key.
-    let normalised = url
-        .trim_end_matches('/')
-        .trim_end_matches(".git")
-        .to_string();
-    Some(format!("git:{}", normalised))
-}
-
-/// Read a UUID from the `.claude-commander-id` stamp file inside `path`,
-/// creating and writing one if the file does not yet exist.  Returns
-/// `"stamp:<uuid>"`.
-fn uuid_stamp_identity(path: &Path) -> String {
-    let stamp_file = path.join(".claude-commander-id");
-    if let Ok(content) = std::fs::read_to_string(&stamp_file) {
-        let trimmed = content.trim().to_string();
-        if !trimmed.is_empty() {
-            return format!("stamp:{}", trimmed);
-        }
-    }
-    let id = Uuid::new_v4().to_string();
-    // Best-effort write – if the directory is read-only we silently skip.
-    let _ = std::fs::write(&stamp_file, &id);
-    format!("stamp:{}", id)
+/// Stable palette color for a project name.  Mirrors the frontend's
+/// `hash * 31 + code_unit` string hash over UTF-16 code units.
+fn auto_project_color(name: &str) -> String {
+    let hash = name
+        .encode_utf16()
+        .fold(0i32, |h, c| h.wrapping_mul(31).wrapping_add(c as i32));
+    let idx = (hash as i64).unsigned_abs() as usize % PROJECT_PALETTE.len();
+    PROJECT_PALETTE[idx].to_string()
 }
 
 // ─── Internal DB helpers ────────────────────────────────────────────────────
 
+/// Column list matching `row_to_project`.
+pub(crate) const PROJECT_COLUMNS: &str = "id, name, path, tags, color, sort_order, is_archived, \
+     created_at, identity_key, icon, group_id, language, framework, package_manager, last_opened_at, \
+     real_path, worktree_of, archived_reason, archived_at";
+
+pub(crate) fn row_to_project(row: &rusqlite::Row) -> rusqlite::Result<Project> {
+    let tags_str: String = row.get(3)?;
+    let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
+    Ok(Project {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        path: row.get(2)?,
+        tags,
+        color: row.get(4)?,
+        sort_order: row.get(5)?,
+        is_archived: {
+            let v: i64 = row.get(6)?;
+            v != 0
+        },
+        created_at: row.get(7)?,
+        identity_key: row.get(8)?,
+        icon: row.get(9)?,
+        group_id: row.get(10)?,
+        language: row.get(11)?,
+        framework: row.get(12)?,
+        package_manager: row.get(13)?,
+        last_opened_at: row.get(14)?,
+        real_path: row.get(15)?,
+        worktree_of: row.get(16)?,
+        archived_reason: row.get(17)?,
+        archived_at: row.get(18)?,
+    })
+}
+
 /// Load all non-archived projects from the DB.
-fn load_db_projects(conn: &rusqlite::Connection) -> Result<Vec<Project>, CommanderError> {
+pub(crate) fn load_db_projects(conn: &rusqlite::Connection) -> Result<Vec<Project>, CommanderError> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, name, path, tags, color, sort_order, is_archived, created_at, identity_key
-             FROM projects WHERE is_archived = 0",
+            &format!("SELECT {PROJECT_COLUMNS} FROM projects WHERE is_archived = 0"),
         )
         .map_err(CommanderError::from)?;
 
     let projects = stmt
-        .query_map([], |row| {
-            let tags_str: String = row.get(3)?;
-            let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
-            Ok(Project {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                path: row.get(2)?,
-                tags,
-                color: row.get(4)?,
-                sort_order: row.get(5)?,
-                is_archived: {
-                    let v: i64 = row.get(6)?;
-                    v != 0
-                },
-                created_at: row.get(7)?,
-                identity_key: row.get(8)?,
-            })
-        })
+        .query_map([], row_to_project)
         .map_err(CommanderError::from)?
         .filter_map(|r| r.ok())
         .collect();
@@ -94,6 +88,37 @@ fn load_db_projects(conn: &rusqlite::Connection) -> Result<Vec<Project>, Command
     Ok(projects)
 }
 
+/// Load the archived projects, which sync matches but leaves archived.
+fn load_archived_projects(conn: &rusqlite::Connection) -> Result<Vec<Project>, CommanderError> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT {PROJECT_COLUMNS} FROM projects WHERE is_archived = 1"))
+        .map_err(CommanderError::from)?;
+    let projects = stmt
+        .query_map([], row_to_project)
+        .map_err(CommanderError::from)?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(projects)
+}
+
+/// Stamp `last_opened_at` on the project whose path is the deepest ancestor
+/// of `path` (so opening a sub-package touches that package's project).
+/// A path outside every project is silently ignored.
+pub(crate) fn touch_project_by_path(
+    conn: &rusqlite::Connection,
+    path: &str,
+) -> Result<(), CommanderError> {
+    let projects = load_db_projects(conn)?;
+    if let Some(id) = correlate_session_project(path, &projects) {
+        conn.execute(
+            "UPDATE projects SET last_opened_at = ?1 WHERE id = ?2",
+            rusqlite::params![chrono::Utc::now().to_rfc3339(), id],
+        )
+        .map_err(CommanderError::from)?;
+    }
+    Ok(())
+}
+
 /// Update a project's path and name in the DB, first removing any conflicting
 /// record that already occupies `new_path` (which would violate the UNIQUE
 /// constraint).  The conflicting record is a stale path-only entry for the
@@ -120,73 +145,194 @@ fn apply_path_update(
     Ok(())
 }
 
-// ─── Commands ───────────────────────────────────────────────────────────────
-
-#[tauri::command]
-pub fn scan_projects(scan_path: Option<String>) -> CmdResult<Vec<Project>> {
-    let base = if let Some(ref p) = scan_path {
-        validate_home_path(p)?
-    } else {
-        dirs::home_dir()
-            .map(|h| h.join("cv"))
-            .ok_or_else(|| to_cmd_err(CommanderError::internal("Cannot determine scan path")))?
-    };
+/// Overwrite the detected stack columns and resolved `real_path` with the
+/// latest scan result.  These are derived data, so unlike color/icon they
+/// are always refreshed.
+fn apply_stack_update(
+    conn: &rusqlite::Connection,
+    id: &str,
+    scanned: &Project,
+) -> Result<(), CommanderError> {
+    conn.execute(
+        "UPDATE projects SET language = ?1, framework = ?2, package_manager = ?3, real_path = ?4
+         WHERE id = ?5",
+        rusqlite::params![
+            scanned.language,
+            scanned.framework,
+            scanned.package_manager,
+            scanned.real_path,
+            id
+        ],
+    )
+    .map_err(CommanderError::from)?;
+    Ok(())
+}
 
-    if !base.exists() {
-        return Ok(vec![]);
+/// Records created before worktree-aware identity keys share their main
+/// checkout's key, which would make identity matching pick either one.
+/// Give them their `#worktree:` key before any matching happens.
+fn migrate_worktree_identity_keys(conn: &rusqlite::Connection) -> Result<(), CommanderError> {
+    for proj in load_db_projects(conn)? {
+        let Some(old_key) = proj.identity_key.as_deref() else {
+            continue;
+        };
+        if !old_key.starts_with("git:") || old_key.contains("#worktree:") {
+            continue;
+        }
+        let path = Path::new(&proj.path);
+        if worktree_main_path(path).is_none() {
+            continue;
+        }
+        if let Some(key) = git_remote_identity(path) {
+            conn.execute(
+                "UPDATE projects SET identity_key = ?1 WHERE id = ?2",
+                rusqlite::params![key, proj.id],
+            )?;
+        }
     }
+    Ok(())
+}
 
-    let mut projects = Vec::new();
-
-    for entry in WalkDir::new(&base)
-        .min_depth(1)
-        .max_depth(2)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_dir())
-    {
-        let path = entry.path();
-
-        let has_package_json = path.join("package.json").exists();
-        let has_cargo_toml = path.join("Cargo.toml").exists();
-        let has_git = path.join(".git").exists();
+/// Point every linked worktree's `worktree_of` at the project holding its
+/// main checkout, clearing it when that project is gone.
+fn link_worktrees(conn: &rusqlite::Connection) -> Result<(), CommanderError> {
+    let projects = load_db_projects(conn)?;
+    let by_location: HashMap<PathBuf, &str> = projects
+        .iter()
+        .flat_map(|p| {
+            std::iter::once(&p.path)
+                .chain(p.real_path.as_ref())
+                .map(move |loc| (PathBuf::from(loc), p.id.as_str()))
+        })
+        .collect();
 
-        if !has_package_json && !has_cargo_toml && !has_git {
-            continue;
+    for proj in &projects {
+        let main_id = worktree_main_path(Path::new(&proj.path))
+            .and_then(|main| by_location.get(&main).copied())
+            .filter(|id| *id != proj.id);
+        if main_id != proj.worktree_of.as_deref() {
+            conn.execute(
+                "UPDATE projects SET worktree_of = ?1 WHERE id = ?2",
+                rusqlite::params![main_id, proj.id],
+            )?;
         }
+    }
+    Ok(())
+}
 
-        let path_str = path.to_string_lossy();
-        if path_str.contains("node_modules")
-            || path_str.contains("/.git")
-            || path_str.contains("/target")
-            || path_str.contains("/.cargo")
-        {
-            continue;
+/// Order projects so each one's linked worktrees directly follow it.
+/// Worktrees whose main project isn't in the list keep their own slot.
+fn group_worktrees(projects: Vec<Project>) -> Vec<Project> {
+    let ids: HashSet<String> = projects.iter().map(|p| p.id.clone()).collect();
+    let mut worktrees: HashMap<String, Vec<Project>> = HashMap::new();
+    let mut mains = Vec::new();
+    for proj in projects {
+        match proj.worktree_of.clone().filter(|id| ids.contains(id)) {
+            Some(main_id) => worktrees.entry(main_id).or_default().push(proj),
+            None => mains.push(proj),
         }
+    }
 
-        let name = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+    let mut grouped = Vec::with_capacity(ids.len());
+    for main in mains {
+        let children = worktrees.remove(&main.id).unwrap_or_default();
+        grouped.push(main);
+        grouped.extend(children);
+    }
+    grouped
+}
 
-        let identity_key = Some(compute_identity_key(path));
+// ─── Scanning ───────────────────────────────────────────────────────────────
 
-        projects.push(Project {
-            id: Uuid::new_v4().to_string(), // placeholder; real ID assigned on upsert
-            name,
-            path: path.to_string_lossy().to_string(),
-            tags: vec![],
-            color: None,
-            sort_order: 0,
-            is_archived: false,
-            created_at: chrono::Utc::now().to_rfc3339(),
-            identity_key,
-        });
+/// Resolve the configured scan roots: the `scan_paths` JSON array when set,
+/// otherwise the legacy single `scan_path`, otherwise `~/cv`.  Roots that fail
+/// home-directory validation are skipped.
+pub(crate) fn configured_scan_roots(conn: &rusqlite::Connection) -> Vec<PathBuf> {
+    let configured: Vec<String> = get_json_setting::<Vec<String>>(conn, "scan_paths")
+        .filter(|paths| !paths.is_empty())
+        .or_else(|| get_setting(conn, "scan_path").flatten().map(|p| vec![p]))
+        .unwrap_or_default();
+
+    if configured.is_empty() {
+        return dirs::home_dir().map(|h| h.join("cv")).into_iter().collect();
     }
 
-    projects.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(projects)
+    let allowed = allowed_roots(conn);
+    configured
+        .iter()
+        .filter_map(|p| validate_path_within(p, &allowed).ok())
+        .collect()
+}
+
+/// Files or directories whose presence marks a directory as a project root,
+/// read from the `project_markers` setting.
+pub(crate) fn configured_project_markers(conn: &rusqlite::Connection) -> Vec<String> {
+    get_json_setting::<Vec<String>>(conn, "project_markers")
+        .filter(|markers| !markers.is_empty())
+        .unwrap_or_else(|| DEFAULT_PROJECT_MARKERS.iter().map(|m| m.to_string()).collect())
+}
+
+/// Glob patterns for directories the scanner must skip, read from the
+/// `scan_exclude_patterns` setting.  Invalid patterns are ignored.
+pub(crate) fn configured_exclude_patterns(conn: &rusqlite::Connection) -> Vec<glob::Pattern> {
+    get_json_setting::<Vec<String>>(conn, "scan_exclude_patterns")
+        .unwrap_or_else(|| {
+            DEFAULT_SCAN_EXCLUDE_PATTERNS
+                .iter()
+                .map(|p| p.to_string())
+                .collect()
+        })
+        .iter()
+        .filter_map(|p| glob::Pattern::new(p).ok())
+        .collect()
+}
+
+/// Pick the roots for a scan (explicit `scan_paths`, then explicit
+/// `scan_path`, then whatever is configured in settings) along with the
+/// configured project markers.
+fn resolve_scan_options(
+    state: &AppState,
+    scan_path: Option<String>,
+    scan_paths: Option<Vec<String>>,
+) -> CmdResult<ScanOptions> {
+    let explicit: Vec<String> = match (scan_paths, scan_path) {
+        (Some(paths), _) if !paths.is_empty() => paths,
+        (_, Some(p)) => vec![p],
+        _ => vec![],
+    };
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let roots = if explicit.is_empty() {
+        configured_scan_roots(conn)
+    } else {
+        let allowed = allowed_roots(conn);
+        explicit
+            .iter()
+            .map(|p| validate_path_within(p, &allowed))
+            .collect::<CmdResult<Vec<_>>>()?
+    };
+
+    Ok(ScanOptions {
+        roots,
+        markers: configured_project_markers(conn),
+        excludes: configured_exclude_patterns(conn),
+    })
+}
+
+// ─── Commands ───────────────────────────────────────────────────────────────
+
+#[tauri::command]
+pub fn scan_projects(
+    state: State<AppState>,
+    scan_path: Option<String>,
+    scan_paths: Option<Vec<String>>,
+) -> CmdResult<Vec<Project>> {
+    let opts = resolve_scan_options(&state, scan_path, scan_paths)?;
+    Ok(scan_roots(&opts))
 }
 
 /// Atomic, DB-aware sync.  Scans the filesystem then reconciles the results
@@ -196,29 +342,39 @@ pub fn scan_projects(scan_path: Option<String>) -> CmdResult<Vec<Project>> {
 ///   path updated in DB, record preserved.
 /// - **identity_key match, path same** → no-op, counted as unchanged.
 /// - **path match only** → existing record; backfills identity_key if missing.
+/// - **archived match** → left archived, counted as unchanged.
 /// - **no match** → new project; inserted fresh.
 #[tauri::command]
 pub fn sync_projects(
     state: State<AppState>,
     scan_path: Option<String>,
+    scan_paths: Option<Vec<String>>,
 ) -> CmdResult<SyncResult> {
+    let started = Instant::now();
+
     // Scan filesystem without holding the DB lock.
-    let scanned = scan_projects(scan_path.clone())?;
+    let opts = resolve_scan_options(&state, scan_path, scan_paths)?;
+    let scanned = scan_roots(&opts);
+    let scan_duration_ms = started.elapsed().as_millis() as u64;
+    let roots = opts.roots;
 
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
 
+    migrate_worktree_identity_keys(conn).map_err(to_cmd_err)?;
     let db_projects = load_db_projects(conn).map_err(to_cmd_err)?;
+    let archived_projects = load_archived_projects(conn).map_err(to_cmd_err)?;
 
-    // Build lookup maps.
-    let mut by_identity: HashMap<String, Project> = db_projects
-        .iter()
+    // Build lookup maps.  Archived records are included so a folder that is
+    // still on disk matches its archived row instead of being inserted again
+    // (`path` is UNIQUE); active records come last and win any tie.
+    let all_projects = || archived_projects.iter().chain(&db_projects);
+    let mut by_identity: HashMap<String, Project> = all_projects()
         .filter_map(|p| p.identity_key.as_ref().map(|k| (k.clone(), p.clone())))
         .collect();
-    let by_path: HashMap<String, Project> = db_projects
-        .iter()
+    let by_path: HashMap<String, Project> = all_projects()
         .map(|p| (p.path.clone(), p.clone()))
         .collect();
 
@@ -234,7 +390,13 @@ pub fn sync_projects(
         // ── 1. Match by identity_key ────────────────────────────────────────
         if let Some(key) = ident {
             if let Some(existing) = by_identity.remove(key) {
+                // Archived projects stay archived until `restore_project`.
+                if existing.is_archived {
+                    unchanged_count += 1;
+                    continue;
+                }
                 matched_ids.insert(existing.id.clone());
+                apply_stack_update(conn, &existing.id, scanned_proj).map_err(to_cmd_err)?;
                 if existing.path != scanned_proj.path {
                     // Folder was renamed or relocated.
                     apply_path_update(conn, &existing.id, &scanned_proj.path, &scanned_proj.name)
@@ -242,6 +404,7 @@ pub fn sync_projects(
                     updated.push(Project {
                         path: scanned_proj.path.clone(),
                         name: scanned_proj.name.clone(),
+                        real_path: scanned_proj.real_path.clone(),
                         ..existing
                     });
                 } else {
@@ -253,7 +416,12 @@ pub fn sync_projects(
 
         // ── 2. Match by path ────────────────────────────────────────────────
         if let Some(existing) = by_path.get(&scanned_proj.path) {
+            if existing.is_archived {
+                unchanged_count += 1;
+                continue;
+            }
             matched_ids.insert(existing.id.clone());
+            apply_stack_update(conn, &existing.id, scanned_proj).map_err(to_cmd_err)?;
             // Backfill identity_key for records that pre-date #4.
             if let (None, Some(key)) = (&existing.identity_key, ident) {
                 conn.execute(
@@ -269,10 +437,24 @@ pub fn sync_projects(
         // ── 3. New project ──────────────────────────────────────────────────
         let new_id = Uuid::new_v4().to_string();
         let now = chrono::Utc::now().to_rfc3339();
+        let color = auto_project_color(&scanned_proj.name);
         conn.execute(
-            "INSERT INTO projects (id, name, path, tags, identity_key, created_at)
-             VALUES (?1, ?2, ?3, '[]', ?4, ?5)",
-            rusqlite::params![new_id, scanned_proj.name, scanned_proj.path, ident, now],
+            "INSERT INTO projects (id, name, path, tags, color, identity_key, created_at, icon,
+                                   language, framework, package_manager, real_path)
+             VALUES (?1, ?2, ?3, '[]', ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            rusqlite::params![
+                new_id,
+                scanned_proj.name,
+                scanned_proj.path,
+                color,
+                ident,
+                now,
+                scanned_proj.icon,
+                scanned_proj.language,
+                scanned_proj.framework,
+                scanned_proj.package_manager,
+                scanned_proj.real_path
+            ],
         )
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
 
@@ -281,54 +463,220 @@ pub fn sync_projects(
             name: scanned_proj.name.clone(),
             path: scanned_proj.path.clone(),
             tags: vec![],
-            color: None,
+            color: Some(color),
             sort_order: 0,
             is_archived: false,
             created_at: now,
             identity_key: scanned_proj.identity_key.clone(),
+            icon: scanned_proj.icon.clone(),
+            group_id: None,
+            language: scanned_proj.language.clone(),
+            framework: scanned_proj.framework.clone(),
+            package_manager: scanned_proj.package_manager.clone(),
+            last_opened_at: None,
+            real_path: scanned_proj.real_path.clone(),
+            worktree_of: None,
+            archived_reason: None,
+            archived_at: None,
         });
     }
 
-    // ── 4. Archive stale records ─────────────────────────────────────────────
+    // ── 4. Backfill color / icon on matched records ──────────────────────────
+    // Only fills gaps: a color or icon the user picked is never overwritten.
+    let scanned_icons: HashMap<&str, &str> = scanned
+        .iter()
+        .filter_map(|p| p.icon.as_deref().map(|i| (p.path.as_str(), i)))
+        .collect();
+    for proj in db_projects.iter().filter(|p| matched_ids.contains(&p.id)) {
+        if proj.color.is_none() {
+            conn.execute(
+                "UPDATE projects SET color = ?1 WHERE id = ?2 AND color IS NULL",
+                rusqlite::params![auto_project_color(&proj.name), proj.id],
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        }
+        if proj.icon.is_none() {
+            if let Some(icon) = scanned_icons.get(proj.path.as_str()) {
+                conn.execute(
+                    "UPDATE projects SET icon = ?1 WHERE id = ?2 AND icon IS NULL",
+                    rusqlite::params![icon, proj.id],
+                )
+                .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+            }
+        }
+    }
+
+    // ── 5. Link worktrees to their main project ──────────────────────────────
+    link_worktrees(conn).map_err(to_cmd_err)?;
+
+    // ── 6. Archive stale records ─────────────────────────────────────────────
     // Any DB project not matched during the scan is soft-deleted when either:
     //   a) its path no longer exists on disk, OR
-    //   b) its path exists but falls outside the current scan root (stale from
-    //      a previous scan_path setting or a folder renamed while the app was
-    //      closed).
-    let scan_base: Option<std::path::PathBuf> = if let Some(ref p) = scan_path {
-        validate_home_path(p).ok()
-    } else {
-        dirs::home_dir().map(|h| h.join("cv"))
-    };
-
-    let mut archived_count: usize = 0;
+    //   b) its path exists but falls outside every current scan root (stale
+    //      from a previous scan_paths setting or a folder renamed while the
+    //      app was closed).  Projects added by hand under an `allowed_roots`
+    //      entry are in scope too.
+    let extra_roots = allowed_roots(conn);
+    let mut archived: Vec<SyncHistoryProject> = Vec::new();
     for proj in &db_projects {
         if matched_ids.contains(&proj.id) {
             continue;
         }
         let path_obj = std::path::Path::new(&proj.path);
         let path_exists = path_obj.exists();
-        let within_scan_root = scan_base
-            .as_ref()
-            .map(|base| path_obj.starts_with(base))
-            .unwrap_or(true);
+        // `allowed_roots` are canonical, so compare the resolved path too.
+        let real_path = std::fs::canonicalize(path_obj).ok();
+        let within_scan_root = roots.is_empty()
+            || roots.iter().any(|base| path_obj.starts_with(base))
+            || extra_roots.iter().any(|base| {
+                path_obj.starts_with(base)
+                    || real_path.as_ref().is_some_and(|p| p.starts_with(base))
+            });
 
-        if !path_exists || !within_scan_root {
-            conn.execute(
-                "UPDATE projects SET is_archived = 1 WHERE id = ?1",
-                [&proj.id],
+        let reason = if !path_exists {
+            "missing_path"
+        } else if !within_scan_root {
+            "out_of_scan_root"
+        } else {
+            continue;
+        };
+        conn.execute(
+            "UPDATE projects SET is_archived = 1, archived_reason = ?1, archived_at = ?2
+             WHERE id = ?3",
+            rusqlite::params![reason, chrono::Utc::now().to_rfc3339(), proj.id],
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        archived.push(SyncHistoryProject {
+            id: proj.id.clone(),
+            name: proj.name.clone(),
+            path: proj.path.clone(),
+            reason: Some(reason.to_string()),
+        });
+    }
+
+    // ── 7. Archive inactive projects (opt-in) ────────────────────────────────
+    let inactive_days = get_setting(conn, "auto_archive_inactive_days")
+        .flatten()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(0);
+    let last_planning = if inactive_days > 0 {
+        last_planning_updates(conn).map_err(to_cmd_err)?
+    } else {
+        HashMap::new()
+    };
+    // Walking session files and opening every repo is slow; release the DB
+    // lock meanwhile, like the scan above.
+    drop(db);
+    let candidates: Vec<&Project> = db_projects
+        .iter()
+        .filter(|p| matched_ids.contains(&p.id))
+        .collect();
+    let inactive = if inactive_days > 0 {
+        find_inactive_projects(&candidates, &db_projects, &last_planning, inactive_days)
+    } else {
+        vec![]
+    };
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let now = chrono::Utc::now();
+    let opened_cutoff = (now - chrono::Duration::days(inactive_days)).to_rfc3339();
+    for id in inactive {
+        // Skipped if the project was archived or opened while unlocked.
+        let changed = conn
+            .execute(
+                "UPDATE projects SET is_archived = 1, archived_reason = 'inactive', archived_at = ?1
+                 WHERE id = ?2 AND is_archived = 0
+                   AND (last_opened_at IS NULL OR last_opened_at < ?3)",
+                rusqlite::params![now.to_rfc3339(), id, opened_cutoff],
             )
             .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
-            archived_count += 1;
+        if changed == 0 {
+            continue;
+        }
+        if let Some(proj) = candidates.iter().find(|p| p.id == id) {
+            archived.push(SyncHistoryProject {
+                id: proj.id.clone(),
+                name: proj.name.clone(),
+                path: proj.path.clone(),
+                reason: Some("inactive".to_string()),
+            });
         }
     }
 
-    Ok(SyncResult {
+    let result = SyncResult {
         updated,
         added,
         unchanged_count,
-        archived_count,
-    })
+        archived_count: archived.len(),
+        scanned_roots: roots
+            .iter()
+            .map(|r| r.to_string_lossy().to_string())
+            .collect(),
+        scan_duration_ms,
+        total_duration_ms: started.elapsed().as_millis() as u64,
+    };
+
+    // The sync itself already happened; a failed history write is only logged.
+    if let Err(e) = record_sync(conn, &result, &archived) {
+        log::warn!("Failed to record sync history: {}", e);
+    }
+
+    Ok(result)
+}
+
+/// Latest planning item `updated_at` per project id.
+fn last_planning_updates(
+    conn: &rusqlite::Connection,
+) -> Result<HashMap<String, String>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT project_id, MAX(updated_at) FROM planning_items
+         WHERE project_id IS NOT NULL GROUP BY project_id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+    })?;
+    Ok(rows
+        .filter_map(|r| r.ok())
+        .filter_map(|(id, at)| Some((id, at?)))
+        .collect())
+}
+
+/// Ids of `candidates` with no git commit, Claude session, planning change
+/// (`last_planning`, from `last_planning_updates`) or open in the last
+/// `days` days.  Projects created within the window are never inactive.
+/// `all_projects` is used to attribute sessions.  Reads no DB, so callers
+/// can run it unlocked.
+fn find_inactive_projects(
+    candidates: &[&Project],
+    all_projects: &[Project],
+    last_planning: &HashMap<String, String>,
+    days: i64,
+) -> Vec<String> {
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days);
+    let after_cutoff =
+        |ts: Option<&str>| ts.and_then(parse_timestamp).is_some_and(|t| t >= cutoff);
+
+    let with_sessions = projects_with_sessions_since(all_projects, cutoff.into());
+
+    candidates
+        .iter()
+        .filter(|p| !after_cutoff(Some(&p.created_at)))
+        .filter(|p| !after_cutoff(p.last_opened_at.as_deref()))
+        .filter(|p| !with_sessions.contains(&p.id))
+        .filter(|p| !after_cutoff(last_planning.get(&p.id).map(String::as_str)))
+        .filter(|p| last_commit_time(&p.path).is_none_or(|t| t < cutoff))
+        .map(|p| p.id.clone())
+        .collect()
+}
+
+/// Commit time of HEAD, or `None` outside a repo / on an unborn branch.
+fn last_commit_time(path: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let repo = git2::Repository::open(path).ok()?;
+    let commit = repo.head().ok()?.peel_to_commit().ok()?;
+    chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
 }
 
 #[tauri::command]
@@ -340,34 +688,165 @@ pub fn get_projects(state: State<AppState>) -> CmdResult<Vec<Project>> {
 
     let mut stmt = conn
         .prepare(
-            "SELECT id, name, path, tags, color, sort_order, is_archived, created_at, identity_key
-             FROM projects WHERE is_archived = 0 ORDER BY sort_order, name",
+            &format!(
+                "SELECT {PROJECT_COLUMNS} FROM projects WHERE is_archived = 0 ORDER BY sort_order, name"
+            ),
         )
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
 
     let projects = stmt
+        .query_map([], row_to_project)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(group_worktrees(projects))
+}
+
+/// `get_projects` plus per-project planning item counts by status and linked
+/// issue counts, computed in one query instead of one invoke per project.
+#[tauri::command]
+pub fn get_projects_with_stats(state: State<AppState>) -> CmdResult<Vec<ProjectWithStats>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {PROJECT_COLUMNS},
+                    COALESCE(s.backlog, 0), COALESCE(s.todo, 0), COALESCE(s.in_progress, 0),
+                    COALESCE(s.done, 0), COALESCE(s.linked, 0), COALESCE(s.open_linked, 0)
+             FROM projects
+             LEFT JOIN (
+                 SELECT project_id,
+                        SUM(status = 'backlog') AS backlog,
+                        SUM(status = 'todo') AS todo,
+                        SUM(status = 'in_progress') AS in_progress,
+                        SUM(status = 'done') AS done,
+                        SUM(github_issue_number IS NOT NULL) AS linked,
+                        SUM(github_issue_number IS NOT NULL AND status != 'done') AS open_linked
+                 FROM planning_items
+                 GROUP BY project_id
+             ) s ON s.project_id = projects.id
+             WHERE is_archived = 0
+             ORDER BY sort_order, name"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    // Stats follow the project columns read by `row_to_project`.
+    let offset = PROJECT_COLUMNS.split(',').count();
+    let count = |row: &rusqlite::Row, i: usize| row.get::<_, i64>(offset + i).map(|n| n as usize);
+    let rows: Vec<ProjectWithStats> = stmt
         .query_map([], |row| {
-            let tags_str: String = row.get(3)?;
-            let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
-            Ok(Project {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                path: row.get(2)?,
-                tags,
-                color: row.get(4)?,
-                sort_order: row.get(5)?,
-                is_archived: {
-                    let v: i64 = row.get(6)?;
-                    v != 0
-                },
-                created_at: row.get(7)?,
-                identity_key: row.get(8)?,
+            Ok(ProjectWithStats {
+                project: row_to_project(row)?,
+                backlog_count: count(row, 0)?,
+                todo_count: count(row, 1)?,
+                in_progress_count: count(row, 2)?,
+                done_count: count(row, 3)?,
+                linked_issue_count: count(row, 4)?,
+                open_linked_issue_count: count(row, 5)?,
             })
         })
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?
         .filter_map(|r| r.ok())
         .collect();
 
+    // Keep the worktree grouping of `get_projects`.
+    let order = group_worktrees(rows.iter().map(|r| r.project.clone()).collect());
+    let mut by_id: HashMap<String, ProjectWithStats> = rows
+        .into_iter()
+        .map(|r| (r.project.id.clone(), r))
+        .collect();
+    Ok(order
+        .into_iter()
+        .filter_map(|p| by_id.remove(&p.id))
+        .collect())
+}
+
+/// Persist a drag-and-drop order: each id's `sort_order` becomes its index.
+/// Runs in one transaction, so an unknown id leaves the order untouched.
+#[tauri::command]
+pub fn reorder_projects(
+    state: State<AppState>,
+    ordered_ids: Vec<String>,
+) -> CmdResult<Vec<Project>> {
+    {
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+        {
+            let mut stmt = tx
+                .prepare("UPDATE projects SET sort_order = ?1 WHERE id = ?2")
+                .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+            for (index, id) in ordered_ids.iter().enumerate() {
+                let changed = stmt
+                    .execute(rusqlite::params![index as i64, id])
+                    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+                if changed == 0 {
+                    return Err(to_cmd_err(CommanderError::internal(format!(
+                        "Project not found: {}",
+                        id
+                    ))));
+                }
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    }
+
+    get_projects(state)
+}
+
+/// Record that the project was just opened.
+#[tauri::command]
+pub fn touch_project(state: State<AppState>, project_id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    conn.execute(
+        "UPDATE projects SET last_opened_at = ?1 WHERE id = ?2",
+        rusqlite::params![chrono::Utc::now().to_rfc3339(), project_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    Ok(())
+}
+
+/// Most recently opened projects, newest first (default 5).
+#[tauri::command]
+pub fn get_recent_projects(state: State<AppState>, limit: Option<usize>) -> CmdResult<Vec<Project>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    load_recent_projects(conn, limit.unwrap_or(5)).map_err(to_cmd_err)
+}
+
+pub(crate) fn load_recent_projects(
+    conn: &rusqlite::Connection,
+    limit: usize,
+) -> Result<Vec<Project>, CommanderError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {PROJECT_COLUMNS} FROM projects
+         WHERE is_archived = 0 AND last_opened_at IS NOT NULL
+         ORDER BY last_opened_at DESC LIMIT ?1"
+    ))?;
+
+    let projects = stmt
+        .query_map([limit as i64], row_to_project)?
+        .filter_map(|r| r.ok())
+        .collect();
+
     Ok(projects)
 }
 
@@ -376,7 +855,7 @@ pub fn upsert_project(
     state: State<AppState>,
     project: CreateProjectInput,
 ) -> CmdResult<Project> {
-    validate_home_path(&project.path)?;
+    validate_allowed_path(&state, &project.path)?;
 
     let db = state.db.lock();
     let conn = db
@@ -418,15 +897,16 @@ pub fn upsert_project(
     .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
 
     conn.execute(
-        "INSERT INTO projects (id, name, path, tags, color, identity_key)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "INSERT INTO projects (id, name, path, tags, color, identity_key, icon)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
          ON CONFLICT(id) DO UPDATE SET
              name         = excluded.name,
              path         = excluded.path,
              tags         = excluded.tags,
              color        = excluded.color,
-             identity_key = COALESCE(excluded.identity_key, identity_key)",
-        rusqlite::params![id, project.name, project.path, tags_json, project.color, project.identity_key],
+             identity_key = COALESCE(excluded.identity_key, identity_key),
+             icon         = COALESCE(excluded.icon, icon)",
+        rusqlite::params![id, project.name, project.path, tags_json, project.color, project.identity_key, project.icon],
     )
     .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
 
@@ -440,9 +920,54 @@ pub fn upsert_project(
         is_archived: false,
         created_at: chrono::Utc::now().to_rfc3339(),
         identity_key: project.identity_key,
+        icon: project.icon,
+        group_id: None,
+        language: None,
+        framework: None,
+        package_manager: None,
+        last_opened_at: None,
+        real_path: None,
+        worktree_of: None,
+        archived_reason: None,
+        archived_at: None,
     })
 }
 
+/// Set or clear (`None`) a project's icon: an emoji or a framework key.
+#[tauri::command]
+pub fn set_project_icon(
+    state: State<AppState>,
+    project_id: String,
+    icon: Option<String>,
+) -> CmdResult<()> {
+    let icon = icon.map(|i| i.trim().to_string()).filter(|i| !i.is_empty());
+    if icon.as_ref().is_some_and(|i| i.chars().count() > 32) {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Project icon must be at most 32 characters",
+        )));
+    }
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let changed = conn
+        .execute(
+            "UPDATE projects SET icon = ?1 WHERE id = ?2",
+            rusqlite::params![icon, project_id],
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    if changed == 0 {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Project not found: {}",
+            project_id
+        ))));
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub fn delete_project(state: State<AppState>, project_id: String) -> CmdResult<()> {
     let db = state.db.lock();
@@ -456,36 +981,74 @@ pub fn delete_project(state: State<AppState>, project_id: String) -> CmdResult<(
     Ok(())
 }
 
+/// Reasons `archived_reason` can hold.
+const ARCHIVE_REASONS: &[&str] = &["missing_path", "out_of_scan_root", "inactive", "manual"];
+
+/// Archive a project by hand.  It stays archived across syncs until
+/// `restore_project`.
 #[tauri::command]
-pub fn get_archived_projects(state: State<AppState>) -> CmdResult<Vec<Project>> {
+pub fn archive_project(state: State<AppState>, project_id: String) -> CmdResult<()> {
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
 
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, name, path, tags, color, sort_order, is_archived, created_at, identity_key
-             FROM projects WHERE is_archived = 1 ORDER BY name",
+    let changed = conn
+        .execute(
+            "UPDATE projects SET is_archived = 1, archived_reason = 'manual', archived_at = ?1
+             WHERE id = ?2 AND is_archived = 0",
+            rusqlite::params![chrono::Utc::now().to_rfc3339(), project_id],
         )
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    if changed == 0 {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "No active project with id {project_id}"
+        ))));
+    }
+    Ok(())
+}
 
-    let projects = stmt
-        .query_map([], |row| {
-            let tags_str: String = row.get(3)?;
-            let tags: Vec<String> = serde_json::from_str(&tags_str).unwrap_or_default();
-            Ok(Project {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                path: row.get(2)?,
-                tags,
-                color: row.get(4)?,
-                sort_order: row.get(5)?,
-                is_archived: true,
-                created_at: row.get(7)?,
-                identity_key: row.get(8)?,
+/// Archived projects, most recently archived first.  `reason` narrows to one
+/// `archived_reason`; `archived_since` (RFC 3339) to projects archived at or
+/// after that time.
+#[tauri::command]
+pub fn get_archived_projects(
+    state: State<AppState>,
+    reason: Option<String>,
+    archived_since: Option<String>,
+) -> CmdResult<Vec<Project>> {
+    if let Some(reason) = &reason {
+        if !ARCHIVE_REASONS.contains(&reason.as_str()) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Unknown archive reason: {reason}"
+            ))));
+        }
+    }
+    let archived_since = archived_since
+        .map(|s| {
+            parse_timestamp(&s).map(|t| t.to_rfc3339()).ok_or_else(|| {
+                to_cmd_err(CommanderError::internal(format!("Invalid timestamp: {s}")))
             })
         })
+        .transpose()?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM projects
+             WHERE is_archived = 1
+               AND (?1 IS NULL OR archived_reason = ?1)
+               AND (?2 IS NULL OR archived_at >= ?2)
+             ORDER BY archived_at IS NULL, archived_at DESC, name"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    let projects = stmt
+        .query_map(rusqlite::params![reason, archived_since], row_to_project)
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?
         .filter_map(|r| r.ok())
         .collect();
@@ -501,7 +1064,8 @@ pub fn restore_project(state: State<AppState>, project_id: String) -> CmdResult<
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
 
     conn.execute(
-        "UPDATE projects SET is_archived = 0 WHERE id = ?1",
+        "UPDATE projects SET is_archived = 0, archived_reason = NULL, archived_at = NULL
+         WHERE id = ?1",
         [&project_id],
     )
     .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
@@ -509,28 +1073,223 @@ pub fn restore_project(state: State<AppState>, project_id: String) -> CmdResult<
     Ok(())
 }
 
+/// Delete archived projects, after snapshotting them for `restore_snapshot`.
 #[tauri::command]
-pub fn purge_archived_projects(state: State<AppState>) -> CmdResult<usize> {
+pub fn purge_archived_projects(state: State<AppState>) -> CmdResult<DestructiveOpResult> {
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let snapshot_id = create_snapshot(conn, "purge_archived_projects", PROJECT_TABLES)
+        .map_err(to_cmd_err)?;
     let count = conn
         .execute("DELETE FROM projects WHERE is_archived = 1", [])
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
-    Ok(count)
+    Ok(DestructiveOpResult { count, snapshot_id })
 }
 
+/// Delete every project record, after snapshotting them for `restore_snapshot`.
 #[tauri::command]
-pub fn reset_all_projects(state: State<AppState>) -> CmdResult<usize> {
+pub fn reset_all_projects(state: State<AppState>) -> CmdResult<DestructiveOpResult> {
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let snapshot_id = create_snapshot(conn, "reset_all_projects", PROJECT_TABLES)
+        .map_err(to_cmd_err)?;
     let count = conn
         .execute("DELETE FROM projects", [])
         .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
-    Ok(count)
+    Ok(DestructiveOpResult { count, snapshot_id })
+}
+
+/// Groups of project records (archived ones included) that share an
+/// identity key or resolve to the same directory, e.g. after folders were
+/// moved around or reached through a symlink.
+#[tauri::command]
+pub fn find_duplicate_projects(state: State<AppState>) -> CmdResult<Vec<DuplicateProjectGroup>> {
+    let mut projects: Vec<Project> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let mut stmt = conn
+            .prepare(&format!("SELECT {PROJECT_COLUMNS} FROM projects"))
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let rows = stmt
+            .query_map([], row_to_project)
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    // Live records first, then oldest, so each group leads with the one to keep.
+    projects.sort_by(|a, b| {
+        a.is_archived
+            .cmp(&b.is_archived)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+
+    let mut by_identity: BTreeMap<String, Vec<Project>> = BTreeMap::new();
+    let mut by_path: BTreeMap<String, Vec<Project>> = BTreeMap::new();
+    for proj in &projects {
+        if let Some(key) = &proj.identity_key {
+            by_identity.entry(key.clone()).or_default().push(proj.clone());
+        }
+        by_path
+            .entry(canonical_project_path(proj))
+            .or_default()
+            .push(proj.clone());
+    }
+
+    // A pair can match on both; report it once, under identity_key.
+    let mut seen: HashSet<Vec<String>> = HashSet::new();
+    let mut groups = Vec::new();
+    for (reason, matches) in [("identity_key", by_identity), ("path", by_path)] {
+        for (key, members) in matches {
+            if members.len() < 2 {
+                continue;
+            }
+            let mut ids: Vec<String> = members.iter().map(|p| p.id.clone()).collect();
+            ids.sort();
+            if !seen.insert(ids) {
+                continue;
+            }
+            groups.push(DuplicateProjectGroup {
+                reason: reason.to_string(),
+                key,
+                suggested_keep_id: members[0].id.clone(),
+                projects: members,
+            });
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Fold `remove_ids` into `keep_id`.  Planning items, session links, cached
+/// env vars, run artifacts, session stacks and worktree links move to the kept
+/// record; tags are unioned and metadata it lacks (identity key, color, icon,
+/// group) is taken from the duplicates, which are then deleted.  Snapshots
+/// first, so the merge can be undone with `restore_snapshot`.
+#[tauri::command]
+pub fn merge_projects(
+    state: State<AppState>,
+    keep_id: String,
+    remove_ids: Vec<String>,
+) -> CmdResult<DestructiveOpResult> {
+    let mut remove_ids: Vec<String> = remove_ids.into_iter().filter(|id| *id != keep_id).collect();
+    remove_ids.sort();
+    remove_ids.dedup();
+    if remove_ids.is_empty() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "No duplicate projects to merge",
+        )));
+    }
+
+    let mut db = state.db.lock();
+    let conn = db
+        .as_mut()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let keep = load_project(conn, &keep_id)?;
+    let removed = remove_ids
+        .iter()
+        .map(|id| load_project(conn, id))
+        .collect::<CmdResult<Vec<_>>>()?;
+
+    let snapshot_id = create_snapshot(conn, "merge_projects", PROJECT_TABLES)
+        .map_err(to_cmd_err)?;
+    merge_project_records(conn, keep, &removed).map_err(to_cmd_err)?;
+
+    Ok(DestructiveOpResult {
+        count: removed.len(),
+        snapshot_id,
+    })
+}
+
+fn load_project(conn: &rusqlite::Connection, id: &str) -> CmdResult<Project> {
+    conn.query_row(
+        &format!("SELECT {PROJECT_COLUMNS} FROM projects WHERE id = ?1"),
+        [id],
+        row_to_project,
+    )
+    .map_err(|_| to_cmd_err(CommanderError::internal(format!("Project not found: {}", id))))
+}
+
+/// `real_path` when the record has one, otherwise the path resolved through
+/// symlinks; directories that are gone fall back to the stored path.
+fn canonical_project_path(project: &Project) -> String {
+    let path = project.real_path.as_deref().unwrap_or(&project.path);
+    std::fs::canonicalize(path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.trim_end_matches('/').to_string())
+}
+
+fn merge_project_records(
+    conn: &mut rusqlite::Connection,
+    keep: Project,
+    removed: &[Project],
+) -> Result<(), CommanderError> {
+    let mut merged = keep;
+    for dup in removed {
+        for tag in &dup.tags {
+            if !merged.tags.contains(tag) {
+                merged.tags.push(tag.clone());
+            }
+        }
+        merged.identity_key = merged.identity_key.or_else(|| dup.identity_key.clone());
+        merged.color = merged.color.or_else(|| dup.color.clone());
+        merged.icon = merged.icon.or_else(|| dup.icon.clone());
+        merged.group_id = merged.group_id.or_else(|| dup.group_id.clone());
+        merged.last_opened_at = merged.last_opened_at.max(dup.last_opened_at.clone());
+        // A live duplicate means the project is still in use.
+        if !dup.is_archived {
+            merged.is_archived = false;
+            merged.archived_reason = None;
+            merged.archived_at = None;
+        }
+    }
+
+    let tx = conn.transaction()?;
+    for dup in removed {
+        // Rows the kept project already has a copy of (same session link,
+        // same imported issue) are left behind and cascade away with the
+        // duplicate.
+        for sql in [
+            "UPDATE OR IGNORE planning_items SET project_id = ?1 WHERE project_id = ?2",
+            "UPDATE OR IGNORE session_project_links SET project_id = ?1 WHERE project_id = ?2",
+            "UPDATE OR IGNORE env_var_cache SET project_id = ?1 WHERE project_id = ?2",
+            "UPDATE run_artifacts SET project_id = ?1 WHERE project_id = ?2",
+            "UPDATE session_stacks SET project_id = ?1 WHERE project_id = ?2",
+            "UPDATE projects SET worktree_of = ?1 WHERE worktree_of = ?2",
+        ] {
+            tx.execute(sql, rusqlite::params![merged.id, dup.id])?;
+        }
+        tx.execute("DELETE FROM projects WHERE id = ?1", [&dup.id])?;
+    }
+
+    // Written after the deletes: identity_key is unique.
+    tx.execute(
+        "UPDATE projects SET tags = ?1, identity_key = ?2, color = ?3, icon = ?4, group_id = ?5,
+             last_opened_at = ?6, is_archived = ?7, archived_reason = ?8, archived_at = ?9
+         WHERE id = ?10",
+        rusqlite::params![
+            serde_json::to_string(&merged.tags)?,
+            merged.identity_key,
+            merged.color,
+            merged.icon,
+            merged.group_id,
+            merged.last_opened_at,
+            merged.is_archived,
+            merged.archived_reason,
+            merged.archived_at,
+            merged.id,
+        ],
+    )?;
+    tx.commit()?;
+    Ok(())
 }
 
 #[tauri::command]
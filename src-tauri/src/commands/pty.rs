@@ -1,8 +1,18 @@
+use crate::commands::env::read_env_vars;
+use crate::commands::projects::touch_project_by_path;
+use crate::commands::settings::{get_json_setting, get_setting, set_json_setting};
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::pty_state::{PtySession, PtyState};
+use crate::models::{PtyInfo, PtyLimits, PtyResourceUsage, PtySessionUsage};
+use crate::pty_state::{PtyEnvManifest, PtySession, PtyState, PtyStats};
+use crate::state::AppState;
+use crate::utils::validate_allowed_path;
 use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
-use tauri::Emitter;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
 
 #[derive(Clone, serde::Serialize)]
 pub struct PtyOutputPayload {
@@ -13,17 +23,143 @@ pub struct PtyOutputPayload {
 #[derive(Clone, serde::Serialize)]
 pub struct PtyExitPayload {
     pub pty_id: String,
+    /// `None` for a normal exit; `"output_limit"` when the session was killed
+    /// for exceeding `pty_max_output_mb`.
+    pub reason: Option<String>,
+}
+
+/// Payload of `pty-activity`, emitted at most every `ACTIVITY_EMIT_INTERVAL`
+/// per session while it produces output.
+#[derive(Clone, serde::Serialize)]
+pub struct PtyActivityPayload {
+    pub pty_id: String,
+    pub last_activity: String,
 }
 
 const MAX_ROWS: u16 = 500;
 const MAX_COLS: u16 = 500;
 
+const ACTIVITY_EMIT_INTERVAL: Duration = Duration::from_secs(2);
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+fn ms_to_rfc3339(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .to_rfc3339()
+}
+
+fn pty_info(pty_id: &str, session: &PtySession) -> PtyInfo {
+    PtyInfo {
+        pty_id: pty_id.to_string(),
+        project_path: session.stats.project_path.clone(),
+        program: session.stats.program.clone(),
+        created_at: session.stats.created_at.clone(),
+        last_activity: ms_to_rfc3339(session.stats.last_activity_ms.load(Ordering::Relaxed)),
+        env_file: session.env_manifest.as_ref().map(|m| m.env_file.clone()),
+    }
+}
+
+/// Setting holding the per-project env file selection (project path → env file path).
+const PTY_ENV_FILES_SETTING: &str = "pty_env_files";
+
+/// Check that `env_file` is a `.env` / `.env.*` file directly inside `project_dir`.
+fn validate_env_file(
+    state: &AppState,
+    project_dir: &Path,
+    env_file: &str,
+) -> CmdResult<PathBuf> {
+    let path = validate_allowed_path(state, env_file)?;
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let in_project = path.parent() == Some(project_dir);
+    if !in_project || !(name == ".env" || name.starts_with(".env.")) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "{env_file} is not an env file in {}",
+            project_dir.display()
+        ))));
+    }
+    Ok(path)
+}
+
+/// The env file selected for `project_path`, or `None` to launch without one.
+#[tauri::command]
+pub fn get_pty_env_file(
+    state: tauri::State<'_, AppState>,
+    project_path: String,
+) -> CmdResult<Option<String>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let mut selections: BTreeMap<String, String> =
+        get_json_setting(conn, PTY_ENV_FILES_SETTING).unwrap_or_default();
+    Ok(selections.remove(&project_path))
+}
+
+/// Select the env file whose variables are injected into terminals launched
+/// for `project_path`.  `None` clears the selection.
+#[tauri::command]
+pub fn set_pty_env_file(
+    state: tauri::State<'_, AppState>,
+    project_path: String,
+    env_file: Option<String>,
+) -> CmdResult<()> {
+    let project_dir = validate_allowed_path(&state, &project_path)?;
+    let env_file = env_file
+        .map(|f| validate_env_file(&state, &project_dir, &f))
+        .transpose()?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let mut selections: BTreeMap<String, String> =
+        get_json_setting(conn, PTY_ENV_FILES_SETTING).unwrap_or_default();
+    match env_file {
+        Some(path) => selections.insert(project_path, path.to_string_lossy().to_string()),
+        None => selections.remove(&project_path),
+    };
+    set_json_setting(conn, PTY_ENV_FILES_SETTING, &selections)
+}
+
+/// Read the PTY limits from settings, falling back to the `AppSettings` defaults.
+fn load_pty_limits(state: &AppState) -> PtyLimits {
+    let defaults = crate::models::AppSettings::default();
+    let db = state.db.lock();
+    let read = |key: &str, fallback: u64| -> u64 {
+        db.as_ref()
+            .and_then(|conn| get_setting(conn, key).flatten())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(fallback)
+    };
+    PtyLimits {
+        max_sessions: read("pty_max_sessions", defaults.pty_max_sessions as u64) as usize,
+        max_output_kb_per_sec: read(
+            "pty_max_output_kb_per_sec",
+            defaults.pty_max_output_kb_per_sec,
+        ),
+        max_output_mb: read("pty_max_output_mb", defaults.pty_max_output_mb),
+    }
+}
+
+/// Launch `claude` (or the login shell) in a PTY rooted at `project_path`.
+///
+/// Variables from `env_file` — or, when omitted, the project's selection from
+/// `set_pty_env_file` — are added to the child environment.  Pass an empty
+/// string to launch without an env file regardless of the selection.
 #[tauri::command]
 pub fn pty_create(
     project_path: String,
     cols: u16,
     rows: u16,
+    env_file: Option<String>,
     app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
     pty_state: tauri::State<'_, PtyState>,
 ) -> CmdResult<String> {
     use portable_pty::{native_pty_system, CommandBuilder, PtySize};
@@ -36,6 +172,44 @@ pub fn pty_create(
         ))));
     }
 
+    let limits = load_pty_limits(&state);
+    let Some(reservation) = pty_state.reserve(limits.max_sessions) else {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "PTY session limit reached (max {})",
+            limits.max_sessions
+        ))));
+    };
+
+    // Recency tracking is best-effort; never block the terminal on it.
+    if let Some(conn) = state.db.lock().as_ref() {
+        let _ = touch_project_by_path(conn, &project_path);
+    }
+
+    let env_file = match env_file {
+        Some(f) => Some(f).filter(|f| !f.is_empty()),
+        None => get_pty_env_file(state.clone(), project_path.clone())?,
+    };
+    let env_manifest = env_file
+        .map(|f| -> CmdResult<(PtyEnvManifest, Vec<(String, String)>)> {
+            let project_dir = validate_allowed_path(&state, &project_path)?;
+            let path = validate_env_file(&state, &project_dir, &f)?;
+            if !path.is_file() {
+                return Err(to_cmd_err(CommanderError::internal(format!(
+                    "Env file not found: {f}"
+                ))));
+            }
+            let vars: Vec<(String, String)> = read_env_vars(&path)?
+                .into_iter()
+                .map(|v| (v.key, v.value))
+                .collect();
+            let manifest = PtyEnvManifest {
+                env_file: path.to_string_lossy().to_string(),
+                keys: vars.iter().map(|(k, _)| k.clone()).collect(),
+            };
+            Ok((manifest, vars))
+        })
+        .transpose()?;
+
     // Resolve binary: look for claude, fall back to $SHELL, then /bin/zsh
     let program = which::which("claude")
         .map(|p| p.to_string_lossy().into_owned())
@@ -71,6 +245,18 @@ pub fn pty_create(
         format!("{base_path}:/opt/homebrew/bin:/usr/local/bin:/usr/bin:/bin"),
     );
 
+    let env_manifest = env_manifest.map(|(manifest, vars)| {
+        for (key, value) in vars {
+            cmd.env(key, value);
+        }
+        log::info!(
+            "PTY for {project_path}: injected {} variable(s) from {}",
+            manifest.keys.len(),
+            manifest.env_file
+        );
+        manifest
+    });
+
     let _child = pair
         .slave
         .spawn_command(cmd)
@@ -89,40 +275,119 @@ pub fn pty_create(
     let pty_id = uuid::Uuid::new_v4().to_string();
     let pty_id_clone = pty_id.clone();
 
-    // Reader thread — emits pty-output events; exits on EOF/error
+    let stats = Arc::new(PtyStats {
+        created_at: chrono::Utc::now().to_rfc3339(),
+        project_path: project_path.clone(),
+        program: program.clone(),
+        last_activity_ms: AtomicI64::new(now_ms()),
+        bytes_emitted: AtomicU64::new(0),
+        throttle_events: AtomicU64::new(0),
+    });
+    let reader_stats = stats.clone();
+
+    // Registered before the reader starts, so a child that exits at once is
+    // removed by the reader rather than inserted after it is gone, and
+    // `pty-created` always precedes `pty-exit`.
+    let master = Arc::new(Mutex::new(pair.master));
+    let session = PtySession {
+        writer,
+        master,
+        stats,
+        env_manifest,
+    };
+    let info = pty_info(&pty_id, &session);
+    reservation.insert(pty_id.clone(), session);
+    let _ = app_handle.emit("pty-created", info);
+
+    // Reader thread — emits pty-output events; exits on EOF/error.
+    // Output-rate limiting applies backpressure: the thread stops reading for
+    // the rest of the one-second window, so the child blocks on a full PTY
+    // buffer instead of the app buffering unbounded output.
     std::thread::spawn(move || {
+        let rate_limit = limits.max_output_kb_per_sec * 1024;
+        let total_limit = limits.max_output_mb * 1024 * 1024;
+        let mut window_start = Instant::now();
+        let mut window_bytes: u64 = 0;
+        let mut last_activity_emit: Option<Instant> = None;
         let mut buf = [0u8; 4096];
         loop {
-            match reader.read(&mut buf) {
+            let n = match reader.read(&mut buf) {
                 Ok(0) | Err(_) => {
+                    // Drop the finished session so it no longer counts toward the limit.
+                    app_handle
+                        .state::<PtyState>()
+                        .sessions
+                        .lock()
+                        .remove(&pty_id_clone);
                     let _ = app_handle.emit(
                         "pty-exit",
                         PtyExitPayload {
                             pty_id: pty_id_clone.clone(),
+                            reason: None,
                         },
                     );
                     break;
                 }
-                Ok(n) => {
-                    let _ = app_handle.emit(
-                        "pty-output",
-                        PtyOutputPayload {
-                            pty_id: pty_id_clone.clone(),
-                            data: buf[..n].to_vec(),
-                        },
-                    );
+                Ok(n) => n,
+            };
+
+            let total = reader_stats
+                .bytes_emitted
+                .fetch_add(n as u64, Ordering::Relaxed)
+                + n as u64;
+            if total_limit > 0 && total > total_limit {
+                // Dropping the session closes the master fd → child receives SIGHUP.
+                app_handle
+                    .state::<PtyState>()
+                    .sessions
+                    .lock()
+                    .remove(&pty_id_clone);
+                let _ = app_handle.emit(
+                    "pty-exit",
+                    PtyExitPayload {
+                        pty_id: pty_id_clone.clone(),
+                        reason: Some("output_limit".to_string()),
+                    },
+                );
+                break;
+            }
+
+            let _ = app_handle.emit(
+                "pty-output",
+                PtyOutputPayload {
+                    pty_id: pty_id_clone.clone(),
+                    data: buf[..n].to_vec(),
+                },
+            );
+
+            let now = now_ms();
+            reader_stats.last_activity_ms.store(now, Ordering::Relaxed);
+            if last_activity_emit.is_none_or(|t| t.elapsed() >= ACTIVITY_EMIT_INTERVAL) {
+                last_activity_emit = Some(Instant::now());
+                let _ = app_handle.emit(
+                    "pty-activity",
+                    PtyActivityPayload {
+                        pty_id: pty_id_clone.clone(),
+                        last_activity: ms_to_rfc3339(now),
+                    },
+                );
+            }
+
+            if rate_limit > 0 {
+                window_bytes += n as u64;
+                let elapsed = window_start.elapsed();
+                if window_bytes >= rate_limit && elapsed < Duration::from_secs(1) {
+                    reader_stats.throttle_events.fetch_add(1, Ordering::Relaxed);
+                    std::thread::sleep(Duration::from_secs(1) - elapsed);
+                }
+                if window_bytes >= rate_limit || elapsed >= Duration::from_secs(1) {
+                    window_start = Instant::now();
+                    window_bytes = 0;
                 }
             }
         }
     });
 
-    let master = Arc::new(Mutex::new(pair.master));
-
-    pty_state
-        .sessions
-        .lock()
-        .insert(pty_id.clone(), PtySession { writer, master });
-
     Ok(pty_id)
 }
 
@@ -137,6 +402,7 @@ pub fn pty_write(
     let s = sessions
         .get_mut(&pty_id)
         .ok_or_else(|| to_cmd_err(CommanderError::internal("no pty")))?;
+    s.stats.last_activity_ms.store(now_ms(), Ordering::Relaxed);
     s.writer
         .write_all(&data)
         .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
@@ -176,3 +442,45 @@ pub fn pty_kill(pty_id: String, pty_state: tauri::State<'_, PtyState>) -> CmdRes
     pty_state.sessions.lock().remove(&pty_id);
     Ok(())
 }
+
+/// Every live terminal, oldest first.  Together with the `pty-created`,
+/// `pty-activity` and `pty-exit` events this is the one inventory all views
+/// should read instead of tracking PTYs themselves.
+#[tauri::command]
+pub fn pty_list(pty_state: tauri::State<'_, PtyState>) -> Vec<PtyInfo> {
+    let mut list: Vec<PtyInfo> = pty_state
+        .sessions
+        .lock()
+        .iter()
+        .map(|(id, s)| pty_info(id, s))
+        .collect();
+    list.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    list
+}
+
+/// Report per-session output counters alongside the configured limits.
+#[tauri::command]
+pub fn get_pty_resource_usage(
+    state: tauri::State<'_, AppState>,
+    pty_state: tauri::State<'_, PtyState>,
+) -> CmdResult<PtyResourceUsage> {
+    let limits = load_pty_limits(&state);
+    let sessions = pty_state
+        .sessions
+        .lock()
+        .iter()
+        .map(|(id, s)| PtySessionUsage {
+            pty_id: id.clone(),
+            created_at: s.stats.created_at.clone(),
+            bytes_emitted: s.stats.bytes_emitted.load(Ordering::Relaxed),
+            throttle_events: s.stats.throttle_events.load(Ordering::Relaxed),
+            env_file: s.env_manifest.as_ref().map(|m| m.env_file.clone()),
+            env_keys: s
+                .env_manifest
+                .as_ref()
+                .map(|m| m.keys.clone())
+                .unwrap_or_default(),
+        })
+        .collect();
+    Ok(PtyResourceUsage { sessions, limits })
+}
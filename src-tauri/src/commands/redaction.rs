@@ -0,0 +1,79 @@
+use crate::commands::claude::{find_session_file, load_session_detail, read_first_line_cwd};
+use crate::commands::env::{find_env_files, read_env_vars};
+use crate::error::{to_cmd_err, CmdResult};
+use crate::models::{RedactedSession, Redaction, RedactionRules};
+use commander_core::redaction::Redactor;
+use std::path::Path;
+
+/// `.env` values shorter than this (e.g. `true`, `3000`) are too common to
+/// redact by literal match without mangling unrelated text.
+const MIN_ENV_VALUE_LEN: usize = 8;
+
+/// Return the full transcript of `session_id` with sensitive values replaced,
+/// plus the list of redactions made so the caller can show a preview before
+/// exporting or sharing.
+#[tauri::command]
+pub fn redact_session(
+    session_id: String,
+    rules: Option<RedactionRules>,
+) -> CmdResult<RedactedSession> {
+    let rules = rules.unwrap_or_default();
+    let path = find_session_file(&session_id)?;
+    let detail = load_session_detail(&path, usize::MAX)?;
+    let redactor = session_redactor(&path, &rules)?;
+
+    let mut redactions = Vec::new();
+    let turns = detail
+        .turns
+        .into_iter()
+        .map(|turn| redactor.redact_turn(turn, &mut redactions))
+        .collect();
+
+    Ok(RedactedSession {
+        session_id,
+        turns,
+        redactions,
+        total_count: detail.total_count,
+    })
+}
+
+/// Apply `rules` to text derived from the session transcript at `path`, such
+/// as a summary about to be posted.  Returns the redacted text and what was
+/// replaced; the redactions carry an empty `turn_uuid`.
+pub fn redact_session_text(
+    path: &Path,
+    rules: &RedactionRules,
+    text: &str,
+) -> CmdResult<(String, Vec<Redaction>)> {
+    let redactor = session_redactor(path, rules)?;
+    let mut redactions = Vec::new();
+    let text = redactor.redact_text(text, "", &mut redactions);
+    Ok((text, redactions))
+}
+
+/// The redactor for the session transcript at `path`, which knows the values
+/// in its project's `.env*` files when `rules.env_values` is on.
+pub fn session_redactor(path: &Path, rules: &RedactionRules) -> CmdResult<Redactor> {
+    let env_values = if rules.env_values {
+        read_first_line_cwd(path)
+            .map(|cwd| collect_env_values(&cwd))
+            .unwrap_or_default()
+    } else {
+        vec![]
+    };
+    Redactor::new(rules, env_values).map_err(to_cmd_err)
+}
+
+/// `(key, value)` pairs from every `.env*` file in the session's working
+/// directory, longest value first so overlapping values redact cleanly.
+fn collect_env_values(cwd: &str) -> Vec<(String, String)> {
+    let mut values: Vec<(String, String)> = find_env_files(Path::new(cwd))
+        .into_iter()
+        .flat_map(|f| read_env_vars(Path::new(&f.path)).unwrap_or_default())
+        .filter(|v| v.value.chars().count() >= MIN_ENV_VALUE_LEN)
+        .map(|v| (v.key, v.value))
+        .collect();
+    values.sort_by_key(|(_, v)| std::cmp::Reverse(v.len()));
+    values.dedup_by(|a, b| a.1 == b.1);
+    values
+}
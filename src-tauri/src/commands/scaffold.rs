@@ -0,0 +1,199 @@
+use crate::commands::projects::upsert_project;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{CreateProjectInput, Project, ProjectTemplate};
+use crate::state::AppState;
+use crate::utils::validate_allowed_path;
+use tauri::State;
+
+/// A starter layout.  `{name}` in paths and contents is replaced with the
+/// package-safe project name, `{module}` with its underscored form.
+struct Template {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    files: &'static [(&'static str, &'static str)],
+    /// `## Commands` lines for the starter CLAUDE.md.
+    commands: &'static str,
+}
+
+const TEMPLATES: &[Template] = &[
+    Template {
+        id: "empty",
+        name: "Empty",
+        description: "README and .gitignore only",
+        files: &[("README.md", "# {name}\n"), (".gitignore", ".DS_Store\n")],
+        commands: "",
+    },
+    Template {
+        id: "node",
+        name: "Node.js",
+        description: "package.json with an ES module entry point",
+        files: &[
+            (
+                "package.json",
+                "{\n  \"name\": \"{name}\",\n  \"version\": \"0.1.0\",\n  \"type\": \"module\",\n  \"scripts\": {\n    \"start\": \"node index.js\",\n    \"test\": \"node --test\"\n  }\n}\n",
+            ),
+            ("index.js", "console.log(\"Hello from {name}\");\n"),
+            ("README.md", "# {name}\n"),
+            (".gitignore", "node_modules/\n.DS_Store\n"),
+        ],
+        commands: "- Run: `npm start`\n- Test: `npm test`\n",
+    },
+    Template {
+        id: "rust",
+        name: "Rust",
+        description: "Cargo binary crate",
+        files: &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n",
+            ),
+            ("src/main.rs", "fn main() {\n    println!(\"Hello from {name}\");\n}\n"),
+            ("README.md", "# {name}\n"),
+            (".gitignore", "/target\n.DS_Store\n"),
+        ],
+        commands: "- Build: `cargo build`\n- Test: `cargo test`\n- Lint: `cargo clippy -- -D warnings`\n",
+    },
+    Template {
+        id: "python",
+        name: "Python",
+        description: "pyproject.toml with a package and tests folder",
+        files: &[
+            (
+                "pyproject.toml",
+                "[project]\nname = \"{name}\"\nversion = \"0.1.0\"\nrequires-python = \">=3.10\"\n",
+            ),
+            ("src/{module}/__init__.py", ""),
+            ("tests/__init__.py", ""),
+            ("README.md", "# {name}\n"),
+            (".gitignore", "__pycache__/\n.venv/\n.DS_Store\n"),
+        ],
+        commands: "- Install: `uv sync`\n- Test: `uv run pytest`\n",
+    },
+];
+
+/// Templates offered by `create_project_from_template`.
+#[tauri::command]
+pub fn get_project_templates() -> Vec<ProjectTemplate> {
+    TEMPLATES
+        .iter()
+        .map(|t| ProjectTemplate {
+            id: t.id.to_string(),
+            name: t.name.to_string(),
+            description: t.description.to_string(),
+        })
+        .collect()
+}
+
+/// Create `path`, lay out `template`, `git init` it, optionally add a
+/// starter `CLAUDE.md` (default on) and `.env` (default off), and register
+/// the project.  `path` must not exist yet or be an empty directory; its
+/// parent must exist.
+#[tauri::command]
+pub fn create_project_from_template(
+    state: State<AppState>,
+    name: String,
+    path: String,
+    template: String,
+    claude_md: Option<bool>,
+    env_file: Option<bool>,
+) -> CmdResult<Project> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Project name is required",
+        )));
+    }
+    let template = TEMPLATES.iter().find(|t| t.id == template).ok_or_else(|| {
+        to_cmd_err(CommanderError::internal(format!(
+            "Unknown template: {template}"
+        )))
+    })?;
+
+    let dir = validate_allowed_path(&state, &path)?;
+    let occupied = std::fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_some());
+    if occupied || dir.is_file() {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "{} already exists and is not empty",
+            dir.display()
+        ))));
+    }
+
+    let package = package_name(&name);
+    let module = package.replace('-', "_");
+    let fill = |s: &str| s.replace("{name}", &package).replace("{module}", &module);
+
+    let mut files: Vec<(String, String)> = template
+        .files
+        .iter()
+        .map(|(p, c)| (fill(p), fill(c)))
+        .collect();
+    if claude_md.unwrap_or(true) {
+        let mut content =
+            format!("# {name}\n\nInstructions for Claude when working in this repository.\n");
+        if !template.commands.is_empty() {
+            content.push_str("\n## Commands\n\n");
+            content.push_str(template.commands);
+        }
+        files.push(("CLAUDE.md".to_string(), content));
+    }
+    if env_file.unwrap_or(false) {
+        files.push((
+            ".env".to_string(),
+            "# Local secrets and configuration. Not committed.\n".to_string(),
+        ));
+        if let Some((_, gitignore)) = files.iter_mut().find(|(p, _)| p == ".gitignore") {
+            gitignore.push_str(".env\n");
+        }
+    }
+
+    let write_all = || -> std::io::Result<()> {
+        for (relative, content) in &files {
+            let target = dir.join(relative);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(target, content)?;
+        }
+        Ok(())
+    };
+    std::fs::create_dir_all(&dir).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    write_all().map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    git2::Repository::init(&dir).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+
+    upsert_project(
+        state,
+        CreateProjectInput {
+            name,
+            path: dir.to_string_lossy().to_string(),
+            tags: None,
+            color: None,
+            identity_key: None,
+            icon: None,
+        },
+    )
+}
+
+/// Lowercase, dash-separated name usable as an npm / cargo / PyPI package.
+fn package_name(name: &str) -> String {
+    let dashed: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let collapsed = dashed
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if collapsed.is_empty() {
+        "project".to_string()
+    } else {
+        collapsed
+    }
+}
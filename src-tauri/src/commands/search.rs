@@ -1,3 +1,4 @@
+use crate::commands::claude::agent_state_roots;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
 use crate::models::{
     SearchPlanResult, SearchPlanningItemResult, SearchProjectResult, SearchResults, SearchTaskResult,
@@ -5,12 +6,6 @@ use crate::models::{
 use crate::state::AppState;
 use tauri::State;
 
-fn claude_dir() -> std::path::PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
-        .join(".claude")
-}
-
 #[tauri::command]
 pub fn global_search(state: State<AppState>, query: String) -> CmdResult<SearchResults> {
     let q = query.trim().to_lowercase();
@@ -106,18 +101,24 @@ pub fn global_search(state: State<AppState>, query: String) -> CmdResult<SearchR
 }
 
 fn search_plans(q: &str) -> Vec<SearchPlanResult> {
-    let plans_dir = claude_dir().join("plans");
-    if !plans_dir.exists() {
-        return vec![];
+    let mut results = Vec::new();
+    for (source, root) in agent_state_roots() {
+        search_plans_in(&root.join("plans"), &source, q, &mut results);
     }
+    results
+}
 
-    let entries = match std::fs::read_dir(&plans_dir) {
+fn search_plans_in(
+    plans_dir: &std::path::Path,
+    source: &str,
+    q: &str,
+    results: &mut Vec<SearchPlanResult>,
+) {
+    let entries = match std::fs::read_dir(plans_dir) {
         Ok(e) => e,
-        Err(_) => return vec![],
+        Err(_) => return,
     };
 
-    let mut results = Vec::new();
-
     for entry in entries.filter_map(|e| e.ok()) {
         if results.len() >= 5 {
             break;
@@ -177,25 +178,30 @@ fn search_plans(q: &str) -> Vec<SearchPlanResult> {
             title,
             preview,
             modified_at,
+            source: source.to_string(),
         });
     }
-
-    results
 }
 
 fn search_tasks(q: &str) -> Vec<SearchTaskResult> {
-    let tasks_dir = claude_dir().join("tasks");
-    if !tasks_dir.exists() {
-        return vec![];
+    let mut results = Vec::new();
+    for (source, root) in agent_state_roots() {
+        search_tasks_in(&root.join("tasks"), &source, q, &mut results);
     }
+    results
+}
 
-    let entries = match std::fs::read_dir(&tasks_dir) {
+fn search_tasks_in(
+    tasks_dir: &std::path::Path,
+    source: &str,
+    q: &str,
+    results: &mut Vec<SearchTaskResult>,
+) {
+    let entries = match std::fs::read_dir(tasks_dir) {
         Ok(e) => e,
-        Err(_) => return vec![],
+        Err(_) => return,
     };
 
-    let mut results = Vec::new();
-
     'outer: for entry in entries.filter_map(|e| e.ok()) {
         let team_dir = entry.path();
         if !team_dir.is_dir() {
@@ -275,9 +281,8 @@ fn search_tasks(q: &str) -> Vec<SearchTaskResult> {
                 subject,
                 description,
                 status,
+                source: source.to_string(),
             });
         }
     }
-
-    results
 }
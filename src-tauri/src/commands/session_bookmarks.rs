@@ -0,0 +1,110 @@
+use crate::commands::claude::{claude_root, read_first_line_cwd};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::SessionBookmark;
+use crate::state::AppState;
+use commander_core::session::DEFAULT_CLAUDE_SOURCE;
+use tauri::State;
+
+/// Bookmark a session, or replace the note of an existing bookmark.
+#[tauri::command]
+pub fn bookmark_session(
+    state: State<AppState>,
+    session_id: String,
+    project_key: String,
+    note: Option<String>,
+    source: Option<String>,
+) -> CmdResult<()> {
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    let source = source.unwrap_or_else(|| DEFAULT_CLAUDE_SOURCE.to_string());
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "INSERT INTO session_bookmarks (session_id, project_key, source, note, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(session_id) DO UPDATE SET
+           project_key = excluded.project_key,
+           source = excluded.source,
+           note = excluded.note",
+        rusqlite::params![
+            session_id,
+            project_key,
+            source,
+            note,
+            chrono::Utc::now().to_rfc3339()
+        ],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unbookmark_session(state: State<AppState>, session_id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "DELETE FROM session_bookmarks WHERE session_id = ?1",
+        [&session_id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
+
+/// Every bookmark, newest first.  Bookmarks outlive their transcripts; a
+/// deleted one is returned with `missing` set so it can be cleaned up.
+#[tauri::command]
+pub fn list_bookmarked_sessions(state: State<AppState>) -> CmdResult<Vec<SessionBookmark>> {
+    let rows: Vec<(String, String, String, Option<String>, String)> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT session_id, project_key, source, note, created_at
+                 FROM session_bookmarks ORDER BY created_at DESC",
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    Ok(rows
+        .into_iter()
+        .map(|(session_id, project_key, source, note, created_at)| {
+            let path = claude_root(Some(&source))
+                .join("projects")
+                .join(&project_key)
+                .join(format!("{session_id}.jsonl"));
+            let last_message_at = std::fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339());
+            SessionBookmark {
+                cwd: read_first_line_cwd(&path),
+                missing: last_message_at.is_none(),
+                last_message_at,
+                session_id,
+                project_key,
+                source,
+                note,
+                created_at,
+            }
+        })
+        .collect())
+}
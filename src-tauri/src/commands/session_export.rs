@@ -0,0 +1,226 @@
+use crate::commands::claude::{claude_root, load_session_detail, read_first_line_cwd};
+use crate::commands::redaction::session_redactor;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{RedactionRules, SessionToolCall, SessionTurn};
+use crate::utils::write_file_atomic;
+use std::path::PathBuf;
+
+const EXPORT_FORMATS: &[&str] = &["markdown", "html"];
+
+/// Render a session transcript to `~/.claude-commander/exports` as Markdown
+/// or a standalone HTML page and return the file path.  Tool calls are
+/// folded into collapsed `<details>` blocks with their input (and output,
+/// when known) as code.  The transcript goes through `rules` first, as with
+/// `redact_session`; every rule is on unless the caller turns it off.
+#[tauri::command]
+pub fn export_session(
+    project_key: String,
+    session_id: String,
+    format: String,
+    source: Option<String>,
+    rules: Option<RedactionRules>,
+) -> CmdResult<String> {
+    if !EXPORT_FORMATS.contains(&format.as_str()) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid export format: {format}"
+        ))));
+    }
+    // The id names the output file, so it must not carry a path.
+    if session_id.is_empty()
+        || !session_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid session id: {session_id}"
+        ))));
+    }
+    // A project key is one directory name below `projects/`.
+    if project_key.is_empty() || project_key.contains(['/', '\\']) || project_key.contains("..") {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid project key: {project_key}"
+        ))));
+    }
+    let path = claude_root(source.as_deref())
+        .join("projects")
+        .join(&project_key)
+        .join(format!("{session_id}.jsonl"));
+    let detail = load_session_detail(&path, usize::MAX)?;
+    let cwd = read_first_line_cwd(&path);
+
+    let redactor = session_redactor(&path, &rules.unwrap_or_default())?;
+    let mut redactions = Vec::new();
+    let turns: Vec<SessionTurn> = detail
+        .turns
+        .into_iter()
+        .map(|turn| redactor.redact_turn(turn, &mut redactions))
+        .collect();
+    let project = redactor.redact_text(cwd.as_deref().unwrap_or(&project_key), "", &mut redactions);
+
+    let header = ExportHeader {
+        session_id: &session_id,
+        project: &project,
+        exported_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+    };
+    let (content, extension) = match format.as_str() {
+        "html" => (render_html(&header, &turns), "html"),
+        _ => (render_markdown(&header, &turns), "md"),
+    };
+
+    let out = exports_dir()?.join(format!("{session_id}.{extension}"));
+    std::fs::create_dir_all(out.parent().unwrap_or(&out))
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    write_file_atomic(&out, content)?;
+    Ok(out.to_string_lossy().to_string())
+}
+
+fn exports_dir() -> CmdResult<PathBuf> {
+    dirs::home_dir()
+        .map(|h| h.join(".claude-commander").join("exports"))
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("Cannot determine home dir")))
+}
+
+struct ExportHeader<'a> {
+    session_id: &'a str,
+    project: &'a str,
+    exported_at: String,
+}
+
+fn role_label(role: &str) -> &'static str {
+    if role == "user" {
+        "User"
+    } else {
+        "Assistant"
+    }
+}
+
+/// Tool input is stored as compact JSON; pretty-print it when it parses.
+fn pretty_input(call: &SessionToolCall) -> String {
+    serde_json::from_str::<serde_json::Value>(&call.input)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or_else(|_| call.input.clone())
+}
+
+// ─── Markdown ──────────────────────────────────────────────────────────────
+
+fn render_markdown(header: &ExportHeader, turns: &[SessionTurn]) -> String {
+    let mut md = format!(
+        "# Session {}\n\n- Project: `{}`\n- Exported: {}\n",
+        header.session_id, header.project, header.exported_at
+    );
+    for turn in turns {
+        md.push_str(&format!(
+            "\n## {} · {}\n\n",
+            role_label(&turn.role),
+            turn.timestamp
+        ));
+        if !turn.content.trim().is_empty() {
+            md.push_str(turn.content.trim_end());
+            md.push('\n');
+        }
+        for call in &turn.tool_calls {
+            md.push_str(&format!(
+                "\n<details>\n<summary>Tool: {}</summary>\n\n",
+                call.name
+            ));
+            md.push_str(&code_block("json", &pretty_input(call)));
+            if let Some(output) = &call.output {
+                md.push_str(if call.is_error {
+                    "\nError:\n\n"
+                } else {
+                    "\nOutput:\n\n"
+                });
+                md.push_str(&code_block("", output));
+            }
+            md.push_str("\n</details>\n");
+        }
+    }
+    md
+}
+
+/// Fenced code block whose fence is longer than any backtick run inside,
+/// so embedded fences cannot close it early.
+fn code_block(lang: &str, body: &str) -> String {
+    let longest_run = body.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{fence}{lang}\n{}\n{fence}\n", body.trim_end())
+}
+
+// ─── HTML ──────────────────────────────────────────────────────────────────
+
+const HTML_STYLE: &str = "
+body { font: 14px/1.5 -apple-system, BlinkMacSystemFont, sans-serif; max-width: 860px;
+       margin: 2rem auto; padding: 0 1rem; color: #1f2328; }
+header { border-bottom: 1px solid #d0d7de; margin-bottom: 1.5rem; }
+header p { color: #59636e; margin: 0.25rem 0; }
+.turn { border: 1px solid #d0d7de; border-radius: 8px; padding: 0.75rem 1rem; margin: 1rem 0; }
+.turn.user { background: #f6f8fa; }
+.meta { color: #59636e; font-size: 12px; margin-bottom: 0.5rem; }
+.content { white-space: pre-wrap; word-wrap: break-word; }
+details { margin-top: 0.5rem; border: 1px solid #d0d7de; border-radius: 6px; }
+summary { cursor: pointer; padding: 0.25rem 0.5rem; font-family: ui-monospace, monospace; }
+pre { margin: 0; padding: 0.5rem; overflow-x: auto; background: #f6f8fa;
+      font: 12px/1.4 ui-monospace, monospace; white-space: pre-wrap; word-break: break-all; }
+";
+
+fn render_html(header: &ExportHeader, turns: &[SessionTurn]) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Session {id}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n\
+         <header>\n<h1>Session {id}</h1>\n<p>Project: <code>{project}</code></p>\n\
+         <p>Exported: {exported}</p>\n</header>\n",
+        id = escape_html(header.session_id),
+        project = escape_html(header.project),
+        exported = escape_html(&header.exported_at),
+    );
+    for turn in turns {
+        html.push_str(&format!(
+            "<section class=\"turn {role}\">\n<div class=\"meta\">{label} · {timestamp}</div>\n",
+            role = if turn.role == "user" {
+                "user"
+            } else {
+                "assistant"
+            },
+            label = role_label(&turn.role),
+            timestamp = escape_html(&turn.timestamp),
+        ));
+        if !turn.content.trim().is_empty() {
+            html.push_str(&format!(
+                "<div class=\"content\">{}</div>\n",
+                escape_html(turn.content.trim_end())
+            ));
+        }
+        for call in &turn.tool_calls {
+            html.push_str(&format!(
+                "<details>\n<summary>{}</summary>\n<pre><code>{}</code></pre>\n",
+                escape_html(&call.name),
+                escape_html(&pretty_input(call))
+            ));
+            if let Some(output) = &call.output {
+                html.push_str(&format!(
+                    "<pre><code>{}</code></pre>\n",
+                    escape_html(output.trim_end())
+                ));
+            }
+            html.push_str("</details>\n");
+        }
+        html.push_str("</section>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
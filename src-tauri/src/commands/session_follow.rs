@@ -0,0 +1,258 @@
+use crate::commands::claude::{
+    correlate_session_project, find_session_file, parse_session_turn, read_first_line_cwd,
+};
+use crate::commands::projects::load_db_projects;
+use crate::commands::settings::get_setting;
+use crate::error::CmdResult;
+use crate::models::{SessionFollowOutput, SessionFollowStarted};
+use crate::services::notifications;
+use crate::state::AppState;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Emitted with a `SessionFollowStarted` payload when a session starts being followed.
+pub const EVENT_AUTO_FOLLOW_STARTED: &str = "auto-follow-started";
+/// Emitted with a `SessionFollowOutput` payload for each batch of new turns.
+pub const EVENT_SESSION_FOLLOW_OUTPUT: &str = "session-follow-output";
+/// Emitted with the session id once a follow stops (idle, cancelled, or file gone).
+pub const EVENT_SESSION_FOLLOW_ENDED: &str = "session-follow-ended";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// A session whose file hasn't grown for this long is considered finished.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+/// How long to wait for the first line (which carries `cwd`) of a new file.
+const CWD_WAIT: Duration = Duration::from_secs(3);
+
+/// Start tailing a session into the follow viewer.  Returns `false` when the
+/// session is already being followed.
+#[tauri::command]
+pub fn follow_session(
+    app: AppHandle,
+    state: State<AppState>,
+    session_id: String,
+) -> CmdResult<bool> {
+    let path = find_session_file(&session_id)?;
+    Ok(start_follow(&app, &state, session_id, path, false))
+}
+
+#[tauri::command]
+pub fn stop_session_follow(state: State<AppState>, session_id: String) {
+    if let Some(flag) = state.session_follows.lock().get(&session_id) {
+        flag.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Ids of sessions currently being followed.
+#[tauri::command]
+pub fn get_followed_sessions(state: State<AppState>) -> Vec<String> {
+    state.session_follows.lock().keys().cloned().collect()
+}
+
+/// Called by the `~/.claude` watcher when a new session file appears; starts
+/// following it when `auto_follow_sessions` is on.
+pub(crate) fn on_session_file_created(app: &AppHandle, path: &Path) {
+    // Sub-agent transcripts live in nested directories; only top-level
+    // `projects/<key>/<session>.jsonl` files are real sessions.
+    let is_session_file = path.extension().and_then(|e| e.to_str()) == Some("jsonl")
+        && path
+            .parent()
+            .and_then(|p| p.parent())
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            == Some("projects");
+    if !is_session_file {
+        return;
+    }
+
+    let state = app.state::<AppState>();
+    let enabled = {
+        let db = state.db.lock();
+        db.as_ref()
+            .and_then(|conn| get_setting(conn, "auto_follow_sessions").flatten())
+            .as_deref()
+            == Some("true")
+    };
+    if !enabled {
+        return;
+    }
+
+    let Some(session_id) = path.file_stem().and_then(|s| s.to_str()) else {
+        return;
+    };
+    start_follow(
+        app,
+        &state,
+        session_id.to_string(),
+        path.to_path_buf(),
+        true,
+    );
+}
+
+fn start_follow(
+    app: &AppHandle,
+    state: &AppState,
+    session_id: String,
+    path: PathBuf,
+    auto: bool,
+) -> bool {
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut follows = state.session_follows.lock();
+        if follows.contains_key(&session_id) {
+            return false;
+        }
+        follows.insert(session_id.clone(), cancel.clone());
+    }
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let cwd = wait_for_cwd(&path, &cancel);
+        let project_id = cwd.as_deref().and_then(|cwd| {
+            let state = app.state::<AppState>();
+            let db = state.db.lock();
+            let projects = load_db_projects(db.as_ref()?).ok()?;
+            correlate_session_project(cwd, &projects)
+        });
+
+        if auto {
+            notifications::notify(
+                &app,
+                notifications::TRIGGER_SESSION_STARTED,
+                "New Claude session",
+                cwd.clone().unwrap_or_else(|| session_id.clone()),
+            );
+        }
+        let _ = app.emit(
+            EVENT_AUTO_FOLLOW_STARTED,
+            SessionFollowStarted {
+                session_id: session_id.clone(),
+                path: path.to_string_lossy().to_string(),
+                cwd,
+                project_id,
+                auto,
+            },
+        );
+
+        if let Err(e) = tail_session(&app, &session_id, &path, &cancel) {
+            log::warn!("session follow {} stopped: {}", session_id, e);
+        }
+
+        app.state::<AppState>()
+            .session_follows
+            .lock()
+            .remove(&session_id);
+        if auto {
+            notifications::notify(
+                &app,
+                notifications::TRIGGER_SESSION_ENDED,
+                "Claude session ended",
+                session_id.clone(),
+            );
+        }
+        let _ = app.emit(EVENT_SESSION_FOLLOW_ENDED, &session_id);
+    });
+
+    true
+}
+
+/// Give a freshly created file a moment to receive its first line.
+fn wait_for_cwd(path: &Path, cancel: &AtomicBool) -> Option<String> {
+    let started = Instant::now();
+    loop {
+        if let Some(cwd) = read_first_line_cwd(path) {
+            return Some(cwd);
+        }
+        if cancel.load(Ordering::Relaxed) || started.elapsed() >= CWD_WAIT {
+            return None;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Stream complete lines appended to `path`, rendered as terminal text, until
+/// cancelled or idle for `IDLE_TIMEOUT`.
+fn tail_session(
+    app: &AppHandle,
+    session_id: &str,
+    path: &Path,
+    cancel: &AtomicBool,
+) -> std::io::Result<()> {
+    let mut file = std::fs::File::open(path)?;
+    let mut offset: u64 = 0;
+    // Bytes after the last newline, kept until the line is complete.
+    let mut partial: Vec<u8> = Vec::new();
+    let mut last_growth = Instant::now();
+
+    while !cancel.load(Ordering::Relaxed) {
+        let len = file.metadata()?.len();
+        if len < offset {
+            // Truncated or replaced: start over.
+            offset = 0;
+            partial.clear();
+        }
+
+        if len > offset {
+            file.seek(SeekFrom::Start(offset))?;
+            let mut chunk = Vec::with_capacity((len - offset) as usize);
+            (&mut file).take(len - offset).read_to_end(&mut chunk)?;
+            offset += chunk.len() as u64;
+            last_growth = Instant::now();
+
+            partial.extend_from_slice(&chunk);
+            let Some(last_newline) = partial.iter().rposition(|&b| b == b'\n') else {
+                continue;
+            };
+            let complete: Vec<u8> = partial.drain(..=last_newline).collect();
+
+            let data: String = String::from_utf8_lossy(&complete)
+                .lines()
+                .filter_map(render_line)
+                .collect();
+            if !data.is_empty() {
+                let _ = app.emit(
+                    EVENT_SESSION_FOLLOW_OUTPUT,
+                    SessionFollowOutput {
+                        session_id: session_id.to_string(),
+                        data,
+                    },
+                );
+            }
+        } else if last_growth.elapsed() >= IDLE_TIMEOUT {
+            break;
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+
+    Ok(())
+}
+
+/// Render one JSONL line as ANSI-coloured terminal text (CRLF line endings,
+/// since the viewer is an xterm).
+fn render_line(line: &str) -> Option<String> {
+    let turn = parse_session_turn(line)?;
+    let mut out = String::new();
+
+    if !turn.content.is_empty() {
+        let body = turn.content.trim_end().replace('\n', "\r\n");
+        if turn.role == "user" {
+            out.push_str(&format!("\x1b[36m› {}\x1b[0m\r\n\r\n", body));
+        } else {
+            out.push_str(&format!("{}\r\n\r\n", body));
+        }
+    }
+
+    for call in &turn.tool_calls {
+        let input: String = call.input.chars().take(120).collect();
+        out.push_str(&format!(
+            "\x1b[33m⏺ {}\x1b[0m\x1b[90m({})\x1b[0m\r\n",
+            call.name, input
+        ));
+    }
+
+    (!out.is_empty()).then_some(out)
+}
@@ -0,0 +1,373 @@
+use crate::commands::claude::{claude_root, claude_roots, parse_session_turn, read_first_line_cwd};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{
+    MatchOffset, SessionIndexStats, SessionSearchHit, SessionTurn, SessionTurnMatch,
+};
+use crate::state::AppState;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Snippet highlight markers.  Control characters cannot appear in
+/// transcript text, so the frontend can split on them safely.
+const MATCH_START: &str = "\u{2}";
+const MATCH_END: &str = "\u{3}";
+
+/// Tool inputs are indexed too (that is where edited code lives), but cut
+/// to keep one huge `Write` from dominating the index.
+const MAX_TOOL_INPUT_CHARS: usize = 4_000;
+
+/// Characters of context kept on each side of the first match in a
+/// `search_in_session` snippet.
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+
+/// Full-text search over every indexed session transcript, best match
+/// first.  Each whitespace-separated word must appear in the turn (prefix
+/// match on the last one).  Snippets wrap matches in `\u{2}`…`\u{3}`.
+#[tauri::command]
+pub fn search_sessions(
+    state: State<AppState>,
+    query: String,
+    limit: Option<usize>,
+) -> CmdResult<Vec<SessionSearchHit>> {
+    let Some(fts_query) = to_fts_query(&query) else {
+        return Ok(vec![]);
+    };
+    let limit = limit.unwrap_or(50).min(500) as i64;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT t.session_id, t.project_key, f.cwd, t.role, t.timestamp,
+                    snippet(session_turns_fts, 5, ?2, ?3, '…', 16)
+             FROM session_turns_fts t
+             LEFT JOIN session_index_files f ON f.path = t.path
+             WHERE session_turns_fts MATCH ?1
+             ORDER BY bm25(session_turns_fts)
+             LIMIT ?4",
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let hits = stmt
+        .query_map(
+            rusqlite::params![fts_query, MATCH_START, MATCH_END, limit],
+            |row| {
+                Ok(SessionSearchHit {
+                    session_id: row.get(0)?,
+                    project_key: row.get(1)?,
+                    cwd: row.get(2)?,
+                    role: row.get(3)?,
+                    timestamp: row.get(4)?,
+                    snippet: row.get(5)?,
+                })
+            },
+        )
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(hits)
+}
+
+/// Case-insensitive find within one transcript, read straight from disk
+/// so matches past the viewer's turn cap are found too.  Returns matching
+/// turns in transcript order.
+#[tauri::command]
+pub fn search_in_session(
+    project_key: String,
+    session_id: String,
+    query: String,
+    source: Option<String>,
+) -> CmdResult<Vec<SessionTurnMatch>> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(vec![]);
+    }
+    let re = regex::RegexBuilder::new(&regex::escape(query))
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| to_cmd_err(CommanderError::internal(e.to_string())))?;
+    let path = claude_root(source.as_deref())
+        .join("projects")
+        .join(&project_key)
+        .join(format!("{session_id}.jsonl"));
+    let file = std::fs::File::open(&path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let matches = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| parse_session_turn(&line))
+        .enumerate()
+        .filter_map(|(index, turn)| match_turn(&re, index, turn))
+        .collect();
+    Ok(matches)
+}
+
+fn match_turn(re: &regex::Regex, turn_index: usize, turn: SessionTurn) -> Option<SessionTurnMatch> {
+    let spans: Vec<(usize, usize)> = re
+        .find_iter(&turn.content)
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    let matching_calls: Vec<_> = turn
+        .tool_calls
+        .iter()
+        .filter(|c| re.is_match(&c.name) || re.is_match(&c.input))
+        .collect();
+    let snippet = if !spans.is_empty() {
+        snippet_around(&turn.content, &spans)
+    } else {
+        let call = matching_calls.first()?;
+        let text = format!("{} {}", call.name, call.input);
+        let call_spans: Vec<_> = re.find_iter(&text).map(|m| (m.start(), m.end())).collect();
+        snippet_around(&text, &call_spans)
+    };
+    Some(SessionTurnMatch {
+        offsets: utf16_offsets(&turn.content, &spans),
+        tool_call_ids: matching_calls.iter().map(|c| c.id.clone()).collect(),
+        uuid: turn.uuid,
+        turn_index,
+        role: turn.role,
+        timestamp: turn.timestamp,
+        snippet,
+    })
+}
+
+/// Text around the first span, with every span inside the window wrapped
+/// in the highlight markers.  `spans` are byte ranges, in order.
+fn snippet_around(text: &str, spans: &[(usize, usize)]) -> String {
+    let Some(&(first_start, first_end)) = spans.first() else {
+        return String::new();
+    };
+    let start = text[..first_start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_CONTEXT_CHARS - 1)
+        .map_or(0, |(i, _)| i);
+    let end = text[first_end..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT_CHARS)
+        .map_or(text.len(), |(i, _)| first_end + i);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    let mut pos = start;
+    for &(s, e) in spans.iter().take_while(|(s, _)| *s < end) {
+        let e = e.min(end);
+        snippet.push_str(&text[pos..s]);
+        snippet.push_str(MATCH_START);
+        snippet.push_str(&text[s..e]);
+        snippet.push_str(MATCH_END);
+        pos = e;
+    }
+    snippet.push_str(&text[pos..end]);
+    if end < text.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// Convert byte ranges to UTF-16 ranges, which is how JavaScript indexes
+/// strings.
+fn utf16_offsets(text: &str, spans: &[(usize, usize)]) -> Vec<MatchOffset> {
+    let mut byte_pos = 0;
+    let mut utf16_pos = 0;
+    let mut advance = |to: usize| {
+        utf16_pos += text[byte_pos..to].encode_utf16().count();
+        byte_pos = to;
+        utf16_pos
+    };
+    spans
+        .iter()
+        .map(|&(s, e)| MatchOffset {
+            start: advance(s),
+            end: advance(e),
+        })
+        .collect()
+}
+
+/// Bring the index up to date now instead of waiting for the background
+/// indexer.
+#[tauri::command]
+pub fn reindex_sessions(state: State<AppState>) -> CmdResult<SessionIndexStats> {
+    index_sessions(&state).map_err(to_cmd_err)
+}
+
+/// Quote every word so FTS5 operators in user input are taken literally.
+fn to_fts_query(query: &str) -> Option<String> {
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(|w| format!("\"{}\"", w.replace('"', "\"\"")))
+        .collect();
+    let last = words.last()?.clone();
+    let mut fts = words[..words.len() - 1].to_vec();
+    fts.push(format!("{last}*"));
+    Some(fts.join(" "))
+}
+
+pub(crate) struct SessionFile {
+    pub path: PathBuf,
+    /// Label of the Claude root the file lives in.
+    pub source: String,
+    pub project_key: String,
+    pub session_id: String,
+    pub mtime: i64,
+    pub size: i64,
+}
+
+/// Index new and changed transcripts under every Claude root and drop
+/// those that were deleted.  Files are only re-read when their mtime or
+/// size changed; parsing happens without holding the DB lock.
+pub(crate) fn index_sessions(state: &AppState) -> Result<SessionIndexStats, CommanderError> {
+    let files = list_session_files();
+    let known: HashMap<String, (i64, i64)> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let mut stmt = conn.prepare("SELECT path, mtime, size FROM session_index_files")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+            .filter_map(|r| r.ok())
+            .collect();
+        rows
+    };
+
+    let mut stats = SessionIndexStats::default();
+    for file in &files {
+        let key = file.path.to_string_lossy().to_string();
+        if known.get(&key) == Some(&(file.mtime, file.size)) {
+            continue;
+        }
+        let turns = match read_turns(&file.path) {
+            Ok(turns) => turns,
+            Err(e) => {
+                log::warn!("skipped session {}: {}", file.path.display(), e);
+                continue;
+            }
+        };
+        let cwd = read_first_line_cwd(&file.path);
+
+        let mut db = state.db.lock();
+        let conn = db
+            .as_mut()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM session_turns_fts WHERE path = ?1", [&key])?;
+        for (role, timestamp, text) in &turns {
+            tx.execute(
+                "INSERT INTO session_turns_fts
+                     (path, session_id, project_key, role, timestamp, text)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![
+                    key,
+                    file.session_id,
+                    file.project_key,
+                    role,
+                    timestamp,
+                    text
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO session_index_files
+                 (path, session_id, project_key, cwd, mtime, size, turns, indexed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![
+                key,
+                file.session_id,
+                file.project_key,
+                cwd,
+                file.mtime,
+                file.size,
+                turns.len() as i64,
+                chrono::Utc::now().to_rfc3339(),
+            ],
+        )?;
+        tx.commit()?;
+        stats.indexed += 1;
+        stats.turns += turns.len();
+    }
+
+    let present: std::collections::HashSet<String> = files
+        .iter()
+        .map(|f| f.path.to_string_lossy().to_string())
+        .collect();
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+    for path in known.keys().filter(|p| !present.contains(*p)) {
+        conn.execute("DELETE FROM session_turns_fts WHERE path = ?1", [path])?;
+        conn.execute("DELETE FROM session_index_files WHERE path = ?1", [path])?;
+        stats.removed += 1;
+    }
+    stats.total_sessions = files.len();
+    Ok(stats)
+}
+
+/// Every `projects/<key>/<session>.jsonl` under the configured Claude roots.
+pub(crate) fn list_session_files() -> Vec<SessionFile> {
+    let mut files = Vec::new();
+    for (source, root) in claude_roots() {
+        let Ok(project_dirs) = std::fs::read_dir(root.join("projects")) else {
+            continue;
+        };
+        for project_dir in project_dirs.filter_map(|e| e.ok()) {
+            let project_key = project_dir.file_name().to_string_lossy().to_string();
+            let Ok(entries) = std::fs::read_dir(project_dir.path()) else {
+                continue;
+            };
+            for entry in entries.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+                    continue;
+                }
+                let Ok(meta) = entry.metadata() else {
+                    continue;
+                };
+                let mtime = meta
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map_or(0, |d| d.as_secs() as i64);
+                files.push(SessionFile {
+                    session_id: path
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    source: source.clone(),
+                    project_key: project_key.clone(),
+                    path,
+                    mtime,
+                    size: meta.len() as i64,
+                });
+            }
+        }
+    }
+    files
+}
+
+/// `(role, timestamp, text)` per turn; tool calls contribute their name
+/// and (truncated) input.
+fn read_turns(path: &Path) -> Result<Vec<(String, String, String)>, CommanderError> {
+    let file = std::fs::File::open(path).map_err(CommanderError::io)?;
+    let turns = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| parse_session_turn(&line))
+        .map(|turn| {
+            let mut text = turn.content;
+            for call in &turn.tool_calls {
+                let input: String = call.input.chars().take(MAX_TOOL_INPUT_CHARS).collect();
+                text.push_str(&format!("\n{} {}", call.name, input));
+            }
+            (turn.role, turn.timestamp, text)
+        })
+        .filter(|(_, _, text)| !text.trim().is_empty())
+        .collect();
+    Ok(turns)
+}
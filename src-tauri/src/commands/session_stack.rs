@@ -0,0 +1,385 @@
+use crate::commands::claude::{
+    claude_roots, correlate_session_project, find_session_file, read_first_line_cwd,
+};
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{Project, SessionStack};
+use crate::state::AppState;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// File extension → language.
+const EXTENSION_LANGUAGES: &[(&str, &str)] = &[
+    ("rs", "rust"),
+    ("ts", "typescript"),
+    ("tsx", "typescript"),
+    ("mts", "typescript"),
+    ("js", "javascript"),
+    ("jsx", "javascript"),
+    ("mjs", "javascript"),
+    ("cjs", "javascript"),
+    ("py", "python"),
+    ("go", "go"),
+    ("rb", "ruby"),
+    ("php", "php"),
+    ("java", "java"),
+    ("kt", "kotlin"),
+    ("swift", "swift"),
+    ("c", "c"),
+    ("h", "c"),
+    ("cpp", "cpp"),
+    ("cc", "cpp"),
+    ("hpp", "cpp"),
+    ("cs", "csharp"),
+    ("sql", "sql"),
+    ("sh", "shell"),
+    ("bash", "shell"),
+    ("zsh", "shell"),
+    ("css", "css"),
+    ("scss", "css"),
+    ("html", "html"),
+    ("vue", "vue"),
+    ("svelte", "svelte"),
+];
+
+/// Leading command word → language, for Bash tool calls.
+const COMMAND_LANGUAGES: &[(&str, &str)] = &[
+    ("cargo", "rust"),
+    ("rustc", "rust"),
+    ("npm", "javascript"),
+    ("npx", "javascript"),
+    ("pnpm", "javascript"),
+    ("yarn", "javascript"),
+    ("bun", "javascript"),
+    ("node", "javascript"),
+    ("tsc", "typescript"),
+    ("python", "python"),
+    ("python3", "python"),
+    ("pip", "python"),
+    ("pytest", "python"),
+    ("poetry", "python"),
+    ("uv", "python"),
+    ("go", "go"),
+    ("bundle", "ruby"),
+    ("rails", "ruby"),
+    ("composer", "php"),
+    ("psql", "sql"),
+    ("sqlite3", "sql"),
+    ("mysql", "sql"),
+];
+
+/// Path fragment → topic tag.
+const PATH_TAGS: &[(&str, &str)] = &[
+    ("/migrations/", "sql-migrations"),
+    ("/migrate/", "sql-migrations"),
+    ("/.github/workflows/", "ci"),
+    ("/Dockerfile", "docker"),
+    ("docker-compose", "docker"),
+    ("/tests/", "tests"),
+    ("/__tests__/", "tests"),
+    (".test.", "tests"),
+    (".spec.", "tests"),
+    ("_test.", "tests"),
+    (".tf", "terraform"),
+    ("/k8s/", "kubernetes"),
+];
+
+/// Command substring → topic tag.
+const COMMAND_TAGS: &[(&str, &str)] = &[
+    ("migrate", "sql-migrations"),
+    ("docker ", "docker"),
+    ("docker-compose", "docker"),
+    ("kubectl", "kubernetes"),
+    ("terraform", "terraform"),
+    ("cargo test", "tests"),
+    ("npm test", "tests"),
+    ("pnpm test", "tests"),
+    ("pytest", "tests"),
+    ("go test", "tests"),
+    ("vitest", "tests"),
+    ("jest", "tests"),
+    ("git push", "git-push"),
+];
+
+/// What a transcript's tool calls say about the stack the agent worked in.
+#[derive(Default)]
+struct StackEvidence {
+    languages: HashMap<String, usize>,
+    tags: HashMap<String, usize>,
+    files: Vec<PathBuf>,
+}
+
+/// Infer the languages and topics a session worked in from the files its
+/// tool calls touched and the commands it ran.  Results are cached in
+/// `session_stacks` and recomputed only when the transcript has grown.
+#[tauri::command]
+pub fn get_session_stack(state: State<AppState>, session_id: String) -> CmdResult<SessionStack> {
+    let path = find_session_file(&session_id)?;
+    let projects = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_db_projects(conn).map_err(to_cmd_err)?
+    };
+    session_stack(&state, &session_id, &path, &projects)
+}
+
+/// Sessions across all projects whose detected stack includes `language`
+/// and/or `tag` (e.g. `tag = "sql-migrations"`), most recently analyzed
+/// transcripts first.
+#[tauri::command]
+pub fn find_sessions_by_stack(
+    state: State<AppState>,
+    language: Option<String>,
+    tag: Option<String>,
+) -> CmdResult<Vec<SessionStack>> {
+    let projects = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_db_projects(conn).map_err(to_cmd_err)?
+    };
+
+    let mut matches = Vec::new();
+    for path in session_files() {
+        let Some(session_id) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let stack = match session_stack(&state, session_id, &path, &projects) {
+            Ok(stack) => stack,
+            Err(e) => {
+                log::warn!("stack detection failed for {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        let language_ok = language
+            .as_deref()
+            .is_none_or(|l| stack.languages.iter().any(|s| s.eq_ignore_ascii_case(l)));
+        let tag_ok = tag
+            .as_deref()
+            .is_none_or(|t| stack.tags.iter().any(|s| s.eq_ignore_ascii_case(t)));
+        if language_ok && tag_ok {
+            matches.push(stack);
+        }
+    }
+    matches.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+    Ok(matches)
+}
+
+/// Cached stack for one transcript, re-analyzed when its size changed.
+fn session_stack(
+    state: &AppState,
+    session_id: &str,
+    path: &Path,
+    projects: &[Project],
+) -> CmdResult<SessionStack> {
+    let metadata = std::fs::metadata(path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let size = metadata.len() as i64;
+    let last_modified = metadata.modified().ok().map(|t| {
+        let dt: chrono::DateTime<chrono::Utc> = t.into();
+        dt.to_rfc3339()
+    });
+
+    {
+        let db = state.db.lock();
+        if let Some(conn) = db.as_ref() {
+            if let Some(cached) = load_cached(conn, session_id, size) {
+                return Ok(cached);
+            }
+        }
+    }
+
+    let cwd = read_first_line_cwd(path);
+    let evidence = collect_evidence(path, cwd.as_deref())?;
+    let stack = SessionStack {
+        session_id: session_id.to_string(),
+        primary_language: ranked(&evidence.languages).into_iter().next(),
+        languages: ranked(&evidence.languages),
+        tags: ranked(&evidence.tags),
+        files_touched: evidence.files.len(),
+        project_id: correlate_by_files(&evidence.files, projects)
+            .or_else(|| correlate_session_project(cwd.as_deref()?, projects)),
+        last_modified,
+    };
+
+    let db = state.db.lock();
+    if let Some(conn) = db.as_ref() {
+        if let Err(e) = store(conn, &stack, size) {
+            log::warn!("failed to cache stack for {}: {}", session_id, e);
+        }
+    }
+    Ok(stack)
+}
+
+fn load_cached(conn: &rusqlite::Connection, session_id: &str, size: i64) -> Option<SessionStack> {
+    conn.query_row(
+        "SELECT primary_language, languages, tags, files_touched, project_id, last_modified
+         FROM session_stacks WHERE session_id = ?1 AND file_size = ?2",
+        rusqlite::params![session_id, size],
+        |row| {
+            let languages: String = row.get(1)?;
+            let tags: String = row.get(2)?;
+            Ok(SessionStack {
+                session_id: session_id.to_string(),
+                primary_language: row.get(0)?,
+                languages: serde_json::from_str(&languages).unwrap_or_default(),
+                tags: serde_json::from_str(&tags).unwrap_or_default(),
+                files_touched: row.get::<_, i64>(3)? as usize,
+                project_id: row.get(4)?,
+                last_modified: row.get(5)?,
+            })
+        },
+    )
+    .ok()
+}
+
+fn store(
+    conn: &rusqlite::Connection,
+    stack: &SessionStack,
+    size: i64,
+) -> Result<(), CommanderError> {
+    conn.execute(
+        "INSERT INTO session_stacks
+             (session_id, primary_language, languages, tags, files_touched, project_id,
+              last_modified, file_size, analyzed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, datetime('now'))
+         ON CONFLICT(session_id) DO UPDATE SET
+             primary_language = excluded.primary_language,
+             languages = excluded.languages,
+             tags = excluded.tags,
+             files_touched = excluded.files_touched,
+             project_id = excluded.project_id,
+             last_modified = excluded.last_modified,
+             file_size = excluded.file_size,
+             analyzed_at = excluded.analyzed_at",
+        rusqlite::params![
+            stack.session_id,
+            stack.primary_language,
+            serde_json::to_string(&stack.languages)?,
+            serde_json::to_string(&stack.tags)?,
+            stack.files_touched as i64,
+            stack.project_id,
+            stack.last_modified,
+            size,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Every top-level session transcript under `projects/` of each Claude root.
+fn session_files() -> Vec<PathBuf> {
+    claude_roots()
+        .into_iter()
+        .filter_map(|(_, root)| std::fs::read_dir(root.join("projects")).ok())
+        .flat_map(|dirs| dirs.filter_map(|e| e.ok()))
+        .filter_map(|dir| std::fs::read_dir(dir.path()).ok())
+        .flat_map(|files| files.filter_map(|e| e.ok()))
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("jsonl"))
+        .collect()
+}
+
+fn collect_evidence(session_path: &Path, cwd: Option<&str>) -> CmdResult<StackEvidence> {
+    use std::io::BufRead;
+    let file = std::fs::File::open(session_path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let mut evidence = StackEvidence::default();
+
+    for line in std::io::BufReader::new(file).lines().map_while(Result::ok) {
+        let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        let Some(blocks) = v["message"]["content"].as_array() else {
+            continue;
+        };
+
+        for block in blocks.iter().filter(|b| b["type"] == "tool_use") {
+            let input = &block["input"];
+            match block["name"].as_str() {
+                Some("Read" | "Write" | "Edit" | "MultiEdit" | "NotebookEdit") => {
+                    let raw = input["file_path"]
+                        .as_str()
+                        .or_else(|| input["notebook_path"].as_str());
+                    if let Some(raw) = raw {
+                        let path = match cwd {
+                            Some(cwd) if Path::new(raw).is_relative() => Path::new(cwd).join(raw),
+                            _ => PathBuf::from(raw),
+                        };
+                        record_path(&mut evidence, &path);
+                        if !evidence.files.contains(&path) {
+                            evidence.files.push(path);
+                        }
+                    }
+                }
+                Some("Bash") => {
+                    if let Some(command) = input["command"].as_str() {
+                        record_command(&mut evidence, command);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(evidence)
+}
+
+fn record_path(evidence: &mut StackEvidence, path: &Path) {
+    if let Some(language) = path.extension().and_then(|e| e.to_str()).and_then(|ext| {
+        let ext = ext.to_lowercase();
+        EXTENSION_LANGUAGES
+            .iter()
+            .find(|(e, _)| *e == ext)
+            .map(|(_, l)| *l)
+    }) {
+        *evidence.languages.entry(language.to_string()).or_default() += 1;
+    }
+
+    let text = path.to_string_lossy();
+    for (fragment, tag) in PATH_TAGS {
+        if text.contains(fragment) {
+            *evidence.tags.entry(tag.to_string()).or_default() += 1;
+        }
+    }
+}
+
+fn record_command(evidence: &mut StackEvidence, command: &str) {
+    // Chained commands (`cd x && cargo test`) count each step.
+    for step in command.split(['&', '|', ';']) {
+        let Some(word) = step.split_whitespace().next() else {
+            continue;
+        };
+        if let Some((_, language)) = COMMAND_LANGUAGES.iter().find(|(w, _)| *w == word) {
+            *evidence.languages.entry(language.to_string()).or_default() += 1;
+        }
+    }
+    for (needle, tag) in COMMAND_TAGS {
+        if command.contains(needle) {
+            *evidence.tags.entry(tag.to_string()).or_default() += 1;
+        }
+    }
+}
+
+/// Keys by descending count, ties alphabetical.
+fn ranked(counts: &HashMap<String, usize>) -> Vec<String> {
+    let mut entries: Vec<(&String, &usize)> = counts.iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    entries.into_iter().map(|(k, _)| k.clone()).collect()
+}
+
+/// The project containing most of the touched files.  Catches sessions
+/// started from a parent directory or a scratch dir that still worked on a
+/// known project.
+fn correlate_by_files(files: &[PathBuf], projects: &[Project]) -> Option<String> {
+    let mut votes: HashMap<String, usize> = HashMap::new();
+    for file in files {
+        if let Some(id) = correlate_session_project(&file.to_string_lossy(), projects) {
+            *votes.entry(id).or_default() += 1;
+        }
+    }
+    votes
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(id, _)| id)
+}
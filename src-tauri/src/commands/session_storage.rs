@@ -0,0 +1,234 @@
+use crate::commands::claude::read_first_line_cwd;
+use crate::commands::session_search::{list_session_files, SessionFile};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{SessionCleanupResult, SessionStorageProject, SessionStorageReport};
+use crate::state::AppState;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Total transcript size and count per Claude project directory, largest
+/// first.  Only file metadata is read, plus the first line of one
+/// transcript per project for its working directory.
+#[tauri::command]
+pub fn analyze_session_storage() -> CmdResult<SessionStorageReport> {
+    let mut by_project: HashMap<(String, String), (SessionStorageProject, PathBuf)> =
+        HashMap::new();
+    let mut report = SessionStorageReport::default();
+    for file in list_session_files() {
+        let bytes = file.size as u64 + companion_dir_size(&file.path);
+        report.total_bytes += bytes;
+        report.total_sessions += 1;
+        let (project, sample) = by_project
+            .entry((file.source.clone(), file.project_key.clone()))
+            .or_insert_with(|| {
+                (
+                    SessionStorageProject {
+                        source: file.source.clone(),
+                        project_key: file.project_key.clone(),
+                        ..Default::default()
+                    },
+                    file.path.clone(),
+                )
+            });
+        project.bytes += bytes;
+        project.sessions += 1;
+        let modified = format_mtime(file.mtime);
+        if project.newest.as_ref().is_none_or(|n| *n < modified) {
+            project.newest = Some(modified.clone());
+            *sample = file.path.clone();
+        }
+        if project.oldest.as_ref().is_none_or(|o| *o > modified) {
+            project.oldest = Some(modified);
+        }
+    }
+
+    report.projects = by_project
+        .into_values()
+        .map(|(mut project, sample)| {
+            project.cwd = read_first_line_cwd(&sample);
+            project
+        })
+        .collect();
+    report.projects.sort_by(|a, b| {
+        b.bytes
+            .cmp(&a.bytes)
+            .then(a.project_key.cmp(&b.project_key))
+    });
+    Ok(report)
+}
+
+/// Remove transcripts not written to in `older_than_days` days, across
+/// every Claude root.  Bookmarked sessions are always kept.  By default the
+/// files are moved under `~/.claude-commander/trash/sessions/<timestamp>/`
+/// rather than deleted; pass `move_to_trash: false` to delete them.  With
+/// `dry_run` nothing is touched and the result lists what would go.
+#[tauri::command]
+pub fn cleanup_sessions(
+    state: State<AppState>,
+    older_than_days: u32,
+    dry_run: bool,
+    move_to_trash: Option<bool>,
+) -> CmdResult<SessionCleanupResult> {
+    if older_than_days == 0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "older_than_days must be at least 1",
+        )));
+    }
+    let bookmarked: HashSet<String> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        let mut stmt = conn
+            .prepare("SELECT session_id FROM session_bookmarks")
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+            .filter_map(|r| r.ok())
+            .collect();
+        ids
+    };
+
+    let cutoff = chrono::Utc::now().timestamp() - older_than_days as i64 * 86_400;
+    let mut result = SessionCleanupResult {
+        dry_run,
+        ..Default::default()
+    };
+    let mut stale: Vec<SessionFile> = Vec::new();
+    for file in list_session_files() {
+        if file.mtime >= cutoff {
+            continue;
+        }
+        if bookmarked.contains(&file.session_id) {
+            result.skipped_bookmarked += 1;
+            continue;
+        }
+        stale.push(file);
+    }
+
+    let trash = if move_to_trash.unwrap_or(true) && !dry_run && !stale.is_empty() {
+        let dir = trash_dir()?;
+        result.trash_path = Some(dir.to_string_lossy().to_string());
+        Some(dir)
+    } else {
+        None
+    };
+
+    for file in stale {
+        let companion = file.path.with_extension("");
+        let bytes = file.size as u64 + companion_dir_size(&file.path);
+        if !dry_run {
+            let removed = match &trash {
+                Some(dir) => {
+                    let dest = dir
+                        .join(path_component(&file.source))
+                        .join(&file.project_key);
+                    move_into(&file.path, &dest).and_then(|_| {
+                        if companion.is_dir() {
+                            move_into(&companion, &dest)?;
+                        }
+                        Ok(())
+                    })
+                }
+                None => std::fs::remove_file(&file.path).and_then(|_| {
+                    if companion.is_dir() {
+                        std::fs::remove_dir_all(&companion)?;
+                    }
+                    Ok(())
+                }),
+            };
+            if let Err(e) = removed {
+                result
+                    .errors
+                    .push(format!("{}: {}", file.path.display(), e));
+                continue;
+            }
+        }
+        result.sessions += 1;
+        result.bytes += bytes;
+        result.paths.push(file.path.to_string_lossy().to_string());
+    }
+    Ok(result)
+}
+
+/// A source label as a single directory name.  Source labels are user-chosen (see
+/// `ClaudeDirConfig::label`) or `project:<id>`, so anything but letters,
+/// digits, `-`, `_` and `.` becomes `_`, and `.`/`..` cannot climb out.
+fn path_component(label: &str) -> String {
+    let name: String = label
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.chars().all(|c| c == '.') {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// Newer Claude Code versions keep subagent transcripts and large tool
+/// results in a `<session id>/` directory next to the `.jsonl`.
+fn companion_dir_size(transcript: &Path) -> u64 {
+    let dir = transcript.with_extension("");
+    if !dir.is_dir() {
+        return 0;
+    }
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn format_mtime(mtime: i64) -> String {
+    chrono::DateTime::from_timestamp(mtime, 0)
+        .unwrap_or_default()
+        .to_rfc3339()
+}
+
+fn trash_dir() -> CmdResult<PathBuf> {
+    dirs::home_dir()
+        .map(|h| {
+            h.join(".claude-commander")
+                .join("trash")
+                .join("sessions")
+                .join(chrono::Local::now().format("%Y%m%d-%H%M%S").to_string())
+        })
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("Cannot determine home dir")))
+}
+
+/// Move `path` into `dir`, copying when a rename across filesystems fails.
+fn move_into(path: &Path, dir: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let dest = dir.join(path.file_name().unwrap_or_default());
+    if std::fs::rename(path, &dest).is_ok() {
+        return Ok(());
+    }
+    if path.is_dir() {
+        for entry in walkdir::WalkDir::new(path)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let target = dest.join(entry.path().strip_prefix(path).unwrap_or(entry.path()));
+            if entry.file_type().is_dir() {
+                std::fs::create_dir_all(&target)?;
+            } else {
+                std::fs::copy(entry.path(), &target)?;
+            }
+        }
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::copy(path, &dest)?;
+        std::fs::remove_file(path)
+    }
+}
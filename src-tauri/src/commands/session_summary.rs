@@ -0,0 +1,237 @@
+use crate::commands::claude::{find_session_file, load_session_detail};
+use crate::commands::github::{parse_issue_number, parse_repo_from_url};
+use crate::commands::redaction::redact_session_text;
+use crate::commands::terminal::find_claude_bin;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{RedactionRules, SessionSummaryPreview, SessionSummaryResult, SessionTurn};
+use crate::services::forge::forge_for_repo;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Transcript budget handed to `claude -p`; the tail is kept because the end
+/// of a session says what was actually delivered.
+const MAX_TRANSCRIPT_CHARS: usize = 60_000;
+
+/// Headless summaries that take longer than this fall back to extractive.
+const CLAUDE_TIMEOUT: Duration = Duration::from_secs(120);
+
+const SUMMARY_PROMPT: &str = "Below is the transcript of a Claude Code session. \
+Summarize what the agent actually did in 3-6 concise markdown bullet points: \
+changes made, files touched, commands run, and anything left unfinished. \
+Output only the bullet points.";
+
+/// Summarise a Claude session as an issue comment, for the user to review
+/// before `post_session_summary`.  Uses headless `claude -p` when available,
+/// otherwise an extractive summary, and applies `rules` (all on by default)
+/// to the result.
+#[tauri::command]
+pub async fn preview_session_summary(
+    session_id: String,
+    rules: Option<RedactionRules>,
+) -> CmdResult<SessionSummaryPreview> {
+    let rules = rules.unwrap_or_default();
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = find_session_file(&session_id)?;
+        let detail = load_session_detail(&path, usize::MAX)?;
+        if detail.turns.is_empty() {
+            return Err(to_cmd_err(CommanderError::internal(
+                "Session has no messages to summarise",
+            )));
+        }
+
+        let (bullets, method) = match summarize_with_claude(&detail.turns) {
+            Some(text) => (text, "claude"),
+            None => (extractive_summary(&detail.turns), "extractive"),
+        };
+        let comment = format_comment(&session_id, &detail.turns, &bullets);
+        let (summary, redactions) = redact_session_text(&path, &rules, &comment)?;
+
+        Ok(SessionSummaryPreview {
+            summary,
+            method: method.to_string(),
+            redactions,
+        })
+    })
+    .await
+    .map_err(|e| to_cmd_err(CommanderError::internal(e)))?
+}
+
+/// Post `summary` (normally a reviewed `preview_session_summary`) as a
+/// comment on the issue at `issue_link` (`https://github.com/owner/repo/issues/N`,
+/// or a GitLab `…/-/issues/N` URL).  `rules` are applied again first, so
+/// secrets typed into an edited summary are not posted either.
+#[tauri::command]
+pub async fn post_session_summary(
+    session_id: String,
+    issue_link: String,
+    summary: String,
+    rules: Option<RedactionRules>,
+) -> CmdResult<SessionSummaryResult> {
+    let rules = rules.unwrap_or_default();
+    let (Some(repo), Some(number)) = (
+        parse_repo_from_url(&issue_link),
+        parse_issue_number(&issue_link),
+    ) else {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Not an issue URL: {}",
+            issue_link
+        ))));
+    };
+
+    if summary.trim().is_empty() {
+        return Err(to_cmd_err(CommanderError::internal("Summary is empty")));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let forge = forge_for_repo(&repo);
+        forge.require_auth()?;
+
+        let path = find_session_file(&session_id)?;
+        let (summary, _) = redact_session_text(&path, &rules, &summary)?;
+        let comment_url = forge.add_issue_comment(&repo, number, &summary)?;
+
+        Ok(SessionSummaryResult {
+            summary,
+            comment_url,
+        })
+    })
+    .await
+    .map_err(|e| to_cmd_err(CommanderError::internal(e)))?
+}
+
+/// Plain-text rendering of the transcript for the summariser.
+fn transcript_text(turns: &[SessionTurn]) -> String {
+    let mut out = String::new();
+    for turn in turns {
+        if !turn.content.is_empty() {
+            out.push_str(&format!("{}: {}\n", turn.role, turn.content));
+        }
+        for call in &turn.tool_calls {
+            out.push_str(&format!("[tool {}] {}\n", call.name, call.input));
+        }
+    }
+    let len = out.chars().count();
+    if len > MAX_TRANSCRIPT_CHARS {
+        out = out.chars().skip(len - MAX_TRANSCRIPT_CHARS).collect();
+    }
+    out
+}
+
+/// Pipe the transcript into `claude -p`.  `None` on any failure or timeout.
+fn summarize_with_claude(turns: &[SessionTurn]) -> Option<String> {
+    let mut child = Command::new(find_claude_bin())
+        .args(["-p", SUMMARY_PROMPT])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // Write stdin from a separate thread so a full stdout pipe can't deadlock us.
+    let input = transcript_text(turns);
+    let mut stdin = child.stdin.take()?;
+    std::thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    let deadline = Instant::now() + CLAUDE_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(250)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    }
+
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Fallback summary built from the transcript itself: the opening request,
+/// the files edited, tool usage, and the agent's final message.
+fn extractive_summary(turns: &[SessionTurn]) -> String {
+    let mut bullets = Vec::new();
+
+    if let Some(first) = turns.iter().find(|t| t.role == "user") {
+        bullets.push(format!("- **Request:** {}", truncate(&first.content, 300)));
+    }
+
+    let mut files: BTreeSet<String> = BTreeSet::new();
+    let mut tool_count = 0;
+    for call in turns.iter().flat_map(|t| &t.tool_calls) {
+        tool_count += 1;
+        if matches!(
+            call.name.as_str(),
+            "Write" | "Edit" | "MultiEdit" | "NotebookEdit"
+        ) {
+            if let Ok(input) = serde_json::from_str::<serde_json::Value>(&call.input) {
+                if let Some(p) = input["file_path"]
+                    .as_str()
+                    .or_else(|| input["notebook_path"].as_str())
+                {
+                    files.insert(p.to_string());
+                }
+            }
+        }
+    }
+    if !files.is_empty() {
+        let list: Vec<String> = files.iter().take(15).map(|f| format!("`{}`", f)).collect();
+        let more = files.len().saturating_sub(15);
+        bullets.push(format!(
+            "- **Files changed ({}):** {}{}",
+            files.len(),
+            list.join(", "),
+            if more > 0 {
+                format!(" and {} more", more)
+            } else {
+                String::new()
+            }
+        ));
+    }
+    if tool_count > 0 {
+        bullets.push(format!("- **Tool calls:** {}", tool_count));
+    }
+
+    if let Some(last) = turns
+        .iter()
+        .rev()
+        .find(|t| t.role == "assistant" && !t.content.is_empty())
+    {
+        bullets.push(format!("- **Outcome:** {}", truncate(&last.content, 500)));
+    }
+
+    bullets.join("\n")
+}
+
+fn format_comment(session_id: &str, turns: &[SessionTurn], bullets: &str) -> String {
+    let started = turns.first().map(|t| t.timestamp.as_str()).unwrap_or("");
+    let ended = turns.last().map(|t| t.timestamp.as_str()).unwrap_or("");
+    format!(
+        "### Claude session summary\n\n_Session `{}` · {} turns · {} → {}_\n\n{}\n",
+        session_id,
+        turns.len(),
+        started,
+        ended,
+        bullets
+    )
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    let one_line = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    if one_line.chars().count() <= max_chars {
+        one_line
+    } else {
+        let cut: String = one_line.chars().take(max_chars).collect();
+        format!("{}…", cut)
+    }
+}
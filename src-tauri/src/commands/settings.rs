@@ -1,7 +1,13 @@
+use crate::commands::claude::{restart_claude_watcher, set_extra_claude_dirs};
+use crate::commands::terminal::EDITORS;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::models::AppSettings;
+use crate::models::{AppSettings, DashboardWidget, DASHBOARD_WIDGET_IDS};
+use crate::services::notifications::PRIORITIES;
 use crate::state::AppState;
-use tauri::State;
+use std::collections::{HashMap, HashSet};
+use commander_core::session::DEFAULT_CLAUDE_SOURCE;
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
 
 #[tauri::command]
 pub fn get_settings(state: State<AppState>) -> CmdResult<AppSettings> {
@@ -12,14 +18,29 @@ pub fn get_settings(state: State<AppState>) -> CmdResult<AppSettings> {
 
     let defaults = AppSettings::default();
 
-    let scan_path = get_setting(conn, "scan_path")
+    let legacy_scan_path = get_setting(conn, "scan_path")
         .unwrap_or(defaults.scan_path.clone());
+    let scan_paths = get_json_setting::<Vec<String>>(conn, "scan_paths")
+        .filter(|paths| !paths.is_empty())
+        .unwrap_or_else(|| legacy_scan_path.clone().into_iter().collect());
+    let scan_path = scan_paths.first().cloned().or(legacy_scan_path);
+    let project_markers = get_json_setting::<Vec<String>>(conn, "project_markers")
+        .filter(|markers| !markers.is_empty())
+        .unwrap_or(defaults.project_markers.clone());
+    // An explicitly empty list is valid here: it means "exclude nothing".
+    let scan_exclude_patterns = get_json_setting::<Vec<String>>(conn, "scan_exclude_patterns")
+        .unwrap_or(defaults.scan_exclude_patterns.clone());
+    let allowed_roots = get_json_setting::<Vec<String>>(conn, "allowed_roots")
+        .unwrap_or_default();
     let theme = get_setting(conn, "theme")
         .unwrap_or(Some(defaults.theme.clone()))
         .unwrap_or(defaults.theme.clone());
     let terminal = get_setting(conn, "terminal")
         .unwrap_or(Some(defaults.terminal.clone()))
         .unwrap_or(defaults.terminal.clone());
+    let editor = get_setting(conn, "editor")
+        .flatten()
+        .unwrap_or(defaults.editor.clone());
     let onboarding_completed = get_setting(conn, "onboarding_completed")
         .flatten()
         .map(|v| v == "true")
@@ -28,31 +49,285 @@ pub fn get_settings(state: State<AppState>) -> CmdResult<AppSettings> {
         .flatten()
         .map(|v| v == "true")
         .unwrap_or(true); // default: prompt is on
+    let update_check_frequency = get_setting(conn, "update_check_frequency")
+        .flatten()
+        .unwrap_or(defaults.update_check_frequency.clone());
+    let offline_mode = get_setting(conn, "offline_mode")
+        .flatten()
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    let last_update_check = get_setting(conn, "last_update_check").flatten();
+    let pty_max_sessions = get_setting(conn, "pty_max_sessions")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.pty_max_sessions);
+    let pty_max_output_kb_per_sec = get_setting(conn, "pty_max_output_kb_per_sec")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.pty_max_output_kb_per_sec);
+    let pty_max_output_mb = get_setting(conn, "pty_max_output_mb")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.pty_max_output_mb);
+    let auto_follow_sessions = get_setting(conn, "auto_follow_sessions")
+        .flatten()
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    let notification_digest_minutes = get_setting(conn, "notification_digest_minutes")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.notification_digest_minutes);
+    // Stored overrides are merged over the defaults so new triggers get a priority.
+    let mut notification_priorities = defaults.notification_priorities.clone();
+    notification_priorities.extend(
+        get_json_setting::<HashMap<String, String>>(conn, "notification_priorities")
+            .unwrap_or_default(),
+    );
+    let auto_archive_inactive_days = get_setting(conn, "auto_archive_inactive_days")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.auto_archive_inactive_days);
+    let sync_rules = get_json_setting(conn, "sync_rules").unwrap_or(defaults.sync_rules.clone());
+    let extra_claude_dirs = get_json_setting(conn, "extra_claude_dirs").unwrap_or_default();
+    let monthly_budget_usd = get_setting(conn, "monthly_budget_usd")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.monthly_budget_usd);
+    let weekly_budget_usd = get_setting(conn, "weekly_budget_usd")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.weekly_budget_usd);
+    let history_retention_days = get_setting(conn, "history_retention_days")
+        .flatten()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(defaults.history_retention_days);
+    // Widgets added since the layout was saved are appended, disabled.
+    let mut dashboard_widgets: Vec<DashboardWidget> =
+        get_json_setting(conn, "dashboard_widgets").unwrap_or(defaults.dashboard_widgets.clone());
+    for id in DASHBOARD_WIDGET_IDS {
+        if !dashboard_widgets.iter().any(|w| w.id == *id) {
+            dashboard_widgets.push(DashboardWidget { id: id.to_string(), enabled: false });
+        }
+    }
 
-    Ok(AppSettings { scan_path, theme, terminal, onboarding_completed, github_close_prompt })
+    Ok(AppSettings {
+        scan_path,
+        scan_paths,
+        project_markers,
+        scan_exclude_patterns,
+        allowed_roots,
+        theme,
+        terminal,
+        editor,
+        onboarding_completed,
+        github_close_prompt,
+        update_check_frequency,
+        offline_mode,
+        last_update_check,
+        pty_max_sessions,
+        pty_max_output_kb_per_sec,
+        pty_max_output_mb,
+        sync_rules,
+        auto_follow_sessions,
+        notification_digest_minutes,
+        notification_priorities,
+        auto_archive_inactive_days,
+        extra_claude_dirs,
+        monthly_budget_usd,
+        weekly_budget_usd,
+        dashboard_widgets,
+        history_retention_days,
+    })
 }
 
 #[tauri::command]
-pub fn update_settings(state: State<AppState>, settings: AppSettings) -> CmdResult<()> {
+pub fn update_settings(
+    app: AppHandle,
+    state: State<AppState>,
+    settings: AppSettings,
+) -> CmdResult<()> {
+    validate_settings(&settings)?;
+
     let db = state.db.lock();
     let conn = db
         .as_ref()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
 
-    if let Some(path) = &settings.scan_path {
-        set_setting(conn, "scan_path", path)?;
+    // `scan_paths` is the source of truth; `scan_path` mirrors its first
+    // root.  A client that only sends `scan_path` still gets a one-root list.
+    let mut scan_paths: Vec<String> = settings
+        .scan_paths
+        .iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if scan_paths.is_empty() {
+        scan_paths.extend(settings.scan_path.clone().filter(|p| !p.trim().is_empty()));
     }
+    if let Some(first) = scan_paths.first() {
+        set_setting(conn, "scan_path", first)?;
+    }
+    set_json_setting(conn, "scan_paths", &scan_paths)?;
+    set_json_setting(conn, "project_markers", &settings.project_markers)?;
+    set_json_setting(conn, "scan_exclude_patterns", &settings.scan_exclude_patterns)?;
+    set_json_setting(conn, "allowed_roots", &settings.allowed_roots)?;
     set_setting(conn, "theme", &settings.theme)?;
     set_setting(conn, "terminal", &settings.terminal)?;
+    set_setting(conn, "editor", &settings.editor)?;
     set_setting(conn, "onboarding_completed",
         if settings.onboarding_completed { "true" } else { "false" })?;
     set_setting(conn, "github_close_prompt",
         if settings.github_close_prompt { "true" } else { "false" })?;
+    set_setting(conn, "update_check_frequency", &settings.update_check_frequency)?;
+    set_setting(conn, "offline_mode",
+        if settings.offline_mode { "true" } else { "false" })?;
+    // `last_update_check` is owned by the update scheduler and never written here.
+    set_setting(conn, "pty_max_sessions", &settings.pty_max_sessions.to_string())?;
+    set_setting(conn, "pty_max_output_kb_per_sec",
+        &settings.pty_max_output_kb_per_sec.to_string())?;
+    set_setting(conn, "pty_max_output_mb", &settings.pty_max_output_mb.to_string())?;
+    set_json_setting(conn, "sync_rules", &settings.sync_rules)?;
+    set_setting(conn, "auto_follow_sessions",
+        if settings.auto_follow_sessions { "true" } else { "false" })?;
+    set_setting(conn, "notification_digest_minutes",
+        &settings.notification_digest_minutes.to_string())?;
+    set_json_setting(conn, "notification_priorities", &settings.notification_priorities)?;
+    set_setting(conn, "auto_archive_inactive_days",
+        &settings.auto_archive_inactive_days.to_string())?;
+    set_json_setting(conn, "extra_claude_dirs", &settings.extra_claude_dirs)?;
+    set_setting(conn, "monthly_budget_usd", &settings.monthly_budget_usd.to_string())?;
+    set_setting(conn, "weekly_budget_usd", &settings.weekly_budget_usd.to_string())?;
+    set_json_setting(conn, "dashboard_widgets", &settings.dashboard_widgets)?;
+    set_setting(conn, "history_retention_days",
+        &settings.history_retention_days.to_string())?;
+    drop(db);
+
+    // Listings pick the new roots up immediately; the watcher must be rebuilt.
+    set_extra_claude_dirs(settings.extra_claude_dirs);
+    restart_claude_watcher(&app);
 
     Ok(())
 }
 
-fn get_setting(conn: &rusqlite::Connection, key: &str) -> Option<Option<String>> {
+/// Reject invalid values before anything is written, so a bad field never
+/// leaves the settings table half-updated.
+fn validate_settings(settings: &AppSettings) -> CmdResult<()> {
+    const VALID_FREQUENCIES: &[&str] = &["startup", "daily", "weekly", "manual"];
+    if !VALID_FREQUENCIES.contains(&settings.update_check_frequency.as_str()) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid update check frequency: {}",
+            settings.update_check_frequency
+        ))));
+    }
+
+    if !settings.monthly_budget_usd.is_finite() || settings.monthly_budget_usd < 0.0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Monthly budget must be zero or a positive amount",
+        )));
+    }
+    if !settings.weekly_budget_usd.is_finite() || settings.weekly_budget_usd < 0.0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Weekly budget must be zero or a positive amount",
+        )));
+    }
+
+    let mut seen_widgets = HashSet::new();
+    for widget in &settings.dashboard_widgets {
+        if !DASHBOARD_WIDGET_IDS.contains(&widget.id.as_str()) || !seen_widgets.insert(&widget.id) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Invalid or duplicate dashboard widget: {}",
+                widget.id
+            ))));
+        }
+    }
+
+    if !EDITORS.iter().any(|e| e.key == settings.editor) {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid editor: {}",
+            settings.editor
+        ))));
+    }
+
+    // Marker names are joined onto candidate directories, so reject anything
+    // that could walk outside them.
+    if settings
+        .project_markers
+        .iter()
+        .any(|m| m.trim().is_empty() || m.contains('/') || m == "..")
+    {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Project markers must be plain file or directory names",
+        )));
+    }
+
+    if let Some(bad) = settings
+        .scan_exclude_patterns
+        .iter()
+        .find(|p| glob::Pattern::new(p).is_err())
+    {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid scan exclude pattern: {bad}"
+        ))));
+    }
+
+    // Whitelisting `/` would switch path validation off entirely.
+    for root in &settings.allowed_roots {
+        let path = std::path::Path::new(root);
+        if !path.is_absolute() || path.parent().is_none() || !path.is_dir() {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Allowed root must be an existing absolute directory other than /: {root}"
+            ))));
+        }
+    }
+
+    if settings.pty_max_sessions == 0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "pty_max_sessions must be at least 1",
+        )));
+    }
+
+    if settings.notification_digest_minutes == 0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "notification_digest_minutes must be at least 1",
+        )));
+    }
+
+    if let Some((trigger, priority)) = settings
+        .notification_priorities
+        .iter()
+        .find(|(_, p)| !PRIORITIES.contains(&p.as_str()))
+    {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid notification priority for {trigger}: {priority}"
+        ))));
+    }
+
+    let mut labels = std::collections::HashSet::new();
+    for dir in &settings.extra_claude_dirs {
+        let label = dir.label.as_str();
+        if label.trim().is_empty() || label == DEFAULT_CLAUDE_SOURCE || !labels.insert(label) {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Claude dir labels must be unique, non-empty and not \"{DEFAULT_CLAUDE_SOURCE}\": {label}"
+            ))));
+        }
+        if !std::path::Path::new(&dir.path).is_absolute() {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Claude dir must be an absolute path: {}",
+                dir.path
+            ))));
+        }
+    }
+
+    if settings.sync_rules.interval_minutes == 0 {
+        return Err(to_cmd_err(CommanderError::internal(
+            "sync_rules.interval_minutes must be at least 1",
+        )));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn get_setting(conn: &rusqlite::Connection, key: &str) -> Option<Option<String>> {
     conn.query_row(
         "SELECT value FROM settings WHERE key = ?1",
         [key],
@@ -62,7 +337,7 @@ fn get_setting(conn: &rusqlite::Connection, key: &str) -> Option<Option<String>>
     .map(Some)
 }
 
-fn set_setting(conn: &rusqlite::Connection, key: &str, value: &str) -> CmdResult<()> {
+pub(crate) fn set_setting(conn: &rusqlite::Connection, key: &str, value: &str) -> CmdResult<()> {
     conn.execute(
         "INSERT INTO settings (key, value) VALUES (?1, ?2)
          ON CONFLICT(key) DO UPDATE SET value=excluded.value",
@@ -71,3 +346,33 @@ fn set_setting(conn: &rusqlite::Connection, key: &str, value: &str) -> CmdResult
     .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
     Ok(())
 }
+
+/// Extra roots from the `allowed_roots` setting, canonicalized.  Roots that
+/// no longer exist (an unmounted volume) are skipped.
+pub(crate) fn allowed_roots(conn: &rusqlite::Connection) -> Vec<PathBuf> {
+    get_json_setting::<Vec<String>>(conn, "allowed_roots")
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|root| std::fs::canonicalize(root).ok())
+        .collect()
+}
+
+/// Read a JSON-encoded setting; `None` when unset or unparsable.
+pub(crate) fn get_json_setting<T: serde::de::DeserializeOwned>(
+    conn: &rusqlite::Connection,
+    key: &str,
+) -> Option<T> {
+    get_setting(conn, key)
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// Store `value` as a JSON string (used for list-valued settings).
+pub(crate) fn set_json_setting<T: serde::Serialize>(
+    conn: &rusqlite::Connection,
+    key: &str,
+    value: &T,
+) -> CmdResult<()> {
+    let json = serde_json::to_string(value).map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    set_setting(conn, key, &json)
+}
@@ -0,0 +1,328 @@
+use crate::commands::claude::{claude_root, modified_at};
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{Project, SlashCommand, SlashCommandFile};
+use crate::state::AppState;
+use crate::utils::write_file_atomic;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use walkdir::WalkDir;
+
+const SCOPE_USER: &str = "user";
+const SCOPE_PROJECT: &str = "project";
+
+/// Subdirectories namespace commands; deeper trees are not searched.
+const MAX_NAMESPACE_DEPTH: usize = 3;
+
+/// Slash commands from `~/.claude/commands/` and from `.claude/commands/` of
+/// the project `project_id`, or of every project when unset.
+#[tauri::command]
+pub fn list_slash_commands(
+    state: State<AppState>,
+    project_id: Option<String>,
+) -> CmdResult<Vec<SlashCommand>> {
+    let projects = load_projects(&state)?;
+    let mut commands = scan_commands_dir(&user_commands_dir(), SCOPE_USER, None);
+    for project in projects
+        .iter()
+        .filter(|p| project_id.as_deref().is_none_or(|id| id == p.id))
+    {
+        commands.extend(scan_commands_dir(
+            &project_commands_dir(project),
+            SCOPE_PROJECT,
+            Some(project),
+        ));
+    }
+    Ok(commands)
+}
+
+/// One command with its raw text and the body below the frontmatter.
+#[tauri::command]
+pub fn read_slash_command(
+    state: State<AppState>,
+    scope: String,
+    project_id: Option<String>,
+    name: String,
+) -> CmdResult<SlashCommandFile> {
+    let (dir, project) = resolve_dir(&state, &scope, project_id.as_deref())?;
+    let path = command_path(&dir, &name)?;
+    let content = std::fs::read_to_string(&path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    let (_, body) =
+        split_frontmatter(&content).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    Ok(SlashCommandFile {
+        body: body.to_string(),
+        command: describe(&dir, &path, &content, &scope, project.as_ref()),
+        content,
+    })
+}
+
+/// Create a new command; fails if one with that name already exists.
+#[tauri::command]
+pub fn create_slash_command(
+    state: State<AppState>,
+    scope: String,
+    project_id: Option<String>,
+    name: String,
+    content: String,
+) -> CmdResult<SlashCommand> {
+    split_frontmatter(&content).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    let (dir, project) = resolve_dir(&state, &scope, project_id.as_deref())?;
+    let path = command_path(&dir, &name)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    Ok(describe(&dir, &path, &content, &scope, project.as_ref()))
+}
+
+/// Overwrite an existing command.  With `expected_modified_at`, a file
+/// changed since it was read is left alone and a `CONFLICT` is returned.
+#[tauri::command]
+pub fn update_slash_command(
+    state: State<AppState>,
+    scope: String,
+    project_id: Option<String>,
+    name: String,
+    content: String,
+    expected_modified_at: Option<String>,
+) -> CmdResult<SlashCommand> {
+    split_frontmatter(&content).map_err(|e| to_cmd_err(CommanderError::parse(e)))?;
+    let (dir, project) = resolve_dir(&state, &scope, project_id.as_deref())?;
+    let path = command_path(&dir, &name)?;
+    let current = modified_at(&path).ok_or_else(|| {
+        to_cmd_err(CommanderError::FileNotFound {
+            path: path.to_string_lossy().to_string(),
+        })
+    })?;
+    if expected_modified_at.is_some_and(|expected| expected != current) {
+        return Err(to_cmd_err(CommanderError::Conflict {
+            path: path.to_string_lossy().to_string(),
+        }));
+    }
+    write_file_atomic(&path, content.clone())?;
+    Ok(describe(&dir, &path, &content, &scope, project.as_ref()))
+}
+
+fn user_commands_dir() -> PathBuf {
+    claude_root(None).join("commands")
+}
+
+fn project_commands_dir(project: &Project) -> PathBuf {
+    Path::new(&project.path).join(".claude").join("commands")
+}
+
+fn load_projects(state: &AppState) -> CmdResult<Vec<Project>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    load_db_projects(conn).map_err(to_cmd_err)
+}
+
+/// The commands directory for `scope`, plus the project for project scope.
+fn resolve_dir(
+    state: &AppState,
+    scope: &str,
+    project_id: Option<&str>,
+) -> CmdResult<(PathBuf, Option<Project>)> {
+    match (scope, project_id) {
+        (SCOPE_USER, _) => Ok((user_commands_dir(), None)),
+        (SCOPE_PROJECT, Some(id)) => {
+            let project = load_projects(state)?
+                .into_iter()
+                .find(|p| p.id == id)
+                .ok_or_else(|| {
+                    to_cmd_err(CommanderError::internal(format!("Unknown project: {id}")))
+                })?;
+            Ok((project_commands_dir(&project), Some(project)))
+        }
+        (SCOPE_PROJECT, None) => Err(to_cmd_err(CommanderError::internal(
+            "Project commands need a project_id",
+        ))),
+        _ => Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid command scope: {scope}"
+        )))),
+    }
+}
+
+/// `name` is the command's path below the commands directory without
+/// `.md`, e.g. `"review"` or `"frontend/component"`.  Each segment must be
+/// a plain file name so the result stays inside `dir`.
+fn command_path(dir: &Path, name: &str) -> CmdResult<PathBuf> {
+    let segments: Vec<&str> = name.split('/').collect();
+    let valid = segments.len() <= MAX_NAMESPACE_DEPTH + 1
+        && segments.iter().all(|s| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+                && !s.starts_with('.')
+        });
+    if !valid {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid command name: {name}"
+        ))));
+    }
+    Ok(dir.join(format!("{name}.md")))
+}
+
+fn scan_commands_dir(dir: &Path, scope: &str, project: Option<&Project>) -> Vec<SlashCommand> {
+    let mut commands: Vec<SlashCommand> = WalkDir::new(dir)
+        .max_depth(MAX_NAMESPACE_DEPTH + 1)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().and_then(|x| x.to_str()) == Some("md"))
+        .filter_map(|e| {
+            let content = std::fs::read_to_string(e.path()).ok()?;
+            Some(describe(dir, e.path(), &content, scope, project))
+        })
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    commands
+}
+
+fn describe(
+    dir: &Path,
+    path: &Path,
+    content: &str,
+    scope: &str,
+    project: Option<&Project>,
+) -> SlashCommand {
+    let name = path
+        .strip_prefix(dir)
+        .unwrap_or(path)
+        .with_extension("")
+        .to_string_lossy()
+        .replace('\\', "/");
+    // A malformed header still lists the command, just without metadata.
+    let frontmatter = split_frontmatter(content)
+        .map(|(fields, _)| fields)
+        .unwrap_or_default();
+    SlashCommand {
+        name,
+        scope: scope.to_string(),
+        project_id: project.map(|p| p.id.clone()),
+        project_name: project.map(|p| p.name.clone()),
+        path: path.to_string_lossy().to_string(),
+        description: frontmatter.get("description").cloned(),
+        argument_hint: frontmatter.get("argument-hint").cloned(),
+        allowed_tools: frontmatter.get("allowed-tools").cloned(),
+        model: frontmatter.get("model").cloned(),
+        modified_at: modified_at(path),
+        frontmatter,
+    }
+}
+
+/// Split a command file into its frontmatter fields and body.  Frontmatter
+/// is the simple YAML subset Claude Code commands use: `key: value` lines,
+/// optionally quoted, with `- item` lines continuing the previous key's
+/// list (joined with `", "`).  A file without a `---` header is all body.
+pub(crate) fn split_frontmatter(content: &str) -> Result<(BTreeMap<String, String>, &str), String> {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return Ok((BTreeMap::new(), content));
+    };
+    // The closing fence is the first `---` line, which may be the very next
+    // line when the header is empty.
+    let end = std::iter::once(0)
+        .chain(rest.match_indices('\n').map(|(i, _)| i + 1))
+        .find(|&i| {
+            rest[i..].strip_prefix("---").is_some_and(|after| {
+                after.is_empty() || after.starts_with('\n') || after.starts_with("\r\n")
+            })
+        })
+        .ok_or("Frontmatter is missing its closing ---")?;
+    let header = &rest[..end];
+    let body = rest[end + 3..].trim_start_matches(['\r', '\n']);
+
+    let mut fields = BTreeMap::new();
+    let mut last_key: Option<String> = None;
+    for (n, line) in header.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            let key = last_key.as_ref().ok_or(format!(
+                "Frontmatter line {}: list item without a key",
+                n + 1
+            ))?;
+            let value: &mut String = fields.entry(key.clone()).or_default();
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+            value.push_str(unquote(item.trim()));
+            continue;
+        }
+        let (key, value) = trimmed
+            .split_once(':')
+            .ok_or(format!("Frontmatter line {}: expected `key: value`", n + 1))?;
+        let key = key.trim().to_string();
+        if key.is_empty() {
+            return Err(format!("Frontmatter line {}: empty key", n + 1));
+        }
+        fields.insert(key.clone(), unquote(value.trim()).to_string());
+        last_key = Some(key);
+    }
+    Ok((fields, body))
+}
+
+pub(crate) fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+        .unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_frontmatter() {
+        let (fields, body) = split_frontmatter("---\n---\nRun the tests.\n").unwrap();
+        assert!(fields.is_empty());
+        assert_eq!(body, "Run the tests.\n");
+
+        let (fields, body) = split_frontmatter("---\r\n---\r\nBody").unwrap();
+        assert!(fields.is_empty());
+        assert_eq!(body, "Body");
+
+        let (fields, body) = split_frontmatter("---\n---").unwrap();
+        assert!(fields.is_empty());
+        assert_eq!(body, "");
+    }
+
+    #[test]
+    fn frontmatter_fields_and_body() {
+        let content =
+            "---\ndescription: \"Review a PR\"\nallowed-tools:\n  - Bash\n  - Read\n---\n\nBody\n";
+        let (fields, body) = split_frontmatter(content).unwrap();
+        assert_eq!(fields["description"], "Review a PR");
+        assert_eq!(fields["allowed-tools"], "Bash, Read");
+        assert_eq!(body, "Body\n");
+    }
+
+    #[test]
+    fn no_header_is_all_body() {
+        let (fields, body) = split_frontmatter("Just text\n---\n").unwrap();
+        assert!(fields.is_empty());
+        assert_eq!(body, "Just text\n---\n");
+    }
+
+    #[test]
+    fn unclosed_header_is_an_error() {
+        assert!(split_frontmatter("---\ndescription: x\n").is_err());
+        assert!(split_frontmatter("---\n----\nbody").is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::commands::claude::{claude_root, read_all_plans, read_all_task_files};
+use crate::commands::github::fetch_issue_states_batched;
+use crate::commands::issue_cache::cache_issue_state;
+use crate::commands::settings::get_json_setting;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{StatusSyncAction, SyncRules};
+use crate::services::forge::forge_for_repo;
+use crate::state::AppState;
+use std::collections::HashSet;
+use tauri::State;
+
+pub(crate) const RULE_ITEM_DONE_CLOSES_ISSUE: &str = "item_done_closes_issue";
+pub(crate) const RULE_ISSUE_CLOSED_MARKS_ITEM_DONE: &str = "issue_closed_marks_item_done";
+pub(crate) const RULE_TASK_COMPLETED_CHECKS_PLAN: &str = "task_completed_checks_plan";
+
+/// Planning item that was imported from (or linked to) a GitHub issue.
+struct LinkedItem {
+    id: String,
+    subject: String,
+    done: bool,
+    repo: String,
+    number: i64,
+}
+
+/// Dry run: list the changes the sync engine would make, without making them.
+#[tauri::command]
+pub fn preview_status_sync(state: State<AppState>) -> CmdResult<Vec<StatusSyncAction>> {
+    let rules = load_sync_rules(&state)?;
+    plan_status_sync(&state, &rules)
+}
+
+/// Run the sync engine once and report each change with its outcome.
+#[tauri::command]
+pub fn run_status_sync(state: State<AppState>) -> CmdResult<Vec<StatusSyncAction>> {
+    let rules = load_sync_rules(&state)?;
+    let mut actions = plan_status_sync(&state, &rules)?;
+    apply_status_sync(&state, &mut actions);
+    Ok(actions)
+}
+
+pub(crate) fn load_sync_rules(state: &AppState) -> CmdResult<SyncRules> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    Ok(get_json_setting(conn, "sync_rules").unwrap_or_default())
+}
+
+/// Work out every action the enabled rules call for.  Issue states are read
+/// live from `gh`; issues that can't be fetched are skipped.
+pub(crate) fn plan_status_sync(
+    state: &AppState,
+    rules: &SyncRules,
+) -> CmdResult<Vec<StatusSyncAction>> {
+    let mut actions = Vec::new();
+
+    if rules.item_done_closes_issue || rules.issue_closed_marks_item_done {
+        let items = {
+            let db = state.db.lock();
+            let conn = db
+                .as_ref()
+                .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+            load_linked_items(conn).map_err(to_cmd_err)?
+        };
+
+        // Only hit the network for items whose answer could produce an action.
+        let items: Vec<LinkedItem> = items
+            .into_iter()
+            .filter(|item| {
+                if item.done {
+                    rules.item_done_closes_issue
+                } else {
+                    rules.issue_closed_marks_item_done
+                }
+            })
+            .collect();
+        let (states, errors) =
+            fetch_issue_states_batched(items.iter().map(|i| (i.repo.as_str(), i.number)));
+        for error in errors {
+            log::warn!(
+                "status sync: could not fetch issue states of {}: {}",
+                error.repo,
+                error.message
+            );
+        }
+
+        for item in items {
+            let Some(issue_state) = states.get(&(item.repo.clone(), item.number)) else {
+                continue;
+            };
+
+            let (rule, description) = match (item.done, issue_state.as_str()) {
+                (true, "open") => (
+                    RULE_ITEM_DONE_CLOSES_ISSUE,
+                    format!(
+                        "Close {}#{} — \"{}\" is done",
+                        item.repo, item.number, item.subject
+                    ),
+                ),
+                (false, "closed") => (
+                    RULE_ISSUE_CLOSED_MARKS_ITEM_DONE,
+                    format!(
+                        "Mark \"{}\" done — {}#{} was closed",
+                        item.subject, item.repo, item.number
+                    ),
+                ),
+                _ => continue,
+            };
+
+            actions.push(StatusSyncAction {
+                rule: rule.to_string(),
+                description,
+                planning_item_id: Some(item.id),
+                github_repo: Some(item.repo),
+                github_issue_number: Some(item.number),
+                plan_filename: None,
+                plan_source: None,
+                task_subject: None,
+                applied: false,
+                error: None,
+            });
+        }
+    }
+
+    if rules.task_completed_checks_plan {
+        actions.extend(plan_checkbox_actions()?);
+    }
+
+    Ok(actions)
+}
+
+/// Carry out planned actions in place, recording success or the error on each.
+pub(crate) fn apply_status_sync(state: &AppState, actions: &mut [StatusSyncAction]) {
+    for action in actions.iter_mut() {
+        let result = match action.rule.as_str() {
+            RULE_ITEM_DONE_CLOSES_ISSUE => close_issue(state, action),
+            RULE_ISSUE_CLOSED_MARKS_ITEM_DONE => mark_item_done(state, action),
+            RULE_TASK_COMPLETED_CHECKS_PLAN => check_plan_item(action),
+            other => Err(format!("Unknown sync rule: {other}")),
+        };
+        match result {
+            Ok(()) => action.applied = true,
+            Err(e) => action.error = Some(e),
+        }
+    }
+}
+
+fn load_linked_items(conn: &rusqlite::Connection) -> Result<Vec<LinkedItem>, CommanderError> {
+    let mut stmt = conn.prepare(
+        "SELECT pi.id, pi.subject, pi.status, l.github_repo, l.github_issue_number
+         FROM planning_items pi
+         JOIN planning_item_github_links l ON l.item_id = pi.id
+         WHERE l.github_repo IS NOT NULL AND l.github_issue_number IS NOT NULL",
+    )?;
+    let items = stmt
+        .query_map([], |row| {
+            let status: String = row.get(2)?;
+            Ok(LinkedItem {
+                id: row.get(0)?,
+                subject: row.get(1)?,
+                done: status == "done",
+                repo: row.get(3)?,
+                number: row.get(4)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(items)
+}
+
+/// Unchecked plan checkboxes whose text matches a completed Claude task
+/// from the same Claude root.
+fn plan_checkbox_actions() -> CmdResult<Vec<StatusSyncAction>> {
+    let completed: HashSet<(String, String)> = read_all_task_files()?
+        .into_iter()
+        .flat_map(|file| file.tasks)
+        .filter(|t| t.status == "completed")
+        .map(|t| (t.source, normalize_subject(&t.subject)))
+        .collect();
+    if completed.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut actions = Vec::new();
+    for plan in read_all_plans()? {
+        for line in plan.content.lines() {
+            let Some(text) = unchecked_checkbox_text(line) else {
+                continue;
+            };
+            if !completed.contains(&(plan.source.clone(), normalize_subject(text))) {
+                continue;
+            }
+            actions.push(StatusSyncAction {
+                rule: RULE_TASK_COMPLETED_CHECKS_PLAN.to_string(),
+                description: format!("Check off \"{}\" in {}", text, plan.filename),
+                planning_item_id: None,
+                github_repo: None,
+                github_issue_number: None,
+                plan_filename: Some(plan.filename.clone()),
+                plan_source: Some(plan.source.clone()),
+                task_subject: Some(text.to_string()),
+                applied: false,
+                error: None,
+            });
+        }
+    }
+    Ok(actions)
+}
+
+/// Text of a `- [ ] …` / `* [ ] …` markdown checkbox line.
+fn unchecked_checkbox_text(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let rest = trimmed
+        .strip_prefix("- [ ] ")
+        .or_else(|| trimmed.strip_prefix("* [ ] "))?;
+    Some(rest.trim())
+}
+
+fn normalize_subject(s: &str) -> String {
+    s.trim().to_lowercase()
+}
+
+fn close_issue(state: &AppState, action: &StatusSyncAction) -> Result<(), String> {
+    let (Some(repo), Some(number)) = (&action.github_repo, action.github_issue_number) else {
+        return Err("Action has no linked issue".to_string());
+    };
+
+    forge_for_repo(repo).close_issue(repo, number)?;
+
+    // Keep task and card links pointing at the same issue in step with the
+    // new state.
+    let db = state.db.lock();
+    if let Some(conn) = db.as_ref() {
+        let now = chrono::Utc::now().to_rfc3339();
+        for table in ["task_github_links", "planning_item_github_links"] {
+            let _ = conn.execute(
+                &format!(
+                    "UPDATE {table}
+                     SET github_issue_state = 'closed', state_updated_at = ?1
+                     WHERE github_repo = ?2 AND github_issue_number = ?3"
+                ),
+                rusqlite::params![now, repo, number],
+            );
+        }
+        let _ = cache_issue_state(conn, repo, number, "closed");
+    }
+    Ok(())
+}
+
+fn mark_item_done(state: &AppState, action: &StatusSyncAction) -> Result<(), String> {
+    let Some(id) = &action.planning_item_id else {
+        return Err("Action has no planning item".to_string());
+    };
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "UPDATE planning_items SET status = 'done', updated_at = datetime('now') WHERE id = ?1",
+        [id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
+
+/// Rewrite the first matching unchecked line as `[x]`, leaving the rest of
+/// the file byte-for-byte intact.
+fn check_plan_item(action: &StatusSyncAction) -> Result<(), String> {
+    let (Some(filename), Some(subject)) = (&action.plan_filename, &action.task_subject) else {
+        return Err("Action has no plan checkbox".to_string());
+    };
+
+    let path = claude_root(action.plan_source.as_deref())
+        .join("plans")
+        .join(filename);
+    let content = std::fs::read_to_string(&path).map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+
+    let wanted = normalize_subject(subject);
+    let mut checked = false;
+    let updated: String = content
+        .split_inclusive('\n')
+        .map(|line| {
+            if !checked
+                && unchecked_checkbox_text(line)
+                    .map(normalize_subject)
+                    .as_deref()
+                    == Some(wanted.as_str())
+            {
+                checked = true;
+                line.replacen("[ ]", "[x]", 1)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    if !checked {
+        return Err(format!(
+            "Checkbox \"{subject}\" no longer found in {filename}"
+        ));
+    }
+    std::fs::write(&path, updated).map_err(|e| to_cmd_err(CommanderError::io(e)))
+}
@@ -0,0 +1,378 @@
+use crate::commands::claude::{read_all_task_files, update_claude_task};
+use crate::commands::projects::load_db_projects;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::AutoSuggestion;
+use crate::state::AppState;
+use std::collections::HashSet;
+use tauri::State;
+
+pub(crate) const KIND_PLANNING_ITEM: &str = "planning_item";
+pub(crate) const KIND_CLAUDE_TASK: &str = "claude_task";
+
+/// Only commits this recent are considered, so the first run after install
+/// does not dig up the whole history.
+const LOOKBACK_HOURS: i64 = 24;
+
+/// Commits read per project on each pass.
+const MAX_COMMITS: usize = 50;
+
+/// Subjects shorter than this are too generic to match inside a commit
+/// message ("fix", "tests", ...).
+const MIN_SUBJECT_MATCH_LEN: usize = 12;
+
+const SUGGESTION_COLUMNS: &str =
+    "id, kind, target_id, team_id, source, project_id, title, reason, commit_id, created_at";
+
+/// Pending suggestions, newest first.
+#[tauri::command]
+pub fn get_suggestions(state: State<AppState>) -> CmdResult<Vec<AutoSuggestion>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {SUGGESTION_COLUMNS} FROM auto_suggestions
+             WHERE status = 'pending' ORDER BY created_at DESC"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let suggestions = stmt
+        .query_map([], row_to_suggestion)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(suggestions)
+}
+
+/// Carry out a suggestion: mark the planning item `done` or write
+/// `completed` into the Claude task file.
+#[tauri::command]
+pub fn accept_suggestion(state: State<AppState>, id: String) -> CmdResult<AutoSuggestion> {
+    let suggestion = load_suggestion(&state, &id)?;
+    match suggestion.kind.as_str() {
+        KIND_PLANNING_ITEM => {
+            let db = state.db.lock();
+            let conn = db
+                .as_ref()
+                .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+            conn.execute(
+                "UPDATE planning_items SET status = 'done', updated_at = datetime('now')
+                 WHERE id = ?1",
+                [&suggestion.target_id],
+            )
+            .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+        }
+        KIND_CLAUDE_TASK => {
+            let team_id = suggestion.team_id.clone().ok_or_else(|| {
+                to_cmd_err(CommanderError::internal("Task suggestion has no team"))
+            })?;
+            update_claude_task(
+                team_id,
+                suggestion.target_id.clone(),
+                Some("completed".to_string()),
+                None,
+                suggestion.source.clone(),
+            )?;
+        }
+        other => {
+            return Err(to_cmd_err(CommanderError::internal(format!(
+                "Unknown suggestion kind: {other}"
+            ))))
+        }
+    }
+    set_suggestion_status(&state, &id, "accepted")?;
+    Ok(suggestion)
+}
+
+/// Hide a suggestion.  The same commit will not suggest it again.
+#[tauri::command]
+pub fn dismiss_suggestion(state: State<AppState>, id: String) -> CmdResult<()> {
+    set_suggestion_status(&state, &id, "dismissed")
+}
+
+fn load_suggestion(state: &AppState, id: &str) -> CmdResult<AutoSuggestion> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.query_row(
+        &format!("SELECT {SUGGESTION_COLUMNS} FROM auto_suggestions WHERE id = ?1"),
+        [id],
+        row_to_suggestion,
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))
+}
+
+fn set_suggestion_status(state: &AppState, id: &str, status: &str) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    conn.execute(
+        "UPDATE auto_suggestions SET status = ?1 WHERE id = ?2",
+        rusqlite::params![status, id],
+    )
+    .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
+
+fn row_to_suggestion(row: &rusqlite::Row) -> rusqlite::Result<AutoSuggestion> {
+    Ok(AutoSuggestion {
+        id: row.get(0)?,
+        kind: row.get(1)?,
+        target_id: row.get(2)?,
+        team_id: row.get(3)?,
+        source: row.get(4)?,
+        project_id: row.get(5)?,
+        title: row.get(6)?,
+        reason: row.get(7)?,
+        commit_id: row.get(8)?,
+        created_at: row.get(9)?,
+    })
+}
+
+/// In-progress planning item, with what a commit can match it by.
+struct OpenItem {
+    id: String,
+    project_id: String,
+    subject: String,
+    issue_number: Option<i64>,
+    /// Path-like words from the description, e.g. `src/auth/middleware.rs`.
+    files: Vec<String>,
+}
+
+struct RecentCommit {
+    short_id: String,
+    message: String,
+    files: Vec<String>,
+}
+
+/// Scan recent commits in every project for references to in-progress
+/// planning items (issue number, subject, or a file named in the item's
+/// description) and in-progress Claude tasks (subject), and store a
+/// suggestion for each new match.  Targets that already have a pending
+/// suggestion are skipped.  Returns the suggestions created by this pass.
+pub(crate) fn detect_suggestions(state: &AppState) -> Result<Vec<AutoSuggestion>, CommanderError> {
+    let (projects, items, pending) = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+        (
+            load_db_projects(conn)?,
+            load_open_items(conn)?,
+            load_pending_targets(conn)?,
+        )
+    };
+    let tasks: Vec<_> = read_all_task_files()
+        .map_err(CommanderError::internal)?
+        .into_iter()
+        .flat_map(|file| {
+            let team_id = file.team_id;
+            file.tasks
+                .into_iter()
+                .filter(|t| t.status == "in_progress")
+                .map(move |t| (team_id.clone(), t))
+        })
+        .collect();
+
+    let mut found = Vec::new();
+    for project in &projects {
+        let project_items: Vec<&OpenItem> = items
+            .iter()
+            .filter(|i| i.project_id == project.id)
+            .collect();
+        if project_items.is_empty() && tasks.is_empty() {
+            continue;
+        }
+        let Ok(commits) = recent_commits(&project.path) else {
+            continue;
+        };
+        for commit in &commits {
+            let message = commit.message.to_lowercase();
+            for item in &project_items {
+                let Some(reason) = match_item(item, commit, &message) else {
+                    continue;
+                };
+                found.push(AutoSuggestion {
+                    id: format!("{KIND_PLANNING_ITEM}:{}:{}", item.id, commit.short_id),
+                    kind: KIND_PLANNING_ITEM.to_string(),
+                    target_id: item.id.clone(),
+                    team_id: None,
+                    source: None,
+                    project_id: Some(project.id.clone()),
+                    title: item.subject.clone(),
+                    reason,
+                    commit_id: commit.short_id.clone(),
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                });
+            }
+            for (team_id, task) in &tasks {
+                let subject = task.subject.trim().to_lowercase();
+                if subject.len() < MIN_SUBJECT_MATCH_LEN || !message.contains(&subject) {
+                    continue;
+                }
+                found.push(AutoSuggestion {
+                    id: format!(
+                        "{KIND_CLAUDE_TASK}:{}:{team_id}:{}:{}",
+                        task.source, task.id, commit.short_id
+                    ),
+                    kind: KIND_CLAUDE_TASK.to_string(),
+                    target_id: task.id.clone(),
+                    team_id: Some(team_id.clone()),
+                    source: Some(task.source.clone()),
+                    project_id: Some(project.id.clone()),
+                    title: task.subject.clone(),
+                    reason: format!(
+                        "Commit {} in {} mentions this task",
+                        commit.short_id, project.name
+                    ),
+                    commit_id: commit.short_id.clone(),
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                });
+            }
+        }
+    }
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| CommanderError::internal("DB not initialized"))?;
+    let mut pending = pending;
+    let mut created = Vec::new();
+    for suggestion in found {
+        let target = target_key(&suggestion);
+        if pending.contains(&target) {
+            continue;
+        }
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO auto_suggestions
+                 (id, kind, target_id, team_id, source, project_id, title, reason, commit_id,
+                  status, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 'pending', ?10)",
+            rusqlite::params![
+                suggestion.id,
+                suggestion.kind,
+                suggestion.target_id,
+                suggestion.team_id,
+                suggestion.source,
+                suggestion.project_id,
+                suggestion.title,
+                suggestion.reason,
+                suggestion.commit_id,
+                suggestion.created_at,
+            ],
+        )?;
+        if inserted > 0 {
+            pending.insert(target);
+            created.push(suggestion);
+        }
+    }
+    Ok(created)
+}
+
+/// Why `commit` looks like it finishes `item`, if it does.
+fn match_item(item: &OpenItem, commit: &RecentCommit, message: &str) -> Option<String> {
+    if let Some(number) = item.issue_number {
+        let re = regex::Regex::new(&format!(r"#{number}\b")).ok()?;
+        if re.is_match(message) {
+            return Some(format!("Commit {} references #{number}", commit.short_id));
+        }
+    }
+    let subject = item.subject.trim().to_lowercase();
+    if subject.len() >= MIN_SUBJECT_MATCH_LEN && message.contains(&subject) {
+        return Some(format!("Commit {} mentions this item", commit.short_id));
+    }
+    let touched = item
+        .files
+        .iter()
+        .find(|f| commit.files.iter().any(|c| c.ends_with(f.as_str())))?;
+    Some(format!("Commit {} changes {touched}", commit.short_id))
+}
+
+fn target_key(s: &AutoSuggestion) -> String {
+    format!(
+        "{}:{}:{}:{}",
+        s.kind,
+        s.source.as_deref().unwrap_or_default(),
+        s.team_id.as_deref().unwrap_or_default(),
+        s.target_id
+    )
+}
+
+fn load_pending_targets(conn: &rusqlite::Connection) -> Result<HashSet<String>, CommanderError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {SUGGESTION_COLUMNS} FROM auto_suggestions WHERE status = 'pending'"
+    ))?;
+    let targets = stmt
+        .query_map([], row_to_suggestion)?
+        .filter_map(|r| r.ok())
+        .map(|s| target_key(&s))
+        .collect();
+    Ok(targets)
+}
+
+fn load_open_items(conn: &rusqlite::Connection) -> Result<Vec<OpenItem>, CommanderError> {
+    let path_word = regex::Regex::new(r"[\w.-]+(?:/[\w.-]+)*\.[A-Za-z0-9]{1,6}\b")
+        .map_err(|e| CommanderError::internal(e.to_string()))?;
+    let mut stmt = conn.prepare(
+        "SELECT id, project_id, subject, description, github_issue_number
+         FROM planning_items
+         WHERE status = 'in_progress' AND project_id IS NOT NULL",
+    )?;
+    let items = stmt
+        .query_map([], |row| {
+            let description: Option<String> = row.get(3)?;
+            Ok(OpenItem {
+                id: row.get(0)?,
+                project_id: row.get(1)?,
+                subject: row.get(2)?,
+                issue_number: row.get(4)?,
+                files: description
+                    .as_deref()
+                    .map(|d| {
+                        path_word
+                            .find_iter(d)
+                            .map(|m| m.as_str().to_string())
+                            // A bare "e.g." or version number is not a file.
+                            .filter(|w| w.contains('/') || w.contains('_') || w.len() > 6)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(items)
+}
+
+/// Commits from the last `LOOKBACK_HOURS` on HEAD with the files each one
+/// changed relative to its first parent.
+fn recent_commits(project_path: &str) -> Result<Vec<RecentCommit>, git2::Error> {
+    let repo = git2::Repository::open(project_path)?;
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    let since = chrono::Utc::now().timestamp() - LOOKBACK_HOURS * 3600;
+
+    let mut commits = Vec::new();
+    for oid in revwalk.take(MAX_COMMITS) {
+        let commit = repo.find_commit(oid?)?;
+        if commit.time().seconds() < since {
+            break;
+        }
+        let tree = commit.tree()?;
+        let parent_tree = commit.parent(0).ok().map(|p| p.tree()).transpose()?;
+        let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+        let files = diff
+            .deltas()
+            .filter_map(|d| d.new_file().path().or_else(|| d.old_file().path()))
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        commits.push(RecentCommit {
+            short_id: commit.id().to_string()[..7].to_string(),
+            message: commit.message().unwrap_or_default().to_string(),
+            files,
+        });
+    }
+    Ok(commits)
+}
@@ -0,0 +1,96 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{Project, SyncHistoryEntry, SyncHistoryProject, SyncResult};
+use crate::state::AppState;
+use tauri::State;
+
+/// Older runs are pruned once the table grows past this many rows.
+const MAX_HISTORY_ROWS: i64 = 500;
+
+const HISTORY_COLUMNS: &str = "id, synced_at, added, updated, archived, unchanged_count, \
+     scanned_roots, scan_duration_ms, total_duration_ms";
+
+/// The most recent `limit` syncs (default 50), newest first.
+#[tauri::command]
+pub fn get_sync_history(
+    state: State<AppState>,
+    limit: Option<usize>,
+) -> CmdResult<Vec<SyncHistoryEntry>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {HISTORY_COLUMNS} FROM sync_history ORDER BY id DESC LIMIT ?1"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let entries = stmt
+        .query_map([limit.unwrap_or(50) as i64], row_to_entry)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(entries)
+}
+
+/// Persist a finished sync.  `archived` carries the archived records, which
+/// `SyncResult` only counts.
+pub(crate) fn record_sync(
+    conn: &rusqlite::Connection,
+    result: &SyncResult,
+    archived: &[SyncHistoryProject],
+) -> Result<(), CommanderError> {
+    let added: Vec<SyncHistoryProject> = result.added.iter().map(history_project).collect();
+    let updated: Vec<SyncHistoryProject> = result.updated.iter().map(history_project).collect();
+    conn.execute(
+        "INSERT INTO sync_history (synced_at, added, updated, archived, unchanged_count,
+                                   scanned_roots, scan_duration_ms, total_duration_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            chrono::Utc::now().to_rfc3339(),
+            serde_json::to_string(&added)?,
+            serde_json::to_string(&updated)?,
+            serde_json::to_string(archived)?,
+            result.unchanged_count as i64,
+            serde_json::to_string(&result.scanned_roots)?,
+            result.scan_duration_ms as i64,
+            result.total_duration_ms as i64,
+        ],
+    )?;
+    conn.execute(
+        "DELETE FROM sync_history WHERE id <= (SELECT MAX(id) FROM sync_history) - ?1",
+        [MAX_HISTORY_ROWS],
+    )?;
+    Ok(())
+}
+
+fn history_project(project: &Project) -> SyncHistoryProject {
+    SyncHistoryProject {
+        id: project.id.clone(),
+        name: project.name.clone(),
+        path: project.path.clone(),
+        reason: None,
+    }
+}
+
+fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<SyncHistoryEntry> {
+    let json_list = |idx: usize| -> rusqlite::Result<Vec<SyncHistoryProject>> {
+        let raw: String = row.get(idx)?;
+        Ok(serde_json::from_str(&raw).unwrap_or_default())
+    };
+    let roots: String = row.get(6)?;
+    let unchanged: i64 = row.get(5)?;
+    let scan_ms: i64 = row.get(7)?;
+    let total_ms: i64 = row.get(8)?;
+    Ok(SyncHistoryEntry {
+        id: row.get(0)?,
+        synced_at: row.get(1)?,
+        added: json_list(2)?,
+        updated: json_list(3)?,
+        archived: json_list(4)?,
+        unchanged_count: unchanged as usize,
+        scanned_roots: serde_json::from_str(&roots).unwrap_or_default(),
+        scan_duration_ms: scan_ms as u64,
+        total_duration_ms: total_ms as u64,
+    })
+}
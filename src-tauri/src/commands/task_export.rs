@@ -0,0 +1,141 @@
+use crate::commands::claude::read_all_task_files;
+use crate::commands::github::load_all_links;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{ClaudeTask, TaskGithubLink};
+use crate::state::AppState;
+use crate::utils::write_file_atomic;
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::State;
+
+const CSV_COLUMNS: &[&str] = &[
+    "team_id",
+    "id",
+    "subject",
+    "description",
+    "status",
+    "owner",
+    "active_form",
+    "created_at",
+    "updated_at",
+    "source",
+    "blocked_by",
+    "blocks",
+    "github_issue_number",
+    "github_issue_url",
+    "github_repo",
+    "github_issue_state",
+];
+
+/// Write every task, or one team's, to `path` as CSV or JSON, with the
+/// linked GitHub issue of each.  Deleted tasks are left out.  Returns the
+/// number of tasks written.
+#[tauri::command]
+pub fn export_claude_tasks(
+    state: State<AppState>,
+    team_id: Option<String>,
+    format: String,
+    path: String,
+) -> CmdResult<usize> {
+    if format != "csv" && format != "json" {
+        return Err(to_cmd_err(CommanderError::internal(format!(
+            "Invalid export format: {format}"
+        ))));
+    }
+    let path = Path::new(&path);
+    if !path.is_absolute() {
+        return Err(to_cmd_err(CommanderError::internal(
+            "Export path must be absolute",
+        )));
+    }
+
+    let links: HashMap<(String, String), TaskGithubLink> = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        load_all_links(conn)
+            .map_err(to_cmd_err)?
+            .into_iter()
+            .map(|l| ((l.team_id.clone(), l.task_id.clone()), l))
+            .collect()
+    };
+
+    let mut rows: Vec<(String, ClaudeTask)> = read_all_task_files()?
+        .into_iter()
+        .filter(|f| team_id.as_ref().is_none_or(|t| *t == f.team_id))
+        .flat_map(|f| {
+            let team = f.team_id;
+            f.tasks.into_iter().map(move |t| (team.clone(), t))
+        })
+        .filter(|(_, t)| t.status != "deleted")
+        .collect();
+    rows.sort_by_key(|(team, t)| {
+        (
+            team.clone(),
+            t.id.parse::<u64>().unwrap_or(u64::MAX),
+            t.id.clone(),
+        )
+    });
+
+    let content = if format == "json" {
+        let tasks: Vec<serde_json::Value> = rows
+            .iter()
+            .map(|(team, task)| {
+                let mut value = serde_json::to_value(task).unwrap_or_default();
+                value["team_id"] = team.clone().into();
+                value["github"] = json!(links.get(&(team.clone(), task.id.clone())));
+                if let Some(obj) = value.as_object_mut() {
+                    obj.remove("sort_order");
+                }
+                value
+            })
+            .collect();
+        serde_json::to_string_pretty(&tasks).map_err(|e| to_cmd_err(CommanderError::parse(e)))?
+    } else {
+        let mut csv = CSV_COLUMNS.join(",");
+        csv.push('\n');
+        for (team, task) in &rows {
+            let link = links.get(&(team.clone(), task.id.clone()));
+            let fields = [
+                team.clone(),
+                task.id.clone(),
+                task.subject.clone(),
+                task.description.clone().unwrap_or_default(),
+                task.status.clone(),
+                task.owner.clone().unwrap_or_default(),
+                task.active_form.clone().unwrap_or_default(),
+                task.created_at.clone().unwrap_or_default(),
+                task.updated_at.clone().unwrap_or_default(),
+                task.source.clone(),
+                task.blocked_by.join(" "),
+                task.blocks.join(" "),
+                link.and_then(|l| l.github_issue_number)
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+                link.map(|l| l.github_issue_url.clone()).unwrap_or_default(),
+                link.and_then(|l| l.github_repo.clone()).unwrap_or_default(),
+                link.and_then(|l| l.github_issue_state.clone())
+                    .unwrap_or_default(),
+            ];
+            let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+            csv.push_str(&line.join(","));
+            csv.push('\n');
+        }
+        csv
+    };
+
+    write_file_atomic(path, content)?;
+    Ok(rows.len())
+}
+
+/// Quote a field when it holds a separator, quote or line break, doubling
+/// embedded quotes (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
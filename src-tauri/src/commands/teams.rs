@@ -0,0 +1,242 @@
+use crate::commands::claude::{agent_state_roots, claude_root, read_task_files};
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{
+    ClaudeTask, ClaudeTeam, TaskGraph, TaskGraphEdge, TaskGraphNode, TeamMember, TeamOwner,
+};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Every agent team across the Claude roots and project `.claude`
+/// directories, with its config (name, lead, members) and task counts per
+/// status and owner.  A team appears if it has a task folder, a config, or
+/// both.  Teams with open tasks come first, then the most recently updated.
+#[tauri::command]
+pub fn get_claude_teams() -> CmdResult<Vec<ClaudeTeam>> {
+    let mut teams = Vec::new();
+    for (source, root) in agent_state_roots() {
+        let mut by_id: HashMap<String, ClaudeTeam> = HashMap::new();
+        for file in read_task_files(&root.join("tasks"), &source)? {
+            let team = by_id
+                .entry(file.team_id.clone())
+                .or_insert_with(|| ClaudeTeam {
+                    team_id: file.team_id.clone(),
+                    source: source.clone(),
+                    ..Default::default()
+                });
+            summarize_tasks(team, &file.tasks);
+        }
+
+        for (team_id, config) in read_team_configs(&root.join("teams")) {
+            let team = by_id.entry(team_id.clone()).or_insert_with(|| ClaudeTeam {
+                team_id,
+                source: source.clone(),
+                ..Default::default()
+            });
+            apply_config(team, &config);
+        }
+        teams.extend(by_id.into_values());
+    }
+
+    teams.sort_by(|a, b| {
+        let open = |t: &ClaudeTeam| t.pending + t.in_progress > 0;
+        open(b)
+            .cmp(&open(a))
+            .then_with(|| b.last_updated.cmp(&a.last_updated))
+            .then_with(|| a.team_id.cmp(&b.team_id))
+    });
+    Ok(teams)
+}
+
+fn summarize_tasks(team: &mut ClaudeTeam, tasks: &[ClaudeTask]) {
+    let mut owners: HashMap<&str, TeamOwner> = HashMap::new();
+    for task in tasks {
+        let owner = task.owner.as_deref().filter(|o| !o.is_empty()).map(|o| {
+            owners.entry(o).or_insert_with(|| TeamOwner {
+                owner: o.to_string(),
+                ..Default::default()
+            })
+        });
+        let (team_count, owner_count) = match task.status.as_str() {
+            "in_progress" => (&mut team.in_progress, owner.map(|o| &mut o.in_progress)),
+            "completed" => (&mut team.completed, owner.map(|o| &mut o.completed)),
+            // Deleted tasks are hidden everywhere else, so not counted.
+            "deleted" => continue,
+            _ => (&mut team.pending, owner.map(|o| &mut o.pending)),
+        };
+        *team_count += 1;
+        if let Some(count) = owner_count {
+            *count += 1;
+        }
+        if task.updated_at > team.last_updated {
+            team.last_updated = task.updated_at.clone();
+        }
+        if team.name.is_none() {
+            team.name = task.team_name.clone();
+        }
+    }
+    team.owners = owners.into_values().collect();
+    team.owners.sort_by(|a, b| {
+        (b.pending + b.in_progress)
+            .cmp(&(a.pending + a.in_progress))
+            .then_with(|| a.owner.cmp(&b.owner))
+    });
+}
+
+/// `(team id, parsed config.json)` for each folder under `teams_dir`.
+/// Unreadable or malformed configs are skipped with a warning.
+fn read_team_configs(teams_dir: &Path) -> Vec<(String, Value)> {
+    let Ok(entries) = std::fs::read_dir(teams_dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter_map(|entry| {
+            let path = entry.path().join("config.json");
+            let team_id = entry.file_name().to_str()?.to_string();
+            let content = std::fs::read_to_string(&path).ok()?;
+            match serde_json::from_str(&content) {
+                Ok(config) => Some((team_id, config)),
+                Err(e) => {
+                    log::warn!("skipped malformed team config {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn apply_config(team: &mut ClaudeTeam, config: &Value) {
+    let text = |v: &Value, key: &str| v.get(key).and_then(|s| s.as_str()).map(String::from);
+    team.has_config = true;
+    if let Some(name) = text(config, "name") {
+        team.name = Some(name);
+    }
+    team.description = text(config, "description");
+    team.created_at = match config.get("createdAt") {
+        // Claude Code writes epoch milliseconds; older files use RFC 3339.
+        Some(Value::Number(ms)) => ms
+            .as_i64()
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|t| t.to_rfc3339()),
+        Some(v) => v.as_str().map(String::from),
+        None => None,
+    };
+
+    let members = config
+        .get("members")
+        .and_then(|m| m.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let lead_id = text(config, "leadAgentId");
+    team.lead = members
+        .iter()
+        .find(|m| lead_id.is_some() && text(m, "agentId") == lead_id)
+        .and_then(|m| text(m, "name"))
+        .or(lead_id);
+    team.members = members
+        .iter()
+        .filter_map(|m| {
+            Some(TeamMember {
+                name: text(m, "name").or_else(|| text(m, "agentId"))?,
+                agent_type: text(m, "agentType"),
+                model: text(m, "model"),
+                color: text(m, "color"),
+            })
+        })
+        .collect();
+}
+
+/// Dependency graph of one team's tasks from their `blockedBy` / `blocks`
+/// fields.  Either side of a dependency may record it; both are merged.
+/// Edges to tasks that no longer exist are dropped, as are deleted tasks.
+#[tauri::command]
+pub fn get_task_graph(team_id: String, source: Option<String>) -> CmdResult<TaskGraph> {
+    let tasks: Vec<ClaudeTask> = read_task_files(
+        &claude_root(source.as_deref()).join("tasks"),
+        source
+            .as_deref()
+            .unwrap_or(commander_core::session::DEFAULT_CLAUDE_SOURCE),
+    )?
+    .into_iter()
+    .find(|f| f.team_id == team_id)
+    .ok_or_else(|| {
+        to_cmd_err(CommanderError::FileNotFound {
+            path: format!("tasks/{team_id}"),
+        })
+    })?
+    .tasks
+    .into_iter()
+    .filter(|t| t.status != "deleted")
+    .collect();
+
+    let ids: HashSet<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+    let mut edges: Vec<TaskGraphEdge> = Vec::new();
+    let mut seen = HashSet::new();
+    for task in &tasks {
+        let before = task
+            .blocked_by
+            .iter()
+            .map(|b| (b.as_str(), task.id.as_str()));
+        let after = task.blocks.iter().map(|b| (task.id.as_str(), b.as_str()));
+        for (from, to) in before.chain(after) {
+            if from != to && ids.contains(from) && ids.contains(to) && seen.insert((from, to)) {
+                edges.push(TaskGraphEdge {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }
+        }
+    }
+
+    // Longest-path depth by relaxation; still changing after one pass per
+    // node means a cycle.
+    let mut depth: HashMap<&str, usize> = ids.iter().map(|id| (*id, 0)).collect();
+    let mut has_cycle = true;
+    for _ in 0..=tasks.len() {
+        let mut changed = false;
+        for edge in &edges {
+            let next = depth[edge.from.as_str()] + 1;
+            if next > depth[edge.to.as_str()] && next <= tasks.len() {
+                depth.insert(edge.to.as_str(), next);
+                changed = true;
+            }
+        }
+        if !changed {
+            has_cycle = false;
+            break;
+        }
+    }
+
+    let status: HashMap<&str, &str> = tasks
+        .iter()
+        .map(|t| (t.id.as_str(), t.status.as_str()))
+        .collect();
+    let mut nodes: Vec<TaskGraphNode> = tasks
+        .iter()
+        .map(|task| TaskGraphNode {
+            id: task.id.clone(),
+            subject: task.subject.clone(),
+            status: task.status.clone(),
+            owner: task.owner.clone(),
+            depth: depth[task.id.as_str()],
+            blocked: edges
+                .iter()
+                .any(|e| e.to == task.id && status[e.from.as_str()] != "completed"),
+        })
+        .collect();
+    nodes.sort_by_key(|n| {
+        (
+            n.depth,
+            n.id.parse::<u64>().unwrap_or(u64::MAX),
+            n.id.clone(),
+        )
+    });
+
+    Ok(TaskGraph {
+        team_id,
+        nodes,
+        edges,
+        has_cycle,
+    })
+}
@@ -1,12 +1,12 @@
+use crate::commands::projects::{load_db_projects, touch_project_by_path};
+use crate::commands::settings::get_setting;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-use crate::utils::validate_home_path;
+use crate::models::{EditorInfo, TerminalInfo};
+use crate::state::AppState;
+use crate::utils::{urlencoding_simple, validate_allowed_path};
 use std::io::Write;
-
-#[derive(serde::Serialize)]
-pub struct TerminalInfo {
-    pub detected: String,
-    pub available: Vec<String>,
-}
+use std::path::Path;
+use tauri::State;
 
 #[tauri::command]
 pub fn detect_terminal() -> CmdResult<TerminalInfo> {
@@ -26,23 +26,10 @@ pub fn detect_terminal() -> CmdResult<TerminalInfo> {
     Ok(TerminalInfo { detected, available })
 }
 
-#[tauri::command]
-pub fn launch_claude(project_path: String, terminal: Option<String>) -> CmdResult<()> {
-    // Validate that project_path is within the user's home directory
-    validate_home_path(&project_path)?;
-
-    let terminal = terminal.unwrap_or_else(|| {
-        if std::path::Path::new("/Applications/Warp.app").exists() {
-            "warp".to_string()
-        } else if std::path::Path::new("/Applications/iTerm.app").exists() {
-            "iterm2".to_string()
-        } else {
-            "terminal".to_string()
-        }
-    });
-
-    // Find claude binary — common install locations as fallback
-    let claude_bin = which::which("claude")
+/// Find the claude binary on PATH, falling back to common install locations
+/// (GUI apps on macOS don't inherit the shell's PATH).
+pub(crate) fn find_claude_bin() -> String {
+    which::which("claude")
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| {
             // Check well-known install locations before giving up
@@ -56,7 +43,40 @@ pub fn launch_claude(project_path: String, terminal: Option<String>) -> CmdResul
                 .find(|&&p| std::path::Path::new(p).exists())
                 .map(|&p| p.to_string())
                 .unwrap_or_else(|| "claude".to_string())
-        });
+        })
+}
+
+#[tauri::command]
+pub fn launch_claude(
+    state: State<AppState>,
+    project_path: String,
+    terminal: Option<String>,
+) -> CmdResult<()> {
+    // Validate that project_path is within the home directory or an allowed root
+    validate_allowed_path(&state, &project_path)?;
+
+    // Recency tracking is best-effort; never block the launch on it.
+    if let Some(conn) = state.db.lock().as_ref() {
+        let _ = touch_project_by_path(conn, &project_path);
+        // A project inside another registered project is a monorepo package.
+        // Its board and sessions are already its own (sessions go to the
+        // deepest project containing their cwd); scope the agent to it too.
+        if let Some(root) = enclosing_project_path(conn, &project_path) {
+            ensure_scoped_claude_md(Path::new(&root), Path::new(&project_path));
+        }
+    }
+
+    let terminal = terminal.unwrap_or_else(|| {
+        if std::path::Path::new("/Applications/Warp.app").exists() {
+            "warp".to_string()
+        } else if std::path::Path::new("/Applications/iTerm.app").exists() {
+            "iterm2".to_string()
+        } else {
+            "terminal".to_string()
+        }
+    });
+
+    let claude_bin = find_claude_bin();
 
     match terminal.as_str() {
         "iterm2" => launch_via_script(&project_path, &claude_bin, "iTerm"),
@@ -71,6 +91,59 @@ pub fn launch_claude(project_path: String, terminal: Option<String>) -> CmdResul
     }
 }
 
+/// Path of the innermost registered project that strictly contains
+/// `project_path`, if any.
+fn enclosing_project_path(conn: &rusqlite::Connection, project_path: &str) -> Option<String> {
+    let package = Path::new(project_path);
+    load_db_projects(conn)
+        .ok()?
+        .into_iter()
+        .map(|p| p.path)
+        .filter(|root| package != Path::new(root) && package.starts_with(root))
+        .max_by_key(|root| Path::new(root).components().count())
+}
+
+/// Write a CLAUDE.md into `package_dir` that scopes the agent to the package
+/// and imports the repository-level CLAUDE.md (if any).  Existing files are
+/// never touched; failures are logged and otherwise ignored.
+fn ensure_scoped_claude_md(root: &Path, package_dir: &Path) {
+    let target = package_dir.join("CLAUDE.md");
+    if target.exists() {
+        return;
+    }
+
+    let rel = package_dir.strip_prefix(root).unwrap_or(package_dir);
+    let repo_name = root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let package_name = package_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut content = format!(
+        "# {package_name}\n\n\
+         This is the `{}` package of the `{repo_name}` monorepo.\n\
+         Keep changes scoped to this directory unless the task explicitly requires otherwise.\n",
+        rel.display(),
+    );
+
+    if root.join("CLAUDE.md").exists() {
+        // One `..` per path component between the package and the repo root.
+        let up = rel
+            .components()
+            .map(|_| "..")
+            .collect::<Vec<_>>()
+            .join("/");
+        content.push_str(&format!("\nRepository-wide instructions: @{up}/CLAUDE.md\n"));
+    }
+
+    if let Err(e) = std::fs::write(&target, content) {
+        log::warn!("failed to write scoped CLAUDE.md {}: {}", target.display(), e);
+    }
+}
+
 /// Write a temp .command script and open it with the given terminal app.
 /// Avoids AppleScript/Automation permission entirely — `open` requires no TCC entitlement.
 fn launch_via_script(project_path: &str, claude_bin: &str, terminal_app: &str) -> CmdResult<()> {
@@ -127,6 +200,217 @@ fn launch_via_script(project_path: &str, claude_bin: &str, terminal_app: &str) -
     Ok(())
 }
 
+/// An editor that can be selected in the `editor` setting.
+pub(crate) struct Editor {
+    pub key: &'static str,
+    /// macOS application bundles, first found wins.
+    apps: &'static [&'static str],
+    /// Command-line launcher, used when no bundle is installed (Linux, or a
+    /// Toolbox install outside `/Applications`).
+    cli: &'static str,
+    /// URL scheme accepting `<scheme>://file/<absolute path>:<line>`, if any.
+    scheme: Option<&'static str>,
+}
+
+pub(crate) const EDITORS: &[Editor] = &[
+    Editor {
+        key: "vscode",
+        apps: &["Visual Studio Code.app"],
+        cli: "code",
+        scheme: Some("vscode"),
+    },
+    Editor {
+        key: "cursor",
+        apps: &["Cursor.app"],
+        cli: "cursor",
+        scheme: Some("cursor"),
+    },
+    Editor {
+        key: "windsurf",
+        apps: &["Windsurf.app"],
+        cli: "windsurf",
+        scheme: Some("windsurf"),
+    },
+    Editor {
+        key: "zed",
+        apps: &["Zed.app"],
+        cli: "zed",
+        scheme: Some("zed"),
+    },
+    Editor {
+        key: "intellij",
+        apps: &["IntelliJ IDEA.app", "IntelliJ IDEA Ultimate.app", "IntelliJ IDEA CE.app"],
+        cli: "idea",
+        scheme: None,
+    },
+    Editor {
+        key: "webstorm",
+        apps: &["WebStorm.app"],
+        cli: "webstorm",
+        scheme: None,
+    },
+    Editor {
+        key: "pycharm",
+        apps: &["PyCharm.app", "PyCharm Professional Edition.app", "PyCharm CE.app"],
+        cli: "pycharm",
+        scheme: None,
+    },
+    Editor {
+        key: "goland",
+        apps: &["GoLand.app"],
+        cli: "goland",
+        scheme: None,
+    },
+    Editor {
+        key: "rustrover",
+        apps: &["RustRover.app"],
+        cli: "rustrover",
+        scheme: None,
+    },
+];
+
+impl Editor {
+    /// How to launch this editor here, or `None` if it is not installed.
+    fn launcher(&self) -> Option<EditorLauncher> {
+        let app_dirs = [
+            Some(Path::new("/Applications").to_path_buf()),
+            dirs::home_dir().map(|h| h.join("Applications")),
+        ];
+        self.apps
+            .iter()
+            .flat_map(|app| app_dirs.iter().flatten().map(move |dir| dir.join(app)))
+            .find(|path| path.exists())
+            .map(EditorLauncher::App)
+            .or_else(|| which::which(self.cli).ok().map(EditorLauncher::Cli))
+    }
+}
+
+enum EditorLauncher {
+    App(std::path::PathBuf),
+    Cli(std::path::PathBuf),
+}
+
+impl EditorLauncher {
+    fn open(&self, target: &Path) -> CmdResult<()> {
+        let mut cmd = match self {
+            EditorLauncher::App(app) => {
+                let mut cmd = std::process::Command::new("open");
+                cmd.arg("-a").arg(app);
+                cmd
+            }
+            EditorLauncher::Cli(bin) => std::process::Command::new(bin),
+        };
+        cmd.arg(target)
+            .spawn()
+            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+        Ok(())
+    }
+}
+
+fn find_editor(key: &str) -> CmdResult<&'static Editor> {
+    EDITORS
+        .iter()
+        .find(|e| e.key == key)
+        .ok_or_else(|| to_cmd_err(CommanderError::internal(format!("Unknown editor: {key}"))))
+}
+
+/// The `editor` setting, or VS Code when unset.
+fn preferred_editor(state: &AppState) -> String {
+    state
+        .db
+        .lock()
+        .as_ref()
+        .and_then(|conn| get_setting(conn, "editor").flatten())
+        .unwrap_or_else(|| "vscode".to_string())
+}
+
+#[tauri::command]
+pub fn detect_editors(state: State<AppState>) -> CmdResult<EditorInfo> {
+    let available: Vec<String> = EDITORS
+        .iter()
+        .filter(|e| e.launcher().is_some())
+        .map(|e| e.key.to_string())
+        .collect();
+
+    let preferred = preferred_editor(&state);
+    let detected = available
+        .iter()
+        .find(|key| **key == preferred)
+        .or_else(|| available.first())
+        .cloned();
+
+    Ok(EditorInfo { detected, available })
+}
+
+/// Open the project directory in `editor` (defaults to the `editor`
+/// setting), preferring the installed app bundle over its CLI launcher.
+#[tauri::command]
+pub fn open_in_editor(
+    state: State<AppState>,
+    project_path: String,
+    editor: Option<String>,
+) -> CmdResult<()> {
+    let canonical = validate_allowed_path(&state, &project_path)?;
+
+    // Recency tracking is best-effort; never block the launch on it.
+    if let Some(conn) = state.db.lock().as_ref() {
+        let _ = touch_project_by_path(conn, &project_path);
+    }
+
+    let editor = find_editor(&editor.unwrap_or_else(|| preferred_editor(&state)))?;
+    let launcher = editor.launcher().ok_or_else(|| {
+        to_cmd_err(CommanderError::internal(format!(
+            "{} is not installed",
+            editor.key
+        )))
+    })?;
+    launcher.open(&canonical)
+}
+
+/// Open `file` at `line` in an editor via its URL scheme.  A relative `file`
+/// is resolved against `project_path`; `editor` defaults to the `editor`
+/// setting.  Editors without a URL scheme (JetBrains IDEs) open the file
+/// without jumping to the line.
+#[tauri::command]
+pub fn open_file_at(
+    state: State<AppState>,
+    project_path: String,
+    file: String,
+    line: Option<u32>,
+    editor: Option<String>,
+) -> CmdResult<()> {
+    let full = Path::new(&project_path).join(&file);
+    let canonical = validate_allowed_path(&state, &full.to_string_lossy())?;
+    if !canonical.is_file() {
+        return Err(to_cmd_err(CommanderError::FileNotFound {
+            path: canonical.to_string_lossy().to_string(),
+        }));
+    }
+
+    let editor = find_editor(&editor.unwrap_or_else(|| preferred_editor(&state)))?;
+    let Some(scheme) = editor.scheme else {
+        let launcher = editor.launcher().ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "{} is not installed",
+                editor.key
+            )))
+        })?;
+        return launcher.open(&canonical);
+    };
+
+    // Encode each segment but keep the separators, which the schemes expect literally.
+    let encoded_path = canonical
+        .to_string_lossy()
+        .split('/')
+        .map(urlencoding_simple)
+        .collect::<Vec<_>>()
+        .join("/");
+    open_url(&format!(
+        "{scheme}://file{encoded_path}:{}",
+        line.unwrap_or(1).max(1)
+    ))
+}
+
 /// POSIX single-quote a string for use in shell commands.
 fn shell_quote(s: &str) -> String {
     format!("'{}'", s.replace('\'', "'\\''"))
@@ -139,16 +423,3 @@ fn open_url(url: &str) -> CmdResult<()> {
         .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
     Ok(())
 }
-
-/// Simple percent-encoding for URL parameters (only ASCII safe chars pass through)
-fn urlencoding_simple(s: &str) -> String {
-    s.bytes()
-        .map(|b| {
-            if b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.' || b == b'~' {
-                (b as char).to_string()
-            } else {
-                format!("%{:02X}", b)
-            }
-        })
-        .collect()
-}
@@ -0,0 +1,79 @@
+use crate::commands::claude::{claude_roots, modified_at};
+use crate::error::CmdResult;
+use crate::models::{ClaudeTodo, ClaudeTodoList};
+use serde_json::Value;
+use std::path::Path;
+
+/// Todo lists Claude Code keeps per session in `<root>/todos/`, most
+/// recently modified first.  With `session_id`, only that session's lists
+/// (main agent and subagents) are returned, empty ones included; otherwise
+/// lists with no items are skipped, as Claude Code leaves an empty file
+/// behind for most sessions.
+#[tauri::command]
+pub fn read_claude_todos(session_id: Option<String>) -> CmdResult<Vec<ClaudeTodoList>> {
+    let mut lists = Vec::new();
+    for (source, root) in claude_roots() {
+        let Ok(entries) = std::fs::read_dir(root.join("todos")) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(list) = read_todo_list(&path, &source) else {
+                continue;
+            };
+            let wanted = match &session_id {
+                Some(id) => list.session_id == *id,
+                None => !list.todos.is_empty(),
+            };
+            if wanted {
+                lists.push(list);
+            }
+        }
+    }
+    lists.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    Ok(lists)
+}
+
+fn read_todo_list(path: &Path, source: &str) -> Option<ClaudeTodoList> {
+    let filename = path.file_name()?.to_str()?.to_string();
+    let stem = path.file_stem()?.to_str()?;
+    // `<session id>-agent-<agent id>.json`; older files are just the id.
+    let (session_id, agent_id) = match stem.split_once("-agent-") {
+        Some((session, agent)) => (session.to_string(), Some(agent.to_string())),
+        None => (stem.to_string(), None),
+    };
+
+    let content = std::fs::read_to_string(path).ok()?;
+    let items: Vec<Value> = match serde_json::from_str(&content) {
+        Ok(items) => items,
+        Err(e) => {
+            log::warn!("skipped malformed todo file {}: {}", path.display(), e);
+            return None;
+        }
+    };
+    let text = |v: &Value, key: &str| v.get(key).and_then(|s| s.as_str()).map(String::from);
+    let todos = items
+        .iter()
+        .filter_map(|item| {
+            Some(ClaudeTodo {
+                id: text(item, "id"),
+                content: text(item, "content")?,
+                status: text(item, "status").unwrap_or_else(|| "pending".to_string()),
+                active_form: text(item, "activeForm"),
+                priority: text(item, "priority"),
+            })
+        })
+        .collect();
+
+    Some(ClaudeTodoList {
+        session_id,
+        agent_id,
+        filename,
+        source: source.to_string(),
+        modified_at: modified_at(path),
+        todos,
+    })
+}
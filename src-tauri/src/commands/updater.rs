@@ -1,15 +1,9 @@
-use serde::Serialize;
 use tauri::AppHandle;
 use tauri_plugin_updater::UpdaterExt;
 
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
-
-#[derive(Debug, Serialize)]
-pub struct UpdateInfo {
-    pub available: bool,
-    pub version: Option<String>,
-    pub body: Option<String>,
-}
+use crate::models::UpdateInfo;
+use crate::services::update_scheduler::record_update_check;
 
 #[tauri::command]
 pub async fn check_for_update(app: AppHandle) -> CmdResult<UpdateInfo> {
@@ -18,7 +12,12 @@ pub async fn check_for_update(app: AppHandle) -> CmdResult<UpdateInfo> {
         .build()
         .map_err(|e| to_cmd_err(CommanderError::internal(e)))?;
 
-    match updater.check().await {
+    let result = updater.check().await;
+    if result.is_ok() {
+        record_update_check(&app);
+    }
+
+    match result {
         Ok(Some(update)) => Ok(UpdateInfo {
             available: true,
             version: Some(update.version.clone()),
@@ -0,0 +1,276 @@
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::WorkspaceSnapshot;
+use crate::state::AppState;
+use crate::utils::validate_allowed_path;
+use git2::build::CheckoutBuilder;
+use git2::{IndexAddOption, Oid, Repository, ResetType, Signature};
+use tauri::State;
+
+/// Snapshot commits live under this hidden namespace: invisible to
+/// `git branch` / `git log --all` noise filters, but kept alive through gc.
+const SNAPSHOT_REF_PREFIX: &str = "refs/commander/snapshots/";
+
+/// Column list matching `row_to_snapshot`.
+const SNAPSHOT_COLUMNS: &str =
+    "id, project_path, branch, head_commit, snapshot_commit, has_changes, label, created_at";
+
+fn git_err(e: git2::Error) -> String {
+    to_cmd_err(CommanderError::from(e))
+}
+
+/// Record HEAD and commit the whole working tree (tracked changes plus
+/// untracked, non-ignored files) to a hidden ref, without touching the
+/// working tree, the index or any branch.  Take one before an agent run so
+/// it can be rolled back even if the agent never commits.
+#[tauri::command]
+pub fn create_workspace_snapshot(
+    state: State<AppState>,
+    project_path: String,
+    label: Option<String>,
+) -> CmdResult<WorkspaceSnapshot> {
+    validate_allowed_path(&state, &project_path)?;
+    let repo = Repository::discover(&project_path).map_err(git_err)?;
+    let snapshot = snapshot_repo(&repo, &project_path, label.as_deref())?;
+
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+    insert_snapshot(conn, &snapshot).map_err(to_cmd_err)?;
+    Ok(snapshot)
+}
+
+/// Snapshots for `project_path`, newest first.
+#[tauri::command]
+pub fn list_workspace_snapshots(
+    state: State<AppState>,
+    project_path: String,
+) -> CmdResult<Vec<WorkspaceSnapshot>> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {SNAPSHOT_COLUMNS} FROM workspace_snapshots
+             WHERE project_path = ?1 ORDER BY created_at DESC"
+        ))
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    let snapshots = stmt
+        .query_map([&project_path], row_to_snapshot)
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(snapshots)
+}
+
+/// Put the repository back to the snapshot: the branch is moved to the
+/// recorded commit and the working tree to the snapshotted files, which show
+/// up as uncommitted changes again.  Files created since are removed unless
+/// ignored.  The current state is snapshotted first and returned, so the
+/// restore itself can be undone.
+#[tauri::command]
+pub fn restore_workspace_snapshot(
+    state: State<AppState>,
+    id: String,
+) -> CmdResult<WorkspaceSnapshot> {
+    let target = {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        conn.query_row(
+            &format!("SELECT {SNAPSHOT_COLUMNS} FROM workspace_snapshots WHERE id = ?1"),
+            [&id],
+            row_to_snapshot,
+        )
+        .map_err(|_| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Workspace snapshot not found: {id}"
+            )))
+        })?
+    };
+
+    validate_allowed_path(&state, &target.project_path)?;
+    let repo = Repository::discover(&target.project_path).map_err(git_err)?;
+
+    let backup = snapshot_repo(
+        &repo,
+        &target.project_path,
+        Some(&format!("before restoring {}", target.id)),
+    )?;
+    {
+        let db = state.db.lock();
+        let conn = db
+            .as_ref()
+            .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+        insert_snapshot(conn, &backup).map_err(to_cmd_err)?;
+    }
+
+    let head_commit = repo
+        .find_commit(Oid::from_str(&target.head_commit).map_err(git_err)?)
+        .map_err(git_err)?;
+    let wip_commit = repo
+        .find_commit(Oid::from_str(&target.snapshot_commit).map_err(git_err)?)
+        .map_err(git_err)?;
+
+    // Point HEAD at the recorded branch first, so the mixed reset below moves
+    // that branch rather than whatever is checked out now.
+    match &target.branch {
+        Some(branch) => {
+            let refname = format!("refs/heads/{branch}");
+            if repo.find_reference(&refname).is_err() {
+                repo.branch(branch, &head_commit, false).map_err(git_err)?;
+            }
+            repo.set_head(&refname).map_err(git_err)?;
+        }
+        None => repo.set_head_detached(head_commit.id()).map_err(git_err)?,
+    }
+
+    repo.checkout_tree(
+        wip_commit.as_object(),
+        Some(CheckoutBuilder::new().force().remove_untracked(true)),
+    )
+    .map_err(git_err)?;
+    repo.reset(head_commit.as_object(), ResetType::Mixed, None)
+        .map_err(git_err)?;
+
+    Ok(backup)
+}
+
+/// Delete a snapshot's metadata and its hidden ref.
+#[tauri::command]
+pub fn delete_workspace_snapshot(state: State<AppState>, id: String) -> CmdResult<()> {
+    let db = state.db.lock();
+    let conn = db
+        .as_ref()
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("DB not initialized")))?;
+
+    let project_path: Option<String> = conn
+        .query_row(
+            "SELECT project_path FROM workspace_snapshots WHERE id = ?1",
+            [&id],
+            |row| row.get(0),
+        )
+        .ok();
+    if let Some(path) = project_path {
+        if let Ok(repo) = Repository::discover(&path) {
+            if let Ok(mut reference) = repo.find_reference(&format!("{SNAPSHOT_REF_PREFIX}{id}")) {
+                let _ = reference.delete();
+            }
+        }
+    }
+
+    conn.execute("DELETE FROM workspace_snapshots WHERE id = ?1", [&id])
+        .map_err(|e| to_cmd_err(CommanderError::from(e)))?;
+    Ok(())
+}
+
+// ─── Internal helpers ───────────────────────────────────────────────────────
+
+fn snapshot_repo(
+    repo: &Repository,
+    project_path: &str,
+    label: Option<&str>,
+) -> CmdResult<WorkspaceSnapshot> {
+    let head = repo.head().map_err(|_| {
+        to_cmd_err(CommanderError::git(
+            "Repository has no commits yet; nothing to snapshot against",
+        ))
+    })?;
+    let branch = head
+        .is_branch()
+        .then(|| head.shorthand().map(|s| s.to_string()))
+        .flatten();
+    let head_commit = head.peel_to_commit().map_err(git_err)?;
+
+    // Stage everything into the in-memory index only, write it out as a tree,
+    // then re-read the on-disk index so the user's staging is left untouched.
+    let mut index = repo.index().map_err(git_err)?;
+    let tree_id = index
+        .add_all(["*"], IndexAddOption::DEFAULT, None)
+        .and_then(|_| index.update_all(["*"], None))
+        .and_then(|_| index.write_tree());
+    index.read(true).map_err(git_err)?;
+    let tree = repo.find_tree(tree_id.map_err(git_err)?).map_err(git_err)?;
+
+    let created_at = chrono::Utc::now();
+    let id = format!(
+        "{}-{}",
+        created_at.format("%Y%m%d-%H%M%S"),
+        &uuid::Uuid::new_v4().simple().to_string()[..6]
+    );
+    let signature = repo
+        .signature()
+        .or_else(|_| Signature::now("Claude Commander", "commander@localhost"))
+        .map_err(git_err)?;
+    let message = match label {
+        Some(label) => format!("Workspace snapshot {id}: {label}"),
+        None => format!("Workspace snapshot {id}"),
+    };
+    let snapshot_commit = repo
+        .commit(
+            None,
+            &signature,
+            &signature,
+            &message,
+            &tree,
+            &[&head_commit],
+        )
+        .map_err(git_err)?;
+    repo.reference(
+        &format!("{SNAPSHOT_REF_PREFIX}{id}"),
+        snapshot_commit,
+        false,
+        &message,
+    )
+    .map_err(git_err)?;
+
+    Ok(WorkspaceSnapshot {
+        id,
+        project_path: project_path.to_string(),
+        branch,
+        head_commit: head_commit.id().to_string(),
+        snapshot_commit: snapshot_commit.to_string(),
+        has_changes: tree.id() != head_commit.tree_id(),
+        label: label.map(|l| l.to_string()),
+        created_at: created_at.to_rfc3339(),
+    })
+}
+
+fn insert_snapshot(
+    conn: &rusqlite::Connection,
+    snapshot: &WorkspaceSnapshot,
+) -> Result<(), CommanderError> {
+    conn.execute(
+        &format!(
+            "INSERT INTO workspace_snapshots ({SNAPSHOT_COLUMNS})
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+        ),
+        rusqlite::params![
+            snapshot.id,
+            snapshot.project_path,
+            snapshot.branch,
+            snapshot.head_commit,
+            snapshot.snapshot_commit,
+            snapshot.has_changes,
+            snapshot.label,
+            snapshot.created_at,
+        ],
+    )?;
+    Ok(())
+}
+
+fn row_to_snapshot(row: &rusqlite::Row) -> rusqlite::Result<WorkspaceSnapshot> {
+    Ok(WorkspaceSnapshot {
+        id: row.get(0)?,
+        project_path: row.get(1)?,
+        branch: row.get(2)?,
+        head_commit: row.get(3)?,
+        snapshot_commit: row.get(4)?,
+        has_changes: row.get(5)?,
+        label: row.get(6)?,
+        created_at: row.get(7)?,
+    })
+}
@@ -2,14 +2,13 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
-mod db;
 pub mod error;
-pub mod models;
 mod pty_state;
 mod services;
 mod state;
 pub mod utils;
 
+pub use commander_core::{db, models};
 use pty_state::PtyState;
 use state::AppState;
 use tauri::Manager;
@@ -20,7 +19,6 @@ fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .manage(AppState::new())
@@ -56,57 +54,64 @@ fn main() {
                 }
             }
 
-            // Start watching ~/.claude/ for task/plan/session changes
-            let claude_dir = dirs::home_dir()
-                .map(|h| h.join(".claude"))
-                .filter(|p| p.exists());
+            // Background update checks (frequency / offline mode read from settings)
+            services::update_scheduler::start(app_handle.clone());
 
-            if let Some(claude_path) = claude_dir {
-                match services::file_watcher::ClaudeWatcher::new(
-                    app_handle.clone(),
-                    claude_path.clone(),
-                ) {
-                    Ok(watcher) => {
-                        let mut watcher_lock = app_state.claude_watcher.lock();
-                        *watcher_lock = Some(watcher);
-                        log::info!("Watching {:?} for changes", claude_path);
-                    }
-                    Err(e) => {
-                        log::warn!("Failed to start file watcher: {}", e);
-                    }
-                }
+            // Background status sync between planning items, issues, and plans
+            services::status_sync::start(app_handle.clone());
+
+            // Digest of low-priority notifications
+            services::notifications::start(app_handle.clone());
+
+            // Full-text index and cost ledger of session transcripts
+            services::session_index::start(app_handle.clone());
+
+            // Weekly WAL checkpoint, backup, pruning and cache refresh
+            services::maintenance::start(app_handle.clone());
+
+            // Suggest closing in-progress items that recent commits finish
+            services::suggestions::start(app_handle.clone());
+
+            // Start/end events for running Claude CLI processes
+            services::claude_processes::start(app_handle.clone());
+
+            // Start watching ~/.claude/ plus any extra Claude config roots
+            // from the `extra_claude_dirs` setting, and projects' in-repo
+            // `.claude` directories, for task/plan/session changes
+            if let Some(conn) = app_state.db.lock().as_ref() {
+                commands::claude::set_extra_claude_dirs(
+                    commands::settings::get_json_setting(conn, "extra_claude_dirs")
+                        .unwrap_or_default(),
+                );
+                commands::claude::set_project_claude_dirs(
+                    &commands::projects::load_db_projects(conn).unwrap_or_default(),
+                );
             }
+            commands::claude::restart_claude_watcher(&app_handle);
 
-            // Start watching the project scan path for directory removals.
-            // Read scan_path from settings (falls back to ~/cv if not set).
-            let scan_path: Option<std::path::PathBuf> = {
+            // Start watching every project scan root for directory removals.
+            // Roots come from the `scan_paths` setting (falls back to
+            // `scan_path`, then ~/cv).
+            let scan_roots: Vec<std::path::PathBuf> = {
                 let db_lock = app_state.db.lock();
                 db_lock
                     .as_ref()
-                    .and_then(|conn| {
-                        conn.query_row(
-                            "SELECT value FROM settings WHERE key = 'scan_path'",
-                            [],
-                            |row| row.get::<_, String>(0),
-                        )
-                        .ok()
-                    })
-                    .or_else(|| {
-                        dirs::home_dir().map(|h| h.join("cv").to_string_lossy().to_string())
-                    })
-                    .map(std::path::PathBuf::from)
+                    .map(commands::projects::configured_scan_roots)
+                    .unwrap_or_default()
+                    .into_iter()
                     .filter(|p| p.exists())
+                    .collect()
             };
 
-            if let Some(proj_path) = scan_path {
+            if !scan_roots.is_empty() {
                 match services::file_watcher::ProjectWatcher::new(
                     app_handle.clone(),
-                    proj_path.clone(),
+                    scan_roots.clone(),
                 ) {
                     Ok(watcher) => {
                         let mut watcher_lock = app_state.project_watcher.lock();
                         *watcher_lock = Some(watcher);
-                        log::info!("Watching {:?} for project removals", proj_path);
+                        log::info!("Watching {:?} for project removals", scan_roots);
                     }
                     Err(e) => {
                         log::warn!("Failed to start project watcher: {}", e);
@@ -120,50 +125,192 @@ fn main() {
             // Projects
             commands::projects::scan_projects,
             commands::projects::sync_projects,
+            commands::sync_history::get_sync_history,
+            commands::event_log::get_event_log,
+            commands::maintenance::run_maintenance_now,
             commands::projects::get_projects,
+            commands::projects::get_projects_with_stats,
+            commands::projects::reorder_projects,
+            commands::projects::find_duplicate_projects,
+            commands::projects::merge_projects,
+            commands::scaffold::get_project_templates,
+            commands::scaffold::create_project_from_template,
             commands::projects::get_archived_projects,
             commands::projects::restore_project,
+            commands::projects::archive_project,
             commands::projects::upsert_project,
+            commands::projects::set_project_icon,
+            commands::projects::touch_project,
+            commands::projects::get_recent_projects,
+            commands::overview::get_project_overview,
             commands::projects::delete_project,
             commands::projects::purge_archived_projects,
             commands::projects::reset_all_projects,
             commands::projects::import_scanned_projects,
+            // Demo data
+            commands::demo_seed::seed_demo_data,
+            commands::demo_seed::clear_demo_data,
+            // Backups
+            commands::backups::list_snapshots,
+            commands::backups::restore_snapshot,
+            // Groups
+            commands::groups::get_groups,
+            commands::groups::create_group,
+            commands::groups::rename_group,
+            commands::groups::delete_group,
+            commands::groups::assign_project_group,
+            // Dependencies
+            commands::dependencies::get_cross_project_dependencies,
+            // Disk usage
+            commands::disk_usage::get_project_disk_usage,
+            commands::disk_usage::cancel_project_disk_usage,
+            commands::disk_usage::list_project_disk_usage,
             // Claude
             commands::claude::read_claude_tasks,
+            commands::claude::set_task_order,
+            commands::claude::get_all_tasks_flat,
+            commands::claude::update_claude_task,
+            commands::claude::create_claude_task,
+            commands::teams::get_claude_teams,
+            commands::teams::get_task_graph,
+            commands::todos::read_claude_todos,
+            commands::claude_history::read_claude_history,
+            commands::task_export::export_claude_tasks,
+            commands::claude_processes::get_active_claude_processes,
             commands::claude::list_claude_plans,
             commands::claude::read_claude_plan,
+            commands::claude::save_claude_plan,
+            commands::plan_history::get_plan_history,
+            commands::plan_history::restore_plan_version,
             commands::claude::read_claude_sessions,
             commands::claude::read_session_messages,
             commands::claude::read_claude_session,
+            commands::claude::read_session_attachment,
+            commands::redaction::redact_session,
+            commands::claude_settings::read_claude_settings,
+            commands::claude_settings::update_claude_settings,
+            commands::slash_commands::list_slash_commands,
+            commands::slash_commands::read_slash_command,
+            commands::slash_commands::create_slash_command,
+            commands::slash_commands::update_slash_command,
+            // Artifacts
+            commands::artifacts::get_run_artifacts,
+            // Session stacks
+            commands::session_stack::get_session_stack,
+            commands::session_stack::find_sessions_by_stack,
+            // Session storage
+            commands::session_storage::analyze_session_storage,
+            commands::session_storage::cleanup_sessions,
+            // Session bookmarks
+            commands::session_bookmarks::bookmark_session,
+            commands::session_bookmarks::unbookmark_session,
+            commands::session_bookmarks::list_bookmarked_sessions,
             // Terminal
             commands::terminal::detect_terminal,
             commands::terminal::launch_claude,
+            commands::terminal::open_file_at,
+            commands::terminal::detect_editors,
+            commands::terminal::open_in_editor,
+            // Files
+            commands::files::read_allowed_file,
             // Git
             commands::git::git_status,
             commands::git::git_log,
             commands::git::git_branches,
+            commands::git::git_stage,
+            commands::git::git_unstage,
+            commands::git::git_commit,
+            commands::git::diff_since_session,
+            commands::git_remote::clone_repository,
+            commands::git_remote::git_fetch,
+            commands::git_remote::git_pull,
+            commands::git_remote::cancel_git_operation,
+            commands::workspace_snapshots::create_workspace_snapshot,
+            commands::workspace_snapshots::list_workspace_snapshots,
+            commands::workspace_snapshots::restore_workspace_snapshot,
+            commands::workspace_snapshots::delete_workspace_snapshot,
             // Env
             commands::env::list_env_files,
             commands::env::get_env_vars,
             commands::env::set_env_var,
             commands::env::delete_env_var,
             commands::env::get_deploy_configs,
+            // Badges
+            commands::badges::get_project_badges,
             // Planning
             commands::planning::get_planning_items,
             commands::planning::create_planning_item,
             commands::planning::update_planning_item,
             commands::planning::move_planning_item,
             commands::planning::delete_planning_item,
+            commands::planning::create_plan_from_item,
+            commands::planning::create_issue_from_planning_item,
+            commands::planning::import_issues_as_items,
+            commands::planning::import_github_issues,
+            commands::planning::get_all_planning_items,
+            // Board filters
+            commands::board_filter::get_saved_filters,
+            commands::board_filter::save_filter,
+            commands::board_filter::delete_saved_filter,
+            // Status sync
+            commands::status_sync::preview_status_sync,
+            commands::status_sync::run_status_sync,
+            // Presentation
+            commands::presentation::start_presentation_server,
+            commands::presentation::stop_presentation_server,
+            commands::presentation::get_presentation_servers,
+            // Notifications
+            commands::notifications::get_pending_notifications,
+            commands::notifications::flush_notification_digest,
             // GitHub
+            commands::github::check_github_auth,
+            commands::github::set_github_token,
             commands::github::detect_github_repo,
+            commands::github::list_github_remotes,
+            commands::github::list_issue_remotes,
             commands::github::create_github_issue,
+            commands::github::list_github_issues,
+            commands::github::list_github_prs,
+            commands::github::get_pr_checks,
+            commands::github::create_github_pr,
             commands::github::close_github_issue,
+            commands::github::suggest_closing_commit,
             commands::github::fetch_issue_states,
             commands::github::upsert_task_github_link,
             commands::github::get_task_github_links,
             commands::github::delete_task_github_link,
+            commands::github::upsert_planning_item_github_link,
+            commands::github::get_planning_item_github_links,
+            commands::github::fetch_planning_item_issue_states,
+            commands::github::delete_planning_item_github_link,
+            commands::github::get_issue_comments,
+            commands::github::add_issue_comment,
+            commands::github_projects::list_github_projects,
+            commands::github_projects::add_issue_to_github_project,
+            commands::issue_cache::get_cached_issues,
+            commands::issue_cache::refresh_issue_cache,
+            commands::issue_templates::get_issue_templates,
+            commands::session_follow::follow_session,
+            commands::session_follow::stop_session_follow,
+            commands::session_follow::get_followed_sessions,
+            commands::session_summary::preview_session_summary,
+            commands::session_summary::post_session_summary,
+            commands::session_export::export_session,
             // Search
             commands::search::global_search,
+            commands::session_search::search_sessions,
+            commands::session_search::reindex_sessions,
+            commands::session_search::search_in_session,
+            // Costs
+            commands::cost_ledger::get_cost_summary,
+            commands::cost_ledger::get_usage_stats,
+            commands::activity::get_claude_activity,
+            // Dashboard
+            commands::dashboard::get_dashboard_data,
+            // Suggestions
+            commands::suggestions::get_suggestions,
+            commands::suggestions::accept_suggestion,
+            commands::suggestions::dismiss_suggestion,
             // Settings
             commands::settings::get_settings,
             commands::settings::update_settings,
@@ -175,6 +322,10 @@ fn main() {
             commands::pty::pty_write,
             commands::pty::pty_resize,
             commands::pty::pty_kill,
+            commands::pty::get_pty_resource_usage,
+            commands::pty::pty_list,
+            commands::pty::get_pty_env_file,
+            commands::pty::set_pty_env_file,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
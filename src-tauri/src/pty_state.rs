@@ -1,20 +1,86 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use parking_lot::Mutex;
 
+/// Metadata and output counters for one PTY, updated by its reader thread.
+pub struct PtyStats {
+    pub created_at: String,
+    pub project_path: String,
+    /// Binary the PTY runs (`claude` or the login shell).
+    pub program: String,
+    /// Unix time in milliseconds of the last input or output.
+    pub last_activity_ms: AtomicI64,
+    /// Total bytes forwarded to the frontend since the session started.
+    pub bytes_emitted: AtomicU64,
+    /// Number of times the reader paused because the output rate limit was hit.
+    pub throttle_events: AtomicU64,
+}
+
+/// Which env file a PTY was launched with and the variable names it injected.
+pub struct PtyEnvManifest {
+    pub env_file: String,
+    pub keys: Vec<String>,
+}
+
 pub struct PtySession {
     pub writer: Box<dyn std::io::Write + Send>,
     pub master: Arc<Mutex<Box<dyn portable_pty::MasterPty + Send>>>,
+    pub stats: Arc<PtyStats>,
+    pub env_manifest: Option<PtyEnvManifest>,
 }
 
 pub struct PtyState {
     pub sessions: Mutex<HashMap<String, PtySession>>,
+    /// Slots held by `pty_create` calls still spawning their session.
+    reserved: AtomicUsize,
 }
 
 impl PtyState {
     pub fn new() -> Self {
         Self {
             sessions: Mutex::new(HashMap::new()),
+            reserved: AtomicUsize::new(0),
+        }
+    }
+
+    /// Hold one of `max` session slots while a session is spawned, or `None`
+    /// if they are all taken.  Counting running and reserved sessions under
+    /// the sessions lock keeps concurrent creates from overshooting `max`.
+    pub fn reserve(&self, max: usize) -> Option<PtyReservation<'_>> {
+        let sessions = self.sessions.lock();
+        if sessions.len() + self.reserved.load(Ordering::Acquire) >= max {
+            return None;
+        }
+        self.reserved.fetch_add(1, Ordering::AcqRel);
+        Some(PtyReservation {
+            state: self,
+            used: false,
+        })
+    }
+}
+
+/// A session slot from `PtyState::reserve`, given back when dropped unless
+/// the session was inserted into it.
+pub struct PtyReservation<'a> {
+    state: &'a PtyState,
+    used: bool,
+}
+
+impl PtyReservation<'_> {
+    /// Add the spawned session, turning the reserved slot into a running one.
+    pub fn insert(mut self, pty_id: String, session: PtySession) {
+        let mut sessions = self.state.sessions.lock();
+        sessions.insert(pty_id, session);
+        self.state.reserved.fetch_sub(1, Ordering::AcqRel);
+        self.used = true;
+    }
+}
+
+impl Drop for PtyReservation<'_> {
+    fn drop(&mut self) {
+        if !self.used {
+            self.state.reserved.fetch_sub(1, Ordering::AcqRel);
         }
     }
 }
@@ -0,0 +1,65 @@
+use crate::commands::claude::correlate_session_project;
+use crate::commands::claude_processes::list_claude_processes;
+use crate::commands::projects::load_db_projects;
+use crate::models::ClaudeProcess;
+use crate::state::AppState;
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Emitted with a `ClaudeProcess` when a Claude CLI process appears.
+pub const EVENT_CLAUDE_SESSION_STARTED: &str = "claude-session-started";
+/// Emitted with the `ClaudeProcess` last seen when it exits.
+pub const EVENT_CLAUDE_SESSION_ENDED: &str = "claude-session-ended";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Poll the process list and emit start/end events for Claude processes.
+/// Processes already running at launch are reported as started on the
+/// first pass.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let mut running: HashMap<u32, ClaudeProcess> = HashMap::new();
+        loop {
+            let mut current: HashMap<u32, ClaudeProcess> = list_claude_processes()
+                .into_iter()
+                .map(|p| (p.pid, p))
+                .collect();
+
+            let started: Vec<u32> = current
+                .keys()
+                .filter(|pid| !running.contains_key(pid))
+                .copied()
+                .collect();
+            if !started.is_empty() {
+                let state = app_handle.state::<AppState>();
+                let projects = {
+                    let db = state.db.lock();
+                    db.as_ref()
+                        .and_then(|conn| load_db_projects(conn).ok())
+                        .unwrap_or_default()
+                };
+                for pid in started {
+                    let process = current.get_mut(&pid).expect("listed above");
+                    process.project_id = process
+                        .cwd
+                        .as_deref()
+                        .and_then(|cwd| correlate_session_project(cwd, &projects));
+                    let _ = app_handle.emit(EVENT_CLAUDE_SESSION_STARTED, &*process);
+                }
+            }
+
+            for (pid, process) in running.drain() {
+                match current.get_mut(&pid) {
+                    // Keep the project found when it started.
+                    Some(still) => still.project_id = process.project_id,
+                    None => {
+                        let _ = app_handle.emit(EVENT_CLAUDE_SESSION_ENDED, &process);
+                    }
+                }
+            }
+            running = current;
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
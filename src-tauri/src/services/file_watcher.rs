@@ -1,13 +1,15 @@
+use crate::commands::{plan_history, session_follow};
+use crate::state::AppState;
 use notify::{Event, EventKind, RecursiveMode, Watcher};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 
 // ─── ProjectWatcher ─────────────────────────────────────────────────────────
 
-/// Watches the configured project scan path for directory-removal events.
+/// Watches the configured project scan roots for directory-removal events.
 /// When a removal is detected the `projects-stale` Tauri event is emitted so
 /// the frontend can call `sync_projects` and archive the missing records.
 pub struct ProjectWatcher {
@@ -16,7 +18,7 @@ pub struct ProjectWatcher {
 }
 
 impl ProjectWatcher {
-    pub fn new(app_handle: AppHandle, watch_path: PathBuf) -> Result<Self, notify::Error> {
+    pub fn new(app_handle: AppHandle, watch_paths: Vec<PathBuf>) -> Result<Self, notify::Error> {
         let (stop_tx, stop_rx) = std::sync::mpsc::sync_channel::<()>(0);
         let app_clone = app_handle.clone();
 
@@ -51,9 +53,14 @@ impl ProjectWatcher {
                 }
             })?;
 
-        // Non-recursive: only immediate children of the scan path are project
+        // Non-recursive: only immediate children of a scan root are project
         // roots, so watching the top level is sufficient to detect folder removal.
-        watcher.watch(&watch_path, RecursiveMode::NonRecursive)?;
+        // A root that cannot be watched is logged and skipped so the others still work.
+        for watch_path in &watch_paths {
+            if let Err(e) = watcher.watch(watch_path, RecursiveMode::NonRecursive) {
+                log::warn!("Failed to watch scan root {:?}: {}", watch_path, e);
+            }
+        }
 
         Ok(Self {
             _watcher: watcher,
@@ -67,10 +74,14 @@ const DEBOUNCE_MS: u64 = 500;
 pub const EVENT_TASKS_CHANGED: &str = "claude-tasks-changed";
 pub const EVENT_PLANS_CHANGED: &str = "claude-plans-changed";
 pub const EVENT_SESSIONS_CHANGED: &str = "claude-sessions-changed";
+pub const EVENT_TODOS_CHANGED: &str = "claude-todos-changed";
 /// Emitted when a directory removal is detected under the project scan path.
 /// The frontend should respond by calling `sync_projects` to archive stale records.
 pub const EVENT_PROJECTS_STALE: &str = "projects-stale";
 
+/// Watches every configured Claude config root and project `.claude`
+/// directory (see `commands::claude::agent_state_roots`) for task, plan,
+/// session and todo changes.
 pub struct ClaudeWatcher {
     _watcher: notify::RecommendedWatcher,
     /// Dropping this sender signals the debounce thread to exit.
@@ -78,11 +89,16 @@ pub struct ClaudeWatcher {
 }
 
 impl ClaudeWatcher {
-    pub fn new(app_handle: AppHandle, watch_path: PathBuf) -> Result<Self, notify::Error> {
+    pub fn new(app_handle: AppHandle, watch_paths: Vec<PathBuf>) -> Result<Self, notify::Error> {
         let pending_events: Arc<Mutex<HashMap<PathBuf, Instant>>> =
             Arc::new(Mutex::new(HashMap::new()));
         let pending_clone = pending_events.clone();
         let app_clone = app_handle.clone();
+        // Event paths are resolved, so compare against resolved roots.
+        let roots: Vec<PathBuf> = watch_paths
+            .iter()
+            .map(|p| p.canonicalize().unwrap_or_else(|_| p.clone()))
+            .collect();
 
         // Shutdown channel — dropping the sender causes the receiver to see Disconnected
         let (stop_tx, stop_rx) = std::sync::mpsc::sync_channel::<()>(0);
@@ -113,18 +129,35 @@ impl ClaudeWatcher {
 
             for path in to_emit {
                 let path_str = path.to_string_lossy().to_string();
-                // Determine what changed based on path
-                if path_str.contains("tasks") {
-                    let _ = app_clone.emit(EVENT_TASKS_CHANGED, &path_str);
-                } else if path_str.contains("plans") {
-                    let _ = app_clone.emit(EVENT_PLANS_CHANGED, &path_str);
-                } else if path_str.contains("projects") {
-                    let _ = app_clone.emit(EVENT_SESSIONS_CHANGED, &path_str);
+                // Determine what changed from the directory under the root
+                match root_subdir(&path, &roots) {
+                    Some("todos") => {
+                        let _ = app_clone.emit(EVENT_TODOS_CHANGED, &path_str);
+                    }
+                    Some("tasks") => {
+                        let _ = app_clone.emit(EVENT_TASKS_CHANGED, &path_str);
+                    }
+                    Some("plans") => {
+                        // Keep the new text so it can be restored once Claude
+                        // overwrites it again.
+                        if let Err(e) = plan_history::record_plan_version(
+                            &app_clone.state::<AppState>(),
+                            &path,
+                        ) {
+                            log::warn!("failed to record plan version {}: {}", path_str, e);
+                        }
+                        let _ = app_clone.emit(EVENT_PLANS_CHANGED, &path_str);
+                    }
+                    Some("projects") => {
+                        let _ = app_clone.emit(EVENT_SESSIONS_CHANGED, &path_str);
+                    }
+                    _ => {}
                 }
             }
         });
 
         let pending_for_handler = pending_events.clone();
+        let app_for_handler = app_handle.clone();
 
         let mut watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
             if let Ok(event) = res {
@@ -132,6 +165,13 @@ impl ClaudeWatcher {
                     return;
                 }
 
+                // A new session file means a Claude run just started.
+                if matches!(event.kind, EventKind::Create(_)) {
+                    for path in &event.paths {
+                        session_follow::on_session_file_created(&app_for_handler, path);
+                    }
+                }
+
                 for path in &event.paths {
                     // Only watch .json and .jsonl and .md files
                     if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
@@ -145,7 +185,12 @@ impl ClaudeWatcher {
             }
         })?;
 
-        watcher.watch(&watch_path, RecursiveMode::Recursive)?;
+        // A root that cannot be watched is logged and skipped so the others still work.
+        for watch_path in &watch_paths {
+            if let Err(e) = watcher.watch(watch_path, RecursiveMode::Recursive) {
+                log::warn!("Failed to watch claude dir {:?}: {}", watch_path, e);
+            }
+        }
 
         Ok(Self {
             _watcher: watcher,
@@ -153,3 +198,18 @@ impl ClaudeWatcher {
         })
     }
 }
+
+/// First component of `path` below the innermost watched root containing
+/// it, e.g. `tasks` for `<root>/tasks/<team>/1.json`.  Matching whole
+/// components keeps a project named `tasks-app` or a checkout under
+/// `~/projects` from being mistaken for Claude's own directories.
+fn root_subdir<'a>(path: &'a Path, roots: &[PathBuf]) -> Option<&'a str> {
+    roots
+        .iter()
+        .filter_map(|root| path.strip_prefix(root).ok())
+        .min_by_key(|rest| rest.components().count())?
+        .components()
+        .next()?
+        .as_os_str()
+        .to_str()
+}
@@ -0,0 +1,342 @@
+//! Issue operations behind one interface for every supported Git forge.
+//!
+//! Repositories are named the way the link tables store them: GitHub ones
+//! as `"owner/repo"`, anything else prefixed with its host, e.g.
+//! `"gitlab.com/group/subgroup/project"` or `"git.example.org/team/app"`.
+//! Every host other than github.com is treated as a GitLab instance and
+//! driven through `glab api`, which brings its own per-host login.
+
+use crate::commands::github::{
+    close_remote_issue, create_remote_issue, fetch_remote_issue_comments, fetch_repo_issue_states,
+    post_issue_comment, require_github_auth,
+};
+use crate::commands::issue_templates::fetch_github_issue_templates;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::models::{CachedIssueComment, CreateGithubIssueOutput, IssueTemplate};
+use crate::services::github_api::{self, GithubApi};
+use crate::utils::run_external_tool;
+use serde_json::Value;
+use std::process::Command;
+
+pub trait Forge {
+    /// Fail early, with the forge's own remediation, when it cannot be used.
+    fn require_auth(&self) -> CmdResult<()>;
+
+    fn create_issue(
+        &self,
+        repo: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> CmdResult<CreateGithubIssueOutput>;
+
+    fn close_issue(&self, repo: &str, number: i64) -> CmdResult<()>;
+
+    /// `"open"` or `"closed"` for as many of `numbers` as could be fetched
+    /// in one round trip, and a message when some or all could not.
+    fn issue_states(&self, repo: &str, numbers: &[i64]) -> (Vec<(i64, String)>, Option<String>);
+
+    /// Comments on an issue, oldest first.
+    fn issue_comments(&self, repo: &str, number: i64) -> CmdResult<Vec<CachedIssueComment>>;
+
+    /// Post a comment and return its URL when the forge reports one.
+    fn add_issue_comment(&self, repo: &str, number: i64, body: &str) -> CmdResult<Option<String>>;
+
+    /// Templates a new issue can start from; empty when there are none.
+    fn issue_templates(&self, repo: &str) -> CmdResult<Vec<IssueTemplate>>;
+}
+
+/// The forge hosting `repo`.
+pub fn forge_for_repo(repo: &str) -> Box<dyn Forge> {
+    match gitlab_host(repo) {
+        Some(_) => Box::new(Gitlab),
+        None => Box::new(Github {
+            api: GithubApi::from_keychain(),
+        }),
+    }
+}
+
+/// Host-qualified repository of a GitLab-style remote URL, e.g.
+/// `git@gitlab.com:group/app.git` → `"gitlab.com/group/app"`.  Only hosts
+/// with "gitlab" in their name are recognised from a remote; repositories
+/// on other self-hosted instances can still be typed in by hand.
+pub fn parse_gitlab_remote(url: &str) -> Option<String> {
+    let url = url.trim();
+    let (host, path) = if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("ssh://git@"))
+    {
+        rest.split_once('/')?
+    } else {
+        url.strip_prefix("git@")?.split_once(':')?
+    };
+    // Drop a port (`ssh://git@host:2222/…`) and any credentials.
+    let host = host.rsplit('@').next()?.split(':').next()?;
+    if !host.contains("gitlab") {
+        return None;
+    }
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    (path.contains('/') && !path.starts_with('/')).then(|| format!("{host}/{path}"))
+}
+
+/// Host-qualified repository of a GitLab issue URL such as
+/// `https://gitlab.com/group/app/-/issues/12` → `"gitlab.com/group/app"`.
+pub fn parse_gitlab_issue_url(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("https://")?;
+    let (repo, _) = rest.split_once("/-/issues/")?;
+    gitlab_host(repo).map(|_| repo.to_string())
+}
+
+/// How a commit message names issue `number` of `repo` so that a closing
+/// keyword in front of it works: `owner/repo#12` on GitHub, the project
+/// path without its host on GitLab.
+pub fn issue_reference(repo: &str, number: i64) -> String {
+    match gitlab_host(repo) {
+        Some(host) => format!("{}#{number}", &repo[host.len() + 1..]),
+        None => format!("{repo}#{number}"),
+    }
+}
+
+/// The host of a host-qualified repository, or `None` for GitHub's
+/// `"owner/repo"` (GitHub logins cannot contain a dot).
+fn gitlab_host(repo: &str) -> Option<&str> {
+    let (first, rest) = repo.split_once('/')?;
+    (first.contains('.') && first != "github.com" && rest.contains('/')).then_some(first)
+}
+
+// ─── GitHub ─────────────────────────────────────────────────────────────────
+
+/// The REST client when a token is stored, `gh` otherwise.
+struct Github {
+    api: Option<GithubApi>,
+}
+
+impl Forge for Github {
+    fn require_auth(&self) -> CmdResult<()> {
+        if github_api::stored_token().is_some() {
+            return Ok(());
+        }
+        require_github_auth()
+    }
+
+    fn create_issue(
+        &self,
+        repo: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> CmdResult<CreateGithubIssueOutput> {
+        create_remote_issue(repo, title, body, labels)
+    }
+
+    fn close_issue(&self, repo: &str, number: i64) -> CmdResult<()> {
+        close_remote_issue(repo, number)
+    }
+
+    fn issue_states(&self, repo: &str, numbers: &[i64]) -> (Vec<(i64, String)>, Option<String>) {
+        fetch_repo_issue_states(self.api.as_ref(), repo, numbers)
+    }
+
+    fn issue_comments(&self, repo: &str, number: i64) -> CmdResult<Vec<CachedIssueComment>> {
+        fetch_remote_issue_comments(repo, number)
+    }
+
+    fn add_issue_comment(&self, repo: &str, number: i64, body: &str) -> CmdResult<Option<String>> {
+        post_issue_comment(repo, number, body)
+    }
+
+    fn issue_templates(&self, repo: &str) -> CmdResult<Vec<IssueTemplate>> {
+        fetch_github_issue_templates(repo)
+    }
+}
+
+// ─── GitLab ─────────────────────────────────────────────────────────────────
+
+/// GitLab.com or a self-hosted instance, through `glab api --hostname`.
+/// Issue numbers are GitLab's per-project `iid`s, as shown in its URLs.
+struct Gitlab;
+
+impl Gitlab {
+    /// `(host, path, url-encoded project id)` of a host-qualified repo.
+    fn split(repo: &str) -> CmdResult<(&str, &str, String)> {
+        let host = gitlab_host(repo).ok_or_else(|| {
+            to_cmd_err(CommanderError::internal(format!(
+                "Not a GitLab repository: {repo}"
+            )))
+        })?;
+        let path = &repo[host.len() + 1..];
+        Ok((host, path, path.replace('/', "%2F")))
+    }
+
+    fn api(host: &str, method: &str, endpoint: &str, fields: &[(&str, &str)]) -> CmdResult<Value> {
+        let mut command = Command::new("glab");
+        command.args(["api", "--hostname", host, "--method", method, endpoint]);
+        for (name, value) in fields {
+            command.arg("--raw-field").arg(format!("{name}={value}"));
+        }
+        let output = run_external_tool(&mut command)?;
+        serde_json::from_slice(&output.stdout).map_err(|e| to_cmd_err(CommanderError::from(e)))
+    }
+}
+
+impl Forge for Gitlab {
+    fn require_auth(&self) -> CmdResult<()> {
+        // Any host will do: `glab auth status` checks every configured one.
+        run_external_tool(Command::new("glab").args(["auth", "status"]))?;
+        Ok(())
+    }
+
+    fn create_issue(
+        &self,
+        repo: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> CmdResult<CreateGithubIssueOutput> {
+        let (host, _, project) = Self::split(repo)?;
+        let labels = labels.join(",");
+        let issue = Self::api(
+            host,
+            "POST",
+            &format!("projects/{project}/issues"),
+            &[("title", title), ("description", body), ("labels", &labels)],
+        )?;
+        let number = issue["iid"]
+            .as_i64()
+            .ok_or_else(|| to_cmd_err(CommanderError::parse("Missing 'iid' in GitLab response")))?;
+        let url = issue["web_url"]
+            .as_str()
+            .ok_or_else(|| {
+                to_cmd_err(CommanderError::parse(
+                    "Missing 'web_url' in GitLab response",
+                ))
+            })?
+            .to_string();
+        Ok(CreateGithubIssueOutput { number, url })
+    }
+
+    fn close_issue(&self, repo: &str, number: i64) -> CmdResult<()> {
+        let (host, _, project) = Self::split(repo)?;
+        Self::api(
+            host,
+            "PUT",
+            &format!("projects/{project}/issues/{number}"),
+            &[("state_event", "close")],
+        )?;
+        Ok(())
+    }
+
+    fn issue_states(&self, repo: &str, numbers: &[i64]) -> (Vec<(i64, String)>, Option<String>) {
+        let (host, _, project) = match Self::split(repo) {
+            Ok(parts) => parts,
+            Err(e) => return (Vec::new(), Some(error_message(&e))),
+        };
+        let iids: String = numbers.iter().map(|n| format!("&iids[]={n}")).collect();
+        let endpoint = format!("projects/{project}/issues?scope=all&per_page=100{iids}");
+        let issues = match Self::api(host, "GET", &endpoint, &[]) {
+            Ok(issues) => issues,
+            Err(e) => return (Vec::new(), Some(error_message(&e))),
+        };
+
+        // GitLab says "opened" where GitHub says "open".
+        let states: Vec<(i64, String)> = issues
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|issue| {
+                let state = match issue["state"].as_str()? {
+                    "opened" => "open",
+                    "closed" => "closed",
+                    _ => return None,
+                };
+                Some((issue["iid"].as_i64()?, state.to_string()))
+            })
+            .collect();
+        let missing = numbers.len().saturating_sub(states.len());
+        let error = (missing > 0).then(|| format!("{missing} issue(s) not found"));
+        (states, error)
+    }
+
+    fn issue_comments(&self, repo: &str, number: i64) -> CmdResult<Vec<CachedIssueComment>> {
+        let (host, _, project) = Self::split(repo)?;
+        let notes = Self::api(
+            host,
+            "GET",
+            &format!("projects/{project}/issues/{number}/notes?sort=asc&per_page=100"),
+            &[],
+        )?;
+        Ok(notes
+            .as_array()
+            .into_iter()
+            .flatten()
+            // System notes record label changes, mentions and the like.
+            .filter(|n| !n["system"].as_bool().unwrap_or(false))
+            .map(|n| CachedIssueComment {
+                author: n["author"]["username"].as_str().map(str::to_string),
+                body: n["body"].as_str().unwrap_or_default().to_string(),
+                created_at: n["created_at"].as_str().map(str::to_string),
+            })
+            .collect())
+    }
+
+    fn add_issue_comment(&self, repo: &str, number: i64, body: &str) -> CmdResult<Option<String>> {
+        let (host, path, project) = Self::split(repo)?;
+        let note = Self::api(
+            host,
+            "POST",
+            &format!("projects/{project}/issues/{number}/notes"),
+            &[("body", body)],
+        )?;
+        Ok(note["id"]
+            .as_i64()
+            .map(|id| format!("https://{host}/{path}/-/issues/{number}#note_{id}")))
+    }
+
+    /// The project's `.gitlab/issue_templates`, which GitLab serves as
+    /// plain markdown; labels there are `/label` quick actions in the body.
+    fn issue_templates(&self, repo: &str) -> CmdResult<Vec<IssueTemplate>> {
+        let (host, _, project) = Self::split(repo)?;
+        let list = Self::api(
+            host,
+            "GET",
+            &format!("projects/{project}/templates/issues"),
+            &[],
+        )?;
+        list.as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t["key"].as_str())
+            .map(|key| {
+                let template = Self::api(
+                    host,
+                    "GET",
+                    &format!(
+                        "projects/{project}/templates/issues/{}",
+                        key.replace(' ', "%20")
+                    ),
+                    &[],
+                )?;
+                Ok(IssueTemplate {
+                    file: format!("{key}.md"),
+                    name: template["name"].as_str().unwrap_or(key).to_string(),
+                    about: None,
+                    title: None,
+                    labels: Vec::new(),
+                    body: template["content"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Readable text of a `to_cmd_err` JSON error, for reports that list
+/// several failures side by side.
+fn error_message(err: &str) -> String {
+    let json: Value = serde_json::from_str(err).unwrap_or_default();
+    let details = &json["details"];
+    details["stderr"]
+        .as_str()
+        .or_else(|| details["reason"].as_str())
+        .unwrap_or(err)
+        .to_string()
+}
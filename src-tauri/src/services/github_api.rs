@@ -0,0 +1,391 @@
+//! GitHub REST client for the issue operations, authenticated with a
+//! personal access token kept in the OS keychain.  When no token is stored,
+//! callers in `commands::github` fall back to the `gh` CLI.
+
+use crate::commands::github::{GH_NETWORK_ERROR, GH_NOT_AUTHENTICATED, GH_RATE_LIMITED};
+use crate::error::CommanderError;
+use crate::models::CachedIssueComment;
+use crate::services::github_queue;
+use crate::utils::urlencoding_simple;
+use parking_lot::RwLock;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const API_BASE: &str = "https://api.github.com";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+const KEYCHAIN_SERVICE: &str = "claude-commander";
+const KEYCHAIN_ACCOUNT: &str = "github-token";
+
+/// Reading the keychain spawns a process, so the token is looked up once;
+/// the outer `None` means "not looked up yet".
+static TOKEN: RwLock<Option<Option<String>>> = RwLock::new(None);
+
+/// The stored token, if any.
+pub fn stored_token() -> Option<String> {
+    if let Some(cached) = TOKEN.read().clone() {
+        return cached;
+    }
+    let token = keychain_read();
+    *TOKEN.write() = Some(token.clone());
+    token
+}
+
+/// Store `token` in the keychain, or remove the stored one with `None`.
+pub fn set_stored_token(token: Option<&str>) -> Result<(), CommanderError> {
+    match token {
+        Some(token) => {
+            // Tokens are `ghp_…` / `github_pat_…`; anything else would also
+            // break the `security -i` command line below.
+            if token.is_empty() || !token.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(CommanderError::internal(
+                    "That does not look like a GitHub token",
+                ));
+            }
+            keychain_write(token)?;
+        }
+        None => keychain_delete()?,
+    }
+    *TOKEN.write() = Some(token.map(str::to_string));
+    Ok(())
+}
+
+// ─── Keychain ───────────────────────────────────────────────────────────────
+//
+// macOS: the `security` tool; elsewhere libsecret's `secret-tool`.  The token
+// is passed on stdin so it never shows up in the process list.
+
+fn keychain_read() -> Option<String> {
+    let output = if cfg!(target_os = "macos") {
+        Command::new("security")
+            .args([
+                "find-generic-password",
+                "-s",
+                KEYCHAIN_SERVICE,
+                "-a",
+                KEYCHAIN_ACCOUNT,
+                "-w",
+            ])
+            .output()
+    } else {
+        Command::new("secret-tool")
+            .args([
+                "lookup",
+                "service",
+                KEYCHAIN_SERVICE,
+                "account",
+                KEYCHAIN_ACCOUNT,
+            ])
+            .output()
+    }
+    .ok()?;
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !token.is_empty()).then_some(token)
+}
+
+fn keychain_write(token: &str) -> Result<(), CommanderError> {
+    let (mut command, input) = if cfg!(target_os = "macos") {
+        let mut command = Command::new("security");
+        command.arg("-i");
+        let input = format!(
+            "add-generic-password -U -s {KEYCHAIN_SERVICE} -a {KEYCHAIN_ACCOUNT} -w {token}\n"
+        );
+        (command, input)
+    } else {
+        let mut command = Command::new("secret-tool");
+        command.args([
+            "store",
+            "--label=Claude Commander GitHub token",
+            "service",
+            KEYCHAIN_SERVICE,
+            "account",
+            KEYCHAIN_ACCOUNT,
+        ]);
+        (command, token.to_string())
+    };
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| CommanderError::internal(format!("No usable keychain: {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(CommanderError::internal(format!(
+            "Could not store the token in the keychain: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+fn keychain_delete() -> Result<(), CommanderError> {
+    let result = if cfg!(target_os = "macos") {
+        Command::new("security")
+            .args([
+                "delete-generic-password",
+                "-s",
+                KEYCHAIN_SERVICE,
+                "-a",
+                KEYCHAIN_ACCOUNT,
+            ])
+            .output()
+    } else {
+        Command::new("secret-tool")
+            .args([
+                "clear",
+                "service",
+                KEYCHAIN_SERVICE,
+                "account",
+                KEYCHAIN_ACCOUNT,
+            ])
+            .output()
+    };
+    // A missing entry is already the desired state.
+    result.map(|_| ()).map_err(CommanderError::io)
+}
+
+// ─── Client ─────────────────────────────────────────────────────────────────
+
+pub struct TokenStatus {
+    pub login: String,
+    /// Raw `X-OAuth-Scopes` header; absent for fine-grained tokens.
+    pub scopes: Option<String>,
+    pub rate_limit_remaining: Option<i64>,
+    pub rate_limit_reset: Option<i64>,
+}
+
+pub struct GithubApi {
+    client: Client,
+    token: String,
+}
+
+impl GithubApi {
+    /// A client for the stored token, or `None` when there is none.
+    pub fn from_keychain() -> Option<Self> {
+        stored_token().and_then(|token| Self::with_token(token).ok())
+    }
+
+    pub fn with_token(token: String) -> Result<Self, CommanderError> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("claude-commander/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(CommanderError::internal)?;
+        Ok(Self { client, token })
+    }
+
+    /// Who the token belongs to, its scopes and the remaining rate limit,
+    /// from `GET /user`.  Also validates a token before it is stored.
+    pub fn token_status(&self) -> Result<TokenStatus, CommanderError> {
+        let response = self.send_raw(self.request(Method::GET, "/user"))?;
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let scopes = header("x-oauth-scopes");
+        let rate_limit_remaining = header("x-ratelimit-remaining").and_then(|v| v.parse().ok());
+        let rate_limit_reset = header("x-ratelimit-reset").and_then(|v| v.parse().ok());
+        let user: Value = response.json().map_err(CommanderError::parse)?;
+        let login = user["login"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| CommanderError::parse("Missing 'login' in GitHub response"))?;
+        Ok(TokenStatus {
+            login,
+            scopes,
+            rate_limit_remaining,
+            rate_limit_reset,
+        })
+    }
+
+    /// Open an issue and return its `(number, url)`.
+    pub fn create_issue(
+        &self,
+        repo: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<(i64, String), CommanderError> {
+        let issue = self.send(
+            self.request(Method::POST, &format!("/repos/{repo}/issues"))
+                .json(&json!({ "title": title, "body": body, "labels": labels })),
+        )?;
+        match (issue["number"].as_i64(), issue["html_url"].as_str()) {
+            (Some(number), Some(url)) => Ok((number, url.to_string())),
+            _ => Err(CommanderError::parse(
+                "Missing number or URL in GitHub response",
+            )),
+        }
+    }
+
+    pub fn close_issue(&self, repo: &str, number: i64) -> Result<(), CommanderError> {
+        self.send(
+            self.request(Method::PATCH, &format!("/repos/{repo}/issues/{number}"))
+                .json(&json!({ "state": "closed" })),
+        )?;
+        Ok(())
+    }
+
+    /// Run a GraphQL query and return the whole response, so callers can
+    /// use the `data` that came back alongside any `errors`.
+    pub fn graphql(&self, query: &str, variables: Value) -> Result<Value, CommanderError> {
+        self.send(
+            self.request(Method::POST, "/graphql")
+                .json(&json!({ "query": query, "variables": variables })),
+        )
+    }
+
+    /// The first 100 comments on an issue, oldest first.
+    pub fn issue_comments(
+        &self,
+        repo: &str,
+        number: i64,
+    ) -> Result<Vec<CachedIssueComment>, CommanderError> {
+        let comments = self.send(self.request(
+            Method::GET,
+            &format!("/repos/{repo}/issues/{number}/comments?per_page=100"),
+        ))?;
+        Ok(comments
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|c| CachedIssueComment {
+                author: c["user"]["login"].as_str().map(str::to_string),
+                body: c["body"].as_str().unwrap_or_default().to_string(),
+                created_at: c["created_at"].as_str().map(str::to_string),
+            })
+            .collect())
+    }
+
+    /// Post a comment and return its URL.
+    pub fn add_issue_comment(
+        &self,
+        repo: &str,
+        number: i64,
+        body: &str,
+    ) -> Result<Option<String>, CommanderError> {
+        let comment = self.send(
+            self.request(
+                Method::POST,
+                &format!("/repos/{repo}/issues/{number}/comments"),
+            )
+            .json(&json!({ "body": body })),
+        )?;
+        Ok(comment["html_url"].as_str().map(str::to_string))
+    }
+
+    /// A file's raw text (`raw`) or a directory's JSON listing from the
+    /// default branch of `repo`, or `None` when `path` does not exist.
+    pub fn contents(
+        &self,
+        repo: &str,
+        path: &str,
+        raw: bool,
+    ) -> Result<Option<String>, CommanderError> {
+        let accept = if raw {
+            "application/vnd.github.raw"
+        } else {
+            "application/vnd.github+json"
+        };
+        // Escape each segment, so names with spaces, `#` or `?` stay in the path.
+        let path = path
+            .split('/')
+            .map(urlencoding_simple)
+            .collect::<Vec<_>>()
+            .join("/");
+        let request = self.request_accepting(
+            Method::GET,
+            &format!("/repos/{repo}/contents/{path}"),
+            accept,
+        );
+        let response = request.send().map_err(network_error)?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        check_response(response)?
+            .text()
+            .map(Some)
+            .map_err(CommanderError::parse)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.request_accepting(method, path, "application/vnd.github+json")
+    }
+
+    fn request_accepting(&self, method: Method, path: &str, accept: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{API_BASE}{path}"))
+            .bearer_auth(&self.token)
+            .header("Accept", accept)
+            .header("X-GitHub-Api-Version", "2022-11-28")
+    }
+
+    /// Send and decode the JSON body.
+    fn send(&self, request: RequestBuilder) -> Result<Value, CommanderError> {
+        self.send_raw(request)?
+            .json()
+            .map_err(CommanderError::parse)
+    }
+
+    /// Send, mapping auth and rate-limit failures to the same
+    /// `GITHUB_AUTH_ERROR` kinds the `gh` checks use.
+    fn send_raw(&self, request: RequestBuilder) -> Result<Response, CommanderError> {
+        check_response(request.send().map_err(network_error)?)
+    }
+}
+
+fn network_error(e: reqwest::Error) -> CommanderError {
+    CommanderError::GithubAuthError {
+        kind: GH_NETWORK_ERROR.to_string(),
+        reason: format!("GitHub request failed: {e}"),
+    }
+}
+
+/// Pass successful responses through; turn the rest into errors.
+fn check_response(response: Response) -> Result<Response, CommanderError> {
+    let auth_error = |kind: &str, reason: String| CommanderError::GithubAuthError {
+        kind: kind.to_string(),
+        reason,
+    };
+    let status = response.status();
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<i64>().ok())
+    };
+    let remaining = header("x-ratelimit-remaining");
+    github_queue::record_rate_limit(remaining, header("x-ratelimit-reset"));
+    let rate_limited = remaining == Some(0);
+    if status.is_success() {
+        return Ok(response);
+    }
+    let message = response
+        .json::<Value>()
+        .ok()
+        .and_then(|v| v["message"].as_str().map(str::to_string))
+        .unwrap_or_else(|| status.to_string());
+    Err(match status {
+        StatusCode::UNAUTHORIZED => auth_error(
+            GH_NOT_AUTHENTICATED,
+            "The stored GitHub token was rejected; replace it in Settings.".to_string(),
+        ),
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS if rate_limited => auth_error(
+            GH_RATE_LIMITED,
+            "GitHub API rate limit reached; wait for the reset time and retry.".to_string(),
+        ),
+        _ => CommanderError::internal(format!("GitHub API error: {message}")),
+    })
+}
@@ -0,0 +1,229 @@
+//! Shared dispatcher for GitHub reads, so a busy dashboard does not hammer
+//! the API.  Callers name each request with a key: a response younger than
+//! the caller's TTL is served from memory, a request already in flight is
+//! joined instead of repeated, at most a few run at once, and nothing is
+//! sent while the rate limit is known to be exhausted.
+//!
+//! Writes bypass the queue, but should drop the cached reads they make
+//! stale with `GithubQueue::invalidate`.
+
+use crate::commands::github::GH_RATE_LIMITED;
+use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use parking_lot::{Condvar, Mutex};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Requests allowed to run against GitHub at the same time.
+const MAX_CONCURRENT: usize = 4;
+
+/// An exhausted rate limit that resets within this long is waited out;
+/// beyond it, callers fail with `GH_RATE_LIMITED` straight away.
+const MAX_RESET_WAIT: Duration = Duration::from_secs(5);
+
+/// How long to hold off after `gh` reports a rate limit, since it does not
+/// pass on the reset time.
+const GH_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Expired responses are swept out once the cache holds this many.
+const CACHE_SWEEP_THRESHOLD: usize = 256;
+
+/// The latest `(x-ratelimit-remaining, x-ratelimit-reset)` seen on any
+/// response.  The limit belongs to the account, not to one client, so the
+/// REST client, `gh` and the queue share it.
+static RATE_LIMIT: Mutex<Option<(i64, i64)>> = Mutex::new(None);
+
+/// Note the rate-limit headers of a GitHub response.
+pub fn record_rate_limit(remaining: Option<i64>, reset: Option<i64>) {
+    if let (Some(remaining), Some(reset)) = (remaining, reset) {
+        *RATE_LIMIT.lock() = Some((remaining, reset));
+    }
+}
+
+/// One request in flight, which callers with the same key wait on.
+struct Pending {
+    result: Mutex<Option<CmdResult<Value>>>,
+    done: Condvar,
+}
+
+pub struct GithubQueue {
+    /// Responses by key, with the time they expire.
+    cache: Mutex<HashMap<String, (Instant, Value)>>,
+    in_flight: Mutex<HashMap<String, Arc<Pending>>>,
+    running: Mutex<usize>,
+    slot_freed: Condvar,
+}
+
+impl GithubQueue {
+    pub fn new() -> Self {
+        Self {
+            cache: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            running: Mutex::new(0),
+            slot_freed: Condvar::new(),
+        }
+    }
+
+    /// The response for `key`: cached if younger than `ttl`, shared with an
+    /// identical request already in flight, or fetched now.  Failures are
+    /// handed to the callers waiting on them but never cached.
+    pub fn call<T, F>(&self, key: &str, ttl: Duration, fetch: F) -> CmdResult<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> CmdResult<T>,
+    {
+        let value = self.call_value(key, ttl, || {
+            serde_json::to_value(fetch()?).map_err(|e| to_cmd_err(CommanderError::from(e)))
+        })?;
+        serde_json::from_value(value).map_err(|e| to_cmd_err(CommanderError::from(e)))
+    }
+
+    /// Drop the cached responses whose key starts with `prefix`.
+    pub fn invalidate(&self, prefix: &str) {
+        self.cache.lock().retain(|key, _| !key.starts_with(prefix));
+    }
+
+    fn call_value(
+        &self,
+        key: &str,
+        ttl: Duration,
+        fetch: impl FnOnce() -> CmdResult<Value>,
+    ) -> CmdResult<Value> {
+        let pending = {
+            let mut in_flight = self.in_flight.lock();
+            // Checked under the in-flight lock: a request finishing now
+            // caches its response before it leaves `in_flight`.
+            if let Some(value) = self.cached(key) {
+                return Ok(value);
+            }
+            if let Some(pending) = in_flight.get(key) {
+                let pending = pending.clone();
+                drop(in_flight);
+                let mut result = pending.result.lock();
+                loop {
+                    if let Some(result) = result.as_ref() {
+                        return result.clone();
+                    }
+                    pending.done.wait(&mut result);
+                }
+            }
+            let pending = Arc::new(Pending {
+                result: Mutex::new(None),
+                done: Condvar::new(),
+            });
+            in_flight.insert(key.to_string(), pending.clone());
+            pending
+        };
+
+        let finish = Finish {
+            queue: self,
+            key,
+            pending,
+        };
+        let result = self.dispatch(fetch);
+        if let Ok(value) = &result {
+            let mut cache = self.cache.lock();
+            if cache.len() >= CACHE_SWEEP_THRESHOLD {
+                let now = Instant::now();
+                cache.retain(|_, (expires, _)| *expires > now);
+            }
+            cache.insert(key.to_string(), (Instant::now() + ttl, value.clone()));
+        }
+        *finish.pending.result.lock() = Some(result.clone());
+        result
+    }
+
+    fn cached(&self, key: &str) -> Option<Value> {
+        let cache = self.cache.lock();
+        let (expires, value) = cache.get(key)?;
+        (*expires > Instant::now()).then(|| value.clone())
+    }
+
+    /// Run `fetch` once the rate limit allows it and a slot is free.
+    fn dispatch(&self, fetch: impl FnOnce() -> CmdResult<Value>) -> CmdResult<Value> {
+        wait_for_rate_limit()?;
+        let _slot = self.acquire_slot();
+        let result = fetch();
+        if let Err(e) = &result {
+            if e.contains(GH_RATE_LIMITED) || e.to_lowercase().contains("rate limit") {
+                back_off();
+            }
+        }
+        result
+    }
+
+    fn acquire_slot(&self) -> Slot<'_> {
+        let mut running = self.running.lock();
+        while *running >= MAX_CONCURRENT {
+            self.slot_freed.wait(&mut running);
+        }
+        *running += 1;
+        Slot(self)
+    }
+}
+
+/// A concurrency slot, given back when dropped.
+struct Slot<'a>(&'a GithubQueue);
+
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        *self.0.running.lock() -= 1;
+        self.0.slot_freed.notify_one();
+    }
+}
+
+/// Wakes the callers waiting on a request and retires it, even when the
+/// fetch panicked.
+struct Finish<'a> {
+    queue: &'a GithubQueue,
+    key: &'a str,
+    pending: Arc<Pending>,
+}
+
+impl Drop for Finish<'_> {
+    fn drop(&mut self) {
+        self.queue.in_flight.lock().remove(self.key);
+        let mut result = self.pending.result.lock();
+        if result.is_none() {
+            *result = Some(Err(to_cmd_err(CommanderError::internal(
+                "GitHub request failed",
+            ))));
+        }
+        self.pending.done.notify_all();
+    }
+}
+
+/// Sleep through an exhausted rate limit that resets shortly, or fail.
+fn wait_for_rate_limit() -> CmdResult<()> {
+    let Some((0, reset)) = *RATE_LIMIT.lock() else {
+        return Ok(());
+    };
+    let wait = reset - chrono::Utc::now().timestamp();
+    if wait <= 0 {
+        return Ok(());
+    }
+    let wait = Duration::from_secs(wait as u64);
+    if wait > MAX_RESET_WAIT {
+        return Err(to_cmd_err(CommanderError::GithubAuthError {
+            kind: GH_RATE_LIMITED.to_string(),
+            reason: format!(
+                "GitHub API rate limit reached; it resets in {} min.",
+                wait.as_secs().div_ceil(60)
+            ),
+        }));
+    }
+    std::thread::sleep(wait);
+    Ok(())
+}
+
+/// Treat the limit as exhausted for a while, unless a later reset is known.
+fn back_off() {
+    let until = chrono::Utc::now().timestamp() + GH_RATE_LIMIT_BACKOFF.as_secs() as i64;
+    let mut rate_limit = RATE_LIMIT.lock();
+    if !matches!(*rate_limit, Some((0, reset)) if reset >= until) {
+        *rate_limit = Some((0, until));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::commands::maintenance::run_maintenance;
+use crate::commands::settings::get_setting;
+use crate::state::AppState;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// How often the scheduler wakes up to see whether maintenance is due.
+const POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+const MAINTENANCE_INTERVAL_DAYS: i64 = 7;
+
+/// Start the weekly maintenance loop.  Due-ness is judged from the
+/// `last_maintenance_at` setting, so a manual run also resets the clock.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let state = app_handle.state::<AppState>();
+        if !maintenance_due(&state) {
+            continue;
+        }
+        match run_maintenance(&state) {
+            Ok(report) if report.errors.is_empty() => log::info!(
+                "Maintenance: pruned {} rows in {} ms",
+                report.pruned_rows,
+                report.duration_ms
+            ),
+            Ok(report) => log::warn!("Maintenance finished with errors: {:?}", report.errors),
+            Err(e) => log::warn!("Maintenance failed: {}", e),
+        }
+    });
+}
+
+fn maintenance_due(state: &AppState) -> bool {
+    let db = state.db.lock();
+    let Some(conn) = db.as_ref() else {
+        return false;
+    };
+    let last = get_setting(conn, "last_maintenance_at")
+        .flatten()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok());
+    match last {
+        Some(t) => {
+            chrono::Utc::now().signed_duration_since(t)
+                >= chrono::Duration::days(MAINTENANCE_INTERVAL_DAYS)
+        }
+        None => true,
+    }
+}
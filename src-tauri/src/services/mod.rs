@@ -1 +1,12 @@
+pub mod claude_processes;
 pub mod file_watcher;
+pub mod forge;
+pub mod github_api;
+pub mod github_queue;
+pub mod maintenance;
+pub mod notifications;
+pub mod presentation;
+pub mod session_index;
+pub mod status_sync;
+pub mod suggestions;
+pub mod update_scheduler;
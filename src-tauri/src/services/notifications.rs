@@ -0,0 +1,184 @@
+use crate::commands::settings::{get_json_setting, get_setting};
+use crate::models::{default_notification_priorities, AppNotification};
+use crate::state::AppState;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Emitted with an `AppNotification` for every notification actually delivered
+/// (immediate ones and digests alike).
+pub const EVENT_NOTIFICATION: &str = "notification";
+
+pub const TRIGGER_UPDATE_AVAILABLE: &str = "update_available";
+pub const TRIGGER_STATUS_SYNC: &str = "status_sync";
+pub const TRIGGER_SESSION_STARTED: &str = "session_started";
+pub const TRIGGER_SESSION_ENDED: &str = "session_ended";
+/// Trigger name used for the coalesced digest itself.
+pub const TRIGGER_DIGEST: &str = "digest";
+
+pub const PRIORITIES: &[&str] = &["high", "normal", "low", "off"];
+
+/// Minimum gap between two immediate notifications from the same trigger;
+/// anything sooner is folded into the next digest instead.
+const PER_TRIGGER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// How often the digest loop checks whether a digest is due.
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How many entries a digest lists by title before summarising the rest.
+const DIGEST_PREVIEW: usize = 5;
+
+/// Low-priority notifications waiting for the next digest, plus the
+/// per-trigger rate-limit bookkeeping.
+pub struct NotificationQueue {
+    pending: Mutex<Vec<AppNotification>>,
+    last_sent: Mutex<HashMap<String, Instant>>,
+}
+
+impl NotificationQueue {
+    pub fn new() -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+            last_sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn pending(&self) -> Vec<AppNotification> {
+        self.pending.lock().clone()
+    }
+}
+
+/// Route a notification by its trigger's configured priority: `high` and
+/// `normal` are delivered now (rate-limited per trigger), `low` waits for the
+/// digest, `off` is dropped.
+pub fn notify(app: &AppHandle, trigger: &str, title: impl Into<String>, body: impl Into<String>) {
+    let state = app.state::<AppState>();
+    let priority = trigger_priority(&state, trigger);
+    if priority == "off" {
+        return;
+    }
+
+    let notification = AppNotification {
+        trigger: trigger.to_string(),
+        priority: priority.clone(),
+        title: title.into(),
+        body: body.into(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+
+    let immediate = priority != "low" && {
+        let mut last_sent = state.notifications.last_sent.lock();
+        let cooled_down = last_sent
+            .get(trigger)
+            .is_none_or(|t| t.elapsed() >= PER_TRIGGER_COOLDOWN);
+        // High priority bypasses the cooldown but still resets it.
+        if cooled_down || priority == "high" {
+            last_sent.insert(trigger.to_string(), Instant::now());
+            true
+        } else {
+            false
+        }
+    };
+
+    if immediate {
+        deliver(app, &notification);
+    } else {
+        state.notifications.pending.lock().push(notification);
+    }
+}
+
+/// Start the digest loop.  The interval is re-read from settings on every
+/// wake-up, like the other background services.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_digest = Instant::now();
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let state = app_handle.state::<AppState>();
+            let minutes = {
+                let db = state.db.lock();
+                db.as_ref()
+                    .and_then(|conn| get_setting(conn, "notification_digest_minutes").flatten())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(crate::models::DEFAULT_NOTIFICATION_DIGEST_MINUTES)
+            };
+            if last_digest.elapsed() < Duration::from_secs(minutes.max(1) * 60) {
+                continue;
+            }
+
+            last_digest = Instant::now();
+            flush_digest(&app_handle);
+        }
+    });
+}
+
+/// Deliver everything pending as one digest notification.  Returns how many
+/// notifications it contained.
+pub fn flush_digest(app: &AppHandle) -> usize {
+    let batch: Vec<AppNotification> =
+        std::mem::take(&mut *app.state::<AppState>().notifications.pending.lock());
+    match batch.len() {
+        0 => {}
+        // A digest of one is just that notification.
+        1 => deliver(app, &batch[0]),
+        n => {
+            let mut lines: Vec<String> = batch
+                .iter()
+                .take(DIGEST_PREVIEW)
+                .map(|n| format!("• {}", n.title))
+                .collect();
+            if n > DIGEST_PREVIEW {
+                lines.push(format!("…and {} more", n - DIGEST_PREVIEW));
+            }
+            deliver(
+                app,
+                &AppNotification {
+                    trigger: TRIGGER_DIGEST.to_string(),
+                    priority: "low".to_string(),
+                    title: format!("{n} updates"),
+                    body: lines.join("\n"),
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                },
+            );
+        }
+    }
+    batch.len()
+}
+
+fn trigger_priority(state: &AppState, trigger: &str) -> String {
+    let db = state.db.lock();
+    let configured: Option<HashMap<String, String>> = db
+        .as_ref()
+        .and_then(|conn| get_json_setting(conn, "notification_priorities"));
+    configured
+        .and_then(|p| p.get(trigger).cloned())
+        .or_else(|| default_notification_priorities().remove(trigger))
+        .unwrap_or_else(|| "normal".to_string())
+}
+
+/// Hand the notification to the frontend and, on macOS, to Notification Center.
+fn deliver(app: &AppHandle, notification: &AppNotification) {
+    let _ = app.emit(EVENT_NOTIFICATION, notification);
+
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(&notification.body),
+            applescript_string(&notification.title)
+        );
+        if let Err(e) = std::process::Command::new("osascript")
+            .args(["-e", &script])
+            .spawn()
+        {
+            log::warn!("failed to post desktop notification: {}", e);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn applescript_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
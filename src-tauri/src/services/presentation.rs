@@ -0,0 +1,314 @@
+use crate::commands::claude::read_claude_sessions;
+use crate::commands::planning::load_planning_items;
+use crate::commands::projects::load_db_projects;
+use crate::models::{PlanningItem, PlanningStatus, PresentationServerInfo};
+use crate::state::AppState;
+use serde::Serialize;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// How often the served page reloads itself.
+const REFRESH_SECS: u32 = 15;
+/// Recent Claude sessions listed under the board.
+const ACTIVITY_LIMIT: usize = 10;
+const ACCEPT_POLL: Duration = Duration::from_millis(200);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Connections served at once; further ones are closed unanswered.
+const MAX_CONNECTIONS: usize = 8;
+/// Longest request line read; the rest of a longer one is ignored.
+const MAX_REQUEST_LINE: u64 = 8192;
+
+const COLUMNS: &[(PlanningStatus, &str)] = &[
+    (PlanningStatus::Backlog, "Backlog"),
+    (PlanningStatus::Todo, "Todo"),
+    (PlanningStatus::InProgress, "In Progress"),
+    (PlanningStatus::Done, "Done"),
+];
+
+/// Serves a read-only, auto-refreshing view of one project's board and
+/// recent Claude activity.  The server stops when this value is dropped.
+pub struct PresentationServer {
+    pub info: PresentationServerInfo,
+    stop: Arc<AtomicBool>,
+}
+
+impl PresentationServer {
+    /// Bind on all interfaces (`port` 0 picks a free one), so other devices on
+    /// the LAN can open the board, and start serving.  Every request must
+    /// carry the token.
+    pub fn start(app_handle: AppHandle, project_id: String, port: u16) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)))?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let info = PresentationServerInfo {
+            project_id: project_id.clone(),
+            port,
+            url: format!("http://{}:{}/?token={}", lan_address(), port, token),
+            token: token.clone(),
+            started_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+        let active = Arc::new(AtomicUsize::new(0));
+        std::thread::spawn(move || {
+            while !stop_flag.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        if active.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                            active.fetch_sub(1, Ordering::AcqRel);
+                            continue;
+                        }
+                        let guard = ConnectionGuard(active.clone());
+                        let app = app_handle.clone();
+                        let project_id = project_id.clone();
+                        let token = token.clone();
+                        std::thread::spawn(move || {
+                            let _guard = guard;
+                            if let Err(e) = handle_connection(&app, stream, &project_id, &token) {
+                                log::debug!("presentation request failed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(ACCEPT_POLL);
+                    }
+                    Err(e) => {
+                        log::warn!("presentation server on port {} stopped: {}", port, e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { info, stop })
+    }
+}
+
+impl Drop for PresentationServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Counts a connection as active until its handler finishes.
+struct ConnectionGuard(Arc<AtomicUsize>);
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Board snapshot, also served as JSON at `/api/board`.
+#[derive(Serialize)]
+struct BoardSnapshot {
+    project_name: String,
+    items: Vec<PlanningItem>,
+    activity: Vec<SessionActivity>,
+    generated_at: String,
+}
+
+#[derive(Serialize)]
+struct SessionActivity {
+    session_id: String,
+    message_count: usize,
+    last_message_at: Option<String>,
+}
+
+fn handle_connection(
+    app: &AppHandle,
+    mut stream: TcpStream,
+    project_id: &str,
+    token: &str,
+) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+
+    let mut request_line = String::new();
+    BufReader::new((&stream).take(MAX_REQUEST_LINE)).read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let target = parts.next().unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    if method != "GET" {
+        return respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "GET only",
+        );
+    }
+    let authorized = query.split('&').any(|pair| {
+        pair.strip_prefix("token=")
+            .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()))
+    });
+    if !authorized {
+        return respond(
+            &mut stream,
+            "401 Unauthorized",
+            "text/plain",
+            "Missing or invalid token",
+        );
+    }
+
+    let Some(snapshot) = board_snapshot(app, project_id) else {
+        return respond(
+            &mut stream,
+            "404 Not Found",
+            "text/plain",
+            "Project not found",
+        );
+    };
+    match path {
+        "/" => respond(
+            &mut stream,
+            "200 OK",
+            "text/html; charset=utf-8",
+            &render_page(&snapshot),
+        ),
+        "/api/board" => respond(
+            &mut stream,
+            "200 OK",
+            "application/json",
+            &serde_json::to_string(&snapshot).unwrap_or_default(),
+        ),
+        _ => respond(&mut stream, "404 Not Found", "text/plain", "Not found"),
+    }
+}
+
+/// Compare without returning early, so response timing does not reveal how
+/// much of a guessed token was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+fn board_snapshot(app: &AppHandle, project_id: &str) -> Option<BoardSnapshot> {
+    let state = app.state::<AppState>();
+    let (project_name, items) = {
+        let db = state.db.lock();
+        let conn = db.as_ref()?;
+        let project = load_db_projects(conn)
+            .ok()?
+            .into_iter()
+            .find(|p| p.id == project_id)?;
+        (project.name, load_planning_items(conn, project_id).ok()?)
+    }; // DB lock released before walking the session files
+
+    let activity = read_claude_sessions(state)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|s| s.project_id.as_deref() == Some(project_id))
+        .take(ACTIVITY_LIMIT)
+        .map(|s| SessionActivity {
+            session_id: s.id,
+            message_count: s.message_count,
+            last_message_at: s.last_message_at,
+        })
+        .collect();
+
+    Some(BoardSnapshot {
+        project_name,
+        items,
+        activity,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+fn render_page(snapshot: &BoardSnapshot) -> String {
+    let mut columns = String::new();
+    for (status, label) in COLUMNS {
+        let cards: String = snapshot
+            .items
+            .iter()
+            .filter(|i| &i.status == status)
+            .map(|i| format!("<li>{}</li>", escape_html(&i.subject)))
+            .collect();
+        let count = snapshot
+            .items
+            .iter()
+            .filter(|i| &i.status == status)
+            .count();
+        columns.push_str(&format!(
+            "<section><h2>{label} <span>{count}</span></h2><ul>{cards}</ul></section>"
+        ));
+    }
+
+    let activity: String = if snapshot.activity.is_empty() {
+        "<li class=\"muted\">No Claude sessions yet</li>".to_string()
+    } else {
+        snapshot
+            .activity
+            .iter()
+            .map(|s| {
+                format!(
+                    "<li><code>{}</code> · {} messages · {}</li>",
+                    escape_html(&s.session_id.chars().take(8).collect::<String>()),
+                    s.message_count,
+                    escape_html(s.last_message_at.as_deref().unwrap_or("—"))
+                )
+            })
+            .collect()
+    };
+
+    format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\">\
+         <meta http-equiv=\"refresh\" content=\"{REFRESH_SECS}\">\
+         <title>{name}</title><style>{STYLE}</style></head><body>\
+         <header><h1>{name}</h1><span class=\"muted\">Updated {generated}</span></header>\
+         <main>{columns}</main>\
+         <footer><h2>Recent activity</h2><ul>{activity}</ul></footer>\
+         </body></html>",
+        name = escape_html(&snapshot.project_name),
+        generated = escape_html(&snapshot.generated_at),
+    )
+}
+
+const STYLE: &str = "body{margin:0;padding:24px;font:16px system-ui,sans-serif;\
+background:#0b0d12;color:#e5e7eb}header{display:flex;align-items:baseline;gap:16px}\
+h1{margin:0 0 16px}h2{font-size:15px;text-transform:uppercase;letter-spacing:.05em}\
+h2 span{color:#9ca3af}main{display:grid;grid-template-columns:repeat(4,1fr);gap:16px}\
+section{background:#151922;border-radius:10px;padding:12px}ul{list-style:none;margin:0;\
+padding:0}section li{background:#1f2430;border-radius:6px;padding:8px 10px;margin:6px 0}\
+footer li{padding:4px 0}.muted{color:#9ca3af}code{color:#93c5fd}";
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Best guess at this machine's LAN address: the local end of a UDP socket
+/// "connected" to a public address (no packets are sent).
+fn lan_address() -> String {
+    UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .and_then(|socket| {
+            socket.connect((Ipv4Addr::new(8, 8, 8, 8), 80))?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|_| Ipv4Addr::LOCALHOST.to_string())
+}
@@ -0,0 +1,42 @@
+use crate::commands::activity::update_activity_cache;
+use crate::commands::cost_ledger::{check_budget, update_cost_ledger};
+use crate::commands::session_search::index_sessions;
+use crate::state::AppState;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Pause between indexing passes.  Unchanged transcripts are skipped by
+/// mtime and size, so a pass over an up-to-date index is cheap.
+const POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Start the background session indexer, which also keeps the cost ledger
+/// and activity counts current and raises budget warnings.  The first pass runs right away so
+/// search works shortly after launch.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || loop {
+        let state = app_handle.state::<AppState>();
+        match index_sessions(&state) {
+            Ok(stats) if stats.indexed > 0 || stats.removed > 0 => log::info!(
+                "Session index: {} indexed ({} turns), {} removed, {} total",
+                stats.indexed,
+                stats.turns,
+                stats.removed,
+                stats.total_sessions
+            ),
+            Ok(_) => {}
+            Err(e) => log::warn!("Session indexing failed: {}", e),
+        }
+        match update_cost_ledger(&state) {
+            Ok(updated) if updated > 0 => {
+                log::info!("Cost ledger: {} sessions re-costed", updated);
+                check_budget(&app_handle);
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Cost ledger update failed: {}", e),
+        }
+        if let Err(e) = update_activity_cache(&state) {
+            log::warn!("Activity cache update failed: {}", e);
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    });
+}
@@ -0,0 +1,86 @@
+use crate::commands::settings::get_setting;
+use crate::commands::status_sync::{apply_status_sync, load_sync_rules, plan_status_sync};
+use crate::models::StatusSyncAction;
+use crate::services::notifications;
+use crate::state::AppState;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Emitted with the `StatusSyncAction` list after a background run changed something.
+pub const EVENT_STATUS_SYNC_APPLIED: &str = "status-sync-applied";
+
+/// How often the loop re-reads `sync_rules` to see whether a run is due.
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start the background status sync loop.  Like the update scheduler, the
+/// rules are re-read on every wake-up so toggling `sync_rules.enabled` or the
+/// interval takes effect without a restart.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let state = app_handle.state::<AppState>();
+            let Ok(rules) = load_sync_rules(&state) else {
+                continue;
+            };
+            if !rules.enabled || is_offline(&state) {
+                continue;
+            }
+
+            let interval = Duration::from_secs(rules.interval_minutes.max(1) * 60);
+            if last_run.is_some_and(|t| t.elapsed() < interval) {
+                continue;
+            }
+            last_run = Some(Instant::now());
+
+            let mut actions = match plan_status_sync(&state, &rules) {
+                Ok(actions) => actions,
+                Err(e) => {
+                    log::warn!("status sync failed: {}", e);
+                    continue;
+                }
+            };
+            if actions.is_empty() {
+                continue;
+            }
+
+            apply_status_sync(&state, &mut actions);
+            for action in actions.iter().filter(|a| a.error.is_some()) {
+                log::warn!(
+                    "status sync: {} failed: {}",
+                    action.description,
+                    action.error.as_deref().unwrap_or_default()
+                );
+            }
+            let applied: Vec<StatusSyncAction> =
+                actions.into_iter().filter(|a| a.applied).collect();
+            if !applied.is_empty() {
+                notifications::notify(
+                    &app_handle,
+                    notifications::TRIGGER_STATUS_SYNC,
+                    format!(
+                        "Status sync applied {} change{}",
+                        applied.len(),
+                        if applied.len() == 1 { "" } else { "s" }
+                    ),
+                    applied
+                        .iter()
+                        .map(|a| a.description.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                );
+                let _ = app_handle.emit(EVENT_STATUS_SYNC_APPLIED, applied);
+            }
+        }
+    });
+}
+
+fn is_offline(state: &AppState) -> bool {
+    let db = state.db.lock();
+    db.as_ref()
+        .and_then(|conn| get_setting(conn, "offline_mode").flatten())
+        .as_deref()
+        == Some("true")
+}
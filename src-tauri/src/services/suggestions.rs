@@ -0,0 +1,24 @@
+use crate::commands::suggestions::detect_suggestions;
+use crate::state::AppState;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Pause between passes over recent commits.
+const POLL_INTERVAL: Duration = Duration::from_secs(2 * 60);
+
+/// Start the background suggestion detector.  New suggestions are emitted
+/// as an `auto-suggestions` event carrying only the ones found in that pass.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || loop {
+        let state = app_handle.state::<AppState>();
+        match detect_suggestions(&state) {
+            Ok(created) if !created.is_empty() => {
+                log::info!("Suggestions: {} new", created.len());
+                let _ = app_handle.emit("auto-suggestions", &created);
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Suggestion detection failed: {}", e),
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    });
+}
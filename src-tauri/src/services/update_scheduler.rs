@@ -0,0 +1,107 @@
+use crate::commands::settings::{get_setting, set_setting};
+use crate::models::UpdateInfo;
+use crate::services::notifications;
+use crate::state::AppState;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_updater::UpdaterExt;
+
+/// Emitted with an `UpdateInfo` payload when a scheduled check finds a new version.
+pub const EVENT_UPDATE_AVAILABLE: &str = "update-available";
+
+/// How often the scheduler wakes up to see whether a check is due.
+const POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start the background update-check loop.  The thread lives for the rest of
+/// the process; each wake-up re-reads the settings so frequency / offline
+/// changes take effect without a restart.
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let mut first_run = true;
+        loop {
+            if check_due(&app_handle, first_run) {
+                run_check(&app_handle);
+            }
+            first_run = false;
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
+
+/// Decide whether a check should run now based on `update_check_frequency`,
+/// `offline_mode`, and the recorded `last_update_check`.
+fn check_due(app_handle: &AppHandle, first_run: bool) -> bool {
+    let state = app_handle.state::<AppState>();
+    let db = state.db.lock();
+    let Some(conn) = db.as_ref() else {
+        return false;
+    };
+
+    let offline = get_setting(conn, "offline_mode").flatten().as_deref() == Some("true");
+    if offline {
+        return false;
+    }
+
+    let frequency = get_setting(conn, "update_check_frequency")
+        .flatten()
+        .unwrap_or_else(|| "daily".to_string());
+    let interval = match frequency.as_str() {
+        "manual" => return false,
+        "startup" => return first_run,
+        "weekly" => chrono::Duration::days(7),
+        _ => chrono::Duration::days(1),
+    };
+
+    let last = get_setting(conn, "last_update_check")
+        .flatten()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok());
+
+    match last {
+        Some(t) => chrono::Utc::now().signed_duration_since(t) >= interval,
+        None => true,
+    }
+}
+
+fn run_check(app_handle: &AppHandle) {
+    let updater = match app_handle.updater_builder().build() {
+        Ok(u) => u,
+        Err(e) => {
+            log::warn!("update check skipped: {}", e);
+            return;
+        }
+    };
+
+    match tauri::async_runtime::block_on(updater.check()) {
+        Ok(Some(update)) => {
+            record_update_check(app_handle);
+            notifications::notify(
+                app_handle,
+                notifications::TRIGGER_UPDATE_AVAILABLE,
+                format!("Claude Commander {} is available", update.version),
+                "Open Settings to install the update.",
+            );
+            let _ = app_handle.emit(
+                EVENT_UPDATE_AVAILABLE,
+                UpdateInfo {
+                    available: true,
+                    version: Some(update.version.clone()),
+                    body: update.body.clone(),
+                },
+            );
+        }
+        Ok(None) => record_update_check(app_handle),
+        Err(e) => log::warn!("scheduled update check failed: {}", e),
+    }
+}
+
+/// Persist the current time as `last_update_check`.
+pub fn record_update_check(app_handle: &AppHandle) {
+    let state = app_handle.state::<AppState>();
+    let db = state.db.lock();
+    if let Some(conn) = db.as_ref() {
+        let now = chrono::Utc::now().to_rfc3339();
+        if let Err(e) = set_setting(conn, "last_update_check", &now) {
+            log::warn!("failed to record last_update_check: {}", e);
+        }
+    }
+}
@@ -1,11 +1,31 @@
 use crate::services::file_watcher::{ClaudeWatcher, ProjectWatcher};
+use crate::services::github_queue::GithubQueue;
+use crate::services::notifications::NotificationQueue;
+use crate::services::presentation::PresentationServer;
 use parking_lot::Mutex;
 use rusqlite::Connection;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 pub struct AppState {
     pub db: Mutex<Option<Connection>>,
     pub claude_watcher: Mutex<Option<ClaudeWatcher>>,
     pub project_watcher: Mutex<Option<ProjectWatcher>>,
+    /// Cancel flags for in-flight clone / fetch / pull operations, keyed by
+    /// the caller-supplied operation id.
+    pub git_operations: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// Stop flags for sessions being tailed into the follow viewer, keyed by
+    /// session id.
+    pub session_follows: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// Cancel flags for running disk usage walks, keyed by project path.
+    pub disk_usage_scans: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// Low-priority notifications waiting for the next digest.
+    pub notifications: NotificationQueue,
+    /// Cache, dedupe and rate-limit gate shared by GitHub reads.
+    pub github: GithubQueue,
+    /// Read-only LAN board views, keyed by project id.
+    pub presentation_servers: Mutex<HashMap<String, PresentationServer>>,
 }
 
 impl AppState {
@@ -14,6 +34,12 @@ impl AppState {
             db: Mutex::new(None),
             claude_watcher: Mutex::new(None),
             project_watcher: Mutex::new(None),
+            git_operations: Mutex::new(HashMap::new()),
+            session_follows: Mutex::new(HashMap::new()),
+            disk_usage_scans: Mutex::new(HashMap::new()),
+            notifications: NotificationQueue::new(),
+            github: GithubQueue::new(),
+            presentation_servers: Mutex::new(HashMap::new()),
         }
     }
 }
@@ -1,10 +1,27 @@
+use crate::commands::settings::allowed_roots;
 use crate::error::{to_cmd_err, CmdResult, CommanderError};
+use crate::state::AppState;
+pub use commander_core::filter::parse_timestamp;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 
-/// Validate that `path` is within the user's home directory.
+/// Validate that `path` is within the user's home directory or a root
+/// whitelisted in the `allowed_roots` setting.
+pub fn validate_allowed_path(state: &AppState, path: &str) -> CmdResult<PathBuf> {
+    let roots = {
+        let db = state.db.lock();
+        db.as_ref().map(allowed_roots).unwrap_or_default()
+    };
+    validate_path_within(path, &roots)
+}
+
+/// Validate that `path` is within the user's home directory or one of
+/// `extra_roots` (which must already be canonical).
 /// Accepts both existing and not-yet-existing paths (for files about to be created):
 /// if the path itself doesn't exist, the parent directory is canonicalized instead.
-pub fn validate_home_path(path: &str) -> CmdResult<std::path::PathBuf> {
-    let p = std::path::Path::new(path);
+pub fn validate_path_within(path: &str, extra_roots: &[PathBuf]) -> CmdResult<PathBuf> {
+    let p = Path::new(path);
 
     // Try full canonicalization first; fall back to canonicalizing the parent
     // so that paths for files that don't exist yet (e.g. new .env files) still work.
@@ -21,11 +38,118 @@ pub fn validate_home_path(path: &str) -> CmdResult<std::path::PathBuf> {
     let home = dirs::home_dir()
         .ok_or_else(|| to_cmd_err(CommanderError::internal("Cannot determine home dir")))?;
 
-    if !canonical.starts_with(&home) {
+    if !canonical.starts_with(&home) && !extra_roots.iter().any(|r| canonical.starts_with(r)) {
         return Err(to_cmd_err(CommanderError::internal(
-            "Path must be within home directory",
+            "Path must be within home directory or an allowed root",
         )));
     }
 
     Ok(canonical)
 }
+
+/// Run an external CLI to completion.  Spawn failures and non-zero exits are
+/// mapped to `CommanderError::ExternalTool` with a coarse `kind`, so callers
+/// never surface raw stderr without a machine-readable classification.
+pub fn run_external_tool(command: &mut Command) -> CmdResult<Output> {
+    let tool = command.get_program().to_string_lossy().to_string();
+
+    let output = command.output().map_err(|e| {
+        let kind = if e.kind() == std::io::ErrorKind::NotFound {
+            "not_installed"
+        } else {
+            "failed"
+        };
+        to_cmd_err(CommanderError::ExternalTool {
+            tool: tool.clone(),
+            kind: kind.to_string(),
+            exit_code: None,
+            stderr: e.to_string(),
+        })
+    })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(to_cmd_err(CommanderError::ExternalTool {
+            kind: classify_tool_failure(&stderr).to_string(),
+            tool,
+            exit_code: output.status.code(),
+            stderr,
+        }));
+    }
+
+    Ok(output)
+}
+
+/// Heuristic classification of CLI stderr shared by gh, fly, vercel and npm.
+fn classify_tool_failure(stderr: &str) -> &'static str {
+    let s = stderr.to_lowercase();
+    const AUTH_HINTS: &[&str] = &[
+        "auth login",
+        "not logged in",
+        "not authenticated",
+        "unauthorized",
+        "http 401",
+        "fly auth",
+        "vercel login",
+        "npm login",
+        "e401",
+    ];
+    const NETWORK_HINTS: &[&str] = &[
+        "could not resolve host",
+        "connection refused",
+        "network is unreachable",
+        "timed out",
+        "timeout",
+        "econnrefused",
+        "enotfound",
+        "etimedout",
+        "tls handshake",
+    ];
+    if AUTH_HINTS.iter().any(|h| s.contains(h)) {
+        "not_authenticated"
+    } else if NETWORK_HINTS.iter().any(|h| s.contains(h)) {
+        "network"
+    } else {
+        "failed"
+    }
+}
+
+/// Write `content` to `path` atomically using a sibling temp file + rename.
+/// On POSIX (macOS/Linux) `std::fs::rename` is atomic within the same filesystem,
+/// so readers always see either the old or the new content, never a partial write.
+pub fn write_file_atomic(path: &Path, content: String) -> CmdResult<()> {
+    let filename = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| to_cmd_err(CommanderError::internal("path has no filename")))?;
+
+    let tmp_path = path.with_file_name(format!("{}.tmp", filename));
+
+    {
+        let mut file = std::fs::File::create(&tmp_path)
+            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+        file.write_all(content.as_bytes())
+            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+        file.sync_all()
+            .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+    }
+
+    std::fs::rename(&tmp_path, path)
+        .map_err(|e| to_cmd_err(CommanderError::io(e)))?;
+
+    Ok(())
+}
+
+/// Simple percent-encoding for URL parameters and path segments (only ASCII
+/// safe chars pass through)
+pub fn urlencoding_simple(s: &str) -> String {
+    s.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.' || b == b'~' {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect()
+}
@@ -14,8 +14,11 @@ import ProjectKanban from "@/pages/ProjectKanban";
 import ProjectSessions from "@/pages/ProjectSessions";
 import ClaudeTasks from "@/pages/ClaudeTasks";
 import ClaudePlans from "@/pages/ClaudePlans";
+import ClaudeCommands from "@/pages/ClaudeCommands";
 import ClaudeSessions from "@/pages/ClaudeSessions";
 import SessionDetail from "@/pages/SessionDetail";
+import ClaudeUsage from "@/pages/ClaudeUsage";
+import ClaudeHistory from "@/pages/ClaudeHistory";
 import SettingsPage from "@/pages/SettingsPage";
 
 export default function App() {
@@ -44,11 +47,14 @@ export default function App() {
           <Route index element={<Navigate to="tasks" replace />} />
           <Route path="tasks" element={<ClaudeTasks />} />
           <Route path="plans" element={<ClaudePlans />} />
+          <Route path="commands" element={<ClaudeCommands />} />
           <Route path="sessions" element={<ClaudeSessions />} />
           <Route
             path="sessions/:projectKey/:sessionId"
             element={<SessionDetail />}
           />
+          <Route path="history" element={<ClaudeHistory />} />
+          <Route path="usage" element={<ClaudeUsage />} />
         </Route>
 
         {/* Settings */}
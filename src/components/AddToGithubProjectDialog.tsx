@@ -0,0 +1,140 @@
+import { useState } from "react";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { FolderKanban, Loader2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+
+interface Props {
+  repo: string;
+  number: number;
+  onClose: () => void;
+}
+
+const selectClass =
+  "w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary";
+
+/**
+ * Put a linked issue on one of the owner's GitHub Projects boards,
+ * optionally straight into a Status column.
+ */
+export function AddToGithubProjectDialog({ repo, number, onClose }: Props) {
+  const [owner] = repo.split("/");
+  const [projectId, setProjectId] = useState("");
+  const [columnId, setColumnId] = useState("");
+
+  const {
+    data: projects = [],
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey: ["github-projects", owner],
+    queryFn: () => api.listGithubProjects(owner),
+    staleTime: 5 * 60_000,
+    retry: false,
+  });
+  const openProjects = projects.filter((p) => !p.closed);
+  const project = openProjects.find((p) => p.id === projectId);
+
+  const addMutation = useMutation({
+    mutationFn: () =>
+      api.addIssueToGithubProject(projectId, repo, number, columnId || null),
+    onSuccess: () => {
+      toast.success(`Added #${number} to ${project?.title ?? "the project"}`);
+      onClose();
+    },
+    onError: (e) => toast.error(`Could not add to project: ${String(e)}`),
+  });
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-md mx-4 p-5">
+        <div className="flex items-center gap-2 mb-4">
+          <FolderKanban className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">
+            Add {repo}#{number} to a project
+          </h2>
+        </div>
+
+        {isLoading ? (
+          <div className="flex justify-center py-6">
+            <Loader2 className="size-4 animate-spin text-muted-foreground" />
+          </div>
+        ) : error ? (
+          <p className="text-xs text-destructive">{String(error)}</p>
+        ) : openProjects.length === 0 ? (
+          <p className="text-xs text-muted-foreground py-4">
+            {owner} has no open GitHub Projects.
+          </p>
+        ) : (
+          <div className="space-y-3">
+            <div>
+              <label className="text-xs font-medium text-muted-foreground block mb-1">
+                Project
+              </label>
+              <select
+                className={selectClass}
+                value={projectId}
+                onChange={(e) => {
+                  setProjectId(e.target.value);
+                  setColumnId("");
+                }}
+              >
+                <option value="">Choose a project…</option>
+                {openProjects.map((p) => (
+                  <option key={p.id} value={p.id}>
+                    #{p.number} {p.title}
+                  </option>
+                ))}
+              </select>
+            </div>
+            {project && project.columns.length > 0 && (
+              <div>
+                <label className="text-xs font-medium text-muted-foreground block mb-1">
+                  Column
+                </label>
+                <select
+                  className={selectClass}
+                  value={columnId}
+                  onChange={(e) => setColumnId(e.target.value)}
+                >
+                  <option value="">No status</option>
+                  {project.columns.map((c) => (
+                    <option key={c.id} value={c.id}>
+                      {c.name}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            )}
+          </div>
+        )}
+
+        <div className="flex gap-2 pt-4">
+          <Button
+            variant="outline"
+            size="sm"
+            className="flex-1"
+            onClick={onClose}
+          >
+            Cancel
+          </Button>
+          <Button
+            size="sm"
+            className="flex-1"
+            onClick={() => addMutation.mutate()}
+            disabled={!projectId || addMutation.isPending}
+          >
+            {addMutation.isPending && (
+              <Loader2 className="size-3 mr-1.5 animate-spin" />
+            )}
+            Add to project
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+}
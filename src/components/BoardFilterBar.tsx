@@ -0,0 +1,142 @@
+import { useEffect, useState } from "react";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
+import { Bookmark, Filter, X } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { cn } from "@/lib/utils";
+import type { FilterBoard } from "@/types";
+
+/** Pull the human-readable reason out of a serialized `CommanderError`. */
+function errorReason(e: unknown): string {
+  try {
+    return JSON.parse(String(e)).details?.reason ?? String(e);
+  } catch {
+    return String(e);
+  }
+}
+
+/**
+ * Filter expression input plus the board's saved filters, e.g.
+ * `status:in_progress tag:acme updated:<7d`.  `onApply` receives the
+ * expression when the user presses Enter or picks a saved filter.
+ */
+export function BoardFilterBar({
+  board,
+  value,
+  error,
+  onApply,
+}: {
+  board: FilterBoard;
+  /** The currently applied expression. */
+  value: string;
+  /** Parse error for `value`, shown under the input. */
+  error?: unknown;
+  onApply: (query: string) => void;
+}) {
+  const queryClient = useQueryClient();
+  const [draft, setDraft] = useState(value);
+  useEffect(() => setDraft(value), [value]);
+
+  const { data: saved = [] } = useQuery({
+    queryKey: ["saved-filters"],
+    queryFn: api.getSavedFilters,
+  });
+  const boardFilters = saved.filter((f) => f.board === board);
+
+  const saveMutation = useMutation({
+    mutationFn: (name: string) => api.saveFilter(name, board, value),
+    onSuccess: (f) => {
+      queryClient.invalidateQueries({ queryKey: ["saved-filters"] });
+      toast.success(`Saved filter "${f.name}"`);
+    },
+    onError: (e) => toast.error(errorReason(e)),
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: api.deleteSavedFilter,
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["saved-filters"] }),
+  });
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-2">
+        <div className="relative flex-1">
+          <Filter className="size-3.5 absolute left-2.5 top-1/2 -translate-y-1/2 text-muted-foreground" />
+          <input
+            value={draft}
+            onChange={(e) => setDraft(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") onApply(draft.trim());
+              if (e.key === "Escape") onApply("");
+            }}
+            placeholder="status:in_progress owner:alice updated:<7d"
+            spellCheck={false}
+            className={cn(
+              "w-full text-sm font-mono bg-background border rounded-md pl-8 pr-8 py-1.5 focus:outline-none focus:ring-2 focus:ring-ring",
+              error ? "border-destructive" : "border-border",
+            )}
+          />
+          {draft && (
+            <button
+              type="button"
+              title="Clear filter"
+              onClick={() => onApply("")}
+              className="absolute right-2 top-1/2 -translate-y-1/2 text-muted-foreground hover:text-foreground"
+            >
+              <X className="size-3.5" />
+            </button>
+          )}
+        </div>
+        <button
+          type="button"
+          title="Save filter"
+          disabled={!value || !!error || saveMutation.isPending}
+          onClick={() => {
+            const name = window.prompt("Save filter as");
+            if (name?.trim()) saveMutation.mutate(name.trim());
+          }}
+          className="p-1.5 rounded-md text-muted-foreground hover:text-foreground hover:bg-accent disabled:opacity-50 disabled:pointer-events-none"
+        >
+          <Bookmark className="size-4" />
+        </button>
+      </div>
+
+      {error ? (
+        <p className="text-xs text-destructive">{errorReason(error)}</p>
+      ) : null}
+
+      {boardFilters.length > 0 && (
+        <div className="flex flex-wrap gap-1.5">
+          {boardFilters.map((f) => (
+            <span
+              key={f.id}
+              className={cn(
+                "group inline-flex items-center gap-1 text-xs rounded-full border px-2 py-0.5",
+                f.query === value
+                  ? "border-primary bg-primary/10"
+                  : "border-border hover:bg-accent",
+              )}
+            >
+              <button
+                type="button"
+                title={f.query}
+                onClick={() => onApply(f.query)}
+              >
+                {f.name}
+              </button>
+              <button
+                type="button"
+                title="Delete saved filter"
+                onClick={() => deleteMutation.mutate(f.id)}
+                className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-foreground"
+              >
+                <X className="size-3" />
+              </button>
+            </span>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
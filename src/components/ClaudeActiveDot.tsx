@@ -0,0 +1,12 @@
+/** Pulsing marker for a project with a running Claude process. */
+export function ClaudeActiveDot() {
+  return (
+    <span
+      className="relative flex size-2 shrink-0"
+      title="Claude is working here"
+    >
+      <span className="absolute inline-flex size-full rounded-full bg-green-500 opacity-75 animate-ping" />
+      <span className="relative inline-flex size-2 rounded-full bg-green-500" />
+    </span>
+  );
+}
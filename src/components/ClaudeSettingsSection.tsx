@@ -0,0 +1,223 @@
+import { useEffect, useState } from "react";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
+import { Loader2, Save } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import type { ClaudeSettingsFile } from "@/types";
+
+const PERMISSION_MODES = [
+  "default",
+  "acceptEdits",
+  "plan",
+  "bypassPermissions",
+] as const;
+
+interface Draft {
+  model: string;
+  allow: string;
+  deny: string;
+  ask: string;
+  defaultMode: string;
+  hooks: string;
+}
+
+function toDraft(file: ClaudeSettingsFile): Draft {
+  const s = file.settings as Record<string, unknown>;
+  const permissions = (s.permissions ?? {}) as Record<string, unknown>;
+  const lines = (v: unknown) => (Array.isArray(v) ? v.join("\n") : "");
+  return {
+    model: typeof s.model === "string" ? s.model : "",
+    allow: lines(permissions.allow),
+    deny: lines(permissions.deny),
+    ask: lines(permissions.ask),
+    defaultMode:
+      typeof permissions.defaultMode === "string"
+        ? permissions.defaultMode
+        : "",
+    hooks: s.hooks ? JSON.stringify(s.hooks, null, 2) : "",
+  };
+}
+
+/**
+ * Build the top-level patch for `update_claude_settings`.  Permission keys
+ * the form doesn't show (e.g. `additionalDirectories`) are carried over.
+ */
+function toPatch(file: ClaudeSettingsFile, draft: Draft) {
+  const s = file.settings as Record<string, unknown>;
+  const permissions: Record<string, unknown> = {
+    ...((s.permissions ?? {}) as Record<string, unknown>),
+  };
+  for (const key of ["allow", "deny", "ask"] as const) {
+    const rules = draft[key]
+      .split("\n")
+      .map((r) => r.trim())
+      .filter(Boolean);
+    if (rules.length) permissions[key] = rules;
+    else delete permissions[key];
+  }
+  if (draft.defaultMode) permissions.defaultMode = draft.defaultMode;
+  else delete permissions.defaultMode;
+
+  return {
+    model: draft.model.trim() || null,
+    permissions: Object.keys(permissions).length ? permissions : null,
+    // Throws on invalid JSON; the caller reports it.
+    hooks: draft.hooks.trim() ? JSON.parse(draft.hooks) : null,
+  };
+}
+
+function errorCode(e: unknown): string | undefined {
+  try {
+    return JSON.parse(String(e)).code;
+  } catch {
+    return undefined;
+  }
+}
+
+/** Model, permission rules and hooks from Claude Code's settings.json. */
+export function ClaudeSettingsSection() {
+  const queryClient = useQueryClient();
+  const { data: file, isLoading } = useQuery({
+    queryKey: ["claude-settings"],
+    queryFn: () => api.readClaudeSettings(),
+  });
+  const [draft, setDraft] = useState<Draft | null>(null);
+
+  useEffect(() => {
+    if (file) setDraft(toDraft(file));
+  }, [file]);
+
+  const saveMutation = useMutation({
+    mutationFn: (patch: Record<string, unknown>) =>
+      api.updateClaudeSettings(patch, file?.modified_at),
+    onSuccess: (updated) => {
+      queryClient.setQueryData(["claude-settings"], updated);
+      toast.success("Claude settings saved");
+    },
+    onError: (e) => {
+      if (errorCode(e) === "CONFLICT") {
+        toast.error("settings.json changed on disk; reloaded it");
+        queryClient.invalidateQueries({ queryKey: ["claude-settings"] });
+      } else {
+        toast.error(`Save failed: ${String(e)}`);
+      }
+    },
+  });
+
+  function save() {
+    if (!file || !draft) return;
+    let patch;
+    try {
+      patch = toPatch(file, draft);
+    } catch (e) {
+      toast.error(`Hooks are not valid JSON: ${String(e)}`);
+      return;
+    }
+    saveMutation.mutate(patch);
+  }
+
+  const field =
+    "w-full text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring";
+  const update = (patch: Partial<Draft>) =>
+    setDraft((d) => (d ? { ...d, ...patch } : d));
+
+  return (
+    <div className="border border-border rounded-lg p-4 space-y-4">
+      <div className="flex items-center justify-between gap-3">
+        <h2 className="text-sm font-semibold">Claude Code</h2>
+        {file && (
+          <span
+            className="text-xs text-muted-foreground font-mono truncate"
+            title={file.path}
+          >
+            {file.exists ? file.path : `${file.path} (new)`}
+          </span>
+        )}
+      </div>
+
+      {isLoading || !draft ? (
+        <Loader2 className="size-4 animate-spin text-muted-foreground" />
+      ) : (
+        <>
+          <div>
+            <label className="text-sm font-medium block mb-1">Model</label>
+            <input
+              value={draft.model}
+              onChange={(e) => update({ model: e.target.value })}
+              placeholder="Claude Code default"
+              className={field}
+            />
+          </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Permission mode
+            </label>
+            <select
+              value={draft.defaultMode}
+              onChange={(e) => update({ defaultMode: e.target.value })}
+              className={field}
+            >
+              <option value="">Not set</option>
+              {PERMISSION_MODES.map((mode) => (
+                <option key={mode} value={mode}>
+                  {mode}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {(["allow", "ask", "deny"] as const).map((key) => (
+            <div key={key}>
+              <label className="text-sm font-medium block mb-1 capitalize">
+                {key}
+              </label>
+              <textarea
+                value={draft[key]}
+                onChange={(e) => update({ [key]: e.target.value })}
+                rows={3}
+                placeholder={
+                  key === "deny" ? "Read(./.env)" : "Bash(npm run test:*)"
+                }
+                spellCheck={false}
+                className={`${field} font-mono`}
+              />
+            </div>
+          ))}
+          <p className="text-xs text-muted-foreground -mt-2">
+            Permission rules, one per line.
+          </p>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">Hooks</label>
+            <textarea
+              value={draft.hooks}
+              onChange={(e) => update({ hooks: e.target.value })}
+              rows={6}
+              placeholder={
+                '{\n  "PostToolUse": [\n    { "matcher": "Edit", "hooks": ' +
+                '[{ "type": "command", "command": "npm run lint" }] }\n  ]\n}'
+              }
+              spellCheck={false}
+              className={`${field} font-mono text-xs`}
+            />
+            <p className="text-xs text-muted-foreground mt-1">
+              The <span className="font-mono">hooks</span> object as JSON.
+              Other keys in settings.json are left untouched.
+            </p>
+          </div>
+
+          <Button size="sm" onClick={save} disabled={saveMutation.isPending}>
+            {saveMutation.isPending ? (
+              <Loader2 className="size-3.5 mr-1 animate-spin" />
+            ) : (
+              <Save className="size-3.5 mr-1" />
+            )}
+            Save Claude settings
+          </Button>
+        </>
+      )}
+    </div>
+  );
+}
@@ -8,8 +8,10 @@ import {
   ListTodo,
   CheckSquare,
   Loader2,
+  MessageSquare,
 } from "lucide-react";
 import { api } from "@/lib/api";
+import { formatRelativeTime } from "@/lib/utils";
 
 type ResultItem =
   | { kind: "project"; id: string; name: string; path: string }
@@ -28,6 +30,15 @@ type ResultItem =
       subject: string;
       team_name: string | null;
       status: string;
+    }
+  | {
+      kind: "session";
+      id: string;
+      session_id: string;
+      project_key: string;
+      cwd: string | null;
+      timestamp: string;
+      snippet: string;
     };
 
 type GroupedResults = {
@@ -40,6 +51,7 @@ const KIND_LABELS: Record<ResultItem["kind"], string> = {
   planning: "Planning Items",
   plan: "Plans",
   task: "Tasks",
+  session: "Sessions",
 };
 
 const KIND_ORDER: ResultItem["kind"][] = [
@@ -47,6 +59,7 @@ const KIND_ORDER: ResultItem["kind"][] = [
   "planning",
   "plan",
   "task",
+  "session",
 ];
 
 function groupResults(results: ResultItem[]): GroupedResults {
@@ -92,6 +105,15 @@ export function CommandPalette() {
     staleTime: 5_000,
   });
 
+  // Transcript full-text search, queried separately so slow FTS lookups
+  // never hold back the other groups
+  const { data: sessionHits, isFetching: isFetchingSessions } = useQuery({
+    queryKey: ["session-search", debouncedQuery],
+    queryFn: () => api.searchSessions(debouncedQuery, 8),
+    enabled: debouncedQuery.length >= 2,
+    staleTime: 5_000,
+  });
+
   // Global Cmd+K toggle
   useEffect(() => {
     const handler = (e: KeyboardEvent) => {
@@ -173,6 +195,15 @@ export function CommandPalette() {
             team_name: t.team_name,
             status: t.status,
           })),
+          ...(sessionHits ?? []).map((h, i) => ({
+            kind: "session" as const,
+            id: `${h.session_id}-${i}`,
+            session_id: h.session_id,
+            project_key: h.project_key,
+            cwd: h.cwd,
+            timestamp: h.timestamp,
+            snippet: h.snippet,
+          })),
         ]
       : (projects ?? []).slice(0, 8).map((p) => ({
           kind: "project" as const,
@@ -203,6 +234,12 @@ export function CommandPalette() {
         case "task":
           navigate("/claude/tasks");
           break;
+        case "session":
+          navigate(
+            `/claude/sessions/${encodeURIComponent(result.project_key)}/` +
+              encodeURIComponent(result.session_id),
+          );
+          break;
       }
     },
     [navigate],
@@ -244,7 +281,7 @@ export function CommandPalette() {
       >
         {/* Search input */}
         <div className="flex items-center gap-3 px-4 py-3 border-b border-border">
-          {isFetching ? (
+          {isFetching || isFetchingSessions ? (
             <Loader2
               className="size-4 text-muted-foreground shrink-0 animate-spin"
               aria-hidden="true"
@@ -268,7 +305,7 @@ export function CommandPalette() {
               setActiveIndex(0);
             }}
             onKeyDown={handleInputKeyDown}
-            placeholder="Search projects, tasks, plans..."
+            placeholder="Search projects, tasks, plans, sessions..."
             className="flex-1 bg-transparent text-sm outline-none placeholder:text-muted-foreground"
           />
           <kbd className="text-xs text-muted-foreground bg-muted px-1.5 py-0.5 rounded">
@@ -288,7 +325,9 @@ export function CommandPalette() {
               role="status"
               className="text-sm text-muted-foreground text-center py-6"
             >
-              {isSearching && !isFetching ? "No results" : "Searching…"}
+              {isSearching && !isFetching && !isFetchingSessions
+                ? "No results"
+                : "Searching…"}
             </p>
           ) : (
             groups.map((group) => (
@@ -403,6 +442,29 @@ const ResultRow = forwardRef<
     );
   }
 
+  if (result.kind === "session") {
+    return (
+      <button {...sharedProps}>
+        <MessageSquare
+          className="size-4 text-muted-foreground shrink-0"
+          aria-hidden="true"
+        />
+        <div className="flex-1 min-w-0">
+          <p className="text-xs line-clamp-2">
+            <Highlighted snippet={result.snippet} />
+          </p>
+          <p className="text-xs text-muted-foreground font-mono truncate">
+            {(result.cwd ?? result.project_key).replace(
+              /^\/Users\/[^/]+/,
+              "~",
+            )}
+            {result.timestamp && ` · ${formatRelativeTime(result.timestamp)}`}
+          </p>
+        </div>
+      </button>
+    );
+  }
+
   // task
   return (
     <button {...sharedProps}>
@@ -424,3 +486,23 @@ const ResultRow = forwardRef<
     </button>
   );
 });
+
+/** Render an FTS snippet, marking the `\u0002` … `\u0003` delimited hits. */
+function Highlighted({ snippet }: { snippet: string }) {
+  return (
+    <>
+      {snippet.split("\u0002").map((part, i) => {
+        if (i === 0) return part;
+        const [hit, rest] = part.split("\u0003");
+        return (
+          <span key={i}>
+            <mark className="bg-yellow-200/60 dark:bg-yellow-500/30 rounded-sm">
+              {hit}
+            </mark>
+            {rest}
+          </span>
+        );
+      })}
+    </>
+  );
+}
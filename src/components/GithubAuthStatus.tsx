@@ -0,0 +1,114 @@
+import { useQuery } from "@tanstack/react-query";
+import { AlertTriangle, CheckCircle2, Loader2, RefreshCw } from "lucide-react";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import type { GithubAuthStatus as AuthStatus } from "@/types";
+
+export const GITHUB_AUTH_QUERY_KEY = ["github-auth"];
+
+function useGithubAuth() {
+  return useQuery({
+    queryKey: GITHUB_AUTH_QUERY_KEY,
+    queryFn: api.checkGithubAuth,
+    staleTime: 60_000,
+  });
+}
+
+function formatReset(reset: number | null): string {
+  if (reset === null) return "";
+  const time = new Date(reset * 1000).toLocaleTimeString([], {
+    hour: "2-digit",
+    minute: "2-digit",
+  });
+  return `, resets at ${time}`;
+}
+
+function ProblemNotice({ status }: { status: AuthStatus }) {
+  if (!status.problem) return null;
+  return (
+    <div className="flex items-start gap-2 rounded-md border border-amber-500/40 bg-amber-500/10 px-3 py-2 text-xs">
+      <AlertTriangle
+        className="size-3.5 mt-0.5 shrink-0 text-amber-600 dark:text-amber-400"
+      />
+      <span>{status.remediation}</span>
+    </div>
+  );
+}
+
+/** Setup guidance shown above GitHub actions when something would fail. */
+export function GithubAuthNotice() {
+  const { data: status } = useGithubAuth();
+  return status ? <ProblemNotice status={status} /> : null;
+}
+
+/** The gh install, signed-in account, scopes and rate limit, for Settings. */
+export function GithubAuthStatusPanel() {
+  const { data: status, isFetching, refetch } = useGithubAuth();
+
+  const rows: [string, string][] = status
+    ? [
+        [
+          "GitHub CLI",
+          status.gh_version ? `gh ${status.gh_version}` : "Not installed",
+        ],
+        ["Credentials", status.token_stored ? "API token" : "gh login"],
+        ["Account", status.login ?? "—"],
+        [
+          "Scopes",
+          status.scopes.length
+            ? status.scopes.join(", ")
+            : status.authenticated
+              ? "Not reported (fine-grained token)"
+              : "—",
+        ],
+        [
+          "Rate limit",
+          status.rate_limit_remaining === null
+            ? "—"
+            : `${status.rate_limit_remaining} requests left` +
+              formatReset(status.rate_limit_reset),
+        ],
+      ]
+    : [];
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between">
+        <p className="text-sm font-medium flex items-center gap-1.5">
+          {status && !status.problem && (
+            <CheckCircle2
+              className="size-3.5 text-green-600 dark:text-green-400"
+            />
+          )}
+          Connection
+        </p>
+        <Button
+          variant="ghost"
+          size="sm"
+          onClick={() => refetch()}
+          disabled={isFetching}
+        >
+          {isFetching ? (
+            <Loader2 className="size-3.5 animate-spin" />
+          ) : (
+            <RefreshCw className="size-3.5" />
+          )}
+          Check
+        </Button>
+      </div>
+      {status && (
+        <>
+          <dl className="grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 text-xs">
+            {rows.map(([label, value]) => (
+              <div key={label} className="contents">
+                <dt className="text-muted-foreground">{label}</dt>
+                <dd className="font-mono truncate">{value}</dd>
+              </div>
+            ))}
+          </dl>
+          <ProblemNotice status={status} />
+        </>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,156 @@
+import { useState } from "react";
+import { useQuery } from "@tanstack/react-query";
+import { ChevronLeft, ChevronRight, Loader2 } from "lucide-react";
+import { api } from "@/lib/api";
+import { cn, formatRelativeTime } from "@/lib/utils";
+import type { GithubIssueState, GithubIssueSummary } from "@/types";
+
+const REPO_PATTERN = /^[\w.-]+\/[\w.-]+$/;
+
+const STATES: GithubIssueState[] = ["open", "closed", "all"];
+
+/**
+ * Pages through a repository's issues via `gh` so one can be picked
+ * instead of pasting its URL.  The text filter only narrows the page shown.
+ */
+export function GithubIssuePicker({
+  repo,
+  selectedUrl,
+  onSelect,
+}: {
+  repo: string;
+  selectedUrl: string;
+  onSelect: (issue: GithubIssueSummary) => void;
+}) {
+  const [state, setState] = useState<GithubIssueState>("open");
+  const [page, setPage] = useState(1);
+  const [filter, setFilter] = useState("");
+  const validRepo = REPO_PATTERN.test(repo);
+
+  const { data, isFetching, error } = useQuery({
+    queryKey: ["github-issues", repo, state, page],
+    queryFn: () => api.listGithubIssues(repo, state, undefined, page),
+    enabled: validRepo,
+    staleTime: 60_000,
+    retry: false,
+  });
+
+  if (!validRepo) {
+    return (
+      <p className="text-xs text-muted-foreground">
+        Enter a repository to browse its issues
+      </p>
+    );
+  }
+
+  const needle = filter.trim().toLowerCase();
+  const issues = (data?.issues ?? []).filter(
+    (i) =>
+      !needle ||
+      i.title.toLowerCase().includes(needle) ||
+      String(i.number).includes(needle) ||
+      i.labels.some((l) => l.toLowerCase().includes(needle)),
+  );
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-2">
+        <input
+          className="flex-1 text-xs bg-muted border border-border rounded-md px-2 py-1 outline-none focus:ring-1 focus:ring-primary"
+          placeholder="Filter this page"
+          value={filter}
+          onChange={(e) => setFilter(e.target.value)}
+        />
+        <select
+          className="text-xs bg-muted border border-border rounded-md px-1.5 py-1"
+          value={state}
+          onChange={(e) => {
+            setState(e.target.value as GithubIssueState);
+            setPage(1);
+          }}
+        >
+          {STATES.map((s) => (
+            <option key={s} value={s}>
+              {s}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      <div className="h-48 overflow-y-auto rounded-md border border-border">
+        {isFetching && !data ? (
+          <div className="flex items-center justify-center h-full">
+            <Loader2 className="size-4 animate-spin text-muted-foreground" />
+          </div>
+        ) : error ? (
+          <p className="p-2 text-xs text-destructive">{String(error)}</p>
+        ) : issues.length === 0 ? (
+          <p className="p-2 text-xs text-muted-foreground">No issues</p>
+        ) : (
+          issues.map((issue) => (
+            <button
+              key={issue.number}
+              type="button"
+              onClick={() => onSelect(issue)}
+              className={cn(
+                "w-full text-left px-2 py-1.5 text-xs border-b border-border last:border-b-0 hover:bg-accent/40",
+                issue.url === selectedUrl && "bg-accent",
+              )}
+            >
+              <div className="flex items-center gap-1.5">
+                <span className="font-mono text-muted-foreground shrink-0">
+                  #{issue.number}
+                </span>
+                <span className="truncate">{issue.title}</span>
+                {issue.state === "closed" && (
+                  <span className="ml-auto shrink-0 text-muted-foreground">
+                    closed
+                  </span>
+                )}
+              </div>
+              {(issue.labels.length > 0 || issue.updated_at) && (
+                <div className="flex items-center gap-1 mt-0.5 text-muted-foreground">
+                  {issue.labels.slice(0, 3).map((l) => (
+                    <span key={l} className="px-1 rounded bg-muted">
+                      {l}
+                    </span>
+                  ))}
+                  {issue.updated_at && (
+                    <span className="ml-auto shrink-0">
+                      {formatRelativeTime(issue.updated_at)}
+                    </span>
+                  )}
+                </div>
+              )}
+            </button>
+          ))
+        )}
+      </div>
+
+      <div className="flex items-center justify-between text-xs text-muted-foreground">
+        <button
+          type="button"
+          className="flex items-center gap-0.5 disabled:opacity-40"
+          onClick={() => setPage((p) => p - 1)}
+          disabled={page <= 1 || isFetching}
+        >
+          <ChevronLeft className="size-3" />
+          Newer
+        </button>
+        <span className="flex items-center gap-1">
+          {isFetching && <Loader2 className="size-3 animate-spin" />}
+          Page {page}
+        </span>
+        <button
+          type="button"
+          className="flex items-center gap-0.5 disabled:opacity-40"
+          onClick={() => setPage((p) => p + 1)}
+          disabled={!data?.has_more || isFetching}
+        >
+          Older
+          <ChevronRight className="size-3" />
+        </button>
+      </div>
+    </div>
+  );
+}
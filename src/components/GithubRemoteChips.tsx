@@ -0,0 +1,53 @@
+import { useQuery } from "@tanstack/react-query";
+import { api } from "@/lib/api";
+import { cn } from "@/lib/utils";
+
+/**
+ * One chip per GitHub remote of the project, shown under a repository
+ * input when there is a choice to make (e.g. a fork's `origin` and
+ * `upstream`).  With `anyForge`, GitLab remotes are offered as well.
+ */
+export function GithubRemoteChips({
+  projectPath,
+  value,
+  onSelect,
+  anyForge = false,
+}: {
+  projectPath: string;
+  value: string;
+  onSelect: (repo: string) => void;
+  anyForge?: boolean;
+}) {
+  const { data: remotes = [] } = useQuery({
+    queryKey: [anyForge ? "issue-remotes" : "github-remotes", projectPath],
+    queryFn: () =>
+      anyForge
+        ? api.listIssueRemotes(projectPath)
+        : api.listGithubRemotes(projectPath),
+    staleTime: Infinity,
+  });
+
+  if (remotes.length < 2) return null;
+
+  return (
+    <div className="flex flex-wrap gap-1 mt-1.5">
+      {remotes.map((r) => (
+        <button
+          key={r.remote}
+          type="button"
+          onClick={() => onSelect(r.repo)}
+          className={cn(
+            "px-2 py-0.5 rounded-full border text-xs transition-colors",
+            r.repo.toLowerCase() === value.trim().toLowerCase()
+              ? "border-primary bg-primary/10 text-foreground"
+              : "border-border text-muted-foreground hover:text-foreground",
+          )}
+          title={r.repo}
+        >
+          <span className="font-medium">{r.remote}</span>{" "}
+          <span className="font-mono">{r.repo}</span>
+        </button>
+      ))}
+    </div>
+  );
+}
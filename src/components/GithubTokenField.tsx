@@ -0,0 +1,71 @@
+import { useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { KeyRound, Loader2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import { GITHUB_AUTH_QUERY_KEY } from "@/components/GithubAuthStatus";
+
+/**
+ * Personal access token for the built-in GitHub client.  The token goes
+ * straight to the OS keychain and is never shown again.
+ */
+export function GithubTokenField() {
+  const queryClient = useQueryClient();
+  const [token, setToken] = useState("");
+
+  const tokenMutation = useMutation({
+    mutationFn: (value: string | null) => api.setGithubToken(value),
+    onSuccess: (login) => {
+      setToken("");
+      queryClient.invalidateQueries({ queryKey: GITHUB_AUTH_QUERY_KEY });
+      toast.success(
+        login ? `Signed in to GitHub as ${login}` : "GitHub token removed",
+      );
+    },
+    onError: (e) => toast.error(`Could not save token: ${String(e)}`),
+  });
+
+  return (
+    <div className="space-y-2">
+      <div>
+        <p className="text-sm font-medium">Personal access token</p>
+        <p className="text-xs text-muted-foreground mt-0.5">
+          Issues are created, closed and refreshed through the GitHub API with
+          this token, kept in the system keychain. Without one the{" "}
+          <span className="font-mono">gh</span> CLI is used.
+        </p>
+      </div>
+      <div className="flex items-center gap-2">
+        <input
+          type="password"
+          value={token}
+          onChange={(e) => setToken(e.target.value)}
+          placeholder="ghp_… or github_pat_…"
+          autoComplete="off"
+          className="flex-1 px-2 py-1 text-sm font-mono bg-background border border-input rounded-md focus:outline-none focus:ring-2 focus:ring-ring"
+        />
+        <Button
+          size="sm"
+          onClick={() => tokenMutation.mutate(token.trim())}
+          disabled={!token.trim() || tokenMutation.isPending}
+        >
+          {tokenMutation.isPending ? (
+            <Loader2 className="size-3.5 animate-spin" />
+          ) : (
+            <KeyRound className="size-3.5" />
+          )}
+          Save
+        </Button>
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => tokenMutation.mutate(null)}
+          disabled={tokenMutation.isPending}
+        >
+          Remove
+        </Button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,139 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Download, Github, Loader2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import { GithubAuthNotice } from "@/components/GithubAuthStatus";
+import { GithubRemoteChips } from "@/components/GithubRemoteChips";
+import type { Project } from "@/types";
+
+const inputClass =
+  "w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary";
+
+/**
+ * Import a repository's open issues into the project's backlog.  Issues
+ * imported before are skipped, so running it again only adds new ones.
+ */
+export function ImportIssuesDialog({
+  project,
+  onClose,
+}: {
+  project: Project;
+  onClose: () => void;
+}) {
+  const queryClient = useQueryClient();
+  const [repo, setRepo] = useState<string | null>(null);
+  const [labels, setLabels] = useState("");
+  const [search, setSearch] = useState("");
+
+  const { data: detectedRepo } = useQuery({
+    queryKey: ["github-repo", project.path],
+    queryFn: () => api.detectGithubRepo(project.path),
+  });
+  const repoValue = repo ?? detectedRepo ?? "";
+
+  const importMutation = useMutation({
+    mutationFn: () =>
+      api.importGithubIssues(project.id, repoValue.trim(), {
+        labels: labels
+          .split(",")
+          .map((l) => l.trim())
+          .filter(Boolean),
+        search: search.trim() || undefined,
+      }),
+    onSuccess: (result) => {
+      queryClient.invalidateQueries({ queryKey: ["planning-items"] });
+      toast.success(
+        `Imported ${result.imported.length} issues` +
+          (result.skipped_count
+            ? `, ${result.skipped_count} already on the board`
+            : ""),
+      );
+      onClose();
+    },
+    onError: (e) => toast.error(`Import failed: ${String(e)}`),
+  });
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-md mx-4 p-5">
+        <div className="flex items-center gap-2 mb-4">
+          <Github className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">Import GitHub issues</h2>
+        </div>
+        <div className="space-y-3">
+          <GithubAuthNotice />
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Repository
+            </label>
+            <input
+              className={inputClass}
+              placeholder="owner/repo"
+              value={repoValue}
+              onChange={(e) => setRepo(e.target.value)}
+            />
+            <GithubRemoteChips
+              projectPath={project.path}
+              value={repoValue}
+              onSelect={setRepo}
+            />
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Labels
+            </label>
+            <input
+              className={inputClass}
+              placeholder="bug, help wanted"
+              value={labels}
+              onChange={(e) => setLabels(e.target.value)}
+            />
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Search
+            </label>
+            <input
+              className={inputClass}
+              placeholder="no:assignee sort:created-asc"
+              value={search}
+              onChange={(e) => setSearch(e.target.value)}
+            />
+          </div>
+          <p className="text-xs text-muted-foreground">
+            Open issues are added to the backlog with their link; issues
+            already on the board are skipped.
+          </p>
+          <div className="flex gap-2 pt-1">
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={onClose}
+            >
+              Cancel
+            </Button>
+            <Button
+              size="sm"
+              className="flex-1"
+              onClick={() => importMutation.mutate()}
+              disabled={!repoValue.trim() || importMutation.isPending}
+            >
+              {importMutation.isPending ? (
+                <Loader2 className="size-3 mr-1.5 animate-spin" />
+              ) : (
+                <Download className="size-3 mr-1.5" />
+              )}
+              Import
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,129 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { ExternalLink, Loader2, MessageSquare, Send } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { formatRelativeTime } from "@/lib/utils";
+import { Button } from "@/components/ui/button";
+
+interface Props {
+  repo: string;
+  number: number;
+  url: string;
+  onClose: () => void;
+}
+
+/** An issue's comment thread, with a box to reply. */
+export function IssueCommentsDialog({ repo, number, url, onClose }: Props) {
+  const queryClient = useQueryClient();
+  const [draft, setDraft] = useState("");
+  const queryKey = ["issue-comments", repo, number];
+
+  const {
+    data: comments = [],
+    isLoading,
+    error,
+  } = useQuery({
+    queryKey,
+    queryFn: () => api.getIssueComments(repo, number),
+    retry: false,
+  });
+
+  const commentMutation = useMutation({
+    mutationFn: () => api.addIssueComment(repo, number, draft.trim()),
+    onSuccess: () => {
+      setDraft("");
+      queryClient.invalidateQueries({ queryKey });
+      toast.success("Comment posted");
+    },
+    onError: (e) => toast.error(`Could not post comment: ${String(e)}`),
+  });
+
+  const canPost = draft.trim().length > 0 && !commentMutation.isPending;
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-xl mx-4 p-5 flex flex-col max-h-[80vh]">
+        <div className="flex items-center gap-2 mb-4">
+          <MessageSquare className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">
+            {repo}#{number}
+          </h2>
+          <button
+            className="ml-auto text-muted-foreground hover:text-foreground"
+            onClick={() => window.open(url, "_blank")}
+            title="Open on GitHub"
+          >
+            <ExternalLink className="size-3.5" />
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-y-auto space-y-3 min-h-24">
+          {isLoading ? (
+            <div className="flex justify-center py-6">
+              <Loader2 className="size-4 animate-spin text-muted-foreground" />
+            </div>
+          ) : error ? (
+            <p className="text-xs text-destructive">{String(error)}</p>
+          ) : comments.length === 0 ? (
+            <p className="text-xs text-muted-foreground py-6 text-center">
+              No comments yet.
+            </p>
+          ) : (
+            comments.map((c, i) => (
+              <div
+                key={`${c.created_at}-${i}`}
+                className="rounded-lg border border-border p-3"
+              >
+                <div className="flex items-center gap-2 mb-1 text-xs">
+                  <span className="font-medium">{c.author ?? "unknown"}</span>
+                  <span className="text-muted-foreground">
+                    {formatRelativeTime(c.created_at)}
+                  </span>
+                </div>
+                <p className="text-sm whitespace-pre-wrap break-words">
+                  {c.body}
+                </p>
+              </div>
+            ))
+          )}
+        </div>
+
+        <div className="mt-4 space-y-2">
+          <textarea
+            className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary resize-none"
+            rows={3}
+            placeholder="Write a comment… (⌘↵ to post)"
+            value={draft}
+            onChange={(e) => setDraft(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter" && (e.metaKey || e.ctrlKey) && canPost) {
+                commentMutation.mutate();
+              }
+            }}
+          />
+          <div className="flex justify-end gap-2">
+            <Button variant="outline" size="sm" onClick={onClose}>
+              Close
+            </Button>
+            <Button
+              size="sm"
+              onClick={() => commentMutation.mutate()}
+              disabled={!canPost}
+            >
+              {commentMutation.isPending ? (
+                <Loader2 className="size-3 mr-1.5 animate-spin" />
+              ) : (
+                <Send className="size-3 mr-1.5" />
+              )}
+              Comment
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
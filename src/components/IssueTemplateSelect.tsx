@@ -0,0 +1,68 @@
+import { useQuery } from "@tanstack/react-query";
+import { api } from "@/lib/api";
+import type { IssueTemplate } from "@/types";
+
+const REPO_PATTERN = /^[\w.-]+(\/[\w.-]+)+$/;
+
+/**
+ * Picks one of the repository's issue templates.  Renders nothing until
+ * the repo has templates, so repos without any keep a plain form.
+ */
+export function IssueTemplateSelect({
+  repo,
+  value,
+  onSelect,
+}: {
+  repo: string;
+  value: string | null;
+  onSelect: (template: IssueTemplate | null) => void;
+}) {
+  const { data: templates = [] } = useQuery({
+    queryKey: ["issue-templates", repo],
+    queryFn: () => api.getIssueTemplates(repo),
+    enabled: REPO_PATTERN.test(repo),
+    staleTime: 5 * 60_000,
+    retry: false,
+  });
+
+  if (templates.length === 0) return null;
+
+  const selected = templates.find((t) => t.file === value);
+
+  return (
+    <div>
+      <label className="text-xs font-medium text-muted-foreground block mb-1">
+        Template
+      </label>
+      <select
+        className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+        value={value ?? ""}
+        onChange={(e) =>
+          onSelect(templates.find((t) => t.file === e.target.value) ?? null)
+        }
+      >
+        <option value="">Blank issue</option>
+        {templates.map((t) => (
+          <option key={t.file} value={t.file}>
+            {t.name}
+          </option>
+        ))}
+      </select>
+      {selected?.about && (
+        <p className="text-xs text-muted-foreground mt-1">{selected.about}</p>
+      )}
+      {selected && selected.labels.length > 0 && (
+        <div className="flex flex-wrap gap-1 mt-1.5">
+          {selected.labels.map((label) => (
+            <span
+              key={label}
+              className="px-1.5 py-0.5 rounded-full bg-muted border border-border text-[10px] text-muted-foreground"
+            >
+              {label}
+            </span>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
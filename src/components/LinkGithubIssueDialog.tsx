@@ -1,10 +1,15 @@
 import { useState } from "react";
-import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import { ExternalLink, Link2, Loader2, Trash2, Github } from "lucide-react";
 import { toast } from "sonner";
 import { api } from "@/lib/api";
 import { Button } from "@/components/ui/button";
-import type { ClaudeTask, TaskGithubLink } from "@/types";
+import { GithubAuthNotice } from "@/components/GithubAuthStatus";
+import { GithubIssuePicker } from "@/components/GithubIssuePicker";
+import { GithubRemoteChips } from "@/components/GithubRemoteChips";
+import { IssueTemplateSelect } from "@/components/IssueTemplateSelect";
+import { PROJECT_SOURCE_PREFIX } from "@/components/SourceBadge";
+import type { ClaudeTask, IssueTemplate, TaskGithubLink } from "@/types";
 
 interface Props {
   task: ClaudeTask & { team_id: string };
@@ -25,10 +30,42 @@ export function LinkGithubIssueDialog({
   const queryClient = useQueryClient();
   const [mode, setMode] = useState<Mode>(existingLink ? "link" : "create");
 
+  // Tasks from a project's own .claude directory can offer its remotes.
+  const projectId = task.source.startsWith(PROJECT_SOURCE_PREFIX)
+    ? task.source.slice(PROJECT_SOURCE_PREFIX.length)
+    : null;
+  const { data: projects } = useQuery({
+    queryKey: ["projects"],
+    queryFn: api.getProjects,
+    enabled: projectId !== null,
+  });
+  const projectPath = projects?.find((p) => p.id === projectId)?.path;
+
   // Create new issue state
   const [repo, setRepo] = useState(detectedRepo ?? "");
   const [title, setTitle] = useState(task.subject);
   const [body, setBody] = useState(task.description ?? "");
+  const [template, setTemplate] = useState<IssueTemplate | null>(null);
+
+  // Templates belong to a repository, so switching repos drops the choice.
+  const changeRepo = (next: string) => {
+    setRepo(next);
+    setTemplate(null);
+  };
+
+  // A template adds its title prefix and puts the task's description above
+  // the template body; going back to a blank issue restores both.
+  const applyTemplate = (next: IssueTemplate | null) => {
+    setTemplate(next);
+    const prefix = next?.title?.trim();
+    setTitle(prefix ? `${prefix} ${task.subject}` : task.subject);
+    const description = task.description?.trim() ?? "";
+    setBody(
+      next
+        ? [description, next.body.trim()].filter(Boolean).join("\n\n")
+        : description,
+    );
+  };
 
   // Link existing state
   const [issueUrl, setIssueUrl] = useState(
@@ -47,6 +84,7 @@ export function LinkGithubIssueDialog({
         repo.trim(),
         title.trim(),
         body.trim(),
+        template?.labels,
       );
       await api.upsertTaskGithubLink({
         task_id: task.id,
@@ -68,8 +106,8 @@ export function LinkGithubIssueDialog({
   const linkMutation = useMutation({
     mutationFn: async () => {
       const url = issueUrl.trim();
-      if (!url.startsWith("https://github.com/")) {
-        throw new Error("Please enter a full GitHub issue URL");
+      if (!url.startsWith("https://")) {
+        throw new Error("Please enter a full issue URL");
       }
       await api.upsertTaskGithubLink({
         task_id: task.id,
@@ -105,7 +143,7 @@ export function LinkGithubIssueDialog({
       className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
       onClick={(e) => e.target === e.currentTarget && onClose()}
     >
-      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-md mx-4 p-5">
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-lg mx-4 p-5">
         {/* Header */}
         <div className="flex items-center gap-2 mb-4">
           <Github className="size-4 text-muted-foreground" />
@@ -143,6 +181,7 @@ export function LinkGithubIssueDialog({
 
         {mode === "create" ? (
           <div className="space-y-3">
+            <GithubAuthNotice />
             <div>
               <label className="text-xs font-medium text-muted-foreground block mb-1">
                 Repository <span className="text-destructive">*</span>
@@ -151,9 +190,22 @@ export function LinkGithubIssueDialog({
                 className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
                 placeholder="owner/repo"
                 value={repo}
-                onChange={(e) => setRepo(e.target.value)}
+                onChange={(e) => changeRepo(e.target.value)}
               />
+              {projectPath && (
+                <GithubRemoteChips
+                  projectPath={projectPath}
+                  value={repo}
+                  onSelect={changeRepo}
+                  anyForge
+                />
+              )}
             </div>
+            <IssueTemplateSelect
+              repo={repo.trim()}
+              value={template?.file ?? null}
+              onSelect={applyTemplate}
+            />
             <div>
               <label className="text-xs font-medium text-muted-foreground block mb-1">
                 Title
@@ -225,6 +277,30 @@ export function LinkGithubIssueDialog({
                 </button>
               </div>
             )}
+            <div>
+              <label className="text-xs font-medium text-muted-foreground block mb-1">
+                Repository
+              </label>
+              <input
+                className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+                placeholder="owner/repo"
+                value={repo}
+                onChange={(e) => changeRepo(e.target.value)}
+              />
+              {projectPath && (
+                <GithubRemoteChips
+                  projectPath={projectPath}
+                  value={repo}
+                  onSelect={changeRepo}
+                  anyForge
+                />
+              )}
+            </div>
+            <GithubIssuePicker
+              repo={repo.trim()}
+              selectedUrl={issueUrl}
+              onSelect={(issue) => setIssueUrl(issue.url)}
+            />
             <div>
               <label className="text-xs font-medium text-muted-foreground block mb-1">
                 GitHub Issue URL
@@ -0,0 +1,210 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { Github, Link2, Loader2, Plus, Trash2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import { GithubIssuePicker } from "@/components/GithubIssuePicker";
+import { GithubRemoteChips } from "@/components/GithubRemoteChips";
+import type { PlanningItem, PlanningItemGithubLink, Project } from "@/types";
+
+export const PLANNING_ITEM_LINKS_QUERY_KEY = ["planning-item-github-links"];
+
+interface Props {
+  item: PlanningItem;
+  project: Project;
+  existingLink?: PlanningItemGithubLink;
+  onClose: () => void;
+}
+
+/** Link a Kanban card to an existing GitHub issue, or remove its link. */
+export function LinkPlanningItemIssueDialog({
+  item,
+  project,
+  existingLink,
+  onClose,
+}: Props) {
+  const queryClient = useQueryClient();
+  const [repoInput, setRepoInput] = useState<string | null>(
+    existingLink?.github_repo ?? null,
+  );
+  const [issueUrl, setIssueUrl] = useState(
+    existingLink?.github_issue_url ?? "",
+  );
+
+  const { data: detectedRepo } = useQuery({
+    queryKey: ["github-repo", project.path],
+    queryFn: () => api.detectGithubRepo(project.path),
+    staleTime: Infinity,
+  });
+  // GitLab-only projects have no GitHub repo; fall back to their remotes.
+  const { data: issueRemotes } = useQuery({
+    queryKey: ["issue-remotes", project.path],
+    queryFn: () => api.listIssueRemotes(project.path),
+    staleTime: Infinity,
+    enabled: detectedRepo === null,
+  });
+  const repo = repoInput ?? detectedRepo ?? issueRemotes?.[0]?.repo ?? "";
+
+  const invalidate = () =>
+    queryClient.invalidateQueries({ queryKey: PLANNING_ITEM_LINKS_QUERY_KEY });
+
+  const linkMutation = useMutation({
+    mutationFn: async () => {
+      const url = issueUrl.trim();
+      if (!url.startsWith("https://")) {
+        throw new Error("Please enter a full issue URL");
+      }
+      await api.upsertPlanningItemGithubLink({
+        item_id: item.id,
+        github_issue_url: url,
+      });
+    },
+    onSuccess: () => {
+      invalidate();
+      toast.success("Issue linked");
+      onClose();
+    },
+    onError: (e: Error) => toast.error(e.message),
+  });
+
+  const createMutation = useMutation({
+    mutationFn: () => api.createIssueFromPlanningItem(item.id, repo.trim()),
+    onSuccess: () => {
+      invalidate();
+      queryClient.invalidateQueries({ queryKey: ["planning-items"] });
+      toast.success("Issue created and linked");
+      onClose();
+    },
+    onError: (e) => toast.error(`Could not create issue: ${String(e)}`),
+  });
+
+  const unlinkMutation = useMutation({
+    mutationFn: () => api.deletePlanningItemGithubLink(item.id),
+    onSuccess: () => {
+      invalidate();
+      toast.success("Link removed");
+      onClose();
+    },
+    onError: () => toast.error("Failed to remove link"),
+  });
+
+  const isPending =
+    linkMutation.isPending ||
+    createMutation.isPending ||
+    unlinkMutation.isPending;
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-lg mx-4 p-5">
+        <div className="flex items-center gap-2 mb-4">
+          <Github className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">Link card to GitHub Issue</h2>
+        </div>
+        <p className="text-xs text-muted-foreground mb-4 line-clamp-1">
+          Card: <span className="text-foreground">{item.subject}</span>
+        </p>
+
+        <div className="space-y-3">
+          {existingLink && (
+            <div className="flex items-center gap-2 p-2 bg-muted rounded-lg text-xs">
+              <Link2 className="size-3 text-muted-foreground shrink-0" />
+              <a
+                href="#"
+                onClick={(e) => {
+                  e.preventDefault();
+                  window.open(existingLink.github_issue_url, "_blank");
+                }}
+                className="text-primary truncate hover:underline"
+              >
+                #{existingLink.github_issue_number} —{" "}
+                {existingLink.github_repo}
+              </a>
+              <button
+                className="ml-auto text-destructive hover:text-destructive/80"
+                onClick={() => unlinkMutation.mutate()}
+                disabled={isPending}
+                title="Remove link"
+              >
+                <Trash2 className="size-3" />
+              </button>
+            </div>
+          )}
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Repository
+            </label>
+            <input
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              placeholder="owner/repo"
+              value={repo}
+              onChange={(e) => setRepoInput(e.target.value)}
+            />
+            <GithubRemoteChips
+              projectPath={project.path}
+              value={repo}
+              onSelect={setRepoInput}
+              anyForge
+            />
+          </div>
+          <GithubIssuePicker
+            repo={repo.trim()}
+            selectedUrl={issueUrl}
+            onSelect={(issue) => setIssueUrl(issue.url)}
+          />
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              GitHub Issue URL
+            </label>
+            <input
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              placeholder="https://github.com/owner/repo/issues/123"
+              value={issueUrl}
+              onChange={(e) => setIssueUrl(e.target.value)}
+            />
+          </div>
+          {!existingLink && (
+            <button
+              className="flex items-center gap-1.5 text-xs text-primary hover:underline disabled:opacity-50"
+              onClick={() => createMutation.mutate()}
+              disabled={isPending || !repo.trim()}
+            >
+              {createMutation.isPending ? (
+                <Loader2 className="size-3 animate-spin" />
+              ) : (
+                <Plus className="size-3" />
+              )}
+              Create a new issue from this card instead
+            </button>
+          )}
+          <div className="flex gap-2 pt-1">
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={onClose}
+            >
+              Cancel
+            </Button>
+            <Button
+              size="sm"
+              className="flex-1"
+              onClick={() => linkMutation.mutate()}
+              disabled={isPending}
+            >
+              {linkMutation.isPending ? (
+                <Loader2 className="size-3 mr-1.5 animate-spin" />
+              ) : (
+                <Link2 className="size-3 mr-1.5" />
+              )}
+              Link Issue
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
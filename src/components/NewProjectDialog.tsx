@@ -0,0 +1,153 @@
+import { useState } from "react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { FolderPlus, Loader2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import type { Project } from "@/types";
+
+interface Props {
+  onClose: () => void;
+  onCreated?: (project: Project) => void;
+}
+
+/** Create a directory from a template, `git init` it and register it. */
+export function NewProjectDialog({ onClose, onCreated }: Props) {
+  const queryClient = useQueryClient();
+
+  const { data: templates } = useQuery({
+    queryKey: ["project-templates"],
+    queryFn: api.getProjectTemplates,
+  });
+  const { data: settings } = useQuery({
+    queryKey: ["settings"],
+    queryFn: api.getSettings,
+  });
+
+  const [name, setName] = useState("");
+  const [parent, setParent] = useState<string | null>(null);
+  const [template, setTemplate] = useState("empty");
+  const [claudeMd, setClaudeMd] = useState(true);
+  const [envFile, setEnvFile] = useState(false);
+
+  const parentDir = parent ?? settings?.scan_path ?? "";
+  const path = `${parentDir.replace(/\/+$/, "")}/${name.trim()}`;
+
+  const createMutation = useMutation({
+    mutationFn: () =>
+      api.createProjectFromTemplate(
+        name.trim(),
+        path,
+        template,
+        claudeMd,
+        envFile,
+      ),
+    onSuccess: (project) => {
+      queryClient.invalidateQueries({ queryKey: ["projects"] });
+      toast.success(`Created ${project.name}`);
+      onCreated?.(project);
+      onClose();
+    },
+    onError: (e) => toast.error(`Could not create project: ${String(e)}`),
+  });
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-md mx-4 p-5">
+        <div className="flex items-center gap-2 mb-4">
+          <FolderPlus className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">New Project</h2>
+        </div>
+
+        <div className="space-y-3">
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Name <span className="text-destructive">*</span>
+            </label>
+            <input
+              autoFocus
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              placeholder="my-new-project"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+            />
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Parent directory
+            </label>
+            <input
+              className="w-full text-sm font-mono bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              value={parentDir}
+              onChange={(e) => setParent(e.target.value)}
+            />
+            {name.trim() && (
+              <p className="text-xs text-muted-foreground font-mono mt-1 truncate">
+                {path}
+              </p>
+            )}
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Template
+            </label>
+            <select
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              value={template}
+              onChange={(e) => setTemplate(e.target.value)}
+            >
+              {templates?.map((t) => (
+                <option key={t.id} value={t.id}>
+                  {t.name} — {t.description}
+                </option>
+              ))}
+            </select>
+          </div>
+          <label className="flex items-center gap-2 text-sm cursor-pointer">
+            <input
+              type="checkbox"
+              checked={claudeMd}
+              onChange={(e) => setClaudeMd(e.target.checked)}
+            />
+            Starter CLAUDE.md
+          </label>
+          <label className="flex items-center gap-2 text-sm cursor-pointer">
+            <input
+              type="checkbox"
+              checked={envFile}
+              onChange={(e) => setEnvFile(e.target.checked)}
+            />
+            Empty .env (git-ignored)
+          </label>
+
+          <div className="flex gap-2 pt-1">
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={onClose}
+            >
+              Cancel
+            </Button>
+            <Button
+              size="sm"
+              className="flex-1"
+              onClick={() => createMutation.mutate()}
+              disabled={!name.trim() || !parentDir || createMutation.isPending}
+            >
+              {createMutation.isPending ? (
+                <Loader2 className="size-3 mr-1.5 animate-spin" />
+              ) : (
+                <FolderPlus className="size-3 mr-1.5" />
+              )}
+              Create
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
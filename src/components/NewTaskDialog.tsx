@@ -0,0 +1,118 @@
+import { useState } from "react";
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { ListPlus, Loader2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+
+interface Props {
+  /** Existing team ids offered in the team picker. */
+  teams: string[];
+  onClose: () => void;
+}
+
+/** Queue a pending task for an agent team in `~/.claude/tasks`. */
+export function NewTaskDialog({ teams, onClose }: Props) {
+  const queryClient = useQueryClient();
+  const [team, setTeam] = useState(teams[0] ?? "");
+  const [subject, setSubject] = useState("");
+  const [description, setDescription] = useState("");
+
+  const createMutation = useMutation({
+    mutationFn: () =>
+      api.createClaudeTask(
+        team.trim(),
+        subject.trim(),
+        description.trim() || null,
+      ),
+    onSuccess: (task) => {
+      queryClient.invalidateQueries({ queryKey: ["claude-tasks"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-tasks-flat"] });
+      toast.success(`Queued task #${task.id} for ${team.trim()}`);
+      onClose();
+    },
+    onError: (e) => toast.error(`Could not create task: ${String(e)}`),
+  });
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-md mx-4 p-5">
+        <div className="flex items-center gap-2 mb-4">
+          <ListPlus className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">New Task</h2>
+        </div>
+
+        <div className="space-y-3">
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Team <span className="text-destructive">*</span>
+            </label>
+            <input
+              className="w-full text-sm font-mono bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              list="task-teams"
+              placeholder="team-id"
+              value={team}
+              onChange={(e) => setTeam(e.target.value)}
+            />
+            <datalist id="task-teams">
+              {teams.map((t) => (
+                <option key={t} value={t} />
+              ))}
+            </datalist>
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Subject <span className="text-destructive">*</span>
+            </label>
+            <input
+              autoFocus
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary"
+              value={subject}
+              onChange={(e) => setSubject(e.target.value)}
+            />
+          </div>
+          <div>
+            <label className="text-xs font-medium text-muted-foreground block mb-1">
+              Description
+            </label>
+            <textarea
+              rows={4}
+              className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary resize-none"
+              value={description}
+              onChange={(e) => setDescription(e.target.value)}
+            />
+          </div>
+
+          <div className="flex gap-2 pt-1">
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={onClose}
+            >
+              Cancel
+            </Button>
+            <Button
+              size="sm"
+              className="flex-1"
+              onClick={() => createMutation.mutate()}
+              disabled={
+                !team.trim() || !subject.trim() || createMutation.isPending
+              }
+            >
+              {createMutation.isPending ? (
+                <Loader2 className="size-3 mr-1.5 animate-spin" />
+              ) : (
+                <ListPlus className="size-3 mr-1.5" />
+              )}
+              Create
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -43,6 +43,7 @@ export function OnboardingWizard({
         tags: p.tags,
         color: p.color ?? undefined,
         identity_key: p.identity_key ?? undefined,
+        icon: p.icon ?? undefined,
       }));
     const imported = await api.importScannedProjects(inputs);
     setImportedCount(imported.length);
@@ -14,6 +14,9 @@ import { api } from "@/lib/api";
 import { SecondaryNav } from "./SecondaryNav";
 import { CommandPalette } from "./CommandPalette";
 import { OnboardingWizard } from "./OnboardingWizard";
+import { useSessionFollowEvents } from "@/hooks/useSessionFollow";
+import { useNotificationEvents } from "@/hooks/useNotifications";
+import { useSuggestionEvents } from "@/hooks/useSuggestions";
 import { Toaster } from "sonner";
 
 const primaryNavItems = [
@@ -27,6 +30,9 @@ export function RootLayout() {
   const location = useLocation();
   const { theme, setTheme } = useAppStore();
   const queryClient = useQueryClient();
+  useSessionFollowEvents();
+  useNotificationEvents();
+  useSuggestionEvents();
 
   const { data: settings } = useQuery({
     queryKey: ["settings"],
@@ -12,9 +12,13 @@ import {
   Rocket,
   Plus,
   Loader2,
+  BarChart3,
+  SquareSlash,
+  MessageSquareText,
 } from "lucide-react";
 import { cn } from "@/lib/utils";
 import { api } from "@/lib/api";
+import { usePtyList } from "@/hooks/usePtyList";
 import { Button } from "./ui/button";
 
 export function SecondaryNav() {
@@ -49,6 +53,9 @@ function ProjectsListNav() {
     queryFn: api.getProjects,
     staleTime: 60_000,
   });
+  const { data: ptys } = usePtyList();
+  const terminalCount = (path: string) =>
+    ptys?.filter((p) => p.project_path === path).length ?? 0;
 
   return (
     <aside className="w-[220px] border-r border-border bg-card flex flex-col shrink-0">
@@ -85,6 +92,15 @@ function ProjectsListNav() {
               style={{ backgroundColor: project.color || "#6366f1" }}
             />
             <span className="truncate">{project.name}</span>
+            {terminalCount(project.path) > 0 && (
+              <span
+                className="ml-auto flex items-center gap-0.5 text-xs text-green-500"
+                title={`${terminalCount(project.path)} open terminal(s)`}
+              >
+                <Terminal className="size-3" />
+                {terminalCount(project.path)}
+              </span>
+            )}
           </NavLink>
         ))}
       </nav>
@@ -153,7 +169,10 @@ function ProjectDetailNav({ projectId }: { projectId: string }) {
 const claudeNavItems = [
   { path: "/claude/tasks", icon: CheckSquare, label: "Tasks" },
   { path: "/claude/plans", icon: FileText, label: "Plans" },
+  { path: "/claude/commands", icon: SquareSlash, label: "Commands" },
   { path: "/claude/sessions", icon: Terminal, label: "Sessions" },
+  { path: "/claude/history", icon: MessageSquareText, label: "Prompts" },
+  { path: "/claude/usage", icon: BarChart3, label: "Usage" },
 ];
 
 function ClaudeNav() {
@@ -0,0 +1,98 @@
+import { useEffect, useRef } from "react";
+import { Terminal as XTerm, type ITheme } from "@xterm/xterm";
+import { FitAddon } from "@xterm/addon-fit";
+import { Eye, X } from "lucide-react";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import { useAppStore, type FollowedSession } from "@/lib/store";
+
+interface Props {
+  follow: FollowedSession;
+  theme: ITheme;
+}
+
+/** Read-only xterm that replays and then tails a followed Claude session. */
+export function SessionFollowPane({ follow, theme }: Props) {
+  const { dismissFollow } = useAppStore();
+  const termRef = useRef<XTerm | null>(null);
+  const observerRef = useRef<ResizeObserver | null>(null);
+  const writtenRef = useRef(0);
+  const sessionId = follow.info.session_id;
+
+  const attach = (container: HTMLDivElement | null) => {
+    if (!container || termRef.current) return;
+    const xterm = new XTerm({
+      fontFamily: '"JetBrains Mono", "Fira Code", monospace',
+      fontSize: 12,
+      lineHeight: 1.2,
+      disableStdin: true,
+      cursorBlink: false,
+      scrollback: 5000,
+      theme,
+    });
+    const fit = new FitAddon();
+    xterm.loadAddon(fit);
+    xterm.open(container);
+    fit.fit();
+    const obs = new ResizeObserver(() => fit.fit());
+    obs.observe(container);
+    observerRef.current = obs;
+    termRef.current = xterm;
+  };
+
+  // Write only what arrived since the last render (everything, on mount).
+  useEffect(() => {
+    const xterm = termRef.current;
+    if (!xterm) return;
+    xterm.write(follow.output.slice(writtenRef.current));
+    writtenRef.current = follow.output.length;
+  }, [follow.output]);
+
+  useEffect(() => {
+    if (follow.ended)
+      termRef.current?.writeln("\r\n\x1b[90m[Session idle — follow ended]\x1b[0m");
+  }, [follow.ended]);
+
+  useEffect(() => {
+    if (termRef.current) termRef.current.options.theme = theme;
+  }, [theme]);
+
+  useEffect(
+    () => () => {
+      observerRef.current?.disconnect();
+      termRef.current?.dispose();
+      termRef.current = null;
+    },
+    [],
+  );
+
+  const handleClose = () => {
+    if (!follow.ended) api.stopSessionFollow(sessionId).catch(() => {});
+    dismissFollow(sessionId);
+  };
+
+  return (
+    <div className="flex flex-col h-full border-t border-border">
+      <div className="flex items-center justify-between px-4 h-8 shrink-0 bg-card">
+        <span className="flex items-center gap-1.5 text-xs text-muted-foreground">
+          <Eye className="size-3.5" />
+          Following session{" "}
+          <span className="font-mono">{sessionId.slice(0, 8)}</span>
+          {follow.ended && " (ended)"}
+        </span>
+        <Button
+          variant="ghost"
+          size="icon"
+          className="h-6 w-6"
+          title="Stop following"
+          onClick={handleClose}
+        >
+          <X className="size-3.5" />
+        </Button>
+      </div>
+      <div className="flex-1 relative overflow-hidden">
+        <div ref={attach} className="absolute inset-0 p-1" />
+      </div>
+    </div>
+  );
+}
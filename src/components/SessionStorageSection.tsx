@@ -0,0 +1,157 @@
+import { useState } from "react";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
+import { HardDrive, Loader2, Trash2 } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { formatBytes } from "@/lib/utils";
+import { Button } from "@/components/ui/button";
+import type { SessionCleanupResult } from "@/types";
+
+const VISIBLE_PROJECTS = 8;
+
+/** Disk used by Claude transcripts, with cleanup of old sessions. */
+export function SessionStorageSection() {
+  const queryClient = useQueryClient();
+  const [days, setDays] = useState(90);
+  const [moveToTrash, setMoveToTrash] = useState(true);
+  const [preview, setPreview] = useState<SessionCleanupResult | null>(null);
+
+  const { data: report, isFetching } = useQuery({
+    queryKey: ["session-storage"],
+    queryFn: api.analyzeSessionStorage,
+    staleTime: 60_000,
+  });
+
+  const cleanupMutation = useMutation({
+    mutationFn: (dryRun: boolean) =>
+      api.cleanupSessions(days, dryRun, moveToTrash),
+    onSuccess: (result) => {
+      if (result.dry_run) {
+        setPreview(result);
+        return;
+      }
+      setPreview(null);
+      queryClient.invalidateQueries({ queryKey: ["session-storage"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-sessions"] });
+      const freed = `${result.sessions} sessions (${formatBytes(result.bytes)})`;
+      if (result.errors.length) {
+        toast.warning(`Removed ${freed} with errors`, {
+          description: result.errors.slice(0, 3).join("\n"),
+        });
+      } else {
+        toast.success(`Removed ${freed}`, {
+          description: result.trash_path
+            ? `Moved to ${result.trash_path}`
+            : undefined,
+        });
+      }
+    },
+    onError: (e) => toast.error(`Cleanup failed: ${String(e)}`),
+  });
+
+  return (
+    <div className="border border-border rounded-lg p-4 space-y-4">
+      <div className="flex items-center justify-between">
+        <h2 className="text-sm font-semibold">Session Storage</h2>
+        {isFetching && (
+          <Loader2 className="size-3.5 animate-spin text-muted-foreground" />
+        )}
+      </div>
+
+      {report && (
+        <div className="space-y-2">
+          <p className="text-sm flex items-center gap-1.5">
+            <HardDrive className="size-3.5 text-muted-foreground" />
+            {formatBytes(report.total_bytes)} in {report.total_sessions}{" "}
+            sessions
+          </p>
+          <ul className="text-xs space-y-1">
+            {report.projects.slice(0, VISIBLE_PROJECTS).map((p) => (
+              <li
+                key={`${p.source}/${p.project_key}`}
+                className="flex items-center gap-2"
+              >
+                <span
+                  className="font-mono truncate flex-1 text-muted-foreground"
+                  title={p.cwd ?? p.project_key}
+                >
+                  {p.cwd ?? p.project_key}
+                </span>
+                <span className="shrink-0">{p.sessions} sessions</span>
+                <span className="shrink-0 w-16 text-right">
+                  {formatBytes(p.bytes)}
+                </span>
+              </li>
+            ))}
+          </ul>
+          {report.projects.length > VISIBLE_PROJECTS && (
+            <p className="text-xs text-muted-foreground">
+              and {report.projects.length - VISIBLE_PROJECTS} more projects
+            </p>
+          )}
+        </div>
+      )}
+
+      <div>
+        <label className="text-sm font-medium block mb-1">
+          Remove sessions older than (days)
+        </label>
+        <div className="flex items-center gap-2">
+          <input
+            type="number"
+            min={1}
+            value={days}
+            onChange={(e) => {
+              setDays(Math.max(1, Number(e.target.value) || 1));
+              setPreview(null);
+            }}
+            className="w-24 text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+          />
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => cleanupMutation.mutate(true)}
+            disabled={cleanupMutation.isPending}
+          >
+            Preview
+          </Button>
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => cleanupMutation.mutate(false)}
+            disabled={cleanupMutation.isPending || !preview?.sessions}
+          >
+            {cleanupMutation.isPending ? (
+              <Loader2 className="size-3.5 mr-1.5 animate-spin" />
+            ) : (
+              <Trash2 className="size-3.5 mr-1.5" />
+            )}
+            Clean up
+          </Button>
+        </div>
+        <label className="flex items-center gap-2 text-xs mt-2">
+          <input
+            type="checkbox"
+            checked={moveToTrash}
+            onChange={(e) => setMoveToTrash(e.target.checked)}
+          />
+          Move to ~/.claude-commander/trash instead of deleting
+        </label>
+        {preview && (
+          <p className="text-xs text-muted-foreground mt-1">
+            {preview.sessions} sessions ({formatBytes(preview.bytes)}) would
+            be removed.
+            {preview.skipped_bookmarked > 0 &&
+              ` ${preview.skipped_bookmarked} bookmarked kept.`}
+          </p>
+        )}
+        {!preview && (
+          <p className="text-xs text-muted-foreground mt-1">
+            Sessions not written to in this many days are removed from every
+            Claude directory. Bookmarked sessions are always kept.
+          </p>
+        )}
+      </div>
+    </div>
+  );
+}
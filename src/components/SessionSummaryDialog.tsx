@@ -0,0 +1,145 @@
+import { useState } from "react";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { Loader2, Send, ShieldCheck } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import type { RedactionKind } from "@/types";
+
+const REDACTION_LABELS: Record<RedactionKind, string> = {
+  env_value: "Env value",
+  api_key: "API key",
+  home_path: "Home path",
+  custom: "Custom pattern",
+};
+
+interface Props {
+  sessionId: string;
+  onClose: () => void;
+}
+
+/**
+ * Summarise a session and post it on an issue.  The summary is shown
+ * redacted, with what was removed, and can be edited before posting.
+ */
+export function SessionSummaryDialog({ sessionId, onClose }: Props) {
+  const [issueLink, setIssueLink] = useState("");
+  const [draft, setDraft] = useState<string | null>(null);
+
+  const preview = useQuery({
+    queryKey: ["session-summary-preview", sessionId],
+    queryFn: () => api.previewSessionSummary(sessionId),
+    retry: false,
+    staleTime: Infinity,
+  });
+  const summary = draft ?? preview.data?.summary ?? "";
+  const redactions = preview.data?.redactions ?? [];
+
+  const postMutation = useMutation({
+    mutationFn: () =>
+      api.postSessionSummary(sessionId, issueLink.trim(), summary),
+    onSuccess: (result) => {
+      const url = result.comment_url;
+      toast.success("Summary posted", {
+        action: url
+          ? { label: "Open", onClick: () => window.open(url, "_blank") }
+          : undefined,
+      });
+      onClose();
+    },
+    onError: (e) => toast.error(`Could not post summary: ${String(e)}`),
+  });
+
+  const canPost =
+    !!issueLink.trim() && !!summary.trim() && !postMutation.isPending;
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/40"
+      onClick={(e) => e.target === e.currentTarget && onClose()}
+    >
+      <div className="bg-background border border-border rounded-xl shadow-xl w-full max-w-xl mx-4 p-5 flex flex-col max-h-[80vh]">
+        <div className="flex items-center gap-2 mb-4">
+          <Send className="size-4 text-muted-foreground" />
+          <h2 className="font-semibold text-sm">Post summary to issue</h2>
+          {preview.data && (
+            <span className="ml-auto text-xs text-muted-foreground">
+              {preview.data.method === "claude"
+                ? "Summarised by Claude"
+                : "Extracted from transcript"}
+            </span>
+          )}
+        </div>
+
+        <input
+          className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary mb-3"
+          placeholder="https://github.com/owner/repo/issues/123"
+          value={issueLink}
+          onChange={(e) => setIssueLink(e.target.value)}
+          autoFocus
+        />
+
+        <div className="flex-1 overflow-y-auto space-y-3 min-h-24">
+          {preview.isLoading ? (
+            <div className="flex items-center justify-center gap-2 py-6 text-xs text-muted-foreground">
+              <Loader2 className="size-4 animate-spin" />
+              Summarising session…
+            </div>
+          ) : preview.error ? (
+            <p className="text-xs text-destructive">{String(preview.error)}</p>
+          ) : (
+            <>
+              <div className="rounded-lg border border-border p-3 text-xs">
+                <div className="flex items-center gap-1.5 font-medium mb-1">
+                  <ShieldCheck className="size-3.5 text-muted-foreground" />
+                  {redactions.length === 0
+                    ? "Nothing needed redacting"
+                    : `${redactions.length} value${
+                        redactions.length === 1 ? "" : "s"
+                      } redacted`}
+                </div>
+                {redactions.map((r, i) => (
+                  <div
+                    key={i}
+                    className="flex items-center gap-2 text-muted-foreground"
+                  >
+                    <span className="w-24 shrink-0">
+                      {REDACTION_LABELS[r.kind]}
+                    </span>
+                    <span className="font-mono truncate">{r.preview}</span>
+                    <span className="shrink-0">→</span>
+                    <span className="font-mono truncate">{r.replacement}</span>
+                  </div>
+                ))}
+              </div>
+              <textarea
+                className="w-full text-xs font-mono bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary resize-none"
+                rows={12}
+                value={summary}
+                onChange={(e) => setDraft(e.target.value)}
+              />
+            </>
+          )}
+        </div>
+
+        <div className="mt-4 flex justify-end gap-2">
+          <Button variant="outline" size="sm" onClick={onClose}>
+            Cancel
+          </Button>
+          <Button
+            size="sm"
+            onClick={() => postMutation.mutate()}
+            disabled={!canPost}
+          >
+            {postMutation.isPending ? (
+              <Loader2 className="size-3 mr-1.5 animate-spin" />
+            ) : (
+              <Send className="size-3 mr-1.5" />
+            )}
+            Post comment
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+}
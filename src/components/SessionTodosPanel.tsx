@@ -0,0 +1,97 @@
+import { useQuery } from "@tanstack/react-query";
+import { Check, Circle, ListTodo, Loader2 } from "lucide-react";
+import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
+import { api } from "@/lib/api";
+import { formatRelativeTime } from "@/lib/utils";
+import { SourceBadge } from "@/components/SourceBadge";
+import type { ClaudeTodo, ClaudeTodoList } from "@/types";
+
+/** Lists whose todos are all done are left out once they go quiet. */
+const STALE_DONE_MS = 60 * 60 * 1000;
+
+function isActive(list: ClaudeTodoList) {
+  if (list.todos.some((t) => t.status !== "completed")) return true;
+  const modified = list.modified_at ? Date.parse(list.modified_at) : 0;
+  return Date.now() - modified < STALE_DONE_MS;
+}
+
+function TodoItem({ todo }: { todo: ClaudeTodo }) {
+  if (todo.status === "in_progress") {
+    return (
+      <li className="flex items-start gap-2 font-medium">
+        <Loader2 className="size-3 mt-0.5 shrink-0 animate-spin text-blue-500" />
+        {todo.active_form ?? todo.content}
+      </li>
+    );
+  }
+  const done = todo.status === "completed";
+  return (
+    <li
+      className={`flex items-start gap-2 ${done ? "text-muted-foreground line-through" : ""}`}
+    >
+      {done ? (
+        <Check className="size-3 mt-0.5 shrink-0 text-green-500" />
+      ) : (
+        <Circle className="size-3 mt-0.5 shrink-0 text-muted-foreground" />
+      )}
+      {todo.content}
+    </li>
+  );
+}
+
+/**
+ * The TodoWrite lists of sessions still working through them, refreshed
+ * as Claude Code rewrites `todos/`.  Hidden when there are none.
+ */
+export function SessionTodosPanel() {
+  const { data: lists = [], refetch } = useQuery({
+    queryKey: ["claude-todos"],
+    queryFn: () => api.readClaudeTodos(),
+    staleTime: 30_000,
+  });
+  useClaudeWatcher("claude-todos-changed", refetch);
+
+  const active = lists.filter(isActive);
+  if (active.length === 0) return null;
+
+  return (
+    <div className="mb-6 rounded-lg border border-border p-3 text-left">
+      <div className="flex items-center gap-2 mb-3">
+        <ListTodo className="size-3.5 text-muted-foreground" />
+        <h2 className="text-sm font-semibold">Session todos</h2>
+      </div>
+      <div className="grid gap-3 sm:grid-cols-2">
+        {active.map((list) => {
+          const done = list.todos.filter(
+            (t) => t.status === "completed",
+          ).length;
+          const subagent = list.agent_id && list.agent_id !== list.session_id;
+          return (
+            <div
+              key={`${list.source}-${list.filename}`}
+              className="rounded-md border border-border px-2.5 py-2 text-xs"
+            >
+              <div className="flex items-center gap-1.5 mb-1.5 text-muted-foreground">
+                <span className="font-mono truncate" title={list.session_id}>
+                  {list.session_id.slice(0, 8)}
+                </span>
+                {subagent && <span>· subagent</span>}
+                <SourceBadge source={list.source} />
+                <span className="ml-auto shrink-0">
+                  {done}/{list.todos.length}
+                  {list.modified_at &&
+                    ` · ${formatRelativeTime(list.modified_at)}`}
+                </span>
+              </div>
+              <ul className="space-y-0.5">
+                {list.todos.map((todo, i) => (
+                  <TodoItem key={todo.id ?? i} todo={todo} />
+                ))}
+              </ul>
+            </div>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
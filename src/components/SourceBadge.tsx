@@ -0,0 +1,42 @@
+import { useQuery } from "@tanstack/react-query";
+import { FolderGit2 } from "lucide-react";
+import { api } from "@/lib/api";
+
+export const PROJECT_SOURCE_PREFIX = "project:";
+
+/**
+ * Label of the Claude config root an item came from; hidden for the
+ * default.  Items from a project's own `.claude` directory show the project.
+ */
+export function SourceBadge({ source }: { source: string }) {
+  if (source === "default") return null;
+  if (source.startsWith(PROJECT_SOURCE_PREFIX)) {
+    const projectId = source.slice(PROJECT_SOURCE_PREFIX.length);
+    return <ProjectSourceBadge projectId={projectId} />;
+  }
+  return (
+    <span
+      className="px-1.5 rounded bg-muted text-muted-foreground text-xs font-mono shrink-0"
+      title={`From the "${source}" Claude config dir`}
+    >
+      {source}
+    </span>
+  );
+}
+
+function ProjectSourceBadge({ projectId }: { projectId: string }) {
+  const { data: projects } = useQuery({
+    queryKey: ["projects"],
+    queryFn: api.getProjects,
+  });
+  const project = projects?.find((p) => p.id === projectId);
+  return (
+    <span
+      className="inline-flex items-center gap-1 px-1.5 rounded bg-muted text-muted-foreground text-xs shrink-0"
+      title={`From ${project?.path ?? "the project"}/.claude`}
+    >
+      <FolderGit2 className="size-3" />
+      {project?.name ?? "project"}
+    </span>
+  );
+}
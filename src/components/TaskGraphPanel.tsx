@@ -0,0 +1,96 @@
+import { useQuery } from "@tanstack/react-query";
+import { AlertTriangle, GitBranch } from "lucide-react";
+import { api } from "@/lib/api";
+import type { TaskGraphNode } from "@/types";
+
+const statusClass: Record<TaskGraphNode["status"], string> = {
+  pending: "border-border",
+  in_progress: "border-blue-500/60 bg-blue-500/5",
+  completed: "border-green-500/60 bg-green-500/5 text-muted-foreground",
+  deleted: "border-border opacity-50",
+};
+
+/**
+ * One team's task dependencies as columns: each column holds the tasks
+ * whose longest chain of blockers has that length.  Hidden when no task
+ * declares a dependency.
+ */
+export function TaskGraphPanel({
+  teamId,
+  source,
+}: {
+  teamId: string;
+  source: string;
+}) {
+  const { data: graph } = useQuery({
+    queryKey: ["task-graph", source, teamId],
+    queryFn: () => api.getTaskGraph(teamId, source),
+    staleTime: 30_000,
+  });
+
+  if (!graph || graph.edges.length === 0) return null;
+
+  const blockers = new Map<string, string[]>();
+  for (const edge of graph.edges) {
+    blockers.set(edge.to, [...(blockers.get(edge.to) ?? []), edge.from]);
+  }
+  const columns: TaskGraphNode[][] = [];
+  for (const node of graph.nodes) {
+    if (!columns[node.depth]) columns[node.depth] = [];
+    columns[node.depth].push(node);
+  }
+
+  return (
+    <div className="mb-6 rounded-lg border border-border p-3">
+      <div className="flex items-center gap-2 mb-3">
+        <GitBranch className="size-3.5 text-muted-foreground" />
+        <h2 className="text-sm font-semibold">Dependencies</h2>
+        {graph.has_cycle && (
+          <span className="flex items-center gap-1 text-xs text-amber-600 dark:text-amber-400">
+            <AlertTriangle className="size-3" />
+            Circular dependency
+          </span>
+        )}
+      </div>
+      <div className="flex gap-4 overflow-x-auto pb-1">
+        {columns.map((column, depth) => (
+          <div key={depth} className="space-y-2 w-48 shrink-0">
+            {column.map((node) => (
+              <div
+                key={node.id}
+                className={`rounded-md border px-2 py-1.5 text-xs ${statusClass[node.status]}`}
+              >
+                <p className="font-medium truncate" title={node.subject}>
+                  <span className="text-muted-foreground font-mono mr-1">
+                    #{node.id}
+                  </span>
+                  {node.subject}
+                </p>
+                {blockers.has(node.id) && (
+                  <p
+                    className={
+                      node.blocked
+                        ? "text-amber-600 dark:text-amber-400"
+                        : "text-muted-foreground"
+                    }
+                  >
+                    after{" "}
+                    {blockers
+                      .get(node.id)!
+                      .map((id) => `#${id}`)
+                      .join(", ")}
+                  </p>
+                )}
+                {node.owner && (
+                  <p className="text-muted-foreground truncate">
+                    {node.owner}
+                  </p>
+                )}
+              </div>
+            ))}
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,32 @@
+import { useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import { api } from "@/lib/api";
+
+/**
+ * Running Claude CLI processes, refreshed when the backend reports one
+ * starting or exiting.  Returns the ids of projects Claude is working in.
+ */
+export function useActiveClaudeProjects(): Set<string> {
+  const queryClient = useQueryClient();
+  const { data: processes = [] } = useQuery({
+    queryKey: ["claude-processes"],
+    queryFn: api.getActiveClaudeProcesses,
+  });
+
+  useEffect(() => {
+    const refresh = () =>
+      queryClient.invalidateQueries({ queryKey: ["claude-processes"] });
+    const unlisteners = [
+      listen("claude-session-started", refresh),
+      listen("claude-session-ended", refresh),
+    ];
+    return () => {
+      for (const unlisten of unlisteners) unlisten.then((f) => f());
+    };
+  }, [queryClient]);
+
+  return new Set(
+    processes.flatMap((p) => (p.project_id ? [p.project_id] : [])),
+  );
+}
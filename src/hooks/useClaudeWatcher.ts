@@ -4,7 +4,8 @@ import { listen } from "@tauri-apps/api/event";
 export type ClaudeEvent =
   | "claude-tasks-changed"
   | "claude-plans-changed"
-  | "claude-sessions-changed";
+  | "claude-sessions-changed"
+  | "claude-todos-changed";
 
 /** Subscribe to a Claude file-watcher event and call `refetch` when it fires. */
 export function useClaudeWatcher(event: ClaudeEvent, refetch: () => void) {
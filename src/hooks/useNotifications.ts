@@ -0,0 +1,27 @@
+import { useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { toast } from "sonner";
+import type { AppNotification } from "@/types";
+
+/**
+ * Show each notification the backend delivers (immediate or digest) as a
+ * toast.  Mount once, at the root.
+ */
+export function useNotificationEvents() {
+  useEffect(() => {
+    const unlisten = listen<AppNotification>("notification", (e) => {
+      const { trigger, priority, title, body } = e.payload;
+      // The follow viewer already toasts new sessions, with a "View" action.
+      if (trigger === "session_started") return;
+      const show = priority === "high" ? toast.info : toast;
+      show(title, {
+        description: body || undefined,
+        duration: trigger === "digest" ? 10_000 : undefined,
+      });
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, []);
+}
@@ -0,0 +1,37 @@
+import { useEffect } from "react";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import { listen } from "@tauri-apps/api/event";
+import { api } from "@/lib/api";
+import type { PtyActivityPayload, PtyExitPayload, PtyInfo } from "@/types";
+
+/**
+ * Live terminal inventory from the backend.  Every view that shows PTYs
+ * reads this one query; the `pty-*` events keep it current.
+ */
+export function usePtyList() {
+  const queryClient = useQueryClient();
+
+  useEffect(() => {
+    const refetch = () =>
+      queryClient.invalidateQueries({ queryKey: ["pty-list"] });
+    const unlistenCreated = listen<PtyInfo>("pty-created", refetch);
+    const unlistenExit = listen<PtyExitPayload>("pty-exit", refetch);
+    const unlistenActivity = listen<PtyActivityPayload>(
+      "pty-activity",
+      (e) => {
+        const { pty_id, last_activity } = e.payload;
+        queryClient.setQueryData<PtyInfo[]>(["pty-list"], (list) =>
+          list?.map((p) => (p.pty_id === pty_id ? { ...p, last_activity } : p)),
+        );
+      },
+    );
+
+    return () => {
+      unlistenCreated.then((f) => f());
+      unlistenExit.then((f) => f());
+      unlistenActivity.then((f) => f());
+    };
+  }, [queryClient]);
+
+  return useQuery({ queryKey: ["pty-list"], queryFn: api.ptyList });
+}
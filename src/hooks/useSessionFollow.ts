@@ -0,0 +1,43 @@
+import { useEffect } from "react";
+import { useNavigate } from "react-router";
+import { listen } from "@tauri-apps/api/event";
+import { toast } from "sonner";
+import { useAppStore } from "@/lib/store";
+import type { SessionFollowOutput, SessionFollowStarted } from "@/types";
+
+/**
+ * Mirror the backend's session-follow events into the app store so a follow
+ * viewer that mounts late still gets the full output.  Mount once, at the root.
+ */
+export function useSessionFollowEvents() {
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    const { startFollow, appendFollowOutput, endFollow } =
+      useAppStore.getState();
+
+    const unlisteners = [
+      listen<SessionFollowStarted>("auto-follow-started", (e) => {
+        startFollow(e.payload);
+        const projectId = e.payload.project_id;
+        if (e.payload.auto && projectId) {
+          toast("Following new Claude session", {
+            description: e.payload.cwd ?? undefined,
+            action: {
+              label: "View",
+              onClick: () => navigate(`/projects/${projectId}/terminal`),
+            },
+          });
+        }
+      }),
+      listen<SessionFollowOutput>("session-follow-output", (e) =>
+        appendFollowOutput(e.payload.session_id, e.payload.data),
+      ),
+      listen<string>("session-follow-ended", (e) => endFollow(e.payload)),
+    ];
+
+    return () => {
+      unlisteners.forEach((p) => p.then((unlisten) => unlisten()));
+    };
+  }, [navigate]);
+}
@@ -0,0 +1,48 @@
+import { useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { useQueryClient } from "@tanstack/react-query";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import type { AutoSuggestion } from "@/types";
+
+/**
+ * Offer each suggestion from the backend's commit scan as a toast with
+ * "Mark done" and "Dismiss" actions.  Mount once, at the root.
+ */
+export function useSuggestionEvents() {
+  const queryClient = useQueryClient();
+
+  useEffect(() => {
+    const accept = async (s: AutoSuggestion) => {
+      try {
+        await api.acceptSuggestion(s.id);
+        queryClient.invalidateQueries({
+          queryKey:
+            s.kind === "planning_item" ? ["planning-items"] : ["claude-tasks"],
+        });
+        queryClient.invalidateQueries({ queryKey: ["claude-tasks-flat"] });
+        toast.success(`Marked "${s.title}" done`);
+      } catch (e) {
+        toast.error(`Failed to apply suggestion: ${e}`);
+      }
+    };
+
+    const unlisten = listen<AutoSuggestion[]>("auto-suggestions", (e) => {
+      for (const s of e.payload) {
+        toast(`Done with "${s.title}"?`, {
+          description: s.reason,
+          duration: 30_000,
+          action: { label: "Mark done", onClick: () => accept(s) },
+          cancel: {
+            label: "Dismiss",
+            onClick: () => api.dismissSuggestion(s.id),
+          },
+        });
+      }
+    });
+
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, [queryClient]);
+}
@@ -1,147 +1,710 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke as invokeCommand } from "@tauri-apps/api/core";
+import type { Commands } from "@/types/commands";
 import type {
   AppSettings,
-  ClaudePlan,
-  ClaudeSession,
-  ClaudeTaskFile,
-  SessionDetail,
-  SessionMessage,
-  CreateGithubIssueOutput,
+  ArchiveReason,
+  DashboardWidgetId,
+  FilterBoard,
+  RedactionRules,
   CreatePlanningItemInput,
   CreateProjectInput,
-  DeployConfig,
-  EnvFile,
-  EnvVar,
-  GitBranch,
-  GitCommit,
-  GitStatus,
-  PlanningItem,
+  IssueImportFilters,
+  GithubIssueState,
   PlanningStatus,
-  Project,
-  SearchResults,
-  SyncResult,
-  TaskGithubLink,
-  TerminalInfo,
-  UpdateInfo,
   UpdatePlanningItemInput,
+  UpsertPlanningItemGithubLinkInput,
   UpsertTaskGithubLinkInput,
+  UsageRange,
 } from "@/types";
 
+/**
+ * `invoke`, typed by the generated command table: a wrapper whose arguments
+ * or result drift from the command's Rust signature fails to compile.
+ */
+function invoke<K extends keyof Commands>(
+  command: K,
+  ...args: {} extends Commands[K]["args"]
+    ? [args?: Commands[K]["args"]]
+    : [args: Commands[K]["args"]]
+): Promise<Commands[K]["result"]> {
+  return invokeCommand(command, args[0]);
+}
+
 // ─── Projects ──────────────────────────────────────────────────────────────
 
 export const api = {
   // Projects
-  scanProjects: (scan_path?: string) =>
-    invoke<Project[]>("scan_projects", { scanPath: scan_path }),
+  scanProjects: (scan_path?: string, scan_paths?: string[]) =>
+    invoke("scan_projects", {
+      scanPath: scan_path,
+      scanPaths: scan_paths,
+    }),
+
+  getProjects: () => invoke("get_projects"),
+
+  getProjectsWithStats: () => invoke("get_projects_with_stats"),
 
-  getProjects: () => invoke<Project[]>("get_projects"),
+  reorderProjects: (ordered_ids: string[]) =>
+    invoke("reorder_projects", { orderedIds: ordered_ids }),
+
+  getProjectTemplates: () => invoke("get_project_templates"),
+
+  createProjectFromTemplate: (
+    name: string,
+    path: string,
+    template: string,
+    claude_md?: boolean,
+    env_file?: boolean,
+  ) =>
+    invoke("create_project_from_template", {
+      name,
+      path,
+      template,
+      claudeMd: claude_md ?? null,
+      envFile: env_file ?? null,
+    }),
 
   upsertProject: (project: CreateProjectInput) =>
-    invoke<Project>("upsert_project", { project }),
+    invoke("upsert_project", { project }),
+
+  getProjectOverview: (project_id: string) =>
+    invoke("get_project_overview", { projectId: project_id }),
+
+  touchProject: (project_id: string) =>
+    invoke("touch_project", { projectId: project_id }),
+
+  getRecentProjects: (limit?: number) =>
+    invoke("get_recent_projects", { limit: limit ?? null }),
+
+  setProjectIcon: (project_id: string, icon: string | null) =>
+    invoke("set_project_icon", { projectId: project_id, icon }),
 
   deleteProject: (project_id: string) =>
-    invoke<void>("delete_project", { projectId: project_id }),
+    invoke("delete_project", { projectId: project_id }),
 
   importScannedProjects: (projects: CreateProjectInput[]) =>
-    invoke<Project[]>("import_scanned_projects", { projects }),
+    invoke("import_scanned_projects", { projects }),
 
-  syncProjects: (scan_path?: string) =>
-    invoke<SyncResult>("sync_projects", { scanPath: scan_path }),
+  syncProjects: (scan_path?: string, scan_paths?: string[]) =>
+    invoke("sync_projects", {
+      scanPath: scan_path,
+      scanPaths: scan_paths,
+    }),
+
+  getSyncHistory: (limit?: number) =>
+    invoke("get_sync_history", { limit: limit ?? null }),
 
-  getArchivedProjects: () => invoke<Project[]>("get_archived_projects"),
+  getEventLog: (limit?: number, kind?: string) =>
+    invoke("get_event_log", {
+      limit: limit ?? null,
+      kind: kind ?? null,
+    }),
+
+  runMaintenanceNow: () => invoke("run_maintenance_now"),
+
+  getArchivedProjects: (reason?: ArchiveReason, archived_since?: string) =>
+    invoke("get_archived_projects", {
+      reason: reason ?? null,
+      archivedSince: archived_since ?? null,
+    }),
+
+  archiveProject: (project_id: string) =>
+    invoke("archive_project", { projectId: project_id }),
 
   restoreProject: (project_id: string) =>
-    invoke<void>("restore_project", { projectId: project_id }),
+    invoke("restore_project", { projectId: project_id }),
+
+  purgeArchivedProjects: () => invoke("purge_archived_projects"),
+
+  resetAllProjects: () => invoke("reset_all_projects"),
+
+  findDuplicateProjects: () => invoke("find_duplicate_projects"),
 
-  purgeArchivedProjects: () => invoke<number>("purge_archived_projects"),
+  mergeProjects: (keep_id: string, remove_ids: string[]) =>
+    invoke("merge_projects", {
+      keepId: keep_id,
+      removeIds: remove_ids,
+    }),
+
+  // Backups
+  listSnapshots: () => invoke("list_snapshots"),
+
+  restoreSnapshot: (id: string) => invoke("restore_snapshot", { id }),
+
+  // Demo data (debug builds only)
+  seedDemoData: () => invoke("seed_demo_data"),
+  clearDemoData: () => invoke("clear_demo_data"),
+
+  getCrossProjectDependencies: () => invoke("get_cross_project_dependencies"),
+
+  // Disk usage
+  getProjectDiskUsage: (project_path: string, refresh?: boolean) =>
+    invoke("get_project_disk_usage", {
+      projectPath: project_path,
+      refresh: refresh ?? null,
+    }),
 
-  resetAllProjects: () => invoke<number>("reset_all_projects"),
+  cancelProjectDiskUsage: (project_path: string) =>
+    invoke("cancel_project_disk_usage", { projectPath: project_path }),
+
+  listProjectDiskUsage: () => invoke("list_project_disk_usage"),
+
+  // Groups
+  getGroups: () => invoke("get_groups"),
+
+  createGroup: (name: string) => invoke("create_group", { name }),
+
+  renameGroup: (group_id: string, name: string) =>
+    invoke("rename_group", { groupId: group_id, name }),
+
+  deleteGroup: (group_id: string) =>
+    invoke("delete_group", { groupId: group_id }),
+
+  assignProjectGroup: (project_id: string, group_id: string | null) =>
+    invoke("assign_project_group", {
+      projectId: project_id,
+      groupId: group_id,
+    }),
 
   // Claude
-  readClaudeTasks: () => invoke<ClaudeTaskFile[]>("read_claude_tasks"),
+  readClaudeTasks: () => invoke("read_claude_tasks"),
+
+  getClaudeTeams: () => invoke("get_claude_teams"),
+
+  readClaudeHistory: (limit: number, offset = 0, query?: string) =>
+    invoke("read_claude_history", {
+      limit,
+      offset,
+      query: query ?? null,
+    }),
+
+  readClaudeTodos: (session_id?: string) =>
+    invoke("read_claude_todos", {
+      sessionId: session_id ?? null,
+    }),
+
+  exportClaudeTasks: (
+    format: "csv" | "json",
+    path: string,
+    team_id?: string,
+  ) =>
+    invoke("export_claude_tasks", {
+      teamId: team_id ?? null,
+      format,
+      path,
+    }),
+
+  getActiveClaudeProcesses: () => invoke("get_active_claude_processes"),
+
+  getTaskGraph: (team_id: string, source?: string) =>
+    invoke("get_task_graph", {
+      teamId: team_id,
+      source: source ?? null,
+    }),
+
+  getAllTasksFlat: (filter?: string) =>
+    invoke("get_all_tasks_flat", { filter }),
+
+  updateClaudeTask: (
+    team_id: string,
+    task_id: string,
+    status: string | null,
+    owner: string | null,
+    source?: string,
+  ) =>
+    invoke("update_claude_task", {
+      teamId: team_id,
+      taskId: task_id,
+      status,
+      owner,
+      source: source ?? null,
+    }),
+
+  setTaskOrder: (team_id: string, status: string, ordered_ids: string[]) =>
+    invoke("set_task_order", {
+      teamId: team_id,
+      status,
+      orderedIds: ordered_ids,
+    }),
 
-  listClaudePlans: () => invoke<ClaudePlan[]>("list_claude_plans"),
+  createClaudeTask: (
+    team_id: string,
+    subject: string,
+    description: string | null,
+    source?: string,
+  ) =>
+    invoke("create_claude_task", {
+      teamId: team_id,
+      subject,
+      description,
+      source: source ?? null,
+    }),
 
-  readClaudePlan: (filename: string) =>
-    invoke<string>("read_claude_plan", { filename }),
+  readClaudeSettings: (source?: string) =>
+    invoke("read_claude_settings", {
+      source: source ?? null,
+    }),
 
-  readClaudeSessions: () => invoke<ClaudeSession[]>("read_claude_sessions"),
+  /** Top-level keys replace the file's; `null` removes a key. */
+  updateClaudeSettings: (
+    patch: Record<string, unknown>,
+    expected_modified_at?: string | null,
+    source?: string,
+  ) =>
+    invoke("update_claude_settings", {
+      patch,
+      expectedModifiedAt: expected_modified_at ?? null,
+      source: source ?? null,
+    }),
+
+  listSlashCommands: (project_id?: string) =>
+    invoke("list_slash_commands", {
+      projectId: project_id ?? null,
+    }),
 
-  readSessionMessages: (project_key: string, session_id: string) =>
-    invoke<SessionMessage[]>("read_session_messages", {
+  readSlashCommand: (scope: string, name: string, project_id?: string) =>
+    invoke("read_slash_command", {
+      scope,
+      projectId: project_id ?? null,
+      name,
+    }),
+
+  createSlashCommand: (
+    scope: string,
+    name: string,
+    content: string,
+    project_id?: string,
+  ) =>
+    invoke("create_slash_command", {
+      scope,
+      projectId: project_id ?? null,
+      name,
+      content,
+    }),
+
+  /** Rejects with `CONFLICT` if the file changed since `modified_at`. */
+  updateSlashCommand: (
+    scope: string,
+    name: string,
+    content: string,
+    project_id?: string,
+    expected_modified_at?: string | null,
+  ) =>
+    invoke("update_slash_command", {
+      scope,
+      projectId: project_id ?? null,
+      name,
+      content,
+      expectedModifiedAt: expected_modified_at ?? null,
+    }),
+
+  listClaudePlans: () => invoke("list_claude_plans"),
+
+  readClaudePlan: (filename: string, source?: string) =>
+    invoke("read_claude_plan", { filename, source: source ?? null }),
+
+  getPlanHistory: (filename: string, source?: string) =>
+    invoke("get_plan_history", {
+      filename,
+      source: source ?? null,
+    }),
+
+  restorePlanVersion: (id: number) => invoke("restore_plan_version", { id }),
+
+  /** Resolves to the new `modified_at`; rejects with `CONFLICT` if stale. */
+  saveClaudePlan: (
+    filename: string,
+    content: string,
+    expected_modified_at?: string | null,
+    source?: string,
+  ) =>
+    invoke("save_claude_plan", {
+      filename,
+      content,
+      expectedModifiedAt: expected_modified_at ?? null,
+      source: source ?? null,
+    }),
+
+  readClaudeSessions: () => invoke("read_claude_sessions"),
+
+  readSessionMessages: (
+    project_key: string,
+    session_id: string,
+    source?: string,
+  ) =>
+    invoke("read_session_messages", {
       projectKey: project_key,
       sessionId: session_id,
+      source: source ?? null,
+    }),
+  redactSession: (session_id: string, rules?: RedactionRules) =>
+    invoke("redact_session", {
+      sessionId: session_id,
+      rules: rules ?? null,
+    }),
+  getRunArtifacts: (session_id: string) =>
+    invoke("get_run_artifacts", { sessionId: session_id }),
+  getSessionStack: (session_id: string) =>
+    invoke("get_session_stack", { sessionId: session_id }),
+  findSessionsByStack: (language?: string, tag?: string) =>
+    invoke("find_sessions_by_stack", {
+      language: language ?? null,
+      tag: tag ?? null,
+    }),
+  bookmarkSession: (
+    session_id: string,
+    project_key: string,
+    note?: string,
+    source?: string,
+  ) =>
+    invoke("bookmark_session", {
+      sessionId: session_id,
+      projectKey: project_key,
+      note: note ?? null,
+      source: source ?? null,
+    }),
+  unbookmarkSession: (session_id: string) =>
+    invoke("unbookmark_session", { sessionId: session_id }),
+  listBookmarkedSessions: () => invoke("list_bookmarked_sessions"),
+  followSession: (session_id: string) =>
+    invoke("follow_session", { sessionId: session_id }),
+  stopSessionFollow: (session_id: string) =>
+    invoke("stop_session_follow", { sessionId: session_id }),
+  getFollowedSessions: () => invoke("get_followed_sessions"),
+
+  readClaudeSession: (
+    project_key: string,
+    session_id: string,
+    source?: string,
+  ) =>
+    invoke("read_claude_session", {
+      projectKey: project_key,
+      sessionId: session_id,
+      source: source ?? null,
     }),
 
-  readClaudeSession: (project_key: string, session_id: string) =>
-    invoke<SessionDetail>("read_claude_session", {
+  readSessionAttachment: (path: string) =>
+    invoke("read_session_attachment", { path }),
+
+  exportSession: (
+    project_key: string,
+    session_id: string,
+    format: "markdown" | "html",
+    source?: string,
+    rules?: RedactionRules,
+  ) =>
+    invoke("export_session", {
       projectKey: project_key,
       sessionId: session_id,
+      format,
+      source: source ?? null,
+      rules: rules ?? null,
     }),
 
+  readAllowedFile: (path: string) => invoke("read_allowed_file", { path }),
+
   // Terminal
-  detectTerminal: () => invoke<TerminalInfo>("detect_terminal"),
+  detectTerminal: () => invoke("detect_terminal"),
 
   launchClaude: (project_path: string, terminal?: string) =>
-    invoke<void>("launch_claude", { projectPath: project_path, terminal }),
+    invoke("launch_claude", { projectPath: project_path, terminal }),
+  openFileAt: (
+    project_path: string,
+    file: string,
+    line?: number,
+    editor?: string,
+  ) =>
+    invoke("open_file_at", {
+      projectPath: project_path,
+      file,
+      line: line ?? null,
+      editor: editor ?? null,
+    }),
+  detectEditors: () => invoke("detect_editors"),
+  openInEditor: (project_path: string, editor?: string) =>
+    invoke("open_in_editor", {
+      projectPath: project_path,
+      editor: editor ?? null,
+    }),
 
   // Git
   gitStatus: (project_path: string) =>
-    invoke<GitStatus>("git_status", { projectPath: project_path }),
+    invoke("git_status", { projectPath: project_path }),
 
   gitLog: (project_path: string, limit?: number) =>
-    invoke<GitCommit[]>("git_log", { projectPath: project_path, limit }),
+    invoke("git_log", { projectPath: project_path, limit }),
+
+  diffSinceSession: (project_path: string, session_id: string) =>
+    invoke("diff_since_session", {
+      projectPath: project_path,
+      sessionId: session_id,
+    }),
 
   gitBranches: (project_path: string) =>
-    invoke<GitBranch[]>("git_branches", { projectPath: project_path }),
+    invoke("git_branches", { projectPath: project_path }),
+
+  gitStage: (project_path: string, paths: string[]) =>
+    invoke("git_stage", { projectPath: project_path, paths }),
+
+  gitUnstage: (project_path: string, paths: string[]) =>
+    invoke("git_unstage", { projectPath: project_path, paths }),
+
+  gitCommit: (project_path: string, message: string, amend: boolean) =>
+    invoke("git_commit", {
+      projectPath: project_path,
+      message,
+      amend,
+    }),
+
+  cloneRepository: (url: string, dest_path: string, operation_id: string) =>
+    invoke("clone_repository", {
+      url,
+      destPath: dest_path,
+      operationId: operation_id,
+    }),
+
+  gitFetch: (project_path: string, operation_id: string, remote?: string) =>
+    invoke("git_fetch", {
+      projectPath: project_path,
+      remote: remote ?? null,
+      operationId: operation_id,
+    }),
+
+  gitPull: (project_path: string, operation_id: string, remote?: string) =>
+    invoke("git_pull", {
+      projectPath: project_path,
+      remote: remote ?? null,
+      operationId: operation_id,
+    }),
+
+  cancelGitOperation: (operation_id: string) =>
+    invoke("cancel_git_operation", { operationId: operation_id }),
+
+  createWorkspaceSnapshot: (project_path: string, label?: string) =>
+    invoke("create_workspace_snapshot", {
+      projectPath: project_path,
+      label: label ?? null,
+    }),
+  listWorkspaceSnapshots: (project_path: string) =>
+    invoke("list_workspace_snapshots", {
+      projectPath: project_path,
+    }),
+  restoreWorkspaceSnapshot: (id: string) =>
+    invoke("restore_workspace_snapshot", { id }),
+  deleteWorkspaceSnapshot: (id: string) =>
+    invoke("delete_workspace_snapshot", { id }),
 
   // Env
   listEnvFiles: (project_path: string) =>
-    invoke<EnvFile[]>("list_env_files", { projectPath: project_path }),
+    invoke("list_env_files", { projectPath: project_path }),
 
   getEnvVars: (env_file_path: string) =>
-    invoke<EnvVar[]>("get_env_vars", { envFilePath: env_file_path }),
+    invoke("get_env_vars", { envFilePath: env_file_path }),
 
   setEnvVar: (env_file_path: string, key: string, value: string) =>
-    invoke<void>("set_env_var", { envFilePath: env_file_path, key, value }),
+    invoke("set_env_var", { envFilePath: env_file_path, key, value }),
 
   deleteEnvVar: (env_file_path: string, key: string) =>
-    invoke<void>("delete_env_var", { envFilePath: env_file_path, key }),
+    invoke("delete_env_var", { envFilePath: env_file_path, key }),
 
   getDeployConfigs: (project_path: string) =>
-    invoke<DeployConfig[]>("get_deploy_configs", { projectPath: project_path }),
+    invoke("get_deploy_configs", { projectPath: project_path }),
+
+  // Badges
+  getProjectBadges: (project_path: string) =>
+    invoke("get_project_badges", { projectPath: project_path }),
 
   // Planning
   getPlanningItems: (project_id: string) =>
-    invoke<PlanningItem[]>("get_planning_items", { projectId: project_id }),
+    invoke("get_planning_items", { projectId: project_id }),
 
   createPlanningItem: (item: CreatePlanningItemInput) =>
-    invoke<PlanningItem>("create_planning_item", { item }),
+    invoke("create_planning_item", { item }),
 
   updatePlanningItem: (item: UpdatePlanningItemInput) =>
-    invoke<PlanningItem>("update_planning_item", { item }),
+    invoke("update_planning_item", { item }),
 
   movePlanningItem: (id: string, status: PlanningStatus, sort_order: number) =>
-    invoke<void>("move_planning_item", { id, status, sortOrder: sort_order }),
+    invoke("move_planning_item", { id, status, sortOrder: sort_order }),
+
+  deletePlanningItem: (id: string) => invoke("delete_planning_item", { id }),
+
+  createPlanFromItem: (item_id: string) =>
+    invoke("create_plan_from_item", { itemId: item_id }),
+
+  createIssueFromPlanningItem: (item_id: string, repo: string) =>
+    invoke("create_issue_from_planning_item", {
+      itemId: item_id,
+      repo,
+    }),
+
+  importIssuesAsItems: (repo: string, filters?: IssueImportFilters) =>
+    invoke("import_issues_as_items", {
+      repo,
+      filters: filters ?? null,
+    }),
+
+  importGithubIssues: (
+    project_id: string,
+    repo: string,
+    filters?: IssueImportFilters,
+  ) =>
+    invoke("import_github_issues", {
+      projectId: project_id,
+      repo,
+      filters: filters ?? null,
+    }),
+
+  getAllPlanningItems: (filter?: string) =>
+    invoke("get_all_planning_items", { filter }),
+
+  // Board filters
+  getSavedFilters: () => invoke("get_saved_filters"),
+
+  saveFilter: (name: string, board: FilterBoard, query: string) =>
+    invoke("save_filter", { name, board, query }),
+
+  deleteSavedFilter: (id: string) => invoke("delete_saved_filter", { id }),
+
+  // Status sync
+  previewStatusSync: () => invoke("preview_status_sync"),
+
+  runStatusSync: () => invoke("run_status_sync"),
+
+  // Presentation
+  startPresentationServer: (projectId: string, port?: number) =>
+    invoke("start_presentation_server", {
+      projectId,
+      port,
+    }),
+
+  stopPresentationServer: (projectId: string) =>
+    invoke("stop_presentation_server", { projectId }),
 
-  deletePlanningItem: (id: string) =>
-    invoke<void>("delete_planning_item", { id }),
+  getPresentationServers: () => invoke("get_presentation_servers"),
+
+  // Notifications
+  getPendingNotifications: () => invoke("get_pending_notifications"),
+
+  flushNotificationDigest: () => invoke("flush_notification_digest"),
 
   // GitHub
+  checkGithubAuth: () => invoke("check_github_auth"),
+
+  setGithubToken: (token: string | null) =>
+    invoke("set_github_token", { token }),
+
   detectGithubRepo: (project_path: string) =>
-    invoke<string | null>("detect_github_repo", { projectPath: project_path }),
+    invoke("detect_github_repo", { projectPath: project_path }),
+
+  listGithubRemotes: (project_path: string) =>
+    invoke("list_github_remotes", {
+      projectPath: project_path,
+    }),
+
+  listIssueRemotes: (project_path: string) =>
+    invoke("list_issue_remotes", {
+      projectPath: project_path,
+    }),
+
+  createGithubIssue: (
+    repo: string,
+    title: string,
+    body: string,
+    labels?: string[],
+  ) =>
+    invoke("create_github_issue", {
+      repo,
+      title,
+      body,
+      labels: labels ?? null,
+    }),
+
+  getIssueTemplates: (repo: string) => invoke("get_issue_templates", { repo }),
+
+  listGithubIssues: (
+    repo: string,
+    state?: GithubIssueState,
+    labels?: string[],
+    page?: number,
+  ) =>
+    invoke("list_github_issues", {
+      repo,
+      issueState: state ?? null,
+      labels: labels ?? null,
+      page: page ?? null,
+    }),
 
-  createGithubIssue: (repo: string, title: string, body: string) =>
-    invoke<CreateGithubIssueOutput>("create_github_issue", {
+  listGithubPrs: (repo: string) => invoke("list_github_prs", { repo }),
+
+  getPrChecks: (repo: string, number: number) =>
+    invoke("get_pr_checks", { repo, number }),
+
+  createGithubPr: (
+    repo: string,
+    base: string,
+    head: string,
+    title: string,
+    body: string,
+  ) =>
+    invoke("create_github_pr", {
       repo,
+      base,
+      head,
       title,
       body,
     }),
 
+  getCachedIssues: (repo: string) => invoke("get_cached_issues", { repo }),
+
+  refreshIssueCache: (repo: string, limit?: number) =>
+    invoke("refresh_issue_cache", { repo, limit }),
+
+  getIssueComments: (repo: string, number: number) =>
+    invoke("get_issue_comments", { repo, number }),
+
+  addIssueComment: (repo: string, number: number, body: string) =>
+    invoke("add_issue_comment", { repo, number, body }),
+
+  listGithubProjects: (owner: string) =>
+    invoke("list_github_projects", { owner }),
+
+  addIssueToGithubProject: (
+    project_id: string,
+    repo: string,
+    number: number,
+    column_id: string | null,
+  ) =>
+    invoke("add_issue_to_github_project", {
+      projectId: project_id,
+      repo,
+      number,
+      columnId: column_id,
+    }),
+
+  previewSessionSummary: (session_id: string, rules?: RedactionRules) =>
+    invoke("preview_session_summary", {
+      sessionId: session_id,
+      rules: rules ?? null,
+    }),
+
+  postSessionSummary: (
+    session_id: string,
+    issue_link: string,
+    summary: string,
+    rules?: RedactionRules,
+  ) =>
+    invoke("post_session_summary", {
+      sessionId: session_id,
+      issueLink: issue_link,
+      summary,
+      rules: rules ?? null,
+    }),
+
   upsertTaskGithubLink: (link: UpsertTaskGithubLinkInput) =>
-    invoke<TaskGithubLink>("upsert_task_github_link", { link }),
+    invoke("upsert_task_github_link", { link }),
 
-  getTaskGithubLinks: () => invoke<TaskGithubLink[]>("get_task_github_links"),
+  getTaskGithubLinks: () => invoke("get_task_github_links"),
 
   closeGithubIssue: (
     task_id: string,
@@ -149,45 +712,148 @@ export const api = {
     repo: string,
     number: number,
   ) =>
-    invoke<TaskGithubLink>("close_github_issue", {
+    invoke("close_github_issue", {
       taskId: task_id,
       teamId: team_id,
       repo,
       number,
     }),
 
-  fetchIssueStates: () => invoke<TaskGithubLink[]>("fetch_issue_states"),
+  fetchIssueStates: () => invoke("fetch_issue_states"),
+
+  suggestClosingCommit: (
+    task_id: string,
+    team_id: string,
+    message?: string,
+  ) =>
+    invoke("suggest_closing_commit", {
+      taskId: task_id,
+      teamId: team_id,
+      message: message ?? null,
+    }),
 
   deleteTaskGithubLink: (task_id: string, team_id: string) =>
-    invoke<void>("delete_task_github_link", {
+    invoke("delete_task_github_link", {
       taskId: task_id,
       teamId: team_id,
     }),
 
+  upsertPlanningItemGithubLink: (link: UpsertPlanningItemGithubLinkInput) =>
+    invoke("upsert_planning_item_github_link", {
+      link,
+    }),
+
+  getPlanningItemGithubLinks: () => invoke("get_planning_item_github_links"),
+
+  fetchPlanningItemIssueStates: () =>
+    invoke("fetch_planning_item_issue_states"),
+
+  deletePlanningItemGithubLink: (item_id: string) =>
+    invoke("delete_planning_item_github_link", { itemId: item_id }),
+
   // Search
-  globalSearch: (query: string) =>
-    invoke<SearchResults>("global_search", { query }),
+  globalSearch: (query: string) => invoke("global_search", { query }),
+
+  searchSessions: (query: string, limit?: number) =>
+    invoke("search_sessions", {
+      query,
+      limit: limit ?? null,
+    }),
+
+  searchInSession: (
+    project_key: string,
+    session_id: string,
+    query: string,
+    source?: string,
+  ) =>
+    invoke("search_in_session", {
+      projectKey: project_key,
+      sessionId: session_id,
+      query,
+      source: source ?? null,
+    }),
+
+  reindexSessions: () => invoke("reindex_sessions"),
+
+  getCostSummary: (month?: string) =>
+    invoke("get_cost_summary", { month: month ?? null }),
+
+  getUsageStats: (range: UsageRange, project_id?: string) =>
+    invoke("get_usage_stats", {
+      range,
+      projectId: project_id ?? null,
+    }),
+
+  getClaudeActivity: (range: string) =>
+    invoke("get_claude_activity", { range }),
+
+  analyzeSessionStorage: () => invoke("analyze_session_storage"),
+
+  cleanupSessions: (
+    older_than_days: number,
+    dry_run: boolean,
+    move_to_trash?: boolean,
+  ) =>
+    invoke("cleanup_sessions", {
+      olderThanDays: older_than_days,
+      dryRun: dry_run,
+      moveToTrash: move_to_trash ?? null,
+    }),
+
+  getDashboardData: (widget_ids?: DashboardWidgetId[]) =>
+    invoke("get_dashboard_data", {
+      widgetIds: widget_ids ?? null,
+    }),
+
+  getSuggestions: () => invoke("get_suggestions"),
+
+  acceptSuggestion: (id: string) => invoke("accept_suggestion", { id }),
+
+  dismissSuggestion: (id: string) => invoke("dismiss_suggestion", { id }),
 
   // Settings
-  getSettings: () => invoke<AppSettings>("get_settings"),
+  getSettings: () => invoke("get_settings"),
 
   updateSettings: (settings: AppSettings) =>
-    invoke<void>("update_settings", { settings }),
+    invoke("update_settings", { settings }),
 
   // PTY (in-app terminal)
-  ptyCreate: (project_path: string, cols: number, rows: number) =>
-    invoke<string>("pty_create", { projectPath: project_path, cols, rows }),
+  ptyCreate: (
+    project_path: string,
+    cols: number,
+    rows: number,
+    env_file?: string,
+  ) =>
+    invoke("pty_create", {
+      projectPath: project_path,
+      cols,
+      rows,
+      envFile: env_file ?? null,
+    }),
 
   ptyWrite: (pty_id: string, data: number[]) =>
-    invoke<void>("pty_write", { ptyId: pty_id, data }),
+    invoke("pty_write", { ptyId: pty_id, data }),
 
   ptyResize: (pty_id: string, cols: number, rows: number) =>
-    invoke<void>("pty_resize", { ptyId: pty_id, cols, rows }),
+    invoke("pty_resize", { ptyId: pty_id, cols, rows }),
+
+  ptyKill: (pty_id: string) => invoke("pty_kill", { ptyId: pty_id }),
+
+  ptyList: () => invoke("pty_list"),
 
-  ptyKill: (pty_id: string) => invoke<void>("pty_kill", { ptyId: pty_id }),
+  getPtyResourceUsage: () => invoke("get_pty_resource_usage"),
+
+  getPtyEnvFile: (project_path: string) =>
+    invoke("get_pty_env_file", { projectPath: project_path }),
+
+  setPtyEnvFile: (project_path: string, env_file: string | null) =>
+    invoke("set_pty_env_file", {
+      projectPath: project_path,
+      envFile: env_file,
+    }),
 
   // Updater
-  checkForUpdate: () => invoke<UpdateInfo>("check_for_update"),
+  checkForUpdate: () => invoke("check_for_update"),
 
-  installUpdate: () => invoke<void>("install_update"),
+  installUpdate: () => invoke("install_update"),
 };
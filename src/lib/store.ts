@@ -1,5 +1,5 @@
 import { create } from "zustand";
-import type { Project } from "@/types";
+import type { Project, SessionFollowStarted } from "@/types";
 
 interface AppStore {
   selectedProjectId: string | null;
@@ -10,6 +10,20 @@ interface AppStore {
 
   sidebarCollapsed: boolean;
   toggleSidebar: () => void;
+
+  /** Sessions tailed into the follow viewer, keyed by session id. */
+  follows: Record<string, FollowedSession>;
+  startFollow: (info: SessionFollowStarted) => void;
+  appendFollowOutput: (sessionId: string, data: string) => void;
+  endFollow: (sessionId: string) => void;
+  dismissFollow: (sessionId: string) => void;
+}
+
+export interface FollowedSession {
+  info: SessionFollowStarted;
+  /** Rendered terminal text received so far */
+  output: string;
+  ended: boolean;
 }
 
 export const useAppStore = create<AppStore>((set) => ({
@@ -35,4 +49,37 @@ export const useAppStore = create<AppStore>((set) => ({
 
   sidebarCollapsed: false,
   toggleSidebar: () => set((s) => ({ sidebarCollapsed: !s.sidebarCollapsed })),
+
+  follows: {},
+  startFollow: (info) =>
+    set((s) => ({
+      follows: {
+        ...s.follows,
+        [info.session_id]: { info, output: "", ended: false },
+      },
+    })),
+  appendFollowOutput: (sessionId, data) =>
+    set((s) => {
+      const follow = s.follows[sessionId];
+      if (!follow) return s;
+      return {
+        follows: {
+          ...s.follows,
+          [sessionId]: { ...follow, output: follow.output + data },
+        },
+      };
+    }),
+  endFollow: (sessionId) =>
+    set((s) => {
+      const follow = s.follows[sessionId];
+      if (!follow) return s;
+      return {
+        follows: { ...s.follows, [sessionId]: { ...follow, ended: true } },
+      };
+    }),
+  dismissFollow: (sessionId) =>
+    set((s) => {
+      const { [sessionId]: _dismissed, ...rest } = s.follows;
+      return { follows: rest };
+    }),
 }));
@@ -1,5 +1,6 @@
 import { clsx, type ClassValue } from "clsx";
 import { twMerge } from "tailwind-merge";
+import type { EditorKey } from "@/types";
 
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs));
@@ -22,11 +23,54 @@ export function formatRelativeTime(isoDate: string | null): string {
   return date.toLocaleDateString();
 }
 
+export function formatBytes(bytes: number): string {
+  const units = ["B", "KB", "MB", "GB", "TB"];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return `${unit === 0 ? value : value.toFixed(1)} ${units[unit]}`;
+}
+
+export const EDITOR_NAMES: Record<EditorKey, string> = {
+  vscode: "VS Code",
+  cursor: "Cursor",
+  windsurf: "Windsurf",
+  zed: "Zed",
+  intellij: "IntelliJ IDEA",
+  webstorm: "WebStorm",
+  pycharm: "PyCharm",
+  goland: "GoLand",
+  rustrover: "RustRover",
+};
+
 export function projectPathToKey(path: string): string {
   // Claude Code encodes project paths as the path with / replaced by -
   return path.replace(/\//g, "-").replace(/^-/, "");
 }
 
+/** Framework keys produced by backend icon detection (not emoji). */
+const FRAMEWORK_ICON_KEYS = [
+  "tauri",
+  "nextjs",
+  "astro",
+  "svelte",
+  "nuxt",
+  "vite",
+  "rust",
+  "go",
+  "python",
+  "node",
+];
+
+/** Text for a project's badge: its emoji icon if set, otherwise its initials. */
+export function getProjectBadge(icon: string | null, name: string): string {
+  if (icon && !FRAMEWORK_ICON_KEYS.includes(icon)) return icon;
+  return name.slice(0, 2).toUpperCase();
+}
+
 export function getProjectColor(color: string | null, name: string): string {
   if (color) return color;
   // Generate a stable color from the project name
@@ -0,0 +1,260 @@
+import { useEffect, useState } from "react";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
+import { Loader2, Plus, Save, SquareSlash } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { cn } from "@/lib/utils";
+import { Button } from "@/components/ui/button";
+import type { SlashCommand } from "@/types";
+
+const NEW_COMMAND_TEMPLATE = `---
+description: 
+argument-hint: 
+---
+
+$ARGUMENTS
+`;
+
+function commandKey(c: Pick<SlashCommand, "scope" | "project_id" | "name">) {
+  return `${c.scope}/${c.project_id ?? ""}/${c.name}`;
+}
+
+function errorCode(e: unknown): string | undefined {
+  try {
+    return JSON.parse(String(e)).code;
+  } catch {
+    return undefined;
+  }
+}
+
+/** Custom slash commands, personal and per project, with an editor. */
+export default function ClaudeCommands() {
+  const queryClient = useQueryClient();
+  const [selected, setSelected] = useState<SlashCommand | null>(null);
+  const [draft, setDraft] = useState("");
+  const [creating, setCreating] = useState(false);
+  const [newName, setNewName] = useState("");
+  const [newScope, setNewScope] = useState("user");
+
+  const { data: commands = [], isLoading } = useQuery({
+    queryKey: ["slash-commands"],
+    queryFn: () => api.listSlashCommands(),
+  });
+  const { data: projects = [] } = useQuery({
+    queryKey: ["projects"],
+    queryFn: api.getProjects,
+  });
+
+  const { data: file, isFetching: fileLoading } = useQuery({
+    queryKey: ["slash-command", selected && commandKey(selected)],
+    queryFn: () =>
+      api.readSlashCommand(
+        selected!.scope,
+        selected!.name,
+        selected!.project_id ?? undefined,
+      ),
+    enabled: !!selected,
+  });
+
+  useEffect(() => {
+    if (file) setDraft(file.content);
+  }, [file]);
+
+  const refresh = () =>
+    queryClient.invalidateQueries({ queryKey: ["slash-commands"] });
+
+  const saveMutation = useMutation({
+    mutationFn: () =>
+      api.updateSlashCommand(
+        selected!.scope,
+        selected!.name,
+        draft,
+        selected!.project_id ?? undefined,
+        file?.command.modified_at,
+      ),
+    onSuccess: (command) => {
+      refresh();
+      queryClient.invalidateQueries({ queryKey: ["slash-command"] });
+      setSelected(command);
+      toast.success(`Saved /${command.name}`);
+    },
+    onError: (e) => {
+      if (errorCode(e) === "CONFLICT") {
+        toast.error("The file changed on disk; reloaded it");
+        queryClient.invalidateQueries({ queryKey: ["slash-command"] });
+      } else {
+        toast.error(`Save failed: ${String(e)}`);
+      }
+    },
+  });
+
+  const createMutation = useMutation({
+    mutationFn: () =>
+      api.createSlashCommand(
+        newScope === "user" ? "user" : "project",
+        newName.trim().replace(/^\//, ""),
+        NEW_COMMAND_TEMPLATE,
+        newScope === "user" ? undefined : newScope,
+      ),
+    onSuccess: (command) => {
+      refresh();
+      setCreating(false);
+      setNewName("");
+      setSelected(command);
+    },
+    onError: (e) => toast.error(`Could not create command: ${String(e)}`),
+  });
+
+  // Personal commands first, then one group per project.
+  const groups = new Map<string, SlashCommand[]>();
+  for (const c of commands) {
+    const label = c.scope === "user" ? "Personal" : (c.project_name ?? "");
+    groups.set(label, [...(groups.get(label) ?? []), c]);
+  }
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center justify-center h-full">
+        <Loader2 className="size-6 animate-spin text-muted-foreground" />
+      </div>
+    );
+  }
+
+  return (
+    <div className="flex h-full overflow-hidden">
+      {/* Command list */}
+      <div className="w-72 shrink-0 border-r border-border flex flex-col">
+        <div className="flex items-center justify-between px-4 py-3 border-b border-border">
+          <h1 className="text-sm font-semibold">Slash Commands</h1>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => setCreating((v) => !v)}
+          >
+            <Plus className="size-3.5 mr-1" />
+            New
+          </Button>
+        </div>
+        {creating && (
+          <div className="p-3 border-b border-border space-y-2">
+            <select
+              value={newScope}
+              onChange={(e) => setNewScope(e.target.value)}
+              className="w-full text-xs bg-background border border-border rounded-md px-2 py-1.5 focus:outline-none focus:ring-2 focus:ring-ring"
+            >
+              <option value="user">Personal (~/.claude/commands)</option>
+              {projects.map((p) => (
+                <option key={p.id} value={p.id}>
+                  {p.name}
+                </option>
+              ))}
+            </select>
+            <input
+              value={newName}
+              onChange={(e) => setNewName(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === "Enter" && newName.trim()) {
+                  createMutation.mutate();
+                }
+                if (e.key === "Escape") setCreating(false);
+              }}
+              placeholder="review or frontend/component"
+              spellCheck={false}
+              className="w-full text-xs font-mono bg-background border border-border rounded-md px-2 py-1.5 focus:outline-none focus:ring-2 focus:ring-ring"
+            />
+          </div>
+        )}
+        <div className="flex-1 overflow-y-auto p-2 space-y-3">
+          {commands.length === 0 && (
+            <p className="px-2 py-4 text-xs text-muted-foreground">
+              No commands in ~/.claude/commands or any project&apos;s
+              .claude/commands yet
+            </p>
+          )}
+          {[...groups].map(([label, group]) => (
+            <div key={label}>
+              <p className="px-2 mb-1 text-[11px] font-medium uppercase tracking-wide text-muted-foreground">
+                {label}
+              </p>
+              {group.map((c) => (
+                <button
+                  key={commandKey(c)}
+                  type="button"
+                  onClick={() => setSelected(c)}
+                  className={cn(
+                    "w-full text-left px-2 py-1.5 rounded-md transition-colors",
+                    selected && commandKey(selected) === commandKey(c)
+                      ? "bg-accent"
+                      : "hover:bg-accent/40",
+                  )}
+                >
+                  <span className="block text-sm font-mono truncate">
+                    /{c.name}
+                  </span>
+                  {c.description && (
+                    <span className="block text-xs text-muted-foreground truncate">
+                      {c.description}
+                    </span>
+                  )}
+                </button>
+              ))}
+            </div>
+          ))}
+        </div>
+      </div>
+
+      {/* Editor */}
+      <div className="flex-1 flex flex-col min-w-0">
+        {!selected ? (
+          <div className="flex flex-col items-center justify-center h-full text-muted-foreground">
+            <SquareSlash className="size-10 mb-3" />
+            <p className="text-sm">Select a command to edit it</p>
+          </div>
+        ) : (
+          <>
+            <div className="flex items-center gap-3 px-5 py-3 border-b border-border shrink-0">
+              <div className="flex-1 min-w-0">
+                <p className="text-sm font-semibold font-mono truncate">
+                  /{selected.name}
+                </p>
+                <p className="text-xs text-muted-foreground font-mono truncate">
+                  {selected.path}
+                </p>
+              </div>
+              {file?.command.allowed_tools && (
+                <span
+                  className="text-xs text-muted-foreground truncate max-w-64"
+                  title={file.command.allowed_tools}
+                >
+                  Tools: {file.command.allowed_tools}
+                </span>
+              )}
+              <Button
+                size="sm"
+                onClick={() => saveMutation.mutate()}
+                disabled={
+                  saveMutation.isPending ||
+                  fileLoading ||
+                  draft === file?.content
+                }
+              >
+                {saveMutation.isPending ? (
+                  <Loader2 className="size-3.5 mr-1 animate-spin" />
+                ) : (
+                  <Save className="size-3.5 mr-1" />
+                )}
+                Save
+              </Button>
+            </div>
+            <textarea
+              value={draft}
+              onChange={(e) => setDraft(e.target.value)}
+              spellCheck={false}
+              className="flex-1 p-5 text-sm font-mono bg-background resize-none focus:outline-none"
+            />
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
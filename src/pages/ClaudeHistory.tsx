@@ -0,0 +1,146 @@
+import { useState } from "react";
+import { keepPreviousData, useQuery } from "@tanstack/react-query";
+import { Copy, Loader2, MessageSquareText, Search } from "lucide-react";
+import { toast } from "sonner";
+import { api } from "@/lib/api";
+import { formatRelativeTime } from "@/lib/utils";
+import { Button } from "@/components/ui/button";
+import { SourceBadge } from "@/components/SourceBadge";
+
+const PAGE_SIZE = 100;
+
+/** Prompts typed into Claude Code, searchable and ready to copy again. */
+export default function ClaudeHistory() {
+  const [draft, setDraft] = useState("");
+  const [query, setQuery] = useState("");
+  const [limit, setLimit] = useState(PAGE_SIZE);
+
+  const { data: page, isLoading, isFetching } = useQuery({
+    queryKey: ["claude-history", query, limit],
+    queryFn: () => api.readClaudeHistory(limit, 0, query || undefined),
+    placeholderData: keepPreviousData,
+  });
+
+  const applyQuery = (value: string) => {
+    setQuery(value.trim());
+    setLimit(PAGE_SIZE);
+  };
+
+  const copyPrompt = (text: string) => {
+    navigator.clipboard.writeText(text);
+    toast.success("Copied to clipboard");
+  };
+
+  if (isLoading) {
+    return (
+      <div className="flex items-center justify-center h-full">
+        <Loader2 className="size-6 animate-spin text-muted-foreground" />
+      </div>
+    );
+  }
+
+  const entries = page?.entries ?? [];
+
+  return (
+    <div className="flex flex-col h-full overflow-hidden">
+      <div className="flex items-center justify-between px-5 py-3 border-b border-border shrink-0">
+        <h1 className="text-sm font-semibold">Prompts</h1>
+        <div className="flex items-center gap-3">
+          <div className="relative">
+            <Search className="size-3.5 absolute left-2.5 top-1/2 -translate-y-1/2 text-muted-foreground" />
+            <input
+              value={draft}
+              onChange={(e) => setDraft(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") applyQuery(draft);
+                if (e.key === "Escape") {
+                  setDraft("");
+                  applyQuery("");
+                }
+              }}
+              placeholder="Search prompts"
+              title="Match words in the prompt or project (Enter to apply)"
+              spellCheck={false}
+              className="w-64 text-xs bg-background border border-border rounded-md pl-8 pr-2 py-1 focus:outline-none focus:ring-2 focus:ring-ring"
+            />
+          </div>
+          {isFetching ? (
+            <Loader2 className="size-3.5 animate-spin text-muted-foreground" />
+          ) : (
+            <span className="text-xs text-muted-foreground">
+              {page?.total ?? 0}
+            </span>
+          )}
+        </div>
+      </div>
+
+      {entries.length === 0 ? (
+        <div className="p-6 flex flex-col items-center justify-center min-h-64">
+          <MessageSquareText className="size-10 text-muted-foreground mb-3" />
+          <h3 className="font-medium mb-1">
+            {query ? "No matching prompts" : "No prompt history"}
+          </h3>
+          <p className="text-sm text-muted-foreground">
+            Prompts you type into Claude Code are listed here
+          </p>
+        </div>
+      ) : (
+        <div className="flex-1 overflow-y-auto">
+          <div className="p-3 space-y-1">
+            {entries.map((entry, i) => (
+              <div
+                key={`${entry.source}-${entry.timestamp}-${i}`}
+                className="flex items-start gap-3 p-3 rounded-lg border border-border bg-card group"
+              >
+                <div className="flex-1 min-w-0">
+                  <p className="text-sm whitespace-pre-wrap break-words line-clamp-6">
+                    {entry.display}
+                  </p>
+                  <div className="flex items-center gap-3 mt-1.5 text-xs text-muted-foreground">
+                    {entry.project && (
+                      <span className="font-mono truncate" title={entry.project}>
+                        {entry.project.split("/").slice(-2).join("/")}
+                      </span>
+                    )}
+                    {entry.timestamp && (
+                      <span className="shrink-0">
+                        {formatRelativeTime(entry.timestamp)}
+                      </span>
+                    )}
+                    {entry.pasted_count > 0 && (
+                      <span className="shrink-0">
+                        {entry.pasted_count} pasted
+                      </span>
+                    )}
+                    <SourceBadge source={entry.source} />
+                  </div>
+                </div>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  className="h-7 px-2 text-muted-foreground opacity-0 group-hover:opacity-100"
+                  onClick={() => copyPrompt(entry.display)}
+                  title="Copy prompt"
+                >
+                  <Copy className="size-3.5" />
+                </Button>
+              </div>
+            ))}
+          </div>
+          {page && entries.length < page.total && (
+            <div className="flex justify-center pb-4">
+              <Button
+                variant="outline"
+                size="sm"
+                disabled={isFetching}
+                onClick={() => setLimit((l) => l + PAGE_SIZE)}
+              >
+                Show more
+              </Button>
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -1,15 +1,45 @@
-import { useState } from "react";
-import { useQuery } from "@tanstack/react-query";
+import { useEffect, useState } from "react";
+import { useSearchParams } from "react-router";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
 import ReactMarkdown from "react-markdown";
-import { FileText, Clock, Loader2, ChevronLeft } from "lucide-react";
+import { toast } from "sonner";
+import {
+  FileText,
+  Clock,
+  Loader2,
+  ChevronLeft,
+  History,
+  Pencil,
+  RotateCcw,
+  Save,
+} from "lucide-react";
 import { api } from "@/lib/api";
 import { formatRelativeTime } from "@/lib/utils";
 import { Button } from "@/components/ui/button";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
+import { SourceBadge } from "@/components/SourceBadge";
+import type { ClaudePlan, PlanVersion } from "@/types";
+
+function isConflict(e: unknown): boolean {
+  try {
+    return JSON.parse(String(e)).code === "CONFLICT";
+  } catch {
+    return false;
+  }
+}
 
 export default function ClaudePlans() {
-  const [selectedPlan, setSelectedPlan] = useState<string | null>(null);
+  const queryClient = useQueryClient();
+  const [selectedPlan, setSelectedPlan] = useState<ClaudePlan | null>(null);
+  // Editor text and the mtime it was loaded at; `draft` is null when
+  // reading.
+  const [draft, setDraft] = useState<string | null>(null);
+  const [baseline, setBaseline] = useState<string | null>(null);
+  const [showHistory, setShowHistory] = useState(false);
+  const [viewedVersion, setViewedVersion] = useState<PlanVersion | null>(
+    null,
+  );
 
   const {
     data: plans,
@@ -23,12 +53,90 @@ export default function ClaudePlans() {
 
   useClaudeWatcher("claude-plans-changed", refetch);
 
+  // `?plan=<filename>` opens that plan from the primary root, e.g. when
+  // coming from a planning item.  A plan created just before navigating
+  // shows up once the list refetches.
+  const [searchParams, setSearchParams] = useSearchParams();
+  const linkedPlan = searchParams.get("plan");
+  useEffect(() => {
+    if (!linkedPlan || !plans) return;
+    const plan = plans.find(
+      (p) => p.filename === linkedPlan && p.source === "default",
+    );
+    if (!plan) return;
+    setSelectedPlan(plan);
+    setSearchParams({}, { replace: true });
+  }, [linkedPlan, plans, setSearchParams]);
+
   const { data: planContent, isLoading: contentLoading } = useQuery({
-    queryKey: ["claude-plan-content", selectedPlan],
-    queryFn: () => api.readClaudePlan(selectedPlan!),
+    queryKey: [
+      "claude-plan-content",
+      selectedPlan?.source,
+      selectedPlan?.filename,
+    ],
+    queryFn: () =>
+      api.readClaudePlan(selectedPlan!.filename, selectedPlan!.source),
     enabled: !!selectedPlan,
   });
 
+  const { data: history } = useQuery({
+    queryKey: ["plan-history", selectedPlan?.source, selectedPlan?.filename],
+    queryFn: () =>
+      api.getPlanHistory(selectedPlan!.filename, selectedPlan!.source),
+    enabled: !!selectedPlan && showHistory,
+  });
+
+  const restoreMutation = useMutation({
+    mutationFn: (id: number) => api.restorePlanVersion(id),
+    onSuccess: () => {
+      setViewedVersion(null);
+      queryClient.invalidateQueries({ queryKey: ["claude-plans"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-plan-content"] });
+      queryClient.invalidateQueries({ queryKey: ["plan-history"] });
+      toast.success("Plan restored");
+    },
+    onError: (e) => toast.error(`Restore failed: ${String(e)}`),
+  });
+
+  const saveMutation = useMutation({
+    mutationFn: ({ force }: { force: boolean }) =>
+      api.saveClaudePlan(
+        selectedPlan!.filename,
+        draft ?? "",
+        force ? null : baseline,
+        selectedPlan!.source,
+      ),
+    onSuccess: () => {
+      setDraft(null);
+      queryClient.invalidateQueries({ queryKey: ["claude-plans"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-plan-content"] });
+      toast.success("Plan saved");
+    },
+    onError: (e) => {
+      if (
+        isConflict(e) &&
+        window.confirm(
+          "This plan changed on disk since you opened it. Overwrite it?",
+        )
+      ) {
+        saveMutation.mutate({ force: true });
+      } else if (!isConflict(e)) {
+        toast.error(`Save failed: ${String(e)}`);
+      }
+    },
+  });
+
+  const startEditing = () => {
+    const current = plans?.find(
+      (p) =>
+        p.source === selectedPlan?.source &&
+        p.filename === selectedPlan?.filename,
+    );
+    setBaseline(current?.modified_at ?? selectedPlan?.modified_at ?? null);
+    setDraft(planContent ?? "");
+    setViewedVersion(null);
+  };
+
   if (isLoading) {
     return (
       <div className="flex items-center justify-center h-full">
@@ -57,23 +165,147 @@ export default function ClaudePlans() {
           <Button
             variant="ghost"
             size="sm"
-            onClick={() => setSelectedPlan(null)}
+            onClick={() => {
+              setSelectedPlan(null);
+              setDraft(null);
+              setShowHistory(false);
+              setViewedVersion(null);
+            }}
           >
             <ChevronLeft className="size-4 mr-1" />
             Plans
           </Button>
           <span className="text-sm text-muted-foreground">/</span>
-          <span className="text-sm font-medium">{selectedPlan}</span>
+          <span className="text-sm font-medium">{selectedPlan.filename}</span>
+          <div className="ml-auto flex items-center gap-2">
+            {draft === null ? (
+              <>
+                <Button
+                  variant={showHistory ? "secondary" : "ghost"}
+                  size="sm"
+                  onClick={() => {
+                    setShowHistory((v) => !v);
+                    setViewedVersion(null);
+                  }}
+                >
+                  <History className="size-3.5 mr-1" />
+                  History
+                </Button>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={startEditing}
+                  disabled={contentLoading}
+                >
+                  <Pencil className="size-3.5 mr-1" />
+                  Edit
+                </Button>
+              </>
+            ) : (
+              <>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => setDraft(null)}
+                  disabled={saveMutation.isPending}
+                >
+                  Cancel
+                </Button>
+                <Button
+                  size="sm"
+                  onClick={() => saveMutation.mutate({ force: false })}
+                  disabled={saveMutation.isPending}
+                >
+                  {saveMutation.isPending ? (
+                    <Loader2 className="size-3.5 mr-1 animate-spin" />
+                  ) : (
+                    <Save className="size-3.5 mr-1" />
+                  )}
+                  Save
+                </Button>
+              </>
+            )}
+          </div>
         </div>
-        <ScrollArea className="flex-1 p-6">
-          {contentLoading ? (
-            <Loader2 className="size-5 animate-spin" />
-          ) : (
-            <article className="prose prose-sm dark:prose-invert max-w-3xl">
-              <ReactMarkdown>{planContent ?? ""}</ReactMarkdown>
-            </article>
+        <div className="flex flex-1 min-h-0">
+          {showHistory && draft === null && (
+            <div className="w-56 shrink-0 border-r border-border overflow-y-auto p-2 space-y-0.5">
+              <button
+                type="button"
+                onClick={() => setViewedVersion(null)}
+                className={`w-full text-left px-2 py-1.5 rounded-md text-xs transition-colors ${
+                  viewedVersion === null ? "bg-accent" : "hover:bg-accent/40"
+                }`}
+              >
+                Current
+              </button>
+              {history?.map((version) => (
+                <button
+                  key={version.id}
+                  type="button"
+                  onClick={() => setViewedVersion(version)}
+                  className={`w-full text-left px-2 py-1.5 rounded-md text-xs transition-colors ${
+                    viewedVersion?.id === version.id
+                      ? "bg-accent"
+                      : "hover:bg-accent/40"
+                  }`}
+                >
+                  <span className="block">
+                    {new Date(version.created_at).toLocaleString()}
+                  </span>
+                  <span className="text-muted-foreground">
+                    {version.content.length.toLocaleString()} chars
+                  </span>
+                </button>
+              ))}
+              {history?.length === 0 && (
+                <p className="px-2 py-1.5 text-xs text-muted-foreground">
+                  No earlier versions recorded yet
+                </p>
+              )}
+            </div>
           )}
-        </ScrollArea>
+          <ScrollArea className="flex-1 p-6">
+            {viewedVersion && (
+              <div className="flex items-center gap-3 mb-4 max-w-3xl px-3 py-2 rounded-md border border-border bg-muted/40 text-xs">
+                <span className="flex-1 text-muted-foreground">
+                  Version from{" "}
+                  {new Date(viewedVersion.created_at).toLocaleString()}
+                </span>
+                <Button
+                  size="sm"
+                  variant="outline"
+                  className="h-7 text-xs"
+                  disabled={restoreMutation.isPending}
+                  onClick={() => restoreMutation.mutate(viewedVersion.id)}
+                >
+                  {restoreMutation.isPending ? (
+                    <Loader2 className="size-3 mr-1 animate-spin" />
+                  ) : (
+                    <RotateCcw className="size-3 mr-1" />
+                  )}
+                  Restore
+                </Button>
+              </div>
+            )}
+            {contentLoading ? (
+              <Loader2 className="size-5 animate-spin" />
+            ) : draft !== null ? (
+              <textarea
+                value={draft}
+                onChange={(e) => setDraft(e.target.value)}
+                spellCheck={false}
+                className="w-full max-w-3xl min-h-[60vh] text-sm font-mono bg-background border border-border rounded-md p-3 focus:outline-none focus:ring-2 focus:ring-ring"
+              />
+            ) : (
+              <article className="prose prose-sm dark:prose-invert max-w-3xl">
+                <ReactMarkdown>
+                  {viewedVersion?.content ?? planContent ?? ""}
+                </ReactMarkdown>
+              </article>
+            )}
+          </ScrollArea>
+        </div>
       </div>
     );
   }
@@ -85,17 +317,18 @@ export default function ClaudePlans() {
       <div className="space-y-2">
         {plans.map((plan) => (
           <button
-            key={plan.id}
+            key={`${plan.source}/${plan.id}`}
             type="button"
-            onClick={() => setSelectedPlan(plan.filename)}
+            onClick={() => setSelectedPlan(plan)}
             className="w-full text-left p-4 rounded-lg border border-border bg-card hover:bg-accent/20 transition-colors group"
           >
             <div className="flex items-start justify-between gap-3">
               <div className="flex items-start gap-3 min-w-0">
                 <FileText className="size-4 text-muted-foreground shrink-0 mt-0.5" />
                 <div className="min-w-0">
-                  <p className="font-medium text-sm group-hover:text-primary transition-colors">
+                  <p className="font-medium text-sm group-hover:text-primary transition-colors flex items-center gap-2">
                     {plan.title}
+                    <SourceBadge source={plan.source} />
                   </p>
                   {plan.preview && (
                     <p className="text-xs text-muted-foreground mt-1 line-clamp-2">
@@ -1,18 +1,36 @@
+import { useState } from "react";
 import { useNavigate } from "react-router";
 import { useQuery } from "@tanstack/react-query";
 import {
+  Bookmark,
   Terminal,
   Clock,
   MessageSquare,
   Loader2,
   ChevronRight,
+  Layers,
 } from "lucide-react";
 import { api } from "@/lib/api";
 import { formatRelativeTime } from "@/lib/utils";
 import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
+import { SourceBadge } from "@/components/SourceBadge";
+
+/** Split `lang:rust tag:sql-migrations` into its two optional parts. */
+function parseStackQuery(query: string): { language?: string; tag?: string } {
+  const result: { language?: string; tag?: string } = {};
+  for (const term of query.trim().split(/\s+/)) {
+    if (term.startsWith("tag:")) result.tag = term.slice(4) || undefined;
+    else if (term.startsWith("lang:")) result.language = term.slice(5) || undefined;
+    else if (term) result.language = term;
+  }
+  return result;
+}
 
 export default function ClaudeSessions() {
   const navigate = useNavigate();
+  const [stackDraft, setStackDraft] = useState("");
+  const [stackQuery, setStackQuery] = useState("");
+  const [bookmarkedOnly, setBookmarkedOnly] = useState(false);
 
   const {
     data: sessions,
@@ -25,6 +43,32 @@ export default function ClaudeSessions() {
 
   useClaudeWatcher("claude-sessions-changed", refetch);
 
+  const { data: stackMatches, isFetching: stackLoading } = useQuery({
+    queryKey: ["sessions-by-stack", stackQuery],
+    queryFn: () => {
+      const { language, tag } = parseStackQuery(stackQuery);
+      return api.findSessionsByStack(language, tag);
+    },
+    enabled: !!stackQuery,
+  });
+  const stackById = new Map(
+    (stackMatches ?? []).map((s) => [s.session_id, s] as const),
+  );
+
+  const { data: bookmarks } = useQuery({
+    queryKey: ["session-bookmarks"],
+    queryFn: api.listBookmarkedSessions,
+  });
+  const bookmarkById = new Map(
+    (bookmarks ?? []).map((b) => [b.session_id, b] as const),
+  );
+
+  const visible = (sessions ?? []).filter(
+    (s) =>
+      (!stackQuery || stackById.has(s.id)) &&
+      (!bookmarkedOnly || bookmarkById.has(s.id)),
+  );
+
   if (isLoading) {
     return (
       <div className="flex items-center justify-center h-full">
@@ -50,28 +94,78 @@ export default function ClaudeSessions() {
       {/* Header */}
       <div className="flex items-center justify-between px-5 py-3 border-b border-border shrink-0">
         <h1 className="text-sm font-semibold">Sessions</h1>
-        <span className="text-xs text-muted-foreground">{sessions.length}</span>
+        <div className="flex items-center gap-3">
+          <button
+            onClick={() => setBookmarkedOnly((v) => !v)}
+            className={`flex items-center gap-1.5 text-xs px-2 py-1 rounded-md transition-colors ${
+              bookmarkedOnly
+                ? "bg-accent text-foreground"
+                : "text-muted-foreground hover:text-foreground hover:bg-accent/40"
+            }`}
+            title="Only show bookmarked sessions"
+          >
+            <Bookmark className="size-3.5" />
+            {bookmarks?.length ?? 0}
+          </button>
+          <div className="relative">
+            <Layers className="size-3.5 absolute left-2.5 top-1/2 -translate-y-1/2 text-muted-foreground" />
+            <input
+              value={stackDraft}
+              onChange={(e) => setStackDraft(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") setStackQuery(stackDraft.trim());
+                if (e.key === "Escape") {
+                  setStackDraft("");
+                  setStackQuery("");
+                }
+              }}
+              placeholder="lang:rust tag:sql-migrations"
+              title="Filter by the stack sessions worked in (Enter to apply)"
+              spellCheck={false}
+              className="w-64 text-xs font-mono bg-background border border-border rounded-md pl-8 pr-2 py-1 focus:outline-none focus:ring-2 focus:ring-ring"
+            />
+          </div>
+          {stackLoading ? (
+            <Loader2 className="size-3.5 animate-spin text-muted-foreground" />
+          ) : (
+            <span className="text-xs text-muted-foreground">
+              {visible.length}
+            </span>
+          )}
+        </div>
       </div>
 
       {/* Session list */}
       <div className="flex-1 overflow-y-auto">
         <div className="p-3 space-y-1">
-          {sessions.map((session) => (
+          {visible.map((session) => (
             <button
               key={session.id}
               onClick={() =>
                 navigate(
-                  `/claude/sessions/${encodeURIComponent(session.project_key)}/${encodeURIComponent(session.id)}`,
+                  `/claude/sessions/${encodeURIComponent(session.project_key)}/${encodeURIComponent(session.id)}` +
+                    (session.source === "default"
+                      ? ""
+                      : `?source=${encodeURIComponent(session.source)}`),
                 )
               }
               className="w-full text-left flex items-center gap-3 p-3 rounded-lg border border-border bg-card hover:bg-accent/20 transition-colors group"
             >
               <Terminal className="size-4 text-muted-foreground shrink-0" />
               <div className="flex-1 min-w-0">
-                <p className="text-sm font-medium truncate">
-                  {session.cwd?.split("/").slice(-2).join("/") ||
+                <p className="text-sm font-medium truncate flex items-center gap-1.5">
+                  {bookmarkById.has(session.id) && (
+                    <Bookmark className="size-3 shrink-0 text-amber-500 fill-current" />
+                  )}
+                  {session.title ||
+                    session.cwd?.split("/").slice(-2).join("/") ||
                     session.project_key.slice(0, 40)}
                 </p>
+                {bookmarkById.get(session.id)?.note && (
+                  <p className="text-xs text-amber-500 truncate mt-0.5">
+                    {bookmarkById.get(session.id)?.note}
+                  </p>
+                )}
                 {session.cwd && (
                   <p className="text-xs text-muted-foreground font-mono truncate mt-0.5">
                     {session.cwd}
@@ -86,6 +180,17 @@ export default function ClaudeSessions() {
                     <Clock className="size-3" />
                     {formatRelativeTime(session.last_message_at)}
                   </span>
+                  <SourceBadge source={session.source} />
+                  {stackById.get(session.id)?.languages.slice(0, 3).map((l) => (
+                    <span key={l} className="px-1.5 rounded bg-muted font-mono">
+                      {l}
+                    </span>
+                  ))}
+                  {stackById.get(session.id)?.tags.map((t) => (
+                    <span key={t} className="px-1.5 rounded bg-primary/10 text-primary font-mono">
+                      #{t}
+                    </span>
+                  ))}
                 </div>
               </div>
               <ChevronRight className="size-4 text-muted-foreground shrink-0 opacity-0 group-hover:opacity-100 transition-opacity" />
@@ -1,13 +1,37 @@
 import { useState, useEffect, useRef } from "react";
 import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
-import { CheckSquare, Clock, Link2, Loader2, RefreshCw } from "lucide-react";
+import {
+  Check,
+  CheckSquare,
+  ChevronDown,
+  ChevronUp,
+  Clock,
+  Download,
+  FolderKanban,
+  GitCommit,
+  Link2,
+  ListPlus,
+  Loader2,
+  MessageSquare,
+  RefreshCw,
+  Users,
+} from "lucide-react";
+import { toast } from "sonner";
+import { save } from "@tauri-apps/plugin-dialog";
 import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
 import { api } from "@/lib/api";
 import { formatRelativeTime } from "@/lib/utils";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
+import { AddToGithubProjectDialog } from "@/components/AddToGithubProjectDialog";
+import { IssueCommentsDialog } from "@/components/IssueCommentsDialog";
 import { LinkGithubIssueDialog } from "@/components/LinkGithubIssueDialog";
-import type { ClaudeTask, TaskGithubLink } from "@/types";
+import { BoardFilterBar } from "@/components/BoardFilterBar";
+import { NewTaskDialog } from "@/components/NewTaskDialog";
+import { SessionTodosPanel } from "@/components/SessionTodosPanel";
+import { SourceBadge } from "@/components/SourceBadge";
+import { TaskGraphPanel } from "@/components/TaskGraphPanel";
+import type { ClaudeTask, ClaudeTeam, TaskGithubLink } from "@/types";
 
 const statusConfig: Record<
   string,
@@ -41,6 +65,63 @@ function IssueStateDot({ state }: { state: "open" | "closed" | null }) {
   );
 }
 
+// ─── Team summary ────────────────────────────────────────────────────────────
+
+function TeamSummary({
+  teams,
+  selected,
+  onSelect,
+}: {
+  teams: ClaudeTeam[];
+  selected: string | null;
+  onSelect: (teamId: string | null) => void;
+}) {
+  return (
+    <div className="flex flex-wrap gap-2">
+      {teams.map((team) => {
+        const active = selected === team.team_id;
+        const total = team.pending + team.in_progress + team.completed;
+        const owners = team.owners.map((o) => o.owner);
+        return (
+          <button
+            key={`${team.source}-${team.team_id}`}
+            onClick={() => onSelect(active ? null : team.team_id)}
+            title={[
+              team.description,
+              team.lead && `Lead: ${team.lead}`,
+              team.members.length > 0 &&
+                `Members: ${team.members.map((m) => m.name).join(", ")}`,
+            ]
+              .filter(Boolean)
+              .join("\n")}
+            className={`text-left rounded-md border px-3 py-2 text-xs transition-colors ${
+              active
+                ? "border-primary bg-primary/5"
+                : "border-border hover:bg-accent/50"
+            }`}
+          >
+            <div className="flex items-center gap-1.5 font-medium">
+              <Users className="size-3 text-muted-foreground" />
+              {team.name ?? team.team_id}
+              <SourceBadge source={team.source} />
+            </div>
+            <div className="text-muted-foreground mt-0.5">
+              {team.in_progress} active · {team.pending} pending ·{" "}
+              {team.completed}/{total} done
+            </div>
+            {owners.length > 0 && (
+              <div className="text-muted-foreground truncate max-w-56">
+                {owners.slice(0, 3).join(", ")}
+                {owners.length > 3 && ` +${owners.length - 3}`}
+              </div>
+            )}
+          </button>
+        );
+      })}
+    </div>
+  );
+}
+
 // ─── Close-issue prompt ──────────────────────────────────────────────────────
 
 interface ClosePromptEntry {
@@ -122,6 +203,28 @@ export default function ClaudeTasks() {
     staleTime: 30_000,
   });
 
+  // Board filter expression; when set, the backend does the filtering.
+  const [filter, setFilter] = useState("");
+  const [showNewTask, setShowNewTask] = useState(false);
+  const {
+    data: filteredTasks,
+    error: filterError,
+    refetch: refetchFiltered,
+  } = useQuery({
+    queryKey: ["claude-tasks-flat", filter],
+    queryFn: () => api.getAllTasksFlat(filter),
+    enabled: filter !== "",
+    retry: false,
+  });
+
+  const { data: teams = [], refetch: refetchTeams } = useQuery({
+    queryKey: ["claude-teams"],
+    queryFn: api.getClaudeTeams,
+    staleTime: 30_000,
+  });
+  const [teamFilter, setTeamFilter] = useState<string | null>(null);
+  const selectedTeam = teams.find((t) => t.team_id === teamFilter);
+
   const { data: links, refetch: refetchLinks } = useQuery({
     queryKey: ["task-github-links"],
     queryFn: api.getTaskGithubLinks,
@@ -132,7 +235,12 @@ export default function ClaudeTasks() {
     queryFn: api.getSettings,
   });
 
-  useClaudeWatcher("claude-tasks-changed", refetch);
+  useClaudeWatcher("claude-tasks-changed", () => {
+    refetch();
+    refetchTeams();
+    queryClient.invalidateQueries({ queryKey: ["task-graph"] });
+    if (filter) refetchFiltered();
+  });
 
   // Build a lookup map: `${team_id}:${task_id}` → TaskGithubLink
   const linkMap: Record<string, TaskGithubLink> = {};
@@ -186,9 +294,18 @@ export default function ClaudeTasks() {
 
   const refreshStatesMutation = useMutation({
     mutationFn: api.fetchIssueStates,
-    onSuccess: (updated) => {
-      queryClient.setQueryData(["task-github-links"], updated);
+    onSuccess: ({ links, errors }) => {
+      queryClient.setQueryData(["task-github-links"], links);
       lastRefreshRef.current = Date.now();
+      if (errors.length > 0) {
+        toast.warning(
+          `Could not refresh issues in ${errors.map((e) => e.repo).join(", ")}`,
+          {
+            id: "task-issue-state-errors",
+            description: errors.map((e) => e.message).join("\n"),
+          },
+        );
+      }
     },
   });
 
@@ -229,6 +346,16 @@ export default function ClaudeTasks() {
     },
   });
 
+  // ── Manual task order ────────────────────────────────────────────────────
+  const orderMutation = useMutation({
+    mutationFn: (v: { team_id: string; status: string; ids: string[] }) =>
+      api.setTaskOrder(v.team_id, v.status, v.ids),
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["claude-tasks"] }),
+    onError: (e) =>
+      toast.error("Could not save task order", { description: String(e) }),
+  });
+
   const dismissClosePrompt = () => setClosePromptQueue((q) => q.slice(1));
   const currentPrompt = closePromptQueue[0];
 
@@ -241,10 +368,13 @@ export default function ClaudeTasks() {
     );
   }
 
-  const allTasks =
+  const unfilteredTasks =
     taskFiles?.flatMap((tf) =>
       tf.tasks.map((t) => ({ ...t, team_id: tf.team_id })),
     ) ?? [];
+  const allTasks = (filter ? (filteredTasks ?? []) : unfilteredTasks).filter(
+    (t) => !teamFilter || t.team_id === teamFilter,
+  );
 
   const groups: Record<string, typeof allTasks> = {
     in_progress: allTasks.filter((t) => t.status === "in_progress"),
@@ -252,7 +382,52 @@ export default function ClaudeTasks() {
     completed: allTasks.filter((t) => t.status === "completed"),
   };
 
-  if (allTasks.length === 0) {
+  // Swap a task with its neighbour from the same team and save that team's
+  // column order; Claude task files carry no ordering of their own.
+  const moveTask = (
+    task: (typeof allTasks)[number],
+    columnTasks: typeof allTasks,
+    direction: -1 | 1,
+  ) => {
+    const ids = columnTasks
+      .filter((t) => t.team_id === task.team_id)
+      .map((t) => t.id);
+    const from = ids.indexOf(task.id);
+    const to = from + direction;
+    if (to < 0 || to >= ids.length) return;
+    [ids[from], ids[to]] = [ids[to], ids[from]];
+    orderMutation.mutate({ team_id: task.team_id, status: task.status, ids });
+  };
+
+  // The format follows the extension picked in the save dialog.
+  const exportTasks = async () => {
+    const path = await save({
+      defaultPath: `${teamFilter ?? "claude-tasks"}.csv`,
+      filters: [
+        { name: "CSV", extensions: ["csv"] },
+        { name: "JSON", extensions: ["json"] },
+      ],
+    });
+    if (!path) return;
+    const format = path.toLowerCase().endsWith(".json") ? "json" : "csv";
+    try {
+      const count = await api.exportClaudeTasks(
+        format,
+        path,
+        teamFilter ?? undefined,
+      );
+      toast.success(`Exported ${count} tasks`, { description: path });
+    } catch (e) {
+      toast.error("Export failed", { description: String(e) });
+    }
+  };
+
+  const teamIds = [...new Set(taskFiles?.map((tf) => tf.team_id) ?? [])];
+  const newTaskDialog = showNewTask && (
+    <NewTaskDialog teams={teamIds} onClose={() => setShowNewTask(false)} />
+  );
+
+  if (unfilteredTasks.length === 0) {
     return (
       <div className="p-6 flex flex-col items-center justify-center min-h-64">
         <CheckSquare className="size-10 text-muted-foreground mb-3" />
@@ -260,6 +435,19 @@ export default function ClaudeTasks() {
         <p className="text-sm text-muted-foreground">
           Tasks appear here when Claude Code is running
         </p>
+        <Button
+          variant="outline"
+          size="sm"
+          className="mt-4"
+          onClick={() => setShowNewTask(true)}
+        >
+          <ListPlus className="size-3.5 mr-1.5" />
+          New task
+        </Button>
+        <div className="w-full max-w-2xl mt-8">
+          <SessionTodosPanel />
+        </div>
+        {newTaskDialog}
       </div>
     );
   }
@@ -271,8 +459,32 @@ export default function ClaudeTasks() {
           <h1 className="text-xl font-bold">Tasks</h1>
           <div className="flex items-center gap-2">
             <span className="text-sm text-muted-foreground">
-              {allTasks.length} total
+              {filter || teamFilter
+                ? `${allTasks.length} of ${unfilteredTasks.length}`
+                : `${allTasks.length} total`}
             </span>
+            <Button
+              variant="outline"
+              size="sm"
+              className="h-7"
+              onClick={() => setShowNewTask(true)}
+            >
+              <ListPlus className="size-3.5 mr-1.5" />
+              New task
+            </Button>
+            <Button
+              variant="ghost"
+              size="sm"
+              className="h-7 px-2 text-muted-foreground"
+              onClick={exportTasks}
+              title={
+                teamFilter
+                  ? "Export this team's tasks to CSV or JSON"
+                  : "Export all tasks to CSV or JSON"
+              }
+            >
+              <Download className="size-3.5" />
+            </Button>
             <Button
               variant="ghost"
               size="sm"
@@ -288,6 +500,34 @@ export default function ClaudeTasks() {
           </div>
         </div>
 
+        {teams.length > 1 && (
+          <div className="mb-4">
+            <TeamSummary
+              teams={teams}
+              selected={teamFilter}
+              onSelect={setTeamFilter}
+            />
+          </div>
+        )}
+
+        {selectedTeam && (
+          <TaskGraphPanel
+            teamId={selectedTeam.team_id}
+            source={selectedTeam.source}
+          />
+        )}
+
+        {!filter && !teamFilter && <SessionTodosPanel />}
+
+        <div className="mb-6">
+          <BoardFilterBar
+            board="tasks"
+            value={filter}
+            error={filterError}
+            onApply={setFilter}
+          />
+        </div>
+
         <div className="space-y-6">
           {Object.entries(groups).map(([status, tasks]) => {
             if (tasks.length === 0) return null;
@@ -306,10 +546,15 @@ export default function ClaudeTasks() {
                 <div className="space-y-2">
                   {tasks.map((task) => (
                     <TaskCard
-                      key={`${task.team_id}-${task.id}`}
+                      key={`${task.source}-${task.team_id}-${task.id}`}
                       task={task}
                       link={linkMap[`${task.team_id}:${task.id}`]}
                       showManualCloseButton={!settings?.github_close_prompt}
+                      onMove={
+                        filter
+                          ? undefined
+                          : (direction) => moveTask(task, tasks, direction)
+                      }
                     />
                   ))}
                 </div>
@@ -327,6 +572,7 @@ export default function ClaudeTasks() {
           isPending={closeIssueMutation.isPending}
         />
       )}
+      {newTaskDialog}
     </>
   );
 }
@@ -337,13 +583,18 @@ function TaskCard({
   task,
   link,
   showManualCloseButton,
+  onMove,
 }: {
   task: ClaudeTask & { team_id: string };
   link?: TaskGithubLink;
   showManualCloseButton: boolean;
+  /** Reorder within the column; omitted while a filter is applied. */
+  onMove?: (direction: -1 | 1) => void;
 }) {
   const queryClient = useQueryClient();
   const [showDialog, setShowDialog] = useState(false);
+  const [showComments, setShowComments] = useState(false);
+  const [showAddToProject, setShowAddToProject] = useState(false);
 
   const cfg = statusConfig[task.status] ?? {
     label: task.status,
@@ -369,6 +620,31 @@ function TaskCard({
     },
   });
 
+  const trailerMutation = useMutation({
+    mutationFn: () => api.suggestClosingCommit(task.id, task.team_id),
+    onSuccess: ({ trailer }) => {
+      navigator.clipboard.writeText(trailer);
+      toast.success(`Copied "${trailer}"`, {
+        description: "Add it to the commit message to close the issue.",
+      });
+    },
+    onError: (e) =>
+      toast.error("Could not build the reference", {
+        description: String(e),
+      }),
+  });
+
+  const statusMutation = useMutation({
+    mutationFn: (status: string) =>
+      api.updateClaudeTask(task.team_id, task.id, status, null, task.source),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["claude-tasks"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-tasks-flat"] });
+    },
+    onError: (e) =>
+      toast.error("Could not update task", { description: String(e) }),
+  });
+
   const canManuallyClose =
     showManualCloseButton &&
     task.status === "completed" &&
@@ -383,9 +659,30 @@ function TaskCard({
         <div className="flex-1 min-w-0">
           <div className="flex items-start justify-between gap-2">
             <p className="text-sm font-medium">{task.subject}</p>
-            <Badge variant={cfg.variant} className="text-xs shrink-0">
-              {cfg.label}
-            </Badge>
+            <div className="flex items-center gap-1.5 shrink-0">
+              {onMove && (
+                <div className="flex items-center">
+                  <button
+                    className="text-muted-foreground hover:text-foreground"
+                    onClick={() => onMove(-1)}
+                    title="Move up"
+                  >
+                    <ChevronUp className="size-3.5" />
+                  </button>
+                  <button
+                    className="text-muted-foreground hover:text-foreground"
+                    onClick={() => onMove(1)}
+                    title="Move down"
+                  >
+                    <ChevronDown className="size-3.5" />
+                  </button>
+                </div>
+              )}
+              <SourceBadge source={task.source} />
+              <Badge variant={cfg.variant} className="text-xs shrink-0">
+                {cfg.label}
+              </Badge>
+            </div>
           </div>
           {task.description && (
             <p className="text-xs text-muted-foreground mt-1 line-clamp-2">
@@ -403,12 +700,34 @@ function TaskCard({
                 {task.owner}
               </span>
             )}
+            {task.blocked_by.length > 0 && (
+              <span className="text-xs text-amber-600 dark:text-amber-400">
+                Blocked by {task.blocked_by.map((id) => `#${id}`).join(", ")}
+              </span>
+            )}
             {task.updated_at && (
               <span className="text-xs text-muted-foreground flex items-center gap-1">
                 <Clock className="size-3" />
                 {formatRelativeTime(task.updated_at)}
               </span>
             )}
+            {task.status !== "completed" && (
+              <Button
+                variant="ghost"
+                size="sm"
+                className="h-5 px-1.5 text-xs text-muted-foreground hover:text-foreground"
+                onClick={() => statusMutation.mutate("completed")}
+                disabled={statusMutation.isPending}
+                title="Mark this task completed in its task file"
+              >
+                {statusMutation.isPending ? (
+                  <Loader2 className="size-3 mr-1 animate-spin" />
+                ) : (
+                  <Check className="size-3 mr-1" />
+                )}
+                Mark done
+              </Button>
+            )}
 
             {link ? (
               <div className="flex items-center gap-1.5 ml-auto">
@@ -440,6 +759,40 @@ function TaskCard({
                     )}
                   </Button>
                 )}
+                {link.github_repo && link.github_issue_number != null && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="h-5 px-1.5 text-xs text-muted-foreground hover:text-foreground"
+                    onClick={() => setShowComments(true)}
+                    title="Read and reply to the issue's comments"
+                  >
+                    <MessageSquare className="size-3" />
+                  </Button>
+                )}
+                {link.github_repo && link.github_issue_number != null && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="h-5 px-1.5 text-xs text-muted-foreground hover:text-foreground"
+                    onClick={() => setShowAddToProject(true)}
+                    title="Add the issue to a GitHub Projects board"
+                  >
+                    <FolderKanban className="size-3" />
+                  </Button>
+                )}
+                {link.github_issue_state !== "closed" && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="h-5 px-1.5 text-xs text-muted-foreground hover:text-foreground"
+                    onClick={() => trailerMutation.mutate()}
+                    disabled={trailerMutation.isPending}
+                    title="Copy a commit trailer that closes the issue"
+                  >
+                    <GitCommit className="size-3" />
+                  </Button>
+                )}
                 <Button
                   variant="ghost"
                   size="sm"
@@ -471,6 +824,25 @@ function TaskCard({
           onClose={() => setShowDialog(false)}
         />
       )}
+      {showComments &&
+        link?.github_repo &&
+        link.github_issue_number != null && (
+          <IssueCommentsDialog
+            repo={link.github_repo}
+            number={link.github_issue_number}
+            url={link.github_issue_url}
+            onClose={() => setShowComments(false)}
+          />
+        )}
+      {showAddToProject &&
+        link?.github_repo &&
+        link.github_issue_number != null && (
+          <AddToGithubProjectDialog
+            repo={link.github_repo}
+            number={link.github_issue_number}
+            onClose={() => setShowAddToProject(false)}
+          />
+        )}
     </>
   );
 }
@@ -0,0 +1,286 @@
+import { useState } from "react";
+import { useQuery } from "@tanstack/react-query";
+import { BarChart3, Loader2 } from "lucide-react";
+import { api } from "@/lib/api";
+import type { ClaudeActivity, UsageBucket, UsageRange } from "@/types";
+
+const RANGES: { value: UsageRange; label: string }[] = [
+  { value: "7d", label: "7 days" },
+  { value: "30d", label: "30 days" },
+  { value: "90d", label: "90 days" },
+  { value: "all", label: "All time" },
+];
+
+function formatTokens(n: number): string {
+  if (n >= 1_000_000) return `${(n / 1_000_000).toFixed(1)}M`;
+  if (n >= 1_000) return `${(n / 1_000).toFixed(1)}k`;
+  return String(n);
+}
+
+function formatUsd(n: number): string {
+  return `$${n.toFixed(2)}`;
+}
+
+/** Weeks shown in the activity heatmap. */
+const HEATMAP_WEEKS = 26;
+
+const HEAT_CLASSES = [
+  "bg-muted",
+  "bg-primary/25",
+  "bg-primary/50",
+  "bg-primary/75",
+  "bg-primary",
+];
+
+function localDay(d: Date): string {
+  const pad = (n: number) => String(n).padStart(2, "0");
+  return `${d.getFullYear()}-${pad(d.getMonth() + 1)}-${pad(d.getDate())}`;
+}
+
+/** Token usage and estimated cost from session transcripts. */
+export default function ClaudeUsage() {
+  const [range, setRange] = useState<UsageRange>("30d");
+  const [projectId, setProjectId] = useState("");
+
+  const { data: projects } = useQuery({
+    queryKey: ["projects"],
+    queryFn: api.getProjects,
+    staleTime: 60_000,
+  });
+  const { data: stats, isLoading } = useQuery({
+    queryKey: ["usage-stats", range, projectId],
+    queryFn: () => api.getUsageStats(range, projectId || undefined),
+  });
+
+  // The heatmap always covers the last HEATMAP_WEEKS, whatever the range.
+  const { data: activity } = useQuery({
+    queryKey: ["claude-activity", HEATMAP_WEEKS],
+    queryFn: () => api.getClaudeActivity(`${HEATMAP_WEEKS * 7}d`),
+  });
+
+  const maxDayCost = Math.max(
+    0.01,
+    ...(stats?.by_day.map((d) => d.cost_usd) ?? []),
+  );
+
+  return (
+    <div className="p-6 max-w-5xl">
+      <div className="flex items-center justify-between mb-6">
+        <h1 className="text-xl font-bold">Usage</h1>
+        <div className="flex items-center gap-2">
+          <select
+            value={projectId}
+            onChange={(e) => setProjectId(e.target.value)}
+            className="text-sm bg-background border border-border rounded-md px-2 py-1"
+          >
+            <option value="">All projects</option>
+            {projects?.map((p) => (
+              <option key={p.id} value={p.id}>
+                {p.name}
+              </option>
+            ))}
+          </select>
+          <select
+            value={range}
+            onChange={(e) => setRange(e.target.value as UsageRange)}
+            className="text-sm bg-background border border-border rounded-md px-2 py-1"
+          >
+            {RANGES.map((r) => (
+              <option key={r.value} value={r.value}>
+                {r.label}
+              </option>
+            ))}
+          </select>
+        </div>
+      </div>
+
+      {isLoading || !stats ? (
+        <div className="flex items-center justify-center py-16">
+          <Loader2 className="size-6 animate-spin text-muted-foreground" />
+        </div>
+      ) : stats.totals.sessions === 0 ? (
+        <div className="flex flex-col items-center justify-center min-h-64">
+          <BarChart3 className="size-10 text-muted-foreground mb-3" />
+          <h3 className="font-medium mb-1">No usage recorded</h3>
+          <p className="text-sm text-muted-foreground">
+            Token usage is read from session transcripts in the background
+          </p>
+        </div>
+      ) : (
+        <div className="space-y-6">
+          <div className="grid grid-cols-4 gap-3">
+            <Stat
+              label="Estimated cost"
+              value={formatUsd(stats.totals.cost_usd)}
+            />
+            <Stat label="Sessions" value={String(stats.totals.sessions)} />
+            <Stat
+              label="Input / output"
+              value={`${formatTokens(stats.totals.input_tokens)} / ${formatTokens(stats.totals.output_tokens)}`}
+            />
+            <Stat
+              label="Cache read / write"
+              value={`${formatTokens(stats.totals.cache_read_tokens)} / ${formatTokens(stats.totals.cache_creation_tokens)}`}
+            />
+          </div>
+
+          <section>
+            <h2 className="text-sm font-semibold mb-2">Cost per day</h2>
+            <div className="flex items-end gap-0.5 h-32 border-b border-border">
+              {stats.by_day.map((d) => (
+                <div
+                  key={d.key}
+                  className="flex-1 bg-primary/70 hover:bg-primary rounded-t-sm min-w-0.5"
+                  style={{ height: `${(d.cost_usd / maxDayCost) * 100}%` }}
+                  title={`${d.key}: ${formatUsd(d.cost_usd)}, ${d.sessions} sessions`}
+                />
+              ))}
+            </div>
+            <div className="flex justify-between text-xs text-muted-foreground mt-1">
+              <span>{stats.by_day[0]?.key}</span>
+              <span>{stats.by_day[stats.by_day.length - 1]?.key}</span>
+            </div>
+          </section>
+
+          {activity && <ActivityHeatmap activity={activity} />}
+
+          <div className="grid grid-cols-2 gap-6">
+            <BucketTable
+              title="By project"
+              buckets={stats.by_project}
+              label={(b) => b.label ?? b.key.split("/").pop() ?? b.key}
+            />
+            <BucketTable
+              title="By model"
+              buckets={stats.by_model}
+              label={(b) => b.key}
+            />
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
+
+function ActivityHeatmap({ activity }: { activity: ClaudeActivity }) {
+  const byDay = new Map(activity.days.map((d) => [d.day, d]));
+  const max = Math.max(1, ...activity.days.map((d) => d.messages));
+
+  // Columns are weeks starting on Sunday, ending with the current week.
+  const start = new Date();
+  start.setHours(0, 0, 0, 0);
+  start.setDate(start.getDate() - start.getDay() - (HEATMAP_WEEKS - 1) * 7);
+  const today = localDay(new Date());
+  const weeks = Array.from({ length: HEATMAP_WEEKS }, (_, w) =>
+    Array.from({ length: 7 }, (_, d) => {
+      const date = new Date(start);
+      date.setDate(start.getDate() + w * 7 + d);
+      return localDay(date);
+    }),
+  );
+
+  return (
+    <section>
+      <h2 className="text-sm font-semibold mb-2">
+        Activity
+        <span className="ml-2 font-normal text-xs text-muted-foreground">
+          {activity.total_messages.toLocaleString()} messages in{" "}
+          {activity.total_sessions} sessions
+        </span>
+      </h2>
+      <div className="flex gap-6">
+        <div className="flex gap-0.5">
+          {weeks.map((days) => (
+            <div key={days[0]} className="flex flex-col gap-0.5">
+              {days.map((day) => {
+                const entry = byDay.get(day);
+                const level = entry
+                  ? Math.min(4, Math.ceil((entry.messages / max) * 4))
+                  : 0;
+                return (
+                  <div
+                    key={day}
+                    className={`size-2.5 rounded-[2px] ${
+                      day > today ? "opacity-0" : HEAT_CLASSES[level]
+                    }`}
+                    title={
+                      entry
+                        ? `${day}: ${entry.messages} messages, ${entry.sessions} sessions`
+                        : day
+                    }
+                  />
+                );
+              })}
+            </div>
+          ))}
+        </div>
+        <ol className="flex-1 min-w-0 space-y-1 text-sm">
+          {activity.top_projects.slice(0, 5).map((p) => (
+            <li key={p.key} className="flex items-center gap-2">
+              <span className="truncate flex-1" title={p.key}>
+                {p.label ?? p.key.split("/").pop() ?? p.key}
+              </span>
+              <span className="text-xs text-muted-foreground shrink-0">
+                {p.messages.toLocaleString()} msgs · {p.sessions} sessions
+              </span>
+            </li>
+          ))}
+        </ol>
+      </div>
+    </section>
+  );
+}
+
+function Stat({ label, value }: { label: string; value: string }) {
+  return (
+    <div className="p-3 rounded-lg border border-border bg-card">
+      <p className="text-xs text-muted-foreground mb-1">{label}</p>
+      <p className="text-lg font-bold">{value}</p>
+    </div>
+  );
+}
+
+function BucketTable({
+  title,
+  buckets,
+  label,
+}: {
+  title: string;
+  buckets: UsageBucket[];
+  label: (bucket: UsageBucket) => string;
+}) {
+  return (
+    <section>
+      <h2 className="text-sm font-semibold mb-2">{title}</h2>
+      <table className="w-full text-sm">
+        <thead>
+          <tr className="text-xs text-muted-foreground text-left">
+            <th className="font-normal pb-1">Name</th>
+            <th className="font-normal pb-1 text-right">Tokens</th>
+            <th className="font-normal pb-1 text-right">Cost</th>
+          </tr>
+        </thead>
+        <tbody>
+          {buckets.map((b) => (
+            <tr key={b.key} className="border-t border-border">
+              <td className="py-1.5 truncate max-w-48" title={b.key}>
+                {label(b)}
+              </td>
+              <td className="py-1.5 text-right text-muted-foreground">
+                {formatTokens(
+                  b.input_tokens +
+                    b.output_tokens +
+                    b.cache_read_tokens +
+                    b.cache_creation_tokens,
+                )}
+              </td>
+              <td className="py-1.5 text-right font-mono">
+                {formatUsd(b.cost_usd)}
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </section>
+  );
+}
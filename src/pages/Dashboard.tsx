@@ -1,4 +1,6 @@
+import { useEffect } from "react";
 import { useQuery } from "@tanstack/react-query";
+import { listen } from "@tauri-apps/api/event";
 import { Link } from "react-router";
 import {
   FolderOpen,
@@ -7,18 +9,28 @@ import {
   Clock,
   Bot,
   Terminal,
+  Wallet,
 } from "lucide-react";
 import { api } from "@/lib/api";
-import { formatRelativeTime, getProjectColor } from "@/lib/utils";
+import { formatRelativeTime, getProjectBadge, getProjectColor } from "@/lib/utils";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
+import { ClaudeActiveDot } from "@/components/ClaudeActiveDot";
+import { useActiveClaudeProjects } from "@/hooks/useClaudeProcesses";
+import type { BudgetThreshold, CostSummary } from "@/types";
 
 export default function Dashboard() {
+  const activeClaudeProjects = useActiveClaudeProjects();
   const { data: projects } = useQuery({
     queryKey: ["projects"],
     queryFn: api.getProjects,
   });
 
+  const { data: recentProjects } = useQuery({
+    queryKey: ["projects-recent"],
+    queryFn: () => api.getRecentProjects(6),
+  });
+
   const { data: taskFiles } = useQuery({
     queryKey: ["claude-tasks"],
     queryFn: api.readClaudeTasks,
@@ -29,6 +41,22 @@ export default function Dashboard() {
     queryFn: api.readClaudeSessions,
   });
 
+  const { data: costSummary, refetch: refetchCost } = useQuery({
+    queryKey: ["cost-summary"],
+    queryFn: () => api.getCostSummary(),
+    staleTime: 5 * 60_000,
+  });
+
+  // A crossed budget threshold means the cached total is behind.
+  useEffect(() => {
+    const unlisten = listen<BudgetThreshold>("budget-threshold", () =>
+      refetchCost(),
+    );
+    return () => {
+      unlisten.then((f) => f());
+    };
+  }, [refetchCost]);
+
   const totalTasks =
     taskFiles?.reduce(
       (sum, tf) =>
@@ -38,6 +66,12 @@ export default function Dashboard() {
 
   const recentSessions = sessions?.slice(0, 5) ?? [];
 
+  // Prefer projects the user actually opened; fall back to the first few.
+  const hasRecent = (recentProjects?.length ?? 0) > 0;
+  const featuredProjects = hasRecent
+    ? recentProjects!
+    : (projects?.slice(0, 6) ?? []);
+
   return (
     <div className="p-6 max-w-6xl mx-auto">
       <div className="mb-6">
@@ -69,10 +103,16 @@ export default function Dashboard() {
         />
       </div>
 
+      {costSummary && costSummary.sessions > 0 && (
+        <SpendCard summary={costSummary} />
+      )}
+
       {/* Projects grid */}
       <section className="mb-8">
         <div className="flex items-center justify-between mb-3">
-          <h2 className="text-base font-semibold">Projects</h2>
+          <h2 className="text-base font-semibold">
+            {hasRecent ? "Recently worked on" : "Projects"}
+          </h2>
           <Link to="/projects">
             <Button variant="ghost" size="sm" className="text-xs">
               View all
@@ -93,12 +133,13 @@ export default function Dashboard() {
           />
         ) : (
           <div className="grid grid-cols-2 lg:grid-cols-3 gap-3">
-            {projects.slice(0, 6).map((project) => (
+            {featuredProjects.map((project) => (
               <ProjectCard
                 key={project.id}
                 project={project}
                 sessions={sessions ?? []}
                 tasks={taskFiles ?? []}
+                claudeActive={activeClaudeProjects.has(project.id)}
               />
             ))}
           </div>
@@ -127,10 +168,14 @@ export default function Dashboard() {
               >
                 <Terminal className="size-4 text-muted-foreground shrink-0" />
                 <div className="flex-1 min-w-0">
-                  <p className="text-sm font-mono truncate">
-                    {session.cwd?.split("/").pop() ||
-                      session.project_key.slice(0, 20)}
-                  </p>
+                  {session.title ? (
+                    <p className="text-sm truncate">{session.title}</p>
+                  ) : (
+                    <p className="text-sm font-mono truncate">
+                      {session.cwd?.split("/").pop() ||
+                        session.project_key.slice(0, 20)}
+                    </p>
+                  )}
                   <p className="text-xs text-muted-foreground font-mono truncate">
                     {session.cwd}
                   </p>
@@ -152,6 +197,55 @@ export default function Dashboard() {
   );
 }
 
+/** Estimated agent spend this month, with progress against the budget. */
+function SpendCard({ summary }: { summary: CostSummary }) {
+  const pct = summary.budget_used_pct;
+  const barColor =
+    pct == null || pct < 80
+      ? "bg-primary"
+      : pct < 100
+        ? "bg-amber-500"
+        : "bg-destructive";
+
+  return (
+    <div className="p-4 rounded-lg border border-border bg-card mb-8">
+      <div className="flex items-center justify-between mb-2">
+        <div className="flex items-center gap-2">
+          <Wallet className="size-4 text-muted-foreground" />
+          <span className="text-sm text-muted-foreground">
+            Agent spend this month (estimated)
+          </span>
+        </div>
+        <span className="text-sm">
+          <span className="font-bold">${summary.total_usd.toFixed(2)}</span>
+          {summary.budget_usd > 0 && (
+            <span className="text-muted-foreground">
+              {" "}
+              of ${summary.budget_usd.toFixed(2)}
+            </span>
+          )}
+        </span>
+      </div>
+      {pct != null && (
+        <div className="h-1.5 rounded-full bg-muted overflow-hidden">
+          <div
+            className={`h-full ${barColor}`}
+            style={{ width: `${Math.min(pct, 100)}%` }}
+          />
+        </div>
+      )}
+      <div className="flex gap-4 mt-2 text-xs text-muted-foreground">
+        <span>{summary.sessions} sessions</span>
+        {summary.by_project.slice(0, 3).map((p) => (
+          <span key={p.key} className="font-mono truncate">
+            {p.key.split("/").pop()} ${p.cost_usd.toFixed(2)}
+          </span>
+        ))}
+      </div>
+    </div>
+  );
+}
+
 function StatCard({
   icon: Icon,
   label,
@@ -180,17 +274,25 @@ function ProjectCard({
   project,
   sessions,
   tasks,
+  claudeActive,
 }: {
   project: import("@/types").Project;
   sessions: import("@/types").ClaudeSession[];
   tasks: import("@/types").ClaudeTaskFile[];
+  claudeActive: boolean;
 }) {
   const color = getProjectColor(project.color, project.name);
 
-  // Find sessions for this project by matching cwd
-  const projectSessions = sessions.filter(
-    (s) => s.cwd && s.cwd.startsWith(project.path),
-  );
+  const { data: overview } = useQuery({
+    queryKey: ["project-overview", project.id],
+    queryFn: () => api.getProjectOverview(project.id),
+  });
+  const gitChanges = overview?.git
+    ? overview.git.staged + overview.git.unstaged + overview.git.untracked
+    : 0;
+
+  // Sessions are correlated to the deepest matching project on the backend
+  const projectSessions = sessions.filter((s) => s.project_id === project.id);
   const lastSession = projectSessions[0];
 
   // Count active tasks (by team directories correlating to project... rough heuristic)
@@ -211,10 +313,13 @@ function ProjectCard({
             className="w-8 h-8 rounded-md flex items-center justify-center text-white text-xs font-bold shrink-0"
             style={{ backgroundColor: color }}
           >
-            {project.name.slice(0, 2).toUpperCase()}
+            {getProjectBadge(project.icon, project.name)}
           </div>
           <div className="flex-1 min-w-0">
-            <p className="font-medium text-sm truncate">{project.name}</p>
+            <p className="font-medium text-sm truncate flex items-center gap-1.5">
+              {project.name}
+              {claudeActive && <ClaudeActiveDot />}
+            </p>
             <p className="text-xs text-muted-foreground font-mono truncate">
               {project.path.replace("/Users/admin/", "~/")}
             </p>
@@ -245,6 +350,18 @@ function ProjectCard({
               {activeTasks} tasks
             </Badge>
           )}
+          {overview?.git && (
+            <Badge variant="secondary" className="text-xs">
+              <GitBranch className="size-3 mr-1" />
+              {overview.git.branch}
+              {gitChanges > 0 && ` · ${gitChanges}`}
+            </Badge>
+          )}
+          {(overview?.open_planning_items ?? 0) > 0 && (
+            <Badge variant="secondary" className="text-xs">
+              {overview!.open_planning_items} open items
+            </Badge>
+          )}
         </div>
       </div>
     </Link>
@@ -1,10 +1,37 @@
+import { useState, type ReactNode } from "react";
 import { useOutletContext } from "react-router";
-import { useQuery } from "@tanstack/react-query";
-import { GitBranch, GitCommit, Loader2, AlertCircle } from "lucide-react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import {
+  GitBranch,
+  GitCommit,
+  Loader2,
+  AlertCircle,
+  Camera,
+  Check,
+  CircleDot,
+  Clock,
+  ExternalLink,
+  GitPullRequest,
+  MessageSquare,
+  Minus,
+  Plus,
+  X,
+  RefreshCw,
+  RotateCcw,
+  Trash2,
+} from "lucide-react";
+import { toast } from "sonner";
 import { api } from "@/lib/api";
 import { formatRelativeTime } from "@/lib/utils";
 import { Badge } from "@/components/ui/badge";
-import type { Project } from "@/types";
+import { Button } from "@/components/ui/button";
+import type {
+  GitStatus,
+  GithubPullRequest,
+  PrCheck,
+  Project,
+  WorkspaceSnapshot,
+} from "@/types";
 
 export default function ProjectGit() {
   const { project } = useOutletContext<{ project: Project }>();
@@ -103,54 +130,21 @@ export default function ProjectGit() {
         </section>
       )}
 
-      {/* Working tree status */}
       {status && (
-        <section>
-          <h3 className="text-sm font-semibold mb-2 text-muted-foreground uppercase tracking-wide">
-            Working Tree
-          </h3>
-          {status.staged.length === 0 &&
-          status.unstaged.length === 0 &&
-          status.untracked.length === 0 ? (
-            <p className="text-sm text-muted-foreground">Clean working tree</p>
-          ) : (
-            <div className="space-y-1">
-              {status.staged.map((f) => (
-                <div
-                  key={`staged-${f.path}`}
-                  className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-green-50 dark:bg-green-950 border border-green-200 dark:border-green-800"
-                >
-                  <span className="text-green-700 dark:text-green-300 font-sans">
-                    S
-                  </span>
-                  {f.path}
-                </div>
-              ))}
-              {status.unstaged.map((f) => (
-                <div
-                  key={`unstaged-${f.path}`}
-                  className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-yellow-50 dark:bg-yellow-950 border border-yellow-200 dark:border-yellow-800"
-                >
-                  <span className="text-yellow-700 dark:text-yellow-300 font-sans uppercase">
-                    {f.status[0]}
-                  </span>
-                  {f.path}
-                </div>
-              ))}
-              {status.untracked.map((f) => (
-                <div
-                  key={`untracked-${f}`}
-                  className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-muted border border-border"
-                >
-                  <span className="text-muted-foreground font-sans">?</span>
-                  {f}
-                </div>
-              ))}
-            </div>
-          )}
-        </section>
+        <WorkingTreeSection projectPath={project.path} status={status} />
       )}
 
+      <SnapshotsSection projectPath={project.path} />
+
+      <PullRequestsSection
+        projectPath={project.path}
+        branch={status?.branch}
+        baseCandidates={branches?.map((b) => b.name) ?? []}
+        lastCommitMessage={commits?.[0]?.message}
+      />
+
+      <IssuesSection projectPath={project.path} />
+
       {/* Commits */}
       <section>
         <h3 className="text-sm font-semibold mb-2 text-muted-foreground uppercase tracking-wide">
@@ -187,3 +181,771 @@ export default function ProjectGit() {
     </div>
   );
 }
+
+// ─── Pull requests ─────────────────────────────────────────────────────────
+
+const checkIcon: Record<PrCheck["bucket"], ReactNode> = {
+  pass: <Check className="size-3 text-green-500" />,
+  fail: <X className="size-3 text-red-500" />,
+  pending: <Clock className="size-3 text-amber-500" />,
+  skipping: <span className="size-3 text-muted-foreground">–</span>,
+  cancel: <X className="size-3 text-muted-foreground" />,
+};
+
+/**
+ * Staged, unstaged and untracked files, each with a button to move it in
+ * or out of the index.
+ */
+function WorkingTreeSection({
+  projectPath,
+  status,
+}: {
+  projectPath: string;
+  status: GitStatus;
+}) {
+  const queryClient = useQueryClient();
+  const indexMutation = useMutation({
+    mutationFn: ({ paths, stage }: { paths: string[]; stage: boolean }) =>
+      stage
+        ? api.gitStage(projectPath, paths)
+        : api.gitUnstage(projectPath, paths),
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["git-status", projectPath] }),
+    onError: (e) =>
+      toast.error("Could not update the index", { description: String(e) }),
+  });
+  const stage = (paths: string[]) =>
+    indexMutation.mutate({ paths, stage: true });
+  const unstage = (paths: string[]) =>
+    indexMutation.mutate({ paths, stage: false });
+
+  const unstagedPaths = [
+    ...status.unstaged.map((f) => f.path),
+    ...status.untracked,
+  ];
+  const isClean =
+    status.staged.length === 0 &&
+    status.unstaged.length === 0 &&
+    status.untracked.length === 0;
+
+  const toggle = (path: string, staged: boolean) => (
+    <Button
+      variant="ghost"
+      size="sm"
+      className="h-5 px-1 ml-auto font-sans"
+      onClick={() => (staged ? unstage([path]) : stage([path]))}
+      disabled={indexMutation.isPending}
+      title={staged ? "Unstage" : "Stage"}
+    >
+      {staged ? <Minus className="size-3" /> : <Plus className="size-3" />}
+    </Button>
+  );
+
+  return (
+    <section>
+      <div className="flex items-center justify-between mb-2">
+        <h3 className="text-sm font-semibold text-muted-foreground uppercase tracking-wide">
+          Working Tree
+        </h3>
+        <div className="flex gap-1.5">
+          {status.staged.length > 0 && (
+            <Button
+              variant="outline"
+              size="sm"
+              className="h-7 text-xs"
+              onClick={() => unstage(status.staged.map((f) => f.path))}
+              disabled={indexMutation.isPending}
+            >
+              Unstage all
+            </Button>
+          )}
+          {unstagedPaths.length > 0 && (
+            <Button
+              variant="outline"
+              size="sm"
+              className="h-7 text-xs"
+              onClick={() => stage(unstagedPaths)}
+              disabled={indexMutation.isPending}
+            >
+              Stage all
+            </Button>
+          )}
+        </div>
+      </div>
+      {isClean ? (
+        <p className="text-sm text-muted-foreground">Clean working tree</p>
+      ) : (
+        <div className="space-y-1">
+          {status.staged.map((f) => (
+            <div
+              key={`staged-${f.path}`}
+              className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-green-50 dark:bg-green-950 border border-green-200 dark:border-green-800"
+            >
+              <span className="text-green-700 dark:text-green-300 font-sans">
+                S
+              </span>
+              {f.path}
+              {toggle(f.path, true)}
+            </div>
+          ))}
+          {status.unstaged.map((f) => (
+            <div
+              key={`unstaged-${f.path}`}
+              className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-yellow-50 dark:bg-yellow-950 border border-yellow-200 dark:border-yellow-800"
+            >
+              <span className="text-yellow-700 dark:text-yellow-300 font-sans uppercase">
+                {f.status[0]}
+              </span>
+              {f.path}
+              {toggle(f.path, false)}
+            </div>
+          ))}
+          {status.untracked.map((f) => (
+            <div
+              key={`untracked-${f}`}
+              className="flex items-center gap-2 px-3 py-1.5 rounded text-xs font-mono bg-muted border border-border"
+            >
+              <span className="text-muted-foreground font-sans">?</span>
+              {f}
+              {toggle(f, false)}
+            </div>
+          ))}
+        </div>
+      )}
+      <CommitForm
+        projectPath={projectPath}
+        canCommit={status.staged.length > 0}
+      />
+    </section>
+  );
+}
+
+/**
+ * Commit the index through `git_commit`, which runs the repository's
+ * hooks.  Open issues linked to tasks can be closed by the commit through
+ * a `Fixes` trailer from `suggestClosingCommit`.
+ */
+function CommitForm({
+  projectPath,
+  canCommit,
+}: {
+  projectPath: string;
+  canCommit: boolean;
+}) {
+  const queryClient = useQueryClient();
+  const [message, setMessage] = useState("");
+  const [amend, setAmend] = useState(false);
+
+  const { data: repo } = useQuery({
+    queryKey: ["github-repo", projectPath],
+    queryFn: () => api.detectGithubRepo(projectPath),
+  });
+  const { data: links } = useQuery({
+    queryKey: ["task-github-links"],
+    queryFn: api.getTaskGithubLinks,
+    enabled: !!repo,
+  });
+  const openLinks = (links ?? []).filter(
+    (l) =>
+      l.github_repo === repo &&
+      l.github_issue_number != null &&
+      l.github_issue_state !== "closed",
+  );
+
+  const trailerMutation = useMutation({
+    mutationFn: (key: string) => {
+      // Keyed "team/task"; task ids never contain a slash.
+      const split = key.lastIndexOf("/");
+      return api.suggestClosingCommit(
+        key.slice(split + 1),
+        key.slice(0, split),
+        message,
+      );
+    },
+    onSuccess: (suggestion) => setMessage(suggestion.message ?? message),
+    onError: (e) =>
+      toast.error("Could not add the reference", { description: String(e) }),
+  });
+
+  const commitMutation = useMutation({
+    mutationFn: () => api.gitCommit(projectPath, message, amend),
+    onSuccess: (commit) => {
+      toast.success(`Committed ${commit.short_hash}`, {
+        description: commit.message,
+      });
+      setMessage("");
+      setAmend(false);
+      queryClient.invalidateQueries({ queryKey: ["git-status", projectPath] });
+      queryClient.invalidateQueries({ queryKey: ["git-log", projectPath] });
+    },
+    onError: (e) => toast.error("Commit failed", { description: String(e) }),
+  });
+
+  return (
+    <div className="mt-3 p-3 rounded-md border border-border bg-card space-y-2">
+      <textarea
+        className="w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary resize-none font-mono"
+        rows={3}
+        placeholder={amend ? "New message for the last commit" : "Message"}
+        value={message}
+        onChange={(e) => setMessage(e.target.value)}
+      />
+      <div className="flex items-center gap-3">
+        <label className="flex items-center gap-1.5 text-xs text-muted-foreground cursor-pointer">
+          <input
+            type="checkbox"
+            checked={amend}
+            onChange={(e) => setAmend(e.target.checked)}
+          />
+          Amend last commit
+        </label>
+        {openLinks.length > 0 && (
+          <select
+            className="text-xs bg-muted border border-border rounded-md px-2 py-1 outline-none focus:ring-1 focus:ring-primary"
+            value=""
+            onChange={(e) => trailerMutation.mutate(e.target.value)}
+            disabled={trailerMutation.isPending}
+          >
+            <option value="">Close an issue…</option>
+            {openLinks.map((l) => (
+              <option
+                key={`${l.team_id}/${l.task_id}`}
+                value={`${l.team_id}/${l.task_id}`}
+              >
+                #{l.github_issue_number} (task {l.task_id})
+              </option>
+            ))}
+          </select>
+        )}
+        <Button
+          size="sm"
+          className="ml-auto"
+          disabled={
+            !message.trim() ||
+            (!canCommit && !amend) ||
+            commitMutation.isPending
+          }
+          onClick={() => commitMutation.mutate()}
+        >
+          {commitMutation.isPending ? (
+            <Loader2 className="size-3 mr-1.5 animate-spin" />
+          ) : (
+            <GitCommit className="size-3 mr-1.5" />
+          )}
+          {amend ? "Amend" : "Commit"}
+        </Button>
+      </div>
+    </div>
+  );
+}
+
+function PullRequestsSection({
+  projectPath,
+  branch,
+  baseCandidates,
+  lastCommitMessage,
+}: {
+  projectPath: string;
+  branch?: string;
+  baseCandidates: string[];
+  lastCommitMessage?: string;
+}) {
+  const [creating, setCreating] = useState(false);
+
+  const { data: repo } = useQuery({
+    queryKey: ["github-repo", projectPath],
+    queryFn: () => api.detectGithubRepo(projectPath),
+  });
+  const { data: prs, error } = useQuery({
+    queryKey: ["github-prs", repo],
+    queryFn: () => api.listGithubPrs(repo!),
+    enabled: !!repo,
+    retry: false,
+  });
+
+  if (!repo) return null;
+
+  const hasOpenPr = prs?.some((pr) => pr.head_ref === branch) ?? false;
+  const defaultBase =
+    ["main", "master", "develop"].find((b) => baseCandidates.includes(b)) ??
+    "main";
+
+  return (
+    <section>
+      <div className="flex items-center justify-between mb-2">
+        <h3 className="text-sm font-semibold text-muted-foreground uppercase tracking-wide">
+          Pull Requests
+        </h3>
+        {branch && branch !== defaultBase && !hasOpenPr && !creating && (
+          <Button
+            variant="outline"
+            size="sm"
+            className="h-7 text-xs"
+            onClick={() => setCreating(true)}
+          >
+            <Plus className="size-3 mr-1" />
+            Open PR for {branch}
+          </Button>
+        )}
+      </div>
+      {creating && branch && (
+        <CreatePrForm
+          repo={repo}
+          head={branch}
+          defaultBase={defaultBase}
+          defaultTitle={lastCommitMessage?.split("\n")[0] ?? branch}
+          onDone={() => setCreating(false)}
+        />
+      )}
+      {error ? (
+        <p className="text-sm text-muted-foreground">
+          Could not list pull requests: {String(error)}
+        </p>
+      ) : prs && prs.length > 0 ? (
+        <div className="space-y-1">
+          {prs.map((pr) => (
+            <PullRequestRow key={pr.number} repo={repo} pr={pr} />
+          ))}
+        </div>
+      ) : (
+        <p className="text-sm text-muted-foreground">No open pull requests</p>
+      )}
+    </section>
+  );
+}
+
+function PullRequestRow({
+  repo,
+  pr,
+}: {
+  repo: string;
+  pr: GithubPullRequest;
+}) {
+  const [open, setOpen] = useState(false);
+  const { data: checks, isLoading } = useQuery({
+    queryKey: ["pr-checks", repo, pr.number],
+    queryFn: () => api.getPrChecks(repo, pr.number),
+    enabled: open,
+    retry: false,
+  });
+
+  return (
+    <details
+      className="rounded-md bg-card border border-border text-sm"
+      onToggle={(e) => setOpen(e.currentTarget.open)}
+    >
+      <summary className="flex items-center gap-3 px-3 py-2 cursor-pointer list-none">
+        <GitPullRequest
+          className={`size-3.5 shrink-0 ${pr.is_draft ? "text-muted-foreground" : "text-green-500"}`}
+        />
+        <span className="font-mono text-xs text-muted-foreground shrink-0">
+          #{pr.number}
+        </span>
+        <span className="flex-1 truncate">{pr.title}</span>
+        <span className="font-mono text-xs text-muted-foreground shrink-0">
+          {pr.head_ref} → {pr.base_ref}
+        </span>
+        {pr.review_decision === "APPROVED" && (
+          <Badge variant="success" className="text-xs shrink-0">
+            approved
+          </Badge>
+        )}
+        {pr.review_decision === "CHANGES_REQUESTED" && (
+          <Badge variant="warning" className="text-xs shrink-0">
+            changes requested
+          </Badge>
+        )}
+        {pr.is_draft && (
+          <Badge variant="outline" className="text-xs shrink-0">
+            draft
+          </Badge>
+        )}
+        <button
+          type="button"
+          className="text-muted-foreground hover:text-foreground shrink-0"
+          onClick={(e) => {
+            e.preventDefault();
+            window.open(pr.url, "_blank");
+          }}
+          title="Open on GitHub"
+        >
+          <ExternalLink className="size-3.5" />
+        </button>
+      </summary>
+      <div className="border-t border-border px-3 py-2 space-y-1 text-xs">
+        {isLoading ? (
+          <Loader2 className="size-3 animate-spin text-muted-foreground" />
+        ) : checks && checks.length > 0 ? (
+          checks.map((check, i) => (
+            <div key={i} className="flex items-center gap-2">
+              {checkIcon[check.bucket]}
+              <span className="truncate">
+                {check.workflow ? `${check.workflow} / ` : ""}
+                {check.name}
+              </span>
+              {check.link && (
+                <button
+                  type="button"
+                  className="ml-auto text-muted-foreground hover:text-foreground"
+                  onClick={() => window.open(check.link!, "_blank")}
+                  title="Open check"
+                >
+                  <ExternalLink className="size-3" />
+                </button>
+              )}
+            </div>
+          ))
+        ) : (
+          <p className="text-muted-foreground">No checks reported</p>
+        )}
+      </div>
+    </details>
+  );
+}
+
+function CreatePrForm({
+  repo,
+  head,
+  defaultBase,
+  defaultTitle,
+  onDone,
+}: {
+  repo: string;
+  head: string;
+  defaultBase: string;
+  defaultTitle: string;
+  onDone: () => void;
+}) {
+  const queryClient = useQueryClient();
+  const [base, setBase] = useState(defaultBase);
+  const [title, setTitle] = useState(defaultTitle);
+  const [body, setBody] = useState("");
+
+  const createMutation = useMutation({
+    mutationFn: () =>
+      api.createGithubPr(repo, base.trim(), head, title.trim(), body),
+    onSuccess: (created) => {
+      queryClient.invalidateQueries({ queryKey: ["github-prs", repo] });
+      toast.success(`Opened PR #${created.number}`, {
+        action: {
+          label: "View",
+          onClick: () => window.open(created.url, "_blank"),
+        },
+      });
+      onDone();
+    },
+    onError: (e) =>
+      toast.error("Could not open the pull request", {
+        description: String(e),
+      }),
+  });
+
+  const inputClass =
+    "w-full text-sm bg-muted border border-border rounded-md px-3 py-1.5 outline-none focus:ring-1 focus:ring-primary";
+
+  return (
+    <div className="mb-3 p-3 rounded-md border border-border bg-card space-y-2">
+      <div className="flex items-center gap-2 text-xs text-muted-foreground">
+        <span className="font-mono text-foreground">{head}</span>
+        into
+        <input
+          className="w-32 text-xs font-mono bg-muted border border-border rounded-md px-2 py-1 outline-none focus:ring-1 focus:ring-primary"
+          value={base}
+          onChange={(e) => setBase(e.target.value)}
+        />
+        <span className="ml-auto">The branch must be pushed first</span>
+      </div>
+      <input
+        className={inputClass}
+        placeholder="Title"
+        value={title}
+        onChange={(e) => setTitle(e.target.value)}
+      />
+      <textarea
+        className={`${inputClass} resize-none`}
+        rows={4}
+        placeholder="Description"
+        value={body}
+        onChange={(e) => setBody(e.target.value)}
+      />
+      <div className="flex justify-end gap-2">
+        <Button variant="outline" size="sm" onClick={onDone}>
+          Cancel
+        </Button>
+        <Button
+          size="sm"
+          disabled={!title.trim() || !base.trim() || createMutation.isPending}
+          onClick={() => createMutation.mutate()}
+        >
+          {createMutation.isPending ? (
+            <Loader2 className="size-3 mr-1.5 animate-spin" />
+          ) : (
+            <GitPullRequest className="size-3 mr-1.5" />
+          )}
+          Create PR
+        </Button>
+      </div>
+    </div>
+  );
+}
+
+// ─── GitHub issues (offline cache) ─────────────────────────────────────────
+
+function IssuesSection({ projectPath }: { projectPath: string }) {
+  const queryClient = useQueryClient();
+
+  const { data: repo } = useQuery({
+    queryKey: ["github-repo", projectPath],
+    queryFn: () => api.detectGithubRepo(projectPath),
+  });
+  // Served from the local cache, so the list survives network outages.
+  const { data: issues } = useQuery({
+    queryKey: ["cached-issues", repo],
+    queryFn: () => api.getCachedIssues(repo!),
+    enabled: !!repo,
+  });
+
+  const refreshMutation = useMutation({
+    mutationFn: () => api.refreshIssueCache(repo!),
+    onSuccess: (fresh) =>
+      queryClient.setQueryData(["cached-issues", repo], fresh),
+    onError: (e) =>
+      toast.error("Could not refresh issues; showing cached copy", {
+        description: String(e),
+      }),
+  });
+
+  if (!repo) return null;
+
+  const newestFetch = issues
+    ?.map((i) => i.fetched_at)
+    .sort()
+    .at(-1);
+  const anyStale = issues?.some((i) => i.stale) ?? false;
+
+  return (
+    <section>
+      <div className="flex items-center justify-between mb-2">
+        <h3 className="text-sm font-semibold text-muted-foreground uppercase tracking-wide">
+          Issues
+        </h3>
+        <div className="flex items-center gap-2">
+          {newestFetch && (
+            <span
+              className={`text-xs ${anyStale ? "text-amber-500" : "text-muted-foreground"}`}
+            >
+              {anyStale ? "Stale · " : ""}fetched{" "}
+              {formatRelativeTime(newestFetch)}
+            </span>
+          )}
+          <Button
+            variant="outline"
+            size="sm"
+            className="h-7 text-xs"
+            disabled={refreshMutation.isPending}
+            onClick={() => refreshMutation.mutate()}
+          >
+            {refreshMutation.isPending ? (
+              <Loader2 className="size-3 mr-1 animate-spin" />
+            ) : (
+              <RefreshCw className="size-3 mr-1" />
+            )}
+            Refresh
+          </Button>
+        </div>
+      </div>
+      {issues && issues.length > 0 ? (
+        <div className="space-y-1">
+          {issues.map((issue) => (
+            <details
+              key={issue.number}
+              className="rounded-md bg-card border border-border text-sm"
+            >
+              <summary className="flex items-center gap-3 px-3 py-2 cursor-pointer list-none">
+                <CircleDot
+                  className={`size-3.5 shrink-0 ${
+                    issue.state === "open"
+                      ? "text-green-500"
+                      : "text-purple-500"
+                  }`}
+                />
+                <span className="font-mono text-xs text-muted-foreground shrink-0">
+                  #{issue.number}
+                </span>
+                <span className="flex-1 truncate">{issue.title}</span>
+                {issue.comments.length > 0 && (
+                  <span className="flex items-center gap-1 text-xs text-muted-foreground shrink-0">
+                    <MessageSquare className="size-3" />
+                    {issue.comments.length}
+                  </span>
+                )}
+                {issue.stale && (
+                  <Badge variant="outline" className="text-xs shrink-0">
+                    stale
+                  </Badge>
+                )}
+              </summary>
+              <div className="border-t border-border px-3 py-2 space-y-2 text-xs">
+                {issue.body && (
+                  <p className="whitespace-pre-wrap text-muted-foreground">
+                    {issue.body}
+                  </p>
+                )}
+                {issue.comments.map((c, i) => (
+                  <div key={i} className="border-l-2 border-border pl-2">
+                    <p className="text-muted-foreground">
+                      {c.author ?? "unknown"}
+                      {c.created_at && ` · ${formatRelativeTime(c.created_at)}`}
+                    </p>
+                    <p className="whitespace-pre-wrap">{c.body}</p>
+                  </div>
+                ))}
+              </div>
+            </details>
+          ))}
+        </div>
+      ) : (
+        <p className="text-sm text-muted-foreground">
+          No cached issues for {repo} yet. Refresh to fetch them; they stay
+          available offline afterwards.
+        </p>
+      )}
+    </section>
+  );
+}
+
+// ─── Workspace snapshots ───────────────────────────────────────────────────
+
+function SnapshotsSection({ projectPath }: { projectPath: string }) {
+  const queryClient = useQueryClient();
+
+  const { data: snapshots } = useQuery({
+    queryKey: ["workspace-snapshots", projectPath],
+    queryFn: () => api.listWorkspaceSnapshots(projectPath),
+  });
+
+  const refresh = () => {
+    queryClient.invalidateQueries({
+      queryKey: ["workspace-snapshots", projectPath],
+    });
+    queryClient.invalidateQueries({ queryKey: ["git-status", projectPath] });
+    queryClient.invalidateQueries({ queryKey: ["git-log", projectPath] });
+  };
+
+  const createMutation = useMutation({
+    mutationFn: (label?: string) =>
+      api.createWorkspaceSnapshot(projectPath, label),
+    onSuccess: (s) => {
+      refresh();
+      toast.success(
+        s.has_changes
+          ? "Snapshot taken, including uncommitted changes"
+          : "Snapshot taken",
+      );
+    },
+    onError: (e) => toast.error(`Snapshot failed: ${String(e)}`),
+  });
+
+  const restoreMutation = useMutation({
+    mutationFn: api.restoreWorkspaceSnapshot,
+    onSuccess: (backup) => {
+      refresh();
+      toast.success("Workspace restored", {
+        action: {
+          label: "Undo",
+          onClick: () => restoreMutation.mutate(backup.id),
+        },
+      });
+    },
+    onError: (e) => toast.error(`Restore failed: ${String(e)}`),
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: api.deleteWorkspaceSnapshot,
+    onSuccess: refresh,
+  });
+
+  const restore = (s: WorkspaceSnapshot) => {
+    if (
+      window.confirm(
+        `Restore ${s.branch ?? "HEAD"} to ${s.head_commit.slice(0, 7)} and reset the working tree to this snapshot? The current state is snapshotted first.`,
+      )
+    )
+      restoreMutation.mutate(s.id);
+  };
+
+  return (
+    <section>
+      <div className="flex items-center justify-between mb-2">
+        <h3 className="text-sm font-semibold text-muted-foreground uppercase tracking-wide">
+          Snapshots
+        </h3>
+        <Button
+          variant="outline"
+          size="sm"
+          className="h-7 text-xs"
+          disabled={createMutation.isPending}
+          onClick={() => {
+            const label = window.prompt("Snapshot label (optional)");
+            if (label !== null) createMutation.mutate(label.trim() || undefined);
+          }}
+        >
+          {createMutation.isPending ? (
+            <Loader2 className="size-3 mr-1 animate-spin" />
+          ) : (
+            <Camera className="size-3 mr-1" />
+          )}
+          Snapshot now
+        </Button>
+      </div>
+      {snapshots && snapshots.length > 0 ? (
+        <div className="space-y-1">
+          {snapshots.map((s) => (
+            <div
+              key={s.id}
+              className="flex items-center gap-3 px-3 py-2 rounded-md bg-card border border-border text-sm"
+            >
+              <span className="font-mono text-xs text-muted-foreground shrink-0">
+                {s.head_commit.slice(0, 7)}
+              </span>
+              <span className="flex-1 truncate">
+                {s.label ?? s.branch ?? "detached HEAD"}
+                {s.has_changes && (
+                  <span className="text-xs text-muted-foreground">
+                    {" "}
+                    + uncommitted changes
+                  </span>
+                )}
+              </span>
+              <span className="text-xs text-muted-foreground shrink-0">
+                {formatRelativeTime(s.created_at)}
+              </span>
+              <button
+                type="button"
+                title="Restore this snapshot"
+                disabled={restoreMutation.isPending}
+                onClick={() => restore(s)}
+                className="text-muted-foreground hover:text-foreground disabled:opacity-50"
+              >
+                <RotateCcw className="size-3.5" />
+              </button>
+              <button
+                type="button"
+                title="Delete snapshot"
+                onClick={() => deleteMutation.mutate(s.id)}
+                className="text-muted-foreground hover:text-destructive"
+              >
+                <Trash2 className="size-3.5" />
+              </button>
+            </div>
+          ))}
+        </div>
+      ) : (
+        <p className="text-sm text-muted-foreground">
+          Take a snapshot before an agent run to be able to roll it back, even
+          if nothing gets committed.
+        </p>
+      )}
+    </section>
+  );
+}
@@ -1,10 +1,37 @@
-import { useState } from "react";
-import { useOutletContext } from "react-router";
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { useNavigate, useOutletContext } from "react-router";
 import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
-import { Plus, Trash2, Loader2, ChevronLeft, ChevronRight } from "lucide-react";
+import {
+  Plus,
+  Trash2,
+  Loader2,
+  ChevronLeft,
+  ChevronRight,
+  Tv,
+  Copy,
+  Square,
+  FileText,
+  Github,
+  Link2,
+} from "lucide-react";
+import { toast } from "sonner";
 import { api } from "@/lib/api";
+import { Button } from "@/components/ui/button";
+import { ImportIssuesDialog } from "@/components/ImportIssuesDialog";
+import {
+  LinkPlanningItemIssueDialog,
+  PLANNING_ITEM_LINKS_QUERY_KEY,
+} from "@/components/LinkPlanningItemIssueDialog";
 import { cn } from "@/lib/utils";
-import type { PlanningItem, PlanningStatus, Project } from "@/types";
+import type {
+  PlanningItem,
+  PlanningItemGithubLink,
+  PlanningStatus,
+  Project,
+} from "@/types";
+
+const MIN_ISSUE_REFRESH_INTERVAL_MS = 60_000;
 
 interface OutletContext {
   project: Project;
@@ -24,12 +51,83 @@ const COLUMNS: {
 export default function ProjectKanban() {
   const { project } = useOutletContext<OutletContext>();
   const queryClient = useQueryClient();
+  const [showImport, setShowImport] = useState(false);
+  const [linkingItem, setLinkingItem] = useState<PlanningItem | null>(null);
 
   const { data: items = [], isLoading } = useQuery({
     queryKey: ["planning-items", project.id],
     queryFn: () => api.getPlanningItems(project.id),
   });
 
+  const { data: links } = useQuery({
+    queryKey: PLANNING_ITEM_LINKS_QUERY_KEY,
+    queryFn: api.getPlanningItemGithubLinks,
+  });
+  const linkMap: Record<string, PlanningItemGithubLink> = {};
+  for (const link of links ?? []) {
+    linkMap[link.item_id] = link;
+  }
+
+  // Refresh cached issue states on window focus, at most once a minute.
+  const lastIssueRefreshRef = useRef(0);
+  const refreshIssueStatesMutation = useMutation({
+    mutationFn: api.fetchPlanningItemIssueStates,
+    onSuccess: ({ links, errors }) => {
+      queryClient.setQueryData(PLANNING_ITEM_LINKS_QUERY_KEY, links);
+      lastIssueRefreshRef.current = Date.now();
+      if (errors.length > 0) {
+        toast.warning(
+          `Could not refresh issues in ${errors.map((e) => e.repo).join(", ")}`,
+          {
+            id: "planning-issue-state-errors",
+            description: errors.map((e) => e.message).join("\n"),
+          },
+        );
+      }
+    },
+  });
+  useEffect(() => {
+    const handleFocus = () => {
+      const elapsed = Date.now() - lastIssueRefreshRef.current;
+      if (elapsed > MIN_ISSUE_REFRESH_INTERVAL_MS) {
+        refreshIssueStatesMutation.mutate();
+      }
+    };
+    window.addEventListener("focus", handleFocus);
+    return () => window.removeEventListener("focus", handleFocus);
+  }, []);
+
+  // The background status sync may move items to done.
+  useEffect(() => {
+    const unlisten = listen("status-sync-applied", () => {
+      queryClient.invalidateQueries({ queryKey: ["planning-items"] });
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [queryClient]);
+
+  const { data: presentationServers = [] } = useQuery({
+    queryKey: ["presentation-servers"],
+    queryFn: api.getPresentationServers,
+  });
+  const presentation = presentationServers.find(
+    (s) => s.project_id === project.id,
+  );
+
+  const startPresentationMutation = useMutation({
+    mutationFn: () => api.startPresentationServer(project.id),
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["presentation-servers"] }),
+    onError: (e) => toast.error(`Could not start presentation: ${e}`),
+  });
+
+  const stopPresentationMutation = useMutation({
+    mutationFn: () => api.stopPresentationServer(project.id),
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["presentation-servers"] }),
+  });
+
   const moveMutation = useMutation({
     mutationFn: ({
       id,
@@ -77,6 +175,23 @@ export default function ProjectKanban() {
       }),
   });
 
+  const navigate = useNavigate();
+  const openPlan = (filename: string) =>
+    navigate(`/claude/plans?plan=${encodeURIComponent(filename)}`);
+
+  // Existing plans are opened rather than recreated.
+  const planMutation = useMutation({
+    mutationFn: (id: string) => api.createPlanFromItem(id),
+    onSuccess: (item) => {
+      queryClient.invalidateQueries({
+        queryKey: ["planning-items", project.id],
+      });
+      queryClient.invalidateQueries({ queryKey: ["claude-plans"] });
+      if (item.plan_filename) openPlan(item.plan_filename);
+    },
+    onError: (e) => toast.error(`Could not create plan: ${String(e)}`),
+  });
+
   const updateMutation = useMutation({
     mutationFn: (item: { id: string; subject: string; description?: string }) =>
       api.updatePlanningItem(item),
@@ -131,8 +246,63 @@ export default function ProjectKanban() {
 
   return (
     <div className="flex flex-col h-full">
-      <div className="px-6 py-3 border-b border-border shrink-0">
+      <div className="px-6 py-3 border-b border-border shrink-0 flex items-center gap-3">
         <h2 className="text-base font-semibold">Kanban</h2>
+        <div className="ml-auto flex items-center gap-2">
+          <Button
+            variant="ghost"
+            size="sm"
+            title="Add the repository's open issues to the backlog"
+            onClick={() => setShowImport(true)}
+          >
+            <Github className="size-3.5" />
+            Import issues
+          </Button>
+          {presentation ? (
+            <>
+              <span
+                className="text-xs font-mono text-muted-foreground truncate max-w-72"
+                title={presentation.url}
+              >
+                {presentation.url}
+              </span>
+              <Button
+                variant="ghost"
+                size="sm"
+                title="Copy link"
+                onClick={() => {
+                  navigator.clipboard.writeText(presentation.url);
+                  toast.success("Copied to clipboard");
+                }}
+              >
+                <Copy className="size-3.5" />
+              </Button>
+              <Button
+                variant="outline"
+                size="sm"
+                onClick={() => stopPresentationMutation.mutate()}
+              >
+                <Square className="size-3.5" />
+                Stop
+              </Button>
+            </>
+          ) : (
+            <Button
+              variant="outline"
+              size="sm"
+              title="Serve a read-only, auto-refreshing view of this board on the local network"
+              onClick={() => startPresentationMutation.mutate()}
+              disabled={startPresentationMutation.isPending}
+            >
+              {startPresentationMutation.isPending ? (
+                <Loader2 className="size-3.5 animate-spin" />
+              ) : (
+                <Tv className="size-3.5" />
+              )}
+              Present
+            </Button>
+          )}
+        </div>
       </div>
       <div className="flex-1 overflow-auto p-4">
         <div className="flex gap-4 min-w-max min-h-full">
@@ -147,6 +317,7 @@ export default function ProjectKanban() {
                 label={col.label}
                 dotClass={col.dotClass}
                 items={colItems}
+                links={linkMap}
                 isFirstColumn={colIndex === 0}
                 isLastColumn={colIndex === COLUMNS.length - 1}
                 onDelete={(id) => deleteMutation.mutate(id)}
@@ -161,11 +332,27 @@ export default function ProjectKanban() {
                   })
                 }
                 onMoveItem={handleMoveItem}
+                onPlan={(id) => planMutation.mutate(id)}
+                onLinkIssue={setLinkingItem}
               />
             );
           })}
         </div>
       </div>
+      {showImport && (
+        <ImportIssuesDialog
+          project={project}
+          onClose={() => setShowImport(false)}
+        />
+      )}
+      {linkingItem && (
+        <LinkPlanningItemIssueDialog
+          item={linkingItem}
+          project={project}
+          existingLink={linkMap[linkingItem.id]}
+          onClose={() => setLinkingItem(null)}
+        />
+      )}
     </div>
   );
 }
@@ -177,24 +364,32 @@ interface KanbanColumnProps {
   label: string;
   dotClass: string;
   items: PlanningItem[];
+  /** Item id → its GitHub issue link */
+  links: Record<string, PlanningItemGithubLink>;
   isFirstColumn: boolean;
   isLastColumn: boolean;
   onDelete: (id: string) => void;
   onUpdate: (id: string, subject: string, description?: string) => void;
   onAddItem: (subject: string) => void;
   onMoveItem: (item: PlanningItem, direction: "prev" | "next") => void;
+  /** Create the item's plan file, or open it if it already has one. */
+  onPlan: (id: string) => void;
+  onLinkIssue: (item: PlanningItem) => void;
 }
 
 function KanbanColumn({
   label,
   dotClass,
   items,
+  links,
   isFirstColumn,
   isLastColumn,
   onDelete,
   onUpdate,
   onAddItem,
   onMoveItem,
+  onPlan,
+  onLinkIssue,
 }: KanbanColumnProps) {
   const [isAdding, setIsAdding] = useState(false);
   const [newSubject, setNewSubject] = useState("");
@@ -233,11 +428,14 @@ function KanbanColumn({
           <KanbanCard
             key={item.id}
             item={item}
+            link={links[item.id]}
             isFirstColumn={isFirstColumn}
             isLastColumn={isLastColumn}
             onDelete={onDelete}
             onUpdate={onUpdate}
             onMoveItem={onMoveItem}
+            onPlan={onPlan}
+            onLinkIssue={onLinkIssue}
           />
         ))}
 
@@ -281,20 +479,27 @@ function KanbanColumn({
 
 interface KanbanCardProps {
   item: PlanningItem;
+  link?: PlanningItemGithubLink;
   isFirstColumn: boolean;
   isLastColumn: boolean;
   onDelete: (id: string) => void;
   onUpdate: (id: string, subject: string, description?: string) => void;
   onMoveItem: (item: PlanningItem, direction: "prev" | "next") => void;
+  /** Create the item's plan file, or open it if it already has one. */
+  onPlan: (id: string) => void;
+  onLinkIssue: (item: PlanningItem) => void;
 }
 
 function KanbanCard({
   item,
+  link,
   isFirstColumn,
   isLastColumn,
   onDelete,
   onUpdate,
   onMoveItem,
+  onPlan,
+  onLinkIssue,
 }: KanbanCardProps) {
   const [editingSubject, setEditingSubject] = useState(false);
   const [subjectVal, setSubjectVal] = useState(item.subject);
@@ -397,6 +602,33 @@ function KanbanCard({
         </button>
       </div>
 
+      {link && (
+        <div className="flex items-center gap-1.5 mt-2">
+          {link.github_issue_state && (
+            <span
+              className={cn(
+                "inline-block size-1.5 rounded-full shrink-0",
+                link.github_issue_state === "open"
+                  ? "bg-green-500"
+                  : "bg-purple-500",
+              )}
+              title={link.github_issue_state === "open" ? "Open" : "Closed"}
+            />
+          )}
+          <a
+            href="#"
+            onClick={(e) => {
+              e.preventDefault();
+              window.open(link.github_issue_url, "_blank");
+            }}
+            className="text-xs text-primary hover:underline truncate"
+            title={link.github_issue_url}
+          >
+            {link.github_repo}#{link.github_issue_number}
+          </a>
+        </div>
+      )}
+
       {/* Move buttons */}
       <div className="flex items-center gap-1 mt-2 opacity-0 group-hover:opacity-100 transition-opacity">
         <button
@@ -419,6 +651,30 @@ function KanbanCard({
           Forward
           <ChevronRight className="size-3" />
         </button>
+        <button
+          type="button"
+          onClick={() => onLinkIssue(item)}
+          className="ml-auto flex items-center gap-0.5 px-1.5 py-0.5 rounded text-xs text-muted-foreground hover:text-foreground hover:bg-accent transition-colors"
+          title={
+            link ? "Change or remove the linked issue" : "Link a GitHub issue"
+          }
+        >
+          <Link2 className="size-3" />
+          Issue
+        </button>
+        <button
+          type="button"
+          onClick={() => onPlan(item.id)}
+          className="flex items-center gap-0.5 px-1.5 py-0.5 rounded text-xs text-muted-foreground hover:text-foreground hover:bg-accent transition-colors"
+          title={
+            item.plan_filename
+              ? `Open ${item.plan_filename}`
+              : "Create a Claude plan from this card"
+          }
+        >
+          <FileText className="size-3" />
+          {item.plan_filename ? "Plan" : "New plan"}
+        </button>
       </div>
     </div>
   );
@@ -1,5 +1,5 @@
-import { useOutletContext } from "react-router";
-import { useQuery, useMutation } from "@tanstack/react-query";
+import { useNavigate, useOutletContext } from "react-router";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
 import {
   GitBranch,
   Terminal,
@@ -7,13 +7,23 @@ import {
   CheckSquare,
   Loader2,
   ExternalLink,
+  HardDrive,
+  RefreshCw,
+  X,
+  Code2,
+  Archive,
 } from "lucide-react";
 import { toast } from "sonner";
 import { api } from "@/lib/api";
-import { formatRelativeTime, getProjectColor } from "@/lib/utils";
+import {
+  EDITOR_NAMES,
+  formatBytes,
+  formatRelativeTime,
+  getProjectColor,
+} from "@/lib/utils";
 import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
-import type { Project } from "@/types";
+import type { Project, ProjectDiskUsage } from "@/types";
 
 interface OutletCtx {
   project: Project;
@@ -29,11 +39,31 @@ export default function ProjectOverview() {
     retry: false,
   });
 
+  const { data: badges = [] } = useQuery({
+    queryKey: ["project-badges", project.path],
+    queryFn: () => api.getProjectBadges(project.path),
+  });
+
   const { data: sessions } = useQuery({
     queryKey: ["claude-sessions"],
     queryFn: api.readClaudeSessions,
   });
 
+  const queryClient = useQueryClient();
+  const { data: diskUsage, isFetching: diskUsageLoading } = useQuery({
+    queryKey: ["disk-usage", project.path],
+    queryFn: () => api.getProjectDiskUsage(project.path),
+    retry: false,
+    staleTime: Infinity,
+  });
+
+  const refreshDiskUsageMutation = useMutation({
+    mutationFn: () => api.getProjectDiskUsage(project.path, true),
+    onSuccess: (usage) =>
+      queryClient.setQueryData(["disk-usage", project.path], usage),
+    onError: (e) => toast.error(`Disk usage: ${String(e)}`),
+  });
+
   const { data: termInfo } = useQuery({
     queryKey: ["terminal"],
     queryFn: api.detectTerminal,
@@ -45,8 +75,31 @@ export default function ProjectOverview() {
     onError: () => toast.error("Failed to launch terminal"),
   });
 
+  const { data: editorInfo } = useQuery({
+    queryKey: ["editors"],
+    queryFn: api.detectEditors,
+  });
+
+  const editorMutation = useMutation({
+    mutationFn: () =>
+      api.openInEditor(project.path, editorInfo?.detected ?? undefined),
+    onError: (e) => toast.error(`Failed to open editor: ${String(e)}`),
+  });
+
+  const navigate = useNavigate();
+  const archiveMutation = useMutation({
+    mutationFn: () => api.archiveProject(project.id),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["projects"] });
+      queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      toast.success(`Archived ${project.name}`);
+      navigate("/projects");
+    },
+    onError: (e) => toast.error(`Failed to archive: ${String(e)}`),
+  });
+
   const projectSessions =
-    sessions?.filter((s) => s.cwd && s.cwd.startsWith(project.path)) ?? [];
+    sessions?.filter((s) => s.project_id === project.id) ?? [];
 
   const lastSession = projectSessions[0];
 
@@ -65,7 +118,33 @@ export default function ProjectOverview() {
           <p className="text-sm text-muted-foreground font-mono">
             {project.path}
           </p>
+          {project.real_path && (
+            <p className="text-xs text-muted-foreground font-mono">
+              → {project.real_path}
+            </p>
+          )}
         </div>
+        {editorInfo?.detected && (
+          <Button
+            variant="outline"
+            onClick={() => editorMutation.mutate()}
+            disabled={editorMutation.isPending}
+            className="shrink-0"
+          >
+            <Code2 className="size-4 mr-2" />
+            {EDITOR_NAMES[editorInfo.detected]}
+          </Button>
+        )}
+        <Button
+          variant="ghost"
+          size="icon"
+          title="Archive project"
+          onClick={() => archiveMutation.mutate()}
+          disabled={archiveMutation.isPending}
+          className="shrink-0 text-muted-foreground"
+        >
+          <Archive className="size-4" />
+        </Button>
         <Button
           onClick={() => launchMutation.mutate()}
           disabled={launchMutation.isPending}
@@ -80,6 +159,27 @@ export default function ProjectOverview() {
         </Button>
       </div>
 
+      {/* README / CI badges as quick links */}
+      {badges.length > 0 && (
+        <div className="flex flex-wrap gap-1.5 mb-6">
+          {badges.map((b) => (
+            <Badge
+              key={b.image_url}
+              variant="outline"
+              className={
+                b.link_url ? "cursor-pointer hover:bg-accent gap-1" : "gap-1"
+              }
+              title={b.image_url}
+              onClick={() => b.link_url && window.open(b.link_url, "_blank")}
+            >
+              <span className="text-muted-foreground">{b.kind}</span>
+              {b.label}
+              {b.link_url && <ExternalLink className="size-3" />}
+            </Badge>
+          ))}
+        </div>
+      )}
+
       {/* Stats row */}
       <div className="grid grid-cols-3 gap-3 mb-6">
         <div className="p-3 rounded-lg border border-border">
@@ -169,6 +269,100 @@ export default function ProjectOverview() {
           )}
         </div>
       )}
+
+      {/* Disk usage */}
+      <div className="border border-border rounded-lg p-4 mb-4">
+        <div className="flex items-center justify-between mb-3">
+          <h3 className="text-sm font-semibold flex items-center gap-2">
+            <HardDrive className="size-4" />
+            Disk Usage
+            {diskUsage && (
+              <span className="font-mono font-normal text-muted-foreground">
+                {formatBytes(diskUsage.total_bytes)}
+              </span>
+            )}
+          </h3>
+          {diskUsageLoading || refreshDiskUsageMutation.isPending ? (
+            <Button
+              variant="ghost"
+              size="sm"
+              className="h-7"
+              onClick={() => api.cancelProjectDiskUsage(project.path)}
+            >
+              <Loader2 className="size-3.5 mr-1.5 animate-spin" />
+              <X className="size-3.5 mr-1" />
+              Cancel
+            </Button>
+          ) : (
+            <Button
+              variant="ghost"
+              size="sm"
+              className="h-7"
+              onClick={() => refreshDiskUsageMutation.mutate()}
+            >
+              <RefreshCw className="size-3.5 mr-1.5" />
+              Rescan
+            </Button>
+          )}
+        </div>
+        {diskUsage ? (
+          <DiskUsageBreakdown usage={diskUsage} />
+        ) : (
+          !diskUsageLoading && (
+            <p className="text-sm text-muted-foreground">Not measured yet</p>
+          )
+        )}
+      </div>
+    </div>
+  );
+}
+
+const DISK_USAGE_BUCKETS: {
+  key: keyof ProjectDiskUsage;
+  label: string;
+  className: string;
+}[] = [
+  {
+    key: "node_modules_bytes",
+    label: "node_modules",
+    className: "bg-green-500",
+  },
+  { key: "target_bytes", label: "target", className: "bg-orange-500" },
+  { key: "build_bytes", label: "build output", className: "bg-blue-500" },
+  { key: "git_bytes", label: ".git", className: "bg-purple-500" },
+  { key: "other_bytes", label: "other", className: "bg-muted-foreground" },
+];
+
+function DiskUsageBreakdown({ usage }: { usage: ProjectDiskUsage }) {
+  const buckets = DISK_USAGE_BUCKETS.map((b) => ({
+    ...b,
+    bytes: usage[b.key] as number,
+  })).filter((b) => b.bytes > 0);
+  const total = Math.max(usage.total_bytes, 1);
+
+  return (
+    <div className="space-y-2">
+      <div className="flex h-2 rounded-full overflow-hidden bg-muted">
+        {buckets.map((b) => (
+          <div
+            key={b.key}
+            className={b.className}
+            style={{ width: `${(b.bytes / total) * 100}%` }}
+          />
+        ))}
+      </div>
+      <div className="grid grid-cols-2 gap-x-4 gap-y-1 text-xs">
+        {buckets.map((b) => (
+          <div key={b.key} className="flex items-center gap-2">
+            <span className={`size-2 rounded-full ${b.className}`} />
+            <span className="flex-1 text-muted-foreground">{b.label}</span>
+            <span className="font-mono">{formatBytes(b.bytes)}</span>
+          </div>
+        ))}
+      </div>
+      <p className="text-xs text-muted-foreground">
+        Measured {formatRelativeTime(usage.computed_at)}
+      </p>
     </div>
   );
 }
@@ -1,4 +1,5 @@
 import { useState } from "react";
+import { useOutletContext } from "react-router";
 import { useQuery } from "@tanstack/react-query";
 import ReactMarkdown from "react-markdown";
 import { FileText, Clock, Loader2, ChevronLeft } from "lucide-react";
@@ -7,10 +8,14 @@ import { formatRelativeTime } from "@/lib/utils";
 import { Button } from "@/components/ui/button";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
+import { SourceBadge } from "@/components/SourceBadge";
+import type { ClaudePlan, Project } from "@/types";
 
-// Shows all Claude plans (not project-specific yet, since plans are global)
+// Shows all Claude plans, the project's own `.claude/plans` first; the
+// global ones cannot be attributed to a project.
 export default function ProjectPlans() {
-  const [selectedPlan, setSelectedPlan] = useState<string | null>(null);
+  const { project } = useOutletContext<{ project: Project }>();
+  const [selectedPlan, setSelectedPlan] = useState<ClaudePlan | null>(null);
 
   const {
     data: plans,
@@ -24,9 +29,19 @@ export default function ProjectPlans() {
 
   useClaudeWatcher("claude-plans-changed", refetch);
 
+  const ownSource = `project:${project.id}`;
+  const sortedPlans = [...(plans ?? [])].sort(
+    (a, b) => Number(b.source === ownSource) - Number(a.source === ownSource),
+  );
+
   const { data: planContent, isLoading: contentLoading } = useQuery({
-    queryKey: ["claude-plan-content", selectedPlan],
-    queryFn: () => api.readClaudePlan(selectedPlan!),
+    queryKey: [
+      "claude-plan-content",
+      selectedPlan?.source,
+      selectedPlan?.filename,
+    ],
+    queryFn: () =>
+      api.readClaudePlan(selectedPlan!.filename, selectedPlan!.source),
     enabled: !!selectedPlan,
   });
 
@@ -46,7 +61,7 @@ export default function ProjectPlans() {
             <ChevronLeft className="size-4 mr-1" />
             Plans
           </Button>
-          <span className="text-sm font-medium">{selectedPlan}</span>
+          <span className="text-sm font-medium">{selectedPlan.filename}</span>
         </div>
         <ScrollArea className="flex-1 p-6">
           {contentLoading ? (
@@ -64,27 +79,30 @@ export default function ProjectPlans() {
   return (
     <div className="p-6">
       <h2 className="text-lg font-semibold mb-4">Plans</h2>
-      {!plans || plans.length === 0 ? (
+      {sortedPlans.length === 0 ? (
         <div className="flex flex-col items-center py-8 text-center border border-dashed border-border rounded-lg">
           <FileText className="size-8 text-muted-foreground mb-2" />
           <p className="text-sm text-muted-foreground">
-            No plans in ~/.claude/plans/
+            No plans in ~/.claude/plans/ or this project's .claude/plans/
           </p>
         </div>
       ) : (
         <div className="space-y-2">
-          {plans.map((plan) => (
+          {sortedPlans.map((plan) => (
             <button
-              key={plan.id}
+              key={`${plan.source}/${plan.id}`}
               type="button"
-              onClick={() => setSelectedPlan(plan.filename)}
+              onClick={() => setSelectedPlan(plan)}
               className="w-full text-left p-4 rounded-lg border border-border bg-card hover:bg-accent/20 transition-colors"
             >
               <div className="flex items-start justify-between gap-3">
                 <div className="flex items-start gap-3">
                   <FileText className="size-4 text-muted-foreground mt-0.5" />
                   <div>
-                    <p className="text-sm font-medium">{plan.title}</p>
+                    <p className="text-sm font-medium flex items-center gap-2">
+                      {plan.title}
+                      <SourceBadge source={plan.source} />
+                    </p>
                     {plan.preview && (
                       <p className="text-xs text-muted-foreground mt-1 line-clamp-2">
                         {plan.preview}
@@ -30,18 +30,18 @@ export default function ProjectSessions() {
       "session-messages",
       selectedSession?.project_key,
       selectedSession?.id,
+      selectedSession?.source,
     ],
     queryFn: () =>
       api.readSessionMessages(
         selectedSession!.project_key,
         selectedSession!.id,
+        selectedSession!.source,
       ),
     enabled: !!selectedSession,
   });
 
-  const sessions = allSessions?.filter(
-    (s) => s.cwd && s.cwd.startsWith(project.path),
-  );
+  const sessions = allSessions?.filter((s) => s.project_id === project.id);
 
   if (isLoading) {
     return (
@@ -87,8 +87,12 @@ export default function ProjectSessions() {
               >
                 <Terminal className="size-4 text-muted-foreground shrink-0 mt-0.5" />
                 <div className="flex-1 min-w-0">
-                  <p className="text-xs font-medium truncate">
-                    {session.cwd?.split("/").slice(-2).join("/") ||
+                  <p
+                    className="text-xs font-medium truncate"
+                    title={session.title ?? undefined}
+                  >
+                    {session.title ||
+                      session.cwd?.split("/").slice(-2).join("/") ||
                       session.project_key.slice(0, 30)}
                   </p>
                   <div className="flex items-center gap-2 mt-1 text-xs text-muted-foreground">
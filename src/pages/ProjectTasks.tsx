@@ -5,6 +5,7 @@ import { useClaudeWatcher } from "@/hooks/useClaudeWatcher";
 import { api } from "@/lib/api";
 import { formatRelativeTime } from "@/lib/utils";
 import { Badge } from "@/components/ui/badge";
+import { SourceBadge } from "@/components/SourceBadge";
 import type { Project } from "@/types";
 
 const statusConfig = {
@@ -29,10 +30,15 @@ export default function ProjectTasks() {
 
   useClaudeWatcher("claude-tasks-changed", refetch);
 
-  const allTasks =
+  // Tasks from the project's own `.claude/tasks` come first.
+  const ownSource = `project:${project.id}`;
+  const allTasks = (
     taskFiles?.flatMap((tf) =>
       tf.tasks.map((t) => ({ ...t, team_id: tf.team_id })),
-    ) ?? [];
+    ) ?? []
+  ).sort(
+    (a, b) => Number(b.source === ownSource) - Number(a.source === ownSource),
+  );
 
   return (
     <div className="p-6">
@@ -61,13 +67,16 @@ export default function ProjectTasks() {
             };
             return (
               <div
-                key={`${task.team_id}-${task.id}`}
+                key={`${task.source}-${task.team_id}-${task.id}`}
                 className="flex items-start gap-3 p-3 rounded-lg border border-border bg-card"
               >
                 <CheckSquare className="size-4 text-muted-foreground mt-0.5 shrink-0" />
                 <div className="flex-1 min-w-0">
                   <div className="flex items-start justify-between gap-2">
-                    <p className="text-sm font-medium">{task.subject}</p>
+                    <p className="text-sm font-medium flex items-center gap-2">
+                      {task.subject}
+                      <SourceBadge source={task.source} />
+                    </p>
                     <Badge variant={cfg.variant} className="text-xs shrink-0">
                       {cfg.label}
                     </Badge>
@@ -1,14 +1,21 @@
-import { useEffect, useRef, useCallback, useState } from "react";
+import { useEffect, useRef, useCallback, useMemo, useState } from "react";
 import { useOutletContext } from "react-router";
 import { Terminal as XTerm } from "@xterm/xterm";
 import { FitAddon } from "@xterm/addon-fit";
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
-import { ExternalLink, Loader2 } from "lucide-react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { ExternalLink, KeyRound, Loader2 } from "lucide-react";
 import { toast } from "sonner";
 import { api } from "@/lib/api";
 import { Button } from "@/components/ui/button";
 import { useAppStore } from "@/lib/store";
-import type { Project, PtyOutputPayload, PtyExitPayload } from "@/types";
+import { SessionFollowPane } from "@/components/SessionFollowPane";
+import type {
+  Project,
+  PtyOutputPayload,
+  PtyExitPayload,
+  PtySessionUsage,
+} from "@/types";
 import "@xterm/xterm/css/xterm.css";
 
 function resolveCssColor(varName: string): string {
@@ -49,7 +56,7 @@ function buildXtermTheme(isDark: boolean) {
 
 export default function ProjectTerminal() {
   const { project } = useOutletContext<{ project: Project }>();
-  const { theme } = useAppStore();
+  const { theme, follows } = useAppStore();
   const isDark =
     theme === "dark" ||
     (theme === "system" &&
@@ -67,6 +74,41 @@ export default function ProjectTerminal() {
     "loading" | "ready" | "exited" | "error"
   >("loading");
   const [errorMsg, setErrorMsg] = useState("");
+  // Env file and variable names injected into the running session.
+  const [envManifest, setEnvManifest] = useState<Pick<
+    PtySessionUsage,
+    "env_file" | "env_keys"
+  > | null>(null);
+
+  const queryClient = useQueryClient();
+  const { data: envFiles } = useQuery({
+    queryKey: ["env-files", project.path],
+    queryFn: () => api.listEnvFiles(project.path),
+  });
+  const { data: selectedEnvFile } = useQuery({
+    queryKey: ["pty-env-file", project.path],
+    queryFn: () => api.getPtyEnvFile(project.path),
+  });
+  const envFileMutation = useMutation({
+    mutationFn: (envFile: string | null) =>
+      api.setPtyEnvFile(project.path, envFile),
+    onSuccess: () => {
+      queryClient.invalidateQueries({
+        queryKey: ["pty-env-file", project.path],
+      });
+      toast.success("Environment saved", {
+        description: "Restart the terminal to apply it",
+      });
+    },
+    onError: (e) => toast.error("Failed", { description: String(e) }),
+  });
+
+  // Most recently started session being followed for this project.
+  const projectFollows = Object.values(follows).filter(
+    (f) => f.info.project_id === project.id,
+  );
+  const follow = projectFollows[projectFollows.length - 1];
+  const followTheme = useMemo(() => buildXtermTheme(isDark), [isDark]);
 
   const initTerminal = useCallback(
     async (container: HTMLDivElement | null) => {
@@ -128,6 +170,13 @@ export default function ProjectTerminal() {
         }
         ptyIdRef.current = ptyId;
         setStatus("ready");
+        api
+          .getPtyResourceUsage()
+          .then((usage) => {
+            const session = usage.sessions.find((s) => s.pty_id === ptyId);
+            setEnvManifest(session ?? null);
+          })
+          .catch(() => {});
       } catch (err) {
         setErrorMsg(String(err));
         setStatus("error");
@@ -181,6 +230,7 @@ export default function ProjectTerminal() {
     termRef.current = null;
     setStatus("loading");
     setErrorMsg("");
+    setEnvManifest(null);
     if (containerRef.current) initTerminal(containerRef.current);
   };
 
@@ -200,6 +250,32 @@ export default function ProjectTerminal() {
           )}
         </div>
         <div className="flex items-center gap-1">
+          {envManifest?.env_file && (
+            <span
+              className="flex items-center gap-1 text-xs text-muted-foreground mr-1"
+              title={envManifest.env_keys.join("\n")}
+            >
+              <KeyRound className="size-3" />
+              {envManifest.env_keys.length} vars from{" "}
+              {envManifest.env_file.split("/").pop()}
+            </span>
+          )}
+          {envFiles && envFiles.length > 0 && (
+            <select
+              className="h-7 text-xs bg-muted border border-border rounded-md px-2 outline-none"
+              title="Env file injected into new terminal sessions"
+              value={selectedEnvFile ?? ""}
+              disabled={envFileMutation.isPending}
+              onChange={(e) => envFileMutation.mutate(e.target.value || null)}
+            >
+              <option value="">No env file</option>
+              {envFiles.map((f) => (
+                <option key={f.path} value={f.path}>
+                  {f.filename} ({f.var_count})
+                </option>
+              ))}
+            </select>
+          )}
           {(status === "exited" || status === "error") && (
             <Button
               variant="ghost"
@@ -257,6 +333,16 @@ export default function ProjectTerminal() {
           style={{ visibility: status === "error" ? "hidden" : "visible" }}
         />
       </div>
+
+      {follow && (
+        <div className="h-2/5 shrink-0">
+          <SessionFollowPane
+            key={follow.info.session_id}
+            follow={follow}
+            theme={followTheme}
+          />
+        </div>
+      )}
     </div>
   );
 }
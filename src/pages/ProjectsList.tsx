@@ -9,32 +9,54 @@ import {
   Archive,
   RotateCcw,
   Trash2,
+  GitBranch,
+  FolderPlus,
+  CircleDot,
 } from "lucide-react";
 import { toast } from "sonner";
 import { api } from "@/lib/api";
-import { getProjectColor } from "@/lib/utils";
+import {
+  formatRelativeTime,
+  getProjectBadge,
+  getProjectColor,
+} from "@/lib/utils";
 import { Button } from "@/components/ui/button";
-import type { Project } from "@/types";
+import { ClaudeActiveDot } from "@/components/ClaudeActiveDot";
+import { NewProjectDialog } from "@/components/NewProjectDialog";
+import { useActiveClaudeProjects } from "@/hooks/useClaudeProcesses";
+import type { ArchiveReason, Project, ProjectWithStats } from "@/types";
 
 export default function ProjectsList() {
   const queryClient = useQueryClient();
   const [showArchived, setShowArchived] = useState(false);
+  const [showNewProject, setShowNewProject] = useState(false);
+  const activeClaudeProjects = useActiveClaudeProjects();
 
   const { data: projects, isLoading } = useQuery({
-    queryKey: ["projects"],
-    queryFn: api.getProjects,
+    queryKey: ["projects", "with-stats"],
+    queryFn: api.getProjectsWithStats,
   });
 
   const { data: archivedProjects } = useQuery({
     queryKey: ["projects-archived"],
-    queryFn: api.getArchivedProjects,
+    queryFn: () => api.getArchivedProjects(),
+  });
+  const [archiveReason, setArchiveReason] = useState<ArchiveReason | "">("");
+  const { data: filteredArchived } = useQuery({
+    queryKey: ["projects-archived", archiveReason],
+    queryFn: () => api.getArchivedProjects(archiveReason || undefined),
+    enabled: showArchived && archiveReason !== "",
   });
+  const visibleArchived = archiveReason
+    ? (filteredArchived ?? [])
+    : (archivedProjects ?? []);
 
   const syncMutation = useMutation({
     mutationFn: () => api.syncProjects(),
     onSuccess: (result) => {
       queryClient.invalidateQueries({ queryKey: ["projects"] });
       queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      queryClient.invalidateQueries({ queryKey: ["sync-history"] });
       const parts: string[] = [];
       if (result.added.length > 0) parts.push(`${result.added.length} added`);
       if (result.updated.length > 0)
@@ -45,7 +67,9 @@ export default function ProjectsList() {
         parts.push(`${result.archived_count} archived`);
       if (parts.length === 0)
         parts.push(`${result.unchanged_count} already up to date`);
-      toast.success(`Sync complete — ${parts.join(", ")}`);
+      toast.success(`Sync complete — ${parts.join(", ")}`, {
+        description: `Scanned in ${result.scan_duration_ms} ms (${result.total_duration_ms} ms total)`,
+      });
     },
     onError: () => {
       toast.error("Sync failed");
@@ -85,6 +109,9 @@ export default function ProjectsList() {
 
   return (
     <div className="p-6">
+      {showNewProject && (
+        <NewProjectDialog onClose={() => setShowNewProject(false)} />
+      )}
       <div className="flex items-center justify-between mb-6">
         <div>
           <h1 className="text-xl font-bold">Projects</h1>
@@ -103,6 +130,14 @@ export default function ProjectsList() {
               {showArchived ? "Hide" : "Show"} archived ({archivedCount})
             </Button>
           )}
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => setShowNewProject(true)}
+          >
+            <FolderPlus className="size-4 mr-2" />
+            New Project
+          </Button>
           <Button
             variant="outline"
             size="sm"
@@ -141,18 +176,39 @@ export default function ProjectsList() {
       ) : (
         <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-4">
           {projects.map((project) => (
-            <ProjectCard key={project.id} project={project} />
+            <ProjectCard
+              key={project.id}
+              project={project}
+              worktreeOf={projects.find((p) => p.id === project.worktree_of)}
+              claudeActive={activeClaudeProjects.has(project.id)}
+            />
           ))}
         </div>
       )}
 
       {showArchived && archivedCount > 0 && (
         <div className="mt-8">
-          <p className="text-sm font-medium text-muted-foreground mb-3">
-            Archived — path no longer found on disk
-          </p>
+          <div className="flex items-center justify-between mb-3">
+            <p className="text-sm font-medium text-muted-foreground">
+              Archived
+            </p>
+            <select
+              className="text-xs bg-muted border border-border rounded-md px-2 py-1 outline-none"
+              value={archiveReason}
+              onChange={(e) =>
+                setArchiveReason(e.target.value as ArchiveReason | "")
+              }
+            >
+              <option value="">All reasons</option>
+              {Object.entries(ARCHIVED_REASON_LABELS).map(([key, label]) => (
+                <option key={key} value={key}>
+                  {label}
+                </option>
+              ))}
+            </select>
+          </div>
           <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-4">
-            {archivedProjects!.map((project) => (
+            {visibleArchived.map((project) => (
               <ArchivedProjectCard
                 key={project.id}
                 project={project}
@@ -170,7 +226,17 @@ export default function ProjectsList() {
   );
 }
 
-function ProjectCard({ project }: { project: Project }) {
+function ProjectCard({
+  project,
+  worktreeOf,
+  claudeActive,
+}: {
+  project: ProjectWithStats;
+  /** Main project when this one is a linked git worktree */
+  worktreeOf?: Project;
+  /** A Claude process is running in the project */
+  claudeActive: boolean;
+}) {
   const color = getProjectColor(project.color, project.name);
 
   return (
@@ -181,18 +247,61 @@ function ProjectCard({ project }: { project: Project }) {
             className="w-10 h-10 rounded-lg flex items-center justify-center text-white font-bold text-sm shrink-0"
             style={{ backgroundColor: color }}
           >
-            {project.name.slice(0, 2).toUpperCase()}
+            {getProjectBadge(project.icon, project.name)}
           </div>
           <div className="flex-1 min-w-0">
-            <h3 className="font-semibold text-sm truncate group-hover:text-primary transition-colors">
+            <h3 className="font-semibold text-sm truncate group-hover:text-primary transition-colors flex items-center gap-1.5">
               {project.name}
+              {claudeActive && <ClaudeActiveDot />}
             </h3>
             <p className="text-xs text-muted-foreground font-mono truncate mt-0.5">
               {project.path.replace("/Users/admin/", "~/")}
             </p>
+            {worktreeOf && (
+              <p className="flex items-center gap-1 text-xs text-muted-foreground mt-0.5">
+                <GitBranch className="size-3" />
+                worktree of {worktreeOf.name}
+              </p>
+            )}
           </div>
         </div>
 
+        {(project.framework || project.language) && (
+          <div className="flex flex-wrap gap-1 mb-2">
+            {[project.framework, project.language, project.package_manager]
+              .filter((s): s is string => !!s)
+              .map((s) => (
+                <span
+                  key={s}
+                  className="text-xs px-2 py-0.5 rounded-full border border-border text-muted-foreground"
+                >
+                  {s}
+                </span>
+              ))}
+          </div>
+        )}
+
+        {project.todo_count +
+          project.in_progress_count +
+          project.linked_issue_count >
+          0 && (
+          <div className="flex items-center gap-3 text-xs text-muted-foreground mb-2">
+            {project.in_progress_count > 0 && (
+              <span>{project.in_progress_count} in progress</span>
+            )}
+            {project.todo_count > 0 && <span>{project.todo_count} to do</span>}
+            {project.linked_issue_count > 0 && (
+              <span
+                className="flex items-center gap-1"
+                title={`${project.linked_issue_count} linked issue(s)`}
+              >
+                <CircleDot className="size-3" />
+                {project.open_linked_issue_count} open
+              </span>
+            )}
+          </div>
+        )}
+
         {project.tags.length > 0 && (
           <div className="flex flex-wrap gap-1">
             {project.tags.map((tag) => (
@@ -210,6 +319,13 @@ function ProjectCard({ project }: { project: Project }) {
   );
 }
 
+const ARCHIVED_REASON_LABELS: Record<ArchiveReason, string> = {
+  missing_path: "Path no longer found on disk",
+  out_of_scan_root: "Outside the current scan roots",
+  inactive: "No activity within the auto-archive window",
+  manual: "Archived by hand",
+};
+
 function ArchivedProjectCard({
   project,
   onRestore,
@@ -239,6 +355,13 @@ function ArchivedProjectCard({
           <p className="text-xs text-muted-foreground font-mono truncate mt-0.5">
             {project.path.replace("/Users/admin/", "~/")}
           </p>
+          {project.archived_reason && (
+            <p className="text-xs text-muted-foreground mt-1">
+              {ARCHIVED_REASON_LABELS[project.archived_reason]}
+              {project.archived_at &&
+                ` · ${formatRelativeTime(project.archived_at)}`}
+            </p>
+          )}
         </div>
       </div>
       <div className="flex gap-2">
@@ -1,35 +1,76 @@
-import { useState, useMemo } from "react";
-import { useParams, useNavigate } from "react-router";
-import { useQuery } from "@tanstack/react-query";
+import { useState, useMemo, useEffect } from "react";
+import { useParams, useNavigate, useSearchParams } from "react-router";
+import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
 import {
   ArrowLeft,
+  Bookmark,
   Bot,
   ChevronDown,
   ChevronRight,
   Clock,
+  Download,
+  ExternalLink,
+  FileDiff,
+  FileText,
+  ImageOff,
   Loader2,
   MessageSquare,
+  Send,
   Terminal,
   User,
   Wrench,
 } from "lucide-react";
 import ReactMarkdown from "react-markdown";
+import { toast } from "sonner";
 import { api } from "@/lib/api";
+import { SessionSummaryDialog } from "@/components/SessionSummaryDialog";
 import { formatRelativeTime } from "@/lib/utils";
-import type { SessionTurn, SessionToolCall } from "@/types";
+import type {
+  SessionAttachment,
+  SessionDiff,
+  SessionTurn,
+  SessionToolCall,
+} from "@/types";
 
 // ─── Tool call collapsible block ───────────────────────────────────────────
 
-function ToolCallBlock({ tool }: { tool: SessionToolCall }) {
+function ToolCallBlock({
+  tool,
+  cwd,
+}: {
+  tool: SessionToolCall;
+  /** Session working directory, for resolving relative file paths. */
+  cwd: string | null;
+}) {
   const [open, setOpen] = useState(false);
 
   let inputPretty: string;
+  let input: Record<string, unknown> = {};
   try {
-    inputPretty = JSON.stringify(JSON.parse(tool.input), null, 2);
+    input = JSON.parse(tool.input);
+    inputPretty = JSON.stringify(input, null, 2);
   } catch {
     inputPretty = tool.input;
   }
 
+  // Read/Write/Edit/NotebookEdit name the file they touch; Read may carry a line offset.
+  const filePath =
+    typeof input.file_path === "string"
+      ? input.file_path
+      : typeof input.notebook_path === "string"
+        ? input.notebook_path
+        : null;
+  const line = typeof input.offset === "number" ? input.offset : undefined;
+  const canOpen = !!filePath && (filePath.startsWith("/") || !!cwd);
+
+  const openInEditor = (e: React.MouseEvent) => {
+    e.preventDefault();
+    if (!filePath) return;
+    api
+      .openFileAt(cwd ?? "", filePath, line)
+      .catch((err) => toast.error(`Could not open file: ${String(err)}`));
+  };
+
   return (
     <details
       open={open}
@@ -46,6 +87,20 @@ function ToolCallBlock({ tool }: { tool: SessionToolCall }) {
         <span className="font-mono font-medium text-amber-600 dark:text-amber-400">
           {tool.name}
         </span>
+        {canOpen && (
+          <button
+            type="button"
+            onClick={openInEditor}
+            title={`Open ${filePath} in editor`}
+            className="flex items-center gap-1 min-w-0 font-mono text-muted-foreground hover:text-foreground hover:underline"
+          >
+            <span className="truncate">{filePath!.split("/").pop()}</span>
+            <ExternalLink className="size-3 shrink-0" />
+          </button>
+        )}
+        {tool.is_error && (
+          <span className="text-destructive font-medium">error</span>
+        )}
         <span className="text-muted-foreground ml-auto">{tool.id}</span>
       </summary>
       <div className="border-t border-border px-3 py-2 space-y-2">
@@ -60,9 +115,13 @@ function ToolCallBlock({ tool }: { tool: SessionToolCall }) {
         {tool.output && (
           <div>
             <p className="text-muted-foreground mb-1 font-medium uppercase tracking-wider text-[10px]">
-              Output
+              {tool.is_error ? "Error" : "Output"}
             </p>
-            <pre className="whitespace-pre-wrap break-all text-foreground/80 overflow-x-auto">
+            <pre
+              className={`whitespace-pre-wrap break-all overflow-x-auto ${
+                tool.is_error ? "text-destructive" : "text-foreground/80"
+              }`}
+            >
               {tool.output}
             </pre>
           </div>
@@ -72,14 +131,52 @@ function ToolCallBlock({ tool }: { tool: SessionToolCall }) {
   );
 }
 
+// ─── Attachments ───────────────────────────────────────────────────────────
+
+function AttachmentView({ attachment }: { attachment: SessionAttachment }) {
+  const [expanded, setExpanded] = useState(false);
+  const isImage = attachment.kind === "image";
+  const { data: src, isError } = useQuery({
+    queryKey: ["session-attachment", attachment.path],
+    queryFn: () => api.readSessionAttachment(attachment.path!),
+    enabled: isImage && attachment.path !== null,
+    staleTime: Infinity,
+  });
+
+  if (isImage && (src || attachment.url) && !isError) {
+    return (
+      <img
+        src={src ?? attachment.url!}
+        alt={attachment.tool_use_id ? "Tool result image" : "Attached image"}
+        onClick={() => setExpanded((e) => !e)}
+        className={`rounded border border-border bg-background cursor-zoom-in ${
+          expanded ? "max-w-full" : "max-h-48 max-w-64 object-contain"
+        }`}
+      />
+    );
+  }
+  const Icon = isImage ? ImageOff : FileText;
+  return (
+    <span
+      className="inline-flex items-center gap-1.5 rounded border border-border px-2 py-1 text-xs font-mono"
+      title={attachment.path ?? attachment.url ?? undefined}
+    >
+      <Icon className="size-3.5" />
+      {attachment.media_type ?? attachment.kind}
+    </span>
+  );
+}
+
 // ─── Single turn bubble ────────────────────────────────────────────────────
 
 function TurnBubble({
   turn,
   highlight,
+  cwd,
 }: {
   turn: SessionTurn;
   highlight: string;
+  cwd: string | null;
 }) {
   const isUser = turn.role === "user";
 
@@ -90,8 +187,9 @@ function TurnBubble({
 
   const hasContent = turn.content.trim().length > 0;
   const hasTools = turn.tool_calls.length > 0;
+  const hasAttachments = turn.attachments.length > 0;
 
-  if (!hasContent && !hasTools) return null;
+  if (!hasContent && !hasTools && !hasAttachments) return null;
 
   return (
     <div
@@ -137,7 +235,17 @@ function TurnBubble({
         {hasTools && (
           <div className={hasContent ? "mt-2" : ""}>
             {turn.tool_calls.map((tool) => (
-              <ToolCallBlock key={tool.id} tool={tool} />
+              <ToolCallBlock key={tool.id} tool={tool} cwd={cwd} />
+            ))}
+          </div>
+        )}
+
+        {hasAttachments && (
+          <div
+            className={`flex flex-wrap gap-2 ${hasContent || hasTools ? "mt-2" : ""}`}
+          >
+            {turn.attachments.map((attachment, i) => (
+              <AttachmentView key={i} attachment={attachment} />
             ))}
           </div>
         )}
@@ -155,6 +263,59 @@ function TurnBubble({
   );
 }
 
+// ─── Changes since the session started ─────────────────────────────────────
+
+const diffStatusClass: Record<string, string> = {
+  added: "text-green-600 dark:text-green-400",
+  deleted: "text-red-600 dark:text-red-400",
+  renamed: "text-blue-600 dark:text-blue-400",
+  modified: "text-amber-600 dark:text-amber-400",
+};
+
+function SessionDiffPanel({ diff }: { diff: SessionDiff }) {
+  return (
+    <div className="px-5 py-3 border-b border-border shrink-0 max-h-64 overflow-y-auto text-xs">
+      <p className="text-muted-foreground mb-2">
+        {diff.files_changed} file{diff.files_changed !== 1 ? "s" : ""} changed,{" "}
+        <span className="text-green-600 dark:text-green-400">
+          +{diff.insertions}
+        </span>{" "}
+        <span className="text-red-600 dark:text-red-400">
+          -{diff.deletions}
+        </span>{" "}
+        since {formatRelativeTime(diff.session_started_at)}
+        {diff.base_commit && (
+          <>
+            {" "}
+            (from{" "}
+            <span className="font-mono">{diff.base_commit.short_hash}</span>,{" "}
+            {diff.commits_since} commit{diff.commits_since !== 1 ? "s" : ""}{" "}
+            since)
+          </>
+        )}
+      </p>
+      {diff.files.length > 0 && (
+        <ul className="space-y-0.5 font-mono">
+          {diff.files.map((f) => (
+            <li key={f.path} className="flex items-center gap-2">
+              <span className={`w-16 shrink-0 ${diffStatusClass[f.status]}`}>
+                {f.status}
+              </span>
+              <span className="truncate flex-1">{f.path}</span>
+              <span className="text-green-600 dark:text-green-400">
+                +{f.insertions}
+              </span>
+              <span className="text-red-600 dark:text-red-400">
+                -{f.deletions}
+              </span>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
+
 // ─── SessionDetail page ────────────────────────────────────────────────────
 
 export default function SessionDetail() {
@@ -163,34 +324,103 @@ export default function SessionDetail() {
     sessionId: string;
   }>();
   const navigate = useNavigate();
+  const [searchParams] = useSearchParams();
+  const source = searchParams.get("source") ?? undefined;
   const [filter, setFilter] = useState("");
+  const [debouncedFilter, setDebouncedFilter] = useState("");
+  const [showDiff, setShowDiff] = useState(false);
+  const [showSummary, setShowSummary] = useState(false);
+  const queryClient = useQueryClient();
 
   const { data, isLoading, isError } = useQuery({
-    queryKey: ["session-detail", projectKey, sessionId],
-    queryFn: () => api.readClaudeSession(projectKey!, sessionId!),
+    queryKey: ["session-detail", projectKey, sessionId, source],
+    queryFn: () => api.readClaudeSession(projectKey!, sessionId!, source),
     enabled: !!projectKey && !!sessionId,
   });
 
+  // The session list already carries each session's cwd; reuse its cache.
+  const { data: sessions } = useQuery({
+    queryKey: ["claude-sessions"],
+    queryFn: api.readClaudeSessions,
+  });
+  const cwd = sessions?.find((s) => s.id === sessionId)?.cwd ?? null;
+
+  const { data: bookmarks } = useQuery({
+    queryKey: ["session-bookmarks"],
+    queryFn: api.listBookmarkedSessions,
+  });
+  const bookmark = bookmarks?.find((b) => b.session_id === sessionId);
+
+  const bookmarkMutation = useMutation({
+    mutationFn: (note: string | null) =>
+      note === null
+        ? api.unbookmarkSession(sessionId!)
+        : api.bookmarkSession(
+            sessionId!,
+            projectKey!,
+            note || undefined,
+            source,
+          ),
+    onSuccess: () =>
+      queryClient.invalidateQueries({ queryKey: ["session-bookmarks"] }),
+    onError: (e) => toast.error(`Bookmark failed: ${String(e)}`),
+  });
+
+  const diffQuery = useQuery({
+    queryKey: ["session-diff", cwd, sessionId],
+    queryFn: () => api.diffSinceSession(cwd!, sessionId!),
+    enabled: showDiff && !!cwd && !!sessionId,
+    retry: false,
+  });
+
+  useEffect(() => {
+    const t = setTimeout(() => setDebouncedFilter(filter.trim()), 200);
+    return () => clearTimeout(t);
+  }, [filter]);
+
+  // Find runs in the backend over the whole file, not just the loaded turns.
+  const findQuery = useQuery({
+    queryKey: ["session-find", projectKey, sessionId, source, debouncedFilter],
+    queryFn: () =>
+      api.searchInSession(projectKey!, sessionId!, debouncedFilter, source),
+    enabled: !!projectKey && !!sessionId && !!debouncedFilter,
+    placeholderData: (prev) => prev,
+  });
+
   const visibleTurns = useMemo(() => {
     if (!data) return [];
-    const kw = filter.trim().toLowerCase();
-    if (!kw) return data.turns;
-    return data.turns.filter((t) => {
-      if (t.content.toLowerCase().includes(kw)) return true;
-      if (
-        t.tool_calls.some(
-          (tc) =>
-            tc.name.toLowerCase().includes(kw) ||
-            tc.input.toLowerCase().includes(kw),
-        )
-      )
-        return true;
-      return false;
-    });
-  }, [data, filter]);
+    if (!filter.trim()) return data.turns;
+    const matched = new Set(findQuery.data?.map((m) => m.turn_index));
+    return data.turns.filter((_, i) => matched.has(i));
+  }, [data, filter, findQuery.data]);
+
+  const matchCount = findQuery.data?.length ?? 0;
+  const unloadedMatches =
+    findQuery.data?.filter((m) => m.turn_index >= (data?.turns.length ?? 0))
+      .length ?? 0;
 
   const isTruncated = data && data.total_count > data.turns.length;
 
+  const exportTranscript = async (format: "markdown" | "html") => {
+    try {
+      const path = await api.exportSession(
+        projectKey!,
+        sessionId!,
+        format,
+        source,
+      );
+      toast.success("Transcript exported with secrets redacted", {
+        description: path,
+        action: {
+          label: "Copy path",
+          onClick: () => navigator.clipboard.writeText(path),
+        },
+      });
+    } catch (e) {
+      toast.error(`Export failed: ${String(e)}`);
+    }
+  };
+
   return (
     <div className="flex flex-col h-full overflow-hidden">
       {/* Header */}
@@ -223,23 +453,115 @@ export default function SessionDetail() {
                 )}
               </span>
             )}
+            {bookmark?.note && (
+              <span className="truncate text-amber-500">{bookmark.note}</span>
+            )}
           </div>
         </div>
+        <button
+          onClick={() => {
+            if (bookmark) {
+              bookmarkMutation.mutate(null);
+              return;
+            }
+            const note = window.prompt("Bookmark note (optional)");
+            if (note !== null) bookmarkMutation.mutate(note.trim());
+          }}
+          disabled={bookmarkMutation.isPending}
+          className={`p-1.5 rounded-md transition-colors hover:bg-accent/40 ${
+            bookmark
+              ? "text-amber-500"
+              : "text-muted-foreground hover:text-foreground"
+          }`}
+          title={bookmark ? "Remove bookmark" : "Bookmark this session"}
+          aria-label={bookmark ? "Remove bookmark" : "Bookmark this session"}
+        >
+          <Bookmark className={`size-4 ${bookmark ? "fill-current" : ""}`} />
+        </button>
+        {cwd && (
+          <button
+            onClick={() => setShowDiff((v) => !v)}
+            className={`flex items-center gap-1.5 text-xs px-2 py-1 rounded-md transition-colors ${
+              showDiff
+                ? "bg-accent text-foreground"
+                : "text-muted-foreground hover:text-foreground hover:bg-accent/40"
+            }`}
+            title="What changed in the repository since this session started"
+          >
+            {diffQuery.isFetching ? (
+              <Loader2 className="size-3.5 animate-spin" />
+            ) : (
+              <FileDiff className="size-3.5" />
+            )}
+            Changes
+          </button>
+        )}
+        {data && (
+          <div className="flex items-center gap-0.5 text-xs text-muted-foreground">
+            <Download className="size-3.5 mr-1" />
+            <button
+              onClick={() => exportTranscript("markdown")}
+              className="px-1.5 py-1 rounded-md hover:text-foreground hover:bg-accent/40 transition-colors"
+              title="Export transcript as Markdown"
+            >
+              MD
+            </button>
+            <button
+              onClick={() => exportTranscript("html")}
+              className="px-1.5 py-1 rounded-md hover:text-foreground hover:bg-accent/40 transition-colors"
+              title="Export transcript as a standalone HTML page"
+            >
+              HTML
+            </button>
+          </div>
+        )}
+        {data && (
+          <button
+            onClick={() => setShowSummary(true)}
+            className="flex items-center gap-1.5 text-xs px-2 py-1 rounded-md transition-colors text-muted-foreground hover:text-foreground hover:bg-accent/40"
+            title="Summarise this session as an issue comment"
+          >
+            <Send className="size-3.5" />
+            Summary
+          </button>
+        )}
       </div>
 
+      {showSummary && (
+        <SessionSummaryDialog
+          sessionId={sessionId!}
+          onClose={() => setShowSummary(false)}
+        />
+      )}
+
+      {showDiff && diffQuery.data && <SessionDiffPanel diff={diffQuery.data} />}
+      {showDiff && diffQuery.isError && (
+        <p className="px-5 py-2 border-b border-border text-xs text-destructive">
+          {String(diffQuery.error)}
+        </p>
+      )}
+
       {/* Filter bar */}
       <div className="px-5 py-2 border-b border-border shrink-0">
         <input
           type="text"
-          placeholder="Filter by keyword..."
+          placeholder="Find in transcript..."
           value={filter}
           onChange={(e) => setFilter(e.target.value)}
           className="w-full text-sm bg-muted/40 border border-border rounded-md px-3 py-1.5 placeholder:text-muted-foreground focus:outline-none focus:ring-1 focus:ring-primary"
         />
         {filter && (
-          <p className="text-xs text-muted-foreground mt-1">
-            {visibleTurns.length} matching turn
-            {visibleTurns.length !== 1 ? "s" : ""}
+          <p className="text-xs text-muted-foreground mt-1 flex items-center gap-1">
+            {findQuery.isFetching && (
+              <Loader2 className="size-3 animate-spin" />
+            )}
+            {matchCount} matching turn
+            {matchCount !== 1 ? "s" : ""}
+            {unloadedMatches > 0 && (
+              <span className="text-amber-500">
+                ({unloadedMatches} past the loaded turns)
+              </span>
+            )}
           </p>
         )}
       </div>
@@ -283,6 +605,7 @@ export default function SessionDetail() {
                 key={turn.uuid || `${turn.role}-${turn.timestamp}`}
                 turn={turn}
                 highlight={filter}
+                cwd={cwd}
               />
             ))}
           </div>
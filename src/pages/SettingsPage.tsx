@@ -1,10 +1,29 @@
 import { useState, useEffect } from "react";
 import { useQuery, useMutation, useQueryClient } from "@tanstack/react-query";
-import { Loader2, Save, RefreshCw, Trash2, Database } from "lucide-react";
+import {
+  Loader2,
+  Save,
+  RefreshCw,
+  Trash2,
+  Database,
+  Plus,
+} from "lucide-react";
 import { toast } from "sonner";
 import { api } from "@/lib/api";
+import { EDITOR_NAMES, formatBytes, formatRelativeTime } from "@/lib/utils";
 import { Button } from "@/components/ui/button";
-import type { AppSettings } from "@/types";
+import { ClaudeSettingsSection } from "@/components/ClaudeSettingsSection";
+import { GithubAuthStatusPanel } from "@/components/GithubAuthStatus";
+import { GithubTokenField } from "@/components/GithubTokenField";
+import { SessionStorageSection } from "@/components/SessionStorageSection";
+import type {
+  AppSettings,
+  ClaudeDirConfig,
+  EditorKey,
+  NotificationPriority,
+  StatusSyncAction,
+  SyncRules,
+} from "@/types";
 import { check, type DownloadEvent } from "@tauri-apps/plugin-updater";
 import { getVersion } from "@tauri-apps/api/app";
 import { relaunch } from "@tauri-apps/plugin-process";
@@ -27,10 +46,49 @@ export default function SettingsPage() {
 
   const [form, setForm] = useState<AppSettings>({
     scan_path: null,
+    scan_paths: [],
+    project_markers: ["package.json", "Cargo.toml", ".git"],
+    scan_exclude_patterns: ["**/node_modules", "**/dist", "**/target"],
+    allowed_roots: [],
     theme: "system",
     terminal: "auto",
+    editor: "vscode",
     onboarding_completed: false,
     github_close_prompt: true,
+    update_check_frequency: "daily",
+    offline_mode: false,
+    last_update_check: null,
+    pty_max_sessions: 8,
+    pty_max_output_kb_per_sec: 2048,
+    pty_max_output_mb: 512,
+    sync_rules: {
+      enabled: false,
+      interval_minutes: 15,
+      item_done_closes_issue: true,
+      issue_closed_marks_item_done: true,
+      task_completed_checks_plan: true,
+    },
+    auto_follow_sessions: false,
+    notification_digest_minutes: 15,
+    notification_priorities: {
+      update_available: "high",
+      session_started: "normal",
+      session_ended: "low",
+      status_sync: "low",
+      budget: "high",
+    },
+    auto_archive_inactive_days: 0,
+    extra_claude_dirs: [],
+    monthly_budget_usd: 0,
+    weekly_budget_usd: 0,
+    dashboard_widgets: [
+      { id: "recent_projects", enabled: true },
+      { id: "due_items", enabled: true },
+      { id: "active_agents", enabled: true },
+      { id: "cost", enabled: true },
+      { id: "ci_failures", enabled: true },
+    ],
+    history_retention_days: 90,
   });
 
   useEffect(() => {
@@ -40,16 +98,60 @@ export default function SettingsPage() {
   }, [settings]);
 
   const saveMutation = useMutation({
-    mutationFn: (s: AppSettings) => api.updateSettings(s),
-    onSuccess: () => toast.success("Settings saved"),
+    mutationFn: (s: AppSettings) =>
+      api.updateSettings({
+        ...s,
+        scan_paths: s.scan_paths.map((p) => p.trim()).filter(Boolean),
+        allowed_roots: s.allowed_roots.map((r) => r.trim()).filter(Boolean),
+      }),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["editors"] });
+      toast.success("Settings saved");
+    },
     onError: () => toast.error("Failed to save settings"),
   });
 
+  const { data: editorInfo } = useQuery({
+    queryKey: ["editors"],
+    queryFn: api.detectEditors,
+  });
+
   const { data: currentVersion } = useQuery({
     queryKey: ["appVersion"],
     queryFn: getVersion,
   });
 
+  const [syncPreview, setSyncPreview] = useState<StatusSyncAction[] | null>(
+    null,
+  );
+
+  const previewSyncMutation = useMutation({
+    mutationFn: api.previewStatusSync,
+    onSuccess: setSyncPreview,
+    onError: () => toast.error("Failed to preview status sync"),
+  });
+
+  const runSyncMutation = useMutation({
+    mutationFn: api.runStatusSync,
+    onSuccess: (actions) => {
+      queryClient.invalidateQueries({ queryKey: ["planning-items"] });
+      queryClient.invalidateQueries({ queryKey: ["claude-plans"] });
+      setSyncPreview(null);
+      const failed = actions.filter((a) => a.error).length;
+      const applied = actions.length - failed;
+      if (failed > 0) {
+        toast.error(`Applied ${applied} change(s), ${failed} failed`);
+      } else {
+        toast.success(`Applied ${applied} change(s)`);
+      }
+    },
+    onError: () => toast.error("Status sync failed"),
+  });
+
+  function setSyncRule<K extends keyof SyncRules>(key: K, value: SyncRules[K]) {
+    setForm((f) => ({ ...f, sync_rules: { ...f.sync_rules, [key]: value } }));
+  }
+
   const [confirmAction, setConfirmAction] = useState<"purge" | "reset" | null>(
     null,
   );
@@ -61,31 +163,137 @@ export default function SettingsPage() {
 
   const { data: archivedProjects } = useQuery({
     queryKey: ["projects-archived"],
-    queryFn: api.getArchivedProjects,
+    queryFn: () => api.getArchivedProjects(),
+  });
+
+  const { data: snapshots = [] } = useQuery({
+    queryKey: ["snapshots"],
+    queryFn: api.listSnapshots,
+  });
+
+  const restoreSnapshotMutation = useMutation({
+    mutationFn: api.restoreSnapshot,
+    onSuccess: (count) => {
+      queryClient.invalidateQueries({ queryKey: ["projects"] });
+      queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      toast.success(`Restored ${count} record(s)`);
+    },
+    onError: () => toast.error("Failed to restore backup"),
+  });
+
+  // Destructive operations snapshot first; offer the undo right away.
+  const undoAction = (snapshotId: string) => ({
+    label: "Undo",
+    onClick: () => restoreSnapshotMutation.mutate(snapshotId),
+  });
+
+  const seedDemoMutation = useMutation({
+    mutationFn: api.seedDemoData,
+    onSuccess: (r) => {
+      queryClient.invalidateQueries();
+      toast.success(
+        `Seeded ${r.projects} projects, ${r.planning_items} items and ${r.sessions} sessions`,
+        { description: `Claude data now read from ${r.claude_dir}` },
+      );
+    },
+    onError: (e) => toast.error(`Demo seeding failed: ${String(e)}`),
+  });
+
+  const clearDemoMutation = useMutation({
+    mutationFn: api.clearDemoData,
+    onSuccess: () => {
+      queryClient.invalidateQueries();
+      toast.success("Demo data cleared");
+    },
+    onError: (e) => toast.error(`Could not clear demo data: ${String(e)}`),
   });
 
   const purgeArchivedMutation = useMutation({
     mutationFn: api.purgeArchivedProjects,
-    onSuccess: (count) => {
+    onSuccess: ({ count, snapshot_id }) => {
       queryClient.invalidateQueries({ queryKey: ["projects"] });
       queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      queryClient.invalidateQueries({ queryKey: ["snapshots"] });
       setConfirmAction(null);
-      toast.success(`Purged ${count} archived project(s)`);
+      toast.success(`Purged ${count} archived project(s)`, {
+        action: undoAction(snapshot_id),
+      });
     },
     onError: () => toast.error("Failed to purge archived projects"),
   });
 
   const resetAllMutation = useMutation({
     mutationFn: api.resetAllProjects,
-    onSuccess: (count) => {
+    onSuccess: ({ count, snapshot_id }) => {
       queryClient.invalidateQueries({ queryKey: ["projects"] });
       queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      queryClient.invalidateQueries({ queryKey: ["snapshots"] });
       setConfirmAction(null);
-      toast.success(`Deleted ${count} project record(s)`);
+      toast.success(`Deleted ${count} project record(s)`, {
+        action: undoAction(snapshot_id),
+      });
     },
     onError: () => toast.error("Failed to reset project database"),
   });
 
+  const { data: diskUsage = [] } = useQuery({
+    queryKey: ["disk-usage"],
+    queryFn: api.listProjectDiskUsage,
+  });
+
+  const { data: syncHistory = [] } = useQuery({
+    queryKey: ["sync-history"],
+    queryFn: () => api.getSyncHistory(10),
+  });
+
+  const { data: maintenanceLog = [] } = useQuery({
+    queryKey: ["event-log", "maintenance"],
+    queryFn: () => api.getEventLog(1, "maintenance"),
+  });
+
+  const maintenanceMutation = useMutation({
+    mutationFn: api.runMaintenanceNow,
+    onSuccess: (report) => {
+      queryClient.invalidateQueries({ queryKey: ["event-log"] });
+      queryClient.invalidateQueries({ queryKey: ["sync-history"] });
+      if (report.errors.length > 0) {
+        toast.warning("Maintenance finished with errors", {
+          description: report.errors.join("\n"),
+        });
+      } else {
+        toast.success(`Maintenance done in ${report.duration_ms} ms`, {
+          description: `Pruned ${report.pruned_rows} old rows`,
+        });
+      }
+    },
+    onError: (e) => toast.error(`Maintenance failed: ${String(e)}`),
+  });
+
+  const { data: duplicateGroups = [] } = useQuery({
+    queryKey: ["duplicate-projects"],
+    queryFn: api.findDuplicateProjects,
+  });
+
+  const mergeProjectsMutation = useMutation({
+    mutationFn: ({
+      keepId,
+      removeIds,
+    }: {
+      keepId: string;
+      removeIds: string[];
+    }) => api.mergeProjects(keepId, removeIds),
+    onSuccess: ({ count, snapshot_id }) => {
+      queryClient.invalidateQueries({ queryKey: ["projects"] });
+      queryClient.invalidateQueries({ queryKey: ["projects-archived"] });
+      queryClient.invalidateQueries({ queryKey: ["duplicate-projects"] });
+      queryClient.invalidateQueries({ queryKey: ["snapshots"] });
+      toast.success(`Merged ${count} duplicate record(s)`, {
+        action: undoAction(snapshot_id),
+      });
+    },
+    onError: (e) => toast.error(`Failed to merge projects: ${String(e)}`),
+  });
+
   const [updateState, setUpdateState] = useState<UpdateState>("idle");
   const [updateVersion, setUpdateVersion] = useState<string | null>(null);
   const [updateObj, setUpdateObj] = useState<Awaited<
@@ -153,19 +361,176 @@ export default function SettingsPage() {
 
           <div>
             <label className="text-sm font-medium block mb-1">
-              Projects Scan Path
+              Project Scan Roots
+            </label>
+            <textarea
+              value={form.scan_paths.join("\n")}
+              onChange={(e) =>
+                setForm((f) => ({
+                  ...f,
+                  scan_paths: e.target.value.split("\n"),
+                }))
+              }
+              rows={2}
+              placeholder={"~/cv\n~/work"}
+              className="w-full text-sm font-mono bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+            />
+            <p className="text-xs text-muted-foreground mt-1">
+              Directories to scan for projects, one per line. New projects
+              are created in the first one.
+            </p>
+          </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Allowed Roots
+            </label>
+            <textarea
+              value={form.allowed_roots.join("\n")}
+              onChange={(e) =>
+                setForm((f) => ({
+                  ...f,
+                  allowed_roots: e.target.value.split("\n"),
+                }))
+              }
+              rows={2}
+              placeholder={"/Volumes/Work\n/opt/src"}
+              className="w-full text-sm font-mono bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+            />
+            <p className="text-xs text-muted-foreground mt-1">
+              Directories outside your home folder where projects may live,
+              one per line. Projects, scans, terminals and env files are
+              otherwise limited to your home directory.
+            </p>
+          </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Extra Claude Config Dirs
+            </label>
+            <div className="space-y-2">
+              {form.extra_claude_dirs.map((dir, i) => {
+                const update = (patch: Partial<ClaudeDirConfig>) =>
+                  setForm((f) => ({
+                    ...f,
+                    extra_claude_dirs: f.extra_claude_dirs.map((d, j) =>
+                      j === i ? { ...d, ...patch } : d,
+                    ),
+                  }));
+                return (
+                  <div key={i} className="flex items-center gap-2">
+                    <input
+                      value={dir.label}
+                      onChange={(e) => update({ label: e.target.value })}
+                      placeholder="work"
+                      className="w-28 text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+                    />
+                    <input
+                      value={dir.path}
+                      onChange={(e) => update({ path: e.target.value })}
+                      placeholder="/Users/me/.claude-work"
+                      className="flex-1 text-sm font-mono bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+                    />
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() =>
+                        setForm((f) => ({
+                          ...f,
+                          extra_claude_dirs: f.extra_claude_dirs.filter(
+                            (_, j) => j !== i,
+                          ),
+                        }))
+                      }
+                    >
+                      <Trash2 className="size-3.5" />
+                    </Button>
+                  </div>
+                );
+              })}
+              <Button
+                variant="outline"
+                size="sm"
+                onClick={() =>
+                  setForm((f) => ({
+                    ...f,
+                    extra_claude_dirs: [
+                      ...f.extra_claude_dirs,
+                      { label: "", path: "" },
+                    ],
+                  }))
+                }
+              >
+                <Plus className="size-3.5 mr-1.5" />
+                Add Claude dir
+              </Button>
+            </div>
+            <p className="text-xs text-muted-foreground mt-1">
+              Other Claude Code config directories (a second account, a
+              <code className="mx-1">CLAUDE_CONFIG_DIR</code>install). Their
+              tasks, plans and sessions are listed alongside ~/.claude, tagged
+              with the label.
+            </p>
+          </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Archive Inactive Projects
             </label>
             <input
-              type="text"
-              value={form.scan_path ?? ""}
+              type="number"
+              min={0}
+              value={form.auto_archive_inactive_days}
               onChange={(e) =>
-                setForm((f) => ({ ...f, scan_path: e.target.value || null }))
+                setForm((f) => ({
+                  ...f,
+                  auto_archive_inactive_days: Math.max(
+                    0,
+                    Number(e.target.value) || 0,
+                  ),
+                }))
               }
-              placeholder="~/cv"
-              className="w-full text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+              className="w-24 text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
             />
             <p className="text-xs text-muted-foreground mt-1">
-              Directory to scan for projects
+              Days without commits, sessions, planning changes or opens before
+              a sync archives the project. 0 turns this off.
+            </p>
+          </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Agent Budget (USD)
+            </label>
+            <div className="flex items-center gap-4">
+              {(
+                [
+                  ["monthly_budget_usd", "per month", 10],
+                  ["weekly_budget_usd", "per week", 5],
+                ] as const
+              ).map(([key, label, step]) => (
+                <label key={key} className="flex items-center gap-2 text-sm">
+                  <input
+                    type="number"
+                    min={0}
+                    step={step}
+                    value={form[key]}
+                    onChange={(e) =>
+                      setForm((f) => ({
+                        ...f,
+                        [key]: Math.max(0, Number(e.target.value) || 0),
+                      }))
+                    }
+                    className="w-32 text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+                  />
+                  {label}
+                </label>
+              ))}
+            </div>
+            <p className="text-xs text-muted-foreground mt-1">
+              Estimated from token usage in session transcripts at list
+              prices. You are notified at 50%, 80% and 100% of each. 0 turns
+              a budget off.
             </p>
           </div>
 
@@ -201,10 +566,124 @@ export default function SettingsPage() {
               <option value="terminal">Terminal.app</option>
             </select>
           </div>
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              Preferred Editor
+            </label>
+            <select
+              value={form.editor}
+              onChange={(e) =>
+                setForm((f) => ({
+                  ...f,
+                  editor: e.target.value as AppSettings["editor"],
+                }))
+              }
+              className="w-full text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+            >
+              {(Object.keys(EDITOR_NAMES) as EditorKey[]).map((key) => (
+                <option key={key} value={key}>
+                  {EDITOR_NAMES[key]}
+                  {editorInfo && !editorInfo.available.includes(key)
+                    ? " (not installed)"
+                    : ""}
+                </option>
+              ))}
+            </select>
+            <p className="text-xs text-muted-foreground mt-1">
+              &quot;Open in editor&quot; and file paths in session tool calls
+              open here.
+            </p>
+          </div>
+        </div>
+
+        <div className="border border-border rounded-lg p-4 space-y-4">
+          <h2 className="text-sm font-semibold">Sessions</h2>
+          <label className="flex items-start gap-3 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={form.auto_follow_sessions}
+              onChange={(e) =>
+                setForm((f) => ({
+                  ...f,
+                  auto_follow_sessions: e.target.checked,
+                }))
+              }
+              className="mt-0.5"
+            />
+            <div>
+              <p className="text-sm font-medium">Follow new sessions</p>
+              <p className="text-xs text-muted-foreground mt-0.5">
+                When a Claude session starts, stream its transcript into a
+                read-only viewer under the project's terminal.
+              </p>
+            </div>
+          </label>
+        </div>
+
+        <ClaudeSettingsSection />
+
+        <SessionStorageSection />
+
+        <div className="border border-border rounded-lg p-4 space-y-4">
+          <h2 className="text-sm font-semibold">Notifications</h2>
+          {(
+            [
+              ["update_available", "App update available"],
+              ["session_started", "Claude session started"],
+              ["session_ended", "Claude session ended"],
+              ["status_sync", "Status sync applied changes"],
+              ["budget", "Monthly agent budget reached"],
+            ] as const
+          ).map(([trigger, label]) => (
+            <div key={trigger} className="flex items-center justify-between">
+              <span className="text-sm">{label}</span>
+              <select
+                value={form.notification_priorities[trigger] ?? "normal"}
+                onChange={(e) =>
+                  setForm((f) => ({
+                    ...f,
+                    notification_priorities: {
+                      ...f.notification_priorities,
+                      [trigger]: e.target.value as NotificationPriority,
+                    },
+                  }))
+                }
+                className="text-sm bg-background border border-border rounded-md px-2 py-1 focus:outline-none focus:ring-2 focus:ring-ring"
+              >
+                <option value="high">High</option>
+                <option value="normal">Normal</option>
+                <option value="low">Low (digest)</option>
+                <option value="off">Off</option>
+              </select>
+            </div>
+          ))}
+          <p className="text-xs text-muted-foreground">
+            Low-priority notifications are batched into one digest every{" "}
+            <input
+              type="number"
+              min={1}
+              value={form.notification_digest_minutes}
+              onChange={(e) =>
+                setForm((f) => ({
+                  ...f,
+                  notification_digest_minutes: Math.max(
+                    1,
+                    Number(e.target.value) || 1,
+                  ),
+                }))
+              }
+              className="w-14 px-1 py-0.5 text-xs bg-background border border-input rounded"
+            />{" "}
+            minutes. Normal ones arrive immediately, at most one per event
+            type every 30 seconds.
+          </p>
         </div>
 
         <div className="border border-border rounded-lg p-4 space-y-4">
           <h2 className="text-sm font-semibold">GitHub</h2>
+          <GithubAuthStatusPanel />
+          <GithubTokenField />
           <label className="flex items-start gap-3 cursor-pointer">
             <input
               type="checkbox"
@@ -223,11 +702,106 @@ export default function SettingsPage() {
               </p>
               <p className="text-xs text-muted-foreground mt-0.5">
                 When a task with a linked GitHub issue is marked complete, offer
-                to close the issue automatically via the{" "}
-                <span className="font-mono">gh</span> CLI.
+                to close the issue automatically.
+              </p>
+            </div>
+          </label>
+        </div>
+
+        <div className="border border-border rounded-lg p-4 space-y-4">
+          <h2 className="text-sm font-semibold">Status Sync</h2>
+          {(
+            [
+              [
+                "item_done_closes_issue",
+                "Close the GitHub issue when its planning item is done",
+              ],
+              [
+                "issue_closed_marks_item_done",
+                "Mark a planning item done when its issue is closed",
+              ],
+              [
+                "task_completed_checks_plan",
+                "Check off plan checkboxes matching completed Claude tasks",
+              ],
+            ] as const
+          ).map(([key, label]) => (
+            <label key={key} className="flex items-center gap-3 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={form.sync_rules[key]}
+                onChange={(e) => setSyncRule(key, e.target.checked)}
+              />
+              <span className="text-sm">{label}</span>
+            </label>
+          ))}
+          <label className="flex items-start gap-3 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={form.sync_rules.enabled}
+              onChange={(e) => setSyncRule("enabled", e.target.checked)}
+              className="mt-0.5"
+            />
+            <div>
+              <p className="text-sm font-medium">Sync in the background</p>
+              <p className="text-xs text-muted-foreground mt-0.5">
+                Apply the rules above every{" "}
+                <input
+                  type="number"
+                  min={1}
+                  value={form.sync_rules.interval_minutes}
+                  onChange={(e) =>
+                    setSyncRule(
+                      "interval_minutes",
+                      Math.max(1, Number(e.target.value) || 1),
+                    )
+                  }
+                  className="w-14 px-1 py-0.5 text-xs bg-background border border-input rounded"
+                />{" "}
+                minutes. Skipped in offline mode.
               </p>
             </div>
           </label>
+          <div className="flex items-center gap-2">
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => previewSyncMutation.mutate()}
+              disabled={previewSyncMutation.isPending}
+            >
+              {previewSyncMutation.isPending && (
+                <Loader2 className="size-3.5 animate-spin" />
+              )}
+              Preview changes
+            </Button>
+            <Button
+              size="sm"
+              onClick={() => runSyncMutation.mutate()}
+              disabled={runSyncMutation.isPending}
+            >
+              {runSyncMutation.isPending ? (
+                <Loader2 className="size-3.5 animate-spin" />
+              ) : (
+                <RefreshCw className="size-3.5" />
+              )}
+              Sync now
+            </Button>
+          </div>
+          <p className="text-xs text-muted-foreground">
+            Preview and sync use the saved rules.
+          </p>
+          {syncPreview &&
+            (syncPreview.length === 0 ? (
+              <p className="text-xs text-muted-foreground">
+                Everything is already in sync.
+              </p>
+            ) : (
+              <ul className="text-xs space-y-1 list-disc pl-4">
+                {syncPreview.map((a, i) => (
+                  <li key={i}>{a.description}</li>
+                ))}
+              </ul>
+            ))}
         </div>
 
         <div className="border border-border rounded-lg p-4 space-y-4">
@@ -248,6 +822,183 @@ export default function SettingsPage() {
             </div>
           </div>
 
+          {diskUsage.length > 0 && (
+            <div className="space-y-1.5">
+              <p className="text-xs font-medium">Reclaimable space</p>
+              {diskUsage.slice(0, 5).map((u) => (
+                <div
+                  key={u.project_path}
+                  className="flex items-center gap-2 text-xs"
+                >
+                  <span
+                    className="flex-1 truncate font-mono text-muted-foreground"
+                    title={u.project_path}
+                  >
+                    {u.project_path.split("/").pop()}
+                  </span>
+                  <span className="font-mono">
+                    {formatBytes(
+                      u.node_modules_bytes + u.target_bytes + u.build_bytes,
+                    )}
+                  </span>
+                </div>
+              ))}
+              <p className="text-xs text-muted-foreground">
+                node_modules, target and build output, as of each project's
+                last measurement.
+              </p>
+            </div>
+          )}
+
+          {syncHistory.length > 0 && (
+            <div className="space-y-1.5">
+              <p className="text-xs font-medium">Recent syncs</p>
+              {syncHistory.map((entry) => (
+                <details key={entry.id} className="text-xs">
+                  <summary className="cursor-pointer flex gap-2">
+                    <span className="flex-1 text-muted-foreground">
+                      {formatRelativeTime(entry.synced_at)}
+                    </span>
+                    <span className="font-mono">
+                      +{entry.added.length} ~{entry.updated.length} −
+                      {entry.archived.length}
+                    </span>
+                  </summary>
+                  <ul className="mt-1 pl-4 space-y-0.5">
+                    {entry.added.map((p) => (
+                      <li key={`a-${p.id}`} title={p.path}>
+                        Added {p.name}
+                      </li>
+                    ))}
+                    {entry.updated.map((p) => (
+                      <li key={`u-${p.id}`} title={p.path}>
+                        Moved {p.name} → {p.path}
+                      </li>
+                    ))}
+                    {entry.archived.map((p) => (
+                      <li key={`r-${p.id}`} title={p.path}>
+                        Archived {p.name}
+                        {p.reason && (
+                          <span className="text-muted-foreground">
+                            {" "}
+                            ({p.reason.replace(/_/g, " ")})
+                          </span>
+                        )}
+                      </li>
+                    ))}
+                    {entry.added.length +
+                      entry.updated.length +
+                      entry.archived.length ===
+                      0 && (
+                      <li className="text-muted-foreground">
+                        No changes ({entry.unchanged_count} unchanged)
+                      </li>
+                    )}
+                  </ul>
+                </details>
+              ))}
+            </div>
+          )}
+
+          <div>
+            <label className="text-sm font-medium block mb-1">
+              History Retention (days)
+            </label>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={0}
+                value={form.history_retention_days}
+                onChange={(e) =>
+                  setForm((f) => ({
+                    ...f,
+                    history_retention_days: Math.max(
+                      0,
+                      Number(e.target.value) || 0,
+                    ),
+                  }))
+                }
+                className="w-24 text-sm bg-background border border-border rounded-md px-3 py-2 focus:outline-none focus:ring-2 focus:ring-ring"
+              />
+              <Button
+                variant="outline"
+                size="sm"
+                onClick={() => maintenanceMutation.mutate()}
+                disabled={maintenanceMutation.isPending}
+              >
+                {maintenanceMutation.isPending ? (
+                  <Loader2 className="size-3.5 mr-1.5 animate-spin" />
+                ) : (
+                  <Database className="size-3.5 mr-1.5" />
+                )}
+                Run maintenance now
+              </Button>
+            </div>
+            <p className="text-xs text-muted-foreground mt-1">
+              Weekly maintenance backs up the database and removes sync
+              history, log entries and resolved suggestions older than this.
+              0 keeps everything.
+              {maintenanceLog[0] &&
+                ` Last run ${formatRelativeTime(maintenanceLog[0].created_at)}.`}
+            </p>
+          </div>
+
+          {duplicateGroups.length > 0 && (
+            <div className="space-y-2">
+              <p className="text-xs font-medium">
+                Duplicate records ({duplicateGroups.length})
+              </p>
+              {duplicateGroups.map((group) => (
+                <div
+                  key={group.projects.map((p) => p.id).join()}
+                  className="rounded-md border border-border p-2 space-y-1"
+                >
+                  <p
+                    className="text-xs font-mono text-muted-foreground truncate"
+                    title={group.key}
+                  >
+                    {group.reason === "path" ? "Same folder" : "Same repo"}:{" "}
+                    {group.key}
+                  </p>
+                  {group.projects.map((p) => (
+                    <div key={p.id} className="flex items-center gap-2 text-xs">
+                      <span className="flex-1 truncate" title={p.path}>
+                        {p.name}{" "}
+                        <span className="text-muted-foreground font-mono">
+                          {p.path}
+                        </span>
+                        {p.is_archived && (
+                          <span className="text-muted-foreground">
+                            {" "}
+                            (archived)
+                          </span>
+                        )}
+                      </span>
+                      <Button
+                        variant={
+                          p.id === group.suggested_keep_id ? "outline" : "ghost"
+                        }
+                        size="sm"
+                        className="h-6 px-2"
+                        onClick={() =>
+                          mergeProjectsMutation.mutate({
+                            keepId: p.id,
+                            removeIds: group.projects
+                              .filter((o) => o.id !== p.id)
+                              .map((o) => o.id),
+                          })
+                        }
+                        disabled={mergeProjectsMutation.isPending}
+                      >
+                        Keep this
+                      </Button>
+                    </div>
+                  ))}
+                </div>
+              ))}
+            </div>
+          )}
+
           <div className="space-y-2">
             {confirmAction === "purge" ? (
               <div className="flex items-center gap-2">
@@ -328,6 +1079,56 @@ export default function SettingsPage() {
           <p className="text-xs text-muted-foreground">
             After a reset, go to Projects and click "Sync Projects" to rebuild.
           </p>
+
+          <div className="flex gap-2">
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={() => seedDemoMutation.mutate()}
+              disabled={seedDemoMutation.isPending}
+            >
+              {seedDemoMutation.isPending && (
+                <Loader2 className="size-3.5 mr-1 animate-spin" />
+              )}
+              Load Demo Data
+            </Button>
+            <Button
+              variant="outline"
+              size="sm"
+              className="flex-1"
+              onClick={() => clearDemoMutation.mutate()}
+              disabled={clearDemoMutation.isPending}
+            >
+              {clearDemoMutation.isPending && (
+                <Loader2 className="size-3.5 mr-1 animate-spin" />
+              )}
+              Clear Demo Data
+            </Button>
+          </div>
+
+          {snapshots.length > 0 && (
+            <div className="space-y-1.5">
+              <p className="text-xs font-medium">Automatic backups</p>
+              {snapshots.slice(0, 5).map((s) => (
+                <div key={s.id} className="flex items-center gap-2 text-xs">
+                  <span className="flex-1 text-muted-foreground">
+                    {formatRelativeTime(s.created_at)} · {s.reason} ·{" "}
+                    {s.row_counts.projects ?? 0} project(s)
+                  </span>
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="h-6 px-2"
+                    onClick={() => restoreSnapshotMutation.mutate(s.id)}
+                    disabled={restoreSnapshotMutation.isPending}
+                  >
+                    Restore
+                  </Button>
+                </div>
+              ))}
+            </div>
+          )}
         </div>
 
         <Button
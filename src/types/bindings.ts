@@ -0,0 +1,1584 @@
+// Generated from commander-core's models by `npm run bindings`. Do not edit.
+
+export interface Project {
+  id: string;
+  name: string;
+  path: string;
+  tags: string[];
+  color: string | null;
+  sort_order: number;
+  is_archived: boolean;
+  created_at: string;
+  identity_key: string | null;
+  /** Emoji chosen by the user, or a detected framework key (`"rust"`, `"nextjs"`, ...). */
+  icon: string | null;
+  group_id: string | null;
+  /** Detected on sync: primary language (`"typescript"`, `"rust"`, ...). */
+  language: string | null;
+  /** Detected on sync: `"Next.js"`, `"Tauri"`, `"Axum"`, `"Django"`, ... */
+  framework: string | null;
+  /** Detected on sync: `"pnpm"`, `"cargo"`, `"poetry"`, ... */
+  package_manager: string | null;
+  /** Last time Claude or a terminal was opened for the project. */
+  last_opened_at: string | null;
+  /** Canonical location when `path` runs through a symlink; `None` when `path` is already the real path. */
+  real_path: string | null;
+  /** For a linked git worktree, the id of the project holding the main checkout. */
+  worktree_of: string | null;
+  /** Why the project was archived: `"missing_path"`, `"out_of_scan_root"` or `"inactive"` when sync did it, `"manual"` for `archive_project`. `None` while active. */
+  archived_reason: ArchiveReason | null;
+  /** When the project was last archived; `None` while active or for records archived before this was tracked. */
+  archived_at: string | null;
+}
+
+/**
+ * Why a project was archived: by sync (`missing_path`, `out_of_scan_root`,
+ * `inactive`) or by hand (`manual`).
+ */
+export type ArchiveReason = "missing_path" | "out_of_scan_root" | "inactive" | "manual";
+
+export interface CreateProjectInput {
+  name: string;
+  path: string;
+  tags?: string[] | null;
+  color?: string | null;
+  identity_key?: string | null;
+  icon?: string | null;
+}
+
+/** A starter layout for `create_project_from_template`. */
+export interface ProjectTemplate {
+  id: string;
+  name: string;
+  description: string;
+}
+
+/** Counts-only view of `git_status` for dashboard cards. */
+export interface GitSummary {
+  branch: string;
+  ahead: number;
+  behind: number;
+  staged: number;
+  unstaged: number;
+  untracked: number;
+}
+
+/** Return value of `get_project_overview`: everything a project card shows, gathered in one call. */
+export interface ProjectOverview {
+  project_id: string;
+  /** `None` when the project is not a git repository. */
+  git: GitSummary | null;
+  /** Planning items not yet in `done`. */
+  open_planning_items: number;
+  sessions_last_7_days: number;
+  /** Distinct GitHub issues linked via planning items or Claude tasks. */
+  linked_issues: number;
+  /** Linked issues not known to be closed. */
+  open_linked_issues: number;
+}
+
+/** A named folder of projects ("Client A", "Side projects") for the sidebar. */
+export interface ProjectGroup {
+  id: string;
+  name: string;
+  sort_order: number;
+  created_at: string;
+  /** Number of non-archived projects assigned to the group. */
+  project_count: number;
+}
+
+/** A directed edge in the cross-project dependency graph: the project `from_project_id` declares a dependency that resolves to `to_project_id`. */
+export interface ProjectDependencyEdge {
+  from_project_id: string;
+  to_project_id: string;
+  /** Dependency name as written in the manifest. */
+  dependency_name: string;
+  ecosystem: DependencyEcosystem;
+  /** Manifest section the dependency was declared in, e.g. `"devDependencies"`. */
+  section: string;
+  matched_by: DependencyMatch;
+}
+
+/** Manifest format a project dependency was read from. */
+export type DependencyEcosystem = "npm" | "cargo";
+
+/** How a dependency was matched to a tracked project. */
+export type DependencyMatch = "name" | "path";
+
+/** Space a project takes on disk, split into the usual reclaimable parts. Sizes are allocated bytes; each file counts towards one bucket only. */
+export interface ProjectDiskUsage {
+  project_path: string;
+  total_bytes: number;
+  node_modules_bytes: number;
+  /** Cargo `target/` directories. */
+  target_bytes: number;
+  git_bytes: number;
+  /** `dist/`, `build/`, `.next/` and other generated output. */
+  build_bytes: number;
+  /** Everything not in one of the buckets above. */
+  other_bytes: number;
+  computed_at: string;
+  /** Served from the SQLite cache rather than a fresh walk. */
+  cached: boolean;
+}
+
+export interface PlanningItem {
+  id: string;
+  project_id: string | null;
+  subject: string;
+  description: string | null;
+  status: PlanningStatus;
+  priority: number;
+  sort_order: number;
+  created_at: string;
+  updated_at: string;
+  /** Source issue when the item was imported from GitHub. */
+  github_repo: string | null;
+  github_issue_number: number | null;
+  github_issue_url: string | null;
+  /** Plan in the primary Claude root created by `create_plan_from_item`. */
+  plan_filename: string | null;
+}
+
+export type PlanningStatus = "backlog" | "todo" | "in_progress" | "done";
+
+export interface CreatePlanningItemInput {
+  project_id: string;
+  subject: string;
+  description?: string | null;
+  status: PlanningStatus;
+}
+
+export interface UpdatePlanningItemInput {
+  id: string;
+  subject: string;
+  description?: string | null;
+}
+
+/** A named board filter expression (see `commands::board_filter`). */
+export interface SavedFilter {
+  id: string;
+  name: string;
+  /** `"planning"` | `"tasks"` */
+  board: FilterBoard;
+  query: string;
+  created_at: string;
+}
+
+/** Board a saved filter applies to. */
+export type FilterBoard = "planning" | "tasks";
+
+/** Tech stack inferred from a session's tool calls (see `commands::session_stack`). */
+export interface SessionStack {
+  session_id: string;
+  primary_language: string | null;
+  /** Languages by how often they were touched, most first. */
+  languages: string[];
+  /** Topics such as `"sql-migrations"`, `"docker"`, `"ci"`, `"tests"`. */
+  tags: string[];
+  files_touched: number;
+  /** Project owning most of the touched files, else the one matching the session cwd. */
+  project_id: string | null;
+  last_modified: string | null;
+}
+
+/** A session pinned with `bookmark_session`, joined with what its transcript still says about it. */
+export interface SessionBookmark {
+  session_id: string;
+  project_key: string;
+  /** Claude config root the session lives in (see `ClaudeSession::source`). */
+  source: string;
+  note: string | null;
+  created_at: string;
+  cwd: string | null;
+  /** Transcript mtime; `None` when `missing`. */
+  last_message_at: string | null;
+  /** The transcript has been deleted since it was bookmarked. */
+  missing: boolean;
+}
+
+/** A stored copy of a plan's text (see `commands::plan_history`). */
+export interface PlanVersion {
+  id: number;
+  /** Claude root of the plan (see `ClaudePlan::source`). */
+  source: string;
+  filename: string;
+  content: string;
+  created_at: string;
+}
+
+/** Claude Code's own `settings.json` (see `commands::claude_settings`). */
+export interface ClaudeSettingsFile {
+  path: string;
+  /** `false` when the file doesn't exist yet; `settings` is then `{}`. */
+  exists: boolean;
+  /** Pass back to `update_claude_settings` to detect concurrent edits. */
+  modified_at: string | null;
+  settings: Record<string, unknown>;
+}
+
+/** A custom slash command file (see `commands::slash_commands`). */
+export interface SlashCommand {
+  /** Path below the commands directory without `.md`, e.g. `"frontend/component"` for `/component` in the `frontend` namespace. */
+  name: string;
+  /** `"user"` (`~/.claude/commands`) or `"project"` (`<project>/.claude/commands`). */
+  scope: SlashCommandScope;
+  project_id: string | null;
+  project_name: string | null;
+  path: string;
+  description: string | null;
+  argument_hint: string | null;
+  allowed_tools: string | null;
+  model: string | null;
+  /** Every frontmatter field, including the ones above. */
+  frontmatter: Record<string, string>;
+  modified_at: string | null;
+}
+
+export type SlashCommandScope = "user" | "project";
+
+export interface SlashCommandFile {
+  command: SlashCommand;
+  /** The whole file, frontmatter included. */
+  content: string;
+  /** The prompt below the frontmatter. */
+  body: string;
+}
+
+/** Return value of `get_claude_activity`. */
+export interface ClaudeActivity {
+  /** The requested range, e.g. `"90d"` or `"all"`. */
+  range: string;
+  /** First day included, `None` for `"all"`. */
+  since: string | null;
+  total_sessions: number;
+  total_messages: number;
+  /** Oldest day first; days without messages are omitted. */
+  days: ActivityDay[];
+  /** Most messages first. */
+  top_projects: ActivityProject[];
+}
+
+export interface ActivityDay {
+  /** Local `"YYYY-MM-DD"`. */
+  day: string;
+  sessions: number;
+  messages: number;
+}
+
+export interface ActivityProject {
+  /** Project id, or the session cwd when no project matches. */
+  key: string;
+  /** Project name when `key` is a project id. */
+  label: string | null;
+  sessions: number;
+  messages: number;
+}
+
+/** Return value of `analyze_session_storage`. */
+export interface SessionStorageReport {
+  total_bytes: number;
+  total_sessions: number;
+  /** Largest first. */
+  projects: SessionStorageProject[];
+}
+
+/** Transcripts of one Claude project directory. */
+export interface SessionStorageProject {
+  /** Claude root label (see `ClaudeSession::source`). */
+  source: string;
+  project_key: string;
+  /** Working directory of the most recent session. */
+  cwd: string | null;
+  /** Transcripts plus their companion directories. */
+  bytes: number;
+  sessions: number;
+  /** Oldest and newest transcript mtimes. */
+  oldest: string | null;
+  newest: string | null;
+}
+
+/** Return value of `cleanup_sessions`. */
+export interface SessionCleanupResult {
+  dry_run: boolean;
+  /** Sessions removed, or that would be on a dry run. */
+  sessions: number;
+  bytes: number;
+  paths: string[];
+  /** Where the files were moved; `None` when deleted or on a dry run. */
+  trash_path: string | null;
+  /** Old sessions kept because they are bookmarked. */
+  skipped_bookmarked: number;
+  errors: string[];
+}
+
+/** Filters for `import_issues_as_items`, mapped onto `gh issue list` flags. */
+export interface IssueImportFilters {
+  labels?: string[];
+  assignee?: string | null;
+  milestone?: string | null;
+  /** GitHub search syntax, e.g. `"sort:updated-desc no:assignee"`. */
+  search?: string | null;
+  /** Maximum issues to fetch (default 100). */
+  limit?: number | null;
+}
+
+/** Return value of `import_issues_as_items`. */
+export interface IssueImportResult {
+  project_id: string;
+  imported: PlanningItem[];
+  /** Issues that already had a planning item in the project. */
+  skipped_count: number;
+}
+
+export interface ClaudeTask {
+  id: string;
+  team_name: string | null;
+  subject: string;
+  description: string | null;
+  status: ClaudeTaskStatus;
+  owner: string | null;
+  active_form: string | null;
+  created_at: string | null;
+  updated_at: string | null;
+  /** Label of the Claude config root it was read from (`"default"` for the primary one; see `AppSettings::extra_claude_dirs`), or `project:<project id>` for a project's in-repo `.claude` directory. */
+  source: string;
+  /** Manual position within its team and status column, if the user has reordered it on the board (see `set_task_order`). */
+  sort_order: number | null;
+  /** Ids of tasks in the same team that this one waits for (`blockedBy`) and that wait for it (`blocks`). */
+  blocked_by: string[];
+  blocks: string[];
+}
+
+export type ClaudeTaskStatus = "pending" | "in_progress" | "completed" | "deleted";
+
+export interface ClaudeTaskFile {
+  team_id: string;
+  tasks: ClaudeTask[];
+}
+
+/** A task with the team it belongs to, as returned by `get_all_tasks_flat`. */
+export interface FlatClaudeTask {
+  team_id: string;
+  id: string;
+  team_name: string | null;
+  subject: string;
+  description: string | null;
+  status: ClaudeTaskStatus;
+  owner: string | null;
+  active_form: string | null;
+  created_at: string | null;
+  updated_at: string | null;
+  /** Label of the Claude config root it was read from (`"default"` for the primary one; see `AppSettings::extra_claude_dirs`), or `project:<project id>` for a project's in-repo `.claude` directory. */
+  source: string;
+  /** Manual position within its team and status column, if the user has reordered it on the board (see `set_task_order`). */
+  sort_order: number | null;
+  /** Ids of tasks in the same team that this one waits for (`blockedBy`) and that wait for it (`blocks`). */
+  blocked_by: string[];
+  blocks: string[];
+}
+
+/** A running Claude Code CLI process (`get_active_claude_processes`). */
+export interface ClaudeProcess {
+  pid: number;
+  /** Working directory, when the OS lets us read it. */
+  cwd: string | null;
+  project_id: string | null;
+  started_at: string | null;
+  /** Full command line. */
+  command: string;
+}
+
+/** One `todos/<session>-agent-<agent>.json` file: the TodoWrite list of a session's main agent or one of its subagents. */
+export interface ClaudeTodoList {
+  session_id: string;
+  /** Equal to `session_id` for the main agent. */
+  agent_id: string | null;
+  filename: string;
+  /** Claude config root it was read from (see `ClaudeTask::source`). */
+  source: string;
+  modified_at: string | null;
+  todos: ClaudeTodo[];
+}
+
+export interface ClaudeTodo {
+  id: string | null;
+  content: string;
+  /** "pending" | "in_progress" | "completed" */
+  status: ClaudeTodoStatus;
+  active_form: string | null;
+  priority: string | null;
+}
+
+export type ClaudeTodoStatus = "pending" | "in_progress" | "completed";
+
+/** A prompt typed into Claude Code, from `history.jsonl`. */
+export interface ClaudeHistoryEntry {
+  display: string;
+  /** Working directory the prompt was typed in. */
+  project: string | null;
+  timestamp: string | null;
+  source: string;
+  /** Pastes collapsed into `[Pasted text #n]` placeholders in `display`. */
+  pasted_count: number;
+}
+
+/** One page of `read_claude_history`, newest first. */
+export interface ClaudeHistoryPage {
+  entries: ClaudeHistoryEntry[];
+  /** Entries matching the query across all pages. */
+  total: number;
+  offset: number;
+}
+
+/** An agent team: its `teams/<id>/config.json`, when Claude Code wrote one, and a summary of the tasks under `tasks/<id>/`. */
+export interface ClaudeTeam {
+  /** Directory name under `tasks/` and `teams/`. */
+  team_id: string;
+  /** Claude config root it was read from (see `ClaudeTask::source`). */
+  source: string;
+  name: string | null;
+  description: string | null;
+  created_at: string | null;
+  /** Name of the lead agent. */
+  lead: string | null;
+  members: TeamMember[];
+  /** Whether `config.json` was found; teams without one are known only from their task folder. */
+  has_config: boolean;
+  pending: number;
+  in_progress: number;
+  completed: number;
+  /** Most open tasks first; unowned tasks are not listed. */
+  owners: TeamOwner[];
+  /** Latest `updatedAt` of any task. */
+  last_updated: string | null;
+}
+
+export interface TeamMember {
+  name: string;
+  agent_type: string | null;
+  model: string | null;
+  color: string | null;
+}
+
+/** Task counts for one owner within a team. */
+export interface TeamOwner {
+  owner: string;
+  pending: number;
+  in_progress: number;
+  completed: number;
+}
+
+/** Return value of `get_task_graph`. */
+export interface TaskGraph {
+  team_id: string;
+  /** Ordered by depth, then numeric id. */
+  nodes: TaskGraphNode[];
+  edges: TaskGraphEdge[];
+  /** The dependencies form a cycle; depths are then only approximate. */
+  has_cycle: boolean;
+}
+
+export interface TaskGraphNode {
+  id: string;
+  subject: string;
+  status: ClaudeTaskStatus;
+  owner: string | null;
+  /** Longest chain of blockers in front of it; 0 for tasks that wait on nothing.  Meant as the layout column. */
+  depth: number;
+  /** Some blocker is not completed yet. */
+  blocked: boolean;
+}
+
+/** `from` has to finish before `to` can start. */
+export interface TaskGraphEdge {
+  from: string;
+  to: string;
+}
+
+export interface ClaudePlan {
+  id: string;
+  filename: string;
+  title: string;
+  preview: string;
+  content: string;
+  modified_at: string | null;
+  /** Claude config root it was read from (see `ClaudeTask::source`). */
+  source: string;
+}
+
+export interface SessionMessage {
+  uuid: string;
+  role: MessageRole;
+  content: string;
+  timestamp: string;
+}
+
+/** Author of a session message or turn. */
+export type MessageRole = "user" | "assistant";
+
+export interface ClaudeSession {
+  id: string;
+  project_key: string;
+  cwd: string | null;
+  /** Session summary or first user message, on one line (see `read_session_title`). */
+  title: string | null;
+  message_count: number;
+  last_message_at: string | null;
+  project_id: string | null;
+  /** Claude config root it was read from (see `ClaudeTask::source`). */
+  source: string;
+}
+
+/** A single tool call embedded inside an assistant turn. */
+export interface SessionToolCall {
+  id: string;
+  name: string;
+  /** Input JSON serialised to a compact string for display. */
+  input: string;
+  /** Text of the matching `tool_result` from the following user turn, if one was found. */
+  output: string | null;
+  /** The tool reported a failure (`is_error` on its `tool_result`). */
+  is_error: boolean;
+}
+
+/** A parsed conversation turn from a JSONL session file. */
+export interface SessionTurn {
+  uuid: string;
+  /** "user" | "assistant" */
+  role: MessageRole;
+  /** Plain text content (may be empty for tool-only turns). */
+  content: string;
+  timestamp: string;
+  tool_calls: SessionToolCall[];
+  /** Images and documents in the message, plus those returned by its tool calls. */
+  attachments: SessionAttachment[];
+}
+
+/** An image or document block from a transcript. */
+export interface SessionAttachment {
+  /** "image" | "document" */
+  kind: AttachmentKind;
+  media_type: string | null;
+  /** Temp file a base64 payload was extracted to; only set by `load_session_detail`. */
+  path: string | null;
+  /** Location of a `url` source. */
+  url: string | null;
+  /** The tool call whose result carried it, e.g. `Read` on a screenshot. */
+  tool_use_id: string | null;
+}
+
+export type AttachmentKind = "image" | "document";
+
+/** Return value of `read_claude_session`. */
+export interface SessionDetail {
+  turns: SessionTurn[];
+  /** Total number of lines in the file (before the 500-turn cap). */
+  total_count: number;
+}
+
+/** Payload of `auto-follow-started`. */
+export interface SessionFollowStarted {
+  session_id: string;
+  path: string;
+  cwd: string | null;
+  project_id: string | null;
+  /** `true` when started by the watcher rather than `follow_session`. */
+  auto: boolean;
+}
+
+/** Payload of `session-follow-output`: rendered terminal text for new turns. */
+export interface SessionFollowOutput {
+  session_id: string;
+  data: string;
+}
+
+/** Return value of `preview_session_summary`: the comment to review before `post_session_summary` posts it. */
+export interface SessionSummaryPreview {
+  /** The markdown comment, already redacted. */
+  summary: string;
+  /** "claude" (headless `claude -p`) | "extractive" (fallback) */
+  method: SummaryMethod;
+  /** What redaction removed from the summary. */
+  redactions: Redaction[];
+}
+
+/** How a session summary was produced: by `claude -p` or extracted from the transcript. */
+export type SummaryMethod = "claude" | "extractive";
+
+/** One replacement made by `redact_session`, listed so the user can review what will be removed before sharing. */
+export interface Redaction {
+  /** Turn the match was in; empty for text outside the transcript, such as a session summary. */
+  turn_uuid: string;
+  /** "env_value" | "api_key" | "home_path" | "custom" */
+  kind: RedactionKind;
+  /** The matched text with everything past its first few characters masked. */
+  preview: string;
+  replacement: string;
+}
+
+/** Rule that produced a redaction (see `RedactionRules`). */
+export type RedactionKind = "env_value" | "api_key" | "home_path" | "custom";
+
+/** Return value of `post_session_summary`. */
+export interface SessionSummaryResult {
+  /** The markdown that was posted, after redaction. */
+  summary: string;
+  /** URL of the created comment, as printed by `gh issue comment`. */
+  comment_url: string | null;
+}
+
+/** What `redact_session` strips from a transcript. Every rule defaults to on. */
+export interface RedactionRules {
+  /** Values from the session project's `.env*` files and inline `SECRET_KEY=value` assignments. */
+  env_values?: boolean;
+  /** Well-known API key and token formats (Anthropic, OpenAI, GitHub, AWS, ...). */
+  api_keys?: boolean;
+  /** Absolute paths under the user's home directory, rewritten to `~`. */
+  home_paths?: boolean;
+  /** Extra user-supplied regexes; every match becomes `[REDACTED]`. */
+  custom_patterns?: string[];
+}
+
+/** Return value of `redact_session`. */
+export interface RedactedSession {
+  session_id: string;
+  turns: SessionTurn[];
+  redactions: Redaction[];
+  total_count: number;
+}
+
+/** A file produced during a Claude session (report, screenshot, ...), registered by `get_run_artifacts`. */
+export interface RunArtifact {
+  id: string;
+  session_id: string;
+  project_id: string | null;
+  path: string;
+  /** "screenshot" | "report" | "file" */
+  kind: RunArtifactKind;
+  /** "write" (created by a Write tool call) | "tool_output" (path mentioned in a tool result) */
+  source: RunArtifactSource;
+  size_bytes: number | null;
+  /** Whether the file is still on disk; computed on read, not stored. */
+  exists: boolean;
+  detected_at: string | null;
+}
+
+export type RunArtifactKind = "screenshot" | "report" | "file";
+
+/** Where a run artifact was found: a `Write` tool call or a path in tool output. */
+export type RunArtifactSource = "write" | "tool_output";
+
+export interface GitStatus {
+  branch: string;
+  ahead: number;
+  behind: number;
+  staged: GitFile[];
+  unstaged: GitFile[];
+  untracked: string[];
+}
+
+export interface GitFile {
+  path: string;
+  status: string;
+}
+
+export interface GitCommit {
+  hash: string;
+  short_hash: string;
+  message: string;
+  author: string;
+  timestamp: string;
+}
+
+export interface GitBranch {
+  name: string;
+  is_head: boolean;
+  upstream: string | null;
+}
+
+/** Payload of the `git-progress` event emitted during clone / fetch / pull. */
+export interface GitProgress {
+  operation_id: string;
+  /** "receiving" | "resolving" | "checkout" | "done" */
+  phase: GitProgressPhase;
+  received_objects: number;
+  total_objects: number;
+  indexed_deltas: number;
+  total_deltas: number;
+  received_bytes: number;
+  checkout_completed: number;
+  checkout_total: number;
+}
+
+export type GitProgressPhase = "receiving" | "resolving" | "checkout" | "done";
+
+/** Return value of `git_pull`. */
+export interface GitPullResult {
+  /** "up_to_date" | "fast_forward" */
+  status: GitPullStatus;
+  /** Short hash of HEAD after the pull. */
+  head: string;
+}
+
+export type GitPullStatus = "up_to_date" | "fast_forward";
+
+/** One file in a `SessionDiff`. */
+export interface SessionDiffFile {
+  path: string;
+  /** "added" | "deleted" | "modified" | "renamed" */
+  status: DiffFileStatus;
+  insertions: number;
+  deletions: number;
+}
+
+export type DiffFileStatus = "added" | "deleted" | "modified" | "renamed";
+
+/** Return value of `diff_since_session`: what changed in a repository since a Claude session started. */
+export interface SessionDiff {
+  session_id: string;
+  session_started_at: string;
+  /** Last commit made before the session started; `None` when the whole history is newer (the diff then starts from an empty tree). */
+  base_commit: GitCommit | null;
+  /** Commits on HEAD made after the session started. */
+  commits_since: number;
+  files_changed: number;
+  insertions: number;
+  deletions: number;
+  files: SessionDiffFile[];
+}
+
+/** A pre-agent-run checkpoint of a repository (see `commands::workspace_snapshots`). */
+export interface WorkspaceSnapshot {
+  id: string;
+  project_path: string;
+  /** Checked-out branch, or `None` for a detached HEAD. */
+  branch: string | null;
+  head_commit: string;
+  /** Commit holding the working tree, kept alive by `refs/commander/snapshots/<id>`. */
+  snapshot_commit: string;
+  /** Whether there was uncommitted work to capture. */
+  has_changes: boolean;
+  label: string | null;
+  created_at: string;
+}
+
+/** A status badge found in a project's README or implied by its CI config. */
+export interface ProjectBadge {
+  /** `"ci"` | `"coverage"` | `"version"` | `"license"` | `"downloads"` | `"other"` */
+  kind: BadgeKind;
+  label: string;
+  image_url: string;
+  /** Where the badge links to, e.g. the workflow runs or coverage report. */
+  link_url: string | null;
+  /** `"readme"` | `"ci_config"` */
+  source: BadgeSource;
+}
+
+export type BadgeKind = "ci" | "coverage" | "version" | "license" | "downloads" | "other";
+
+/** Where a badge was found: the README or a CI config. */
+export type BadgeSource = "readme" | "ci_config";
+
+export interface EnvFile {
+  filename: string;
+  path: string;
+  var_count: number;
+}
+
+export interface EnvVar {
+  key: string;
+  value: string;
+  masked: boolean;
+}
+
+export interface DeployConfig {
+  kind: DeployKind;
+  app_name: string | null;
+  region: string | null;
+  raw: Record<string, unknown>;
+}
+
+export type DeployKind = "fly" | "vercel";
+
+export interface SearchProjectResult {
+  id: string;
+  name: string;
+  path: string;
+  tags: string[];
+  color: string | null;
+}
+
+export interface SearchPlanningItemResult {
+  id: string;
+  project_id: string | null;
+  project_name: string;
+  subject: string;
+  description: string | null;
+  status: string;
+}
+
+export interface SearchPlanResult {
+  id: string;
+  filename: string;
+  title: string;
+  preview: string;
+  modified_at: string | null;
+  source: string;
+}
+
+export interface SearchTaskResult {
+  id: string;
+  team_id: string;
+  team_name: string | null;
+  subject: string;
+  description: string | null;
+  status: string;
+  source: string;
+}
+
+export interface SearchResults {
+  projects: SearchProjectResult[];
+  planning_items: SearchPlanningItemResult[];
+  plans: SearchPlanResult[];
+  tasks: SearchTaskResult[];
+}
+
+/** One matching transcript turn from `search_sessions`. */
+export interface SessionSearchHit {
+  session_id: string;
+  /** Directory name under `~/.claude/projects`. */
+  project_key: string;
+  /** Working directory from the transcript's first line, if recorded. */
+  cwd: string | null;
+  /** "user" | "assistant" */
+  role: MessageRole;
+  timestamp: string;
+  /** Matched text with each hit wrapped in `\u{2}` … `\u{3}`. */
+  snippet: string;
+}
+
+/** A matched range within a turn's `content`, in UTF-16 code units so it can be used with JavaScript string indices directly. */
+export interface MatchOffset {
+  start: number;
+  end: number;
+}
+
+/** One turn matching `search_in_session`. */
+export interface SessionTurnMatch {
+  uuid: string;
+  /** Position among the transcript's turns, the same order `read_claude_session` returns them in. */
+  turn_index: number;
+  /** "user" | "assistant" */
+  role: MessageRole;
+  timestamp: string;
+  /** Context around the first match, hits wrapped in `\u{2}` … `\u{3}`. */
+  snippet: string;
+  /** Every match in `content`; empty when only tool calls matched. */
+  offsets: MatchOffset[];
+  /** Tool calls whose name or input matched. */
+  tool_call_ids: string[];
+}
+
+/** Outcome of one session indexing pass. */
+export interface SessionIndexStats {
+  /** Transcripts (re)indexed because they were new or changed. */
+  indexed: number;
+  turns: number;
+  /** Transcripts dropped because their file is gone. */
+  removed: number;
+  total_sessions: number;
+}
+
+/** Estimated spend for one key (project or model) in a `CostSummary`. */
+export interface CostBreakdown {
+  key: string;
+  cost_usd: number;
+  sessions: number;
+}
+
+/** Return value of `get_cost_summary`.  Costs are estimates from list prices and the token counts in session transcripts. */
+export interface CostSummary {
+  /** `"YYYY-MM"` */
+  month: string;
+  total_usd: number;
+  /** `AppSettings::monthly_budget_usd`; `0` = no budget. */
+  budget_usd: number;
+  budget_used_pct: number | null;
+  sessions: number;
+  input_tokens: number;
+  output_tokens: number;
+  cache_creation_tokens: number;
+  cache_read_tokens: number;
+  /** Keyed by session working directory (or project key when unknown). */
+  by_project: CostBreakdown[];
+  by_model: CostBreakdown[];
+}
+
+/** Payload of the `budget-threshold` event, raised once per period for each threshold crossed. */
+export interface BudgetThreshold {
+  /** "monthly" | "weekly" */
+  period: BudgetPeriod;
+  /** `YYYY-MM` for months, the Monday's date for weeks. */
+  period_start: string;
+  /** 50, 80 or 100. */
+  threshold: BudgetPercent;
+  spent_usd: number;
+  budget_usd: number;
+}
+
+export type BudgetPeriod = "monthly" | "weekly";
+
+/** Share of a budget, in percent, that raises a warning. */
+export type BudgetPercent = 50 | 80 | 100;
+
+/** Token and cost totals for one day, project or model in `UsageStats`. */
+export interface UsageBucket {
+  /** `"YYYY-MM-DD"`, project id (or the session cwd when no project matches), or model name. */
+  key: string;
+  /** Project name for project buckets. */
+  label: string | null;
+  input_tokens: number;
+  output_tokens: number;
+  cache_creation_tokens: number;
+  cache_read_tokens: number;
+  cost_usd: number;
+  sessions: number;
+}
+
+/** Return value of `get_usage_stats`. */
+export interface UsageStats {
+  /** The requested range, e.g. `"30d"` or `"all"`. */
+  range: string;
+  /** First day included, `None` for `"all"`. */
+  since: string | null;
+  totals: UsageBucket;
+  /** Oldest day first; days without usage are omitted. */
+  by_day: UsageBucket[];
+  /** Most expensive first. */
+  by_project: UsageBucket[];
+  by_model: UsageBucket[];
+}
+
+/** A proposed status change inferred from a recent commit, e.g. marking an in-progress planning item done because a commit references its issue. */
+export interface AutoSuggestion {
+  /** `"{kind}:{target}:{commit}"`, stable across detection passes. */
+  id: string;
+  /** `"planning_item"` or `"claude_task"`. */
+  kind: SuggestionKind;
+  /** Planning item id or Claude task id. */
+  target_id: string;
+  /** Team of a Claude task; `None` for planning items. */
+  team_id: string | null;
+  /** Claude config root of a task; `None` for planning items. */
+  source: string | null;
+  project_id: string | null;
+  /** Subject of the item or task. */
+  title: string;
+  /** Human-readable evidence, e.g. "Commit 1a2b3c4 references #42". */
+  reason: string;
+  commit_id: string;
+  created_at: string;
+}
+
+export type SuggestionKind = "planning_item" | "claude_task";
+
+/** Open planning item shown by the `due_items` dashboard widget. */
+export interface DashboardPlanningItem {
+  id: string;
+  project_id: string | null;
+  project_name: string | null;
+  subject: string;
+  /** "todo" | "in_progress" */
+  status: DashboardPlanningStatus;
+  priority: number;
+  updated_at: string;
+}
+
+/** Statuses of the planning items the dashboard lists. */
+export type DashboardPlanningStatus = "todo" | "in_progress";
+
+/** Claude session whose transcript was written to recently. */
+export interface ActiveAgent {
+  session_id: string;
+  project_key: string;
+  cwd: string | null;
+  project_id: string | null;
+  /** RFC 3339 time of the last transcript write. */
+  last_activity: string;
+}
+
+/** Latest GitHub Actions run of a workflow on a branch, which failed. */
+export interface CiFailure {
+  project_id: string;
+  project_name: string;
+  /** "owner/repo" */
+  repo: string;
+  workflow: string;
+  title: string;
+  branch: string;
+  url: string;
+  created_at: string;
+}
+
+/** Return value of `get_dashboard_data`; widgets that were not requested are `None`. */
+export interface DashboardData {
+  recent_projects: Project[] | null;
+  due_items: DashboardPlanningItem[] | null;
+  active_agents: ActiveAgent[] | null;
+  cost: CostSummary | null;
+  ci_failures: CiFailure[] | null;
+}
+
+export interface TaskGithubLink {
+  task_id: string;
+  team_id: string;
+  github_issue_url: string;
+  github_issue_number: number | null;
+  github_repo: string | null;
+  created_at: string;
+  /** Cached issue state: `"open"` or `"closed"`.  `None` = not yet fetched. */
+  github_issue_state: IssueState | null;
+  /** When `github_issue_state` was last refreshed. */
+  state_updated_at: string | null;
+}
+
+/** State of a GitHub issue. */
+export type IssueState = "open" | "closed";
+
+export interface UpsertTaskGithubLinkInput {
+  task_id: string;
+  team_id: string;
+  github_issue_url: string;
+  github_issue_number?: number | null;
+  github_repo?: string | null;
+}
+
+/** Kanban card → GitHub issue link; the planning-item counterpart of `TaskGithubLink`.  Imported items are linked to their source issue. */
+export interface PlanningItemGithubLink {
+  item_id: string;
+  github_issue_url: string;
+  github_issue_number: number | null;
+  github_repo: string | null;
+  created_at: string;
+  /** Cached issue state: `"open"` or `"closed"`.  `None` = not yet fetched. */
+  github_issue_state: IssueState | null;
+  state_updated_at: string | null;
+}
+
+export interface UpsertPlanningItemGithubLinkInput {
+  item_id: string;
+  github_issue_url: string;
+  github_issue_number?: number | null;
+  github_repo?: string | null;
+}
+
+/** A repository whose issue states could not be refreshed. */
+export interface IssueStateError {
+  repo: string;
+  message: string;
+}
+
+/** Result of `fetch_issue_states`: every task link, refreshed where possible, plus one error per repository that failed. */
+export interface TaskIssueStateRefresh {
+  links: TaskGithubLink[];
+  errors: IssueStateError[];
+}
+
+/** Result of `fetch_planning_item_issue_states`. */
+export interface PlanningItemIssueStateRefresh {
+  links: PlanningItemGithubLink[];
+  errors: IssueStateError[];
+}
+
+/** Result of `check_github_auth`. */
+export interface GithubAuthStatus {
+  /** `gh` is on PATH and runnable. */
+  installed: boolean;
+  /** Version reported by `gh --version`, e.g. `"2.40.1"`. */
+  gh_version: string | null;
+  /** A personal access token is stored in the keychain; when set, the remaining fields describe that token rather than `gh`'s login. */
+  token_stored: boolean;
+  authenticated: boolean;
+  login: string | null;
+  /** OAuth scopes granted to the token.  Empty for fine-grained tokens, which do not report scopes. */
+  scopes: string[];
+  /** Scopes needed for issue creation that the token lacks. */
+  missing_scopes: string[];
+  rate_limit_remaining: number | null;
+  /** Unix timestamp at which the rate-limit window resets. */
+  rate_limit_reset: number | null;
+  /** `GH_*` problem code when something blocks GitHub actions, else `None`. */
+  problem: GithubAuthProblem | null;
+  /** Human-readable fix for `problem`. */
+  remediation: string | null;
+}
+
+/** Why `gh` cannot be used; the same codes GitHub commands fail with. */
+export type GithubAuthProblem = "GH_NOT_INSTALLED" | "GH_NOT_AUTHENTICATED" | "GH_MISSING_SCOPE" | "GH_RATE_LIMITED" | "GH_NETWORK_ERROR";
+
+export interface CreateGithubIssueOutput {
+  number: number;
+  url: string;
+}
+
+/** An issue template from `get_issue_templates`, ready to pre-fill a new issue.  Issue forms are flattened into one markdown heading per field. */
+export interface IssueTemplate {
+  /** File name in the template directory, e.g. `"bug_report.md"`. */
+  file: string;
+  name: string;
+  about: string | null;
+  /** Title prefix such as `"[Bug] "`. */
+  title: string | null;
+  labels: string[];
+  body: string;
+}
+
+/** From `suggest_closing_commit`: the trailer that closes a task's linked issue, and the commit message it was appended to, when one was given. */
+export interface ClosingCommitSuggestion {
+  /** E.g. `"Fixes owner/repo#12"`. */
+  trailer: string;
+  message: string | null;
+}
+
+/** An issue row in the `list_github_issues` picker. */
+export interface GithubIssueSummary {
+  number: number;
+  title: string;
+  /** `"open"` or `"closed"`. */
+  state: IssueState;
+  url: string;
+  labels: string[];
+  author: string | null;
+  updated_at: string | null;
+}
+
+export interface GithubIssuePage {
+  issues: GithubIssueSummary[];
+  /** 1-based. */
+  page: number;
+  has_more: boolean;
+}
+
+/** A git remote that points at GitHub, from `list_github_remotes`. */
+export interface GithubRemote {
+  /** Remote name, e.g. `"origin"` or `"upstream"`. */
+  remote: string;
+  /** `"owner/repo"`. */
+  repo: string;
+}
+
+/** A GitHub Projects (v2) board, from `list_github_projects`. */
+export interface GithubProject {
+  /** GraphQL node id, passed back to `add_issue_to_github_project`. */
+  id: string;
+  number: number;
+  title: string;
+  url: string;
+  closed: boolean;
+  /** Options of the board's `Status` field; empty when it has none. */
+  columns: GithubProjectColumn[];
+}
+
+export interface GithubProjectColumn {
+  id: string;
+  name: string;
+}
+
+/** A pull request as listed by `list_github_prs`. */
+export interface GithubPullRequest {
+  number: number;
+  title: string;
+  /** `"open"`, `"closed"` or `"merged"`. */
+  state: PullRequestState;
+  url: string;
+  head_ref: string;
+  base_ref: string;
+  is_draft: boolean;
+  author: string | null;
+  /** `"APPROVED"`, `"CHANGES_REQUESTED"` or `"REVIEW_REQUIRED"`; `None` when the repository requires no review. */
+  review_decision: ReviewDecision | null;
+  updated_at: string | null;
+}
+
+export type PullRequestState = "open" | "closed" | "merged";
+
+export type ReviewDecision = "APPROVED" | "CHANGES_REQUESTED" | "REVIEW_REQUIRED";
+
+/** One CI check or status on a pull request (`gh pr checks`). */
+export interface PrCheck {
+  name: string;
+  workflow: string | null;
+  /** `"pass"`, `"fail"`, `"pending"`, `"skipping"` or `"cancel"`. */
+  bucket: CheckBucket;
+  link: string | null;
+}
+
+/** Outcome group `gh pr checks` sorts a check into. */
+export type CheckBucket = "pass" | "fail" | "pending" | "skipping" | "cancel";
+
+export interface CreateGithubPrOutput {
+  number: number;
+  url: string;
+}
+
+export interface CachedIssueComment {
+  author: string | null;
+  body: string;
+  created_at: string | null;
+}
+
+/** A GitHub issue as it looked when last fetched, served from the local cache so it stays browsable without network access. */
+export interface CachedIssue {
+  repo: string;
+  number: number;
+  title: string;
+  body: string | null;
+  /** `"open"` or `"closed"`. */
+  state: IssueState;
+  url: string | null;
+  /** Empty until fetched by `refresh_issue_cache`; other fetches only carry titles and states. */
+  comments: CachedIssueComment[];
+  /** GitHub's last-updated time for the issue, when known. */
+  updated_at: string | null;
+  /** When this copy was fetched. */
+  fetched_at: string;
+  /** `fetched_at` is older than the staleness threshold. */
+  stale: boolean;
+}
+
+/** A project with its planning item counts, as returned by `get_projects_with_stats`. */
+export interface ProjectWithStats {
+  backlog_count: number;
+  todo_count: number;
+  in_progress_count: number;
+  done_count: number;
+  /** Planning items linked to a GitHub issue. */
+  linked_issue_count: number;
+  /** Linked planning items that are not done yet. */
+  open_linked_issue_count: number;
+  id: string;
+  name: string;
+  path: string;
+  tags: string[];
+  color: string | null;
+  sort_order: number;
+  is_archived: boolean;
+  created_at: string;
+  identity_key: string | null;
+  /** Emoji chosen by the user, or a detected framework key (`"rust"`, `"nextjs"`, ...). */
+  icon: string | null;
+  group_id: string | null;
+  /** Detected on sync: primary language (`"typescript"`, `"rust"`, ...). */
+  language: string | null;
+  /** Detected on sync: `"Next.js"`, `"Tauri"`, `"Axum"`, `"Django"`, ... */
+  framework: string | null;
+  /** Detected on sync: `"pnpm"`, `"cargo"`, `"poetry"`, ... */
+  package_manager: string | null;
+  /** Last time Claude or a terminal was opened for the project. */
+  last_opened_at: string | null;
+  /** Canonical location when `path` runs through a symlink; `None` when `path` is already the real path. */
+  real_path: string | null;
+  /** For a linked git worktree, the id of the project holding the main checkout. */
+  worktree_of: string | null;
+  /** Why the project was archived: `"missing_path"`, `"out_of_scan_root"` or `"inactive"` when sync did it, `"manual"` for `archive_project`. `None` while active. */
+  archived_reason: ArchiveReason | null;
+  /** When the project was last archived; `None` while active or for records archived before this was tracked. */
+  archived_at: string | null;
+}
+
+export interface SyncResult {
+  /** Projects whose on-disk path differed from the DB record (renamed/moved). */
+  updated: Project[];
+  /** Brand-new projects discovered by the scan. */
+  added: Project[];
+  /** Number of projects that matched exactly and needed no change. */
+  unchanged_count: number;
+  /** Number of DB records archived because their path no longer exists on disk, or by the inactivity policy. */
+  archived_count: number;
+  /** Every root directory that was walked for this sync. */
+  scanned_roots: string[];
+  /** Time spent walking the roots and probing candidate directories. */
+  scan_duration_ms: number;
+  /** Wall-clock time for the whole sync, including DB reconciliation. */
+  total_duration_ms: number;
+}
+
+/** A project touched by a sync, as recorded in `sync_history`. */
+export interface SyncHistoryProject {
+  id: string;
+  name: string;
+  path: string;
+  /** For archived projects: `"missing_path"` | `"out_of_scan_root"` | `"inactive"`. */
+  reason: ArchiveReason | null;
+}
+
+/** One persisted `sync_projects` run, as returned by `get_sync_history`. */
+export interface SyncHistoryEntry {
+  id: number;
+  /** RFC 3339 time the sync finished. */
+  synced_at: string;
+  added: SyncHistoryProject[];
+  updated: SyncHistoryProject[];
+  archived: SyncHistoryProject[];
+  unchanged_count: number;
+  scanned_roots: string[];
+  scan_duration_ms: number;
+  total_duration_ms: number;
+}
+
+/** One row of the event log, as returned by `get_event_log`. */
+export interface EventLogEntry {
+  id: number;
+  /** What produced the entry, e.g. `"maintenance"`. */
+  kind: string;
+  message: string;
+  /** Structured payload, e.g. a serialised `MaintenanceReport`. */
+  details: unknown;
+  created_at: string;
+}
+
+/** Outcome of one database maintenance run.  Steps that fail are listed in `errors`; the others still run. */
+export interface MaintenanceReport {
+  ran_at: string;
+  duration_ms: number;
+  /** WAL frames copied back into the database file. */
+  wal_frames_checkpointed: number;
+  /** Compacted copy written with `VACUUM INTO`. */
+  backup_path: string | null;
+  /** History and log rows older than `history_retention_days`. */
+  pruned_rows: number;
+  sessions_reindexed: number;
+  ledger_sessions_updated: number;
+  errors: string[];
+}
+
+/** Project records that point at the same repository. */
+export interface DuplicateProjectGroup {
+  /** `"identity_key"` or `"path"`. */
+  reason: DuplicateReason;
+  /** The shared identity key or canonical path. */
+  key: string;
+  /** Live records first, then oldest first. */
+  projects: Project[];
+  /** The record `merge_projects` should keep: the first of `projects`. */
+  suggested_keep_id: string;
+}
+
+/** What a group of duplicate projects shares. */
+export type DuplicateReason = "identity_key" | "path";
+
+/** An additional Claude config root, e.g. a work account installed with its own `CLAUDE_CONFIG_DIR`. */
+export interface ClaudeDirConfig {
+  /** Shown as the `source` of everything read from this root. */
+  label: string;
+  /** Absolute path of the config directory (the one holding `projects/`). */
+  path: string;
+}
+
+/** One home screen widget slot; list order is display order. */
+export interface DashboardWidget {
+  /** One of `DASHBOARD_WIDGET_IDS`. */
+  id: DashboardWidgetId;
+  enabled: boolean;
+}
+
+export type DashboardWidgetId = "recent_projects" | "due_items" | "active_agents" | "cost" | "ci_failures";
+
+export interface AppSettings {
+  /** The first of `scan_paths`, for callers that want one directory such as the new-project dialog.  Only used on save when `scan_paths` is empty. */
+  scan_path: string | null;
+  /** All project scan roots.  Falls back to `[scan_path]` when unset. */
+  scan_paths: string[];
+  /** File or directory names that mark a directory as a project root, e.g. `pyproject.toml`, `go.mod`, `Gemfile`. */
+  project_markers: string[];
+  /** Glob patterns for directories the scanner skips (e.g. `vendor/**`). */
+  scan_exclude_patterns: string[];
+  /** Absolute directories outside the home directory (external volumes, `/opt`, ...) that projects, scans and env files may live under. */
+  allowed_roots: string[];
+  theme: string;
+  terminal: string;
+  /** Editor used by `open_in_editor` and `open_file_at`: `"vscode"` | `"cursor"` | `"windsurf"` | `"zed"` | `"intellij"` | `"webstorm"` | `"pycharm"` | `"goland"` | `"rustrover"`. */
+  editor: EditorKey;
+  onboarding_completed: boolean;
+  /** When `true`, completing a task that has a linked GitHub issue prompts the user to close the issue automatically. */
+  github_close_prompt: boolean;
+  /** How often the background service checks for app updates: `"startup"` | `"daily"` | `"weekly"` | `"manual"`. */
+  update_check_frequency: UpdateCheckFrequency;
+  /** When `true`, background services skip all network calls. */
+  offline_mode: boolean;
+  /** RFC 3339 timestamp of the last completed update check (read-only). */
+  last_update_check: string | null;
+  /** Maximum number of concurrent in-app terminals. */
+  pty_max_sessions: number;
+  /** Per-terminal output rate cap in KiB/s; `0` = unlimited. */
+  pty_max_output_kb_per_sec: number;
+  /** Total output a terminal may produce before it is killed, in MiB; `0` = unlimited. */
+  pty_max_output_mb: number;
+  /** Which status changes the sync engine propagates between linked planning items, GitHub issues, and plan checkboxes. */
+  sync_rules: SyncRules;
+  /** When `true`, a newly started Claude session is tailed into a read-only terminal viewer automatically. */
+  auto_follow_sessions: boolean;
+  /** How often low-priority notifications are coalesced into one digest. */
+  notification_digest_minutes: number;
+  /** Per-trigger priority: `"high"` | `"normal"` | `"low"` (digest only) | `"off"`. */
+  notification_priorities: Record<string, NotificationPriority>;
+  /** Sync archives projects with no commits, sessions, planning changes or opens in this many days; `0` = never. */
+  auto_archive_inactive_days: number;
+  /** Claude config roots read alongside the primary one; tasks, plans and sessions from all roots are merged and tagged with their `source`. */
+  extra_claude_dirs: ClaudeDirConfig[];
+  /** Monthly estimated agent spend in USD that triggers `budget` notifications at 50%, 80% and 100%; `0` = no budget. */
+  monthly_budget_usd: number;
+  /** The same for the current week, Monday to Sunday. */
+  weekly_budget_usd: number;
+  /** Home screen layout; `get_dashboard_data` computes the enabled ones when called without explicit ids. */
+  dashboard_widgets: DashboardWidget[];
+  /** Weekly maintenance deletes sync history, event log entries and resolved suggestions older than this many days; `0` = keep forever. */
+  history_retention_days: number;
+}
+
+/** Key of a supported editor (see `commands::terminal::EDITORS`). */
+export type EditorKey = "vscode" | "cursor" | "windsurf" | "zed" | "intellij" | "webstorm" | "pycharm" | "goland" | "rustrover";
+
+export type UpdateCheckFrequency = "startup" | "daily" | "weekly" | "manual";
+
+/** Propagation rules for the status sync engine.  The individual rules apply to both manual and background runs; `enabled` only controls the background service. */
+export interface SyncRules {
+  /** Run the engine in the background every `interval_minutes`. */
+  enabled: boolean;
+  interval_minutes: number;
+  /** Planning item moved to done → close its GitHub issue. */
+  item_done_closes_issue: boolean;
+  /** GitHub issue closed externally → move its planning item to done. */
+  issue_closed_marks_item_done: boolean;
+  /** Claude task completed → check off the matching `- [ ]` line in plans. */
+  task_completed_checks_plan: boolean;
+}
+
+/** Priority a notification kind is raised with; `off` silences it. */
+export type NotificationPriority = "high" | "normal" | "low" | "off";
+
+/** Return value of `seed_demo_data`. */
+export interface DemoSeedResult {
+  /** Scratch directory holding the demo projects and Claude dir. */
+  root: string;
+  /** Directory now used in place of `~/.claude`. */
+  claude_dir: string;
+  projects: number;
+  planning_items: number;
+  sessions: number;
+  tasks: number;
+}
+
+/** A pre-operation snapshot in `~/.claude-commander/backups`. */
+export interface SnapshotInfo {
+  id: string;
+  created_at: string;
+  /** The operation that triggered it, e.g. `"reset_all_projects"`. */
+  reason: string;
+  row_counts: Record<string, number>;
+}
+
+/** Return value of destructive commands that snapshot first. */
+export interface DestructiveOpResult {
+  /** Rows deleted. */
+  count: number;
+  /** Pass to `restore_snapshot` to undo. */
+  snapshot_id: string;
+}
+
+/** A running read-only board view served on the LAN. */
+export interface PresentationServerInfo {
+  project_id: string;
+  port: number;
+  /** Required as `?token=` on every request. */
+  token: string;
+  /** Ready-to-open URL, token included, using the machine's LAN address. */
+  url: string;
+  started_at: string;
+}
+
+/** Payload of the `notification` event. */
+export interface AppNotification {
+  /** What raised it, e.g. `"status_sync"`; `"digest"` for a coalesced batch. */
+  trigger: string;
+  /** `"high"` | `"normal"` | `"low"` */
+  priority: NotificationLevel;
+  title: string;
+  body: string;
+  created_at: string;
+}
+
+/** Priority of a raised notification (a `NotificationPriority` other than `off`). */
+export type NotificationLevel = "high" | "normal" | "low";
+
+/** One change the sync engine would make (dry run) or made. */
+export interface StatusSyncAction {
+  /** `"item_done_closes_issue"` | `"issue_closed_marks_item_done"` | `"task_completed_checks_plan"` */
+  rule: SyncRule;
+  description: string;
+  planning_item_id: string | null;
+  github_repo: string | null;
+  github_issue_number: number | null;
+  plan_filename: string | null;
+  /** Claude root the plan lives in (see `ClaudePlan::source`). */
+  plan_source: string | null;
+  /** Checkbox text matched against a completed task's subject */
+  task_subject: string | null;
+  applied: boolean;
+  error: string | null;
+}
+
+/** Rule of the status sync engine (see `SyncRules`). */
+export type SyncRule = "item_done_closes_issue" | "issue_closed_marks_item_done" | "task_completed_checks_plan";
+
+/** One live terminal, as listed by `pty_list` and sent with `pty-created`. */
+export interface PtyInfo {
+  pty_id: string;
+  project_path: string;
+  program: string;
+  created_at: string;
+  last_activity: string;
+  env_file: string | null;
+}
+
+export interface PtySessionUsage {
+  pty_id: string;
+  created_at: string;
+  bytes_emitted: number;
+  throttle_events: number;
+  /** Env file injected into the child environment, if any. */
+  env_file: string | null;
+  /** Names (never values) of the variables injected from `env_file`. */
+  env_keys: string[];
+}
+
+export interface PtyResourceUsage {
+  sessions: PtySessionUsage[];
+  limits: PtyLimits;
+}
+
+/** Resource limits applied to in-app terminals.  `0` disables a byte limit. */
+export interface PtyLimits {
+  max_sessions: number;
+  max_output_kb_per_sec: number;
+  max_output_mb: number;
+}
+
+export interface TerminalInfo {
+  detected: string;
+  available: string[];
+}
+
+export interface EditorInfo {
+  /** The `editor` setting if installed, else the first installed editor. */
+  detected: EditorKey | null;
+  /** Keys of installed editors, in `EDITORS` order. */
+  available: EditorKey[];
+}
+
+export interface UpdateInfo {
+  available: boolean;
+  version: string | null;
+  body: string | null;
+}
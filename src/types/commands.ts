@@ -0,0 +1,1197 @@
+// Generated from the app's Tauri commands by `npm run bindings`. Do not edit.
+
+import type {
+  AppNotification,
+  AppSettings,
+  AutoSuggestion,
+  CachedIssue,
+  CachedIssueComment,
+  ClaudeActivity,
+  ClaudeHistoryPage,
+  ClaudePlan,
+  ClaudeProcess,
+  ClaudeSession,
+  ClaudeSettingsFile,
+  ClaudeTask,
+  ClaudeTaskFile,
+  ClaudeTeam,
+  ClaudeTodoList,
+  ClosingCommitSuggestion,
+  CostSummary,
+  CreateGithubIssueOutput,
+  CreateGithubPrOutput,
+  CreatePlanningItemInput,
+  CreateProjectInput,
+  DashboardData,
+  DemoSeedResult,
+  DeployConfig,
+  DestructiveOpResult,
+  DuplicateProjectGroup,
+  EditorInfo,
+  EnvFile,
+  EnvVar,
+  EventLogEntry,
+  FlatClaudeTask,
+  GitBranch,
+  GitCommit,
+  GitPullResult,
+  GitStatus,
+  GithubAuthStatus,
+  GithubIssuePage,
+  GithubProject,
+  GithubPullRequest,
+  GithubRemote,
+  IssueImportFilters,
+  IssueImportResult,
+  IssueTemplate,
+  MaintenanceReport,
+  PlanVersion,
+  PlanningItem,
+  PlanningItemGithubLink,
+  PlanningItemIssueStateRefresh,
+  PrCheck,
+  PresentationServerInfo,
+  Project,
+  ProjectBadge,
+  ProjectDependencyEdge,
+  ProjectDiskUsage,
+  ProjectGroup,
+  ProjectOverview,
+  ProjectTemplate,
+  ProjectWithStats,
+  PtyInfo,
+  PtyResourceUsage,
+  RedactedSession,
+  RedactionRules,
+  RunArtifact,
+  SavedFilter,
+  SearchResults,
+  SessionBookmark,
+  SessionCleanupResult,
+  SessionDetail,
+  SessionDiff,
+  SessionIndexStats,
+  SessionMessage,
+  SessionSearchHit,
+  SessionStack,
+  SessionStorageReport,
+  SessionSummaryPreview,
+  SessionSummaryResult,
+  SessionTurnMatch,
+  SlashCommand,
+  SlashCommandFile,
+  SnapshotInfo,
+  StatusSyncAction,
+  SyncHistoryEntry,
+  SyncResult,
+  TaskGithubLink,
+  TaskGraph,
+  TaskIssueStateRefresh,
+  TerminalInfo,
+  UpdateInfo,
+  UpdatePlanningItemInput,
+  UpsertPlanningItemGithubLinkInput,
+  UpsertTaskGithubLinkInput,
+  UsageStats,
+  WorkspaceSnapshot,
+} from "./bindings";
+
+/** The arguments and result of every command, by name. */
+export interface Commands {
+  scan_projects: {
+    args: {
+      scanPath?: string | null;
+      scanPaths?: string[] | null;
+    };
+    result: Project[];
+  };
+  sync_projects: {
+    args: {
+      scanPath?: string | null;
+      scanPaths?: string[] | null;
+    };
+    result: SyncResult;
+  };
+  get_sync_history: {
+    args: {
+      limit?: number | null;
+    };
+    result: SyncHistoryEntry[];
+  };
+  get_event_log: {
+    args: {
+      limit?: number | null;
+      kind?: string | null;
+    };
+    result: EventLogEntry[];
+  };
+  run_maintenance_now: {
+    args: Record<string, never>;
+    result: MaintenanceReport;
+  };
+  get_projects: {
+    args: Record<string, never>;
+    result: Project[];
+  };
+  get_projects_with_stats: {
+    args: Record<string, never>;
+    result: ProjectWithStats[];
+  };
+  reorder_projects: {
+    args: {
+      orderedIds: string[];
+    };
+    result: Project[];
+  };
+  find_duplicate_projects: {
+    args: Record<string, never>;
+    result: DuplicateProjectGroup[];
+  };
+  merge_projects: {
+    args: {
+      keepId: string;
+      removeIds: string[];
+    };
+    result: DestructiveOpResult;
+  };
+  get_project_templates: {
+    args: Record<string, never>;
+    result: ProjectTemplate[];
+  };
+  create_project_from_template: {
+    args: {
+      name: string;
+      path: string;
+      template: string;
+      claudeMd?: boolean | null;
+      envFile?: boolean | null;
+    };
+    result: Project;
+  };
+  get_archived_projects: {
+    args: {
+      reason?: string | null;
+      archivedSince?: string | null;
+    };
+    result: Project[];
+  };
+  restore_project: {
+    args: {
+      projectId: string;
+    };
+    result: void;
+  };
+  archive_project: {
+    args: {
+      projectId: string;
+    };
+    result: void;
+  };
+  upsert_project: {
+    args: {
+      project: CreateProjectInput;
+    };
+    result: Project;
+  };
+  set_project_icon: {
+    args: {
+      projectId: string;
+      icon?: string | null;
+    };
+    result: void;
+  };
+  touch_project: {
+    args: {
+      projectId: string;
+    };
+    result: void;
+  };
+  get_recent_projects: {
+    args: {
+      limit?: number | null;
+    };
+    result: Project[];
+  };
+  get_project_overview: {
+    args: {
+      projectId: string;
+    };
+    result: ProjectOverview;
+  };
+  delete_project: {
+    args: {
+      projectId: string;
+    };
+    result: void;
+  };
+  purge_archived_projects: {
+    args: Record<string, never>;
+    result: DestructiveOpResult;
+  };
+  reset_all_projects: {
+    args: Record<string, never>;
+    result: DestructiveOpResult;
+  };
+  import_scanned_projects: {
+    args: {
+      projects: CreateProjectInput[];
+    };
+    result: Project[];
+  };
+  seed_demo_data: {
+    args: Record<string, never>;
+    result: DemoSeedResult;
+  };
+  clear_demo_data: {
+    args: Record<string, never>;
+    result: void;
+  };
+  list_snapshots: {
+    args: Record<string, never>;
+    result: SnapshotInfo[];
+  };
+  restore_snapshot: {
+    args: {
+      id: string;
+    };
+    result: number;
+  };
+  get_groups: {
+    args: Record<string, never>;
+    result: ProjectGroup[];
+  };
+  create_group: {
+    args: {
+      name: string;
+    };
+    result: ProjectGroup;
+  };
+  rename_group: {
+    args: {
+      groupId: string;
+      name: string;
+    };
+    result: void;
+  };
+  delete_group: {
+    args: {
+      groupId: string;
+    };
+    result: void;
+  };
+  assign_project_group: {
+    args: {
+      projectId: string;
+      groupId?: string | null;
+    };
+    result: void;
+  };
+  get_cross_project_dependencies: {
+    args: Record<string, never>;
+    result: ProjectDependencyEdge[];
+  };
+  get_project_disk_usage: {
+    args: {
+      projectPath: string;
+      refresh?: boolean | null;
+    };
+    result: ProjectDiskUsage;
+  };
+  cancel_project_disk_usage: {
+    args: {
+      projectPath: string;
+    };
+    result: void;
+  };
+  list_project_disk_usage: {
+    args: Record<string, never>;
+    result: ProjectDiskUsage[];
+  };
+  read_claude_tasks: {
+    args: Record<string, never>;
+    result: ClaudeTaskFile[];
+  };
+  set_task_order: {
+    args: {
+      teamId: string;
+      status: string;
+      orderedIds: string[];
+    };
+    result: void;
+  };
+  get_all_tasks_flat: {
+    args: {
+      filter?: string | null;
+    };
+    result: FlatClaudeTask[];
+  };
+  update_claude_task: {
+    args: {
+      teamId: string;
+      taskId: string;
+      status?: string | null;
+      owner?: string | null;
+      source?: string | null;
+    };
+    result: ClaudeTask;
+  };
+  create_claude_task: {
+    args: {
+      teamId: string;
+      subject: string;
+      description?: string | null;
+      source?: string | null;
+    };
+    result: ClaudeTask;
+  };
+  get_claude_teams: {
+    args: Record<string, never>;
+    result: ClaudeTeam[];
+  };
+  get_task_graph: {
+    args: {
+      teamId: string;
+      source?: string | null;
+    };
+    result: TaskGraph;
+  };
+  read_claude_todos: {
+    args: {
+      sessionId?: string | null;
+    };
+    result: ClaudeTodoList[];
+  };
+  read_claude_history: {
+    args: {
+      limit?: number | null;
+      offset?: number | null;
+      query?: string | null;
+    };
+    result: ClaudeHistoryPage;
+  };
+  export_claude_tasks: {
+    args: {
+      teamId?: string | null;
+      format: string;
+      path: string;
+    };
+    result: number;
+  };
+  get_active_claude_processes: {
+    args: Record<string, never>;
+    result: ClaudeProcess[];
+  };
+  list_claude_plans: {
+    args: Record<string, never>;
+    result: ClaudePlan[];
+  };
+  read_claude_plan: {
+    args: {
+      filename: string;
+      source?: string | null;
+    };
+    result: string;
+  };
+  save_claude_plan: {
+    args: {
+      filename: string;
+      content: string;
+      expectedModifiedAt?: string | null;
+      source?: string | null;
+    };
+    result: string;
+  };
+  get_plan_history: {
+    args: {
+      filename: string;
+      source?: string | null;
+    };
+    result: PlanVersion[];
+  };
+  restore_plan_version: {
+    args: {
+      id: number;
+    };
+    result: string;
+  };
+  read_claude_sessions: {
+    args: Record<string, never>;
+    result: ClaudeSession[];
+  };
+  read_session_messages: {
+    args: {
+      projectKey: string;
+      sessionId: string;
+      source?: string | null;
+    };
+    result: SessionMessage[];
+  };
+  read_claude_session: {
+    args: {
+      projectKey: string;
+      sessionId: string;
+      source?: string | null;
+    };
+    result: SessionDetail;
+  };
+  read_session_attachment: {
+    args: {
+      path: string;
+    };
+    result: string;
+  };
+  redact_session: {
+    args: {
+      sessionId: string;
+      rules?: RedactionRules | null;
+    };
+    result: RedactedSession;
+  };
+  read_claude_settings: {
+    args: {
+      source?: string | null;
+    };
+    result: ClaudeSettingsFile;
+  };
+  update_claude_settings: {
+    args: {
+      patch: unknown;
+      expectedModifiedAt?: string | null;
+      source?: string | null;
+    };
+    result: ClaudeSettingsFile;
+  };
+  list_slash_commands: {
+    args: {
+      projectId?: string | null;
+    };
+    result: SlashCommand[];
+  };
+  read_slash_command: {
+    args: {
+      scope: string;
+      projectId?: string | null;
+      name: string;
+    };
+    result: SlashCommandFile;
+  };
+  create_slash_command: {
+    args: {
+      scope: string;
+      projectId?: string | null;
+      name: string;
+      content: string;
+    };
+    result: SlashCommand;
+  };
+  update_slash_command: {
+    args: {
+      scope: string;
+      projectId?: string | null;
+      name: string;
+      content: string;
+      expectedModifiedAt?: string | null;
+    };
+    result: SlashCommand;
+  };
+  get_run_artifacts: {
+    args: {
+      sessionId: string;
+    };
+    result: RunArtifact[];
+  };
+  get_session_stack: {
+    args: {
+      sessionId: string;
+    };
+    result: SessionStack;
+  };
+  find_sessions_by_stack: {
+    args: {
+      language?: string | null;
+      tag?: string | null;
+    };
+    result: SessionStack[];
+  };
+  analyze_session_storage: {
+    args: Record<string, never>;
+    result: SessionStorageReport;
+  };
+  cleanup_sessions: {
+    args: {
+      olderThanDays: number;
+      dryRun: boolean;
+      moveToTrash?: boolean | null;
+    };
+    result: SessionCleanupResult;
+  };
+  bookmark_session: {
+    args: {
+      sessionId: string;
+      projectKey: string;
+      note?: string | null;
+      source?: string | null;
+    };
+    result: void;
+  };
+  unbookmark_session: {
+    args: {
+      sessionId: string;
+    };
+    result: void;
+  };
+  list_bookmarked_sessions: {
+    args: Record<string, never>;
+    result: SessionBookmark[];
+  };
+  detect_terminal: {
+    args: Record<string, never>;
+    result: TerminalInfo;
+  };
+  launch_claude: {
+    args: {
+      projectPath: string;
+      terminal?: string | null;
+    };
+    result: void;
+  };
+  open_file_at: {
+    args: {
+      projectPath: string;
+      file: string;
+      line?: number | null;
+      editor?: string | null;
+    };
+    result: void;
+  };
+  detect_editors: {
+    args: Record<string, never>;
+    result: EditorInfo;
+  };
+  open_in_editor: {
+    args: {
+      projectPath: string;
+      editor?: string | null;
+    };
+    result: void;
+  };
+  read_allowed_file: {
+    args: {
+      path: string;
+    };
+    result: string;
+  };
+  git_status: {
+    args: {
+      projectPath: string;
+    };
+    result: GitStatus;
+  };
+  git_log: {
+    args: {
+      projectPath: string;
+      limit?: number | null;
+    };
+    result: GitCommit[];
+  };
+  git_branches: {
+    args: {
+      projectPath: string;
+    };
+    result: GitBranch[];
+  };
+  git_stage: {
+    args: {
+      projectPath: string;
+      paths: string[];
+    };
+    result: void;
+  };
+  git_unstage: {
+    args: {
+      projectPath: string;
+      paths: string[];
+    };
+    result: void;
+  };
+  git_commit: {
+    args: {
+      projectPath: string;
+      message: string;
+      amend: boolean;
+    };
+    result: GitCommit;
+  };
+  diff_since_session: {
+    args: {
+      projectPath: string;
+      sessionId: string;
+    };
+    result: SessionDiff;
+  };
+  clone_repository: {
+    args: {
+      url: string;
+      destPath: string;
+      operationId: string;
+    };
+    result: string;
+  };
+  git_fetch: {
+    args: {
+      projectPath: string;
+      remote?: string | null;
+      operationId: string;
+    };
+    result: void;
+  };
+  git_pull: {
+    args: {
+      projectPath: string;
+      remote?: string | null;
+      operationId: string;
+    };
+    result: GitPullResult;
+  };
+  cancel_git_operation: {
+    args: {
+      operationId: string;
+    };
+    result: boolean;
+  };
+  create_workspace_snapshot: {
+    args: {
+      projectPath: string;
+      label?: string | null;
+    };
+    result: WorkspaceSnapshot;
+  };
+  list_workspace_snapshots: {
+    args: {
+      projectPath: string;
+    };
+    result: WorkspaceSnapshot[];
+  };
+  restore_workspace_snapshot: {
+    args: {
+      id: string;
+    };
+    result: WorkspaceSnapshot;
+  };
+  delete_workspace_snapshot: {
+    args: {
+      id: string;
+    };
+    result: void;
+  };
+  list_env_files: {
+    args: {
+      projectPath: string;
+    };
+    result: EnvFile[];
+  };
+  get_env_vars: {
+    args: {
+      envFilePath: string;
+    };
+    result: EnvVar[];
+  };
+  set_env_var: {
+    args: {
+      envFilePath: string;
+      key: string;
+      value: string;
+    };
+    result: void;
+  };
+  delete_env_var: {
+    args: {
+      envFilePath: string;
+      key: string;
+    };
+    result: void;
+  };
+  get_deploy_configs: {
+    args: {
+      projectPath: string;
+    };
+    result: DeployConfig[];
+  };
+  get_project_badges: {
+    args: {
+      projectPath: string;
+    };
+    result: ProjectBadge[];
+  };
+  get_planning_items: {
+    args: {
+      projectId: string;
+    };
+    result: PlanningItem[];
+  };
+  create_planning_item: {
+    args: {
+      item: CreatePlanningItemInput;
+    };
+    result: PlanningItem;
+  };
+  update_planning_item: {
+    args: {
+      item: UpdatePlanningItemInput;
+    };
+    result: PlanningItem;
+  };
+  move_planning_item: {
+    args: {
+      id: string;
+      status: string;
+      sortOrder: number;
+    };
+    result: void;
+  };
+  delete_planning_item: {
+    args: {
+      id: string;
+    };
+    result: void;
+  };
+  create_plan_from_item: {
+    args: {
+      itemId: string;
+    };
+    result: PlanningItem;
+  };
+  create_issue_from_planning_item: {
+    args: {
+      itemId: string;
+      repo: string;
+    };
+    result: PlanningItem;
+  };
+  import_issues_as_items: {
+    args: {
+      repo: string;
+      filters?: IssueImportFilters | null;
+    };
+    result: IssueImportResult;
+  };
+  import_github_issues: {
+    args: {
+      projectId: string;
+      repo: string;
+      filters?: IssueImportFilters | null;
+    };
+    result: IssueImportResult;
+  };
+  get_all_planning_items: {
+    args: {
+      filter?: string | null;
+    };
+    result: PlanningItem[];
+  };
+  get_saved_filters: {
+    args: Record<string, never>;
+    result: SavedFilter[];
+  };
+  save_filter: {
+    args: {
+      name: string;
+      board: string;
+      query: string;
+    };
+    result: SavedFilter;
+  };
+  delete_saved_filter: {
+    args: {
+      id: string;
+    };
+    result: void;
+  };
+  preview_status_sync: {
+    args: Record<string, never>;
+    result: StatusSyncAction[];
+  };
+  run_status_sync: {
+    args: Record<string, never>;
+    result: StatusSyncAction[];
+  };
+  start_presentation_server: {
+    args: {
+      projectId: string;
+      port?: number | null;
+    };
+    result: PresentationServerInfo;
+  };
+  stop_presentation_server: {
+    args: {
+      projectId: string;
+    };
+    result: void;
+  };
+  get_presentation_servers: {
+    args: Record<string, never>;
+    result: PresentationServerInfo[];
+  };
+  get_pending_notifications: {
+    args: Record<string, never>;
+    result: AppNotification[];
+  };
+  flush_notification_digest: {
+    args: Record<string, never>;
+    result: number;
+  };
+  check_github_auth: {
+    args: Record<string, never>;
+    result: GithubAuthStatus;
+  };
+  set_github_token: {
+    args: {
+      token?: string | null;
+    };
+    result: string | null;
+  };
+  detect_github_repo: {
+    args: {
+      projectPath: string;
+    };
+    result: string | null;
+  };
+  list_github_remotes: {
+    args: {
+      projectPath: string;
+    };
+    result: GithubRemote[];
+  };
+  list_issue_remotes: {
+    args: {
+      projectPath: string;
+    };
+    result: GithubRemote[];
+  };
+  create_github_issue: {
+    args: {
+      repo: string;
+      title: string;
+      body: string;
+      labels?: string[] | null;
+    };
+    result: CreateGithubIssueOutput;
+  };
+  list_github_issues: {
+    args: {
+      repo: string;
+      issueState?: string | null;
+      labels?: string[] | null;
+      page?: number | null;
+    };
+    result: GithubIssuePage;
+  };
+  list_github_prs: {
+    args: {
+      repo: string;
+    };
+    result: GithubPullRequest[];
+  };
+  get_pr_checks: {
+    args: {
+      repo: string;
+      number: number;
+    };
+    result: PrCheck[];
+  };
+  create_github_pr: {
+    args: {
+      repo: string;
+      base: string;
+      head: string;
+      title: string;
+      body: string;
+    };
+    result: CreateGithubPrOutput;
+  };
+  close_github_issue: {
+    args: {
+      taskId: string;
+      teamId: string;
+      repo: string;
+      number: number;
+    };
+    result: TaskGithubLink;
+  };
+  suggest_closing_commit: {
+    args: {
+      taskId: string;
+      teamId: string;
+      message?: string | null;
+    };
+    result: ClosingCommitSuggestion;
+  };
+  fetch_issue_states: {
+    args: Record<string, never>;
+    result: TaskIssueStateRefresh;
+  };
+  upsert_task_github_link: {
+    args: {
+      link: UpsertTaskGithubLinkInput;
+    };
+    result: TaskGithubLink;
+  };
+  get_task_github_links: {
+    args: Record<string, never>;
+    result: TaskGithubLink[];
+  };
+  delete_task_github_link: {
+    args: {
+      taskId: string;
+      teamId: string;
+    };
+    result: void;
+  };
+  upsert_planning_item_github_link: {
+    args: {
+      link: UpsertPlanningItemGithubLinkInput;
+    };
+    result: PlanningItemGithubLink;
+  };
+  get_planning_item_github_links: {
+    args: Record<string, never>;
+    result: PlanningItemGithubLink[];
+  };
+  fetch_planning_item_issue_states: {
+    args: Record<string, never>;
+    result: PlanningItemIssueStateRefresh;
+  };
+  delete_planning_item_github_link: {
+    args: {
+      itemId: string;
+    };
+    result: void;
+  };
+  get_issue_comments: {
+    args: {
+      repo: string;
+      number: number;
+    };
+    result: CachedIssueComment[];
+  };
+  add_issue_comment: {
+    args: {
+      repo: string;
+      number: number;
+      body: string;
+    };
+    result: string | null;
+  };
+  list_github_projects: {
+    args: {
+      owner: string;
+    };
+    result: GithubProject[];
+  };
+  add_issue_to_github_project: {
+    args: {
+      projectId: string;
+      repo: string;
+      number: number;
+      columnId?: string | null;
+    };
+    result: string;
+  };
+  get_cached_issues: {
+    args: {
+      repo: string;
+    };
+    result: CachedIssue[];
+  };
+  refresh_issue_cache: {
+    args: {
+      repo: string;
+      limit?: number | null;
+    };
+    result: CachedIssue[];
+  };
+  get_issue_templates: {
+    args: {
+      repo: string;
+    };
+    result: IssueTemplate[];
+  };
+  follow_session: {
+    args: {
+      sessionId: string;
+    };
+    result: boolean;
+  };
+  stop_session_follow: {
+    args: {
+      sessionId: string;
+    };
+    result: void;
+  };
+  get_followed_sessions: {
+    args: Record<string, never>;
+    result: string[];
+  };
+  preview_session_summary: {
+    args: {
+      sessionId: string;
+      rules?: RedactionRules | null;
+    };
+    result: SessionSummaryPreview;
+  };
+  post_session_summary: {
+    args: {
+      sessionId: string;
+      issueLink: string;
+      summary: string;
+      rules?: RedactionRules | null;
+    };
+    result: SessionSummaryResult;
+  };
+  export_session: {
+    args: {
+      projectKey: string;
+      sessionId: string;
+      format: string;
+      source?: string | null;
+      rules?: RedactionRules | null;
+    };
+    result: string;
+  };
+  global_search: {
+    args: {
+      query: string;
+    };
+    result: SearchResults;
+  };
+  search_sessions: {
+    args: {
+      query: string;
+      limit?: number | null;
+    };
+    result: SessionSearchHit[];
+  };
+  reindex_sessions: {
+    args: Record<string, never>;
+    result: SessionIndexStats;
+  };
+  search_in_session: {
+    args: {
+      projectKey: string;
+      sessionId: string;
+      query: string;
+      source?: string | null;
+    };
+    result: SessionTurnMatch[];
+  };
+  get_cost_summary: {
+    args: {
+      month?: string | null;
+    };
+    result: CostSummary;
+  };
+  get_usage_stats: {
+    args: {
+      range: string;
+      projectId?: string | null;
+    };
+    result: UsageStats;
+  };
+  get_claude_activity: {
+    args: {
+      range: string;
+    };
+    result: ClaudeActivity;
+  };
+  get_dashboard_data: {
+    args: {
+      widgetIds?: string[] | null;
+    };
+    result: DashboardData;
+  };
+  get_suggestions: {
+    args: Record<string, never>;
+    result: AutoSuggestion[];
+  };
+  accept_suggestion: {
+    args: {
+      id: string;
+    };
+    result: AutoSuggestion;
+  };
+  dismiss_suggestion: {
+    args: {
+      id: string;
+    };
+    result: void;
+  };
+  get_settings: {
+    args: Record<string, never>;
+    result: AppSettings;
+  };
+  update_settings: {
+    args: {
+      settings: AppSettings;
+    };
+    result: void;
+  };
+  check_for_update: {
+    args: Record<string, never>;
+    result: UpdateInfo;
+  };
+  install_update: {
+    args: Record<string, never>;
+    result: void;
+  };
+  pty_create: {
+    args: {
+      projectPath: string;
+      cols: number;
+      rows: number;
+      envFile?: string | null;
+    };
+    result: string;
+  };
+  pty_write: {
+    args: {
+      ptyId: string;
+      data: number[];
+    };
+    result: void;
+  };
+  pty_resize: {
+    args: {
+      ptyId: string;
+      cols: number;
+      rows: number;
+    };
+    result: void;
+  };
+  pty_kill: {
+    args: {
+      ptyId: string;
+    };
+    result: void;
+  };
+  get_pty_resource_usage: {
+    args: Record<string, never>;
+    result: PtyResourceUsage;
+  };
+  pty_list: {
+    args: Record<string, never>;
+    result: PtyInfo[];
+  };
+  get_pty_env_file: {
+    args: {
+      projectPath: string;
+    };
+    result: string | null;
+  };
+  set_pty_env_file: {
+    args: {
+      projectPath: string;
+      envFile?: string | null;
+    };
+    result: void;
+  };
+}